@@ -56,6 +56,7 @@ pub mod console;
 pub mod logging;
 pub mod mode;
 pub mod renderables;
+pub mod text;
 pub mod theme;
 pub mod traits;
 pub mod widgets;
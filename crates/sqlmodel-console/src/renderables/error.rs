@@ -19,6 +19,7 @@
 //! println!("{}", panel.render_plain());
 //! ```
 
+use crate::text::{truncate_visible, visible_width};
 use crate::theme::Theme;
 
 /// Error severity level for styling.
@@ -313,9 +314,9 @@ impl ErrorPanel {
 
         // Top border with title
         let max_title_chars = width.saturating_sub(4);
-        let title_text = self.truncate_plain_to_width(&self.title, max_title_chars);
+        let title_text = truncate_visible(&self.title, max_title_chars);
         let title = format!(" {title_text} ");
-        let title_len = title.chars().count();
+        let title_len = visible_width(&title);
         let border_space = width.saturating_sub(2);
         let total_pad = border_space.saturating_sub(title_len);
         let left_pad = total_pad / 2;
@@ -363,7 +364,7 @@ impl ErrorPanel {
 
             // SQL content (may need truncation for very long queries)
             let sql_content_width = inner_width.saturating_sub(4);
-            let sql_display = self.truncate_plain_to_width(sql, sql_content_width);
+            let sql_display = truncate_visible(sql, sql_content_width);
             lines.push(format!(
                 "{color}│{reset} {dim}│{reset} {:<width$} {dim}│{reset} {color}│{reset}",
                 sql_display,
@@ -440,10 +441,10 @@ impl ErrorPanel {
     fn wrap_line(&self, content: &str, width: usize, border_color: &str, reset: &str) -> String {
         let inner_width = width.saturating_sub(2);
         let mut rendered = content.to_string();
-        if self.visible_length(&rendered) > inner_width {
-            rendered = self.truncate_ansi_to_width(&rendered, inner_width, reset);
+        if visible_width(&rendered) > inner_width {
+            rendered = truncate_visible(&rendered, inner_width);
         }
-        let visible_len = self.visible_length(&rendered);
+        let visible_len = visible_width(&rendered);
         let padding = inner_width.saturating_sub(visible_len);
 
         format!(
@@ -453,86 +454,6 @@ impl ErrorPanel {
         )
     }
 
-    fn truncate_ansi_to_width(&self, s: &str, max_visible: usize, reset: &str) -> String {
-        let mut out = String::new();
-        let mut visible = 0usize;
-        let mut in_escape = false;
-
-        for c in s.chars() {
-            if c == '\x1b' {
-                in_escape = true;
-                out.push(c);
-                continue;
-            }
-            if in_escape {
-                out.push(c);
-                if c == 'm' {
-                    in_escape = false;
-                }
-                continue;
-            }
-
-            if visible >= max_visible {
-                break;
-            }
-
-            out.push(c);
-            if c == '💡' {
-                visible = visible.saturating_add(2);
-            } else {
-                visible = visible.saturating_add(1);
-            }
-        }
-
-        if s.contains('\x1b') && !out.ends_with(reset) {
-            out.push_str(reset);
-        }
-
-        out
-    }
-
-    fn truncate_plain_to_width(&self, s: &str, max_visible: usize) -> String {
-        if max_visible == 0 {
-            return String::new();
-        }
-
-        let char_count = s.chars().count();
-        if char_count <= max_visible {
-            return s.to_string();
-        }
-
-        if max_visible <= 3 {
-            return ".".repeat(max_visible);
-        }
-
-        let truncated: String = s.chars().take(max_visible - 3).collect();
-        format!("{truncated}...")
-    }
-
-    /// Calculate visible length of a string (excluding ANSI codes).
-    fn visible_length(&self, s: &str) -> usize {
-        let mut len = 0;
-        let mut in_escape = false;
-
-        for c in s.chars() {
-            if c == '\x1b' {
-                in_escape = true;
-            } else if in_escape {
-                if c == 'm' {
-                    in_escape = false;
-                }
-            } else {
-                // Count emoji as 2 characters for terminal width
-                if c == '💡' {
-                    len += 2;
-                } else {
-                    len += 1;
-                }
-            }
-        }
-        len
-    }
-
     /// Get the hint color code from theme.
     fn get_hint_color(&self, theme: &Theme) -> String {
         let (r, g, b) = theme.info.rgb();
@@ -0,0 +1,280 @@
+//! Flush preview display for confirming pending writes.
+//!
+//! Lists the INSERT/UPDATE/DELETE statements a session flush would run,
+//! together with their bound parameters, and provides a confirmation prompt
+//! for interactive tools.
+//!
+//! # Example
+//!
+//! ```rust
+//! use sqlmodel_console::renderables::FlushPreview;
+//!
+//! let preview = FlushPreview::new()
+//!     .statement("INSERT", "heroes", "INSERT INTO heroes (name) VALUES ($1)", vec!["'Spider-Boy'".into()])
+//!     .statement("DELETE", "teams", "DELETE FROM teams WHERE id = $1", vec!["7".into()]);
+//!
+//! println!("{}", preview.render_plain());
+//! print!("{}", preview.confirm_prompt());
+//! ```
+//!
+//! With a session, map each `PreviewStatement` from `Session::preview_flush()`:
+//!
+//! ```rust,ignore
+//! let preview = session.preview_flush().into_iter().fold(FlushPreview::new(), |p, s| {
+//!     let params = s.params.iter().map(|v| format!("{v:?}")).collect();
+//!     p.statement(format!("{:?}", s.kind).to_uppercase(), s.table, s.sql, params)
+//! });
+//! print!("{}\n{}", preview.render_plain(), preview.confirm_prompt());
+//! ```
+
+use crate::theme::Theme;
+
+/// One statement in a flush preview.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PreviewEntry {
+    /// Statement kind (e.g., "INSERT", "UPDATE", "DELETE").
+    pub kind: String,
+    /// Target table.
+    pub table: String,
+    /// SQL text with placeholders.
+    pub sql: String,
+    /// Bound parameters, already formatted for display.
+    pub params: Vec<String>,
+}
+
+/// Display of the statements a flush would execute.
+#[derive(Debug, Clone, Default)]
+pub struct FlushPreview {
+    /// Statements in execution order
+    entries: Vec<PreviewEntry>,
+    /// Theme for styled output
+    theme: Option<Theme>,
+}
+
+impl FlushPreview {
+    /// Create an empty flush preview.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a statement.
+    #[must_use]
+    pub fn statement(
+        mut self,
+        kind: impl Into<String>,
+        table: impl Into<String>,
+        sql: impl Into<String>,
+        params: Vec<String>,
+    ) -> Self {
+        self.entries.push(PreviewEntry {
+            kind: kind.into(),
+            table: table.into(),
+            sql: sql.into(),
+            params,
+        });
+        self
+    }
+
+    /// Set the theme for styled output.
+    #[must_use]
+    pub fn theme(mut self, theme: Theme) -> Self {
+        self.theme = Some(theme);
+        self
+    }
+
+    /// The statements in execution order.
+    #[must_use]
+    pub fn entries(&self) -> &[PreviewEntry] {
+        &self.entries
+    }
+
+    /// Whether there is nothing to flush.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Count statements per kind, in first-seen order.
+    fn kind_counts(&self) -> Vec<(String, usize)> {
+        let mut counts: Vec<(String, usize)> = Vec::new();
+        for entry in &self.entries {
+            let kind = entry.kind.to_lowercase();
+            match counts.iter_mut().find(|(k, _)| *k == kind) {
+                Some((_, n)) => *n += 1,
+                None => counts.push((kind, 1)),
+            }
+        }
+        counts
+    }
+
+    /// One-line summary, e.g. "3 statements (2 insert, 1 delete)".
+    #[must_use]
+    pub fn summary(&self) -> String {
+        if self.entries.is_empty() {
+            return "No pending changes".to_string();
+        }
+        let noun = if self.entries.len() == 1 {
+            "statement"
+        } else {
+            "statements"
+        };
+        let breakdown: Vec<String> = self
+            .kind_counts()
+            .into_iter()
+            .map(|(kind, n)| format!("{n} {kind}"))
+            .collect();
+        format!("{} {noun} ({})", self.entries.len(), breakdown.join(", "))
+    }
+
+    /// Confirmation prompt to print before reading a yes/no answer.
+    #[must_use]
+    pub fn confirm_prompt(&self) -> String {
+        format!("Apply {}? [y/N] ", self.summary())
+    }
+
+    /// Interpret a user's answer to [`confirm_prompt`](Self::confirm_prompt).
+    ///
+    /// Only `y` / `yes` (case-insensitive) confirm; anything else, including
+    /// an empty line, declines.
+    #[must_use]
+    pub fn is_confirmed(answer: &str) -> bool {
+        matches!(answer.trim().to_ascii_lowercase().as_str(), "y" | "yes")
+    }
+
+    /// Render as plain text.
+    #[must_use]
+    pub fn render_plain(&self) -> String {
+        let mut lines = vec![format!("Pending flush: {}", self.summary())];
+        for (i, entry) in self.entries.iter().enumerate() {
+            lines.push(format!("{:>3}. [{}] {}", i + 1, entry.kind, entry.sql));
+            if !entry.params.is_empty() {
+                lines.push(format!("     params: {}", entry.params.join(", ")));
+            }
+        }
+        lines.join("\n")
+    }
+
+    /// Render as styled text with ANSI colors.
+    #[must_use]
+    pub fn render_styled(&self) -> String {
+        let theme = self.theme.clone().unwrap_or_default();
+        let reset = "\x1b[0m";
+        let dim = theme.dim.color_code();
+        let header = theme.header.color_code();
+
+        let mut lines = vec![format!("{header}Pending flush: {}{reset}", self.summary())];
+        for (i, entry) in self.entries.iter().enumerate() {
+            let kind_color = match entry.kind.to_ascii_uppercase().as_str() {
+                "INSERT" => theme.success.color_code(),
+                "UPDATE" => theme.warning.color_code(),
+                "DELETE" => theme.error.color_code(),
+                _ => theme.info.color_code(),
+            };
+            lines.push(format!(
+                "{dim}{:>3}.{reset} {kind_color}{}{reset} {}",
+                i + 1,
+                entry.kind,
+                entry.sql
+            ));
+            if !entry.params.is_empty() {
+                lines.push(format!(
+                    "     {dim}params: {}{reset}",
+                    entry.params.join(", ")
+                ));
+            }
+        }
+        lines.join("\n")
+    }
+
+    /// Render as JSON-serializable structure.
+    #[must_use]
+    pub fn to_json(&self) -> serde_json::Value {
+        let statements: Vec<serde_json::Value> = self
+            .entries
+            .iter()
+            .map(|e| {
+                serde_json::json!({
+                    "kind": e.kind,
+                    "table": e.table,
+                    "sql": e.sql,
+                    "params": e.params,
+                })
+            })
+            .collect();
+
+        serde_json::json!({
+            "count": self.entries.len(),
+            "statements": statements,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> FlushPreview {
+        FlushPreview::new()
+            .statement(
+                "INSERT",
+                "heroes",
+                "INSERT INTO heroes (name) VALUES ($1)",
+                vec!["'Spider-Boy'".into()],
+            )
+            .statement(
+                "INSERT",
+                "heroes",
+                "INSERT INTO heroes (name) VALUES ($1)",
+                vec!["'Rusty-Man'".into()],
+            )
+            .statement(
+                "DELETE",
+                "teams",
+                "DELETE FROM teams WHERE id = $1",
+                vec!["7".into()],
+            )
+    }
+
+    #[test]
+    fn test_summary_and_prompt() {
+        let preview = sample();
+        assert_eq!(preview.summary(), "3 statements (2 insert, 1 delete)");
+        assert_eq!(
+            preview.confirm_prompt(),
+            "Apply 3 statements (2 insert, 1 delete)? [y/N] "
+        );
+        assert_eq!(FlushPreview::new().summary(), "No pending changes");
+    }
+
+    #[test]
+    fn test_is_confirmed() {
+        assert!(FlushPreview::is_confirmed("y"));
+        assert!(FlushPreview::is_confirmed(" YES\n"));
+        assert!(!FlushPreview::is_confirmed(""));
+        assert!(!FlushPreview::is_confirmed("no"));
+    }
+
+    #[test]
+    fn test_render_plain() {
+        let plain = sample().render_plain();
+        assert!(plain.starts_with("Pending flush: 3 statements"));
+        assert!(plain.contains("  3. [DELETE] DELETE FROM teams WHERE id = $1"));
+        assert!(plain.contains("     params: 7"));
+    }
+
+    #[test]
+    fn test_render_styled_has_ansi() {
+        let styled = sample().render_styled();
+        assert!(styled.contains('\x1b'));
+        assert!(styled.contains("DELETE FROM teams"));
+    }
+
+    #[test]
+    fn test_to_json() {
+        let json = sample().to_json();
+        assert_eq!(json["count"], 3);
+        assert_eq!(json["statements"][2]["table"], "teams");
+        assert_eq!(json["statements"][0]["params"][0], "'Spider-Boy'");
+    }
+}
@@ -25,6 +25,7 @@
 //! println!("{}", status.render_plain());
 //! ```
 
+use crate::text::{truncate_visible, visible_width};
 use crate::theme::Theme;
 
 /// Migration state enum indicating the status of a migration.
@@ -466,9 +467,9 @@ impl MigrationStatus {
 
         // Top border with title
         let max_title_chars = width.saturating_sub(4);
-        let title_text = self.truncate_plain_to_width(title, max_title_chars);
+        let title_text = truncate_visible(title, max_title_chars);
         let title_display = format!(" {title_text} ");
-        let title_len = title_display.chars().count();
+        let title_len = visible_width(&title_display);
         let border_space = width.saturating_sub(2);
         let total_pad = border_space.saturating_sub(title_len);
         let left_pad = total_pad / 2;
@@ -534,7 +535,7 @@ impl MigrationStatus {
 
                 // Format version and name (truncate if needed)
                 let version_name = format!("{}_{}", record.version, record.name);
-                let version_name_display = self.truncate_plain_to_width(&version_name, 30);
+                let version_name_display = truncate_visible(&version_name, 30);
 
                 // Format timestamp
                 let timestamp = record.format_timestamp().unwrap_or_else(|| "-".to_string());
@@ -634,7 +635,7 @@ impl MigrationStatus {
 
     /// Wrap a line to fit within the panel width.
     fn wrap_line(&self, content: &str, width: usize) -> String {
-        let visible_len = self.visible_length(content);
+        let visible_len = visible_width(content);
         let padding = width.saturating_sub(2).saturating_sub(visible_len);
         let reset = MigrationState::reset_code();
 
@@ -648,43 +649,6 @@ impl MigrationStatus {
             padding = padding
         )
     }
-
-    fn truncate_plain_to_width(&self, s: &str, max_visible: usize) -> String {
-        if max_visible == 0 {
-            return String::new();
-        }
-
-        let char_count = s.chars().count();
-        if char_count <= max_visible {
-            return s.to_string();
-        }
-
-        if max_visible <= 3 {
-            return ".".repeat(max_visible);
-        }
-
-        let truncated: String = s.chars().take(max_visible - 3).collect();
-        format!("{truncated}...")
-    }
-
-    /// Calculate visible length of a string (excluding ANSI codes).
-    fn visible_length(&self, s: &str) -> usize {
-        let mut len = 0;
-        let mut in_escape = false;
-
-        for c in s.chars() {
-            if c == '\x1b' {
-                in_escape = true;
-            } else if in_escape {
-                if c == 'm' {
-                    in_escape = false;
-                }
-            } else {
-                len += 1;
-            }
-        }
-        len
-    }
 }
 
 impl Default for MigrationStatus {
@@ -3,7 +3,7 @@
 //! This module contains custom renderable types for SQLModel output:
 //!
 //! - Query results as tables
-//! - Schema diagrams as trees
+//! - Schema diagrams as trees, and diffs between two schemas
 //! - Table info panels for single-table details
 //! - Error messages as panels
 //! - Connection pool status dashboards
@@ -14,6 +14,7 @@
 //! - Query tree visualization
 //! - Query timing display
 //! - Migration status panels
+//! - Flush previews with confirmation prompts
 //!
 //! # Implementation Status
 //!
@@ -26,6 +27,7 @@
 pub mod batch_tracker;
 pub mod ddl_display;
 pub mod error;
+pub mod flush_preview;
 pub mod migration_status;
 pub mod operation_progress;
 pub mod pool_status;
@@ -40,15 +42,18 @@ pub mod table_info;
 pub use batch_tracker::{BatchOperationTracker, BatchState};
 pub use ddl_display::{ChangeKind, ChangeRegion, DdlDisplay, SqlDialect};
 pub use error::{ErrorPanel, ErrorSeverity};
+pub use flush_preview::{FlushPreview, PreviewEntry};
 pub use migration_status::{MigrationRecord, MigrationState, MigrationStatus};
 pub use operation_progress::{OperationProgress, ProgressState};
 pub use pool_status::{PoolHealth, PoolStatsProvider, PoolStatusDisplay};
-pub use query_results::{Cell, PlainFormat, QueryResultTable, QueryResults, ValueType};
+pub use query_results::{
+    BinaryDisplay, BorderStyle, Cell, PlainFormat, QueryResultTable, QueryResults, ValueType,
+};
 pub use query_timing::QueryTiming;
 pub use query_tree::QueryTreeView;
 pub use schema_tree::{
-    ColumnData, ForeignKeyData, IndexData, SchemaTree, SchemaTreeConfig, TableData,
+    ColumnData, ForeignKeyData, IndexData, SchemaDiffTree, SchemaTree, SchemaTreeConfig, TableData,
 };
-pub use spinner::{IndeterminateSpinner, SpinnerStyle};
+pub use spinner::{IndeterminateSpinner, SpinnerReporter, SpinnerStyle};
 pub use sql_syntax::SqlHighlighter;
 pub use table_info::{TableInfo, TableStats, format_bytes, format_number};
@@ -16,6 +16,7 @@
 //! println!("{}", display.render_plain());
 //! ```
 
+use crate::text::truncate_visible;
 use crate::theme::Theme;
 use std::time::Duration;
 
@@ -341,7 +342,7 @@ impl PoolStatusDisplay {
             || "Connection Pool Status".to_string(),
             |n| format!("Connection Pool: {}", n),
         );
-        let title_display = self.truncate_plain_to_width(&title, width.saturating_sub(3));
+        let title_display = truncate_visible(&title, width.saturating_sub(3));
 
         // Box drawing
         let top_border = format!("┌{}┐", "─".repeat(width.saturating_sub(2)));
@@ -423,24 +424,6 @@ impl PoolStatusDisplay {
 
         lines.join("\n")
     }
-
-    fn truncate_plain_to_width(&self, s: &str, max_visible: usize) -> String {
-        if max_visible == 0 {
-            return String::new();
-        }
-
-        let char_count = s.chars().count();
-        if char_count <= max_visible {
-            return s.to_string();
-        }
-
-        if max_visible <= 3 {
-            return ".".repeat(max_visible);
-        }
-
-        let truncated: String = s.chars().take(max_visible - 3).collect();
-        format!("{truncated}...")
-    }
 }
 
 #[cfg(test)]
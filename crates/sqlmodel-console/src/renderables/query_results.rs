@@ -24,7 +24,15 @@
 //! println!("{}", table.render_plain_format(PlainFormat::Csv));
 //! ```
 
+use crate::text::{truncate_visible, visible_width};
 use crate::theme::Theme;
+use base64::{Engine as _, engine::general_purpose::STANDARD as BASE64};
+use sqlmodel_core::row::ColumnInfo;
+use sqlmodel_core::{BytesDisplay, DisplayHint, FieldInfo, Model, Row, Value};
+use std::borrow::{Borrow, Cow};
+use std::collections::HashMap;
+use std::io::{self, Write};
+use std::sync::Arc;
 use std::time::Duration;
 
 /// Plain text output format for query results.
@@ -41,6 +49,209 @@ pub enum PlainFormat {
     JsonArray,
 }
 
+/// How binary cells (created with [`Cell::binary`]) are displayed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BinaryDisplay {
+    /// Opaque size marker: `[BLOB: 5 bytes]` (default, never dumps contents)
+    #[default]
+    Marker,
+    /// Full PostgreSQL-style hex: `\x48656c6c6f`
+    Hex,
+    /// Full standard base64: `SGVsbG8=`
+    Base64,
+    /// Hex of at most this many bytes, followed by the total size
+    HexTruncated(usize),
+}
+
+impl BinaryDisplay {
+    /// Format bytes according to this display mode.
+    #[must_use]
+    pub fn format(&self, bytes: &[u8]) -> String {
+        match *self {
+            Self::Marker => binary_marker(bytes.len()),
+            Self::Hex => format!("\\x{}", hex_encode(bytes)),
+            Self::Base64 => BASE64.encode(bytes),
+            Self::HexTruncated(max) if bytes.len() > max => format!(
+                "\\x{}... ({} bytes)",
+                hex_encode(&bytes[..max]),
+                bytes.len()
+            ),
+            Self::HexTruncated(_) => format!("\\x{}", hex_encode(bytes)),
+        }
+    }
+}
+
+impl From<BytesDisplay> for BinaryDisplay {
+    fn from(mode: BytesDisplay) -> Self {
+        match mode {
+            BytesDisplay::Marker => Self::Marker,
+            BytesDisplay::Hex => Self::Hex,
+            BytesDisplay::Base64 => Self::Base64,
+            BytesDisplay::HexTruncated(max) => Self::HexTruncated(max),
+        }
+    }
+}
+
+fn binary_marker(len: usize) -> String {
+    format!("[BLOB: {len} bytes]")
+}
+
+fn hex_encode(bytes: &[u8]) -> String {
+    use std::fmt::Write;
+    bytes
+        .iter()
+        .fold(String::with_capacity(bytes.len() * 2), |mut out, b| {
+            let _ = write!(out, "{b:02x}");
+            out
+        })
+}
+
+/// Hex digits shown for a [`DisplayHint::Hash`] column.
+const HASH_DIGITS: usize = 12;
+
+/// Short lowercase hex for a digest given as bytes (already `\x` hex) or
+/// hex text.
+fn format_hash(text: &str) -> String {
+    let hex = text
+        .strip_prefix("\\x")
+        .or_else(|| text.strip_prefix("0x"))
+        .unwrap_or(text);
+    if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return text.to_string();
+    }
+    let hex = hex.to_ascii_lowercase();
+    if hex.len() > HASH_DIGITS {
+        format!("{}...", &hex[..HASH_DIGITS])
+    } else {
+        hex
+    }
+}
+
+/// `text` rounded to `scale` decimals with comma-grouped thousands.
+fn format_currency(text: &str, scale: u8) -> String {
+    let Ok(Value::Decimal(rounded)) = Value::Decimal(text.to_string()).decimal_round(scale.into())
+    else {
+        return text.to_string();
+    };
+    let (sign, digits) = match rounded.strip_prefix('-') {
+        Some(rest) => ("-", rest),
+        None => ("", rounded.as_str()),
+    };
+    let (int_part, frac_part) = match digits.split_once('.') {
+        Some((int_part, frac_part)) => (int_part, Some(frac_part)),
+        None => (digits, None),
+    };
+    let mut grouped = String::with_capacity(int_part.len() + int_part.len() / 3);
+    for (i, digit) in int_part.chars().enumerate() {
+        if i > 0 && (int_part.len() - i) % 3 == 0 {
+            grouped.push(',');
+        }
+        grouped.push(digit);
+    }
+    // Rounding leaves shorter fractions alone; pad them out to `scale`.
+    let scale = usize::from(scale);
+    match frac_part {
+        Some(frac) => format!("{sign}{grouped}.{frac:0<scale$}"),
+        None if scale > 0 => format!("{sign}{grouped}.{:0<scale$}", ""),
+        None => format!("{sign}{grouped}"),
+    }
+}
+
+/// A fraction as a percentage, without trailing zeros: `0.125` -> `12.5%`.
+fn format_percent(text: &str) -> String {
+    let Ok(Value::Decimal(scaled)) = Value::Decimal(text.to_string()).decimal_mul(&Value::Int(100))
+    else {
+        return text.to_string();
+    };
+    let trimmed = if scaled.contains('.') {
+        scaled.trim_end_matches('0').trim_end_matches('.')
+    } else {
+        scaled.as_str()
+    };
+    format!("{trimmed}%")
+}
+
+/// Display text for a query value.
+fn render_value(value: &Value) -> String {
+    match value {
+        Value::Null | Value::TypedNull(_) => "NULL".to_string(),
+        Value::Bool(v) => v.to_string(),
+        Value::TinyInt(v) => v.to_string(),
+        Value::SmallInt(v) => v.to_string(),
+        Value::Int(v) => v.to_string(),
+        Value::BigInt(v) => v.to_string(),
+        Value::Float(v) => v.to_string(),
+        Value::Double(v) => v.to_string(),
+        Value::Decimal(v) | Value::Text(v) => v.clone(),
+        Value::Bytes(bytes) => binary_marker(bytes.len()),
+        Value::Date(days) => format_date(i64::from(*days)),
+        Value::Time(micros) => format_time(*micros),
+        Value::Timestamp(micros) => format_timestamp(*micros),
+        Value::TimestampTz(micros) => format!("{}+00:00", format_timestamp(*micros)),
+        Value::Uuid(bytes) => {
+            let hex = hex_encode(bytes);
+            format!(
+                "{}-{}-{}-{}-{}",
+                &hex[0..8],
+                &hex[8..12],
+                &hex[12..16],
+                &hex[16..20],
+                &hex[20..32]
+            )
+        }
+        Value::Json(json) => json.to_string(),
+        Value::Array(values) => {
+            let items: Vec<String> = values.iter().map(render_value).collect();
+            format!("[{}]", items.join(", "))
+        }
+        Value::Default => "DEFAULT".to_string(),
+    }
+}
+
+const MICROS_PER_SECOND: i64 = 1_000_000;
+const MICROS_PER_DAY: i64 = 86_400 * MICROS_PER_SECOND;
+
+/// `YYYY-MM-DD` for days since 1970-01-01 (proleptic Gregorian).
+fn format_date(days: i64) -> String {
+    // Howard Hinnant's civil_from_days.
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    format!("{year:04}-{month:02}-{day:02}")
+}
+
+/// `HH:MM:SS[.ffffff]` for microseconds since midnight.
+fn format_time(micros: i64) -> String {
+    use std::fmt::Write;
+    let seconds = micros.div_euclid(MICROS_PER_SECOND);
+    let fraction = micros.rem_euclid(MICROS_PER_SECOND);
+    let mut out = format!(
+        "{:02}:{:02}:{:02}",
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60
+    );
+    if fraction != 0 {
+        let _ = write!(out, ".{fraction:06}");
+    }
+    out
+}
+
+/// `YYYY-MM-DD HH:MM:SS[.ffffff]` for microseconds since the Unix epoch.
+fn format_timestamp(micros: i64) -> String {
+    format!(
+        "{} {}",
+        format_date(micros.div_euclid(MICROS_PER_DAY)),
+        format_time(micros.rem_euclid(MICROS_PER_DAY))
+    )
+}
+
 /// SQL value type for cell coloring.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum ValueType {
@@ -180,6 +391,8 @@ pub struct Cell {
     pub value: String,
     /// The inferred or explicit value type.
     pub value_type: ValueType,
+    /// Raw bytes for binary cells, rendered per the table's [`BinaryDisplay`].
+    pub bytes: Option<Vec<u8>>,
 }
 
 impl Cell {
@@ -188,7 +401,11 @@ impl Cell {
     pub fn new(value: impl Into<String>) -> Self {
         let value = value.into();
         let value_type = ValueType::infer(&value);
-        Self { value, value_type }
+        Self {
+            value,
+            value_type,
+            bytes: None,
+        }
     }
 
     /// Create a cell with explicit type.
@@ -197,6 +414,7 @@ impl Cell {
         Self {
             value: value.into(),
             value_type,
+            bytes: None,
         }
     }
 
@@ -206,6 +424,117 @@ impl Cell {
         Self {
             value: "NULL".to_string(),
             value_type: ValueType::Null,
+            bytes: None,
+        }
+    }
+
+    /// Create a cell from a query value, typed by its variant.
+    ///
+    /// Dates and times render as ISO 8601, UUIDs in hyphenated form and JSON
+    /// compactly; bytes become a [`Cell::binary`].
+    #[must_use]
+    pub fn from_value(value: &Value) -> Self {
+        match value {
+            Value::Null | Value::TypedNull(_) => Self::null(),
+            Value::Bytes(bytes) => Self::binary(bytes.clone()),
+            Value::Bool(_) => Self::with_type(render_value(value), ValueType::Boolean),
+            Value::TinyInt(_) | Value::SmallInt(_) | Value::Int(_) | Value::BigInt(_) => {
+                Self::with_type(render_value(value), ValueType::Integer)
+            }
+            Value::Float(_) | Value::Double(_) | Value::Decimal(_) => {
+                Self::with_type(render_value(value), ValueType::Float)
+            }
+            Value::Date(_) => Self::with_type(render_value(value), ValueType::Date),
+            Value::Time(_) => Self::with_type(render_value(value), ValueType::Time),
+            Value::Timestamp(_) | Value::TimestampTz(_) => {
+                Self::with_type(render_value(value), ValueType::Timestamp)
+            }
+            Value::Uuid(_) => Self::with_type(render_value(value), ValueType::Uuid),
+            Value::Json(_) => Self::with_type(render_value(value), ValueType::Json),
+            Value::Text(_) | Value::Array(_) | Value::Default => {
+                Self::with_type(render_value(value), ValueType::String)
+            }
+        }
+    }
+
+    /// Create a binary cell holding raw bytes.
+    ///
+    /// The value shows as a `[BLOB: N bytes]` marker unless the table's
+    /// [`BinaryDisplay`] asks for hex or base64; JSON output uses base64.
+    #[must_use]
+    pub fn binary(bytes: impl Into<Vec<u8>>) -> Self {
+        let bytes = bytes.into();
+        Self {
+            value: binary_marker(bytes.len()),
+            value_type: ValueType::Binary,
+            bytes: Some(bytes),
+        }
+    }
+}
+
+/// Border glyph set for styled output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BorderStyle {
+    /// Rounded corners: `╭─╮ │ ╰─╯`
+    #[default]
+    Rounded,
+    /// Square corners: `┌─┐ │ └─┘`
+    Square,
+    /// Double lines: `╔═╗ ║ ╚═╝`
+    Double,
+    /// Pure ASCII: `+-+ | +-+`
+    Ascii,
+    /// No borders; columns are separated by spaces and the header is
+    /// underlined with dashes.
+    None,
+}
+
+/// Glyphs for one [`BorderStyle`].
+struct BorderGlyphs {
+    top_left: char,
+    top_right: char,
+    bottom_left: char,
+    bottom_right: char,
+    horizontal: char,
+    vertical: char,
+    left_tee: char,
+    right_tee: char,
+    cross: char,
+    /// Line under the header (differs from `horizontal` only for `None`)
+    rule: char,
+}
+
+impl BorderStyle {
+    const fn glyphs(self) -> BorderGlyphs {
+        let [
+            top_left,
+            top_right,
+            bottom_left,
+            bottom_right,
+            horizontal,
+            vertical,
+            left_tee,
+            right_tee,
+            cross,
+            rule,
+        ] = match self {
+            BorderStyle::Rounded => ['╭', '╮', '╰', '╯', '─', '│', '├', '┤', '┼', '─'],
+            BorderStyle::Square => ['┌', '┐', '└', '┘', '─', '│', '├', '┤', '┼', '─'],
+            BorderStyle::Double => ['╔', '╗', '╚', '╝', '═', '║', '╠', '╣', '╬', '═'],
+            BorderStyle::Ascii => ['+', '+', '+', '+', '-', '|', '+', '+', '+', '-'],
+            BorderStyle::None => [' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', '-'],
+        };
+        BorderGlyphs {
+            top_left,
+            top_right,
+            bottom_left,
+            bottom_right,
+            horizontal,
+            vertical,
+            left_tee,
+            right_tee,
+            cross,
+            rule,
         }
     }
 }
@@ -234,6 +563,32 @@ pub struct QueryResultTable {
     theme: Option<Theme>,
     /// Plain format for non-styled output
     plain_format: PlainFormat,
+    /// Terminate CSV records with CRLF instead of LF
+    csv_crlf: bool,
+    /// How binary cells are displayed
+    binary_display: BinaryDisplay,
+    /// Per-column overrides of `binary_display`
+    column_binary: HashMap<usize, BinaryDisplay>,
+    /// Per-column display transforms
+    formatters: ColumnFormatters,
+    /// Apply column formatters to JSON output too
+    format_json: bool,
+    /// Border glyphs for styled output
+    border_style: BorderStyle,
+}
+
+type ColumnFormatter = Arc<dyn Fn(&str) -> String + Send + Sync>;
+
+/// Display transforms keyed by column index.
+#[derive(Clone, Default)]
+struct ColumnFormatters(HashMap<usize, ColumnFormatter>);
+
+impl std::fmt::Debug for ColumnFormatters {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut columns: Vec<_> = self.0.keys().collect();
+        columns.sort();
+        f.debug_tuple("ColumnFormatters").field(&columns).finish()
+    }
 }
 
 /// Alias for `QueryResultTable` for simpler API.
@@ -255,6 +610,12 @@ impl QueryResultTable {
             show_row_numbers: false,
             theme: None,
             plain_format: PlainFormat::Pipe,
+            csv_crlf: false,
+            binary_display: BinaryDisplay::Marker,
+            column_binary: HashMap::new(),
+            formatters: ColumnFormatters::default(),
+            format_json: false,
+            border_style: BorderStyle::default(),
         }
     }
 
@@ -285,6 +646,80 @@ impl QueryResultTable {
         table
     }
 
+    /// Create a query result table from core rows.
+    ///
+    /// Each cell's [`ValueType`] comes from the value's variant rather than
+    /// being guessed from its text, so a `TEXT` column holding `"123"` stays a
+    /// string and NULLs, bytes, JSON and UUIDs render as such.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let rows = conn.query(&cx, "SELECT id, name FROM heroes", &[]).await?;
+    /// if let Some(first) = rows.first() {
+    ///     println!("{}", QueryResultTable::from_rows(&first.column_info(), &rows).render_plain());
+    /// }
+    /// ```
+    #[must_use]
+    pub fn from_rows(columns: &ColumnInfo, rows: &[Row]) -> Self {
+        let mut table = Self::new();
+        table.columns = columns.names().to_vec();
+        table.rows = rows
+            .iter()
+            .map(|row| row.values().map(Cell::from_value).collect())
+            .collect();
+        table
+    }
+
+    /// Create a query result table from rows of model `M`'s table.
+    ///
+    /// Like [`from_rows`](Self::from_rows), but columns matching a field of
+    /// `M` with a display hint are presented accordingly; see
+    /// [`field_hints`](Self::field_hints).
+    #[must_use]
+    pub fn from_model_rows<M: Model>(columns: &ColumnInfo, rows: &[Row]) -> Self {
+        Self::from_rows(columns, rows).field_hints(M::fields())
+    }
+
+    /// Apply the display hints of `fields` to the columns with the same name.
+    #[must_use]
+    pub fn field_hints(mut self, fields: &[FieldInfo]) -> Self {
+        for field in fields {
+            let Some(hint) = field.display else {
+                continue;
+            };
+            if let Some(col) = self.columns.iter().position(|c| c == field.column_name) {
+                self = self.display_hint(col, hint);
+            }
+        }
+        self
+    }
+
+    /// Present column `col` according to `hint`.
+    ///
+    /// Hashes show as short hex, currency rounded with grouped thousands
+    /// (`1,234.50`) and percentages scaled by 100 (`12.5%`); byte modes
+    /// override the table's [`binary_display`](Self::binary_display) for the
+    /// column. Values that are not numbers are left as they are. This
+    /// replaces any [`format_column`](Self::format_column) for the column.
+    #[must_use]
+    pub fn display_hint(mut self, col: usize, hint: DisplayHint) -> Self {
+        match hint {
+            DisplayHint::Bytes(mode) => {
+                self.column_binary.insert(col, mode.into());
+                self
+            }
+            DisplayHint::Hash => {
+                self.column_binary.insert(col, BinaryDisplay::Hex);
+                self.format_column(col, format_hash)
+            }
+            DisplayHint::Currency(scale) => {
+                self.format_column(col, move |text| format_currency(text, scale))
+            }
+            DisplayHint::Percent => self.format_column(col, format_percent),
+        }
+    }
+
     /// Set the table title.
     #[must_use]
     pub fn title(mut self, title: impl Into<String>) -> Self {
@@ -369,6 +804,13 @@ impl QueryResultTable {
         self
     }
 
+    /// Set the border glyphs used by styled output (default: rounded).
+    #[must_use]
+    pub fn border_style(mut self, style: BorderStyle) -> Self {
+        self.border_style = style;
+        self
+    }
+
     /// Set the plain format for non-styled output.
     #[must_use]
     pub fn plain_format(mut self, format: PlainFormat) -> Self {
@@ -376,6 +818,106 @@ impl QueryResultTable {
         self
     }
 
+    /// Use CRLF record separators in CSV output (RFC 4180; expected by most
+    /// spreadsheet applications). Defaults to LF.
+    #[must_use]
+    pub fn csv_crlf(mut self, crlf: bool) -> Self {
+        self.csv_crlf = crlf;
+        self
+    }
+
+    /// Set how binary cells are displayed (default: size marker only).
+    ///
+    /// Applies to styled, pipe and CSV output; JSON always encodes binary
+    /// cells as base64.
+    #[must_use]
+    pub fn binary_display(mut self, mode: BinaryDisplay) -> Self {
+        self.binary_display = mode;
+        self
+    }
+
+    /// Transform the display value of every cell in column `col`.
+    ///
+    /// The function receives the cell's display string (after binary
+    /// formatting) and returns the text to show, e.g. cents to `$12.34` or an
+    /// enum code to its label. It runs before column widths are computed and
+    /// applies to styled, pipe and CSV output. NULL cells are left alone.
+    ///
+    /// JSON output keeps the typed values unless
+    /// [`format_json`](Self::format_json) is enabled.
+    #[must_use]
+    pub fn format_column(
+        mut self,
+        col: usize,
+        f: impl Fn(&str) -> String + Send + Sync + 'static,
+    ) -> Self {
+        self.formatters.0.insert(col, Arc::new(f));
+        self
+    }
+
+    /// Also apply column formatters to JSON output (default: false).
+    ///
+    /// Formatted cells are then emitted as JSON strings.
+    #[must_use]
+    pub fn format_json(mut self, enabled: bool) -> Self {
+        self.format_json = enabled;
+        self
+    }
+
+    /// Display text for a cell in column `col`, applying the binary display
+    /// mode and any column formatter.
+    fn cell_text<'a>(&self, col: usize, cell: &'a Cell) -> Cow<'a, str> {
+        let text = match &cell.bytes {
+            Some(bytes) => Cow::Owned(
+                self.column_binary
+                    .get(&col)
+                    .unwrap_or(&self.binary_display)
+                    .format(bytes),
+            ),
+            None => Cow::Borrowed(cell.value.as_str()),
+        };
+        match self.formatters.0.get(&col) {
+            Some(f) if cell.value_type != ValueType::Null => Cow::Owned(f(&text)),
+            _ => text,
+        }
+    }
+
+    /// JSON value for a cell in column `col`; binary cells are encoded as
+    /// base64 strings.
+    fn cell_json(&self, col: usize, cell: &Cell) -> serde_json::Value {
+        if self.format_json
+            && cell.value_type != ValueType::Null
+            && self.formatters.0.contains_key(&col)
+        {
+            return serde_json::Value::String(self.cell_text(col, cell).into_owned());
+        }
+        if let Some(bytes) = &cell.bytes {
+            return serde_json::Value::String(BASE64.encode(bytes));
+        }
+        match cell.value_type {
+            ValueType::Null => serde_json::Value::Null,
+            ValueType::Boolean => serde_json::Value::Bool(cell.value.eq_ignore_ascii_case("true")),
+            ValueType::Integer => {
+                if let Ok(n) = cell.value.parse::<i64>() {
+                    serde_json::Value::Number(n.into())
+                } else {
+                    serde_json::Value::String(cell.value.clone())
+                }
+            }
+            ValueType::Float => {
+                if let Ok(n) = cell.value.parse::<f64>() {
+                    serde_json::Number::from_f64(n).map_or_else(
+                        || serde_json::Value::String(cell.value.clone()),
+                        serde_json::Value::Number,
+                    )
+                } else {
+                    serde_json::Value::String(cell.value.clone())
+                }
+            }
+            _ => serde_json::Value::String(cell.value.clone()),
+        }
+    }
+
     /// Get the number of rows.
     #[must_use]
     pub fn row_count(&self) -> usize {
@@ -390,7 +932,7 @@ impl QueryResultTable {
 
     /// Calculate column widths based on content.
     fn calculate_column_widths(&self) -> Vec<usize> {
-        let mut widths: Vec<usize> = self.columns.iter().map(|c| c.chars().count()).collect();
+        let mut widths: Vec<usize> = self.columns.iter().map(|c| visible_width(c)).collect();
 
         // Consider row number column if enabled
         if self.show_row_numbers {
@@ -403,7 +945,7 @@ impl QueryResultTable {
             for (i, cell) in row.iter().enumerate() {
                 let col_idx = if self.show_row_numbers { i + 1 } else { i };
                 if col_idx < widths.len() {
-                    widths[col_idx] = widths[col_idx].max(cell.value.chars().count());
+                    widths[col_idx] = widths[col_idx].max(visible_width(&self.cell_text(i, cell)));
                 }
             }
         }
@@ -424,14 +966,14 @@ impl QueryResultTable {
 
     /// Truncate a value to fit within width, adding "..." if needed.
     fn truncate_value(value: &str, width: usize) -> String {
-        if value.chars().count() <= width {
-            value.to_string()
-        } else if width <= 3 {
-            value.chars().take(width).collect()
-        } else {
-            let truncated: String = value.chars().take(width - 3).collect();
-            format!("{truncated}...")
-        }
+        truncate_visible(value, width)
+    }
+
+    /// The `{:width$}` fill width that pads `value` to `width` display columns.
+    ///
+    /// `format!` pads by character count, which misaligns wide characters.
+    fn fill_width(value: &str, width: usize) -> usize {
+        (width + value.chars().count()).saturating_sub(visible_width(value))
     }
 
     /// Render as plain text using the configured format.
@@ -451,6 +993,40 @@ impl QueryResultTable {
         }
     }
 
+    /// Render each row as a record of `column | value` lines, like psql's
+    /// expanded (`\x`) mode.
+    ///
+    /// Records are headed by `-[ RECORD n ]-` and column names are padded to
+    /// the longest name. Honors `max_rows`.
+    #[must_use]
+    pub fn render_expanded(&self) -> String {
+        let name_width = self
+            .columns
+            .iter()
+            .map(|c| visible_width(c))
+            .max()
+            .unwrap_or(0);
+        let display_rows = self.max_rows.unwrap_or(self.rows.len());
+
+        let mut lines = Vec::new();
+        for (idx, row) in self.rows.iter().take(display_rows).enumerate() {
+            lines.push(format!("-[ RECORD {} ]-", idx + 1));
+            for (i, (col, cell)) in self.columns.iter().zip(row.iter()).enumerate() {
+                let fill = Self::fill_width(col, name_width);
+                lines.push(format!("{col:<fill$} | {}", self.cell_text(i, cell)));
+            }
+        }
+
+        if self.rows.len() > display_rows {
+            lines.push(format!(
+                "... and {} more rows",
+                self.rows.len() - display_rows
+            ));
+        }
+
+        lines.join("\n")
+    }
+
     /// Render as pipe-delimited format.
     fn render_pipe(&self) -> String {
         let mut lines = Vec::new();
@@ -473,7 +1049,11 @@ impl QueryResultTable {
 
         // Data rows
         for (idx, row) in self.rows.iter().take(display_rows).enumerate() {
-            let values: Vec<&str> = row.iter().map(|c| c.value.as_str()).collect();
+            let values: Vec<Cow<'_, str>> = row
+                .iter()
+                .enumerate()
+                .map(|(i, c)| self.cell_text(i, c))
+                .collect();
             let mut line = values.join("|");
             if self.show_row_numbers {
                 line = format!("{}|{line}", idx + 1);
@@ -505,16 +1085,24 @@ impl QueryResultTable {
 
         // Data rows
         for row in self.rows.iter().take(display_rows) {
-            let values: Vec<String> = row.iter().map(|c| Self::csv_escape(&c.value)).collect();
+            let values: Vec<String> = row
+                .iter()
+                .enumerate()
+                .map(|(i, c)| Self::csv_escape(&self.cell_text(i, c)))
+                .collect();
             lines.push(values.join(","));
         }
 
-        lines.join("\n")
+        lines.join(if self.csv_crlf { "\r\n" } else { "\n" })
     }
 
     /// Escape a value for CSV output.
+    ///
+    /// Values containing a comma, a quote, or any control character (including
+    /// a lone `\r`, which strict RFC 4180 parsers treat as a line break) are
+    /// quoted. Embedded line breaks are kept verbatim inside the quotes.
     fn csv_escape(value: &str) -> String {
-        if value.contains(',') || value.contains('"') || value.contains('\n') {
+        if value.contains(',') || value.contains('"') || value.chars().any(char::is_control) {
             let escaped = value.replace('"', "\"\"");
             format!("\"{escaped}\"")
         } else {
@@ -534,33 +1122,8 @@ impl QueryResultTable {
                     .columns
                     .iter()
                     .zip(row.iter())
-                    .map(|(col, cell)| {
-                        let value = match cell.value_type {
-                            ValueType::Null => serde_json::Value::Null,
-                            ValueType::Boolean => {
-                                serde_json::Value::Bool(cell.value.eq_ignore_ascii_case("true"))
-                            }
-                            ValueType::Integer => {
-                                if let Ok(n) = cell.value.parse::<i64>() {
-                                    serde_json::Value::Number(n.into())
-                                } else {
-                                    serde_json::Value::String(cell.value.clone())
-                                }
-                            }
-                            ValueType::Float => {
-                                if let Ok(n) = cell.value.parse::<f64>() {
-                                    serde_json::Number::from_f64(n).map_or_else(
-                                        || serde_json::Value::String(cell.value.clone()),
-                                        serde_json::Value::Number,
-                                    )
-                                } else {
-                                    serde_json::Value::String(cell.value.clone())
-                                }
-                            }
-                            _ => serde_json::Value::String(cell.value.clone()),
-                        };
-                        (col.clone(), value)
-                    })
+                    .enumerate()
+                    .map(|(i, (col, cell))| (col.clone(), self.cell_json(i, cell)))
                     .collect();
                 serde_json::to_string(&obj).unwrap_or_else(|_| "{}".to_string())
             })
@@ -580,33 +1143,8 @@ impl QueryResultTable {
                 self.columns
                     .iter()
                     .zip(row.iter())
-                    .map(|(col, cell)| {
-                        let value = match cell.value_type {
-                            ValueType::Null => serde_json::Value::Null,
-                            ValueType::Boolean => {
-                                serde_json::Value::Bool(cell.value.eq_ignore_ascii_case("true"))
-                            }
-                            ValueType::Integer => {
-                                if let Ok(n) = cell.value.parse::<i64>() {
-                                    serde_json::Value::Number(n.into())
-                                } else {
-                                    serde_json::Value::String(cell.value.clone())
-                                }
-                            }
-                            ValueType::Float => {
-                                if let Ok(n) = cell.value.parse::<f64>() {
-                                    serde_json::Number::from_f64(n).map_or_else(
-                                        || serde_json::Value::String(cell.value.clone()),
-                                        serde_json::Value::Number,
-                                    )
-                                } else {
-                                    serde_json::Value::String(cell.value.clone())
-                                }
-                            }
-                            _ => serde_json::Value::String(cell.value.clone()),
-                        };
-                        (col.clone(), value)
-                    })
+                    .enumerate()
+                    .map(|(i, (col, cell))| (col.clone(), self.cell_json(i, cell)))
                     .collect()
             })
             .collect();
@@ -614,11 +1152,89 @@ impl QueryResultTable {
         serde_json::to_string_pretty(&array).unwrap_or_else(|_| "[]".to_string())
     }
 
+    /// Write the rows to `w` as a JSON array, one object per line.
+    ///
+    /// Produces the same values as [`PlainFormat::JsonArray`] but serializes
+    /// each row straight to the writer instead of building the whole document
+    /// in memory first, so large exports can go directly to a file or pipe.
+    /// Honors `max_rows`.
+    ///
+    /// # Errors
+    ///
+    /// Returns any I/O error from `w`.
+    pub fn write_json_array<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        let display_rows = self.max_rows.unwrap_or(self.rows.len());
+        let objects = self.rows.iter().take(display_rows).map(|row| {
+            self.columns
+                .iter()
+                .zip(row.iter())
+                .enumerate()
+                .map(|(i, (col, cell))| (col.clone(), self.cell_json(i, cell)))
+                .collect()
+        });
+        Self::write_json_objects(w, objects)
+    }
+
+    /// Write `rows` to `w` as a JSON array without collecting them into the table.
+    ///
+    /// Keys come from each row's column names and cells are typed from their
+    /// values as in [`from_rows`](Self::from_rows); this table's formatters,
+    /// JSON settings and `max_rows` still apply. Only the current row is held
+    /// in memory, so feeding rows from a cursor as they arrive exports result
+    /// sets of any size.
+    ///
+    /// # Errors
+    ///
+    /// Returns any I/O error from `w`.
+    pub fn write_json_array_rows<W, I>(&self, w: &mut W, rows: I) -> io::Result<()>
+    where
+        W: Write,
+        I: IntoIterator,
+        I::Item: Borrow<Row>,
+    {
+        let objects = rows
+            .into_iter()
+            .take(self.max_rows.unwrap_or(usize::MAX))
+            .map(|row| {
+                row.borrow()
+                    .iter()
+                    .enumerate()
+                    .map(|(i, (name, value))| {
+                        (
+                            name.to_string(),
+                            self.cell_json(i, &Cell::from_value(value)),
+                        )
+                    })
+                    .collect()
+            });
+        Self::write_json_objects(w, objects)
+    }
+
+    /// Write `[`, the comma-separated objects, and `]`, one object per line.
+    fn write_json_objects<W: Write>(
+        w: &mut W,
+        objects: impl Iterator<Item = serde_json::Map<String, serde_json::Value>>,
+    ) -> io::Result<()> {
+        w.write_all(b"[")?;
+        let mut empty = true;
+        for obj in objects {
+            w.write_all(if empty { b"\n  " } else { b",\n  " })?;
+            serde_json::to_writer(&mut *w, &obj)?;
+            empty = false;
+        }
+        w.write_all(if empty { b"]" } else { b"\n]" })
+    }
+
     /// Render as styled text with ANSI colors and box drawing.
+    ///
+    /// The border glyphs follow [`border_style`](Self::border_style).
     #[must_use]
     pub fn render_styled(&self) -> String {
         let theme = self.theme.clone().unwrap_or_default();
         let widths = self.calculate_column_widths();
+        let g = self.border_style.glyphs();
+        let borderless = self.border_style == BorderStyle::None;
+        let horizontal = |n: usize| g.horizontal.to_string().repeat(n);
 
         let border_color = theme.border.color_code();
         let header_color = theme.header.color_code();
@@ -636,7 +1252,7 @@ impl QueryResultTable {
                 format!(" • {} rows in {:.2}ms", self.rows.len(), ms)
             });
             let full_title = format!(" {title}{timing_str} ");
-            let title_len = full_title.chars().count();
+            let title_len = visible_width(&full_title);
             let left_pad = (total_width.saturating_sub(2).saturating_sub(title_len)) / 2;
             let right_pad = total_width
                 .saturating_sub(2)
@@ -644,14 +1260,16 @@ impl QueryResultTable {
                 .saturating_sub(left_pad);
 
             lines.push(format!(
-                "{border_color}╭{}{}{}╮{reset}",
-                "─".repeat(left_pad),
+                "{border_color}{}{}{}{}{}{reset}",
+                g.top_left,
+                horizontal(left_pad),
                 full_title,
-                "─".repeat(right_pad)
+                horizontal(right_pad),
+                g.top_right
             ));
         } else if let Some(ms) = self.timing_ms {
             let timing_str = format!(" {} rows in {:.2}ms ", self.rows.len(), ms);
-            let timing_len = timing_str.chars().count();
+            let timing_len = visible_width(&timing_str);
             let left_pad = (total_width.saturating_sub(2).saturating_sub(timing_len)) / 2;
             let right_pad = total_width
                 .saturating_sub(2)
@@ -659,15 +1277,19 @@ impl QueryResultTable {
                 .saturating_sub(left_pad);
 
             lines.push(format!(
-                "{border_color}╭{}{}{}╮{reset}",
-                "─".repeat(left_pad),
+                "{border_color}{}{}{}{}{}{reset}",
+                g.top_left,
+                horizontal(left_pad),
                 timing_str,
-                "─".repeat(right_pad)
+                horizontal(right_pad),
+                g.top_right
             ));
-        } else {
+        } else if !borderless {
             lines.push(format!(
-                "{border_color}╭{}╮{reset}",
-                "─".repeat(total_width - 2)
+                "{border_color}{}{}{}{reset}",
+                g.top_left,
+                horizontal(total_width - 2),
+                g.top_right
             ));
         }
 
@@ -683,19 +1305,28 @@ impl QueryResultTable {
             header_cells.push(format!(
                 "{header_color}{:width$}{reset}",
                 truncated,
-                width = width
+                width = Self::fill_width(&truncated, width)
             ));
         }
+        let v = g.vertical;
         lines.push(format!(
-            "{border_color}│{reset} {} {border_color}│{reset}",
-            header_cells.join(&format!(" {border_color}│{reset} "))
+            "{border_color}{v}{reset} {} {border_color}{v}{reset}",
+            header_cells.join(&format!(" {border_color}{v}{reset} "))
         ));
 
         // Header separator
-        let separators: Vec<String> = widths.iter().map(|w| "─".repeat(*w)).collect();
+        let rule = g.rule.to_string();
+        let separators: Vec<String> = widths.iter().map(|w| rule.repeat(*w)).collect();
+        let (edge_rule, cross) = if borderless {
+            (" ".to_string(), "   ".to_string())
+        } else {
+            (rule.clone(), format!("{rule}{}{rule}", g.cross))
+        };
         lines.push(format!(
-            "{border_color}├─{}─┤{reset}",
-            separators.join("─┼─")
+            "{border_color}{}{edge_rule}{}{edge_rule}{}{reset}",
+            g.left_tee,
+            separators.join(&cross),
+            g.right_tee
         ));
 
         // Determine display rows
@@ -718,8 +1349,9 @@ impl QueryResultTable {
             for (i, cell) in row.iter().enumerate() {
                 let col_idx = if self.show_row_numbers { i + 1 } else { i };
                 let width = widths.get(col_idx).copied().unwrap_or(10);
-                let truncated_val = Self::truncate_value(&cell.value, width);
+                let truncated_val = Self::truncate_value(&self.cell_text(i, cell), width);
                 let color = cell.value_type.color_code(&theme);
+                let width = Self::fill_width(&truncated_val, width);
 
                 // Right-align numbers, left-align everything else
                 let formatted = match cell.value_type {
@@ -741,8 +1373,8 @@ impl QueryResultTable {
             }
 
             lines.push(format!(
-                "{border_color}│{reset} {} {border_color}│{reset}",
-                cells.join(&format!(" {border_color}│{reset} "))
+                "{border_color}{v}{reset} {} {border_color}{v}{reset}",
+                cells.join(&format!(" {border_color}{v}{reset} "))
             ));
         }
 
@@ -753,17 +1385,21 @@ impl QueryResultTable {
                 .saturating_sub(4)
                 .saturating_sub(more_text.len());
             lines.push(format!(
-                "{border_color}│{reset} {dim}{more_text}{:padding$}{reset} {border_color}│{reset}",
+                "{border_color}{v}{reset} {dim}{more_text}{:padding$}{reset} {border_color}{v}{reset}",
                 "",
                 padding = padding
             ));
         }
 
         // Bottom border
-        lines.push(format!(
-            "{border_color}╰{}╯{reset}",
-            "─".repeat(total_width - 2)
-        ));
+        if !borderless {
+            lines.push(format!(
+                "{border_color}{}{}{}{reset}",
+                g.bottom_left,
+                horizontal(total_width - 2),
+                g.bottom_right
+            ));
+        }
 
         lines.join("\n")
     }
@@ -779,33 +1415,8 @@ impl QueryResultTable {
                     .columns
                     .iter()
                     .zip(row.iter())
-                    .map(|(col, cell)| {
-                        let value = match cell.value_type {
-                            ValueType::Null => serde_json::Value::Null,
-                            ValueType::Boolean => {
-                                serde_json::Value::Bool(cell.value.eq_ignore_ascii_case("true"))
-                            }
-                            ValueType::Integer => {
-                                if let Ok(n) = cell.value.parse::<i64>() {
-                                    serde_json::Value::Number(n.into())
-                                } else {
-                                    serde_json::Value::String(cell.value.clone())
-                                }
-                            }
-                            ValueType::Float => {
-                                if let Ok(n) = cell.value.parse::<f64>() {
-                                    serde_json::Number::from_f64(n).map_or_else(
-                                        || serde_json::Value::String(cell.value.clone()),
-                                        serde_json::Value::Number,
-                                    )
-                                } else {
-                                    serde_json::Value::String(cell.value.clone())
-                                }
-                            }
-                            _ => serde_json::Value::String(cell.value.clone()),
-                        };
-                        (col.clone(), value)
-                    })
+                    .enumerate()
+                    .map(|(i, (col, cell))| (col.clone(), self.cell_json(i, cell)))
                     .collect();
                 serde_json::Value::Object(obj)
             })
@@ -937,6 +1548,27 @@ mod tests {
         assert!(output.contains("2|Bob"));
     }
 
+    #[test]
+    fn test_render_expanded() {
+        let table = QueryResultTable::new()
+            .columns(vec!["id", "full_name"])
+            .row(vec!["1", "Alice"])
+            .row(vec!["2", "Bob"])
+            .row(vec!["3", "Carol"])
+            .max_rows(2);
+
+        assert_eq!(
+            table.render_expanded(),
+            "-[ RECORD 1 ]-\n\
+             id        | 1\n\
+             full_name | Alice\n\
+             -[ RECORD 2 ]-\n\
+             id        | 2\n\
+             full_name | Bob\n\
+             ... and 1 more rows"
+        );
+    }
+
     #[test]
     fn test_render_pipe_with_timing() {
         let table = QueryResultTable::new()
@@ -983,6 +1615,35 @@ mod tests {
         assert!(output.contains("\"hello, world\""));
     }
 
+    #[test]
+    fn test_render_csv_quotes_line_breaks_and_controls() {
+        let table = QueryResultTable::new().columns(vec!["text"]).rows(vec![
+            vec!["carriage\rreturn"],
+            vec!["windows\r\nline"],
+            vec!["unix\nline"],
+            vec!["tab\there"],
+            vec!["plain"],
+        ]);
+
+        let output = table.render_plain_format(PlainFormat::Csv);
+        assert_eq!(
+            output,
+            "text\n\"carriage\rreturn\"\n\"windows\r\nline\"\n\"unix\nline\"\n\"tab\there\"\nplain"
+        );
+    }
+
+    #[test]
+    fn test_render_csv_crlf_line_endings() {
+        let table = QueryResultTable::new()
+            .columns(vec!["id", "note"])
+            .row(vec!["1", "a\nb"])
+            .row(vec!["2", "c"])
+            .csv_crlf(true);
+
+        let output = table.render_plain_format(PlainFormat::Csv);
+        assert_eq!(output, "id,note\r\n1,\"a\nb\"\r\n2,c");
+    }
+
     #[test]
     fn test_render_json_lines() {
         let table = QueryResultTable::new()
@@ -1006,6 +1667,55 @@ mod tests {
         assert!(output.ends_with(']'));
     }
 
+    #[test]
+    fn test_write_json_array_matches_rendered_array() {
+        let table = QueryResultTable::new()
+            .columns(vec!["id", "name"])
+            .row(vec!["1", "Alice"])
+            .row(vec!["2", "Bob"])
+            .row(vec!["3", "Carol"])
+            .max_rows(2);
+
+        let mut out = Vec::new();
+        table.write_json_array(&mut out).unwrap();
+        let written: serde_json::Value = serde_json::from_slice(&out).unwrap();
+        let rendered: serde_json::Value =
+            serde_json::from_str(&table.render_plain_format(PlainFormat::JsonArray)).unwrap();
+        assert_eq!(written, rendered);
+        assert_eq!(written.as_array().unwrap().len(), 2);
+
+        let mut out = Vec::new();
+        QueryResultTable::new().write_json_array(&mut out).unwrap();
+        assert_eq!(out, b"[]");
+    }
+
+    #[test]
+    fn test_write_json_array_rows_streams_values() {
+        let columns = Arc::new(ColumnInfo::new(vec!["id".to_string(), "name".to_string()]));
+        let rows = (1..=3).map(|id| {
+            Row::with_columns(
+                Arc::clone(&columns),
+                vec![Value::BigInt(id), Value::Text(format!("user{id}"))],
+            )
+        });
+
+        let mut out = Vec::new();
+        QueryResultTable::new()
+            .write_json_array_rows(&mut out, rows)
+            .unwrap();
+        let text = String::from_utf8(out).unwrap();
+        assert_eq!(text.lines().count(), 5);
+        let written: serde_json::Value = serde_json::from_str(&text).unwrap();
+        assert_eq!(
+            written,
+            serde_json::json!([
+                {"id": 1, "name": "user1"},
+                {"id": 2, "name": "user2"},
+                {"id": 3, "name": "user3"},
+            ])
+        );
+    }
+
     #[test]
     fn test_max_rows_truncation() {
         let table = QueryResultTable::new()
@@ -1060,6 +1770,111 @@ mod tests {
         assert_eq!(QueryResultTable::truncate_value("hello", 5), "hello");
     }
 
+    #[test]
+    fn test_truncate_value_wide_chars() {
+        assert_eq!(
+            QueryResultTable::truncate_value("日本語テキスト", 8),
+            "日本..."
+        );
+        assert_eq!(QueryResultTable::fill_width("日本", 6), 4);
+    }
+
+    #[test]
+    fn test_from_rows_types_cells_by_value() {
+        let columns = Arc::new(ColumnInfo::new(vec![
+            "code".to_string(),
+            "born".to_string(),
+            "seen".to_string(),
+            "id".to_string(),
+            "meta".to_string(),
+            "avatar".to_string(),
+            "nick".to_string(),
+        ]));
+        let row = Row::with_columns(
+            Arc::clone(&columns),
+            vec![
+                Value::Text("123".to_string()),
+                Value::Date(19_723),
+                Value::Timestamp(1_704_067_200_000_000 + 3_723_500_000),
+                Value::Uuid([
+                    0x55, 0x0e, 0x84, 0x00, 0xe2, 0x9b, 0x41, 0xd4, 0xa7, 0x16, 0x44, 0x66, 0x55,
+                    0x44, 0x00, 0x00,
+                ]),
+                Value::Json(serde_json::json!({"a": 1})),
+                Value::Bytes(vec![1, 2, 3]),
+                Value::Null,
+            ],
+        );
+
+        let table = QueryResultTable::from_rows(&columns, &[row]);
+        assert_eq!(table.columns, columns.names());
+        let cells: Vec<(&str, ValueType)> = table.rows[0]
+            .iter()
+            .map(|c| (c.value.as_str(), c.value_type))
+            .collect();
+        assert_eq!(
+            cells,
+            vec![
+                ("123", ValueType::String),
+                ("2024-01-01", ValueType::Date),
+                ("2024-01-01 01:02:03.500000", ValueType::Timestamp),
+                ("550e8400-e29b-41d4-a716-446655440000", ValueType::Uuid),
+                ("{\"a\":1}", ValueType::Json),
+                ("[BLOB: 3 bytes]", ValueType::Binary),
+                ("NULL", ValueType::Null),
+            ]
+        );
+        assert_eq!(table.rows[0][5].bytes.as_deref(), Some(&[1u8, 2, 3][..]));
+        assert_eq!(format_date(-1), "1969-12-31");
+    }
+
+    #[test]
+    fn test_display_hints() {
+        use sqlmodel_core::SqlType;
+
+        let columns = Arc::new(ColumnInfo::new(vec![
+            "digest".to_string(),
+            "price".to_string(),
+            "rate".to_string(),
+            "payload".to_string(),
+            "note".to_string(),
+        ]));
+        let row = Row::with_columns(
+            Arc::clone(&columns),
+            vec![
+                Value::Bytes(vec![0xAB; 32]),
+                Value::Decimal("1234567.5".to_string()),
+                Value::Double(0.125),
+                Value::Bytes(b"hi".to_vec()),
+                Value::Text("1234.5".to_string()),
+            ],
+        );
+        let fields = [
+            FieldInfo::new("digest", "digest", SqlType::Blob).display(DisplayHint::Hash),
+            FieldInfo::new("price", "price", SqlType::Text).display(DisplayHint::Currency(2)),
+            FieldInfo::new("rate", "rate", SqlType::Double).display(DisplayHint::Percent),
+            FieldInfo::new("payload", "payload", SqlType::Blob)
+                .display(DisplayHint::Bytes(BytesDisplay::Base64)),
+            FieldInfo::new("note", "note", SqlType::Text),
+        ];
+        let table = QueryResultTable::from_rows(&columns, &[row]).field_hints(&fields);
+        let texts: Vec<String> = table.rows[0]
+            .iter()
+            .enumerate()
+            .map(|(col, cell)| table.cell_text(col, cell).into_owned())
+            .collect();
+        assert_eq!(
+            texts,
+            vec!["abababababab...", "1,234,567.50", "12.5%", "aGk=", "1234.5"]
+        );
+
+        assert_eq!(format_currency("-999.995", 2), "-1,000.00");
+        assert_eq!(format_currency("n/a", 2), "n/a");
+        assert_eq!(format_currency("7", 2), "7.00");
+        assert_eq!(format_percent("1"), "100%");
+        assert_eq!(format_hash("0xDEADBEEF"), "deadbeef");
+    }
+
     #[test]
     fn test_to_json() {
         let table = QueryResultTable::new()
@@ -1144,4 +1959,161 @@ mod tests {
         let rows = json["rows"].as_array().unwrap();
         assert_eq!(rows[0]["count"], 42);
     }
+
+    #[test]
+    fn test_binary_display_modes() {
+        let bytes = b"Hello";
+        assert_eq!(BinaryDisplay::Marker.format(bytes), "[BLOB: 5 bytes]");
+        assert_eq!(BinaryDisplay::Hex.format(bytes), "\\x48656c6c6f");
+        assert_eq!(BinaryDisplay::Base64.format(bytes), "SGVsbG8=");
+        assert_eq!(
+            BinaryDisplay::HexTruncated(2).format(bytes),
+            "\\x4865... (5 bytes)"
+        );
+        assert_eq!(
+            BinaryDisplay::HexTruncated(8).format(bytes),
+            "\\x48656c6c6f"
+        );
+    }
+
+    #[test]
+    fn test_binary_cells_in_table_output() {
+        let table = QueryResultTable::new()
+            .columns(vec!["id", "data"])
+            .row_cells(vec![Cell::new("1"), Cell::binary(b"Hello".to_vec())]);
+
+        // Marker by default.
+        assert!(table.render_plain().contains("1|[BLOB: 5 bytes]"));
+        assert_eq!(table.rows[0][1].value_type, ValueType::Binary);
+
+        let hex = table.clone().binary_display(BinaryDisplay::Hex);
+        assert!(hex.render_plain().contains("1|\\x48656c6c6f"));
+        assert!(hex.render_styled().contains("\\x48656c6c6f"));
+
+        // JSON always carries the bytes as base64.
+        assert_eq!(table.to_json()["rows"][0]["data"], "SGVsbG8=");
+        assert!(
+            table
+                .render_plain_format(PlainFormat::JsonLines)
+                .contains("\"data\":\"SGVsbG8=\"")
+        );
+    }
+
+    #[test]
+    fn test_format_column() {
+        let table = QueryResultTable::new()
+            .columns(vec!["item", "price_cents"])
+            .row(vec!["coffee", "1234"])
+            .row_cells(vec![Cell::new("refund"), Cell::null()])
+            .row(vec!["espresso machine", "1234567890"])
+            .format_column(1, |v| {
+                let cents: i64 = v.parse().unwrap_or(0);
+                format!("${}.{:02}", cents / 100, cents % 100)
+            });
+
+        assert!(table.render_plain().contains("coffee|$12.34"));
+        assert!(table.render_plain().contains("refund|NULL"));
+        assert!(table.render_styled().contains("$12.34"));
+        // The formatted value drives the column width.
+        assert_eq!(table.calculate_column_widths()[1], "$12345678.90".len());
+
+        // JSON keeps typed values unless opted in.
+        assert_eq!(table.to_json()["rows"][0]["price_cents"], 1234);
+        let json = table.format_json(true).to_json();
+        assert_eq!(json["rows"][0]["price_cents"], "$12.34");
+        assert!(json["rows"][1]["price_cents"].is_null());
+    }
+
+    /// Styled output of a fixed two-column table with ANSI codes removed.
+    fn styled_snapshot(style: BorderStyle) -> String {
+        let styled = QueryResultTable::new()
+            .columns(vec!["id", "name"])
+            .row(vec!["1", "Alice"])
+            .border_style(style)
+            .render_styled();
+        let mut plain = String::new();
+        let mut chars = styled.chars();
+        while let Some(c) = chars.next() {
+            if c == '\x1b' {
+                for c in chars.by_ref() {
+                    if c.is_ascii_alphabetic() {
+                        break;
+                    }
+                }
+            } else {
+                plain.push(c);
+            }
+        }
+        plain
+    }
+
+    #[test]
+    fn test_border_style_rounded_is_default() {
+        assert_eq!(
+            styled_snapshot(BorderStyle::Rounded),
+            "╭────────────╮\n\
+             │ id │ name  │\n\
+             ├────┼───────┤\n\
+             │  1 │ Alice │\n\
+             ╰────────────╯"
+        );
+        let default = QueryResultTable::new()
+            .columns(vec!["id", "name"])
+            .row(vec!["1", "Alice"])
+            .render_styled();
+        assert_eq!(
+            default,
+            QueryResultTable::new()
+                .columns(vec!["id", "name"])
+                .row(vec!["1", "Alice"])
+                .border_style(BorderStyle::Rounded)
+                .render_styled()
+        );
+    }
+
+    #[test]
+    fn test_border_style_square() {
+        assert_eq!(
+            styled_snapshot(BorderStyle::Square),
+            "┌────────────┐\n\
+             │ id │ name  │\n\
+             ├────┼───────┤\n\
+             │  1 │ Alice │\n\
+             └────────────┘"
+        );
+    }
+
+    #[test]
+    fn test_border_style_double() {
+        assert_eq!(
+            styled_snapshot(BorderStyle::Double),
+            "╔════════════╗\n\
+             ║ id ║ name  ║\n\
+             ╠════╬═══════╣\n\
+             ║  1 ║ Alice ║\n\
+             ╚════════════╝"
+        );
+    }
+
+    #[test]
+    fn test_border_style_ascii() {
+        assert_eq!(
+            styled_snapshot(BorderStyle::Ascii),
+            "+------------+\n\
+             | id | name  |\n\
+             +----+-------+\n\
+             |  1 | Alice |\n\
+             +------------+"
+        );
+    }
+
+    #[test]
+    fn test_border_style_none() {
+        assert_eq!(
+            styled_snapshot(BorderStyle::None),
+            "  id   name   \n\
+             \x20 --   -----  \n\
+             \x20  1   Alice  "
+        );
+    }
 }
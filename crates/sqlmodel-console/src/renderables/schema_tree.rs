@@ -35,7 +35,12 @@
 //! let tree = SchemaTree::new(&[table]);
 //! println!("{}", tree.render_plain());
 //! ```
+//!
+//! [`SchemaDiffTree`] renders the difference between two such schemas, e.g.
+//! before and after a migration, marking added (`+`), dropped (`-`) and
+//! altered (`~`) tables, columns, indexes and foreign keys.
 
+use crate::renderables::ddl_display::ChangeKind;
 use crate::theme::Theme;
 
 /// Configuration for schema tree rendering.
@@ -123,6 +128,15 @@ impl SchemaTreeConfig {
         self.use_unicode = true;
         self
     }
+
+    /// Tree drawing characters: branch, last branch, vertical, space.
+    fn chars(&self) -> (&'static str, &'static str, &'static str, &'static str) {
+        if self.use_unicode {
+            ("├── ", "└── ", "│   ", "    ")
+        } else {
+            ("+-- ", "\\-- ", "|   ", "    ")
+        }
+    }
 }
 
 /// Simplified table info for rendering (avoids dependency on sqlmodel-schema).
@@ -141,7 +155,7 @@ pub struct TableData {
 }
 
 /// Simplified column info for rendering.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ColumnData {
     /// Column name
     pub name: String,
@@ -158,7 +172,7 @@ pub struct ColumnData {
 }
 
 /// Simplified foreign key info for rendering.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ForeignKeyData {
     /// Constraint name
     pub name: Option<String>,
@@ -175,7 +189,7 @@ pub struct ForeignKeyData {
 }
 
 /// Simplified index info for rendering.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct IndexData {
     /// Index name
     pub name: String,
@@ -252,11 +266,7 @@ impl SchemaTree {
 
     /// Get tree drawing characters.
     fn chars(&self) -> (&'static str, &'static str, &'static str, &'static str) {
-        if self.config.use_unicode {
-            ("├── ", "└── ", "│   ", "    ")
-        } else {
-            ("+-- ", "\\-- ", "|   ", "    ")
-        }
+        self.config.chars()
     }
 
     /// Render the schema as plain text.
@@ -773,6 +783,409 @@ impl Default for SchemaTree {
     }
 }
 
+/// Annotated tree of the differences between two schemas.
+///
+/// Tables are matched by name, columns by name, indexes by name and foreign
+/// keys by local column. Added nodes are marked `+` (green when styled),
+/// dropped ones `-` (red) and altered ones `~` (yellow), with the previous
+/// definition shown as `(was ...)`. Unchanged nodes are left out unless
+/// [`show_unchanged`](Self::show_unchanged) is set. Styling and
+/// type/constraint/index/foreign key visibility come from
+/// [`SchemaTreeConfig`].
+#[derive(Debug, Clone)]
+pub struct SchemaDiffTree {
+    /// Schema before the change
+    before: Vec<TableData>,
+    /// Schema after the change
+    after: Vec<TableData>,
+    /// Also list tables and items that did not change
+    show_unchanged: bool,
+    /// Configuration
+    config: SchemaTreeConfig,
+}
+
+/// One node of a [`SchemaDiffTree`]: a table, a section header or an item.
+#[derive(Debug, Clone)]
+struct DiffNode {
+    /// `None` for unchanged nodes and section headers.
+    change: Option<ChangeKind>,
+    section: bool,
+    label: String,
+    children: Vec<DiffNode>,
+}
+
+impl DiffNode {
+    fn item(change: Option<ChangeKind>, label: String) -> Self {
+        Self {
+            change,
+            section: false,
+            label,
+            children: Vec::new(),
+        }
+    }
+
+    fn section(label: &str, children: Vec<DiffNode>) -> Self {
+        Self {
+            change: None,
+            section: true,
+            label: label.to_string(),
+            children,
+        }
+    }
+
+    fn marker(&self) -> &'static str {
+        match (self.change, self.section) {
+            (Some(ChangeKind::Added), _) => "+ ",
+            (Some(ChangeKind::Removed), _) => "- ",
+            (Some(ChangeKind::Modified), _) => "~ ",
+            (None, true) => "",
+            (None, false) => "  ",
+        }
+    }
+}
+
+impl SchemaDiffTree {
+    /// Create a diff tree from the schema before and after a change.
+    #[must_use]
+    pub fn new(before: &[TableData], after: &[TableData]) -> Self {
+        Self {
+            before: before.to_vec(),
+            after: after.to_vec(),
+            show_unchanged: false,
+            config: SchemaTreeConfig::default(),
+        }
+    }
+
+    /// Set the configuration.
+    #[must_use]
+    pub fn config(mut self, config: SchemaTreeConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Set the theme for styled output.
+    #[must_use]
+    pub fn theme(mut self, theme: Theme) -> Self {
+        self.config.theme = Some(theme);
+        self
+    }
+
+    /// Use ASCII characters instead of Unicode.
+    #[must_use]
+    pub fn ascii(mut self) -> Self {
+        self.config.use_unicode = false;
+        self
+    }
+
+    /// Use Unicode box drawing characters.
+    #[must_use]
+    pub fn unicode(mut self) -> Self {
+        self.config.use_unicode = true;
+        self
+    }
+
+    /// Set whether unchanged tables, columns, indexes and foreign keys are
+    /// listed alongside the changes.
+    #[must_use]
+    pub fn show_unchanged(mut self, show: bool) -> Self {
+        self.show_unchanged = show;
+        self
+    }
+
+    /// Whether the two schemas differ at all.
+    #[must_use]
+    pub fn has_changes(&self) -> bool {
+        self.nodes().iter().any(|node| node.change.is_some())
+    }
+
+    /// Render the diff as plain text, for non-TTY output.
+    #[must_use]
+    pub fn render_plain(&self) -> String {
+        let nodes = self.nodes();
+        if nodes.is_empty() {
+            return "Schema changes: (none)".to_string();
+        }
+
+        let mut lines = vec!["Schema changes".to_string()];
+        self.render_nodes_plain(&nodes, "", &mut lines);
+        lines.join("\n")
+    }
+
+    fn render_nodes_plain(&self, nodes: &[DiffNode], prefix: &str, lines: &mut Vec<String>) {
+        let (branch, last_branch, vertical, space) = self.config.chars();
+        for (i, node) in nodes.iter().enumerate() {
+            let is_last = i == nodes.len() - 1;
+            let connector = if is_last { last_branch } else { branch };
+            lines.push(format!(
+                "{prefix}{connector}{}{}",
+                node.marker(),
+                node.label
+            ));
+            let child_prefix = format!("{prefix}{}", if is_last { space } else { vertical });
+            self.render_nodes_plain(&node.children, &child_prefix, lines);
+        }
+    }
+
+    /// Render the diff as styled text with ANSI colors.
+    #[must_use]
+    pub fn render_styled(&self) -> String {
+        let theme = self.config.theme.clone().unwrap_or_default();
+        let reset = "\x1b[0m";
+        let nodes = self.nodes();
+        if nodes.is_empty() {
+            let dim = theme.dim.color_code();
+            return format!("{dim}Schema changes: (none){reset}");
+        }
+
+        let keyword_color = theme.sql_keyword.color_code();
+        let mut lines = vec![format!("{keyword_color}Schema changes{reset}")];
+        self.render_nodes_styled(&nodes, "", &mut lines, &theme);
+        lines.join("\n")
+    }
+
+    fn render_nodes_styled(
+        &self,
+        nodes: &[DiffNode],
+        prefix: &str,
+        lines: &mut Vec<String>,
+        theme: &Theme,
+    ) {
+        let (branch, last_branch, vertical, space) = self.config.chars();
+        let reset = "\x1b[0m";
+        let dim = theme.dim.color_code();
+        for (i, node) in nodes.iter().enumerate() {
+            let is_last = i == nodes.len() - 1;
+            let connector = if is_last { last_branch } else { branch };
+            let color = match (node.change, node.section) {
+                (Some(ChangeKind::Added), _) => theme.success.color_code(),
+                (Some(ChangeKind::Removed), _) => theme.error.color_code(),
+                (Some(ChangeKind::Modified), _) => theme.warning.color_code(),
+                (None, true) => theme.sql_keyword.color_code(),
+                (None, false) => dim.clone(),
+            };
+            lines.push(format!(
+                "{dim}{prefix}{connector}{reset}{color}{}{}{reset}",
+                node.marker(),
+                node.label
+            ));
+            let child_prefix = format!("{prefix}{}", if is_last { space } else { vertical });
+            self.render_nodes_styled(&node.children, &child_prefix, lines, theme);
+        }
+    }
+
+    /// Build the tree: tables of the new schema in order, then dropped ones.
+    fn nodes(&self) -> Vec<DiffNode> {
+        let mut nodes = Vec::new();
+        for table in &self.after {
+            match self.before.iter().find(|old| old.name == table.name) {
+                None => nodes.push(self.whole_table(ChangeKind::Added, table)),
+                Some(old) => nodes.extend(self.changed_table(old, table)),
+            }
+        }
+        for old in &self.before {
+            if !self.after.iter().any(|table| table.name == old.name) {
+                nodes.push(self.whole_table(ChangeKind::Removed, old));
+            }
+        }
+        nodes
+    }
+
+    /// A created or dropped table, with every item carrying the same marker.
+    fn whole_table(&self, change: ChangeKind, table: &TableData) -> DiffNode {
+        let columns = table
+            .columns
+            .iter()
+            .map(|col| DiffNode::item(Some(change), self.column_label(col)))
+            .collect();
+        let indexes = table
+            .indexes
+            .iter()
+            .map(|idx| DiffNode::item(Some(change), index_label(idx)))
+            .collect();
+        let fks = table
+            .foreign_keys
+            .iter()
+            .map(|fk| DiffNode::item(Some(change), fk_label(fk)))
+            .collect();
+        DiffNode {
+            change: Some(change),
+            section: false,
+            label: self.table_label(table),
+            children: self.sections(columns, indexes, fks),
+        }
+    }
+
+    /// A table present on both sides; `None` if unchanged and hidden.
+    fn changed_table(&self, old: &TableData, new: &TableData) -> Option<DiffNode> {
+        let columns = diff_items(
+            &old.columns,
+            &new.columns,
+            |a, b| a.name == b.name,
+            |col| self.column_label(col),
+            |old, new| {
+                let (was, now) = (self.column_detail(old), self.column_detail(new));
+                if was == now {
+                    self.column_label(new)
+                } else {
+                    format!("{} (was {})", self.column_label(new), was.trim())
+                }
+            },
+            self.show_unchanged,
+        );
+        let indexes = diff_items(
+            &old.indexes,
+            &new.indexes,
+            |a, b| a.name == b.name,
+            index_label,
+            |old, new| format!("{} (was {})", index_label(new), index_label(old)),
+            self.show_unchanged,
+        );
+        let fks = diff_items(
+            &old.foreign_keys,
+            &new.foreign_keys,
+            |a, b| a.column == b.column,
+            fk_label,
+            |old, new| format!("{} (was {})", fk_label(new), fk_label(old)),
+            self.show_unchanged,
+        );
+
+        let pk_changed = old.primary_key != new.primary_key;
+        let changed = pk_changed
+            || [&columns, &indexes, &fks]
+                .iter()
+                .any(|items| items.iter().any(|node| node.change.is_some()));
+        if !changed && !self.show_unchanged {
+            return None;
+        }
+
+        let mut label = self.table_label(new);
+        if pk_changed && self.config.show_constraints {
+            label.push_str(&format!(" (was [PK: {}])", old.primary_key.join(", ")));
+        }
+        Some(DiffNode {
+            change: changed.then_some(ChangeKind::Modified),
+            section: false,
+            label,
+            children: self.sections(columns, indexes, fks),
+        })
+    }
+
+    /// Group items under the section headers the config asks for, skipping
+    /// empty sections.
+    fn sections(
+        &self,
+        columns: Vec<DiffNode>,
+        indexes: Vec<DiffNode>,
+        fks: Vec<DiffNode>,
+    ) -> Vec<DiffNode> {
+        let mut sections = Vec::new();
+        if !columns.is_empty() {
+            sections.push(DiffNode::section("Columns", columns));
+        }
+        if self.config.show_indexes && !indexes.is_empty() {
+            sections.push(DiffNode::section("Indexes", indexes));
+        }
+        if self.config.show_foreign_keys && !fks.is_empty() {
+            sections.push(DiffNode::section("Foreign Keys", fks));
+        }
+        sections
+    }
+
+    fn table_label(&self, table: &TableData) -> String {
+        if self.config.show_constraints && !table.primary_key.is_empty() {
+            format!(
+                "Table: {} [PK: {}]",
+                table.name,
+                table.primary_key.join(", ")
+            )
+        } else {
+            format!("Table: {}", table.name)
+        }
+    }
+
+    fn column_label(&self, col: &ColumnData) -> String {
+        format!("{}{}", col.name, self.column_detail(col))
+    }
+
+    /// Type and constraints of a column, each preceded by a space, as far
+    /// as the config shows them.
+    fn column_detail(&self, col: &ColumnData) -> String {
+        let mut detail = String::new();
+        if self.config.show_types {
+            detail.push(' ');
+            detail.push_str(&col.sql_type);
+        }
+        if self.config.show_constraints {
+            let mut constraints: Vec<String> = Vec::new();
+            if col.primary_key {
+                constraints.push("PK".into());
+            }
+            if col.auto_increment {
+                constraints.push("AUTO".into());
+            }
+            if !col.nullable {
+                constraints.push("NOT NULL".into());
+            }
+            if let Some(ref default) = col.default {
+                constraints.push(format!("DEFAULT {default}"));
+            }
+            if !constraints.is_empty() {
+                detail.push_str(&format!(" [{}]", constraints.join(", ")));
+            }
+        }
+        detail
+    }
+}
+
+/// Diff two lists of items: new-side order first, then removed items.
+fn diff_items<T: PartialEq>(
+    old: &[T],
+    new: &[T],
+    same_key: impl Fn(&T, &T) -> bool,
+    label: impl Fn(&T) -> String,
+    changed_label: impl Fn(&T, &T) -> String,
+    show_unchanged: bool,
+) -> Vec<DiffNode> {
+    let mut nodes = Vec::new();
+    for item in new {
+        match old.iter().find(|o| same_key(o, item)) {
+            None => nodes.push(DiffNode::item(Some(ChangeKind::Added), label(item))),
+            Some(o) if o != item => nodes.push(DiffNode::item(
+                Some(ChangeKind::Modified),
+                changed_label(o, item),
+            )),
+            Some(_) if show_unchanged => nodes.push(DiffNode::item(None, label(item))),
+            Some(_) => {}
+        }
+    }
+    for item in old {
+        if !new.iter().any(|n| same_key(item, n)) {
+            nodes.push(DiffNode::item(Some(ChangeKind::Removed), label(item)));
+        }
+    }
+    nodes
+}
+
+fn index_label(idx: &IndexData) -> String {
+    let unique_marker = if idx.unique { "UNIQUE " } else { "" };
+    format!("{unique_marker}{} ({})", idx.name, idx.columns.join(", "))
+}
+
+fn fk_label(fk: &ForeignKeyData) -> String {
+    let name = fk.name.as_deref().unwrap_or("(unnamed)");
+    let mut label = format!(
+        "{}: {} -> {}.{}",
+        name, fk.column, fk.foreign_table, fk.foreign_column
+    );
+    if let Some(ref on_delete) = fk.on_delete {
+        label.push_str(&format!(" ON DELETE {on_delete}"));
+    }
+    if let Some(ref on_update) = fk.on_update {
+        label.push_str(&format!(" ON UPDATE {on_update}"));
+    }
+    label
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -992,4 +1405,78 @@ mod tests {
         let output = tree.render_plain();
         assert!(output.contains("DEFAULT 'active'"));
     }
+
+    #[test]
+    fn test_schema_diff_marks_changes() {
+        let before = vec![sample_table(), sample_table_with_fk()];
+
+        let mut heroes = sample_table();
+        heroes.columns.retain(|col| col.name != "secret_name");
+        heroes.columns[1].sql_type = "VARCHAR(100)".to_string();
+        heroes.columns.push(sample_column("age", "INTEGER", false));
+        heroes.indexes.push(IndexData {
+            name: "idx_hero_name".to_string(),
+            columns: vec!["name".to_string()],
+            unique: false,
+        });
+        let teams = TableData {
+            name: "teams".to_string(),
+            columns: vec![sample_column("id", "INTEGER", true)],
+            primary_key: vec!["id".to_string()],
+            foreign_keys: vec![],
+            indexes: vec![],
+        };
+
+        let diff = SchemaDiffTree::new(&before, &[heroes, teams]).ascii();
+        assert!(diff.has_changes());
+        let expected = [
+            "Schema changes",
+            "+-- ~ Table: heroes [PK: id]",
+            "|   +-- Columns",
+            "|   |   +-- ~ name VARCHAR(100) (was TEXT)",
+            "|   |   +-- + age INTEGER",
+            "|   |   \\-- - secret_name TEXT",
+            "|   \\-- Indexes",
+            "|       \\-- + idx_hero_name (name)",
+            "+-- + Table: teams [PK: id]",
+            "|   \\-- Columns",
+            "|       \\-- + id INTEGER [PK, AUTO, NOT NULL]",
+            "\\-- - Table: team_members [PK: id]",
+        ];
+        let output = diff.render_plain();
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines[..expected.len()], expected);
+        assert!(output.contains("- fk_hero: hero_id -> heroes.id ON DELETE CASCADE"));
+        assert!(output.contains("- UNIQUE idx_hero_team (hero_id, team_id)"));
+    }
+
+    #[test]
+    fn test_schema_diff_unchanged() {
+        let schema = vec![sample_table()];
+        let diff = SchemaDiffTree::new(&schema, &schema);
+        assert!(!diff.has_changes());
+        assert_eq!(diff.render_plain(), "Schema changes: (none)");
+
+        let output = diff.show_unchanged(true).render_plain();
+        assert!(output.contains("└──   Table: heroes [PK: id]"));
+        assert!(output.contains("  name TEXT"));
+        assert!(!output.contains('~'));
+    }
+
+    #[test]
+    fn test_schema_diff_styled_colors() {
+        let theme = Theme::default();
+        let mut after = sample_table();
+        after.columns.push(sample_column("age", "INTEGER", false));
+        let output = SchemaDiffTree::new(&[sample_table(), sample_table_with_fk()], &[after])
+            .theme(theme.clone())
+            .render_styled();
+
+        let added = format!("{}+ age INTEGER", theme.success.color_code());
+        let altered = format!("{}~ Table: heroes", theme.warning.color_code());
+        let dropped = format!("{}- Table: team_members", theme.error.color_code());
+        assert!(output.contains(&added));
+        assert!(output.contains(&altered));
+        assert!(output.contains(&dropped));
+    }
 }
@@ -15,9 +15,12 @@
 //! println!("{}", spinner.render_plain());
 //! ```
 
+use std::io::{IsTerminal, Write};
+use std::sync::Mutex;
 use std::time::Instant;
 
 use serde::{Deserialize, Serialize};
+use sqlmodel_core::ProgressReporter;
 
 use super::OperationProgress;
 use crate::theme::Theme;
@@ -272,6 +275,78 @@ fn format_elapsed(secs: f64) -> String {
     }
 }
 
+/// A [`ProgressReporter`] that draws an [`IndeterminateSpinner`] on stderr.
+///
+/// Each phase becomes the spinner's message. On a terminal the spinner is
+/// redrawn in place on every phase and tick; otherwise each phase is printed
+/// once as a plain line and ticks are ignored.
+///
+/// ```rust,ignore
+/// let spinner = Arc::new(SpinnerReporter::new(IndeterminateSpinner::new("Connecting")));
+/// let config = PgConfig::new("localhost", "app", "app").progress(spinner.clone());
+/// let conn = PgAsyncConnection::connect(&cx, config).await;
+/// spinner.finish();
+/// ```
+#[derive(Debug)]
+pub struct SpinnerReporter {
+    spinner: Mutex<IndeterminateSpinner>,
+    animate: bool,
+}
+
+impl SpinnerReporter {
+    /// Report progress through `spinner`.
+    #[must_use]
+    pub fn new(spinner: IndeterminateSpinner) -> Self {
+        Self {
+            spinner: Mutex::new(spinner),
+            animate: std::io::stderr().is_terminal(),
+        }
+    }
+
+    /// The message currently shown.
+    #[must_use]
+    pub fn message(&self) -> String {
+        self.lock().message().to_string()
+    }
+
+    /// Clear the spinner line (on a terminal) once the operation is over.
+    pub fn finish(&self) {
+        if self.animate {
+            eprint!("\r\x1b[2K");
+            let _ = std::io::stderr().flush();
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, IndeterminateSpinner> {
+        self.spinner
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+
+    fn redraw(&self, spinner: &IndeterminateSpinner) {
+        eprint!("\r\x1b[2K{}", spinner.render_styled());
+        let _ = std::io::stderr().flush();
+    }
+}
+
+impl ProgressReporter for SpinnerReporter {
+    fn on_phase(&self, phase: &str) {
+        let mut spinner = self.lock();
+        spinner.set_message(phase);
+        if self.animate {
+            self.redraw(&spinner);
+        } else {
+            eprintln!("{}", spinner.render_plain());
+        }
+    }
+
+    fn tick(&self) {
+        if self.animate {
+            self.redraw(&self.lock());
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -283,6 +358,15 @@ mod tests {
         assert_eq!(spinner.current_style(), SpinnerStyle::Dots);
     }
 
+    #[test]
+    fn test_spinner_reporter_follows_phases() {
+        let reporter = SpinnerReporter::new(IndeterminateSpinner::new("Connecting"));
+        reporter.on_phase("Authenticating");
+        reporter.tick();
+        assert_eq!(reporter.message(), "Authenticating");
+        reporter.finish();
+    }
+
     #[test]
     fn test_spinner_all_styles() {
         for style in [
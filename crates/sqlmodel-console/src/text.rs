@@ -0,0 +1,156 @@
+//! Terminal text measurement.
+//!
+//! Styled output mixes ANSI escape sequences, which take no space on screen,
+//! with characters that take one or two columns (CJK, most emoji). These
+//! helpers measure and cut strings by what the terminal actually displays.
+
+use unicode_width::UnicodeWidthChar;
+
+/// Escape character starting every ANSI sequence.
+const ESC: char = '\x1b';
+
+/// SGR reset, appended when truncation cuts off a styled string's own reset.
+const RESET: &str = "\x1b[0m";
+
+/// Marker appended to truncated text.
+const ELLIPSIS: &str = "...";
+
+/// Byte length of the escape sequence at the start of `s` (which starts with ESC).
+///
+/// Handles CSI sequences (`ESC [ ... final`), OSC sequences (`ESC ] ...`
+/// terminated by BEL or `ESC \`) and two-character escapes.
+fn escape_len(s: &str) -> usize {
+    let bytes = s.as_bytes();
+    match bytes.get(1) {
+        Some(b'[') => bytes[2..]
+            .iter()
+            .position(|b| (0x40..=0x7e).contains(b))
+            .map_or(bytes.len(), |i| i + 3),
+        Some(b']') => {
+            let mut i = 2;
+            while i < bytes.len() {
+                match bytes[i] {
+                    0x07 => return i + 1,
+                    0x1b if bytes.get(i + 1) == Some(&b'\\') => return i + 2,
+                    _ => i += 1,
+                }
+            }
+            bytes.len()
+        }
+        Some(_) => 1 + s[1..].chars().next().map_or(0, char::len_utf8),
+        None => 1,
+    }
+}
+
+/// Number of terminal columns `s` occupies.
+///
+/// ANSI escape sequences count as zero, wide characters as two, and
+/// zero-width and control characters as zero.
+#[must_use]
+pub fn visible_width(s: &str) -> usize {
+    let mut width = 0;
+    let mut rest = s;
+    while let Some(c) = rest.chars().next() {
+        if c == ESC {
+            rest = &rest[escape_len(rest)..];
+            continue;
+        }
+        width += c.width().unwrap_or(0);
+        rest = &rest[c.len_utf8()..];
+    }
+    width
+}
+
+/// Truncate `s` to at most `max_width` terminal columns.
+///
+/// Text that fits is returned unchanged. Otherwise it is cut so that `...`
+/// still fits, never splitting a wide character. Escape sequences before the
+/// cut are kept, and a reset is appended so styling does not leak past the
+/// truncated text. Widths of three or less get only dots.
+#[must_use]
+pub fn truncate_visible(s: &str, max_width: usize) -> String {
+    if visible_width(s) <= max_width {
+        return s.to_string();
+    }
+    if max_width <= ELLIPSIS.len() {
+        return ".".repeat(max_width);
+    }
+
+    let budget = max_width - ELLIPSIS.len();
+    let mut out = String::with_capacity(s.len());
+    let mut width = 0;
+    let mut styled = false;
+    let mut rest = s;
+    while let Some(c) = rest.chars().next() {
+        if c == ESC {
+            let len = escape_len(rest);
+            out.push_str(&rest[..len]);
+            styled = true;
+            rest = &rest[len..];
+            continue;
+        }
+        let char_width = c.width().unwrap_or(0);
+        if width + char_width > budget {
+            break;
+        }
+        out.push(c);
+        width += char_width;
+        rest = &rest[c.len_utf8()..];
+    }
+
+    out.push_str(ELLIPSIS);
+    if styled {
+        out.push_str(RESET);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_visible_width_ignores_ansi() {
+        assert_eq!(visible_width("plain"), 5);
+        assert_eq!(visible_width("\x1b[1;31mred\x1b[0m"), 3);
+        assert_eq!(visible_width("\x1b[38;2;255;0;0mrgb\x1b[0m!"), 4);
+        assert_eq!(
+            visible_width("\x1b]8;;https://example.com\x1b\\link\x1b]8;;\x07"),
+            4
+        );
+        assert_eq!(visible_width(""), 0);
+    }
+
+    #[test]
+    fn test_visible_width_counts_wide_chars() {
+        assert_eq!(visible_width("日本語"), 6);
+        assert_eq!(visible_width("💡 hint"), 7);
+        assert_eq!(visible_width("\x1b[32m日本\x1b[0m"), 4);
+        assert_eq!(visible_width("e\u{301}"), 1);
+    }
+
+    #[test]
+    fn test_truncate_visible_plain() {
+        assert_eq!(truncate_visible("hello", 5), "hello");
+        assert_eq!(truncate_visible("hello world", 8), "hello...");
+        assert_eq!(truncate_visible("hello", 3), "...");
+        assert_eq!(truncate_visible("hello", 0), "");
+    }
+
+    #[test]
+    fn test_truncate_visible_wide_chars() {
+        // "日本" fills 4 of the 5 columns left for text; "語" would overflow.
+        let truncated = truncate_visible("日本語テキスト", 8);
+        assert_eq!(truncated, "日本...");
+        assert!(visible_width(&truncated) <= 8);
+    }
+
+    #[test]
+    fn test_truncate_visible_keeps_styles_and_resets() {
+        let styled = "\x1b[31mcolored text\x1b[0m";
+        let truncated = truncate_visible(styled, 10);
+        assert_eq!(truncated, "\x1b[31mcolored...\x1b[0m");
+        assert_eq!(visible_width(&truncated), 10);
+        assert_eq!(truncate_visible(styled, 12), styled);
+    }
+}
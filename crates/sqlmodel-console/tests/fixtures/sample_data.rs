@@ -25,6 +25,10 @@ fn col(
         primary_key: pk,
         auto_increment: auto,
         comment: None,
+        custom_type: None,
+        generated: None,
+        stored: false,
+        collation: None,
     }
 }
 
@@ -55,6 +59,8 @@ pub fn user_table_info() -> TableInfo {
             unique: true,
             index_type: None,
             primary: false,
+            expressions: Vec::new(),
+            predicate: None,
         }],
         comment: None,
     }
@@ -78,6 +84,8 @@ pub fn posts_table_info() -> TableInfo {
             foreign_column: "id".to_string(),
             on_delete: Some("CASCADE".to_string()),
             on_update: None,
+            deferrable: false,
+            initially_deferred: false,
         }],
         unique_constraints: Vec::new(),
         check_constraints: Vec::new(),
@@ -87,6 +95,8 @@ pub fn posts_table_info() -> TableInfo {
             unique: false,
             index_type: None,
             primary: false,
+            expressions: Vec::new(),
+            predicate: None,
         }],
         comment: None,
     }
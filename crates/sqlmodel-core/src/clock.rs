@@ -0,0 +1,97 @@
+//! Time sources for timestamp stamping.
+//!
+//! Code that needs "now" (e.g. filling [`Timestamps`](crate::Timestamps)
+//! fields) asks a [`Clock`] instead of the system time directly, so tests can
+//! substitute a [`FixedClock`] and assert exact values.
+//!
+//! Timestamps are microseconds since the Unix epoch, the same representation
+//! as [`Value::Timestamp`](crate::Value::Timestamp).
+
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// A source of the current time.
+pub trait Clock: Send + Sync + std::fmt::Debug {
+    /// Current time in microseconds since the Unix epoch.
+    fn now(&self) -> i64;
+}
+
+/// The system wall clock.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl SystemClock {
+    /// Current system time in microseconds since the Unix epoch.
+    ///
+    /// Times before the epoch are returned as negative values.
+    #[must_use]
+    pub fn now_micros() -> i64 {
+        match SystemTime::now().duration_since(UNIX_EPOCH) {
+            Ok(d) => i64::try_from(d.as_micros()).unwrap_or(i64::MAX),
+            Err(e) => i64::try_from(e.duration().as_micros()).map_or(i64::MIN, |us| -us),
+        }
+    }
+}
+
+impl Clock for SystemClock {
+    fn now(&self) -> i64 {
+        Self::now_micros()
+    }
+}
+
+/// A manually controlled clock for tests.
+///
+/// Starts at a fixed instant and only moves when [`set`](Self::set) or
+/// [`advance`](Self::advance) is called. Shared references can move it, so
+/// one `Arc<FixedClock>` can be handed to a session and driven from the test.
+#[derive(Debug, Default)]
+pub struct FixedClock {
+    micros: AtomicI64,
+}
+
+impl FixedClock {
+    /// Create a clock frozen at `micros` since the Unix epoch.
+    #[must_use]
+    pub const fn new(micros: i64) -> Self {
+        Self {
+            micros: AtomicI64::new(micros),
+        }
+    }
+
+    /// Move the clock to `micros` since the Unix epoch.
+    pub fn set(&self, micros: i64) {
+        self.micros.store(micros, Ordering::SeqCst);
+    }
+
+    /// Move the clock forward by `micros`.
+    pub fn advance(&self, micros: i64) {
+        self.micros.fetch_add(micros, Ordering::SeqCst);
+    }
+}
+
+impl Clock for FixedClock {
+    fn now(&self) -> i64 {
+        self.micros.load(Ordering::SeqCst)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fixed_clock() {
+        let clock = FixedClock::new(1_000);
+        assert_eq!(clock.now(), 1_000);
+        clock.advance(500);
+        assert_eq!(clock.now(), 1_500);
+        clock.set(42);
+        assert_eq!(clock.now(), 42);
+    }
+
+    #[test]
+    fn test_system_clock_is_after_2020() {
+        // 2020-01-01T00:00:00Z in microseconds.
+        assert!(SystemClock.now() > 1_577_836_800_000_000);
+    }
+}
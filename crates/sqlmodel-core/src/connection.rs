@@ -5,15 +5,18 @@
 //! - [`Connection`] - Main trait for executing queries and managing transactions
 //! - [`Transaction`] - Trait for transactional operations with savepoint support
 //! - [`IsolationLevel`] - SQL transaction isolation levels
+//! - [`TransactionMode`] - Isolation level plus access/deferrable modifiers for `BEGIN`
+//! - [`TransactionStatus`] - Server-reported transaction state (idle, active, failed)
 //! - [`PreparedStatement`] - Pre-compiled statement for efficient repeated execution
 //!
 //! All operations integrate with asupersync's structured concurrency via `Cx` context
 //! for proper cancellation and timeout handling.
 
-use crate::error::Result;
-use crate::row::Row;
+use crate::error::{ConfigError, Error, Result};
+use crate::row::{FromValue, Row};
 use crate::value::Value;
 use asupersync::{Cx, Outcome};
+use std::borrow::Cow;
 
 /// Transaction isolation level.
 ///
@@ -56,6 +59,177 @@ impl IsolationLevel {
     }
 }
 
+/// Server-side transaction state of a connection.
+///
+/// Mirrors the status byte PostgreSQL reports in every `ReadyForQuery`
+/// message. Once a statement fails inside a transaction block the server
+/// rejects everything except `ROLLBACK`, so callers can check for
+/// [`TransactionStatus::Failed`] before issuing more work.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TransactionStatus {
+    /// Not inside a transaction block.
+    #[default]
+    Idle,
+    /// Inside a transaction block that can still do work.
+    InTransaction,
+    /// Inside a failed transaction block; only `ROLLBACK` will succeed.
+    Failed,
+}
+
+/// Transaction characteristics used to build a `BEGIN` statement.
+///
+/// Combines an optional isolation level with PostgreSQL's `READ ONLY` and
+/// `DEFERRABLE` modifiers. A `SERIALIZABLE READ ONLY DEFERRABLE` transaction
+/// waits for a safe snapshot and then runs without serialization failures,
+/// which suits long-running reporting queries.
+///
+/// # Example
+///
+/// ```
+/// use sqlmodel_core::{IsolationLevel, TransactionMode};
+///
+/// let mode = TransactionMode::new(IsolationLevel::Serializable)
+///     .read_only(true)
+///     .deferrable(true);
+/// assert_eq!(
+///     mode.begin_sql(),
+///     "BEGIN ISOLATION LEVEL SERIALIZABLE READ ONLY DEFERRABLE"
+/// );
+/// assert_eq!(TransactionMode::default().begin_sql(), "BEGIN");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct TransactionMode {
+    /// Isolation level, or `None` to use the server default.
+    pub isolation: Option<IsolationLevel>,
+    /// Whether the transaction is `READ ONLY`.
+    pub read_only: bool,
+    /// Whether the transaction is `DEFERRABLE` (PostgreSQL; only has an
+    /// effect for `SERIALIZABLE READ ONLY` transactions).
+    pub deferrable: bool,
+}
+
+impl TransactionMode {
+    /// Create a mode with the given isolation level and no modifiers.
+    #[must_use]
+    pub const fn new(isolation: IsolationLevel) -> Self {
+        Self {
+            isolation: Some(isolation),
+            read_only: false,
+            deferrable: false,
+        }
+    }
+
+    /// Set whether the transaction is read-only.
+    #[must_use]
+    pub const fn read_only(mut self, read_only: bool) -> Self {
+        self.read_only = read_only;
+        self
+    }
+
+    /// Set whether the transaction is deferrable.
+    #[must_use]
+    pub const fn deferrable(mut self, deferrable: bool) -> Self {
+        self.deferrable = deferrable;
+        self
+    }
+
+    /// Build the PostgreSQL `BEGIN` statement for this mode.
+    #[must_use]
+    pub fn begin_sql(&self) -> String {
+        let mut sql = String::from("BEGIN");
+        if let Some(level) = self.isolation {
+            sql.push_str(" ISOLATION LEVEL ");
+            sql.push_str(level.as_sql());
+        }
+        if self.read_only {
+            sql.push_str(" READ ONLY");
+        }
+        if self.deferrable {
+            sql.push_str(" DEFERRABLE");
+        }
+        sql
+    }
+
+    /// Build the statements that open a transaction with this mode in
+    /// `dialect`, in execution order.
+    ///
+    /// PostgreSQL gets [`begin_sql`](Self::begin_sql). MySQL sets the
+    /// isolation level with `SET TRANSACTION` and then runs
+    /// `START TRANSACTION [READ ONLY]`. SQLite maps isolation levels to
+    /// `BEGIN DEFERRED`/`IMMEDIATE`/`EXCLUSIVE` like its driver does; it has
+    /// no read-only transactions, so `READ ONLY` is dropped there (open the
+    /// database read-only to enforce it).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Config`] for `DEFERRABLE` outside PostgreSQL.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sqlmodel_core::{Dialect, IsolationLevel, TransactionMode};
+    ///
+    /// let mode = TransactionMode::new(IsolationLevel::RepeatableRead).read_only(true);
+    /// assert_eq!(
+    ///     mode.begin_statements(Dialect::Mysql).unwrap(),
+    ///     [
+    ///         "SET TRANSACTION ISOLATION LEVEL REPEATABLE READ",
+    ///         "START TRANSACTION READ ONLY",
+    ///     ]
+    /// );
+    /// assert_eq!(
+    ///     mode.begin_statements(Dialect::Sqlite).unwrap(),
+    ///     ["BEGIN IMMEDIATE"]
+    /// );
+    /// ```
+    pub fn begin_statements(&self, dialect: Dialect) -> Result<Vec<String>> {
+        let unsupported = |modifier: &str| {
+            Err(Error::Config(ConfigError {
+                message: format!("{dialect:?} transactions do not support {modifier}"),
+                source: None,
+            }))
+        };
+        match dialect {
+            Dialect::Postgres => Ok(vec![self.begin_sql()]),
+            Dialect::Mysql => {
+                if self.deferrable {
+                    return unsupported("DEFERRABLE");
+                }
+                if self.isolation.is_none() && !self.read_only {
+                    return Ok(vec!["BEGIN".to_string()]);
+                }
+                let mut statements = Vec::with_capacity(2);
+                if let Some(level) = self.isolation {
+                    statements.push(format!(
+                        "SET TRANSACTION ISOLATION LEVEL {}",
+                        level.as_sql()
+                    ));
+                }
+                statements.push(if self.read_only {
+                    "START TRANSACTION READ ONLY".to_string()
+                } else {
+                    "START TRANSACTION".to_string()
+                });
+                Ok(statements)
+            }
+            Dialect::Sqlite => {
+                if self.deferrable {
+                    return unsupported("DEFERRABLE");
+                }
+                let sql = match self.isolation {
+                    None => "BEGIN",
+                    Some(IsolationLevel::Serializable) => "BEGIN EXCLUSIVE",
+                    Some(IsolationLevel::RepeatableRead | IsolationLevel::ReadCommitted) => {
+                        "BEGIN IMMEDIATE"
+                    }
+                    Some(IsolationLevel::ReadUncommitted) => "BEGIN DEFERRED",
+                };
+                Ok(vec![sql.to_string()])
+            }
+        }
+    }
+}
+
 /// A prepared statement for repeated execution.
 ///
 /// Prepared statements are pre-compiled by the database, allowing efficient
@@ -185,6 +359,109 @@ impl Dialect {
         matches!(self, Dialect::Postgres)
     }
 
+    /// Check if this dialect supports `RETURNING` on multi-row INSERTs.
+    ///
+    /// PostgreSQL and SQLite (3.35+) do; MySQL has no `RETURNING` clause.
+    pub const fn supports_returning(self) -> bool {
+        matches!(self, Dialect::Postgres | Dialect::Sqlite)
+    }
+
+    /// `INSERT` of one row made entirely of column defaults.
+    ///
+    /// `table` is inserted as given (quote it first). MySQL has no
+    /// `DEFAULT VALUES` form and takes an empty column and value list instead.
+    pub fn default_values_insert(self, table: &str) -> String {
+        match self {
+            Dialect::Postgres | Dialect::Sqlite => format!("INSERT INTO {table} DEFAULT VALUES"),
+            Dialect::Mysql => format!("INSERT INTO {table} () VALUES ()"),
+        }
+    }
+
+    /// Maximum number of bound parameters in a single statement.
+    ///
+    /// PostgreSQL and MySQL encode the parameter count as a 16-bit integer
+    /// (65535); SQLite's default `SQLITE_MAX_VARIABLE_NUMBER` is 32766 since
+    /// 3.32.
+    pub const fn max_params(self) -> usize {
+        match self {
+            Dialect::Postgres | Dialect::Mysql => 65_535,
+            Dialect::Sqlite => 32_766,
+        }
+    }
+
+    /// SQL literal for a boolean.
+    ///
+    /// SQLite has no boolean type and MySQL's `BOOLEAN` is `TINYINT(1)`, so
+    /// both get `1`/`0`; PostgreSQL gets `TRUE`/`FALSE`.
+    pub const fn bool_literal(self, value: bool) -> &'static str {
+        match (self, value) {
+            (Dialect::Postgres, true) => "TRUE",
+            (Dialect::Postgres, false) => "FALSE",
+            (Dialect::Sqlite | Dialect::Mysql, true) => "1",
+            (Dialect::Sqlite | Dialect::Mysql, false) => "0",
+        }
+    }
+
+    /// Render `value` as a keyword literal, if it has one.
+    ///
+    /// Booleans follow [`bool_literal`](Self::bool_literal), `Null` and
+    /// `TypedNull` are `NULL` and `Default` is `DEFAULT`. Other values have no
+    /// keyword form and should be bound as parameters.
+    pub fn keyword_literal(self, value: &Value) -> Option<&'static str> {
+        match value {
+            Value::Bool(b) => Some(self.bool_literal(*b)),
+            Value::Null | Value::TypedNull(_) => Some("NULL"),
+            Value::Default => Some("DEFAULT"),
+            _ => None,
+        }
+    }
+
+    /// Render a column `DEFAULT` expression for this dialect.
+    ///
+    /// `true`, `false` and `null` in any case become this dialect's literals,
+    /// so a model declaring `default = "true"` emits `DEFAULT TRUE` on
+    /// PostgreSQL and `DEFAULT 1` on SQLite. Other expressions pass through.
+    pub fn default_expr(self, expr: &str) -> Cow<'_, str> {
+        let trimmed = expr.trim();
+        let keyword = if trimmed.eq_ignore_ascii_case("true") {
+            Value::Bool(true)
+        } else if trimmed.eq_ignore_ascii_case("false") {
+            Value::Bool(false)
+        } else if trimmed.eq_ignore_ascii_case("null") {
+            Value::Null
+        } else {
+            return Cow::Borrowed(expr);
+        };
+        Cow::Borrowed(self.keyword_literal(&keyword).unwrap_or(expr))
+    }
+
+    /// Render the `LIMIT`/`OFFSET` tail of a query, with a leading space.
+    ///
+    /// PostgreSQL and SQLite get `LIMIT n OFFSET m`, MySQL `LIMIT m, n`.
+    /// MySQL and SQLite reject an `OFFSET` without a `LIMIT`, so an offset
+    /// alone is paired with "no limit" there (`LIMIT m, 18446744073709551615`
+    /// and `LIMIT -1 OFFSET m`). PostgreSQL and SQLite take signed 64-bit
+    /// counts; larger values are clamped to `i64::MAX`. Returns an empty
+    /// string when both are `None`.
+    pub fn limit_offset(self, limit: Option<u64>, offset: Option<u64>) -> String {
+        let clamp = |n: u64| n.min(i64::MAX.unsigned_abs());
+        match (self, limit, offset) {
+            (_, None, None) => String::new(),
+            (Dialect::Mysql, Some(limit), None) => format!(" LIMIT {limit}"),
+            (Dialect::Mysql, limit, Some(offset)) => {
+                format!(" LIMIT {offset}, {}", limit.unwrap_or(u64::MAX))
+            }
+            (Dialect::Postgres, None, Some(offset)) => format!(" OFFSET {}", clamp(offset)),
+            (Dialect::Sqlite, None, Some(offset)) => format!(" LIMIT -1 OFFSET {}", clamp(offset)),
+            (Dialect::Postgres | Dialect::Sqlite, Some(limit), None) => {
+                format!(" LIMIT {}", clamp(limit))
+            }
+            (Dialect::Postgres | Dialect::Sqlite, Some(limit), Some(offset)) => {
+                format!(" LIMIT {} OFFSET {}", clamp(limit), clamp(offset))
+            }
+        }
+    }
+
     /// Quote an identifier for this dialect.
     ///
     /// Properly escapes embedded quote characters by doubling them:
@@ -202,6 +479,163 @@ impl Dialect {
             }
         }
     }
+
+    /// Maximum identifier length in bytes, or `None` if unlimited.
+    ///
+    /// PostgreSQL silently truncates longer names; MySQL rejects them.
+    pub const fn max_identifier_length(self) -> Option<usize> {
+        match self {
+            Dialect::Sqlite => None,
+            Dialect::Postgres => Some(63),
+            Dialect::Mysql => Some(64),
+        }
+    }
+
+    /// Build a parameterized `column IN (...)` predicate over `values`.
+    ///
+    /// Placeholders are numbered from `start` (1-based), so the fragment can be
+    /// appended to a statement that already binds `start - 1` parameters.
+    /// Returns the SQL fragment and the parameters to bind, in order.
+    ///
+    /// An empty slice yields the always-false predicate `1=0` with no
+    /// parameters, since `IN ()` is a syntax error on every backend.
+    pub fn in_clause(self, column: &str, values: &[Value], start: usize) -> (String, Vec<Value>) {
+        if values.is_empty() {
+            return ("1=0".to_string(), Vec::new());
+        }
+        let placeholders: Vec<String> = (start..start + values.len())
+            .map(|i| self.placeholder(i))
+            .collect();
+        let sql = format!(
+            "{} IN ({})",
+            self.quote_identifier(column),
+            placeholders.join(", ")
+        );
+        (sql, values.to_vec())
+    }
+
+    /// Build a parameterized `(a, b) IN ((...), ...)` predicate over composite keys.
+    ///
+    /// Each tuple must hold one value per column, in column order; the values
+    /// are bound tuple by tuple with placeholders numbered from `start`.
+    /// PostgreSQL gets row-value syntax, SQLite and MySQL the equivalent
+    /// `((a = ? AND b = ?) OR ...)`. A single column falls back to
+    /// [`in_clause`](Self::in_clause), and no tuples yield `1=0`.
+    pub fn tuple_in_clause(
+        self,
+        columns: &[&str],
+        tuples: &[Vec<Value>],
+        start: usize,
+    ) -> (String, Vec<Value>) {
+        if columns.is_empty() || tuples.is_empty() {
+            return ("1=0".to_string(), Vec::new());
+        }
+        if let [column] = columns {
+            let values: Vec<Value> = tuples.iter().filter_map(|t| t.first().cloned()).collect();
+            return self.in_clause(column, &values, start);
+        }
+
+        let quoted: Vec<String> = columns.iter().map(|c| self.quote_identifier(c)).collect();
+        let mut params = Vec::with_capacity(tuples.len() * columns.len());
+        let mut next = start;
+        let groups: Vec<String> = tuples
+            .iter()
+            .map(|tuple| {
+                debug_assert_eq!(tuple.len(), columns.len(), "tuple arity must match columns");
+                let placeholders: Vec<String> = tuple
+                    .iter()
+                    .map(|value| {
+                        params.push(value.clone());
+                        next += 1;
+                        self.placeholder(next - 1)
+                    })
+                    .collect();
+                match self {
+                    Dialect::Postgres => format!("({})", placeholders.join(", ")),
+                    Dialect::Sqlite | Dialect::Mysql => {
+                        let terms: Vec<String> = quoted
+                            .iter()
+                            .zip(&placeholders)
+                            .map(|(column, placeholder)| format!("{column} = {placeholder}"))
+                            .collect();
+                        format!("({})", terms.join(" AND "))
+                    }
+                }
+            })
+            .collect();
+
+        let sql = match self {
+            Dialect::Postgres => format!("({}) IN ({})", quoted.join(", "), groups.join(", ")),
+            Dialect::Sqlite | Dialect::Mysql => format!("({})", groups.join(" OR ")),
+        };
+        (sql, params)
+    }
+}
+
+/// Upsert syntax a backend understands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UpsertSyntax {
+    /// `INSERT ... ON CONFLICT (...) DO UPDATE` (PostgreSQL, SQLite 3.24+).
+    OnConflict,
+    /// `INSERT ... ON DUPLICATE KEY UPDATE` (MySQL, MariaDB).
+    OnDuplicateKey,
+    /// No upsert support.
+    None,
+}
+
+/// What a connected backend supports, as far as SQL generation is concerned.
+///
+/// [`Dialect`] describes the SQL flavour; capabilities also depend on the
+/// server or library version, so drivers report them per connection through
+/// [`Connection::capabilities`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct Capabilities {
+    /// `INSERT ... RETURNING`.
+    pub returning_insert: bool,
+    /// `UPDATE ... RETURNING`.
+    pub returning_update: bool,
+    /// `DELETE ... RETURNING`.
+    pub returning_delete: bool,
+    /// `RETURNING` on a multi-row `INSERT` yields one row per inserted row.
+    pub multi_row_returning: bool,
+    /// Upsert form, if any.
+    pub upsert_syntax: UpsertSyntax,
+    /// Maximum number of bound parameters in a single statement.
+    pub max_bind_params: usize,
+}
+
+impl Capabilities {
+    /// Capabilities of a current server speaking `dialect`.
+    ///
+    /// Drivers that know the server or library version should adjust these;
+    /// see [`Capabilities::without_returning`].
+    pub const fn for_dialect(dialect: Dialect) -> Self {
+        let returning = dialect.supports_returning();
+        Self {
+            returning_insert: returning,
+            returning_update: returning,
+            returning_delete: returning,
+            multi_row_returning: returning,
+            upsert_syntax: match dialect {
+                Dialect::Postgres | Dialect::Sqlite => UpsertSyntax::OnConflict,
+                Dialect::Mysql => UpsertSyntax::OnDuplicateKey,
+            },
+            max_bind_params: dialect.max_params(),
+        }
+    }
+
+    /// The same capabilities with every form of `RETURNING` turned off.
+    #[must_use]
+    pub const fn without_returning(self) -> Self {
+        Self {
+            returning_insert: false,
+            returning_update: false,
+            returning_delete: false,
+            multi_row_returning: false,
+            ..self
+        }
+    }
 }
 
 pub trait Connection: Send + Sync {
@@ -218,6 +652,21 @@ pub trait Connection: Send + Sync {
         Dialect::Postgres
     }
 
+    /// Get the server-side transaction state as of the last completed exchange.
+    ///
+    /// Drivers that don't track it report [`TransactionStatus::Idle`].
+    fn transaction_status(&self) -> TransactionStatus {
+        TransactionStatus::Idle
+    }
+
+    /// Get what the connected backend supports.
+    ///
+    /// Defaults to [`Capabilities::for_dialect`] for [`Connection::dialect`];
+    /// drivers override it when support depends on the server version.
+    fn capabilities(&self) -> Capabilities {
+        Capabilities::for_dialect(self.dialect())
+    }
+
     /// Execute a query and return all rows.
     fn query(
         &self,
@@ -234,6 +683,95 @@ pub trait Connection: Send + Sync {
         params: &[Value],
     ) -> impl Future<Output = Outcome<Option<Row>, crate::Error>> + Send;
 
+    /// Execute a query and return the first column of the first row, if any.
+    ///
+    /// Intended for single-value queries such as `SELECT COUNT(*)` or
+    /// `SELECT max(id)`. A SQL `NULL` is returned as `Some(Value::Null)`;
+    /// `None` means the query produced no rows (or no columns).
+    fn scalar(
+        &self,
+        cx: &Cx,
+        sql: &str,
+        params: &[Value],
+    ) -> impl Future<Output = Outcome<Option<Value>, crate::Error>> + Send {
+        async move {
+            self.query_one(cx, sql, params)
+                .await
+                .map(|row| row.and_then(|row| row.get(0).cloned()))
+        }
+    }
+
+    /// Execute a query and convert the first column of the first row to `T`.
+    ///
+    /// Use `Option<T>` for aggregates that yield `NULL` on empty input, e.g.
+    /// `scalar_as::<Option<i64>>(cx, "SELECT max(id) FROM heroes", &[])`.
+    fn scalar_as<T: FromValue + Send>(
+        &self,
+        cx: &Cx,
+        sql: &str,
+        params: &[Value],
+    ) -> impl Future<Output = Outcome<Option<T>, crate::Error>> + Send {
+        async move {
+            match self.scalar(cx, sql, params).await {
+                Outcome::Ok(Some(value)) => match T::from_value(&value) {
+                    Ok(v) => Outcome::Ok(Some(v)),
+                    Err(e) => Outcome::Err(e),
+                },
+                Outcome::Ok(None) => Outcome::Ok(None),
+                Outcome::Err(e) => Outcome::Err(e),
+                Outcome::Cancelled(r) => Outcome::Cancelled(r),
+                Outcome::Panicked(p) => Outcome::Panicked(p),
+            }
+        }
+    }
+
+    /// Execute a query and decode every row as `M`.
+    ///
+    /// A typed read without a session: rows are decoded with
+    /// [`Model::from_row`](crate::Model::from_row) and nothing is tracked.
+    /// The first row that fails to decode fails the whole call.
+    fn query_as<M: crate::Model>(
+        &self,
+        cx: &Cx,
+        sql: &str,
+        params: &[Value],
+    ) -> impl Future<Output = Outcome<Vec<M>, crate::Error>> + Send {
+        async move {
+            match self.query(cx, sql, params).await {
+                Outcome::Ok(rows) => match rows.iter().map(M::from_row).collect() {
+                    Ok(models) => Outcome::Ok(models),
+                    Err(e) => Outcome::Err(e),
+                },
+                Outcome::Err(e) => Outcome::Err(e),
+                Outcome::Cancelled(r) => Outcome::Cancelled(r),
+                Outcome::Panicked(p) => Outcome::Panicked(p),
+            }
+        }
+    }
+
+    /// Execute a query and decode the first row, if any, as `M`.
+    ///
+    /// See [`query_as`](Self::query_as).
+    fn query_one_as<M: crate::Model>(
+        &self,
+        cx: &Cx,
+        sql: &str,
+        params: &[Value],
+    ) -> impl Future<Output = Outcome<Option<M>, crate::Error>> + Send {
+        async move {
+            match self.query_one(cx, sql, params).await {
+                Outcome::Ok(Some(row)) => match M::from_row(&row) {
+                    Ok(model) => Outcome::Ok(Some(model)),
+                    Err(e) => Outcome::Err(e),
+                },
+                Outcome::Ok(None) => Outcome::Ok(None),
+                Outcome::Err(e) => Outcome::Err(e),
+                Outcome::Cancelled(r) => Outcome::Cancelled(r),
+                Outcome::Panicked(p) => Outcome::Panicked(p),
+            }
+        }
+    }
+
     /// Execute a statement (INSERT, UPDATE, DELETE) and return rows affected.
     fn execute(
         &self,
@@ -274,6 +812,59 @@ pub trait Connection: Send + Sync {
         isolation: IsolationLevel,
     ) -> impl Future<Output = Outcome<Self::Tx<'_>, crate::Error>> + Send;
 
+    /// Run `f` inside a transaction.
+    ///
+    /// Begins a transaction, hands it to `f`, and commits if `f` returns
+    /// `Ok`; any other outcome rolls back and is returned as-is (a failed
+    /// rollback after an error is only logged). The closure returns a boxed
+    /// future so it can borrow the transaction:
+    ///
+    /// ```rust,ignore
+    /// let moved = conn
+    ///     .transaction_scope(cx, |tx| {
+    ///         Box::pin(async move {
+    ///             tx.execute(cx, "UPDATE accounts SET balance = balance - 10 WHERE id = 1", &[])
+    ///                 .await?;
+    ///             tx.execute(cx, "UPDATE accounts SET balance = balance + 10 WHERE id = 2", &[])
+    ///                 .await
+    ///         })
+    ///     })
+    ///     .await;
+    /// ```
+    fn transaction_scope<'conn, F, T>(
+        &'conn self,
+        cx: &Cx,
+        f: F,
+    ) -> impl Future<Output = Outcome<T, crate::Error>> + Send
+    where
+        F: for<'tx> FnOnce(
+                &'tx Self::Tx<'conn>,
+            ) -> std::pin::Pin<
+                Box<dyn Future<Output = Outcome<T, crate::Error>> + Send + 'tx>,
+            > + Send,
+        T: Send,
+        Self::Tx<'conn>: Sync,
+    {
+        async move {
+            let tx = match self.begin(cx).await {
+                Outcome::Ok(tx) => tx,
+                Outcome::Err(e) => return Outcome::Err(e),
+                Outcome::Cancelled(r) => return Outcome::Cancelled(r),
+                Outcome::Panicked(p) => return Outcome::Panicked(p),
+            };
+            let outcome = f(&tx).await;
+            match outcome {
+                Outcome::Ok(value) => tx.commit(cx).await.map(|()| value),
+                other => {
+                    if let Outcome::Err(e) = tx.rollback(cx).await {
+                        tracing::warn!(error = %e, "Rollback after failed transaction scope failed");
+                    }
+                    other
+                }
+            }
+        }
+    }
+
     /// Prepare a statement for repeated execution.
     ///
     /// Prepared statements are cached by the driver and can be executed
@@ -313,6 +904,40 @@ pub trait Connection: Send + Sync {
         }
     }
 
+    /// Postpone checks of `DEFERRABLE` constraints until the current
+    /// transaction commits.
+    ///
+    /// Issues `SET CONSTRAINTS ALL DEFERRED` on PostgreSQL and
+    /// `PRAGMA defer_foreign_keys = ON` on SQLite; both last until the end of
+    /// the transaction. This lets rows that reference each other be inserted
+    /// in any order. MySQL has no equivalent and returns an error.
+    fn defer_constraints(&self, cx: &Cx) -> impl Future<Output = Outcome<(), crate::Error>> + Send {
+        async move {
+            let sql = match self.dialect() {
+                Dialect::Postgres => "SET CONSTRAINTS ALL DEFERRED",
+                Dialect::Sqlite => "PRAGMA defer_foreign_keys = ON",
+                Dialect::Mysql => {
+                    return Outcome::Err(crate::Error::Custom(
+                        "MySQL does not support deferred constraints".to_string(),
+                    ));
+                }
+            };
+            self.execute(cx, sql, &[]).await.map(|_| ())
+        }
+    }
+
+    /// Clear session state before the connection is reused.
+    ///
+    /// Pools call this when recycling a connection so that open transactions,
+    /// prepared statements and `SET` parameters from the previous user do not
+    /// leak into the next one. It is called on every checkout, so drivers
+    /// should return early when nothing changed the session, keeping caches
+    /// such as prepared statements warm. The default implementation does
+    /// nothing.
+    fn reset(&self, _cx: &Cx) -> impl Future<Output = Outcome<(), crate::Error>> + Send {
+        async { Outcome::Ok(()) }
+    }
+
     /// Close the connection gracefully.
     fn close(self, cx: &Cx) -> impl Future<Output = Result<()>> + Send;
 }
@@ -389,6 +1014,43 @@ pub trait TransactionOps: Send {
         name: &str,
     ) -> impl Future<Output = Outcome<(), crate::Error>> + Send;
 
+    /// Create a savepoint and return a guard that ends it.
+    ///
+    /// The [`Savepoint`] keeps the name, so the scope is closed with
+    /// [`Savepoint::release`] or [`Savepoint::rollback`] instead of a second
+    /// string that could be misspelled. Statements inside the scope still go
+    /// through the transaction:
+    ///
+    /// ```rust,ignore
+    /// let sp = tx.savepoint_scope(&cx, "import_row").await?;
+    /// match tx.execute(&cx, "INSERT INTO items (sku) VALUES ($1)", &[sku]).await {
+    ///     Outcome::Ok(_) => sp.release(&cx).await?,
+    ///     _ => sp.rollback(&cx).await?,
+    /// }
+    /// ```
+    fn savepoint_scope<'tx>(
+        &'tx self,
+        cx: &Cx,
+        name: &str,
+    ) -> impl Future<Output = Outcome<Savepoint<'tx, Self>, crate::Error>> + Send
+    where
+        Self: Sized + Sync,
+    {
+        let name = name.to_string();
+        async move {
+            match self.savepoint(cx, &name).await {
+                Outcome::Ok(()) => Outcome::Ok(Savepoint {
+                    tx: self,
+                    name,
+                    finished: false,
+                }),
+                Outcome::Err(e) => Outcome::Err(e),
+                Outcome::Cancelled(r) => Outcome::Cancelled(r),
+                Outcome::Panicked(p) => Outcome::Panicked(p),
+            }
+        }
+    }
+
     /// Commit the transaction, making all changes permanent.
     fn commit(self, cx: &Cx) -> impl Future<Output = Outcome<(), crate::Error>> + Send;
 
@@ -396,6 +1058,50 @@ pub trait TransactionOps: Send {
     fn rollback(self, cx: &Cx) -> impl Future<Output = Outcome<(), crate::Error>> + Send;
 }
 
+/// An open savepoint, created by [`TransactionOps::savepoint_scope`].
+///
+/// End it with [`release`](Self::release) or [`rollback`](Self::rollback).
+/// Dropping it without either only logs a warning: the savepoint stays open
+/// until the transaction ends.
+#[must_use = "a savepoint should be released or rolled back"]
+pub struct Savepoint<'tx, T: TransactionOps> {
+    tx: &'tx T,
+    name: String,
+    finished: bool,
+}
+
+impl<T: TransactionOps> Savepoint<'_, T> {
+    /// The savepoint's name.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Release the savepoint, keeping its changes in the transaction.
+    pub async fn release(mut self, cx: &Cx) -> Outcome<(), crate::Error> {
+        self.finished = true;
+        self.tx.release(cx, &self.name).await
+    }
+
+    /// Roll back to the savepoint, discarding the changes made since.
+    pub async fn rollback(mut self, cx: &Cx) -> Outcome<(), crate::Error> {
+        self.finished = true;
+        self.tx.rollback_to(cx, &self.name).await
+    }
+}
+
+impl<T: TransactionOps> Drop for Savepoint<'_, T> {
+    fn drop(&mut self) {
+        if !self.finished {
+            tracing::warn!(
+                savepoint = %self.name,
+                "Savepoint dropped without release() or rollback(); it stays open until the \
+                 transaction ends"
+            );
+        }
+    }
+}
+
 /// A database transaction (concrete implementation).
 ///
 /// Transactions provide ACID guarantees and can be committed or rolled back.
@@ -655,6 +1361,271 @@ mod tests {
         assert_eq!(level, IsolationLevel::ReadCommitted);
     }
 
+    #[test]
+    fn test_capabilities_for_dialect() {
+        let pg = Capabilities::for_dialect(Dialect::Postgres);
+        assert!(pg.returning_insert && pg.returning_update && pg.returning_delete);
+        assert!(pg.multi_row_returning);
+        assert_eq!(pg.upsert_syntax, UpsertSyntax::OnConflict);
+        assert_eq!(pg.max_bind_params, 65_535);
+
+        let mysql = Capabilities::for_dialect(Dialect::Mysql);
+        assert!(!mysql.returning_insert && !mysql.returning_delete);
+        assert_eq!(mysql.upsert_syntax, UpsertSyntax::OnDuplicateKey);
+
+        let plain = pg.without_returning();
+        assert!(!plain.returning_insert && !plain.returning_update);
+        assert!(!plain.returning_delete && !plain.multi_row_returning);
+        assert_eq!(plain.upsert_syntax, pg.upsert_syntax);
+        assert_eq!(plain.max_bind_params, pg.max_bind_params);
+    }
+
+    fn unwrap_outcome<T>(outcome: Outcome<T, crate::Error>) -> T {
+        match outcome {
+            Outcome::Ok(v) => v,
+            Outcome::Err(e) => panic!("unexpected error: {e}"),
+            _ => panic!("unexpected cancellation or panic"),
+        }
+    }
+
+    #[derive(Default)]
+    struct RecordingTx {
+        executed: std::sync::Arc<std::sync::Mutex<Vec<String>>>,
+    }
+
+    impl RecordingTx {
+        fn record(&self, sql: String) -> Outcome<(), crate::Error> {
+            self.executed.lock().unwrap().push(sql);
+            Outcome::Ok(())
+        }
+    }
+
+    impl TransactionOps for RecordingTx {
+        async fn query(
+            &self,
+            _cx: &Cx,
+            _sql: &str,
+            _params: &[Value],
+        ) -> Outcome<Vec<Row>, crate::Error> {
+            Outcome::Ok(Vec::new())
+        }
+
+        async fn query_one(
+            &self,
+            _cx: &Cx,
+            _sql: &str,
+            _params: &[Value],
+        ) -> Outcome<Option<Row>, crate::Error> {
+            Outcome::Ok(None)
+        }
+
+        async fn execute(
+            &self,
+            _cx: &Cx,
+            sql: &str,
+            _params: &[Value],
+        ) -> Outcome<u64, crate::Error> {
+            self.record(sql.to_string()).map(|()| 1)
+        }
+
+        async fn savepoint(&self, _cx: &Cx, name: &str) -> Outcome<(), crate::Error> {
+            self.record(format!("SAVEPOINT {name}"))
+        }
+
+        async fn rollback_to(&self, _cx: &Cx, name: &str) -> Outcome<(), crate::Error> {
+            self.record(format!("ROLLBACK TO SAVEPOINT {name}"))
+        }
+
+        async fn release(&self, _cx: &Cx, name: &str) -> Outcome<(), crate::Error> {
+            self.record(format!("RELEASE SAVEPOINT {name}"))
+        }
+
+        async fn commit(self, _cx: &Cx) -> Outcome<(), crate::Error> {
+            self.record("COMMIT".to_string())
+        }
+
+        async fn rollback(self, _cx: &Cx) -> Outcome<(), crate::Error> {
+            self.record("ROLLBACK".to_string())
+        }
+    }
+
+    /// Connection whose transactions share its statement log.
+    #[derive(Default)]
+    struct RecordingConn {
+        executed: std::sync::Arc<std::sync::Mutex<Vec<String>>>,
+    }
+
+    impl Connection for RecordingConn {
+        type Tx<'conn> = RecordingTx;
+
+        async fn query(
+            &self,
+            _cx: &Cx,
+            _sql: &str,
+            _params: &[Value],
+        ) -> Outcome<Vec<Row>, crate::Error> {
+            Outcome::Ok(Vec::new())
+        }
+
+        async fn query_one(
+            &self,
+            _cx: &Cx,
+            _sql: &str,
+            _params: &[Value],
+        ) -> Outcome<Option<Row>, crate::Error> {
+            Outcome::Ok(None)
+        }
+
+        async fn execute(
+            &self,
+            _cx: &Cx,
+            sql: &str,
+            _params: &[Value],
+        ) -> Outcome<u64, crate::Error> {
+            self.executed.lock().unwrap().push(sql.to_string());
+            Outcome::Ok(1)
+        }
+
+        async fn insert(
+            &self,
+            _cx: &Cx,
+            _sql: &str,
+            _params: &[Value],
+        ) -> Outcome<i64, crate::Error> {
+            Outcome::Ok(0)
+        }
+
+        async fn batch(
+            &self,
+            _cx: &Cx,
+            _statements: &[(String, Vec<Value>)],
+        ) -> Outcome<Vec<u64>, crate::Error> {
+            Outcome::Ok(Vec::new())
+        }
+
+        async fn begin(&self, cx: &Cx) -> Outcome<Self::Tx<'_>, crate::Error> {
+            self.begin_with(cx, IsolationLevel::default()).await
+        }
+
+        async fn begin_with(
+            &self,
+            _cx: &Cx,
+            _isolation: IsolationLevel,
+        ) -> Outcome<Self::Tx<'_>, crate::Error> {
+            self.executed.lock().unwrap().push("BEGIN".to_string());
+            Outcome::Ok(RecordingTx {
+                executed: std::sync::Arc::clone(&self.executed),
+            })
+        }
+
+        async fn prepare(&self, _cx: &Cx, sql: &str) -> Outcome<PreparedStatement, crate::Error> {
+            Outcome::Ok(PreparedStatement::new(0, sql.to_string(), 0))
+        }
+
+        async fn query_prepared(
+            &self,
+            _cx: &Cx,
+            _stmt: &PreparedStatement,
+            _params: &[Value],
+        ) -> Outcome<Vec<Row>, crate::Error> {
+            Outcome::Ok(Vec::new())
+        }
+
+        async fn execute_prepared(
+            &self,
+            _cx: &Cx,
+            _stmt: &PreparedStatement,
+            _params: &[Value],
+        ) -> Outcome<u64, crate::Error> {
+            Outcome::Ok(0)
+        }
+
+        async fn ping(&self, _cx: &Cx) -> Outcome<(), crate::Error> {
+            Outcome::Ok(())
+        }
+
+        async fn close(self, _cx: &Cx) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_transaction_scope_commits_or_rolls_back() {
+        let rt = asupersync::runtime::RuntimeBuilder::current_thread()
+            .build()
+            .unwrap();
+        let cx = &Cx::for_testing();
+        let conn = RecordingConn::default();
+
+        rt.block_on(async {
+            let rows = conn
+                .transaction_scope(cx, |tx| {
+                    Box::pin(async move { tx.execute(cx, "INSERT INTO t VALUES (1)", &[]).await })
+                })
+                .await;
+            assert_eq!(unwrap_outcome(rows), 1);
+
+            let failed: Outcome<(), crate::Error> = conn
+                .transaction_scope(cx, |tx| {
+                    Box::pin(async move {
+                        match tx.execute(cx, "INSERT INTO t VALUES (2)", &[]).await {
+                            Outcome::Ok(_) => {
+                                Outcome::Err(crate::Error::Custom("boom".to_string()))
+                            }
+                            other => other.map(|_| ()),
+                        }
+                    })
+                })
+                .await;
+            assert!(matches!(failed, Outcome::Err(crate::Error::Custom(ref m)) if m == "boom"));
+        });
+
+        assert_eq!(
+            *conn.executed.lock().unwrap(),
+            [
+                "BEGIN",
+                "INSERT INTO t VALUES (1)",
+                "COMMIT",
+                "BEGIN",
+                "INSERT INTO t VALUES (2)",
+                "ROLLBACK",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_savepoint_scope_ends_under_its_own_name() {
+        let rt = asupersync::runtime::RuntimeBuilder::current_thread()
+            .build()
+            .unwrap();
+        let cx = Cx::for_testing();
+        let tx = RecordingTx::default();
+
+        rt.block_on(async {
+            let outer = unwrap_outcome(tx.savepoint_scope(&cx, "outer").await);
+            let inner = unwrap_outcome(tx.savepoint_scope(&cx, "inner").await);
+            assert_eq!(inner.name(), "inner");
+            unwrap_outcome(tx.execute(&cx, "DELETE FROM t", &[]).await);
+            unwrap_outcome(inner.rollback(&cx).await);
+            unwrap_outcome(outer.release(&cx).await);
+
+            // Dropping an unfinished savepoint only warns.
+            drop(unwrap_outcome(tx.savepoint_scope(&cx, "leaked").await));
+        });
+
+        assert_eq!(
+            *tx.executed.lock().unwrap(),
+            [
+                "SAVEPOINT outer",
+                "SAVEPOINT inner",
+                "DELETE FROM t",
+                "ROLLBACK TO SAVEPOINT inner",
+                "RELEASE SAVEPOINT outer",
+                "SAVEPOINT leaked",
+            ]
+        );
+    }
+
     #[test]
     fn test_isolation_level_as_sql() {
         assert_eq!(IsolationLevel::ReadUncommitted.as_sql(), "READ UNCOMMITTED");
@@ -663,6 +1634,194 @@ mod tests {
         assert_eq!(IsolationLevel::Serializable.as_sql(), "SERIALIZABLE");
     }
 
+    #[test]
+    fn test_transaction_mode_begin_sql() {
+        assert_eq!(TransactionMode::default().begin_sql(), "BEGIN");
+        assert_eq!(
+            TransactionMode::new(IsolationLevel::RepeatableRead).begin_sql(),
+            "BEGIN ISOLATION LEVEL REPEATABLE READ"
+        );
+        assert_eq!(
+            TransactionMode::default().read_only(true).begin_sql(),
+            "BEGIN READ ONLY"
+        );
+        assert_eq!(
+            TransactionMode::new(IsolationLevel::Serializable)
+                .read_only(true)
+                .deferrable(true)
+                .begin_sql(),
+            "BEGIN ISOLATION LEVEL SERIALIZABLE READ ONLY DEFERRABLE"
+        );
+    }
+
+    #[test]
+    fn test_transaction_mode_begin_statements() {
+        let mode = TransactionMode::new(IsolationLevel::Serializable).read_only(true);
+        assert_eq!(
+            mode.begin_statements(Dialect::Postgres).unwrap(),
+            ["BEGIN ISOLATION LEVEL SERIALIZABLE READ ONLY"]
+        );
+        assert_eq!(
+            mode.begin_statements(Dialect::Mysql).unwrap(),
+            [
+                "SET TRANSACTION ISOLATION LEVEL SERIALIZABLE",
+                "START TRANSACTION READ ONLY"
+            ]
+        );
+        assert_eq!(
+            TransactionMode::default()
+                .begin_statements(Dialect::Mysql)
+                .unwrap(),
+            ["BEGIN"]
+        );
+        assert_eq!(
+            TransactionMode::new(IsolationLevel::ReadCommitted)
+                .begin_statements(Dialect::Sqlite)
+                .unwrap(),
+            ["BEGIN IMMEDIATE"]
+        );
+        assert_eq!(
+            mode.begin_statements(Dialect::Sqlite).unwrap(),
+            ["BEGIN EXCLUSIVE"]
+        );
+        assert!(matches!(
+            TransactionMode::default()
+                .deferrable(true)
+                .begin_statements(Dialect::Mysql),
+            Err(Error::Config(_))
+        ));
+    }
+
+    #[test]
+    fn test_dialect_literals() {
+        assert_eq!(Dialect::Postgres.bool_literal(true), "TRUE");
+        assert_eq!(Dialect::Sqlite.bool_literal(false), "0");
+        assert_eq!(
+            Dialect::Mysql.keyword_literal(&Value::Bool(true)),
+            Some("1")
+        );
+        assert_eq!(Dialect::Sqlite.keyword_literal(&Value::Null), Some("NULL"));
+        assert_eq!(
+            Dialect::Mysql.keyword_literal(&Value::TypedNull(crate::SqlType::Integer)),
+            Some("NULL")
+        );
+        assert_eq!(
+            Dialect::Postgres.keyword_literal(&Value::Default),
+            Some("DEFAULT")
+        );
+        assert_eq!(Dialect::Postgres.keyword_literal(&Value::Int(1)), None);
+
+        assert_eq!(Dialect::Postgres.default_expr("true"), "TRUE");
+        assert_eq!(Dialect::Sqlite.default_expr("TRUE"), "1");
+        assert_eq!(Dialect::Sqlite.default_expr(" False "), "0");
+        assert_eq!(Dialect::Mysql.default_expr("null"), "NULL");
+        assert_eq!(Dialect::Postgres.default_expr("'true'"), "'true'");
+        assert_eq!(
+            Dialect::Sqlite.default_expr("CURRENT_TIMESTAMP"),
+            "CURRENT_TIMESTAMP"
+        );
+    }
+
+    #[test]
+    fn test_dialect_limit_offset() {
+        let big = u64::MAX;
+        assert_eq!(Dialect::Postgres.limit_offset(None, None), "");
+        assert_eq!(
+            Dialect::Postgres.limit_offset(Some(10), Some(20)),
+            " LIMIT 10 OFFSET 20"
+        );
+        assert_eq!(Dialect::Postgres.limit_offset(None, Some(20)), " OFFSET 20");
+        assert_eq!(
+            Dialect::Postgres.limit_offset(Some(big), Some(big)),
+            " LIMIT 9223372036854775807 OFFSET 9223372036854775807"
+        );
+
+        assert_eq!(Dialect::Sqlite.limit_offset(Some(10), None), " LIMIT 10");
+        assert_eq!(
+            Dialect::Sqlite.limit_offset(Some(10), Some(20)),
+            " LIMIT 10 OFFSET 20"
+        );
+        assert_eq!(
+            Dialect::Sqlite.limit_offset(None, Some(20)),
+            " LIMIT -1 OFFSET 20"
+        );
+        assert_eq!(
+            Dialect::Sqlite.limit_offset(None, Some(big)),
+            " LIMIT -1 OFFSET 9223372036854775807"
+        );
+
+        assert_eq!(Dialect::Mysql.limit_offset(Some(10), None), " LIMIT 10");
+        assert_eq!(
+            Dialect::Mysql.limit_offset(Some(10), Some(20)),
+            " LIMIT 20, 10"
+        );
+        assert_eq!(
+            Dialect::Mysql.limit_offset(None, Some(20)),
+            " LIMIT 20, 18446744073709551615"
+        );
+    }
+
+    #[test]
+    fn test_dialect_tuple_in_clause() {
+        let tuples = vec![
+            vec![Value::BigInt(1), Value::BigInt(10)],
+            vec![Value::BigInt(2), Value::BigInt(20)],
+        ];
+        let columns = ["order_id", "product_id"];
+
+        let (sql, params) = Dialect::Postgres.tuple_in_clause(&columns, &tuples, 2);
+        assert_eq!(
+            sql,
+            "(\"order_id\", \"product_id\") IN (($2, $3), ($4, $5))"
+        );
+        assert_eq!(
+            params,
+            vec![
+                Value::BigInt(1),
+                Value::BigInt(10),
+                Value::BigInt(2),
+                Value::BigInt(20)
+            ]
+        );
+
+        let (sql, params) = Dialect::Sqlite.tuple_in_clause(&columns, &tuples, 1);
+        assert_eq!(
+            sql,
+            "((\"order_id\" = ?1 AND \"product_id\" = ?2) OR (\"order_id\" = ?3 AND \"product_id\" = ?4))"
+        );
+        assert_eq!(params.len(), 4);
+
+        let (sql, _) = Dialect::Mysql.tuple_in_clause(&columns, &tuples[..1], 1);
+        assert_eq!(sql, "((`order_id` = ? AND `product_id` = ?))");
+
+        let (sql, params) = Dialect::Postgres.tuple_in_clause(&["id"], &[vec![Value::Int(7)]], 1);
+        assert_eq!(sql, "\"id\" IN ($1)");
+        assert_eq!(params, vec![Value::Int(7)]);
+
+        let (sql, params) = Dialect::Sqlite.tuple_in_clause(&columns, &[], 1);
+        assert_eq!(sql, "1=0");
+        assert!(params.is_empty());
+    }
+
+    #[test]
+    fn test_dialect_in_clause() {
+        let values = [Value::BigInt(7), Value::BigInt(8), Value::BigInt(9)];
+
+        let (sql, params) = Dialect::Postgres.in_clause("id", &values, 3);
+        assert_eq!(sql, "\"id\" IN ($3, $4, $5)");
+        assert_eq!(params, values.to_vec());
+
+        let (sql, _) = Dialect::Sqlite.in_clause("id", &values[..2], 1);
+        assert_eq!(sql, "\"id\" IN (?1, ?2)");
+
+        let (sql, _) = Dialect::Mysql.in_clause("id", &values[..1], 4);
+        assert_eq!(sql, "`id` IN (?)");
+
+        let (sql, params) = Dialect::Postgres.in_clause("id", &[], 1);
+        assert_eq!(sql, "1=0");
+        assert!(params.is_empty());
+    }
+
     #[test]
     fn test_prepared_statement_new() {
         let stmt = PreparedStatement::new(1, "SELECT * FROM users WHERE id = $1".to_string(), 1);
@@ -2,6 +2,8 @@
 
 use std::fmt;
 
+use crate::value::Value;
+
 /// The primary error type for all SQLModel operations.
 #[derive(Debug)]
 pub enum Error {
@@ -23,6 +25,8 @@ pub enum Error {
     Config(ConfigError),
     /// Validation errors
     Validation(ValidationError),
+    /// A flushed UPDATE or DELETE matched no row
+    StaleObject(StaleObjectError),
     /// I/O errors
     Io(std::io::Error),
     /// Operation timed out
@@ -68,6 +72,17 @@ pub struct QueryError {
     pub message: String,
     pub detail: Option<String>,
     pub hint: Option<String>,
+    /// Schema of the object the error concerns (PostgreSQL `s` field)
+    pub schema: Option<String>,
+    /// Table the error concerns (PostgreSQL `t` field)
+    pub table: Option<String>,
+    /// Column the error concerns (PostgreSQL `c` field)
+    pub column: Option<String>,
+    /// Name of the violated constraint (PostgreSQL `n` field)
+    pub constraint: Option<String>,
+    /// Context the error occurred in, e.g. a PL/pgSQL call stack
+    /// (PostgreSQL `W` field)
+    pub where_: Option<String>,
     pub position: Option<usize>,
     pub source: Option<Box<dyn std::error::Error + Send + Sync>>,
 }
@@ -120,6 +135,10 @@ pub enum TransactionErrorKind {
     SavepointNotFound,
     /// Nested transaction not supported
     NestedNotSupported,
+    /// Aborted by an earlier error; only a rollback is accepted
+    Aborted,
+    /// Owned by an outer transaction manager, not the session
+    External,
 }
 
 #[derive(Debug)]
@@ -198,6 +217,20 @@ pub struct ConfigError {
     pub source: Option<Box<dyn std::error::Error + Send + Sync>>,
 }
 
+/// A single-row UPDATE or DELETE issued by a flush affected no rows.
+///
+/// The row was deleted or had its primary key changed by someone else after
+/// the object was loaded, so the in-memory object no longer describes it.
+#[derive(Debug, Clone)]
+pub struct StaleObjectError {
+    /// Table the object maps to.
+    pub table: String,
+    /// Primary key values of the object, in key column order.
+    pub pk: Vec<Value>,
+    /// The statement that matched nothing (`"UPDATE"` or `"DELETE"`).
+    pub statement: &'static str,
+}
+
 /// Validation error for field-level and model-level validation.
 #[derive(Debug, Clone)]
 pub struct ValidationError {
@@ -245,6 +278,8 @@ pub enum ValidationErrorKind {
     UniqueItems,
     /// Invalid credit card number (Luhn check failed)
     CreditCard,
+    /// A CHECK constraint evaluated to false
+    Check,
 }
 
 impl ValidationError {
@@ -407,6 +442,19 @@ impl ValidationError {
         );
     }
 
+    /// Add a CHECK constraint violation.
+    pub fn add_check(&mut self, field: impl Into<String>, expr: &str) {
+        self.add(
+            field,
+            ValidationErrorKind::Check,
+            if expr.starts_with('(') && expr.ends_with(')') {
+                format!("violates CHECK {expr}")
+            } else {
+                format!("violates CHECK ({expr})")
+            },
+        );
+    }
+
     /// Convert to Result, returning Ok(()) if no errors, Err(self) otherwise.
     pub fn into_result(self) -> std::result::Result<(), Self> {
         if self.is_empty() { Ok(()) } else { Err(self) }
@@ -474,6 +522,14 @@ impl Error {
             _ => None,
         }
     }
+
+    /// Get the name of the violated constraint, if the backend reported it
+    pub fn constraint(&self) -> Option<&str> {
+        match self {
+            Error::Query(q) => q.constraint.as_deref(),
+            _ => None,
+        }
+    }
 }
 
 impl QueryError {
@@ -486,6 +542,13 @@ impl QueryError {
     pub fn is_foreign_key_violation(&self) -> bool {
         self.sqlstate.as_deref() == Some("23503")
     }
+
+    /// Is this a violation of the constraint named `name`?
+    ///
+    /// Only backends that report the constraint name (PostgreSQL) can match.
+    pub fn is_constraint(&self, name: &str) -> bool {
+        self.constraint.as_deref() == Some(name)
+    }
 }
 
 impl fmt::Display for Error {
@@ -516,6 +579,7 @@ impl fmt::Display for Error {
             Error::Schema(e) => write!(f, "Schema error: {}", e.message),
             Error::Config(e) => write!(f, "Configuration error: {}", e.message),
             Error::Validation(e) => write!(f, "Validation error: {}", e),
+            Error::StaleObject(e) => write!(f, "Stale object: {}", e),
             Error::Io(e) => write!(f, "I/O error: {}", e),
             Error::Timeout => write!(f, "Operation timed out"),
             Error::Cancelled => write!(f, "Operation cancelled"),
@@ -637,6 +701,18 @@ impl fmt::Display for ValidationError {
 
 impl std::error::Error for ValidationError {}
 
+impl fmt::Display for StaleObjectError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} on '{}' with primary key {:?} affected no rows",
+            self.statement, self.table, self.pk
+        )
+    }
+}
+
+impl std::error::Error for StaleObjectError {}
+
 impl From<std::io::Error> for Error {
     fn from(err: std::io::Error) -> Self {
         Error::Io(err)
@@ -697,6 +773,12 @@ impl From<ValidationError> for Error {
     }
 }
 
+impl From<StaleObjectError> for Error {
+    fn from(err: StaleObjectError) -> Self {
+        Error::StaleObject(err)
+    }
+}
+
 /// Result type alias for SQLModel operations.
 pub type Result<T> = std::result::Result<T, Error>;
 
@@ -713,16 +795,24 @@ mod tests {
             message: "unique violation".to_string(),
             detail: None,
             hint: None,
+            schema: None,
+            table: None,
+            column: None,
+            constraint: Some("users_email_key".to_string()),
+            where_: None,
             position: None,
             source: None,
         };
 
         assert!(query.is_unique_violation());
         assert!(!query.is_foreign_key_violation());
+        assert!(query.is_constraint("users_email_key"));
+        assert!(!query.is_constraint("users_pkey"));
 
         let err = Error::Query(query);
         assert_eq!(err.sqlstate(), Some("23505"));
         assert_eq!(err.sql(), Some("SELECT 1"));
+        assert_eq!(err.constraint(), Some("users_email_key"));
     }
 
     #[test]
@@ -734,6 +824,11 @@ mod tests {
             message: "deadlock detected".to_string(),
             detail: None,
             hint: None,
+            schema: None,
+            table: None,
+            column: None,
+            constraint: None,
+            where_: None,
             position: None,
             source: None,
         });
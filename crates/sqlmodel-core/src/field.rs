@@ -1,6 +1,7 @@
 //! Field and column definitions.
 
-use crate::types::SqlType;
+use crate::connection::Dialect;
+use crate::types::{SqlEnum, SqlType};
 
 /// Referential action for foreign key constraints (ON DELETE / ON UPDATE).
 ///
@@ -51,6 +52,90 @@ impl ReferentialAction {
     }
 }
 
+/// A user-defined database type that a column depends on.
+///
+/// Postgres requires these to exist before any table references them, so the
+/// DDL generator emits `CREATE TYPE` / `CREATE DOMAIN` ahead of `CREATE TABLE`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CustomSqlType {
+    /// An enumerated type: `CREATE TYPE name AS ENUM (variants...)`.
+    Enum {
+        /// Type name.
+        name: &'static str,
+        /// Allowed labels, in declaration order.
+        variants: &'static [&'static str],
+    },
+    /// A constrained domain: `CREATE DOMAIN name AS base_type [CHECK (check)]`.
+    Domain {
+        /// Domain name.
+        name: &'static str,
+        /// Underlying SQL type (e.g., "TEXT", "INTEGER").
+        base_type: &'static str,
+        /// Optional CHECK expression, written in terms of `VALUE`.
+        check: Option<&'static str>,
+    },
+}
+
+impl CustomSqlType {
+    /// Build the enum definition for a [`SqlEnum`] type.
+    #[must_use]
+    pub const fn from_enum<E: SqlEnum>() -> Self {
+        CustomSqlType::Enum {
+            name: E::TYPE_NAME,
+            variants: E::VARIANTS,
+        }
+    }
+
+    /// Get the type name.
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            CustomSqlType::Enum { name, .. } | CustomSqlType::Domain { name, .. } => name,
+        }
+    }
+
+    /// The built-in type used where custom types are unsupported (SQLite, MySQL).
+    ///
+    /// Enums fall back to `TEXT`; domains to their underlying type.
+    #[must_use]
+    pub const fn base_type(&self) -> &'static str {
+        match self {
+            CustomSqlType::Enum { .. } => "TEXT",
+            CustomSqlType::Domain { base_type, .. } => base_type,
+        }
+    }
+}
+
+/// How console output should present a column's values.
+///
+/// Set with `#[sqlmodel(display = "...")]`; query result tables built for a
+/// model apply it to the matching column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DisplayHint {
+    /// A digest: short lowercase hex, like an abbreviated commit hash.
+    Hash,
+    /// A money amount: rounded to this many decimals, thousands grouped.
+    Currency(u8),
+    /// Binary data shown as described.
+    Bytes(BytesDisplay),
+    /// A fraction shown as a percentage (`0.125` -> `12.5%`).
+    Percent,
+}
+
+/// Presentation of binary values for [`DisplayHint::Bytes`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BytesDisplay {
+    /// Size marker only: `[BLOB: 5 bytes]`.
+    #[default]
+    Marker,
+    /// PostgreSQL-style hex: `\x48656c6c6f`.
+    Hex,
+    /// Standard base64: `SGVsbG8=`.
+    Base64,
+    /// Hex of at most this many bytes, followed by the total size.
+    HexTruncated(usize),
+}
+
 /// Metadata about a model field/column.
 #[derive(Debug, Clone)]
 pub struct FieldInfo {
@@ -63,6 +148,9 @@ pub struct FieldInfo {
     /// Explicit SQL type override string (e.g., "VARCHAR(255)", "DECIMAL(10,2)")
     /// When set, this takes precedence over `sql_type` in DDL generation.
     pub sql_type_override: Option<&'static str>,
+    /// User-defined enum or domain type backing this column.
+    /// Its name is used as the column type unless `sql_type_override` is set.
+    pub custom_type: Option<CustomSqlType>,
     /// Precision for DECIMAL/NUMERIC types (total digits)
     pub precision: Option<u8>,
     /// Scale for DECIMAL/NUMERIC types (digits after decimal point)
@@ -83,6 +171,10 @@ pub struct FieldInfo {
     pub on_delete: Option<ReferentialAction>,
     /// Referential action for ON UPDATE (only valid with foreign_key)
     pub on_update: Option<ReferentialAction>,
+    /// Whether the foreign key constraint is DEFERRABLE (only valid with foreign_key)
+    pub deferrable: bool,
+    /// Whether the foreign key is checked at commit by default (INITIALLY DEFERRED)
+    pub initially_deferred: bool,
     /// Index name if indexed
     pub index: Option<&'static str>,
     /// Alias for both input and output (like serde rename).
@@ -127,6 +219,9 @@ pub struct FieldInfo {
     /// SQL comment for the column (used in DDL generation).
     /// This maps to the COMMENT ON COLUMN or inline COMMENT clause depending on the database.
     pub column_comment: Option<&'static str>,
+    /// Collation for the column (e.g. `"C"`, `"NOCASE"`, `"utf8mb4_bin"`).
+    /// Emitted as a `COLLATE` clause in DDL and compared by schema diffing.
+    pub collation: Option<&'static str>,
     /// Extra metadata as JSON string (for custom extensions/info).
     /// This can be used to store additional information that doesn't fit in other fields.
     pub column_info: Option<&'static str>,
@@ -159,6 +254,8 @@ pub struct FieldInfo {
     /// }
     /// ```
     pub discriminator: Option<&'static str>,
+    /// How console output presents this column's values.
+    pub display: Option<DisplayHint>,
 }
 
 impl FieldInfo {
@@ -169,6 +266,7 @@ impl FieldInfo {
             column_name,
             sql_type,
             sql_type_override: None,
+            custom_type: None,
             precision: None,
             scale: None,
             nullable: false,
@@ -179,6 +277,8 @@ impl FieldInfo {
             foreign_key: None,
             on_delete: None,
             on_update: None,
+            deferrable: false,
+            initially_deferred: false,
             index: None,
             alias: None,
             validation_alias: None,
@@ -193,9 +293,11 @@ impl FieldInfo {
             const_field: false,
             column_constraints: &[],
             column_comment: None,
+            collation: None,
             column_info: None,
             hybrid_sql: None,
             discriminator: None,
+            display: None,
         }
     }
 
@@ -221,6 +323,24 @@ impl FieldInfo {
         self
     }
 
+    /// Back this column with a user-defined enum or domain type.
+    pub const fn custom_type(mut self, custom: CustomSqlType) -> Self {
+        self.custom_type = Some(custom);
+        self
+    }
+
+    /// Set the custom type from optional.
+    pub const fn custom_type_opt(mut self, custom: Option<CustomSqlType>) -> Self {
+        self.custom_type = custom;
+        self
+    }
+
+    /// Back this column with the database enum type for a [`SqlEnum`].
+    pub const fn sql_enum<E: SqlEnum>(mut self) -> Self {
+        self.custom_type = Some(CustomSqlType::from_enum::<E>());
+        self
+    }
+
     /// Set precision for DECIMAL/NUMERIC types.
     ///
     /// Precision is the total number of digits (before and after decimal point).
@@ -277,17 +397,36 @@ impl FieldInfo {
 
     /// Get the effective SQL type name for DDL generation.
     ///
+    /// This is the PostgreSQL spelling, naming any enum/domain type; use
+    /// [`effective_sql_type_for`](Self::effective_sql_type_for) for other dialects.
+    #[must_use]
+    pub fn effective_sql_type(&self) -> String {
+        self.effective_sql_type_for(Dialect::Postgres)
+    }
+
+    /// Get the effective SQL type name for DDL generation in `dialect`.
+    ///
     /// Priority:
     /// 1. `sql_type_override` if set
-    /// 2. For DECIMAL/NUMERIC: uses `precision` and `scale` fields if set
-    /// 3. Falls back to `sql_type.sql_name()`
+    /// 2. A custom enum/domain type: its name on PostgreSQL, otherwise its
+    ///    [`base_type`](CustomSqlType::base_type)
+    /// 3. For DECIMAL/NUMERIC: uses `precision` and `scale` fields if set
+    /// 4. Falls back to `sql_type.sql_name()`
     #[must_use]
-    pub fn effective_sql_type(&self) -> String {
+    pub fn effective_sql_type_for(&self, dialect: Dialect) -> String {
         // sql_type_override takes highest precedence
         if let Some(override_str) = self.sql_type_override {
             return override_str.to_string();
         }
 
+        // A user-defined enum/domain is referenced by name where it exists
+        if let Some(custom) = &self.custom_type {
+            return match dialect {
+                Dialect::Postgres => custom.name().to_string(),
+                Dialect::Sqlite | Dialect::Mysql => custom.base_type().to_string(),
+            };
+        }
+
         // For Decimal/Numeric types, use precision/scale fields if available
         match self.sql_type {
             SqlType::Decimal { .. } | SqlType::Numeric { .. } => {
@@ -383,6 +522,28 @@ impl FieldInfo {
         self
     }
 
+    /// Mark the foreign key constraint as DEFERRABLE.
+    ///
+    /// Deferrable constraints can be postponed to commit time with
+    /// `SET CONSTRAINTS ALL DEFERRED`, which is what allows inserting rows
+    /// that reference each other. This is only meaningful when `foreign_key`
+    /// is also set.
+    pub const fn deferrable(mut self, value: bool) -> Self {
+        self.deferrable = value;
+        self
+    }
+
+    /// Mark the foreign key constraint as DEFERRABLE INITIALLY DEFERRED.
+    ///
+    /// Setting this to `true` also makes the constraint deferrable.
+    pub const fn initially_deferred(mut self, value: bool) -> Self {
+        self.initially_deferred = value;
+        if value {
+            self.deferrable = true;
+        }
+        self
+    }
+
     /// Set index name.
     pub const fn index(mut self, name: &'static str) -> Self {
         self.index = Some(name);
@@ -600,6 +761,20 @@ impl FieldInfo {
         self
     }
 
+    /// Set the column collation.
+    ///
+    /// The name is dialect-specific and emitted as `COLLATE "<name>"`.
+    pub const fn collation(mut self, collation: &'static str) -> Self {
+        self.collation = Some(collation);
+        self
+    }
+
+    /// Set collation from optional.
+    pub const fn collation_opt(mut self, collation: Option<&'static str>) -> Self {
+        self.collation = collation;
+        self
+    }
+
     /// Set extra metadata as JSON string.
     ///
     /// This can be used for custom extensions or information
@@ -643,6 +818,18 @@ impl FieldInfo {
         self
     }
 
+    /// Set how console output presents this column.
+    pub const fn display(mut self, hint: DisplayHint) -> Self {
+        self.display = Some(hint);
+        self
+    }
+
+    /// Set the display hint from optional.
+    pub const fn display_opt(mut self, hint: Option<DisplayHint>) -> Self {
+        self.display = hint;
+        self
+    }
+
     /// Get the name to use when serializing (output).
     ///
     /// Priority: serialization_alias > alias > name
@@ -685,6 +872,117 @@ impl FieldInfo {
             || self.validation_alias.is_some()
             || self.serialization_alias.is_some()
     }
+
+    /// Whether a JSON Schema for the model lists this field as `required`.
+    ///
+    /// Required fields are non-nullable, stored (not computed) and have
+    /// neither a SQL nor a serialization default.
+    #[must_use]
+    pub const fn is_required(&self) -> bool {
+        !self.nullable && !self.computed && !self.has_default && self.default.is_none()
+    }
+
+    /// The declared SQL `default` as a value, when it is a plain literal.
+    ///
+    /// Understands `NULL`, `TRUE`/`FALSE`, single-quoted strings (with `''`
+    /// escapes) and numbers, optionally wrapped in parentheses. Expressions
+    /// the database evaluates itself, such as `CURRENT_TIMESTAMP` or
+    /// `gen_random_uuid()`, return `None`.
+    #[must_use]
+    pub fn default_value(&self) -> Option<crate::Value> {
+        let mut expr = self.default?.trim();
+        while let Some(inner) = expr.strip_prefix('(').and_then(|e| e.strip_suffix(')')) {
+            expr = inner.trim();
+        }
+
+        if expr.eq_ignore_ascii_case("null") {
+            return Some(crate::Value::Null);
+        }
+        if expr.eq_ignore_ascii_case("true") {
+            return Some(crate::Value::Bool(true));
+        }
+        if expr.eq_ignore_ascii_case("false") {
+            return Some(crate::Value::Bool(false));
+        }
+        if let Some(quoted) = expr.strip_prefix('\'').and_then(|e| e.strip_suffix('\'')) {
+            // Reject `'a' || 'b'` and friends: inside the quotes every `'` must be doubled.
+            if quoted.replace("''", "").contains('\'') {
+                return None;
+            }
+            return Some(crate::Value::Text(quoted.replace("''", "'")));
+        }
+        if let Ok(n) = expr.parse::<i64>() {
+            return Some(crate::Value::BigInt(n));
+        }
+        expr.parse::<f64>()
+            .ok()
+            .filter(|f| f.is_finite())
+            .map(crate::Value::Double)
+    }
+
+    /// JSON Schema for this field's property in `Model::json_schema`.
+    ///
+    /// The type comes from the custom enum labels or `sql_type`; nullable
+    /// fields accept `null` via `anyOf`. `title`, `description`, the
+    /// `default_json` value and `discriminator` are added when set, computed
+    /// fields are marked `readOnly`, and `schema_extra` is merged in last so
+    /// it can override anything generated.
+    #[must_use]
+    pub fn json_schema(&self) -> serde_json::Value {
+        use serde_json::json;
+
+        let mut schema = match &self.custom_type {
+            Some(CustomSqlType::Enum { variants, .. }) => {
+                json!({"type": "string", "enum": variants})
+            }
+            _ => self.sql_type.json_schema(),
+        };
+        if self.nullable {
+            schema = json!({"anyOf": [schema, {"type": "null"}]});
+        }
+
+        let mut map = match schema {
+            serde_json::Value::Object(map) => map,
+            _ => serde_json::Map::new(),
+        };
+        if let Some(title) = self.title {
+            map.insert("title".to_string(), json!(title));
+        }
+        if let Some(description) = self.description {
+            map.insert("description".to_string(), json!(description));
+        }
+        if let Some(default) = self
+            .default_json
+            .and_then(|d| serde_json::from_str::<serde_json::Value>(d).ok())
+        {
+            map.insert("default".to_string(), default);
+        }
+        if self.computed {
+            map.insert("readOnly".to_string(), json!(true));
+        }
+        if let Some(property) = self.discriminator {
+            map.insert(
+                "discriminator".to_string(),
+                json!({"propertyName": property}),
+            );
+        }
+        merge_schema_extra(&mut map, self.schema_extra);
+        serde_json::Value::Object(map)
+    }
+}
+
+/// Merge a JSON object given as a string into a schema object.
+///
+/// Extra data that is not a valid JSON object is ignored.
+pub(crate) fn merge_schema_extra(
+    schema: &mut serde_json::Map<String, serde_json::Value>,
+    extra: Option<&str>,
+) {
+    if let Some(serde_json::Value::Object(extra)) =
+        extra.and_then(|e| serde_json::from_str::<serde_json::Value>(e).ok())
+    {
+        schema.extend(extra);
+    }
 }
 
 /// A column reference used in queries.
@@ -1009,6 +1307,51 @@ mod tests {
         assert_eq!(field.effective_sql_type(), "MONEY");
     }
 
+    #[test]
+    fn test_effective_sql_type_uses_custom_type_name() {
+        enum Mood {
+            Happy,
+            Sad,
+        }
+
+        impl SqlEnum for Mood {
+            const VARIANTS: &'static [&'static str] = &["happy", "sad"];
+            const TYPE_NAME: &'static str = "mood";
+
+            fn to_sql_str(&self) -> &'static str {
+                match self {
+                    Mood::Happy => "happy",
+                    Mood::Sad => "sad",
+                }
+            }
+
+            fn from_sql_str(s: &str) -> Result<Self, String> {
+                match s {
+                    "happy" => Ok(Mood::Happy),
+                    "sad" => Ok(Mood::Sad),
+                    other => Err(format!("unknown mood: {other}")),
+                }
+            }
+        }
+
+        let field = FieldInfo::new("mood", "mood", SqlType::Text).sql_enum::<Mood>();
+        assert_eq!(field.effective_sql_type(), "mood");
+        // Dialects without enum types use the base type.
+        assert_eq!(field.effective_sql_type_for(Dialect::Sqlite), "TEXT");
+        assert_eq!(field.effective_sql_type_for(Dialect::Mysql), "TEXT");
+        assert_eq!(
+            field.custom_type,
+            Some(CustomSqlType::Enum {
+                name: "mood",
+                variants: &["happy", "sad"],
+            })
+        );
+
+        // An explicit override still wins.
+        let field = field.sql_type_override("TEXT");
+        assert_eq!(field.effective_sql_type(), "TEXT");
+    }
+
     #[test]
     fn test_effective_sql_type_uses_precision_scale() {
         let field = FieldInfo::new(
@@ -1363,6 +1706,16 @@ mod tests {
         assert_eq!(field2.column_comment, Some("User's display name"));
     }
 
+    #[test]
+    fn test_field_info_collation() {
+        let field1 = FieldInfo::new("email", "email", SqlType::Text);
+        assert_eq!(field1.collation, None);
+
+        let field2 = FieldInfo::new("email", "email", SqlType::Text).collation("NOCASE");
+        assert_eq!(field2.collation, Some("NOCASE"));
+        assert_eq!(field2.collation_opt(None).collation, None);
+    }
+
     #[test]
     fn test_field_info_column_info() {
         // Default should be None
@@ -1394,6 +1747,22 @@ mod tests {
         assert_eq!(field4.discriminator, Some("kind"));
     }
 
+    #[test]
+    fn test_field_info_deferrable() {
+        let field1 = FieldInfo::new("team_id", "team_id", SqlType::BigInt);
+        assert!(!field1.deferrable);
+        assert!(!field1.initially_deferred);
+
+        let field2 = FieldInfo::new("team_id", "team_id", SqlType::BigInt).deferrable(true);
+        assert!(field2.deferrable);
+        assert!(!field2.initially_deferred);
+
+        // initially_deferred implies deferrable
+        let field3 = FieldInfo::new("team_id", "team_id", SqlType::BigInt).initially_deferred(true);
+        assert!(field3.deferrable);
+        assert!(field3.initially_deferred);
+    }
+
     // =========================================================================
     // InheritanceStrategy Tests
     // =========================================================================
@@ -1495,4 +1864,26 @@ mod tests {
         assert_eq!(info.discriminator_column, Some("type"));
         assert!(info.is_base());
     }
+
+    #[test]
+    fn test_default_value_parses_literals() {
+        use crate::Value;
+
+        let value = |expr| {
+            FieldInfo::new("c", "c", SqlType::Text)
+                .default(expr)
+                .default_value()
+        };
+        assert_eq!(value("'it''s'"), Some(Value::Text("it's".to_string())));
+        assert_eq!(value("(0)"), Some(Value::BigInt(0)));
+        assert_eq!(value("-1.5"), Some(Value::Double(-1.5)));
+        assert_eq!(value("TRUE"), Some(Value::Bool(true)));
+        assert_eq!(value("null"), Some(Value::Null));
+        assert_eq!(value("CURRENT_TIMESTAMP"), None);
+        assert_eq!(value("'a' || 'b'"), None);
+        assert_eq!(
+            FieldInfo::new("c", "c", SqlType::Text).default_value(),
+            None
+        );
+    }
 }
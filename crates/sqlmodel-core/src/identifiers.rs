@@ -4,6 +4,9 @@
 //! (table names, column names, etc.) to prevent SQL injection and
 //! handle special characters.
 
+use crate::connection::Dialect;
+use crate::error::{Error, Result};
+
 /// Quote a SQL identifier using ANSI double-quoting.
 ///
 /// Embedded double-quotes are escaped by doubling them (`"` → `""`).
@@ -41,6 +44,50 @@ pub fn quote_ident_mysql(name: &str) -> String {
     format!("`{}`", name.replace('`', "``"))
 }
 
+/// Validate a SQL identifier and quote it for `dialect`.
+///
+/// Use this when building SQL from a name supplied at runtime, such as a
+/// column passed to a session method. Where [`quote_ident`] makes any string
+/// safe to embed, this also rejects names no table or column should have:
+/// empty names, names longer than the dialect allows, and names containing
+/// control characters or either identifier quote character (`"` or `` ` ``).
+///
+/// # Errors
+///
+/// Returns [`Error::Custom`] describing the first rule the name breaks.
+///
+/// # Examples
+///
+/// ```
+/// use sqlmodel_core::{Dialect, quote_ident_checked};
+///
+/// assert_eq!(quote_ident_checked("users", Dialect::Postgres).unwrap(), "\"users\"");
+/// assert_eq!(quote_ident_checked("users", Dialect::Mysql).unwrap(), "`users`");
+/// assert!(quote_ident_checked("users\"; DROP TABLE secrets; --", Dialect::Postgres).is_err());
+/// ```
+#[allow(clippy::result_large_err)]
+pub fn quote_ident_checked(name: &str, dialect: Dialect) -> Result<String> {
+    if name.is_empty() {
+        return Err(Error::Custom("SQL identifier cannot be empty".to_string()));
+    }
+    if let Some(max_len) = dialect.max_identifier_length() {
+        if name.len() > max_len {
+            return Err(Error::Custom(format!(
+                "SQL identifier '{name}' exceeds the {max_len}-byte limit"
+            )));
+        }
+    }
+    if let Some(c) = name
+        .chars()
+        .find(|&c| c == '"' || c == '`' || c.is_control())
+    {
+        return Err(Error::Custom(format!(
+            "SQL identifier {name:?} contains disallowed character {c:?}"
+        )));
+    }
+    Ok(dialect.quote_identifier(name))
+}
+
 /// Sanitize a SQL identifier by removing non-alphanumeric/underscore characters.
 ///
 /// Use this when quoting is not possible (e.g., PRAGMA commands, SHOW commands).
@@ -230,6 +277,44 @@ mod tests {
         assert_eq!(sanitize_identifier("naïve"), "nave");
     }
 
+    // ==================== Checked Quoting Tests ====================
+
+    #[test]
+    fn test_quote_ident_checked_quotes_per_dialect() {
+        assert_eq!(
+            quote_ident_checked("user_name", Dialect::Postgres).unwrap(),
+            "\"user_name\""
+        );
+        assert_eq!(
+            quote_ident_checked("user name", Dialect::Sqlite).unwrap(),
+            "\"user name\""
+        );
+        assert_eq!(
+            quote_ident_checked("select", Dialect::Mysql).unwrap(),
+            "`select`"
+        );
+    }
+
+    #[test]
+    fn test_quote_ident_checked_rejects_unsafe_names() {
+        assert!(quote_ident_checked("", Dialect::Postgres).is_err());
+        assert!(quote_ident_checked("users\"; DROP TABLE secrets; --", Dialect::Postgres).is_err());
+        assert!(quote_ident_checked("user`name", Dialect::Postgres).is_err());
+        assert!(quote_ident_checked("user\"name", Dialect::Mysql).is_err());
+        assert!(quote_ident_checked("user\0name", Dialect::Sqlite).is_err());
+        assert!(quote_ident_checked("user\nname", Dialect::Sqlite).is_err());
+    }
+
+    #[test]
+    fn test_quote_ident_checked_length_limit() {
+        let name = "a".repeat(64);
+        assert!(quote_ident_checked(&name[..63], Dialect::Postgres).is_ok());
+        assert!(quote_ident_checked(&name, Dialect::Postgres).is_err());
+        assert!(quote_ident_checked(&name, Dialect::Mysql).is_ok());
+        assert!(quote_ident_checked(&"a".repeat(65), Dialect::Mysql).is_err());
+        assert!(quote_ident_checked(&"a".repeat(200), Dialect::Sqlite).is_ok());
+    }
+
     #[test]
     fn test_sanitize_numbers_preserved() {
         assert_eq!(sanitize_identifier("table123"), "table123");
@@ -27,6 +27,7 @@
 // Re-export asupersync primitives for structured concurrency
 pub use asupersync::{Budget, Cx, Outcome, RegionId, TaskId};
 
+pub mod clock;
 pub mod connection;
 pub mod dynamic;
 pub mod error;
@@ -35,38 +36,47 @@ pub mod fields_set;
 pub mod hybrid;
 pub mod identifiers;
 pub mod model;
+pub mod progress;
 pub mod relationship;
+pub mod retry;
 pub mod row;
+pub mod script;
 pub mod tracked;
 pub mod types;
 pub mod validate;
 pub mod value;
 
+pub use clock::{Clock, FixedClock, SystemClock};
 pub use connection::{
-    Connection, Dialect, IsolationLevel, PreparedStatement, Transaction, TransactionInternal,
-    TransactionOps,
+    Capabilities, Connection, Dialect, IsolationLevel, PreparedStatement, Savepoint, Transaction,
+    TransactionInternal, TransactionMode, TransactionOps, TransactionStatus, UpsertSyntax,
+};
+pub use error::{
+    Error, FieldValidationError, Result, StaleObjectError, ValidationError, ValidationErrorKind,
 };
-pub use error::{Error, FieldValidationError, Result, ValidationError, ValidationErrorKind};
 pub use field::{
-    Column, Field, FieldInfo, InheritanceInfo, InheritanceStrategy, ReferentialAction,
+    BytesDisplay, Column, CustomSqlType, DisplayHint, Field, FieldInfo, InheritanceInfo,
+    InheritanceStrategy, ReferentialAction,
 };
 pub use fields_set::FieldsSet;
 pub use hybrid::Hybrid;
-pub use identifiers::{quote_ident, quote_ident_mysql, sanitize_identifier};
+pub use identifiers::{quote_ident, quote_ident_checked, quote_ident_mysql, sanitize_identifier};
 pub use model::{
     AttributeChange, AutoIncrement, ExtraFieldsBehavior, Model, ModelConfig, ModelEvents,
-    SoftDelete, Timestamps,
+    SoftDelete, TableConstraint, Timestamps,
 };
+pub use progress::ProgressReporter;
 pub use relationship::{
     Lazy, LazyLoader, LinkTableInfo, PassiveDeletes, Related, RelatedMany, RelationshipInfo,
     RelationshipKind, find_back_relationship, find_relationship, validate_back_populates,
 };
+pub use retry::{RetryPolicy, retry};
 pub use row::Row;
 pub use tracked::TrackedModel;
 pub use types::{SqlEnum, SqlType, TypeInfo};
 pub use validate::{
     DumpMode, DumpOptions, DumpResult, ModelDump, ModelValidate, SqlModelDump, SqlModelValidate,
     ValidateInput, ValidateOptions, ValidateResult, apply_serialization_aliases,
-    apply_validation_aliases,
+    apply_validation_aliases, evaluate_check, validate_checks,
 };
 pub use value::Value;
@@ -5,7 +5,7 @@
 //! `#[derive(Model)]` macro from `sqlmodel-macros`.
 
 use crate::Result;
-use crate::field::{FieldInfo, InheritanceInfo};
+use crate::field::{FieldInfo, InheritanceInfo, merge_schema_extra};
 use crate::relationship::RelationshipInfo;
 use crate::row::Row;
 use crate::value::Value;
@@ -136,6 +136,20 @@ impl ModelConfig {
     }
 }
 
+/// A table-level constraint declared on a model.
+///
+/// Unlike [`FieldInfo::column_constraints`](crate::FieldInfo), these may
+/// reference several columns. DDL generation names them
+/// `uk_<table>_<col1>_<col2>` and `ck_<table>_<n>` (1-based, in declaration
+/// order among the checks).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TableConstraint {
+    /// `CHECK (expression)`, e.g. `start_date < end_date`.
+    Check(&'static str),
+    /// `UNIQUE (columns...)` over the given column names.
+    Unique(&'static [&'static str]),
+}
+
 /// Trait for types that can be mapped to database tables.
 ///
 /// This trait provides metadata about the table structure and
@@ -169,6 +183,12 @@ pub trait Model: Sized + Send + Sync {
     /// no relationships can rely on the default empty slice.
     const RELATIONSHIPS: &'static [RelationshipInfo] = &[];
 
+    /// Table-level constraints spanning one or more columns.
+    ///
+    /// Populated by struct-level `#[sqlmodel(check = "...")]` and
+    /// `#[sqlmodel(unique = ("a", "b"))]` attributes.
+    const TABLE_CONSTRAINTS: &'static [TableConstraint] = &[];
+
     /// Inheritance metadata for this model.
     ///
     /// Returns information about table inheritance if this model participates
@@ -181,6 +201,22 @@ pub trait Model: Sized + Send + Sync {
     /// Get field metadata for all columns.
     fn fields() -> &'static [FieldInfo];
 
+    /// Field metadata for the primary key column(s), in
+    /// [`PRIMARY_KEY`](Self::PRIMARY_KEY) order.
+    ///
+    /// Each key entry is matched against a field's column name or Rust
+    /// name; entries with no matching field are skipped.
+    fn primary_key_fields() -> Vec<&'static FieldInfo> {
+        Self::PRIMARY_KEY
+            .iter()
+            .filter_map(|pk| {
+                Self::fields()
+                    .iter()
+                    .find(|f| f.column_name == *pk || f.name == *pk)
+            })
+            .collect()
+    }
+
     /// Convert this model instance to a row of values.
     fn to_row(&self) -> Vec<(&'static str, Value)>;
 
@@ -188,6 +224,32 @@ pub trait Model: Sized + Send + Sync {
     #[allow(clippy::result_large_err)]
     fn from_row(row: &Row) -> Result<Self>;
 
+    /// Construct a model instance from a row holding only some of its columns.
+    ///
+    /// Returns the instance and the names of the columns that came from the
+    /// row, so callers can track the object as partially loaded. Every
+    /// [`PRIMARY_KEY`](Self::PRIMARY_KEY) column must be in the row: a
+    /// placeholder key would identify a different (or no) row.
+    ///
+    /// `#[derive(Model)]` generates this: absent columns take the field's
+    /// declared `default` when it is a literal, then `None` for `Option`
+    /// fields, then `Default::default()` when the field type has one; any
+    /// other absent column is an error. This fallback decodes the row with
+    /// [`from_row`](Self::from_row), so hand-written models must be given
+    /// every column their `from_row` reads.
+    #[allow(clippy::result_large_err)]
+    fn from_partial_row(row: &Row) -> Result<(Self, Vec<&'static str>)> {
+        partial::check_primary_key::<Self>(row)?;
+        let obj = Self::from_row(row)?;
+        let loaded = obj
+            .to_row()
+            .into_iter()
+            .map(|(name, _)| name)
+            .filter(|name| row.contains_column(name))
+            .collect();
+        Ok((obj, loaded))
+    }
+
     /// If this is a joined-table inheritance *child* model, return the base (parent) table row.
     ///
     /// This enables query builders to implement joined inheritance DML (base+child insert/update/delete)
@@ -214,6 +276,36 @@ pub trait Model: Sized + Send + Sync {
         ModelConfig::new()
     }
 
+    /// Build an OpenAPI-compatible JSON Schema object for this model.
+    ///
+    /// Every field not marked `exclude` becomes a property named by its
+    /// `output_name()`, described by [`FieldInfo::json_schema`]. Fields that
+    /// are non-nullable and have no default are listed in `required`. The
+    /// model config's `title` and `json_schema_extra` apply at the top level.
+    fn json_schema() -> serde_json::Value {
+        let mut properties = serde_json::Map::new();
+        let mut required = Vec::new();
+        for field in Self::fields().iter().filter(|f| !f.exclude) {
+            properties.insert(field.output_name().to_string(), field.json_schema());
+            if field.is_required() {
+                required.push(serde_json::Value::from(field.output_name()));
+            }
+        }
+
+        let config = Self::model_config();
+        let mut schema = serde_json::Map::new();
+        if let Some(title) = config.title {
+            schema.insert("title".to_string(), title.into());
+        }
+        schema.insert("type".to_string(), "object".into());
+        schema.insert("properties".to_string(), properties.into());
+        if !required.is_empty() {
+            schema.insert("required".to_string(), required.into());
+        }
+        merge_schema_extra(&mut schema, config.json_schema_extra);
+        serde_json::Value::Object(schema)
+    }
+
     /// The shard key field name for horizontal sharding.
     ///
     /// Returns `None` if the model doesn't use sharding. When set,
@@ -229,6 +321,22 @@ pub trait Model: Sized + Send + Sync {
     fn shard_key_value(&self) -> Option<Value> {
         None
     }
+
+    /// Column that marks a row as soft-deleted (e.g. `deleted_at`).
+    ///
+    /// Set via `#[sqlmodel(soft_delete = "field")]` on models implementing
+    /// [`SoftDelete`]. A row is live while this column is `NULL`, so DDL
+    /// generation enforces unique fields only among live rows, letting a
+    /// value be reused once the row holding it is soft-deleted.
+    const SOFT_DELETE_COLUMN: Option<&'static str> = None;
+
+    /// Integer column used for optimistic concurrency control.
+    ///
+    /// Set via `#[sqlmodel(version)]`. When present, the session increments
+    /// it on every UPDATE and adds `AND version = <old>` to the WHERE clause,
+    /// failing the flush with `Error::StaleObject` if another writer bumped
+    /// it first.
+    const VERSION_COLUMN: Option<&'static str> = None;
 }
 
 /// Marker trait for models that support automatic ID generation.
@@ -247,6 +355,10 @@ pub trait Timestamps: Model {
 }
 
 /// Trait for soft-deletable models.
+///
+/// Pair it with `#[sqlmodel(soft_delete = "deleted_at")]` so schema generation
+/// knows which column holds the deletion marker; see
+/// [`Model::SOFT_DELETE_COLUMN`].
 pub trait SoftDelete: Model {
     /// Mark the model as deleted.
     fn mark_deleted(&mut self);
@@ -405,6 +517,79 @@ pub struct AttributeChange {
     pub new_value: serde_json::Value,
 }
 
+/// Support code for the `from_partial_row` that `#[derive(Model)]` generates.
+#[doc(hidden)]
+pub mod partial {
+    use super::Model;
+    use crate::Result;
+    use crate::error::{Error, TypeError};
+    use crate::row::Row;
+    use std::marker::PhantomData;
+
+    /// Fail unless every primary key column of `M` is in `row`.
+    #[allow(clippy::result_large_err)]
+    pub fn check_primary_key<M: Model>(row: &Row) -> Result<()> {
+        match M::PRIMARY_KEY.iter().find(|pk| !row.contains_column(pk)) {
+            Some(pk) => Err(Error::Type(TypeError {
+                expected: "primary key column",
+                actual: format!("column '{pk}' not in partial row for {}", M::TABLE_NAME),
+                column: Some((*pk).to_string()),
+                rust_type: None,
+            })),
+            None => Ok(()),
+        }
+    }
+
+    /// The error for an absent column with nothing to fill it from.
+    #[must_use]
+    pub fn missing_column<M: Model>(column: &str) -> Error {
+        Error::Type(TypeError {
+            expected: "loaded column or declared default",
+            actual: format!(
+                "column '{column}' not in partial row for {} and has no default",
+                M::TABLE_NAME
+            ),
+            column: Some(column.to_string()),
+            rust_type: None,
+        })
+    }
+
+    /// Yields `T::default()` when `T: Default` and `None` otherwise.
+    ///
+    /// Call as `(&DefaultProbe::<T>::new()).default_value()` with both
+    /// [`ViaDefault`] and [`ViaNoDefault`] in scope: method lookup tries the
+    /// by-value receiver first, so the `Default` impl wins whenever it applies.
+    pub struct DefaultProbe<T>(PhantomData<T>);
+
+    impl<T> DefaultProbe<T> {
+        #[must_use]
+        #[allow(clippy::new_without_default)]
+        pub const fn new() -> Self {
+            Self(PhantomData)
+        }
+    }
+
+    pub trait ViaDefault<T> {
+        fn default_value(&self) -> Option<T>;
+    }
+
+    impl<T: Default> ViaDefault<T> for DefaultProbe<T> {
+        fn default_value(&self) -> Option<T> {
+            Some(T::default())
+        }
+    }
+
+    pub trait ViaNoDefault<T> {
+        fn default_value(&self) -> Option<T>;
+    }
+
+    impl<T> ViaNoDefault<T> for &DefaultProbe<T> {
+        fn default_value(&self) -> Option<T> {
+            None
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -440,11 +625,146 @@ mod tests {
         }
     }
 
+    #[derive(Debug, Default)]
+    struct Note {
+        id: i64,
+        title: String,
+        body: String,
+    }
+
+    impl Model for Note {
+        const TABLE_NAME: &'static str = "notes";
+        const PRIMARY_KEY: &'static [&'static str] = &["id"];
+
+        fn fields() -> &'static [FieldInfo] {
+            &[]
+        }
+
+        fn to_row(&self) -> Vec<(&'static str, Value)> {
+            vec![
+                ("id", Value::BigInt(self.id)),
+                ("title", Value::Text(self.title.clone())),
+                ("body", Value::Text(self.body.clone())),
+            ]
+        }
+
+        fn from_row(row: &Row) -> Result<Self> {
+            Ok(Self {
+                id: row.get_named("id")?,
+                title: row.get_named("title")?,
+                body: row.get_named("body")?,
+            })
+        }
+
+        fn primary_key_value(&self) -> Vec<Value> {
+            vec![Value::BigInt(self.id)]
+        }
+
+        fn is_new(&self) -> bool {
+            false
+        }
+    }
+
+    #[test]
+    fn test_from_partial_row() {
+        let row = Row::new(
+            vec!["id".to_string(), "title".to_string(), "body".to_string()],
+            vec![
+                Value::BigInt(7),
+                Value::Text("Groceries".to_string()),
+                Value::Text(String::new()),
+            ],
+        );
+        let (note, loaded) = Note::from_partial_row(&row).unwrap();
+        assert_eq!(note.id, 7);
+        assert_eq!(note.title, "Groceries");
+        assert_eq!(loaded, ["id", "title", "body"]);
+
+        // Hand-written models have no defaults to fall back on.
+        let row = Row::new(
+            vec!["id".to_string(), "title".to_string()],
+            vec![Value::BigInt(7), Value::Text("Groceries".to_string())],
+        );
+        assert!(Note::from_partial_row(&row).is_err());
+
+        // The key is never defaulted.
+        let row = Row::new(
+            vec!["title".to_string()],
+            vec![Value::Text("x".to_string())],
+        );
+        assert!(matches!(
+            Note::from_partial_row(&row),
+            Err(crate::Error::Type(ref e)) if e.column.as_deref() == Some("id")
+        ));
+    }
+
+    #[test]
+    fn test_default_probe() {
+        use partial::{DefaultProbe, ViaDefault as _, ViaNoDefault as _};
+
+        assert_eq!(
+            (&DefaultProbe::<String>::new()).default_value(),
+            Some(String::new())
+        );
+        assert!((&DefaultProbe::<Note>::new()).default_value().is_some());
+        assert!(
+            (&DefaultProbe::<TestModel>::new())
+                .default_value()
+                .is_none()
+        );
+    }
+
     #[test]
     fn test_default_relationships_is_empty() {
         assert!(TestModel::RELATIONSHIPS.is_empty());
     }
 
+    struct Enrollment;
+
+    impl Model for Enrollment {
+        const TABLE_NAME: &'static str = "enrollments";
+        const PRIMARY_KEY: &'static [&'static str] = &["course_id", "student"];
+
+        fn fields() -> &'static [FieldInfo] {
+            static FIELDS: &[FieldInfo] = &[
+                FieldInfo::new("student", "student_id", SqlType::BigInt).primary_key(true),
+                FieldInfo::new("grade", "grade", SqlType::Text),
+                FieldInfo::new("course_id", "course_id", SqlType::Integer).primary_key(true),
+            ];
+            FIELDS
+        }
+
+        fn to_row(&self) -> Vec<(&'static str, Value)> {
+            vec![]
+        }
+
+        fn from_row(_row: &Row) -> Result<Self> {
+            Ok(Self)
+        }
+
+        fn primary_key_value(&self) -> Vec<Value> {
+            vec![]
+        }
+
+        fn is_new(&self) -> bool {
+            false
+        }
+    }
+
+    #[test]
+    fn test_primary_key_fields_follow_primary_key_order() {
+        let fields = TestModel::primary_key_fields();
+        assert_eq!(fields.len(), 1);
+        assert_eq!(fields[0].sql_type, SqlType::Integer);
+
+        // Matched by column name or Rust field name, in PRIMARY_KEY order.
+        let columns: Vec<&str> = Enrollment::primary_key_fields()
+            .iter()
+            .map(|f| f.column_name)
+            .collect();
+        assert_eq!(columns, ["course_id", "student_id"]);
+    }
+
     // Test default ModelEvents implementation
     impl ModelEvents for TestModel {}
 
@@ -640,4 +960,65 @@ mod tests {
         assert!(!config.table);
         assert!(!config.from_attributes);
     }
+
+    #[derive(Debug)]
+    struct SchemaModel;
+
+    impl Model for SchemaModel {
+        const TABLE_NAME: &'static str = "heroes";
+        const PRIMARY_KEY: &'static [&'static str] = &["id"];
+
+        fn fields() -> &'static [FieldInfo] {
+            static FIELDS: &[FieldInfo] = &[
+                FieldInfo::new("id", "id", SqlType::BigInt)
+                    .primary_key(true)
+                    .nullable(true),
+                FieldInfo::new("name", "name", SqlType::VarChar(50))
+                    .alias("heroName")
+                    .description("Public name")
+                    .schema_extra(r#"{"examples": ["Deadpond"]}"#),
+                FieldInfo::new("age", "age", SqlType::Integer).default_json("0"),
+                FieldInfo::new("pet", "pet", SqlType::Json).discriminator("kind"),
+                FieldInfo::new("secret", "secret", SqlType::Text).exclude(true),
+            ];
+            FIELDS
+        }
+
+        fn to_row(&self) -> Vec<(&'static str, Value)> {
+            vec![]
+        }
+
+        fn from_row(_row: &Row) -> Result<Self> {
+            Ok(Self)
+        }
+
+        fn primary_key_value(&self) -> Vec<Value> {
+            vec![]
+        }
+
+        fn is_new(&self) -> bool {
+            true
+        }
+    }
+
+    #[test]
+    fn test_json_schema_from_fields() {
+        let schema = SchemaModel::json_schema();
+        assert_eq!(schema["type"], "object");
+
+        let props = schema["properties"].as_object().unwrap();
+        assert_eq!(props.len(), 4);
+        assert!(!props.contains_key("secret"));
+        assert_eq!(
+            props["id"]["anyOf"],
+            serde_json::json!([{"type": "integer", "format": "int64"}, {"type": "null"}])
+        );
+        assert_eq!(props["heroName"]["maxLength"], 50);
+        assert_eq!(props["heroName"]["description"], "Public name");
+        assert_eq!(props["heroName"]["examples"][0], "Deadpond");
+        assert_eq!(props["age"]["default"], 0);
+        assert_eq!(props["pet"]["discriminator"]["propertyName"], "kind");
+
+        assert_eq!(schema["required"], serde_json::json!(["heroName", "pet"]));
+    }
 }
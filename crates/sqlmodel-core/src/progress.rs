@@ -0,0 +1,17 @@
+//! Progress callbacks for slow operations.
+//!
+//! Drivers and the session report what they are waiting on to an optional
+//! [`ProgressReporter`], so a CLI can show a spinner labelled with the
+//! current phase ("Authenticating", "Flushing", ...) during a slow connect or
+//! a long query. Reporters are called inline on the I/O path and should
+//! return quickly.
+
+/// Receives phase changes and activity ticks from long-running operations.
+pub trait ProgressReporter: Send + Sync + std::fmt::Debug {
+    /// A new phase of the operation has started.
+    fn on_phase(&self, phase: &str);
+
+    /// The current phase is still making progress (e.g. another chunk of a
+    /// query response arrived). The default does nothing.
+    fn tick(&self) {}
+}
@@ -0,0 +1,291 @@
+//! Retry with exponential backoff.
+//!
+//! Serialization failures, deadlocks, pool exhaustion and timeouts often
+//! succeed on a second try. [`retry`] reruns an operation under a
+//! [`RetryPolicy`] while [`Error::is_retryable`] says another attempt may
+//! help, sleeping between attempts and giving up promptly if the `Cx` is
+//! cancelled:
+//!
+//! ```ignore
+//! let policy = RetryPolicy::new(5).base_delay(Duration::from_millis(20));
+//! let moved = retry(&policy, &cx, || transfer(&cx, &pool, from, to, amount)).await;
+//! ```
+
+use std::collections::hash_map::RandomState;
+use std::future::Future;
+use std::hash::{BuildHasher, Hasher};
+use std::time::Duration;
+
+use asupersync::time::{sleep, wall_now};
+use asupersync::{CancelReason, Cx, Outcome};
+
+use crate::Error;
+
+/// Longest single sleep between cancellation checks while backing off.
+const CANCEL_CHECK_INTERVAL: Duration = Duration::from_millis(10);
+
+/// How often and how patiently to retry a failing operation.
+///
+/// The delay before retry `n` (1-based) is `base_delay * 2^(n-1)`, capped at
+/// `max_delay`. With `jitter`, each delay is drawn uniformly from its upper
+/// half so clients that failed together do not retry in lockstep.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Total attempts, including the first; 0 behaves like 1.
+    pub max_attempts: u32,
+    /// Delay before the first retry.
+    pub base_delay: Duration,
+    /// Upper bound for any single delay.
+    pub max_delay: Duration,
+    /// Randomize delays (see above).
+    pub jitter: bool,
+}
+
+impl Default for RetryPolicy {
+    /// Three attempts, 50ms doubling up to 2s, with jitter.
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(50),
+            max_delay: Duration::from_secs(2),
+            jitter: true,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Default delays with the given number of attempts.
+    #[must_use]
+    pub fn new(max_attempts: u32) -> Self {
+        Self {
+            max_attempts,
+            ..Self::default()
+        }
+    }
+
+    /// Set the delay before the first retry.
+    #[must_use]
+    pub fn base_delay(mut self, delay: Duration) -> Self {
+        self.base_delay = delay;
+        self
+    }
+
+    /// Set the upper bound for any single delay.
+    #[must_use]
+    pub fn max_delay(mut self, delay: Duration) -> Self {
+        self.max_delay = delay;
+        self
+    }
+
+    /// Enable or disable jitter.
+    #[must_use]
+    pub fn jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Delay before retry number `retry` (1-based), without jitter.
+    #[must_use]
+    pub fn delay_for(&self, retry: u32) -> Duration {
+        let factor = 2u32.saturating_pow(retry.saturating_sub(1));
+        self.base_delay
+            .checked_mul(factor)
+            .map_or(self.max_delay, |delay| delay.min(self.max_delay))
+    }
+
+    fn jittered(&self, delay: Duration) -> Duration {
+        if !self.jitter || delay.is_zero() {
+            return delay;
+        }
+        // Each RandomState is freshly seeded, which is random enough here.
+        let random = RandomState::new().build_hasher().finish();
+        let half = delay / 2;
+        let spread = u64::try_from(half.as_nanos()).unwrap_or(u64::MAX);
+        half + Duration::from_nanos(random % spread.saturating_add(1))
+    }
+}
+
+/// Run `op` until it succeeds, fails with a non-retryable error, or the
+/// policy's attempts run out.
+///
+/// Returns the last error once attempts are exhausted. Cancellation of `cx`
+/// is checked before every retry and while sleeping, so a cancelled caller
+/// never waits out a long backoff. Cancelled and panicked outcomes from `op`
+/// are returned immediately.
+pub async fn retry<T, F, Fut>(policy: &RetryPolicy, cx: &Cx, mut op: F) -> Outcome<T, Error>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Outcome<T, Error>>,
+{
+    let max_attempts = policy.max_attempts.max(1);
+    let mut attempt = 1;
+    loop {
+        let error = match op().await {
+            Outcome::Err(e) if e.is_retryable() && attempt < max_attempts => e,
+            other => return other,
+        };
+
+        let delay = policy.jittered(policy.delay_for(attempt));
+        tracing::debug!(
+            attempt,
+            max_attempts,
+            ?delay,
+            error = %error,
+            "Retrying after retryable error"
+        );
+        if let Err(reason) = backoff(cx, delay).await {
+            return Outcome::Cancelled(reason);
+        }
+        attempt += 1;
+    }
+}
+
+/// Sleep for `delay`, waking every [`CANCEL_CHECK_INTERVAL`] to check `cx`.
+///
+/// On cancellation this returns the reason recorded on `cx`, so callers see
+/// why the context was cancelled rather than a generic backoff reason.
+async fn backoff(cx: &Cx, delay: Duration) -> Result<(), CancelReason> {
+    let mut remaining = delay;
+    loop {
+        if let Some(reason) = cx.cancel_reason() {
+            return Err(reason);
+        }
+        if cx.is_cancel_requested() {
+            return Err(CancelReason::user("retry backoff cancelled"));
+        }
+        if remaining.is_zero() {
+            return Ok(());
+        }
+        let step = remaining.min(CANCEL_CHECK_INTERVAL);
+        sleep(wall_now(), step).await;
+        remaining -= step;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::{QueryError, QueryErrorKind};
+    use asupersync::runtime::RuntimeBuilder;
+    use std::cell::Cell;
+    use std::time::Instant;
+
+    fn serialization_failure() -> Error {
+        Error::Query(QueryError {
+            kind: QueryErrorKind::Serialization,
+            sql: None,
+            sqlstate: Some("40001".to_string()),
+            message: "could not serialize access".to_string(),
+            detail: None,
+            hint: None,
+            schema: None,
+            table: None,
+            column: None,
+            constraint: None,
+            where_: None,
+            position: None,
+            source: None,
+        })
+    }
+
+    fn fast_policy(max_attempts: u32) -> RetryPolicy {
+        RetryPolicy::new(max_attempts)
+            .base_delay(Duration::from_millis(1))
+            .jitter(false)
+    }
+
+    #[test]
+    fn test_delay_doubles_up_to_max() {
+        let policy = RetryPolicy::new(10)
+            .base_delay(Duration::from_millis(100))
+            .max_delay(Duration::from_millis(350));
+        assert_eq!(policy.delay_for(1), Duration::from_millis(100));
+        assert_eq!(policy.delay_for(2), Duration::from_millis(200));
+        assert_eq!(policy.delay_for(3), Duration::from_millis(350));
+        assert_eq!(policy.delay_for(64), Duration::from_millis(350));
+
+        for _ in 0..20 {
+            let delay = policy.jittered(Duration::from_millis(200));
+            assert!(delay >= Duration::from_millis(100) && delay <= Duration::from_millis(200));
+        }
+    }
+
+    #[test]
+    fn test_retry_immediate_success() {
+        let rt = RuntimeBuilder::current_thread().build().unwrap();
+        let cx = Cx::for_testing();
+        let calls = Cell::new(0);
+        let outcome = rt.block_on(retry(&fast_policy(3), &cx, || {
+            calls.set(calls.get() + 1);
+            async { Outcome::Ok(7) }
+        }));
+        assert!(matches!(outcome, Outcome::Ok(7)));
+        assert_eq!(calls.get(), 1);
+    }
+
+    #[test]
+    fn test_retry_exhausts_attempts() {
+        let rt = RuntimeBuilder::current_thread().build().unwrap();
+        let cx = Cx::for_testing();
+        let calls = Cell::new(0);
+        let outcome: Outcome<(), Error> = rt.block_on(retry(&fast_policy(3), &cx, || {
+            calls.set(calls.get() + 1);
+            async { Outcome::Err(serialization_failure()) }
+        }));
+        assert!(matches!(outcome, Outcome::Err(ref e) if e.is_retryable()));
+        assert_eq!(calls.get(), 3);
+    }
+
+    #[test]
+    fn test_retry_stops_on_non_retryable_error() {
+        let rt = RuntimeBuilder::current_thread().build().unwrap();
+        let cx = Cx::for_testing();
+        let calls = Cell::new(0);
+        let outcome: Outcome<(), Error> = rt.block_on(retry(&fast_policy(5), &cx, || {
+            calls.set(calls.get() + 1);
+            let attempt = calls.get();
+            async move {
+                if attempt == 1 {
+                    Outcome::Err(serialization_failure())
+                } else {
+                    Outcome::Err(Error::Custom("bad input".to_string()))
+                }
+            }
+        }));
+        assert!(matches!(outcome, Outcome::Err(Error::Custom(_))));
+        assert_eq!(calls.get(), 2);
+    }
+
+    #[test]
+    fn test_retry_cancelled_mid_backoff() {
+        let rt = RuntimeBuilder::current_thread().build().unwrap();
+        let cx = Cx::for_testing();
+        let canceller = {
+            let cx = cx.clone();
+            std::thread::spawn(move || {
+                std::thread::sleep(Duration::from_millis(30));
+                cx.set_cancel_requested(true);
+            })
+        };
+
+        let policy = RetryPolicy::new(3)
+            .base_delay(Duration::from_secs(30))
+            .jitter(false);
+        let calls = Cell::new(0);
+        let started = Instant::now();
+        let outcome: Outcome<(), Error> = rt.block_on(retry(&policy, &cx, || {
+            calls.set(calls.get() + 1);
+            async { Outcome::Err(serialization_failure()) }
+        }));
+        canceller.join().unwrap();
+
+        let Outcome::Cancelled(reason) = outcome else {
+            panic!("expected cancellation, got {outcome:?}");
+        };
+        if let Some(expected) = cx.cancel_reason() {
+            assert_eq!(format!("{reason:?}"), format!("{expected:?}"));
+        }
+        assert_eq!(calls.get(), 1);
+        assert!(started.elapsed() < Duration::from_secs(5));
+    }
+}
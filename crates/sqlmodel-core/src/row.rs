@@ -2,7 +2,9 @@
 
 use crate::Result;
 use crate::error::{Error, TypeError};
+use crate::types::SqlType;
 use crate::value::Value;
+use indexmap::IndexMap;
 use std::collections::HashMap;
 use std::sync::Arc;
 
@@ -20,12 +22,29 @@ pub struct ColumnInfo {
 
 impl ColumnInfo {
     /// Create new column info from a list of column names.
+    ///
+    /// Duplicate names (e.g. `id` from both sides of a join) resolve to their
+    /// first occurrence in name lookups; later occurrences stay reachable by
+    /// index or through [`Row::all_named`].
     pub fn new(names: Vec<String>) -> Self {
-        let name_to_index = names
-            .iter()
-            .enumerate()
-            .map(|(i, name)| (name.clone(), i))
-            .collect();
+        let mut name_to_index = HashMap::with_capacity(names.len());
+        let mut duplicates = Vec::new();
+        for (i, name) in names.iter().enumerate() {
+            if name_to_index.contains_key(name) {
+                if !duplicates.contains(name) {
+                    duplicates.push(name.clone());
+                }
+            } else {
+                name_to_index.insert(name.clone(), i);
+            }
+        }
+        if !duplicates.is_empty() {
+            tracing::warn!(
+                columns = ?duplicates,
+                "Result set has duplicate column names; lookups by name return the first \
+                 occurrence (alias the columns or use Row::all_named to reach the others)"
+            );
+        }
         Self {
             names,
             name_to_index,
@@ -61,6 +80,15 @@ impl ColumnInfo {
     pub fn names(&self) -> &[String] {
         &self.names
     }
+
+    /// Get the indices of every column with the given name, in order.
+    pub fn indices_of<'a>(&'a self, name: &'a str) -> impl Iterator<Item = usize> + 'a {
+        self.names
+            .iter()
+            .enumerate()
+            .filter(move |(_, n)| *n == name)
+            .map(|(i, _)| i)
+    }
 }
 
 /// A single row returned from a database query.
@@ -177,6 +205,18 @@ impl Row {
         self.columns.index_of(name).and_then(|i| self.values.get(i))
     }
 
+    /// Get the values of every column with the given name, in column order.
+    ///
+    /// Unlike [`get_by_name`](Self::get_by_name), which only sees the first
+    /// occurrence, this surfaces each duplicate (e.g. both `id` columns of a
+    /// join).
+    pub fn all_named(&self, name: &str) -> Vec<&Value> {
+        self.columns
+            .indices_of(name)
+            .filter_map(|i| self.values.get(i))
+            .collect()
+    }
+
     /// Check if a column exists by name.
     pub fn contains_column(&self, name: &str) -> bool {
         self.columns.contains(name)
@@ -220,6 +260,34 @@ impl Row {
         })
     }
 
+    /// Get a typed value by column name, first coercing it to `target`.
+    ///
+    /// Generated `from_row` implementations use this so that a column whose
+    /// database type differs slightly from the field (e.g. `BIGINT` read into
+    /// an `i32`) converts predictably via [`Value::coerce`].
+    #[allow(clippy::result_large_err)]
+    pub fn get_named_coerced<T: FromValue>(&self, name: &str, target: SqlType) -> Result<T> {
+        let value = self.get_by_name(name).ok_or_else(|| {
+            Error::Type(TypeError {
+                expected: std::any::type_name::<T>(),
+                actual: format!("column '{}' not found", name),
+                column: Some(name.to_string()),
+                rust_type: None,
+            })
+        })?;
+        value
+            .clone()
+            .coerce(target)
+            .and_then(|v| T::from_value(&v))
+            .map_err(|e| match e {
+                Error::Type(mut te) => {
+                    te.column = Some(name.to_string());
+                    Error::Type(te)
+                }
+                e => e,
+            })
+    }
+
     /// Get all column names.
     pub fn column_names(&self) -> impl Iterator<Item = &str> {
         self.columns.names().iter().map(String::as_str)
@@ -238,6 +306,65 @@ impl Row {
             .map(String::as_str)
             .zip(self.values.iter())
     }
+
+    /// Convert into a column-ordered map of column name to value.
+    ///
+    /// Duplicate column names (e.g. from a join selecting `id` twice) are kept
+    /// by suffixing later occurrences: `id`, `id_1`, `id_2`, ... skipping any
+    /// suffix that is itself a column name.
+    pub fn into_map(self) -> IndexMap<String, Value> {
+        let mut map = IndexMap::with_capacity(self.values.len());
+        for (name, value) in self.columns.names().iter().zip(self.values) {
+            let mut key = name.clone();
+            let mut n = 1;
+            while map.contains_key(&key) || (key != *name && self.columns.contains(&key)) {
+                key = format!("{name}_{n}");
+                n += 1;
+            }
+            map.insert(key, value);
+        }
+        map
+    }
+
+    /// Columns whose values differ between `self` and `other`, matched by name.
+    ///
+    /// Each entry is `(column, self_value, other_value)`, in `self`'s column
+    /// order followed by columns only `other` has. A column missing from one
+    /// side compares as `NULL` there. Duplicate names match their first
+    /// occurrence, as in [`Row::get_by_name`].
+    pub fn diff<'a>(&'a self, other: &'a Row) -> Vec<(String, &'a Value, &'a Value)> {
+        static MISSING: Value = Value::Null;
+
+        let mut changes = Vec::new();
+        for (i, name) in self.columns.names().iter().enumerate() {
+            if self.columns.index_of(name) != Some(i) {
+                continue;
+            }
+            let theirs = other.get_by_name(name).unwrap_or(&MISSING);
+            if self.values[i] != *theirs {
+                changes.push((name.clone(), &self.values[i], theirs));
+            }
+        }
+        for (i, name) in other.columns.names().iter().enumerate() {
+            if other.columns.index_of(name) != Some(i) || self.columns.contains(name) {
+                continue;
+            }
+            if other.values[i] != MISSING {
+                changes.push((name.clone(), &MISSING, &other.values[i]));
+            }
+        }
+        changes
+    }
+}
+
+/// Rows are equal when they have the same column names in the same order and
+/// equal values.
+impl PartialEq for Row {
+    fn eq(&self, other: &Self) -> bool {
+        self.values == other.values
+            && (Arc::ptr_eq(&self.columns, &other.columns)
+                || self.columns.names() == other.columns.names())
+    }
 }
 
 /// Trait for converting from a `Value` to a typed value.
@@ -602,6 +729,116 @@ impl FromValue for [u8; 16] {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_row_eq_and_diff() {
+        let columns = vec!["id".to_string(), "name".to_string(), "age".to_string()];
+        let before = Row::new(
+            columns.clone(),
+            vec![Value::Int(1), Value::Text("Rusty".into()), Value::Int(30)],
+        );
+        let after = Row::new(
+            columns,
+            vec![Value::Int(1), Value::Text("Rusty".into()), Value::Int(31)],
+        );
+        assert_eq!(before, before.clone());
+        assert_ne!(before, after);
+        assert_eq!(
+            before.diff(&after),
+            [("age".to_string(), &Value::Int(30), &Value::Int(31))]
+        );
+
+        // Order matters for equality but not for diffing.
+        let reordered = Row::new(
+            vec!["name".to_string(), "id".to_string(), "age".to_string()],
+            vec![Value::Text("Rusty".into()), Value::Int(1), Value::Int(30)],
+        );
+        assert_ne!(before, reordered);
+        assert!(before.diff(&reordered).is_empty());
+
+        // Columns on one side only compare against NULL.
+        let narrower = Row::new(
+            vec!["id".to_string(), "email".to_string()],
+            vec![Value::Int(1), Value::Text("r@example.com".into())],
+        );
+        assert_eq!(
+            before.diff(&narrower),
+            [
+                (
+                    "name".to_string(),
+                    &Value::Text("Rusty".into()),
+                    &Value::Null
+                ),
+                ("age".to_string(), &Value::Int(30), &Value::Null),
+                (
+                    "email".to_string(),
+                    &Value::Null,
+                    &Value::Text("r@example.com".into())
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_into_map_preserves_order_and_suffixes_duplicates() {
+        let row = Row::new(
+            vec![
+                "name".to_string(),
+                "id".to_string(),
+                "name".to_string(),
+                "name_1".to_string(),
+            ],
+            vec![
+                Value::Text("Deadpond".to_string()),
+                Value::Int(1),
+                Value::Text("Preventers".to_string()),
+                Value::Int(9),
+            ],
+        );
+        let map = row.into_map();
+        let keys: Vec<&str> = map.keys().map(String::as_str).collect();
+        assert_eq!(keys, ["name", "id", "name_2", "name_1"]);
+        assert_eq!(map["name_2"], Value::Text("Preventers".to_string()));
+        assert_eq!(map["name_1"], Value::Int(9));
+    }
+
+    #[test]
+    fn test_duplicate_columns_resolve_first_and_all_named() {
+        let row = Row::new(
+            vec!["id".to_string(), "name".to_string(), "id".to_string()],
+            vec![
+                Value::Int(1),
+                Value::Text("Deadpond".to_string()),
+                Value::Int(10),
+            ],
+        );
+        assert_eq!(row.get_by_name("id"), Some(&Value::Int(1)));
+        assert_eq!(row.all_named("id"), vec![&Value::Int(1), &Value::Int(10)]);
+        assert_eq!(
+            row.all_named("name"),
+            vec![&Value::Text("Deadpond".to_string())]
+        );
+        assert!(row.all_named("missing").is_empty());
+    }
+
+    #[test]
+    fn test_get_named_coerced() {
+        let row = Row::new(
+            vec!["id".to_string(), "count".to_string()],
+            vec![Value::BigInt(7), Value::BigInt(i64::MAX)],
+        );
+        assert_eq!(
+            row.get_named_coerced::<i32>("id", SqlType::Integer)
+                .unwrap(),
+            7
+        );
+        assert!(row.get_named::<i32>("id").is_err());
+
+        let Err(Error::Type(err)) = row.get_named_coerced::<i32>("count", SqlType::Integer) else {
+            panic!("expected overflow error");
+        };
+        assert_eq!(err.column.as_deref(), Some("count"));
+    }
+
     #[test]
     fn test_row_basic_access() {
         let row = Row::new(
@@ -0,0 +1,340 @@
+//! Comment stripping and statement splitting for SQL scripts.
+//!
+//! Drivers execute one statement at a time (PostgreSQL's extended protocol
+//! rejects several in one `Parse`, SQLite stops after the first), so
+//! migration scripts and ad-hoc validation go through [`split_statements`]
+//! first:
+//!
+//! ```
+//! use sqlmodel_core::{Dialect, script::split_statements};
+//!
+//! let script = "-- seed\nINSERT INTO notes VALUES ('a;b'); /* done */ SELECT 1;";
+//! assert_eq!(
+//!     split_statements(script, Dialect::Postgres),
+//!     vec!["INSERT INTO notes VALUES ('a;b')", "SELECT 1"],
+//! );
+//! ```
+//!
+//! The scanner knows just enough lexical structure to find real `;`s and
+//! comments: quoted strings and identifiers (with doubled-quote escapes),
+//! `--` and `/* */` comments, and per dialect:
+//!
+//! - PostgreSQL: `$tag$ ... $tag$` bodies, `E'...'` backslash escapes and
+//!   nested block comments.
+//! - MySQL: backslash escapes in every string, backtick identifiers and `#`
+//!   comments. `--` only starts a comment when followed by whitespace, so
+//!   `1--1` is arithmetic. `DELIMITER` is a client command and is not
+//!   understood.
+//! - SQLite: `CREATE TRIGGER ... BEGIN ...; END` bodies stay in one piece.
+//!
+//! Executable comments (`/*! ... */`) and optimizer hints (`/*+ ... */`) are
+//! part of the statement and are kept verbatim.
+
+use crate::connection::Dialect;
+
+/// Split a script into its statements, with comments removed.
+///
+/// Statements are trimmed and lose their terminating `;`; empty statements
+/// (stray `;`s, comment-only stretches) are dropped.
+pub fn split_statements(sql: &str, dialect: Dialect) -> Vec<String> {
+    scan(sql, dialect, true)
+}
+
+/// Remove `--`, `/* */` (and, for MySQL, `#`) comments from `sql`.
+///
+/// Comment markers inside string literals, quoted identifiers and dollar
+/// quoted bodies are left alone, as are `/*!` and `/*+` blocks. A block
+/// comment becomes a single space so the tokens around it stay separate; a
+/// line comment keeps its newline.
+pub fn strip_comments(sql: &str, dialect: Dialect) -> String {
+    scan(sql, dialect, false).pop().unwrap_or_default()
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+/// Tracks whether a SQLite `CREATE TRIGGER` body is open, where `;` ends a
+/// statement of the body rather than the script's statement.
+#[derive(Default)]
+struct TriggerBody {
+    words: usize,
+    create: bool,
+    trigger: bool,
+    depth: usize,
+}
+
+impl TriggerBody {
+    fn word(&mut self, word: &str) {
+        self.words += 1;
+        if self.words == 1 {
+            self.create = word.eq_ignore_ascii_case("CREATE");
+        } else if self.create && self.words <= 3 && word.eq_ignore_ascii_case("TRIGGER") {
+            self.trigger = true;
+        } else if self.trigger {
+            if word.eq_ignore_ascii_case("BEGIN") || word.eq_ignore_ascii_case("CASE") {
+                self.depth += 1;
+            } else if word.eq_ignore_ascii_case("END") {
+                self.depth = self.depth.saturating_sub(1);
+            }
+        }
+    }
+}
+
+/// Index just past the literal opened by `quote` at `start`.
+fn skip_quoted(chars: &[char], start: usize, quote: char, backslash: bool) -> usize {
+    let mut i = start + 1;
+    while i < chars.len() {
+        if backslash && chars[i] == '\\' {
+            i += 2;
+        } else if chars[i] == quote {
+            if chars.get(i + 1) == Some(&quote) {
+                i += 2;
+            } else {
+                return i + 1;
+            }
+        } else {
+            i += 1;
+        }
+    }
+    chars.len()
+}
+
+/// Index just past the block comment opened at `start`.
+fn skip_block_comment(chars: &[char], start: usize, nested: bool) -> usize {
+    let mut depth = 0;
+    let mut i = start;
+    while i < chars.len() {
+        match (chars[i], chars.get(i + 1)) {
+            ('/', Some('*')) if depth == 0 || nested => {
+                depth += 1;
+                i += 2;
+            }
+            ('*', Some('/')) => {
+                depth -= 1;
+                i += 2;
+                if depth == 0 {
+                    return i;
+                }
+            }
+            _ => i += 1,
+        }
+    }
+    chars.len()
+}
+
+/// Index just past the PostgreSQL dollar-quoted body starting at `start`,
+/// or `None` if the `$` does not open one (e.g. a `$1` placeholder).
+fn skip_dollar_quoted(chars: &[char], start: usize) -> Option<usize> {
+    let mut i = start + 1;
+    while i < chars.len() && is_word_char(chars[i]) {
+        i += 1;
+    }
+    if chars.get(i) != Some(&'$') || chars.get(start + 1).is_some_and(char::is_ascii_digit) {
+        return None;
+    }
+    let tag = &chars[start..=i];
+    let body = i + 1;
+    Some(
+        (body..chars.len())
+            .find(|&j| chars[j..].starts_with(tag))
+            .map_or(chars.len(), |j| j + tag.len()),
+    )
+}
+
+fn scan(sql: &str, dialect: Dialect, split: bool) -> Vec<String> {
+    let chars: Vec<char> = sql.chars().collect();
+    let mut statements = Vec::new();
+    let mut current = String::new();
+    let mut word = String::new();
+    let mut trigger = TriggerBody::default();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        if is_word_char(c) {
+            word.push(c);
+            current.push(c);
+            i += 1;
+            continue;
+        }
+        if !word.is_empty() {
+            if dialect == Dialect::Sqlite {
+                trigger.word(&word);
+            }
+            word.clear();
+        }
+
+        let next = chars.get(i + 1).copied();
+        let end = match c {
+            '\'' => {
+                let e_string = dialect == Dialect::Postgres
+                    && i > 0
+                    && matches!(chars[i - 1], 'e' | 'E')
+                    && (i < 2 || !is_word_char(chars[i - 2]));
+                skip_quoted(&chars, i, c, dialect == Dialect::Mysql || e_string)
+            }
+            '"' => skip_quoted(&chars, i, c, false),
+            '`' if dialect == Dialect::Mysql => skip_quoted(&chars, i, c, false),
+            '$' if dialect == Dialect::Postgres
+                && (i == 0 || !(is_word_char(chars[i - 1]) || chars[i - 1] == '$')) =>
+            {
+                skip_dollar_quoted(&chars, i).unwrap_or(i + 1)
+            }
+            '-' if next == Some('-')
+                && (dialect != Dialect::Mysql
+                    || chars
+                        .get(i + 2)
+                        .is_none_or(|c| c.is_whitespace() || c.is_control())) =>
+            {
+                i = (i..chars.len())
+                    .find(|&j| chars[j] == '\n')
+                    .unwrap_or(chars.len());
+                continue;
+            }
+            '#' if dialect == Dialect::Mysql => {
+                i = (i..chars.len())
+                    .find(|&j| chars[j] == '\n')
+                    .unwrap_or(chars.len());
+                continue;
+            }
+            '/' if next == Some('*') && matches!(chars.get(i + 2), Some('!' | '+')) => {
+                skip_block_comment(&chars, i, dialect == Dialect::Postgres)
+            }
+            '/' if next == Some('*') => {
+                i = skip_block_comment(&chars, i, dialect == Dialect::Postgres);
+                if !current.is_empty() && !current.ends_with(char::is_whitespace) {
+                    current.push(' ');
+                }
+                continue;
+            }
+            ';' if split && trigger.depth == 0 => {
+                push_statement(&mut statements, &current);
+                current.clear();
+                trigger = TriggerBody::default();
+                i += 1;
+                continue;
+            }
+            _ => i + 1,
+        };
+        current.extend(&chars[i..end]);
+        i = end;
+    }
+
+    if split {
+        push_statement(&mut statements, &current);
+    } else {
+        statements.push(current);
+    }
+    statements
+}
+
+fn push_statement(statements: &mut Vec<String>, statement: &str) {
+    let statement = statement.trim();
+    if !statement.is_empty() {
+        statements.push(statement.to_string());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_respects_literals_and_comments() {
+        let script = "
+            -- create the table; not a statement break
+            CREATE TABLE notes (id INT, body TEXT);
+            /* block; comment */
+            INSERT INTO notes VALUES (1, 'a;b''c -- no comment');;
+            SELECT \"odd;name\" FROM notes -- trailing
+        ";
+        assert_eq!(
+            split_statements(script, Dialect::Sqlite),
+            vec![
+                "CREATE TABLE notes (id INT, body TEXT)",
+                "INSERT INTO notes VALUES (1, 'a;b''c -- no comment')",
+                "SELECT \"odd;name\" FROM notes",
+            ]
+        );
+        assert!(split_statements("-- only a comment\n ; ", Dialect::Postgres).is_empty());
+    }
+
+    #[test]
+    fn test_split_postgres_dollar_quotes() {
+        let script =
+            "CREATE FUNCTION f() RETURNS int AS $$ BEGIN RETURN 1; END; $$ LANGUAGE plpgsql;
+            DO $body$ BEGIN PERFORM 'x;'; END $body$;
+            SELECT $1::int; SELECT E'it\\'s;'; /* outer /* nested; */ still; */ SELECT 2";
+        assert_eq!(
+            split_statements(script, Dialect::Postgres),
+            vec![
+                "CREATE FUNCTION f() RETURNS int AS $$ BEGIN RETURN 1; END; $$ LANGUAGE plpgsql",
+                "DO $body$ BEGIN PERFORM 'x;'; END $body$",
+                "SELECT $1::int",
+                "SELECT E'it\\'s;'",
+                "SELECT 2",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_split_mysql_escapes_and_hash_comments() {
+        let script = "INSERT INTO t VALUES ('it\\'s; fine'); # note; here\nSELECT `a;b` FROM t";
+        assert_eq!(
+            split_statements(script, Dialect::Mysql),
+            vec![
+                "INSERT INTO t VALUES ('it\\'s; fine')",
+                "SELECT `a;b` FROM t"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_split_mysql_double_dash_needs_whitespace() {
+        let script = "SELECT 1--1; -- note\nSELECT 2 --\tend";
+        assert_eq!(
+            split_statements(script, Dialect::Mysql),
+            vec!["SELECT 1--1", "SELECT 2"]
+        );
+        assert_eq!(
+            split_statements("SELECT 1--1;", Dialect::Postgres),
+            vec!["SELECT 1"]
+        );
+    }
+
+    #[test]
+    fn test_keeps_executable_comments_and_hints() {
+        let script =
+            "SELECT /*+ MAX_EXECUTION_TIME(1000) */ 1; /*!40101 SET NAMES utf8; */ /* gone */";
+        assert_eq!(
+            split_statements(script, Dialect::Mysql),
+            vec![
+                "SELECT /*+ MAX_EXECUTION_TIME(1000) */ 1",
+                "/*!40101 SET NAMES utf8; */"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_split_sqlite_trigger_body() {
+        let script = "CREATE TRIGGER touch AFTER UPDATE ON t BEGIN
+                UPDATE t SET n = CASE WHEN n > 0 THEN n END;
+                DELETE FROM log;
+            END;
+            SELECT 1;";
+        let statements = split_statements(script, Dialect::Sqlite);
+        assert_eq!(statements.len(), 2);
+        assert!(statements[0].ends_with("END"));
+        assert!(statements[0].contains("DELETE FROM log;"));
+        assert_eq!(statements[1], "SELECT 1");
+    }
+
+    #[test]
+    fn test_strip_comments() {
+        assert_eq!(
+            strip_comments("SELECT/* x */1 -- y\nFROM t; '--kept'", Dialect::Postgres),
+            "SELECT 1 \nFROM t; '--kept'"
+        );
+    }
+}
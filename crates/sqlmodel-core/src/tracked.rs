@@ -7,7 +7,9 @@
 use std::ops::{Deref, DerefMut};
 
 use crate::fields_set::FieldsSet;
-use crate::validate::{DumpOptions, DumpResult, apply_serialization_aliases};
+use crate::validate::{
+    DumpOptions, DumpResult, apply_include_exclude, apply_serialization_aliases,
+};
 use crate::{FieldInfo, Model};
 
 /// A model instance with explicit "fields set" tracking.
@@ -150,6 +152,7 @@ impl<T: Model + serde::Serialize> TrackedModel<T> {
                 exclude_computed_fields,
                 exclude_defaults,
             );
+            apply_include_exclude(map, T::fields(), include.as_ref(), exclude.as_ref());
         }
 
         if by_alias {
@@ -157,12 +160,6 @@ impl<T: Model + serde::Serialize> TrackedModel<T> {
         }
 
         if let serde_json::Value::Object(ref mut map) = value {
-            if let Some(ref include_set) = include {
-                map.retain(|k, _| include_set.contains(k));
-            }
-            if let Some(ref exclude_set) = exclude {
-                map.retain(|k, _| !exclude_set.contains(k));
-            }
             if exclude_none {
                 map.retain(|_, v| !v.is_null());
             }
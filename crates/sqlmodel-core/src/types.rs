@@ -90,6 +90,39 @@ impl SqlType {
         }
     }
 
+    /// JSON Schema describing values of this type, as used by `Model::json_schema`.
+    ///
+    /// JSON columns and custom types accept any value and map to `{}`.
+    pub fn json_schema(&self) -> serde_json::Value {
+        use serde_json::json;
+        match self {
+            SqlType::TinyInt | SqlType::SmallInt | SqlType::Integer => {
+                json!({"type": "integer", "format": "int32"})
+            }
+            SqlType::BigInt => json!({"type": "integer", "format": "int64"}),
+            SqlType::Real => json!({"type": "number", "format": "float"}),
+            SqlType::Double => json!({"type": "number", "format": "double"}),
+            SqlType::Numeric { .. } | SqlType::Decimal { .. } => json!({"type": "number"}),
+            SqlType::Boolean => json!({"type": "boolean"}),
+            SqlType::Char(len) | SqlType::VarChar(len) => {
+                json!({"type": "string", "maxLength": len})
+            }
+            SqlType::Text => json!({"type": "string"}),
+            SqlType::Binary(_) | SqlType::VarBinary(_) | SqlType::Blob => {
+                json!({"type": "string", "format": "binary"})
+            }
+            SqlType::Date => json!({"type": "string", "format": "date"}),
+            SqlType::Time => json!({"type": "string", "format": "time"}),
+            SqlType::DateTime | SqlType::Timestamp | SqlType::TimestampTz => {
+                json!({"type": "string", "format": "date-time"})
+            }
+            SqlType::Uuid => json!({"type": "string", "format": "uuid"}),
+            SqlType::Json | SqlType::JsonB | SqlType::Custom(_) => json!({}),
+            SqlType::Enum(variants) => json!({"type": "string", "enum": variants}),
+            SqlType::Array(inner) => json!({"type": "array", "items": inner.json_schema()}),
+        }
+    }
+
     /// Check if this type is numeric.
     pub const fn is_numeric(&self) -> bool {
         matches!(
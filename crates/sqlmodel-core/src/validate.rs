@@ -6,7 +6,8 @@
 //! It also provides `model_validate()` functionality for creating and
 //! validating models from various input types (similar to Pydantic).
 
-use std::collections::HashMap;
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
 use std::sync::OnceLock;
 
 use regex::Regex;
@@ -406,10 +407,13 @@ pub struct DumpOptions {
     /// In this crate, `model_dump()`/`sql_model_dump()` return `serde_json::Value`,
     /// so both modes currently produce equivalent JSON-value output.
     pub mode: DumpMode,
-    /// Only include these fields (if Some)
-    pub include: Option<std::collections::HashSet<String>>,
-    /// Exclude these fields
-    pub exclude: Option<std::collections::HashSet<String>>,
+    /// Only include these fields (if Some).
+    ///
+    /// For model-aware dumps, entries match a field by its Rust name or its
+    /// output alias. A field flagged `exclude` stays excluded even if listed.
+    pub include: Option<HashSet<String>>,
+    /// Exclude these fields (matched like `include`).
+    pub exclude: Option<HashSet<String>>,
     /// Use field aliases in output.
     ///
     /// When true, `sql_model_dump()` will rename fields to their
@@ -660,7 +664,7 @@ impl<T: serde::Serialize> ModelDump for T {
 /// Convert a Value to serde_json::Value.
 fn value_to_json(value: Value) -> serde_json::Value {
     match value {
-        Value::Null => serde_json::Value::Null,
+        Value::Null | Value::TypedNull(_) => serde_json::Value::Null,
         Value::Bool(b) => serde_json::Value::Bool(b),
         Value::TinyInt(i) => serde_json::Value::Number(i.into()),
         Value::SmallInt(i) => serde_json::Value::Number(i.into()),
@@ -799,6 +803,33 @@ pub fn apply_serialization_aliases(json: &mut serde_json::Value, fields: &[crate
     }
 }
 
+/// Apply `DumpOptions` include/exclude sets to a dumped object.
+///
+/// Runs on field-name keys, before alias renaming. A set entry matches a key
+/// directly or through the field's output alias, so callers can list either
+/// name. Callers apply per-field `exclude` flags first, which keeps a
+/// field-level exclude in force even when the field is in `include`.
+pub(crate) fn apply_include_exclude(
+    map: &mut serde_json::Map<String, serde_json::Value>,
+    fields: &[crate::FieldInfo],
+    include: Option<&HashSet<String>>,
+    exclude: Option<&HashSet<String>>,
+) {
+    let listed = |set: &HashSet<String>, key: &str| {
+        set.contains(key)
+            || fields
+                .iter()
+                .find(|f| f.name == key)
+                .is_some_and(|f| set.contains(f.output_name()))
+    };
+    if let Some(include) = include {
+        map.retain(|k, _| listed(include, k));
+    }
+    if let Some(exclude) = exclude {
+        map.retain(|k, _| !listed(exclude, k));
+    }
+}
+
 /// Model-aware validation that supports field aliases.
 ///
 /// Unlike the generic `ModelValidate`, this trait uses the `Model::fields()`
@@ -995,7 +1026,7 @@ pub trait SqlModelDump: Model + serde::Serialize {
 
             // Exclude computed fields if requested (must happen before alias renaming)
             if options.exclude_computed_fields {
-                let computed_field_names: std::collections::HashSet<&str> = Self::fields()
+                let computed_field_names: HashSet<&str> = Self::fields()
                     .iter()
                     .filter(|f| f.computed)
                     .map(|f| f.name)
@@ -1020,6 +1051,14 @@ pub trait SqlModelDump: Model + serde::Serialize {
                     }
                 }
             }
+
+            // Include/exclude sets match field names or aliases
+            apply_include_exclude(
+                map,
+                Self::fields(),
+                options.include.as_ref(),
+                options.exclude.as_ref(),
+            );
         }
 
         // Apply serialization aliases if by_alias is set
@@ -1027,18 +1066,7 @@ pub trait SqlModelDump: Model + serde::Serialize {
             apply_serialization_aliases(&mut value, Self::fields());
         }
 
-        // Apply remaining options (include/exclude work on the final key names)
         if let serde_json::Value::Object(ref mut map) = value {
-            // Apply include filter
-            if let Some(ref include) = options.include {
-                map.retain(|k, _| include.contains(k));
-            }
-
-            // Apply exclude filter
-            if let Some(ref exclude) = options.exclude {
-                map.retain(|k, _| !exclude.contains(k));
-            }
-
             // Apply exclude_none filter
             if options.exclude_none {
                 map.retain(|_, v| !v.is_null());
@@ -1367,6 +1395,448 @@ pub trait SqlModelUpdate: Model + serde::Serialize + DeserializeOwned {
 /// Blanket implementation for all Model types that implement Serialize + DeserializeOwned.
 impl<T: Model + serde::Serialize + DeserializeOwned> SqlModelUpdate for T {}
 
+// ============================================================================
+// Client-Side CHECK Evaluation
+// ============================================================================
+
+/// Evaluate a model's simple CHECK constraints against its current values.
+///
+/// Column constraints (`FieldInfo::column_constraints`) and table-level
+/// [`TableConstraint::Check`](crate::TableConstraint::Check) expressions are
+/// evaluated with [`evaluate_check`], so obvious violations are reported
+/// before a round trip to the database. Failures on a column constraint are
+/// recorded under the field's name, table-level failures under `__model__`.
+/// Expressions outside the supported grammar are skipped and left to the
+/// database.
+pub fn validate_checks<M: Model>(obj: &M) -> Result<(), ValidationError> {
+    let row = obj.to_row();
+    let mut errors = ValidationError::new();
+
+    for field in M::fields() {
+        for constraint in field.column_constraints {
+            let Some(expr) = check_body(constraint) else {
+                continue;
+            };
+            if evaluate_check(expr, &row) == Some(false) {
+                errors.add_check(field.name, expr);
+            }
+        }
+    }
+    for constraint in M::TABLE_CONSTRAINTS {
+        let crate::TableConstraint::Check(expr) = constraint else {
+            continue;
+        };
+        if evaluate_check(expr, &row) == Some(false) {
+            errors.add_check("__model__", expr);
+        }
+    }
+
+    errors.into_result()
+}
+
+/// Evaluate a CHECK expression against `(column, value)` pairs.
+///
+/// Supports comparisons (`=`, `<>`, `!=`, `<`, `<=`, `>`, `>=`) between
+/// columns and numeric, string or boolean literals, `[NOT] IN (...)`,
+/// `[NOT] BETWEEN ... AND ...`, `IS [NOT] NULL`, and `AND`/`OR`/`NOT` with
+/// parentheses. Like the database, a CHECK only fails when it is definitely
+/// false; NULL operands make it pass.
+///
+/// Returns `None` if the expression can't be parsed, names an unknown
+/// column, or compares values of unrelated types.
+pub fn evaluate_check(expr: &str, columns: &[(&str, Value)]) -> Option<bool> {
+    let tokens = tokenize_check(expr)?;
+    let mut parser = CheckParser { tokens, pos: 0 };
+    let parsed = parser.or()?;
+    if parser.pos != parser.tokens.len() {
+        return None;
+    }
+    let lookup = |name: &str| {
+        columns
+            .iter()
+            .find(|(column, _)| column.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value)
+    };
+    // SQL three-valued logic: unknown passes.
+    Some(parsed.eval(&lookup)? != Some(false))
+}
+
+/// Strip `[CONSTRAINT name] CHECK` from a column constraint, or `None` if it
+/// is some other kind of constraint.
+fn check_body(constraint: &str) -> Option<&str> {
+    let mut rest = constraint.trim();
+    if starts_with_keyword(rest, "CONSTRAINT") {
+        rest = rest["CONSTRAINT".len()..].trim_start();
+        let name_end = rest.find(char::is_whitespace)?;
+        rest = rest[name_end..].trim_start();
+    }
+    if !starts_with_keyword(rest, "CHECK") {
+        return None;
+    }
+    Some(rest["CHECK".len()..].trim())
+}
+
+fn starts_with_keyword(s: &str, keyword: &str) -> bool {
+    s.get(..keyword.len())
+        .is_some_and(|head| head.eq_ignore_ascii_case(keyword))
+        && !s[keyword.len()..].starts_with(|c: char| c.is_ascii_alphanumeric() || c == '_')
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum CheckToken {
+    /// Bare word: a keyword or an unquoted column name.
+    Word(String),
+    /// Quoted column name.
+    Ident(String),
+    Literal(Value),
+    Op(&'static str),
+    LParen,
+    RParen,
+    Comma,
+}
+
+fn tokenize_check(expr: &str) -> Option<Vec<CheckToken>> {
+    let chars: Vec<char> = expr.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        match c {
+            _ if c.is_whitespace() => i += 1,
+            '(' => {
+                tokens.push(CheckToken::LParen);
+                i += 1;
+            }
+            ')' => {
+                tokens.push(CheckToken::RParen);
+                i += 1;
+            }
+            ',' => {
+                tokens.push(CheckToken::Comma);
+                i += 1;
+            }
+            '\'' | '"' | '`' | '[' => {
+                let close = if c == '[' { ']' } else { c };
+                let mut text = String::new();
+                i += 1;
+                loop {
+                    match chars.get(i) {
+                        None => return None,
+                        Some(&ch) if ch == close => {
+                            // A doubled quote is an escaped quote.
+                            if close != ']' && chars.get(i + 1) == Some(&close) {
+                                text.push(close);
+                                i += 2;
+                            } else {
+                                i += 1;
+                                break;
+                            }
+                        }
+                        Some(&ch) => {
+                            text.push(ch);
+                            i += 1;
+                        }
+                    }
+                }
+                tokens.push(if c == '\'' {
+                    CheckToken::Literal(Value::Text(text))
+                } else {
+                    CheckToken::Ident(text)
+                });
+            }
+            _ if c.is_ascii_digit()
+                || (c == '.' && chars.get(i + 1).is_some_and(char::is_ascii_digit)) =>
+            {
+                let start = i;
+                while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.') {
+                    i += 1;
+                }
+                let text: String = chars[start..i].iter().collect();
+                let value = if text.contains('.') {
+                    Value::Double(text.parse().ok()?)
+                } else {
+                    Value::BigInt(text.parse().ok()?)
+                };
+                tokens.push(CheckToken::Literal(value));
+            }
+            _ if c.is_alphabetic() || c == '_' => {
+                let start = i;
+                while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                    i += 1;
+                }
+                tokens.push(CheckToken::Word(chars[start..i].iter().collect()));
+            }
+            _ => {
+                let two: String = chars[i..chars.len().min(i + 2)].iter().collect();
+                let op = match two.as_str() {
+                    "<=" => "<=",
+                    ">=" => ">=",
+                    "<>" | "!=" => "<>",
+                    "==" => "=",
+                    _ => match c {
+                        '=' => "=",
+                        '<' => "<",
+                        '>' => ">",
+                        '-' => "-",
+                        _ => return None,
+                    },
+                };
+                i += if op.len() == 2 || two == "==" { 2 } else { 1 };
+                tokens.push(CheckToken::Op(op));
+            }
+        }
+    }
+    Some(tokens)
+}
+
+#[derive(Debug, Clone)]
+enum CheckOperand {
+    Column(String),
+    Literal(Value),
+}
+
+#[derive(Debug, Clone)]
+enum CheckExpr {
+    And(Box<CheckExpr>, Box<CheckExpr>),
+    Or(Box<CheckExpr>, Box<CheckExpr>),
+    Not(Box<CheckExpr>),
+    Compare(CheckOperand, &'static str, CheckOperand),
+    In(CheckOperand, Vec<CheckOperand>, bool),
+    Between(CheckOperand, CheckOperand, CheckOperand, bool),
+    IsNull(CheckOperand, bool),
+    /// A bare boolean column or literal.
+    Operand(CheckOperand),
+}
+
+/// Recursive-descent parser for the restricted CHECK grammar.
+struct CheckParser {
+    tokens: Vec<CheckToken>,
+    pos: usize,
+}
+
+impl CheckParser {
+    fn peek(&self) -> Option<&CheckToken> {
+        self.tokens.get(self.pos)
+    }
+
+    fn eat(&mut self, token: &CheckToken) -> bool {
+        if self.peek() == Some(token) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn eat_keyword(&mut self, keyword: &str) -> bool {
+        match self.peek() {
+            Some(CheckToken::Word(word)) if word.eq_ignore_ascii_case(keyword) => {
+                self.pos += 1;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn or(&mut self) -> Option<CheckExpr> {
+        let mut left = self.and()?;
+        while self.eat_keyword("OR") {
+            left = CheckExpr::Or(Box::new(left), Box::new(self.and()?));
+        }
+        Some(left)
+    }
+
+    fn and(&mut self) -> Option<CheckExpr> {
+        let mut left = self.not()?;
+        while self.eat_keyword("AND") {
+            left = CheckExpr::And(Box::new(left), Box::new(self.not()?));
+        }
+        Some(left)
+    }
+
+    fn not(&mut self) -> Option<CheckExpr> {
+        if self.eat_keyword("NOT") {
+            return Some(CheckExpr::Not(Box::new(self.not()?)));
+        }
+        self.predicate()
+    }
+
+    fn predicate(&mut self) -> Option<CheckExpr> {
+        if self.eat(&CheckToken::LParen) {
+            let inner = self.or()?;
+            return self.eat(&CheckToken::RParen).then_some(inner);
+        }
+
+        let operand = self.operand()?;
+        if let Some(CheckToken::Op(op)) = self.peek() {
+            let op = *op;
+            if op == "-" {
+                return None;
+            }
+            self.pos += 1;
+            return Some(CheckExpr::Compare(operand, op, self.operand()?));
+        }
+        if self.eat_keyword("IS") {
+            let negated = self.eat_keyword("NOT");
+            return self
+                .eat_keyword("NULL")
+                .then_some(CheckExpr::IsNull(operand, negated));
+        }
+        let negated = self.eat_keyword("NOT");
+        if self.eat_keyword("IN") {
+            if !self.eat(&CheckToken::LParen) {
+                return None;
+            }
+            let mut list = vec![self.operand()?];
+            while self.eat(&CheckToken::Comma) {
+                list.push(self.operand()?);
+            }
+            return self
+                .eat(&CheckToken::RParen)
+                .then_some(CheckExpr::In(operand, list, negated));
+        }
+        if self.eat_keyword("BETWEEN") {
+            let low = self.operand()?;
+            if !self.eat_keyword("AND") {
+                return None;
+            }
+            let high = self.operand()?;
+            return Some(CheckExpr::Between(operand, low, high, negated));
+        }
+        (!negated).then_some(CheckExpr::Operand(operand))
+    }
+
+    fn operand(&mut self) -> Option<CheckOperand> {
+        let token = self.peek()?.clone();
+        self.pos += 1;
+        match token {
+            CheckToken::Literal(value) => Some(CheckOperand::Literal(value)),
+            CheckToken::Ident(name) => Some(CheckOperand::Column(name)),
+            CheckToken::Op("-") => match self.operand()? {
+                CheckOperand::Literal(Value::BigInt(n)) => {
+                    Some(CheckOperand::Literal(Value::BigInt(-n)))
+                }
+                CheckOperand::Literal(Value::Double(n)) => {
+                    Some(CheckOperand::Literal(Value::Double(-n)))
+                }
+                CheckOperand::Literal(_) | CheckOperand::Column(_) => None,
+            },
+            CheckToken::Word(word) => {
+                let literal = match word.to_ascii_uppercase().as_str() {
+                    "TRUE" => Value::Bool(true),
+                    "FALSE" => Value::Bool(false),
+                    "NULL" => Value::Null,
+                    "AND" | "OR" | "NOT" | "IN" | "IS" | "BETWEEN" => return None,
+                    _ => return Some(CheckOperand::Column(word)),
+                };
+                Some(CheckOperand::Literal(literal))
+            }
+            CheckToken::Op(_) | CheckToken::LParen | CheckToken::RParen | CheckToken::Comma => None,
+        }
+    }
+}
+
+impl CheckExpr {
+    /// Evaluate to SQL truth (`Some(None)` is UNKNOWN), or `None` if the
+    /// expression can't be evaluated client-side.
+    fn eval<'v>(&self, lookup: &impl Fn(&str) -> Option<&'v Value>) -> Option<Option<bool>> {
+        Some(match self {
+            CheckExpr::And(left, right) => match (left.eval(lookup)?, right.eval(lookup)?) {
+                (Some(false), _) | (_, Some(false)) => Some(false),
+                (Some(true), Some(true)) => Some(true),
+                _ => None,
+            },
+            CheckExpr::Or(left, right) => match (left.eval(lookup)?, right.eval(lookup)?) {
+                (Some(true), _) | (_, Some(true)) => Some(true),
+                (Some(false), Some(false)) => Some(false),
+                _ => None,
+            },
+            CheckExpr::Not(inner) => inner.eval(lookup)?.map(|b| !b),
+            CheckExpr::Compare(left, op, right) => {
+                let ordering = compare_check_values(left.resolve(lookup)?, right.resolve(lookup)?)?;
+                let Some(ordering) = ordering else {
+                    return Some(None);
+                };
+                Some(match *op {
+                    "=" => ordering.is_eq(),
+                    "<>" => ordering.is_ne(),
+                    "<" => ordering.is_lt(),
+                    "<=" => ordering.is_le(),
+                    ">" => ordering.is_gt(),
+                    ">=" => ordering.is_ge(),
+                    _ => return None,
+                })
+            }
+            CheckExpr::In(operand, list, negated) => {
+                let value = operand.resolve(lookup)?;
+                let mut found = Some(false);
+                for item in list {
+                    match compare_check_values(value, item.resolve(lookup)?)? {
+                        Some(ordering) if ordering.is_eq() => {
+                            found = Some(true);
+                            break;
+                        }
+                        Some(_) => {}
+                        None => found = None,
+                    }
+                }
+                found.map(|found| found != *negated)
+            }
+            CheckExpr::Between(operand, low, high, negated) => {
+                let value = operand.resolve(lookup)?;
+                let above = compare_check_values(value, low.resolve(lookup)?)?;
+                let below = compare_check_values(value, high.resolve(lookup)?)?;
+                match (above.map(Ordering::is_ge), below.map(Ordering::is_le)) {
+                    (Some(false), _) | (_, Some(false)) => Some(*negated),
+                    (Some(true), Some(true)) => Some(!*negated),
+                    _ => None,
+                }
+            }
+            CheckExpr::IsNull(operand, negated) => {
+                Some(operand.resolve(lookup)?.is_null() != *negated)
+            }
+            CheckExpr::Operand(operand) => {
+                let value = operand.resolve(lookup)?;
+                if value.is_null() {
+                    None
+                } else {
+                    Some(value.as_bool()?)
+                }
+            }
+        })
+    }
+}
+
+impl CheckOperand {
+    fn resolve<'r, 'v: 'r>(
+        &'r self,
+        lookup: &impl Fn(&str) -> Option<&'v Value>,
+    ) -> Option<&'r Value> {
+        match self {
+            CheckOperand::Column(name) => lookup(name),
+            CheckOperand::Literal(value) => Some(value),
+        }
+    }
+}
+
+/// Order two values for a CHECK comparison: `Some(None)` if either is NULL,
+/// `None` if their types can't be compared.
+fn compare_check_values(a: &Value, b: &Value) -> Option<Option<Ordering>> {
+    if a.is_null() || b.is_null() {
+        return Some(None);
+    }
+    if let (Some(x), Some(y)) = (a.as_i64(), b.as_i64()) {
+        return Some(Some(x.cmp(&y)));
+    }
+    if let (Some(x), Some(y)) = (a.as_f64(), b.as_f64()) {
+        return x.partial_cmp(&y).map(Some);
+    }
+    match (a, b) {
+        (Value::Text(x), Value::Text(y)) => Some(Some(x.cmp(y))),
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -2047,6 +2517,92 @@ mod tests {
         assert_eq!(json["email"], "alice@example.com");
     }
 
+    /// Test model with a field-level `exclude` flag and a default.
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct TestAccount {
+        id: i64,
+        name: String,
+        password: String,
+        role: String,
+    }
+
+    impl Model for TestAccount {
+        const TABLE_NAME: &'static str = "accounts";
+        const PRIMARY_KEY: &'static [&'static str] = &["id"];
+
+        fn fields() -> &'static [FieldInfo] {
+            static FIELDS: &[FieldInfo] = &[
+                FieldInfo::new("id", "id", SqlType::BigInt).primary_key(true),
+                FieldInfo::new("name", "name", SqlType::Text).serialization_alias("userName"),
+                FieldInfo::new("password", "password", SqlType::Text).exclude(true),
+                FieldInfo::new("role", "role", SqlType::Text).default_json(r#""member""#),
+            ];
+            FIELDS
+        }
+
+        fn to_row(&self) -> Vec<(&'static str, Value)> {
+            vec![]
+        }
+
+        fn from_row(_row: &Row) -> crate::Result<Self> {
+            Err(crate::Error::Custom("not used".to_string()))
+        }
+
+        fn primary_key_value(&self) -> Vec<Value> {
+            vec![Value::BigInt(self.id)]
+        }
+
+        fn is_new(&self) -> bool {
+            false
+        }
+    }
+
+    fn test_account() -> TestAccount {
+        TestAccount {
+            id: 1,
+            name: "Alice".to_string(),
+            password: "hunter2".to_string(),
+            role: "member".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_sql_model_dump_field_exclude_wins_over_include() {
+        let json = test_account()
+            .sql_model_dump(DumpOptions::default().include(["name", "password"]))
+            .unwrap();
+        assert_eq!(json, serde_json::json!({"name": "Alice"}));
+
+        let tracked = crate::TrackedModel::all_fields_set(test_account());
+        let json = tracked
+            .sql_model_dump(DumpOptions::default().include(["name", "password"]))
+            .unwrap();
+        assert_eq!(json, serde_json::json!({"name": "Alice"}));
+    }
+
+    #[test]
+    fn test_sql_model_dump_include_exclude_with_aliases_and_defaults() {
+        // Field names and output aliases both match, with or without by_alias.
+        let json = test_account()
+            .sql_model_dump(DumpOptions::default().by_alias().include(["id", "name"]))
+            .unwrap();
+        assert_eq!(json, serde_json::json!({"id": 1, "userName": "Alice"}));
+
+        let json = test_account()
+            .sql_model_dump(DumpOptions::default().exclude(["userName"]))
+            .unwrap();
+        assert_eq!(json, serde_json::json!({"id": 1, "role": "member"}));
+
+        let json = test_account()
+            .sql_model_dump(
+                DumpOptions::default()
+                    .include(["name", "role"])
+                    .exclude_defaults(),
+            )
+            .unwrap();
+        assert_eq!(json, serde_json::json!({"name": "Alice"}));
+    }
+
     #[test]
     fn test_tracked_model_dump_accepts_python_mode_and_round_trip() {
         let user = TestAliasedUser {
@@ -3121,4 +3677,126 @@ mod tests {
         // Note: exclude_none only affects top-level nulls in model_dump
         // Nested nulls are handled by serde's skip_serializing_if
     }
+
+    // ==================== CHECK Evaluation Tests ====================
+
+    #[test]
+    fn test_evaluate_check_grammar() {
+        let row = [
+            ("age", Value::Int(30)),
+            ("price", Value::Double(9.5)),
+            ("status", Value::Text("active".to_string())),
+            ("active", Value::Bool(true)),
+            ("deleted_at", Value::Null),
+        ];
+        let check = |expr: &str| evaluate_check(expr, &row);
+
+        assert_eq!(check("age >= 0"), Some(true));
+        assert_eq!(check("(age >= 0 AND age <= 25)"), Some(false));
+        assert_eq!(check("age > -1 AND price < 10"), Some(true));
+        assert_eq!(check("\"age\" <> 30 OR NOT active"), Some(false));
+        assert_eq!(check("status IN ('active', 'inactive')"), Some(true));
+        assert_eq!(check("status NOT IN ('active')"), Some(false));
+        assert_eq!(check("age BETWEEN 18 AND 65"), Some(true));
+        assert_eq!(check("price NOT BETWEEN 1 AND 10"), Some(false));
+        assert_eq!(
+            check("deleted_at IS NULL AND status IS NOT NULL"),
+            Some(true)
+        );
+        assert_eq!(check("status = 'it''s'"), Some(false));
+
+        // NULL operands make the check unknown, which passes.
+        assert_eq!(check("deleted_at > 5"), Some(true));
+        assert_eq!(check("deleted_at IN (1, 2)"), Some(true));
+
+        // Unsupported expressions are skipped.
+        assert_eq!(check("length(status) > 3"), None);
+        assert_eq!(check("age - 1 > 0"), None);
+        assert_eq!(check("missing = 1"), None);
+        assert_eq!(check("status > 5"), None);
+        assert_eq!(check("age >"), None);
+    }
+
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct TestBooking {
+        id: i64,
+        nights: i32,
+        start_day: i32,
+        end_day: i32,
+        room: String,
+    }
+
+    impl Model for TestBooking {
+        const TABLE_NAME: &'static str = "bookings";
+        const PRIMARY_KEY: &'static [&'static str] = &["id"];
+        const TABLE_CONSTRAINTS: &'static [crate::TableConstraint] =
+            &[crate::TableConstraint::Check("start_day < end_day")];
+
+        fn fields() -> &'static [FieldInfo] {
+            static FIELDS: &[FieldInfo] = &[
+                FieldInfo::new("id", "id", SqlType::BigInt).primary_key(true),
+                FieldInfo::new("nights", "night_count", SqlType::Integer)
+                    .column_constraints(&["CHECK (night_count BETWEEN 1 AND 30)", "NOT NULL"]),
+                FieldInfo::new("start_day", "start_day", SqlType::Integer),
+                FieldInfo::new("end_day", "end_day", SqlType::Integer),
+                FieldInfo::new("room", "room", SqlType::Text).column_constraints(&[
+                    "CONSTRAINT ck_room CHECK(room IN ('single', 'double'))",
+                    "CHECK (length(room) < 20)",
+                ]),
+            ];
+            FIELDS
+        }
+
+        fn to_row(&self) -> Vec<(&'static str, Value)> {
+            vec![
+                ("id", Value::BigInt(self.id)),
+                ("night_count", Value::Int(self.nights)),
+                ("start_day", Value::Int(self.start_day)),
+                ("end_day", Value::Int(self.end_day)),
+                ("room", Value::Text(self.room.clone())),
+            ]
+        }
+
+        fn from_row(_row: &Row) -> crate::Result<Self> {
+            Err(crate::Error::Custom("not used".to_string()))
+        }
+
+        fn primary_key_value(&self) -> Vec<Value> {
+            vec![Value::BigInt(self.id)]
+        }
+
+        fn is_new(&self) -> bool {
+            false
+        }
+    }
+
+    #[test]
+    fn test_validate_checks_reports_failing_fields() {
+        let mut booking = TestBooking {
+            id: 1,
+            nights: 3,
+            start_day: 10,
+            end_day: 13,
+            room: "double".to_string(),
+        };
+        assert!(validate_checks(&booking).is_ok());
+
+        booking.nights = 45;
+        booking.end_day = 2;
+        booking.room = "suite".to_string();
+        let errors = validate_checks(&booking).unwrap_err().errors;
+        let failures: Vec<(&str, &str)> = errors
+            .iter()
+            .map(|e| (e.field.as_str(), e.message.as_str()))
+            .collect();
+        assert_eq!(
+            failures,
+            vec![
+                ("nights", "violates CHECK (night_count BETWEEN 1 AND 30)"),
+                ("room", "violates CHECK (room IN ('single', 'double'))"),
+                ("__model__", "violates CHECK (start_day < end_day)"),
+            ]
+        );
+        assert!(errors.iter().all(|e| e.kind == ValidationErrorKind::Check));
+    }
 }
@@ -26,13 +26,16 @@ pub enum Value {
     /// 64-bit signed integer
     BigInt(i64),
 
-    /// 32-bit floating point
+    /// 32-bit floating point; NaN and the infinities are kept as such
     Float(f32),
 
-    /// 64-bit floating point
+    /// 64-bit floating point; NaN and the infinities are kept as such
     Double(f64),
 
-    /// Arbitrary precision decimal (stored as string)
+    /// Arbitrary precision decimal (stored as string).
+    ///
+    /// Besides plain numbers, `"NaN"`, `"Infinity"` and `"-Infinity"` stand
+    /// for the special values of PostgreSQL's `numeric`.
     Decimal(String),
 
     /// Text string
@@ -64,18 +67,38 @@ pub enum Value {
 
     /// SQL DEFAULT keyword
     Default,
+
+    /// NULL value with a known SQL type.
+    ///
+    /// Behaves like [`Value::Null`] except when bound as a parameter: drivers
+    /// that declare parameter types (PostgreSQL) send this type instead of
+    /// leaving the server to infer it, which fails in ambiguous positions
+    /// such as `$1 IS NULL`. Serializes exactly as `Null` (so serialized
+    /// snapshots treat the two alike) and deserializes as `Null`.
+    //
+    // Untagged variants must come last.
+    #[serde(untagged, serialize_with = "serialize_typed_null", skip_deserializing)]
+    TypedNull(SqlType),
+}
+
+/// Serialize a [`Value::TypedNull`] the way [`Value::Null`] serializes.
+fn serialize_typed_null<S: serde::Serializer>(
+    _: &SqlType,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    Value::Null.serialize(serializer)
 }
 
 impl Value {
     /// Check if this value is NULL.
     pub const fn is_null(&self) -> bool {
-        matches!(self, Value::Null)
+        matches!(self, Value::Null | Value::TypedNull(_))
     }
 
     /// Get the type name of this value.
     pub const fn type_name(&self) -> &'static str {
         match self {
-            Value::Null => "NULL",
+            Value::Null | Value::TypedNull(_) => "NULL",
             Value::Bool(_) => "BOOLEAN",
             Value::TinyInt(_) => "TINYINT",
             Value::SmallInt(_) => "SMALLINT",
@@ -153,6 +176,111 @@ impl Value {
         }
     }
 
+    /// Look up a nested JSON value by RFC 6901 pointer (e.g. `"/user/tags/0"`).
+    ///
+    /// Returns `None` if this is not a `Value::Json` or the pointer does not resolve.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqlmodel_core::Value;
+    ///
+    /// let v = Value::Json(serde_json::json!({"user": {"tags": ["a", "b"]}}));
+    /// assert_eq!(v.json_pointer("/user/tags/1"), Some(&serde_json::json!("b")));
+    /// assert_eq!(v.json_pointer("/missing"), None);
+    /// ```
+    pub fn json_pointer(&self, ptr: &str) -> Option<&serde_json::Value> {
+        match self {
+            Value::Json(json) => json.pointer(ptr),
+            _ => None,
+        }
+    }
+
+    /// Return a copy of this JSON value with the location at `ptr` set to `new`.
+    ///
+    /// The parent of `ptr` must already exist. Object members are inserted or
+    /// replaced; array elements are replaced by index, and `-` (or an index
+    /// equal to the length) appends. An empty pointer replaces the whole document.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqlmodel_core::Value;
+    ///
+    /// let v = Value::Json(serde_json::json!({"user": {"name": "Ann", "tags": []}}));
+    /// let updated = v
+    ///     .json_set("/user/name", "Bea")
+    ///     .and_then(|v| v.json_set("/user/tags/-", "admin"))
+    ///     .unwrap();
+    /// assert_eq!(
+    ///     updated,
+    ///     Value::Json(serde_json::json!({"user": {"name": "Bea", "tags": ["admin"]}}))
+    /// );
+    /// ```
+    #[allow(clippy::result_large_err)]
+    pub fn json_set(&self, ptr: &str, new: impl Into<serde_json::Value>) -> crate::Result<Value> {
+        let Value::Json(json) = self else {
+            return Err(Error::Type(TypeError {
+                expected: "JSON",
+                actual: self.type_name().to_string(),
+                column: None,
+                rust_type: None,
+            }));
+        };
+
+        let mut json = json.clone();
+        let new = new.into();
+        if ptr.is_empty() {
+            return Ok(Value::Json(new));
+        }
+
+        let Some((parent_ptr, token)) = ptr.rsplit_once('/') else {
+            return Err(Error::Custom(format!(
+                "invalid JSON pointer '{ptr}': must be empty or start with '/'"
+            )));
+        };
+        let key = token.replace("~1", "/").replace("~0", "~");
+        let Some(parent) = json.pointer_mut(parent_ptr) else {
+            return Err(Error::Custom(format!(
+                "JSON pointer '{ptr}': parent '{parent_ptr}' does not exist"
+            )));
+        };
+
+        match parent {
+            serde_json::Value::Object(map) => {
+                map.insert(key, new);
+            }
+            serde_json::Value::Array(items) => {
+                let index = if key == "-" {
+                    items.len()
+                } else {
+                    key.parse::<usize>().map_err(|_| {
+                        Error::Custom(format!(
+                            "JSON pointer '{ptr}': '{key}' is not a valid array index"
+                        ))
+                    })?
+                };
+                match index.cmp(&items.len()) {
+                    std::cmp::Ordering::Less => items[index] = new,
+                    std::cmp::Ordering::Equal => items.push(new),
+                    std::cmp::Ordering::Greater => {
+                        return Err(Error::Custom(format!(
+                            "JSON pointer '{ptr}': index {index} out of bounds (len {})",
+                            items.len()
+                        )));
+                    }
+                }
+            }
+            _ => {
+                return Err(Error::Custom(format!(
+                    "JSON pointer '{ptr}': parent '{parent_ptr}' is not an object or array"
+                )));
+            }
+        }
+
+        Ok(Value::Json(json))
+    }
+
     /// Convert a `u64` to `Value`, clamping to `i64::MAX` if it overflows.
     ///
     /// This is a convenience method for cases where you want to store large `u64`
@@ -186,6 +314,28 @@ impl Value {
         }
     }
 
+    /// Escape `%`, `_` and `escape_char` in `literal` so a LIKE pattern built
+    /// from it matches the text exactly.
+    ///
+    /// Pair the result with `ESCAPE '<escape_char>'` in the LIKE clause.
+    ///
+    /// ```
+    /// use sqlmodel_core::Value;
+    ///
+    /// assert_eq!(Value::like_escape("50%_off\\", '\\'), "50\\%\\_off\\\\");
+    /// ```
+    #[must_use]
+    pub fn like_escape(literal: &str, escape_char: char) -> String {
+        let mut escaped = String::with_capacity(literal.len());
+        for ch in literal.chars() {
+            if ch == '%' || ch == '_' || ch == escape_char {
+                escaped.push(escape_char);
+            }
+            escaped.push(ch);
+        }
+        escaped
+    }
+
     /// Convert to f32, allowing precision loss for large values.
     ///
     /// This is more lenient than `TryFrom<Value> for f32`, which errors on precision loss.
@@ -266,6 +416,410 @@ impl Value {
             })),
         }
     }
+
+    /// Convert this value to the representation expected for `target`.
+    ///
+    /// Integer widening always succeeds and narrowing is checked (overflow is
+    /// an error). Numbers and text convert in either direction when the text
+    /// parses unambiguously, and floats convert to integers only when they
+    /// hold an exact integral value. Integers become booleans by comparing
+    /// with zero. NULL passes through unchanged, as does any value whose
+    /// target has no coercion rule (dates, JSON, ...), leaving the final
+    /// check to the `FromValue` conversion.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqlmodel_core::{SqlType, Value};
+    ///
+    /// assert_eq!(Value::BigInt(7).coerce(SqlType::Integer).unwrap(), Value::Int(7));
+    /// assert!(Value::BigInt(i64::MAX).coerce(SqlType::Integer).is_err());
+    /// assert_eq!(Value::Text("42".into()).coerce(SqlType::BigInt).unwrap(), Value::BigInt(42));
+    /// assert_eq!(Value::Null.coerce(SqlType::Integer).unwrap(), Value::Null);
+    /// ```
+    #[allow(clippy::result_large_err)]
+    pub fn coerce(self, target: SqlType) -> crate::Result<Value> {
+        if self.is_null() || matches!(self, Value::Default) {
+            return Ok(self);
+        }
+        match target {
+            SqlType::TinyInt => {
+                let v = self.coerce_to_i64("i8")?;
+                i8::try_from(v)
+                    .map(Value::TinyInt)
+                    .map_err(|_| coerce_overflow(v, "i8"))
+            }
+            SqlType::SmallInt => {
+                let v = self.coerce_to_i64("i16")?;
+                i16::try_from(v)
+                    .map(Value::SmallInt)
+                    .map_err(|_| coerce_overflow(v, "i16"))
+            }
+            SqlType::Integer => {
+                let v = self.coerce_to_i64("i32")?;
+                i32::try_from(v)
+                    .map(Value::Int)
+                    .map_err(|_| coerce_overflow(v, "i32"))
+            }
+            SqlType::BigInt => self.coerce_to_i64("i64").map(Value::BigInt),
+            SqlType::Real => match self {
+                Value::Text(ref t) | Value::Decimal(ref t) => match t.trim().parse::<f32>() {
+                    Ok(v) => Ok(Value::Float(v)),
+                    Err(_) => Err(coerce_error(&self, "f32")),
+                },
+                other => f32::try_from(other).map(Value::Float),
+            },
+            SqlType::Double => match self {
+                Value::Text(ref t) | Value::Decimal(ref t) => match t.trim().parse::<f64>() {
+                    Ok(v) => Ok(Value::Double(v)),
+                    Err(_) => Err(coerce_error(&self, "f64")),
+                },
+                other => f64::try_from(other).map(Value::Double),
+            },
+            SqlType::Numeric { .. } | SqlType::Decimal { .. } => match self {
+                Value::Decimal(_) => Ok(self),
+                Value::TinyInt(_)
+                | Value::SmallInt(_)
+                | Value::Int(_)
+                | Value::BigInt(_)
+                | Value::Float(_)
+                | Value::Double(_) => Ok(Value::Decimal(self.numeric_text())),
+                Value::Text(ref t) if t.trim().parse::<f64>().is_ok() => {
+                    Ok(Value::Decimal(t.trim().to_string()))
+                }
+                _ => Err(coerce_error(&self, "decimal")),
+            },
+            SqlType::Boolean => match self {
+                Value::Bool(_) => Ok(self),
+                // Any non-zero integer is true, as in `TryFrom<Value> for bool`.
+                Value::TinyInt(_) | Value::SmallInt(_) | Value::Int(_) | Value::BigInt(_) => {
+                    match self.as_i64() {
+                        Some(n) => Ok(Value::Bool(n != 0)),
+                        None => Err(coerce_error(&self, "bool")),
+                    }
+                }
+                Value::Text(ref t) => match t.trim().to_ascii_lowercase().as_str() {
+                    "true" | "t" | "1" => Ok(Value::Bool(true)),
+                    "false" | "f" | "0" => Ok(Value::Bool(false)),
+                    _ => Err(coerce_error(&self, "bool")),
+                },
+                _ => Err(coerce_error(&self, "bool")),
+            },
+            SqlType::Char(_) | SqlType::VarChar(_) | SqlType::Text => match self {
+                Value::Text(_) => Ok(self),
+                Value::Decimal(t) => Ok(Value::Text(t)),
+                Value::Bool(b) => Ok(Value::Text(b.to_string())),
+                Value::TinyInt(_)
+                | Value::SmallInt(_)
+                | Value::Int(_)
+                | Value::BigInt(_)
+                | Value::Float(_)
+                | Value::Double(_) => Ok(Value::Text(self.numeric_text())),
+                _ => Err(coerce_error(&self, "text")),
+            },
+            _ => Ok(self),
+        }
+    }
+
+    /// Integer view of a value for [`coerce`](Self::coerce).
+    #[allow(clippy::result_large_err, clippy::cast_possible_truncation)]
+    fn coerce_to_i64(&self, expected: &'static str) -> crate::Result<i64> {
+        const LIMIT: f64 = 9_223_372_036_854_775_808.0; // 2^63
+        let from_float = |f: f64| {
+            if f.is_finite() && f.fract() == 0.0 && (-LIMIT..LIMIT).contains(&f) {
+                Ok(f as i64)
+            } else {
+                Err(coerce_error(self, expected))
+            }
+        };
+        match self {
+            Value::TinyInt(_) | Value::SmallInt(_) | Value::Int(_) | Value::BigInt(_) => {
+                Ok(self.as_i64().unwrap_or_default())
+            }
+            Value::Bool(b) => Ok(i64::from(*b)),
+            Value::Float(f) => from_float(f64::from(*f)),
+            Value::Double(f) => from_float(*f),
+            Value::Text(t) | Value::Decimal(t) => {
+                let t = t.trim();
+                match t.parse::<i64>() {
+                    Ok(v) => Ok(v),
+                    Err(_) => match t.parse::<f64>() {
+                        Ok(f) if matches!(self, Value::Decimal(_)) => from_float(f),
+                        _ => Err(coerce_error(self, expected)),
+                    },
+                }
+            }
+            _ => Err(coerce_error(self, expected)),
+        }
+    }
+
+    /// Decimal text for a numeric value.
+    fn numeric_text(&self) -> String {
+        match self {
+            Value::TinyInt(v) => v.to_string(),
+            Value::SmallInt(v) => v.to_string(),
+            Value::Int(v) => v.to_string(),
+            Value::BigInt(v) => v.to_string(),
+            Value::Float(v) => v.to_string(),
+            Value::Double(v) => v.to_string(),
+            Value::Decimal(v) | Value::Text(v) => v.clone(),
+            other => other.type_name().to_string(),
+        }
+    }
+
+    /// Exact sum of two decimals.
+    ///
+    /// Operands may be `Decimal`, integer, or `Text` holding a plain decimal
+    /// literal; floats are rejected since they are already inexact. The
+    /// result keeps the larger of the two scales, as SQL `NUMERIC` does.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqlmodel_core::Value;
+    ///
+    /// let sum = Value::Decimal("0.10".into()).decimal_add(&Value::Decimal("0.20".into()));
+    /// assert_eq!(sum.unwrap(), Value::Decimal("0.30".into()));
+    /// ```
+    #[allow(clippy::result_large_err)]
+    pub fn decimal_add(&self, other: &Value) -> crate::Result<Value> {
+        let (a, b) = (
+            DecimalNum::from_value(self)?,
+            DecimalNum::from_value(other)?,
+        );
+        Ok(Value::Decimal(a.add(&b).to_string()))
+    }
+
+    /// Exact product of two decimals; the result scale is the sum of the
+    /// operand scales (`1.5 * 0.25 = 0.375`). Operands as for
+    /// [`decimal_add`](Self::decimal_add).
+    #[allow(clippy::result_large_err)]
+    pub fn decimal_mul(&self, other: &Value) -> crate::Result<Value> {
+        let (a, b) = (
+            DecimalNum::from_value(self)?,
+            DecimalNum::from_value(other)?,
+        );
+        Ok(Value::Decimal(a.mul(&b).to_string()))
+    }
+
+    /// Round a decimal to `scale` fractional digits, halves away from zero
+    /// (`2.345 -> 2.35`, `-2.345 -> -2.35`). A value with `scale` or fewer
+    /// fractional digits is returned unchanged (`2.5` stays `2.5`).
+    #[allow(clippy::result_large_err)]
+    pub fn decimal_round(&self, scale: u32) -> crate::Result<Value> {
+        let scale = usize::try_from(scale).unwrap_or(usize::MAX);
+        Ok(Value::Decimal(
+            DecimalNum::from_value(self)?.round(scale).to_string(),
+        ))
+    }
+}
+
+fn coerce_error(value: &Value, expected: &'static str) -> Error {
+    Error::Type(TypeError {
+        expected,
+        actual: format!("{} cannot be coerced", value.type_name()),
+        column: None,
+        rust_type: None,
+    })
+}
+
+fn coerce_overflow(value: i64, expected: &'static str) -> Error {
+    Error::Type(TypeError {
+        expected,
+        actual: format!("integer {value} out of range"),
+        column: None,
+        rust_type: None,
+    })
+}
+
+/// Sign, magnitude digits (most significant first) and scale of a decimal,
+/// for the exact arithmetic behind [`Value::decimal_add`] and friends.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct DecimalNum {
+    negative: bool,
+    digits: Vec<u8>,
+    scale: usize,
+}
+
+impl DecimalNum {
+    #[allow(clippy::result_large_err)]
+    fn from_value(value: &Value) -> crate::Result<Self> {
+        let text = match value {
+            Value::Decimal(t) | Value::Text(t) => t.trim().to_string(),
+            Value::TinyInt(_) | Value::SmallInt(_) | Value::Int(_) | Value::BigInt(_) => {
+                value.numeric_text()
+            }
+            _ => return Err(coerce_error(value, "decimal")),
+        };
+        Self::parse(&text).ok_or_else(|| {
+            Error::Type(TypeError {
+                expected: "decimal",
+                actual: format!("'{text}' is not a decimal literal"),
+                column: None,
+                rust_type: None,
+            })
+        })
+    }
+
+    /// Parse `[+-]digits[.digits]`; at least one digit overall.
+    fn parse(text: &str) -> Option<Self> {
+        let (negative, unsigned) = match text.as_bytes().first() {
+            Some(b'-') => (true, &text[1..]),
+            Some(b'+') => (false, &text[1..]),
+            _ => (false, text),
+        };
+        let (int_part, frac_part) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+        let all_digits = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+        if int_part.len() + frac_part.len() == 0 || !all_digits(int_part) || !all_digits(frac_part)
+        {
+            return None;
+        }
+        let digits = int_part
+            .bytes()
+            .chain(frac_part.bytes())
+            .map(|b| b - b'0')
+            .collect();
+        Some(Self {
+            negative,
+            digits,
+            scale: frac_part.len(),
+        })
+    }
+
+    /// Magnitude digits with `scale` fractional digits (`scale >= self.scale`).
+    fn digits_at(&self, scale: usize) -> Vec<u8> {
+        let mut digits = self.digits.clone();
+        digits.resize(digits.len() + scale - self.scale, 0);
+        digits
+    }
+
+    fn add(&self, other: &Self) -> Self {
+        let scale = self.scale.max(other.scale);
+        let (a, b) = (self.digits_at(scale), other.digits_at(scale));
+        let (negative, digits) = if self.negative == other.negative {
+            (self.negative, add_magnitudes(&a, &b))
+        } else if compare_magnitudes(&a, &b).is_ge() {
+            (self.negative, sub_magnitudes(&a, &b))
+        } else {
+            (other.negative, sub_magnitudes(&b, &a))
+        };
+        Self {
+            negative,
+            digits,
+            scale,
+        }
+    }
+
+    fn mul(&self, other: &Self) -> Self {
+        let mut product = vec![0u32; self.digits.len() + other.digits.len()];
+        for (i, &a) in self.digits.iter().rev().enumerate() {
+            for (j, &b) in other.digits.iter().rev().enumerate() {
+                product[i + j] += u32::from(a) * u32::from(b);
+            }
+        }
+        let mut carry = 0;
+        let mut digits = Vec::with_capacity(product.len());
+        for slot in product {
+            let total = slot + carry;
+            digits.push(u8::try_from(total % 10).unwrap_or_default());
+            carry = total / 10;
+        }
+        digits.reverse();
+        Self {
+            negative: self.negative != other.negative,
+            digits,
+            scale: self.scale + other.scale,
+        }
+    }
+
+    fn round(&self, scale: usize) -> Self {
+        if scale >= self.scale {
+            return self.clone();
+        }
+        let keep = self.digits.len() - (self.scale - scale);
+        let mut digits = self.digits[..keep].to_vec();
+        if self.digits[keep] >= 5 {
+            digits = add_magnitudes(&digits, &[1]);
+        }
+        Self {
+            negative: self.negative,
+            digits,
+            scale,
+        }
+    }
+}
+
+impl std::fmt::Display for DecimalNum {
+    /// Canonical text: no redundant leading zeros, exactly `scale` fractional
+    /// digits, and no sign on zero.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut digits: Vec<u8> = self.digits.clone();
+        if digits.len() <= self.scale {
+            let mut padded = vec![0; self.scale + 1 - digits.len()];
+            padded.extend(digits);
+            digits = padded;
+        }
+        let int_len = digits.len() - self.scale;
+        let first = digits[..int_len - 1]
+            .iter()
+            .position(|&d| d != 0)
+            .unwrap_or(int_len - 1);
+        if self.negative && digits.iter().any(|&d| d != 0) {
+            f.write_str("-")?;
+        }
+        let text = |ds: &[u8]| ds.iter().map(|d| char::from(b'0' + d)).collect::<String>();
+        f.write_str(&text(&digits[first..int_len]))?;
+        if self.scale > 0 {
+            write!(f, ".{}", text(&digits[int_len..]))?;
+        }
+        Ok(())
+    }
+}
+
+/// Compare two digit magnitudes, ignoring leading zeros.
+fn compare_magnitudes(a: &[u8], b: &[u8]) -> std::cmp::Ordering {
+    let strip = |ds: &[u8]| -> usize { ds.iter().position(|&d| d != 0).unwrap_or(ds.len()) };
+    let (a, b) = (&a[strip(a)..], &b[strip(b)..]);
+    a.len().cmp(&b.len()).then_with(|| a.cmp(b))
+}
+
+fn add_magnitudes(a: &[u8], b: &[u8]) -> Vec<u8> {
+    let mut sum = Vec::with_capacity(a.len().max(b.len()) + 1);
+    let (mut ai, mut bi) = (a.iter().rev(), b.iter().rev());
+    let mut carry = 0;
+    loop {
+        let (x, y) = (ai.next(), bi.next());
+        if x.is_none() && y.is_none() {
+            break;
+        }
+        let total = x.copied().unwrap_or(0) + y.copied().unwrap_or(0) + carry;
+        sum.push(total % 10);
+        carry = total / 10;
+    }
+    if carry > 0 {
+        sum.push(carry);
+    }
+    sum.reverse();
+    sum
+}
+
+/// `a - b` for magnitudes with `a >= b`.
+fn sub_magnitudes(a: &[u8], b: &[u8]) -> Vec<u8> {
+    let mut diff = Vec::with_capacity(a.len());
+    let mut bi = b.iter().rev();
+    let mut borrow = 0;
+    for &x in a.iter().rev() {
+        let y = bi.next().copied().unwrap_or(0) + borrow;
+        if x >= y {
+            diff.push(x - y);
+            borrow = 0;
+        } else {
+            diff.push(x + 10 - y);
+            borrow = 1;
+        }
+    }
+    diff.reverse();
+    diff
 }
 
 // Conversion implementations
@@ -431,6 +985,7 @@ impl From<Vec<bool>> for Value {
 // TryFrom implementations for extracting values
 
 use crate::error::{Error, TypeError};
+use crate::types::SqlType;
 
 impl TryFrom<Value> for bool {
     type Error = Error;
@@ -730,7 +1285,7 @@ where
 
     fn try_from(value: Value) -> Result<Self, Self::Error> {
         match value {
-            Value::Null => Ok(None),
+            Value::Null | Value::TypedNull(_) => Ok(None),
             v => T::try_from(v).map(Some),
         }
     }
@@ -824,6 +1379,154 @@ impl TryFrom<Value> for Vec<f64> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_coerce_integers() {
+        assert_eq!(
+            Value::Int(5).coerce(SqlType::BigInt).unwrap(),
+            Value::BigInt(5)
+        );
+        assert_eq!(
+            Value::BigInt(5).coerce(SqlType::Integer).unwrap(),
+            Value::Int(5)
+        );
+        assert_eq!(
+            Value::SmallInt(-3).coerce(SqlType::TinyInt).unwrap(),
+            Value::TinyInt(-3)
+        );
+        assert!(
+            Value::BigInt(i64::from(i32::MAX) + 1)
+                .coerce(SqlType::Integer)
+                .is_err()
+        );
+        assert!(Value::Int(300).coerce(SqlType::TinyInt).is_err());
+        assert_eq!(
+            Value::Double(4.0).coerce(SqlType::Integer).unwrap(),
+            Value::Int(4)
+        );
+        assert!(Value::Double(4.5).coerce(SqlType::Integer).is_err());
+        assert_eq!(
+            Value::Text(" 12 ".into())
+                .coerce(SqlType::SmallInt)
+                .unwrap(),
+            Value::SmallInt(12)
+        );
+        assert!(Value::Text("12abc".into()).coerce(SqlType::BigInt).is_err());
+        assert_eq!(
+            Value::Decimal("7.00".into())
+                .coerce(SqlType::BigInt)
+                .unwrap(),
+            Value::BigInt(7)
+        );
+    }
+
+    #[test]
+    fn test_coerce_text_float_bool_and_null() {
+        assert_eq!(
+            Value::BigInt(42).coerce(SqlType::Text).unwrap(),
+            Value::Text("42".into())
+        );
+        assert_eq!(
+            Value::Text("2.5".into()).coerce(SqlType::Double).unwrap(),
+            Value::Double(2.5)
+        );
+        assert_eq!(
+            Value::Int(3).coerce(SqlType::Double).unwrap(),
+            Value::Double(3.0)
+        );
+        assert_eq!(
+            Value::Int(3)
+                .coerce(SqlType::Decimal {
+                    precision: 10,
+                    scale: 2
+                })
+                .unwrap(),
+            Value::Decimal("3".into())
+        );
+        assert_eq!(
+            Value::Text("t".into()).coerce(SqlType::Boolean).unwrap(),
+            Value::Bool(true)
+        );
+        assert_eq!(
+            Value::Int(0).coerce(SqlType::Boolean).unwrap(),
+            Value::Bool(false)
+        );
+        assert_eq!(
+            Value::Int(2).coerce(SqlType::Boolean).unwrap(),
+            Value::Bool(true)
+        );
+        assert_eq!(
+            Value::BigInt(-1).coerce(SqlType::Boolean).unwrap(),
+            Value::Bool(true)
+        );
+        assert!(Value::Bytes(vec![1]).coerce(SqlType::Text).is_err());
+        assert_eq!(Value::Null.coerce(SqlType::Integer).unwrap(), Value::Null);
+        // Targets without a coercion rule pass the value through.
+        assert_eq!(Value::Int(1).coerce(SqlType::Date).unwrap(), Value::Int(1));
+    }
+
+    #[test]
+    fn test_json_pointer() {
+        let v = Value::Json(serde_json::json!({"a": {"b~c": [1, {"d/e": true}]}}));
+        assert_eq!(v.json_pointer("/a/b~0c/0"), Some(&serde_json::json!(1)));
+        assert_eq!(
+            v.json_pointer("/a/b~0c/1/d~1e"),
+            Some(&serde_json::json!(true))
+        );
+        assert_eq!(
+            v.json_pointer(""),
+            Some(&serde_json::json!({"a": {"b~c": [1, {"d/e": true}]}}))
+        );
+        assert_eq!(v.json_pointer("/a/x"), None);
+        assert_eq!(Value::Text("{}".into()).json_pointer(""), None);
+    }
+
+    #[test]
+    fn test_json_set_objects_and_arrays() {
+        let v = Value::Json(serde_json::json!({"user": {"name": "Ann"}, "tags": ["x"]}));
+
+        let updated = v.json_set("/user/name", "Bea").unwrap();
+        assert_eq!(
+            updated.json_pointer("/user/name"),
+            Some(&serde_json::json!("Bea"))
+        );
+        // The original is untouched.
+        assert_eq!(
+            v.json_pointer("/user/name"),
+            Some(&serde_json::json!("Ann"))
+        );
+
+        let updated = updated.json_set("/user/a~1b", 1).unwrap();
+        assert_eq!(
+            updated.json_pointer("/user/a~1b"),
+            Some(&serde_json::json!(1))
+        );
+
+        let updated = updated.json_set("/tags/0", "y").unwrap();
+        let updated = updated.json_set("/tags/-", "z").unwrap();
+        let updated = updated.json_set("/tags/2", "w").unwrap();
+        assert_eq!(
+            updated.json_pointer("/tags"),
+            Some(&serde_json::json!(["y", "z", "w"]))
+        );
+
+        let replaced = v.json_set("", serde_json::json!([1])).unwrap();
+        assert_eq!(replaced, Value::Json(serde_json::json!([1])));
+    }
+
+    #[test]
+    fn test_json_set_errors() {
+        let v = Value::Json(serde_json::json!({"tags": ["x"], "n": 1}));
+        assert!(v.json_set("/missing/key", 1).is_err());
+        assert!(v.json_set("/tags/5", 1).is_err());
+        assert!(v.json_set("/tags/abc", 1).is_err());
+        assert!(v.json_set("/n/x", 1).is_err());
+        assert!(v.json_set("no-slash", 1).is_err());
+        assert!(matches!(
+            Value::Int(1).json_set("/a", 1),
+            Err(Error::Type(_))
+        ));
+    }
+
     #[test]
     fn test_from_bool() {
         let v: Value = true.into();
@@ -982,9 +1685,21 @@ mod tests {
         assert_eq!(original, recovered);
     }
 
+    #[test]
+    fn test_typed_null_serializes_as_null() {
+        let typed = serde_json::to_vec(&[Value::TypedNull(SqlType::Integer)]).unwrap();
+        assert_eq!(typed, serde_json::to_vec(&[Value::Null]).unwrap());
+        let back: Vec<Value> = serde_json::from_slice(&typed).unwrap();
+        assert_eq!(back, [Value::Null]);
+    }
+
     #[test]
     fn test_is_null() {
         assert!(Value::Null.is_null());
+        assert!(Value::TypedNull(SqlType::Integer).is_null());
+        assert_eq!(Value::TypedNull(SqlType::Text).type_name(), "NULL");
+        let typed: Option<i32> = Option::try_from(Value::TypedNull(SqlType::Integer)).unwrap();
+        assert_eq!(typed, None);
         assert!(!Value::Int(0).is_null());
         assert!(!Value::Bool(false).is_null());
     }
@@ -1413,4 +2128,38 @@ mod tests {
         let v: f32 = Value::Float(original).try_into().unwrap();
         assert!((v - original).abs() < f32::EPSILON);
     }
+
+    #[test]
+    fn test_decimal_arithmetic_is_exact() {
+        let dec = |s: &str| Value::Decimal(s.to_string());
+        assert_eq!(dec("0.10").decimal_add(&dec("0.20")).unwrap(), dec("0.30"));
+        assert_eq!(
+            dec("0.10").decimal_add(&dec("-0.20")).unwrap(),
+            dec("-0.10")
+        );
+        assert_eq!(
+            dec("999.99").decimal_add(&dec("0.01")).unwrap(),
+            dec("1000.00")
+        );
+        assert_eq!(dec("1").decimal_add(&dec("-1.00")).unwrap(), dec("0.00"));
+        assert_eq!(
+            dec("19.99").decimal_add(&Value::Int(5)).unwrap(),
+            dec("24.99")
+        );
+
+        assert_eq!(dec("1.5").decimal_mul(&dec("0.25")).unwrap(), dec("0.375"));
+        assert_eq!(dec("-2").decimal_mul(&dec("3.10")).unwrap(), dec("-6.20"));
+        assert_eq!(dec("0").decimal_mul(&dec("-1.5")).unwrap(), dec("0.0"));
+
+        assert_eq!(dec("2.345").decimal_round(2).unwrap(), dec("2.35"));
+        assert_eq!(dec("-2.345").decimal_round(2).unwrap(), dec("-2.35"));
+        assert_eq!(dec("9.995").decimal_round(2).unwrap(), dec("10.00"));
+        assert_eq!(dec("-0.004").decimal_round(2).unwrap(), dec("0.00"));
+        assert_eq!(dec("2.5").decimal_round(3).unwrap(), dec("2.5"));
+        assert_eq!(dec("-2.5").decimal_round(u32::MAX).unwrap(), dec("-2.5"));
+
+        assert!(dec("1e5").decimal_add(&dec("1")).is_err());
+        assert!(dec("NaN").decimal_round(0).is_err());
+        assert!(Value::Double(0.1).decimal_add(&dec("0.2")).is_err());
+    }
 }
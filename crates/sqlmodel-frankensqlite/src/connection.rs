@@ -283,6 +283,11 @@ impl FrankenConnection {
                 message: "Already in a transaction".to_string(),
                 detail: None,
                 hint: None,
+                schema: None,
+                table: None,
+                column: None,
+                constraint: None,
+                where_: None,
                 position: None,
                 source: None,
             }));
@@ -314,6 +319,11 @@ impl FrankenConnection {
                 message: "Not in a transaction".to_string(),
                 detail: None,
                 hint: None,
+                schema: None,
+                table: None,
+                column: None,
+                constraint: None,
+                where_: None,
                 position: None,
                 source: None,
             }));
@@ -339,6 +349,11 @@ impl FrankenConnection {
                 message: "Not in a transaction".to_string(),
                 detail: None,
                 hint: None,
+                schema: None,
+                table: None,
+                column: None,
+                constraint: None,
+                where_: None,
                 position: None,
                 source: None,
             }));
@@ -1094,6 +1109,11 @@ fn franken_to_query_error(e: &fsqlite_error::FrankenError, sql: &str) -> Error {
         message: e.to_string(),
         detail: None,
         hint: None,
+        schema: None,
+        table: None,
+        column: None,
+        constraint: None,
+        where_: None,
         position: None,
         source: None,
     })
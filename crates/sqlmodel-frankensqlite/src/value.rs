@@ -7,7 +7,7 @@ use sqlmodel_core::value::Value;
 #[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
 pub fn value_to_sqlite(v: &Value) -> SqliteValue {
     match v {
-        Value::Null | Value::Default => SqliteValue::Null,
+        Value::Null | Value::TypedNull(_) | Value::Default => SqliteValue::Null,
         Value::Bool(b) => SqliteValue::Integer(i64::from(*b)),
         Value::TinyInt(i) => SqliteValue::Integer(i64::from(*i)),
         Value::SmallInt(i) => SqliteValue::Integer(i64::from(*i)),
@@ -93,6 +93,21 @@ pub fn infer_sql_type(ty: &Type) -> TokenStream {
     }
 }
 
+/// SQL type that `from_row` coerces a column value to before converting it
+/// into the field's Rust type.
+///
+/// Only primitive numeric, boolean and string fields get a target; other
+/// types (custom enums, JSON, dates, ...) convert from the raw value.
+pub fn coercion_target(ty: &Type) -> Option<TokenStream> {
+    let type_str = type_to_string(unwrap_option_type(ty));
+    match type_str.as_str() {
+        "bool" | "i8" | "i16" | "i32" | "i64" | "f32" | "f64" | "String" => {
+            Some(infer_sql_type(ty))
+        }
+        _ => None,
+    }
+}
+
 /// Parse an explicit sql_type attribute string into a SqlType TokenStream.
 ///
 /// Supports common SQL type names:
@@ -21,7 +21,7 @@ mod parse;
 mod validate;
 mod validate_derive;
 
-use parse::{InheritanceStrategy, ModelDef, RelationshipKindAttr, parse_model};
+use parse::{DisplayHintAttr, InheritanceStrategy, ModelDef, RelationshipKindAttr, parse_model};
 
 /// Derive macro for the `Model` trait.
 ///
@@ -43,6 +43,9 @@ use parse::{InheritanceStrategy, ModelDef, RelationshipKindAttr, parse_model};
 /// - `#[sqlmodel(foreign_key = "table.column")]` - Add foreign key reference
 /// - `#[sqlmodel(index = "name")]` - Add to named index
 /// - `#[sqlmodel(skip)]` - Skip this field in database operations
+/// - `#[sqlmodel(version)]` - Optimistic-concurrency counter, bumped and checked on UPDATE
+/// - `#[sqlmodel(display = "hint")]` - Console display hint: `hash`, `percent`, `currency`,
+///   `currency(N)`, `bytes`, `hex`, `hex(N)` or `base64`
 ///
 /// # Example
 ///
@@ -146,6 +149,9 @@ fn generate_model_impl(model: &ModelDef) -> proc_macro2::TokenStream {
     // Generate from_row implementation
     let from_row_body = generate_from_row(model);
 
+    // Generate from_partial_row implementation
+    let from_partial_row_body = generate_from_partial_row(model);
+
     // Generate primary_key_value implementation
     let pk_value_body = generate_primary_key_value(model);
 
@@ -161,6 +167,50 @@ fn generate_model_impl(model: &ModelDef) -> proc_macro2::TokenStream {
     // Generate shard_key implementation
     let (shard_key_const, shard_key_value_body) = generate_shard_key(model);
 
+    // Resolve the soft-delete field to its column name
+    let soft_delete_column = match model.config.soft_delete.as_deref() {
+        Some(name) => {
+            let column = model
+                .fields
+                .iter()
+                .find(|f| f.name == name)
+                .map_or_else(|| name.to_string(), |f| f.column_name.clone());
+            quote::quote! { Some(#column) }
+        }
+        None => quote::quote! { None },
+    };
+
+    let version_column = match model.fields.iter().find(|f| f.version) {
+        Some(field) => {
+            let column = &field.column_name;
+            quote::quote! { Some(#column) }
+        }
+        None => quote::quote! { None },
+    };
+
+    // Generate table-level constraints, resolving field names to columns
+    let table_constraints =
+        model
+            .config
+            .table_constraints
+            .iter()
+            .map(|constraint| match constraint {
+                parse::TableConstraintDef::Check(expression) => {
+                    quote::quote! { sqlmodel_core::TableConstraint::Check(#expression) }
+                }
+                parse::TableConstraintDef::Unique(columns) => {
+                    let columns = columns.iter().map(|column| {
+                        let name = column.value();
+                        model
+                            .fields
+                            .iter()
+                            .find(|f| f.name == name)
+                            .map_or(name, |f| f.column_name.clone())
+                    });
+                    quote::quote! { sqlmodel_core::TableConstraint::Unique(&[#(#columns),*]) }
+                }
+            });
+
     // Generate joined-parent extraction for joined-table inheritance child models.
     let joined_parent_row_body = generate_joined_parent_row(model);
 
@@ -176,6 +226,10 @@ fn generate_model_impl(model: &ModelDef) -> proc_macro2::TokenStream {
             const PRIMARY_KEY: &'static [&'static str] = #pk_slice;
             const RELATIONSHIPS: &'static [sqlmodel_core::RelationshipInfo] = #relationships;
             const SHARD_KEY: Option<&'static str> = #shard_key_const;
+            const SOFT_DELETE_COLUMN: Option<&'static str> = #soft_delete_column;
+            const VERSION_COLUMN: Option<&'static str> = #version_column;
+            const TABLE_CONSTRAINTS: &'static [sqlmodel_core::TableConstraint] =
+                &[#(#table_constraints),*];
 
             fn fields() -> &'static [sqlmodel_core::FieldInfo] {
                 static FIELDS: &[sqlmodel_core::FieldInfo] = &[
@@ -192,6 +246,12 @@ fn generate_model_impl(model: &ModelDef) -> proc_macro2::TokenStream {
                 #from_row_body
             }
 
+            fn from_partial_row(
+                row: &sqlmodel_core::Row,
+            ) -> sqlmodel_core::Result<(Self, Vec<&'static str>)> {
+                #from_partial_row_body
+            }
+
             fn primary_key_value(&self) -> Vec<sqlmodel_core::Value> {
                 #pk_value_body
             }
@@ -389,6 +449,9 @@ fn generate_field_infos(model: &ModelDef) -> proc_macro2::TokenStream {
             quote::quote! { None }
         };
 
+        let deferrable = field.deferrable;
+        let initially_deferred = field.initially_deferred;
+
         // Alias tokens
         let alias_ts = if let Some(ref alias) = field.alias {
             quote::quote! { Some(#alias) }
@@ -464,6 +527,12 @@ fn generate_field_infos(model: &ModelDef) -> proc_macro2::TokenStream {
             quote::quote! { None }
         };
 
+        let collation_ts = if let Some(ref collation) = field.collation {
+            quote::quote! { Some(#collation) }
+        } else {
+            quote::quote! { None }
+        };
+
         // Column info
         let column_info_ts = if let Some(ref info) = field.column_info {
             quote::quote! { Some(#info) }
@@ -485,6 +554,38 @@ fn generate_field_infos(model: &ModelDef) -> proc_macro2::TokenStream {
             quote::quote! { None }
         };
 
+        // Console display hint
+        let display_ts = match field.display {
+            None => quote::quote! { None },
+            Some(hint) => {
+                let hint = match hint {
+                    DisplayHintAttr::Hash => quote::quote! { Hash },
+                    DisplayHintAttr::Percent => quote::quote! { Percent },
+                    DisplayHintAttr::Currency(scale) => quote::quote! { Currency(#scale) },
+                    DisplayHintAttr::BytesMarker => {
+                        quote::quote! { Bytes(sqlmodel_core::BytesDisplay::Marker) }
+                    }
+                    DisplayHintAttr::BytesHex => {
+                        quote::quote! { Bytes(sqlmodel_core::BytesDisplay::Hex) }
+                    }
+                    DisplayHintAttr::BytesHexTruncated(len) => {
+                        quote::quote! { Bytes(sqlmodel_core::BytesDisplay::HexTruncated(#len)) }
+                    }
+                    DisplayHintAttr::BytesBase64 => {
+                        quote::quote! { Bytes(sqlmodel_core::BytesDisplay::Base64) }
+                    }
+                };
+                quote::quote! { Some(sqlmodel_core::DisplayHint::#hint) }
+            }
+        };
+
+        // Database enum type backing the column
+        let custom_type_ts = if let Some(ref path) = field.sql_enum {
+            quote::quote! { Some(sqlmodel_core::CustomSqlType::from_enum::<#path>()) }
+        } else {
+            quote::quote! { None }
+        };
+
         // Decimal precision (max_digits -> precision, decimal_places -> scale)
         let precision_ts = if let Some(p) = field.max_digits {
             quote::quote! { Some(#p) }
@@ -501,6 +602,7 @@ fn generate_field_infos(model: &ModelDef) -> proc_macro2::TokenStream {
         field_ts.push(quote::quote! {
             sqlmodel_core::FieldInfo::new(stringify!(#field_ident), #column_name, #sql_type_ts)
                 .sql_type_override_opt(#sql_type_override_ts)
+                .custom_type_opt(#custom_type_ts)
                 .precision_opt(#precision_ts)
                 .scale_opt(#scale_ts)
                 .nullable(#nullable)
@@ -511,6 +613,8 @@ fn generate_field_infos(model: &ModelDef) -> proc_macro2::TokenStream {
                 .foreign_key_opt(#fk_ts)
                 .on_delete_opt(#on_delete_ts)
                 .on_update_opt(#on_update_ts)
+                .deferrable(#deferrable)
+                .initially_deferred(#initially_deferred)
                 .index_opt(#index_ts)
                 .alias_opt(#alias_ts)
                 .validation_alias_opt(#validation_alias_ts)
@@ -524,9 +628,11 @@ fn generate_field_infos(model: &ModelDef) -> proc_macro2::TokenStream {
                 .const_field(#const_field)
                 .column_constraints(#column_constraints_ts)
                 .column_comment_opt(#column_comment_ts)
+                .collation_opt(#collation_ts)
                 .column_info_opt(#column_info_ts)
                 .hybrid_sql_opt(#hybrid_sql_ts)
                 .discriminator_opt(#discriminator_ts)
+                .display_opt(#display_ts)
         });
     }
 
@@ -573,107 +679,164 @@ fn generate_to_row(model: &ModelDef) -> proc_macro2::TokenStream {
     }
 }
 
+/// Generate the row lookup that decodes `field` from `row_ident`.
+fn generate_field_get(field: &parse::FieldDef, row_ident: &syn::Ident) -> proc_macro2::TokenStream {
+    let column_name = &field.column_name;
+
+    // Primitive fields tolerate minor schema/model type mismatches.
+    let get = match infer::coercion_target(&field.ty) {
+        Some(target) => quote::quote! {
+            #row_ident.get_named_coerced(#column_name, #target)
+        },
+        None => quote::quote! { #row_ident.get_named(#column_name) },
+    };
+
+    if parse::is_option_type(&field.ty) {
+        // For Option<T> fields, handle NULL gracefully
+        quote::quote! { #get.ok() }
+    } else {
+        // For required fields, propagate errors
+        quote::quote! { #get? }
+    }
+}
+
+/// Generate initializers for the fields that never come from the model's own columns.
+fn generate_non_column_fields(model: &ModelDef) -> Vec<proc_macro2::TokenStream> {
+    let mut fields = Vec::new();
+
+    // Handle skipped fields with Default
+    fields.extend(model.fields.iter().filter(|f| f.skip).map(|f| {
+        let field_name = &f.name;
+        quote::quote! { #field_name: Default::default() }
+    }));
+
+    // Handle relationship fields with Default (they're not in the DB row)
+    fields.extend(
+        model
+            .fields
+            .iter()
+            .filter(|f| f.relationship.is_some())
+            .map(|f| {
+                let field_name = &f.name;
+                quote::quote! { #field_name: Default::default() }
+            }),
+    );
+
+    // Joined-table inheritance parent field hydration.
+    fields.extend(model.fields.iter().filter(|f| f.parent).map(|f| {
+        let field_name = &f.name;
+        let ty = &f.ty;
+        quote::quote! {
+            #field_name: {
+                let inh = <Self as sqlmodel_core::Model>::inheritance();
+                let parent_table = inh.parent.ok_or_else(|| {
+                    sqlmodel_core::Error::Custom(
+                        "joined inheritance parent_table missing in inheritance metadata".to_string(),
+                    )
+                })?;
+                if !row.has_prefix(parent_table) {
+                    return Err(sqlmodel_core::Error::Custom(format!(
+                        "expected prefixed parent columns for joined inheritance: {}__*",
+                        parent_table
+                    )));
+                }
+                let prow = row.subset_by_prefix(parent_table);
+                <#ty as sqlmodel_core::Model>::from_row(&prow)?
+            }
+        }
+    }));
+
+    // Handle computed fields with Default (they're not in the DB row)
+    fields.extend(model.computed_fields().iter().map(|f| {
+        let field_name = &f.name;
+        quote::quote! { #field_name: Default::default() }
+    }));
+
+    fields
+}
+
 /// Generate the from_row method body.
 fn generate_from_row(model: &ModelDef) -> proc_macro2::TokenStream {
     let name = &model.name;
-    let mut field_extractions = Vec::new();
 
     // Support both "plain" rows (SELECT *) and prefixed/aliased rows (e.g. eager loading,
     // joined inheritance) by looking for `table__col` prefixes.
     let row_ident = quote::format_ident!("local_row");
 
-    for field in model.select_fields() {
+    let field_extractions = model.select_fields().into_iter().map(|field| {
         let field_name = &field.name;
-        let column_name = &field.column_name;
+        let get = generate_field_get(field, &row_ident);
+        quote::quote! { #field_name: #get }
+    });
+    let non_column_fields = generate_non_column_fields(model);
 
-        if parse::is_option_type(&field.ty) {
-            // For Option<T> fields, handle NULL gracefully
-            field_extractions.push(quote::quote! {
-                #field_name: #row_ident.get_named(#column_name).ok()
-            });
+    quote::quote! {
+        let #row_ident = if row.has_prefix(<Self as sqlmodel_core::Model>::TABLE_NAME) {
+            row.subset_by_prefix(<Self as sqlmodel_core::Model>::TABLE_NAME)
         } else {
-            // For required fields, propagate errors
-            field_extractions.push(quote::quote! {
-                #field_name: #row_ident.get_named(#column_name)?
-            });
-        }
-    }
+            row.clone()
+        };
 
-    // Handle skipped fields with Default
-    let skipped_fields: Vec<_> = model
-        .fields
-        .iter()
-        .filter(|f| f.skip)
-        .map(|f| {
-            let field_name = &f.name;
-            quote::quote! { #field_name: Default::default() }
+        Ok(#name {
+            #(#field_extractions,)*
+            #(#non_column_fields,)*
         })
-        .collect();
+    }
+}
 
-    // Handle relationship fields with Default (they're not in the DB row)
-    let relationship_fields: Vec<_> = model
-        .fields
-        .iter()
-        .filter(|f| f.relationship.is_some())
-        .map(|f| {
-            let field_name = &f.name;
-            quote::quote! { #field_name: Default::default() }
-        })
-        .collect();
+/// Generate the from_partial_row method body.
+///
+/// Columns missing from the row fall back to the field's literal `default`,
+/// then to `Default::default()` for types that have one (`None` for options).
+fn generate_from_partial_row(model: &ModelDef) -> proc_macro2::TokenStream {
+    let name = &model.name;
+    let row_ident = quote::format_ident!("local_row");
+    let default_row_ident = quote::format_ident!("default_row");
 
-    // Joined-table inheritance parent field hydration.
-    let parent_fields: Vec<_> = model
-        .fields
-        .iter()
-        .filter(|f| f.parent)
-        .map(|f| {
-            let field_name = &f.name;
-            let ty = &f.ty;
-            quote::quote! {
-                #field_name: {
-                    let inh = <Self as sqlmodel_core::Model>::inheritance();
-                    let parent_table = inh.parent.ok_or_else(|| {
-                        sqlmodel_core::Error::Custom(
-                            "joined inheritance parent_table missing in inheritance metadata".to_string(),
-                        )
-                    })?;
-                    if !row.has_prefix(parent_table) {
-                        return Err(sqlmodel_core::Error::Custom(format!(
-                            "expected prefixed parent columns for joined inheritance: {}__*",
-                            parent_table
-                        )));
-                    }
-                    let prow = row.subset_by_prefix(parent_table);
-                    <#ty as sqlmodel_core::Model>::from_row(&prow)?
-                }
+    let field_extractions = model.select_fields().into_iter().map(|field| {
+        let field_name = &field.name;
+        let column_name = &field.column_name;
+        let ty = &field.ty;
+        let get = generate_field_get(field, &row_ident);
+        let get_default = generate_field_get(field, &default_row_ident);
+        quote::quote! {
+            #field_name: if #row_ident.contains_column(#column_name) {
+                loaded.push(#column_name);
+                #get
+            } else if let Some(value) = <Self as sqlmodel_core::Model>::fields()
+                .iter()
+                .find(|f| f.column_name == #column_name)
+                .and_then(sqlmodel_core::FieldInfo::default_value)
+            {
+                let #default_row_ident =
+                    sqlmodel_core::Row::new(vec![#column_name.to_string()], vec![value]);
+                #get_default
+            } else {
+                (&DefaultProbe::<#ty>::new())
+                    .default_value()
+                    .ok_or_else(|| partial::missing_column::<Self>(#column_name))?
             }
-        })
-        .collect();
-
-    // Handle computed fields with Default (they're not in the DB row)
-    let computed_fields: Vec<_> = model
-        .computed_fields()
-        .iter()
-        .map(|f| {
-            let field_name = &f.name;
-            quote::quote! { #field_name: Default::default() }
-        })
-        .collect();
+        }
+    });
+    let non_column_fields = generate_non_column_fields(model);
 
     quote::quote! {
+        #[allow(unused_imports)]
+        use sqlmodel_core::model::partial::{self, DefaultProbe, ViaDefault as _, ViaNoDefault as _};
+
         let #row_ident = if row.has_prefix(<Self as sqlmodel_core::Model>::TABLE_NAME) {
             row.subset_by_prefix(<Self as sqlmodel_core::Model>::TABLE_NAME)
         } else {
             row.clone()
         };
+        partial::check_primary_key::<Self>(&#row_ident)?;
 
-        Ok(#name {
+        let mut loaded = Vec::new();
+        let obj = #name {
             #(#field_extractions,)*
-            #(#skipped_fields,)*
-            #(#relationship_fields,)*
-            #(#parent_fields,)*
-            #(#computed_fields,)*
-        })
+            #(#non_column_fields,)*
+        };
+        Ok((obj, loaded))
     }
 }
 
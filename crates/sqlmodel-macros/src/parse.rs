@@ -59,6 +59,19 @@ pub struct ModelConfigParsed {
     pub discriminator_value: Option<String>,
     /// Shard key field name for horizontal sharding.
     pub shard_key: Option<String>,
+    /// Field holding the soft-delete marker (e.g. `deleted_at`).
+    pub soft_delete: Option<String>,
+    /// Table-level constraints, in declaration order.
+    pub table_constraints: Vec<TableConstraintDef>,
+}
+
+/// A struct-level `check = "..."` or `unique = ("a", "b")` attribute.
+#[derive(Debug, Clone)]
+pub enum TableConstraintDef {
+    /// CHECK expression.
+    Check(String),
+    /// Field or column names of a composite UNIQUE constraint.
+    Unique(Vec<syn::LitStr>),
 }
 
 /// Parsed model definition from a struct with `#[derive(Model)]`.
@@ -104,6 +117,10 @@ pub struct FieldDef {
     pub on_delete: Option<String>,
     /// ON UPDATE referential action (e.g., "CASCADE", "NO ACTION").
     pub on_update: Option<String>,
+    /// Whether the foreign key constraint is DEFERRABLE.
+    pub deferrable: bool,
+    /// Whether the foreign key constraint is INITIALLY DEFERRED.
+    pub initially_deferred: bool,
     /// SQL DEFAULT expression.
     pub default: Option<String>,
     /// Index name if this field is part of an index.
@@ -158,6 +175,8 @@ pub struct FieldDef {
     pub column_constraints: Vec<String>,
     /// SQL comment for the column.
     pub column_comment: Option<String>,
+    /// Column collation (emitted as `COLLATE "<name>"`).
+    pub collation: Option<String>,
     /// Extra metadata as JSON string.
     pub column_info: Option<String>,
     /// Complete column specification override (sa_column).
@@ -170,6 +189,55 @@ pub struct FieldDef {
     /// Discriminator field name for union types.
     /// Used to identify which field in a union determines the concrete type.
     pub discriminator: Option<String>,
+    /// Path to a `SqlEnum` type backing this column (Postgres `CREATE TYPE ... AS ENUM`).
+    pub sql_enum: Option<syn::Path>,
+    /// Optimistic-concurrency version counter (`#[sqlmodel(version)]`).
+    pub version: bool,
+    /// Console display hint (`#[sqlmodel(display = "...")]`).
+    pub display: Option<DisplayHintAttr>,
+}
+
+/// Console display hint parsed from `display = "..."`.
+///
+/// Mirrors `sqlmodel_core::DisplayHint`; binary modes are flattened.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DisplayHintAttr {
+    /// `"hash"`
+    Hash,
+    /// `"currency"` (2 decimals) or `"currency(N)"`
+    Currency(u8),
+    /// `"percent"`
+    Percent,
+    /// `"bytes"`: size marker only
+    BytesMarker,
+    /// `"hex"`
+    BytesHex,
+    /// `"hex(N)"`: hex of at most N bytes
+    BytesHexTruncated(usize),
+    /// `"base64"`
+    BytesBase64,
+}
+
+impl DisplayHintAttr {
+    /// Parse a `display = "..."` value; `None` if unrecognized.
+    fn parse(spec: &str) -> Option<Self> {
+        let spec = spec.trim();
+        let (name, arg) = match spec.split_once('(') {
+            Some((name, rest)) => (name.trim(), Some(rest.strip_suffix(')')?.trim())),
+            None => (spec, None),
+        };
+        match (name, arg) {
+            ("hash", None) => Some(Self::Hash),
+            ("percent", None) => Some(Self::Percent),
+            ("currency", None) => Some(Self::Currency(2)),
+            ("currency", Some(scale)) => scale.parse().ok().map(Self::Currency),
+            ("bytes", None) => Some(Self::BytesMarker),
+            ("hex", None) => Some(Self::BytesHex),
+            ("hex", Some(len)) => len.parse().ok().map(Self::BytesHexTruncated),
+            ("base64", None) => Some(Self::BytesBase64),
+            _ => None,
+        }
+    }
 }
 
 /// Parsed relationship attribute from `#[sqlmodel(relationship(...))]`.
@@ -664,13 +732,54 @@ fn parse_struct_sqlmodel_attrs(attrs: &[Attribute], struct_name: &Ident) -> Resu
                         "expected string literal for shard_key",
                     ))
                 }
+            } else if meta.path.is_ident("check") {
+                let value: Lit = meta.value()?.parse()?;
+                if let Lit::Str(lit_str) = value {
+                    config
+                        .table_constraints
+                        .push(TableConstraintDef::Check(lit_str.value()));
+                    Ok(())
+                } else {
+                    Err(Error::new_spanned(
+                        value,
+                        "expected string literal for check",
+                    ))
+                }
+            } else if meta.path.is_ident("unique") {
+                let value = meta.value()?;
+                let content;
+                syn::parenthesized!(content in value);
+                let columns = content
+                    .parse_terminated(|input| input.parse::<syn::LitStr>(), syn::Token![,])?;
+                if columns.is_empty() {
+                    return Err(Error::new_spanned(
+                        &meta.path,
+                        "unique needs at least one column, e.g. unique = (\"a\", \"b\")",
+                    ));
+                }
+                config
+                    .table_constraints
+                    .push(TableConstraintDef::Unique(columns.into_iter().collect()));
+                Ok(())
+            } else if meta.path.is_ident("soft_delete") {
+                let value: Lit = meta.value()?.parse()?;
+                if let Lit::Str(lit_str) = value {
+                    config.soft_delete = Some(lit_str.value());
+                    Ok(())
+                } else {
+                    Err(Error::new_spanned(
+                        value,
+                        "expected string literal for soft_delete",
+                    ))
+                }
             } else {
                 Err(Error::new_spanned(
                     meta.path,
                     "unknown sqlmodel struct attribute (supported: table, table_alias, from_attributes, \
                      validate_assignment, extra, strict, populate_by_name, use_enum_values, \
                      arbitrary_types_allowed, defer_build, revalidate_instances, json_schema_extra, title, \
-                     inheritance, inherits, discriminator, discriminator_value, shard_key)",
+                     inheritance, inherits, discriminator, discriminator_value, shard_key, soft_delete, \
+                     check, unique)",
                 ))
             }
         })?;
@@ -884,6 +993,8 @@ fn parse_field(field: &Field) -> Result<FieldDef> {
         foreign_key: attrs.foreign_key,
         on_delete: attrs.on_delete,
         on_update: attrs.on_update,
+        deferrable: attrs.deferrable || attrs.initially_deferred,
+        initially_deferred: attrs.initially_deferred,
         default: attrs.default,
         index: attrs.index,
         skip: attrs.skip,
@@ -906,11 +1017,15 @@ fn parse_field(field: &Field) -> Result<FieldDef> {
         const_field: attrs.const_field,
         column_constraints: attrs.column_constraints,
         column_comment: attrs.column_comment,
+        collation: attrs.collation,
         column_info: attrs.column_info,
         sa_column: attrs.sa_column,
         hybrid: attrs.hybrid,
         hybrid_sql: attrs.hybrid_sql,
         discriminator: attrs.discriminator,
+        sql_enum: attrs.sql_enum,
+        version: attrs.version,
+        display: attrs.display,
     })
 }
 
@@ -926,6 +1041,8 @@ struct FieldAttrs {
     foreign_key: Option<String>,
     on_delete: Option<String>,
     on_update: Option<String>,
+    deferrable: bool,
+    initially_deferred: bool,
     default: Option<String>,
     index: Option<String>,
     skip: bool,
@@ -959,6 +1076,8 @@ struct FieldAttrs {
     column_constraints: Vec<String>,
     /// SQL comment for the column.
     column_comment: Option<String>,
+    /// Column collation.
+    collation: Option<String>,
     /// Extra metadata as JSON string.
     column_info: Option<String>,
     /// Complete column specification override (sa_column).
@@ -969,8 +1088,14 @@ struct FieldAttrs {
     hybrid_sql: Option<String>,
     /// Discriminator field name for union types.
     discriminator: Option<String>,
+    /// Path to a `SqlEnum` type backing this column.
+    sql_enum: Option<syn::Path>,
     /// Joined-table inheritance parent field (embedded parent model).
     parent: bool,
+    /// Optimistic-concurrency version counter.
+    version: bool,
+    /// Console display hint.
+    display: Option<DisplayHintAttr>,
 }
 
 /// Detect the relationship kind from a field's Rust type.
@@ -1021,12 +1146,18 @@ fn parse_field_attrs(
                 result.nullable = Some(true);
             } else if path.is_ident("unique") {
                 result.unique = true;
+            } else if path.is_ident("deferrable") {
+                result.deferrable = true;
+            } else if path.is_ident("initially_deferred") {
+                result.initially_deferred = true;
             } else if path.is_ident("skip") {
                 result.skip = true;
             } else if path.is_ident("skip_insert") {
                 result.skip_insert = true;
             } else if path.is_ident("skip_update") {
                 result.skip_update = true;
+            } else if path.is_ident("version") {
+                result.version = true;
             } else if path.is_ident("column") {
                 let value: Lit = meta.value()?.parse()?;
                 if let Lit::Str(lit_str) = value {
@@ -1067,13 +1198,16 @@ fn parse_field_attrs(
                 }
             } else if path.is_ident("default") {
                 let value: Lit = meta.value()?.parse()?;
-                if let Lit::Str(lit_str) = value {
-                    result.default = Some(lit_str.value());
-                } else {
-                    return Err(Error::new_spanned(
-                        value,
-                        "expected string literal for default",
-                    ));
+                match value {
+                    Lit::Str(lit_str) => result.default = Some(lit_str.value()),
+                    // Rendered per dialect (TRUE on Postgres, 1 on SQLite) by the DDL generators.
+                    Lit::Bool(lit_bool) => result.default = Some(lit_bool.value.to_string()),
+                    _ => {
+                        return Err(Error::new_spanned(
+                            value,
+                            "expected string or boolean literal for default",
+                        ));
+                    }
                 }
             } else if path.is_ident("index") {
                 let value: Lit = meta.value()?.parse()?;
@@ -1275,6 +1409,16 @@ fn parse_field_attrs(
                         "expected string literal for column_comment",
                     ));
                 }
+            } else if path.is_ident("collation") {
+                let value: Lit = meta.value()?.parse()?;
+                if let Lit::Str(lit_str) = value {
+                    result.collation = Some(lit_str.value());
+                } else {
+                    return Err(Error::new_spanned(
+                        value,
+                        "expected string literal for collation",
+                    ));
+                }
             } else if path.is_ident("column_info") {
                 let value: Lit = meta.value()?.parse()?;
                 if let Lit::Str(lit_str) = value {
@@ -1314,9 +1458,37 @@ fn parse_field_attrs(
                         "expected string literal for discriminator",
                     ));
                 }
+            } else if path.is_ident("sql_enum") {
+                // Parse sql_enum = "path::ToEnum" for database enum types
+                let value: Lit = meta.value()?.parse()?;
+                if let Lit::Str(lit_str) = value {
+                    result.sql_enum = Some(lit_str.parse()?);
+                } else {
+                    return Err(Error::new_spanned(
+                        value,
+                        "expected string literal for sql_enum",
+                    ));
+                }
             } else if path.is_ident("parent") {
                 // Joined-table inheritance embedded parent field (flag).
                 result.parent = true;
+            } else if path.is_ident("display") {
+                // Parse display = "hash" | "currency(2)" | "percent" | "hex" | ...
+                let value: Lit = meta.value()?.parse()?;
+                let Lit::Str(lit_str) = &value else {
+                    return Err(Error::new_spanned(
+                        value,
+                        "expected string literal for display",
+                    ));
+                };
+                let Some(hint) = DisplayHintAttr::parse(&lit_str.value()) else {
+                    return Err(Error::new_spanned(
+                        lit_str,
+                        "unknown display hint; expected one of: hash, percent, currency, \
+                         currency(N), bytes, hex, hex(N), base64",
+                    ));
+                };
+                result.display = Some(hint);
             } else {
                 // Unknown attribute
                 let attr_name = path.to_token_stream().to_string();
@@ -1325,11 +1497,12 @@ fn parse_field_attrs(
                     format!(
                         "unknown sqlmodel attribute `{attr_name}`. \
                          Valid attributes are: primary_key, auto_increment, column, nullable, \
-                         unique, foreign_key, on_delete, on_update, default, sql_type, index, \
+                         unique, foreign_key, on_delete, on_update, deferrable, initially_deferred, \
+                         default, sql_type, index, \
                          skip, skip_insert, skip_update, relationship, alias, validation_alias, \
                          serialization_alias, computed, max_digits, decimal_places, default_json, repr, \
-                         const_field, column_constraints, column_comment, column_info, sa_column, \
-                         hybrid, sql, discriminator, parent"
+                         const_field, column_constraints, column_comment, collation, column_info, sa_column, \
+                         hybrid, sql, discriminator, sql_enum, parent, version, display"
                     ),
                 ));
             }
@@ -1774,6 +1947,14 @@ fn validate_field_attrs(attrs: &FieldAttrs, field_name: &Ident, field_type: &Typ
         ));
     }
 
+    // Deferrability only applies to a foreign key constraint
+    if (attrs.deferrable || attrs.initially_deferred) && attrs.foreign_key.is_none() {
+        return Err(Error::new_spanned(
+            field_name,
+            "`deferrable` and `initially_deferred` require `foreign_key`",
+        ));
+    }
+
     // Validate relationship attribute is on a relationship type
     if attrs.relationship.is_some() {
         let detected = detect_relationship_kind(field_type);
@@ -1794,6 +1975,8 @@ fn validate_field_attrs(attrs: &FieldAttrs, field_name: &Ident, field_type: &Typ
             || attrs.foreign_key.is_some()
             || attrs.on_delete.is_some()
             || attrs.on_update.is_some()
+            || attrs.deferrable
+            || attrs.initially_deferred
             || attrs.default.is_some()
             || attrs.sql_type.is_some()
             || attrs.index.is_some()
@@ -1855,6 +2038,9 @@ fn validate_field_attrs(attrs: &FieldAttrs, field_name: &Ident, field_type: &Typ
         if attrs.on_update.is_some() {
             conflicts.push("on_update");
         }
+        if attrs.deferrable || attrs.initially_deferred {
+            conflicts.push("deferrable");
+        }
         if attrs.default.is_some() {
             conflicts.push("default");
         }
@@ -3367,6 +3553,26 @@ mod tests {
         assert!(def.config.arbitrary_types_allowed);
     }
 
+    #[test]
+    fn test_parse_boolean_default() {
+        let input: DeriveInput = parse_quote! {
+            struct Flag {
+                #[sqlmodel(primary_key)]
+                id: i64,
+                #[sqlmodel(default = true)]
+                active: bool,
+                #[sqlmodel(default = "'draft'")]
+                status: String,
+            }
+        };
+
+        let def = parse_model(&input).unwrap();
+        let active = def.fields.iter().find(|f| f.name == "active").unwrap();
+        assert_eq!(active.default, Some("true".to_string()));
+        let status = def.fields.iter().find(|f| f.name == "status").unwrap();
+        assert_eq!(status.default, Some("'draft'".to_string()));
+    }
+
     // =========================================================================
     // Default JSON Tests (for exclude_defaults support)
     // =========================================================================
@@ -3474,6 +3680,34 @@ mod tests {
         assert!(!password_field.repr); // Explicitly set to false
     }
 
+    #[test]
+    fn test_parse_version_field() {
+        let input: DeriveInput = parse_quote! {
+            struct Account {
+                #[sqlmodel(primary_key)]
+                id: i64,
+                #[sqlmodel(version)]
+                revision: i32,
+            }
+        };
+        let def = parse_model(&input).unwrap();
+        let versioned: Vec<_> = def.fields.iter().filter(|f| f.version).collect();
+        assert_eq!(versioned.len(), 1);
+        assert_eq!(versioned[0].name, "revision");
+        assert!(crate::validate::validate_model(&def).is_ok());
+
+        let input: DeriveInput = parse_quote! {
+            struct Account {
+                #[sqlmodel(primary_key)]
+                id: i64,
+                #[sqlmodel(version)]
+                revision: Option<i32>,
+            }
+        };
+        let def = parse_model(&input).unwrap();
+        assert!(crate::validate::validate_model(&def).is_err());
+    }
+
     #[test]
     fn test_parse_repr_true_explicit() {
         let input: DeriveInput = parse_quote! {
@@ -3595,6 +3829,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_collation() {
+        let input: DeriveInput = parse_quote! {
+            struct User {
+                #[sqlmodel(primary_key)]
+                id: i64,
+                #[sqlmodel(collation = "NOCASE")]
+                email: String,
+            }
+        };
+
+        let def = parse_model(&input).unwrap();
+
+        let email_field = def.fields.iter().find(|f| f.name == "email").unwrap();
+        assert_eq!(email_field.collation, Some("NOCASE".to_string()));
+        let id_field = def.fields.iter().find(|f| f.name == "id").unwrap();
+        assert_eq!(id_field.collation, None);
+    }
+
     #[test]
     fn test_parse_column_info() {
         let input: DeriveInput = parse_quote! {
@@ -4031,6 +4284,94 @@ mod tests {
         assert_eq!(pet_field.alias.as_deref(), Some("animal"));
     }
 
+    // ==================== SQL Enum Tests ====================
+
+    #[test]
+    fn test_parse_display_hint() {
+        let input: DeriveInput = parse_quote! {
+            struct Payment {
+                #[sqlmodel(primary_key)]
+                id: i64,
+                #[sqlmodel(display = "currency(3)")]
+                amount: String,
+                #[sqlmodel(display = "hex(4)")]
+                digest: Vec<u8>,
+            }
+        };
+        let def = parse_model(&input).unwrap();
+        let display: Vec<_> = def.fields.iter().map(|f| f.display).collect();
+        assert_eq!(
+            display,
+            vec![
+                None,
+                Some(DisplayHintAttr::Currency(3)),
+                Some(DisplayHintAttr::BytesHexTruncated(4)),
+            ]
+        );
+
+        assert_eq!(
+            DisplayHintAttr::parse("currency"),
+            Some(DisplayHintAttr::Currency(2))
+        );
+        assert_eq!(
+            DisplayHintAttr::parse(" hash "),
+            Some(DisplayHintAttr::Hash)
+        );
+        assert_eq!(DisplayHintAttr::parse("hex(x)"), None);
+        assert_eq!(DisplayHintAttr::parse("percent(2)"), None);
+
+        let input: DeriveInput = parse_quote! {
+            struct Payment {
+                #[sqlmodel(primary_key)]
+                id: i64,
+                #[sqlmodel(display = "money")]
+                amount: String,
+            }
+        };
+        assert!(parse_model(&input).is_err());
+    }
+
+    #[test]
+    fn test_sql_enum_field_parsed() {
+        let input: DeriveInput = parse_quote! {
+            struct Hero {
+                #[sqlmodel(primary_key)]
+                id: i64,
+                #[sqlmodel(sql_enum = "crate::types::HeroStatus")]
+                status: HeroStatus,
+            }
+        };
+
+        let def = parse_model(&input).unwrap();
+
+        let status = def.fields.iter().find(|f| f.name == "status").unwrap();
+        let path = status.sql_enum.as_ref().unwrap();
+        assert_eq!(
+            path.to_token_stream().to_string().replace(' ', ""),
+            "crate::types::HeroStatus"
+        );
+        let id = def.fields.iter().find(|f| f.name == "id").unwrap();
+        assert!(id.sql_enum.is_none());
+    }
+
+    #[test]
+    fn test_sql_enum_requires_string_literal() {
+        let input: DeriveInput = parse_quote! {
+            struct Hero {
+                #[sqlmodel(primary_key)]
+                id: i64,
+                #[sqlmodel(sql_enum = 1)]
+                status: HeroStatus,
+            }
+        };
+
+        let err = parse_model(&input).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("expected string literal for sql_enum")
+        );
+    }
+
     // ==================== Generic Model Tests ====================
 
     #[test]
@@ -9,7 +9,7 @@ use proc_macro2::Span;
 use quote::ToTokens;
 use syn::{Error, GenericArgument, PathArguments, Type};
 
-use crate::parse::{FieldDef, ModelDef};
+use crate::parse::{FieldDef, ModelDef, TableConstraintDef};
 
 /// Validate a parsed model definition.
 ///
@@ -31,6 +31,9 @@ pub fn validate_model(model: &ModelDef) -> Result<(), Error> {
     // Cross-field validations
     validate_auto_increment_has_pk(model, &mut errors);
     validate_joined_inheritance_parent_field(model, &mut errors);
+    validate_soft_delete_field(model, &mut errors);
+    validate_version_field(model, &mut errors);
+    validate_table_constraints(model, &mut errors);
 
     // Combine all errors
     if errors.is_empty() {
@@ -69,6 +72,94 @@ fn validate_joined_inheritance_parent_field(model: &ModelDef, errors: &mut Vec<E
     }
 }
 
+/// Validate that `soft_delete` names a nullable, non-skipped field.
+fn validate_soft_delete_field(model: &ModelDef, errors: &mut Vec<Error>) {
+    let Some(name) = model.config.soft_delete.as_deref() else {
+        return;
+    };
+    match model.fields.iter().find(|f| f.name == name && !f.skip) {
+        Some(field) if !field.nullable => errors.push(Error::new(
+            field.name.span(),
+            "soft_delete field must be nullable; NULL marks a row as not deleted",
+        )),
+        Some(_) => {}
+        None => errors.push(Error::new(
+            model.name.span(),
+            format!("soft_delete names unknown field '{name}'"),
+        )),
+    }
+}
+
+/// Validate that at most one field is a `version` counter and that it is a
+/// plain, non-nullable, updatable column outside the primary key.
+fn validate_version_field(model: &ModelDef, errors: &mut Vec<Error>) {
+    let versions: Vec<&FieldDef> = model.fields.iter().filter(|f| f.version).collect();
+    for extra in versions.iter().skip(1) {
+        errors.push(Error::new(
+            extra.name.span(),
+            "only one field may be marked #[sqlmodel(version)]",
+        ));
+    }
+    let Some(field) = versions.first() else {
+        return;
+    };
+    if field.primary_key {
+        errors.push(Error::new(
+            field.name.span(),
+            "version field cannot be part of the primary key",
+        ));
+    }
+    if field.nullable {
+        errors.push(Error::new(
+            field.name.span(),
+            "version field must not be nullable",
+        ));
+    }
+    if !is_signed_integer(&field.ty) {
+        errors.push(Error::new(
+            field.name.span(),
+            "version field must be a signed integer (i8, i16, i32 or i64)",
+        ));
+    }
+    if field.skip || field.skip_update {
+        errors.push(Error::new(
+            field.name.span(),
+            "version field must be written on UPDATE; remove skip/skip_update",
+        ));
+    }
+}
+
+/// Validate struct-level `check` and `unique` table constraints.
+fn validate_table_constraints(model: &ModelDef, errors: &mut Vec<Error>) {
+    for constraint in &model.config.table_constraints {
+        match constraint {
+            TableConstraintDef::Check(expression) => {
+                if expression.trim().is_empty() {
+                    errors.push(Error::new(
+                        model.name.span(),
+                        "check constraint expression cannot be empty",
+                    ));
+                }
+            }
+            TableConstraintDef::Unique(columns) => {
+                for column in columns {
+                    let name = column.value();
+                    let known = model
+                        .fields
+                        .iter()
+                        .any(|f| !f.skip && (f.name == name || f.column_name == name));
+                    if !known {
+                        errors.push(Error::new(
+                            column.span(),
+                            format!("unique constraint names unknown column '{name}'"),
+                        ));
+                    }
+                }
+            }
+        }
+    }
+}
+
 /// Validate that the struct has at least one field.
 fn validate_has_fields(model: &ModelDef, errors: &mut Vec<Error>) {
     if model.fields.is_empty() {
@@ -228,6 +319,17 @@ fn is_nested_option(ty: &Type) -> bool {
     false
 }
 
+/// Check if a type is one of the signed integer primitives.
+fn is_signed_integer(ty: &Type) -> bool {
+    let Type::Path(type_path) = ty else {
+        return false;
+    };
+    type_path
+        .path
+        .get_ident()
+        .is_some_and(|ident| ["i8", "i16", "i32", "i64"].iter().any(|name| ident == name))
+}
+
 /// Check if a type is a reference (&T or &mut T).
 fn is_reference_type(ty: &Type) -> bool {
     matches!(ty, Type::Reference(_))
@@ -261,6 +363,18 @@ mod tests {
         assert!(!is_nested_option(&ty));
     }
 
+    #[test]
+    fn test_is_signed_integer() {
+        let ty: Type = parse_quote!(i64);
+        assert!(is_signed_integer(&ty));
+
+        let ty: Type = parse_quote!(u32);
+        assert!(!is_signed_integer(&ty));
+
+        let ty: Type = parse_quote!(Option<i32>);
+        assert!(!is_signed_integer(&ty));
+    }
+
     #[test]
     fn test_is_reference_type() {
         let ty: Type = parse_quote!(&str);
@@ -19,7 +19,9 @@ use asupersync::net::TcpStream;
 use asupersync::sync::Mutex;
 use asupersync::{Cx, Outcome};
 
-use sqlmodel_core::connection::{Connection, IsolationLevel, PreparedStatement, TransactionOps};
+use sqlmodel_core::connection::{
+    Capabilities, Connection, Dialect, IsolationLevel, PreparedStatement, TransactionOps,
+};
 use sqlmodel_core::error::{
     ConnectionError, ConnectionErrorKind, ProtocolError, QueryError, QueryErrorKind,
 };
@@ -36,7 +38,7 @@ use crate::protocol::{
     capabilities, charset, prepared,
 };
 use crate::types::{
-    ColumnDef, FieldType, decode_binary_value_with_len, decode_text_value, interpolate_params,
+    ColumnDef, FieldType, decode_binary_row, decode_text_value, interpolate_params,
 };
 
 /// Async MySQL connection.
@@ -1782,24 +1784,23 @@ impl MySqlAsyncConnection {
                 break;
             }
 
-            #[allow(clippy::cast_possible_truncation)] // MySQL packets are max 16MB
-            match PacketType::from_first_byte(payload[0], payload.len() as u32) {
-                PacketType::Eof | PacketType::Ok => {
-                    let mut reader = PacketReader::new(&payload);
-                    if payload[0] == 0x00 {
+            // Binary rows always start with a 0x00 header, so only 0xFE (EOF,
+            // or an OK packet under CLIENT_DEPRECATE_EOF) ends the result set.
+            match payload[0] {
+                0xFE => {
+                    let mut reader = PacketReader::new(&payload[1..]);
+                    if server_caps & capabilities::CLIENT_DEPRECATE_EOF != 0 {
                         if let Some(ok) = reader.parse_ok_packet() {
                             self.status_flags = ok.status_flags;
                             self.warnings = ok.warnings;
                         }
-                    } else if payload[0] == 0xFE {
-                        if let Some(eof) = reader.parse_eof_packet() {
-                            self.status_flags = eof.status_flags;
-                            self.warnings = eof.warnings;
-                        }
+                    } else if let Some(eof) = reader.parse_eof_packet() {
+                        self.status_flags = eof.status_flags;
+                        self.warnings = eof.warnings;
                     }
                     break;
                 }
-                PacketType::Error => {
+                0xFF => {
                     let mut reader = PacketReader::new(&payload);
                     let Some(err) = reader.parse_err_packet() else {
                         return Outcome::Err(protocol_error("Invalid error packet"));
@@ -1807,10 +1808,10 @@ impl MySqlAsyncConnection {
                     self.state = ConnectionState::Ready;
                     return Outcome::Err(query_error(&err));
                 }
-                _ => {
-                    let row = self.parse_binary_row(&payload, cols);
-                    rows.push(row);
-                }
+                _ => match self.parse_binary_row(&payload, cols) {
+                    Ok(row) => rows.push(row),
+                    Err(e) => return Outcome::Err(e),
+                },
             }
         }
 
@@ -1825,51 +1826,11 @@ impl MySqlAsyncConnection {
     }
 
     /// Parse a binary protocol row.
-    fn parse_binary_row(&self, data: &[u8], columns: &[ColumnDef]) -> Row {
-        // Binary row format:
-        // - 0x00 header (1 byte)
-        // - NULL bitmap ((column_count + 7 + 2) / 8 bytes)
-        // - Column values (only non-NULL)
-
-        let mut values = Vec::with_capacity(columns.len());
-        let mut column_names = Vec::with_capacity(columns.len());
-
-        if data.is_empty() {
-            return Row::new(column_names, values);
-        }
-
-        // Skip header byte (0x00)
-        let mut pos = 1;
-
-        // NULL bitmap: (column_count + 7 + 2) / 8 bytes
-        // The +2 offset is for the reserved bits at the beginning
-        let null_bitmap_len = (columns.len() + 7 + 2) / 8;
-        if pos + null_bitmap_len > data.len() {
-            return Row::new(column_names, values);
-        }
-        let null_bitmap = &data[pos..pos + null_bitmap_len];
-        pos += null_bitmap_len;
-
-        // Parse column values
-        for (i, col) in columns.iter().enumerate() {
-            column_names.push(col.name.clone());
-
-            // Check NULL bitmap (bit position is i + 2 due to offset)
-            let bit_pos = i + 2;
-            let is_null = (null_bitmap[bit_pos / 8] & (1 << (bit_pos % 8))) != 0;
-
-            if is_null {
-                values.push(Value::Null);
-            } else {
-                let is_unsigned = col.flags & 0x20 != 0; // UNSIGNED_FLAG
-                let (value, consumed) =
-                    decode_binary_value_with_len(&data[pos..], col.column_type, is_unsigned);
-                values.push(value);
-                pos += consumed;
-            }
-        }
-
-        Row::new(column_names, values)
+    fn parse_binary_row(&self, data: &[u8], columns: &[ColumnDef]) -> Result<Row, Error> {
+        let values = decode_binary_row(data, columns)
+            .ok_or_else(|| protocol_error("Truncated binary result row"))?;
+        let column_names = columns.iter().map(|c| c.name.clone()).collect();
+        Ok(Row::new(column_names, values))
     }
 
     /// Write a pre-built packet (with header already included).
@@ -2033,6 +1994,33 @@ fn mysql_server_uses_oaep(server_version: &str) -> bool {
     (major, minor, patch) >= (8, 0, 5)
 }
 
+/// Backend capabilities for a server reporting `server_version`.
+///
+/// MySQL has no `RETURNING`. MariaDB 10.5+ accepts it on `INSERT` (including
+/// multi-row) and `DELETE`, but not on `UPDATE`. MariaDB may prefix its
+/// version with `5.5.5-` for old replication clients.
+fn mysql_capabilities(server_version: Option<&str>) -> Capabilities {
+    let base = Capabilities::for_dialect(Dialect::Mysql).without_returning();
+    let Some(version) = server_version.filter(|v| v.contains("MariaDB")) else {
+        return base;
+    };
+    let version = version.strip_prefix("5.5.5-").unwrap_or(version);
+    let mut parts = version
+        .split(|c: char| !c.is_ascii_digit())
+        .map(|part| part.parse::<u64>().unwrap_or(0));
+    let major = parts.next().unwrap_or(0);
+    let minor = parts.next().unwrap_or(0);
+    if (major, minor) < (10, 5) {
+        return base;
+    }
+    Capabilities {
+        returning_insert: true,
+        returning_delete: true,
+        multi_row_returning: true,
+        ..base
+    }
+}
+
 fn query_error(err: &ErrPacket) -> Error {
     let kind = if err.is_duplicate_key() || err.is_foreign_key_violation() {
         QueryErrorKind::Constraint
@@ -2047,6 +2035,11 @@ fn query_error(err: &ErrPacket) -> Error {
         sql: None,
         detail: None,
         hint: None,
+        schema: None,
+        table: None,
+        column: None,
+        constraint: None,
+        where_: None,
         position: None,
         source: None,
     })
@@ -2060,6 +2053,11 @@ fn query_error_msg(msg: impl Into<String>) -> Error {
         sql: None,
         detail: None,
         hint: None,
+        schema: None,
+        table: None,
+        column: None,
+        constraint: None,
+        where_: None,
         position: None,
         source: None,
     })
@@ -2120,13 +2118,17 @@ fn validate_savepoint_name(name: &str) -> Result<(), Error> {
 /// ```
 pub struct SharedMySqlConnection {
     inner: Arc<Mutex<MySqlAsyncConnection>>,
+    /// Derived from the handshake's server version, which never changes.
+    capabilities: Capabilities,
 }
 
 impl SharedMySqlConnection {
     /// Create a new shared connection from a raw connection.
     pub fn new(conn: MySqlAsyncConnection) -> Self {
+        let capabilities = mysql_capabilities(conn.server_version());
         Self {
             inner: Arc::new(Mutex::new(conn)),
+            capabilities,
         }
     }
 
@@ -2150,6 +2152,7 @@ impl Clone for SharedMySqlConnection {
     fn clone(&self) -> Self {
         Self {
             inner: Arc::clone(&self.inner),
+            capabilities: self.capabilities,
         }
     }
 }
@@ -2239,6 +2242,10 @@ impl Connection for SharedMySqlConnection {
         sqlmodel_core::Dialect::Mysql
     }
 
+    fn capabilities(&self) -> Capabilities {
+        self.capabilities
+    }
+
     fn query(
         &self,
         cx: &Cx,
@@ -2613,6 +2620,7 @@ impl<'conn> Drop for SharedMySqlTransaction<'conn> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use sqlmodel_core::UpsertSyntax;
 
     #[test]
     fn test_connection_state() {
@@ -2660,4 +2668,25 @@ mod tests {
         let long_name = "a".repeat(65);
         assert!(validate_savepoint_name(&long_name).is_err());
     }
+
+    #[test]
+    fn test_mysql_capabilities_by_server_version() {
+        let mysql = mysql_capabilities(Some("8.0.36"));
+        assert!(!mysql.returning_insert && !mysql.returning_delete);
+        assert_eq!(mysql.upsert_syntax, UpsertSyntax::OnDuplicateKey);
+        assert_eq!(mysql.max_bind_params, 65_535);
+        assert_eq!(mysql_capabilities(None), mysql);
+        assert_eq!(mysql_capabilities(Some("10.4.32-MariaDB")), mysql);
+
+        for version in [
+            "10.5.8-MariaDB",
+            "5.5.5-10.11.6-MariaDB-1:10.11.6+maria~ubu2204",
+        ] {
+            let maria = mysql_capabilities(Some(version));
+            assert!(maria.returning_insert, "{version}");
+            assert!(maria.returning_delete, "{version}");
+            assert!(maria.multi_row_returning, "{version}");
+            assert!(!maria.returning_update, "{version}");
+        }
+    }
 }
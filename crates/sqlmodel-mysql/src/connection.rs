@@ -1256,7 +1256,7 @@ impl MySqlConnection {
 #[cfg(feature = "console")]
 fn format_value(value: &Value) -> String {
     match value {
-        Value::Null => "NULL".to_string(),
+        Value::Null | Value::TypedNull(_) => "NULL".to_string(),
         Value::Bool(b) => if *b { "true" } else { "false" }.to_string(),
         Value::TinyInt(i) => i.to_string(),
         Value::SmallInt(i) => i.to_string(),
@@ -1357,6 +1357,11 @@ fn query_error(err: &ErrPacket) -> Error {
         sql: None,
         detail: None,
         hint: None,
+        schema: None,
+        table: None,
+        column: None,
+        constraint: None,
+        where_: None,
         position: None,
         source: None,
     })
@@ -1370,6 +1375,11 @@ fn query_error_msg(msg: impl Into<String>) -> Error {
         sql: None,
         detail: None,
         hint: None,
+        schema: None,
+        table: None,
+        column: None,
+        constraint: None,
+        where_: None,
         position: None,
         source: None,
     })
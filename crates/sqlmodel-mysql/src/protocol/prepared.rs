@@ -29,7 +29,7 @@
 #![allow(clippy::cast_possible_truncation)]
 
 use super::{Command, PacketWriter};
-use crate::types::{ColumnDef, FieldType};
+use crate::types::{ColumnDef, FieldType, encode_binary_value, value_to_field_type};
 use sqlmodel_core::Value;
 
 /// Response from COM_STMT_PREPARE.
@@ -154,7 +154,7 @@ pub fn build_stmt_execute_packet(
         let mut null_bitmap = vec![0u8; null_bitmap_len];
 
         for (i, param) in params.iter().enumerate() {
-            if matches!(param, Value::Null) {
+            if param.is_null() {
                 null_bitmap[i / 8] |= 1 << (i % 8);
             }
         }
@@ -184,8 +184,8 @@ pub fn build_stmt_execute_packet(
 
         // Parameter values (only non-NULL)
         for param in params {
-            if !matches!(param, Value::Null) {
-                encode_binary_param(&mut writer, param);
+            if !param.is_null() {
+                writer.write_bytes(&encode_binary_value(param, value_to_field_type(param)));
             }
         }
     }
@@ -268,30 +268,6 @@ pub fn parse_stmt_prepare_ok(data: &[u8]) -> Option<StmtPrepareOk> {
     })
 }
 
-/// Determine the MySQL field type for a Value.
-fn value_to_field_type(value: &Value) -> FieldType {
-    match value {
-        Value::Null => FieldType::Null,
-        Value::Bool(_) => FieldType::Tiny,
-        Value::TinyInt(_) => FieldType::Tiny,
-        Value::SmallInt(_) => FieldType::Short,
-        Value::Int(_) => FieldType::Long,
-        Value::BigInt(_) => FieldType::LongLong,
-        Value::Float(_) => FieldType::Float,
-        Value::Double(_) => FieldType::Double,
-        Value::Decimal(_) => FieldType::NewDecimal,
-        Value::Text(_) => FieldType::VarString,
-        Value::Bytes(_) => FieldType::Blob,
-        Value::Json(_) => FieldType::Json,
-        Value::Date(_) => FieldType::Date,
-        Value::Time(_) => FieldType::Time,
-        Value::Timestamp(_) | Value::TimestampTz(_) => FieldType::DateTime,
-        Value::Uuid(_) => FieldType::Blob,
-        Value::Array(_) => FieldType::Json,
-        Value::Default => FieldType::Null,
-    }
-}
-
 /// Check if a value should use unsigned encoding.
 fn is_unsigned_value(value: &Value) -> bool {
     // In Rust, we use signed types, so we typically send as signed.
@@ -299,229 +275,6 @@ fn is_unsigned_value(value: &Value) -> bool {
     matches!(value, Value::BigInt(i) if *i > i64::MAX / 2)
 }
 
-/// Encode a parameter value for binary protocol.
-fn encode_binary_param(writer: &mut PacketWriter, value: &Value) {
-    match value {
-        Value::Null => {
-            // NULL values are indicated in the NULL bitmap, no data here
-        }
-        Value::Bool(b) => {
-            writer.write_u8(if *b { 1 } else { 0 });
-        }
-        Value::TinyInt(i) => {
-            writer.write_u8(*i as u8);
-        }
-        Value::SmallInt(i) => {
-            writer.write_u16_le(*i as u16);
-        }
-        Value::Int(i) => {
-            writer.write_u32_le(*i as u32);
-        }
-        Value::BigInt(i) => {
-            writer.write_u64_le(*i as u64);
-        }
-        Value::Float(f) => {
-            writer.write_bytes(&f.to_le_bytes());
-        }
-        Value::Double(f) => {
-            writer.write_bytes(&f.to_le_bytes());
-        }
-        Value::Decimal(s) => {
-            write_length_encoded_string(writer, s);
-        }
-        Value::Text(s) => {
-            write_length_encoded_string(writer, s);
-        }
-        Value::Bytes(b) => {
-            write_length_encoded_bytes(writer, b);
-        }
-        Value::Json(j) => {
-            let s = j.to_string();
-            write_length_encoded_string(writer, &s);
-        }
-        Value::Date(days) => {
-            // MySQL DATE binary format: length + year(2) + month(1) + day(1)
-            // We have days since epoch, need to convert
-            encode_binary_date(writer, *days);
-        }
-        Value::Time(micros) => {
-            // MySQL TIME binary format
-            encode_binary_time(writer, *micros);
-        }
-        Value::Timestamp(micros) | Value::TimestampTz(micros) => {
-            // MySQL DATETIME binary format
-            encode_binary_datetime(writer, *micros);
-        }
-        Value::Uuid(bytes) => {
-            write_length_encoded_bytes(writer, bytes);
-        }
-        Value::Array(arr) => {
-            // Encode arrays as JSON
-            let s = serde_json::to_string(arr).unwrap_or_default();
-            write_length_encoded_string(writer, &s);
-        }
-        Value::Default => {
-            // DEFAULT values are indicated in the NULL bitmap, no data here
-        }
-    }
-}
-
-/// Write a length-encoded string.
-fn write_length_encoded_string(writer: &mut PacketWriter, s: &str) {
-    write_length_encoded_bytes(writer, s.as_bytes());
-}
-
-/// Write length-encoded bytes.
-fn write_length_encoded_bytes(writer: &mut PacketWriter, data: &[u8]) {
-    let len = data.len();
-    if len < 251 {
-        writer.write_u8(len as u8);
-    } else if len < 0x10000 {
-        writer.write_u8(0xFC);
-        writer.write_u16_le(len as u16);
-    } else if len < 0x0100_0000 {
-        writer.write_u8(0xFD);
-        writer.write_u8((len & 0xFF) as u8);
-        writer.write_u8(((len >> 8) & 0xFF) as u8);
-        writer.write_u8(((len >> 16) & 0xFF) as u8);
-    } else {
-        writer.write_u8(0xFE);
-        writer.write_u64_le(len as u64);
-    }
-    writer.write_bytes(data);
-}
-
-/// Encode a date value (days since epoch) to MySQL binary format.
-fn encode_binary_date(writer: &mut PacketWriter, days: i32) {
-    // Convert days since Unix epoch (1970-01-01) to year/month/day
-    // Using a simplified algorithm
-    let (year, month, day) = days_to_ymd(days);
-
-    if year == 0 && month == 0 && day == 0 {
-        // Zero date - send length 0
-        writer.write_u8(0);
-    } else {
-        writer.write_u8(4); // length
-        writer.write_u16_le(year as u16);
-        writer.write_u8(month as u8);
-        writer.write_u8(day as u8);
-    }
-}
-
-/// Encode a time value (microseconds since midnight) to MySQL binary format.
-fn encode_binary_time(writer: &mut PacketWriter, micros: i64) {
-    let is_negative = micros < 0;
-    let micros = micros.unsigned_abs();
-
-    let total_seconds = micros / 1_000_000;
-    let microseconds = (micros % 1_000_000) as u32;
-
-    let hours = total_seconds / 3600;
-    let minutes = (total_seconds % 3600) / 60;
-    let seconds = total_seconds % 60;
-
-    // For times > 24 hours, we need to include days
-    let days = hours / 24;
-    let hours = hours % 24;
-
-    if days == 0 && hours == 0 && minutes == 0 && seconds == 0 && microseconds == 0 {
-        writer.write_u8(0); // length 0 for zero time
-    } else if microseconds == 0 {
-        writer.write_u8(8); // length without microseconds
-        writer.write_u8(if is_negative { 1 } else { 0 });
-        writer.write_u32_le(days as u32);
-        writer.write_u8(hours as u8);
-        writer.write_u8(minutes as u8);
-        writer.write_u8(seconds as u8);
-    } else {
-        writer.write_u8(12); // length with microseconds
-        writer.write_u8(if is_negative { 1 } else { 0 });
-        writer.write_u32_le(days as u32);
-        writer.write_u8(hours as u8);
-        writer.write_u8(minutes as u8);
-        writer.write_u8(seconds as u8);
-        writer.write_u32_le(microseconds);
-    }
-}
-
-/// Encode a datetime value (microseconds since epoch) to MySQL binary format.
-fn encode_binary_datetime(writer: &mut PacketWriter, micros: i64) {
-    // Convert microseconds since Unix epoch to date/time components
-    let total_seconds = micros / 1_000_000;
-    let microseconds = (micros % 1_000_000).unsigned_abs() as u32;
-
-    // Days since epoch
-    let days = (total_seconds / 86400) as i32;
-    let time_of_day = (total_seconds % 86400).unsigned_abs();
-
-    let (year, month, day) = days_to_ymd(days);
-    let hour = (time_of_day / 3600) as u8;
-    let minute = ((time_of_day % 3600) / 60) as u8;
-    let second = (time_of_day % 60) as u8;
-
-    if year == 0
-        && month == 0
-        && day == 0
-        && hour == 0
-        && minute == 0
-        && second == 0
-        && microseconds == 0
-    {
-        writer.write_u8(0); // Zero datetime
-    } else if hour == 0 && minute == 0 && second == 0 && microseconds == 0 {
-        writer.write_u8(4); // Date only
-        writer.write_u16_le(year as u16);
-        writer.write_u8(month as u8);
-        writer.write_u8(day as u8);
-    } else if microseconds == 0 {
-        writer.write_u8(7); // Date + time without microseconds
-        writer.write_u16_le(year as u16);
-        writer.write_u8(month as u8);
-        writer.write_u8(day as u8);
-        writer.write_u8(hour);
-        writer.write_u8(minute);
-        writer.write_u8(second);
-    } else {
-        writer.write_u8(11); // Full datetime with microseconds
-        writer.write_u16_le(year as u16);
-        writer.write_u8(month as u8);
-        writer.write_u8(day as u8);
-        writer.write_u8(hour);
-        writer.write_u8(minute);
-        writer.write_u8(second);
-        writer.write_u32_le(microseconds);
-    }
-}
-
-/// Convert days since Unix epoch to (year, month, day).
-///
-/// Uses the civil calendar algorithm from Howard Hinnant.
-/// Unix epoch is 1970-01-01 (day 0).
-fn days_to_ymd(days: i32) -> (i32, i32, i32) {
-    // Shift epoch from 1970-01-01 to 0000-03-01 (simplifies leap year handling)
-    // 719468 is the number of days from 0000-03-01 to 1970-01-01
-    let z = days + 719_468;
-
-    // Compute era (400-year period)
-    let era = if z >= 0 {
-        z / 146_097
-    } else {
-        (z - 146_096) / 146_097
-    };
-    let doe = (z - era * 146_097) as u32; // day of era [0, 146096]
-    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365; // year of era [0, 399]
-    let y = yoe as i32 + era * 400;
-    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100); // day of year [0, 365]
-    let mp = (5 * doy + 2) / 153; // month in [0, 11] starting from March
-    let d = doy - (153 * mp + 2) / 5 + 1; // day [1, 31]
-    let m = if mp < 10 { mp + 3 } else { mp - 9 }; // month [1, 12]
-
-    // Adjust year if month is Jan or Feb (we shifted to March-based year)
-    let year = if m <= 2 { y + 1 } else { y };
-
-    (year, m as i32, d as i32)
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -661,16 +414,4 @@ mod tests {
         );
         assert_eq!(value_to_field_type(&Value::Bytes(vec![])), FieldType::Blob);
     }
-
-    #[test]
-    fn test_days_to_ymd() {
-        // Unix epoch
-        assert_eq!(days_to_ymd(0), (1970, 1, 1));
-
-        // 2000-01-01 is day 10957
-        assert_eq!(days_to_ymd(10957), (2000, 1, 1));
-
-        // 2024-02-29 (leap year) is day 19782
-        assert_eq!(days_to_ymd(19782), (2024, 2, 29));
-    }
 }
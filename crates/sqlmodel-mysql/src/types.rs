@@ -330,19 +330,19 @@ pub fn decode_text_value(field_type: FieldType, data: &[u8], is_unsigned: bool)
             if is_unsigned {
                 text.parse::<u8>().map_or_else(
                     |_| Value::Text(text.into_owned()),
-                    |v| Value::TinyInt(v as i8),
+                    |v| Value::SmallInt(i16::from(v)),
                 )
             } else {
                 text.parse::<i8>()
                     .map_or_else(|_| Value::Text(text.into_owned()), Value::TinyInt)
             }
         }
-        // SMALLINT (16-bit)
+        // SMALLINT (16-bit); YEAR (1901-2155) always fits SMALLINT
         FieldType::Short | FieldType::Year => {
-            if is_unsigned {
+            if is_unsigned && field_type == FieldType::Short {
                 text.parse::<u16>().map_or_else(
                     |_| Value::Text(text.into_owned()),
-                    |v| Value::SmallInt(v as i16),
+                    |v| Value::Int(i32::from(v)),
                 )
             } else {
                 text.parse::<i16>()
@@ -352,8 +352,10 @@ pub fn decode_text_value(field_type: FieldType, data: &[u8], is_unsigned: bool)
         // INT/MEDIUMINT (32-bit)
         FieldType::Long | FieldType::Int24 => {
             if is_unsigned {
-                text.parse::<u32>()
-                    .map_or_else(|_| Value::Text(text.into_owned()), |v| Value::Int(v as i32))
+                text.parse::<u32>().map_or_else(
+                    |_| Value::Text(text.into_owned()),
+                    |v| Value::BigInt(i64::from(v)),
+                )
             } else {
                 text.parse::<i32>()
                     .map_or_else(|_| Value::Text(text.into_owned()), Value::Int)
@@ -362,10 +364,8 @@ pub fn decode_text_value(field_type: FieldType, data: &[u8], is_unsigned: bool)
         // BIGINT (64-bit)
         FieldType::LongLong => {
             if is_unsigned {
-                text.parse::<u64>().map_or_else(
-                    |_| Value::Text(text.into_owned()),
-                    |v| Value::BigInt(v as i64),
-                )
+                text.parse::<u64>()
+                    .map_or_else(|_| Value::Text(text.into_owned()), unsigned_bigint_value)
             } else {
                 text.parse::<i64>()
                     .map_or_else(|_| Value::Text(text.into_owned()), Value::BigInt)
@@ -382,8 +382,8 @@ pub fn decode_text_value(field_type: FieldType, data: &[u8], is_unsigned: bool)
             .parse::<f64>()
             .map_or_else(|_| Value::Text(text.into_owned()), Value::Double),
 
-        // Decimal (keep as text to preserve precision)
-        FieldType::Decimal | FieldType::NewDecimal => Value::Text(text.into_owned()),
+        // Decimal (kept as its exact text)
+        FieldType::Decimal | FieldType::NewDecimal => Value::Decimal(text.into_owned()),
 
         // Binary/blob types
         FieldType::TinyBlob
@@ -415,6 +415,11 @@ pub fn decode_text_value(field_type: FieldType, data: &[u8], is_unsigned: bool)
     }
 }
 
+/// Decode an unsigned BIGINT, spilling values above `i64::MAX` into a decimal.
+fn unsigned_bigint_value(v: u64) -> Value {
+    i64::try_from(v).map_or_else(|_| Value::Decimal(v.to_string()), Value::BigInt)
+}
+
 fn decode_text_date(original: &str) -> Value {
     let trimmed = original.trim();
     if trimmed.is_empty() {
@@ -700,6 +705,8 @@ fn parse_4_digits(bytes: &[u8], offset: usize) -> Option<u32> {
 /// Decode a binary protocol value to a sqlmodel Value.
 ///
 /// In binary protocol, values are encoded in type-specific binary formats.
+/// UNSIGNED integers widen to the next larger signed variant, with BIGINT
+/// UNSIGNED values above `i64::MAX` returned as [`Value::Decimal`].
 pub fn decode_binary_value(field_type: FieldType, data: &[u8], is_unsigned: bool) -> Value {
     match field_type {
         // TINY (1 byte)
@@ -707,9 +714,11 @@ pub fn decode_binary_value(field_type: FieldType, data: &[u8], is_unsigned: bool
             if data.is_empty() {
                 return Value::Null;
             }
-            // Both signed and unsigned map to i8 (interpretation differs at application level)
-            let _ = is_unsigned;
-            Value::TinyInt(data[0] as i8)
+            if is_unsigned {
+                Value::SmallInt(i16::from(data[0]))
+            } else {
+                Value::TinyInt(data[0] as i8)
+            }
         }
 
         // SHORT (2 bytes, little-endian)
@@ -718,9 +727,11 @@ pub fn decode_binary_value(field_type: FieldType, data: &[u8], is_unsigned: bool
                 return Value::Null;
             }
             let val = u16::from_le_bytes([data[0], data[1]]);
-            // Both signed and unsigned map to i16 (interpretation differs at application level)
-            let _ = is_unsigned;
-            Value::SmallInt(val as i16)
+            if is_unsigned && field_type == FieldType::Short {
+                Value::Int(i32::from(val))
+            } else {
+                Value::SmallInt(val as i16)
+            }
         }
 
         // LONG/INT24 (4 bytes, little-endian)
@@ -729,9 +740,11 @@ pub fn decode_binary_value(field_type: FieldType, data: &[u8], is_unsigned: bool
                 return Value::Null;
             }
             let val = u32::from_le_bytes([data[0], data[1], data[2], data[3]]);
-            // Both signed and unsigned map to i32 (interpretation differs at application level)
-            let _ = is_unsigned;
-            Value::Int(val as i32)
+            if is_unsigned {
+                Value::BigInt(i64::from(val))
+            } else {
+                Value::Int(val as i32)
+            }
         }
 
         // LONGLONG (8 bytes, little-endian)
@@ -742,9 +755,11 @@ pub fn decode_binary_value(field_type: FieldType, data: &[u8], is_unsigned: bool
             let val = u64::from_le_bytes([
                 data[0], data[1], data[2], data[3], data[4], data[5], data[6], data[7],
             ]);
-            // Both signed and unsigned map to i64 (interpretation differs at application level)
-            let _ = is_unsigned;
-            Value::BigInt(val as i64)
+            if is_unsigned {
+                unsigned_bigint_value(val)
+            } else {
+                Value::BigInt(val as i64)
+            }
         }
 
         // FLOAT (4 bytes)
@@ -791,9 +806,9 @@ pub fn decode_binary_value(field_type: FieldType, data: &[u8], is_unsigned: bool
         | FieldType::DateTime2
         | FieldType::Timestamp2 => decode_binary_temporal_value(field_type, data),
 
-        // Decimal types - keep as text for precision
+        // Decimal types - sent as their exact text
         FieldType::Decimal | FieldType::NewDecimal => {
-            Value::Text(String::from_utf8_lossy(data).into_owned())
+            Value::Decimal(String::from_utf8_lossy(data).into_owned())
         }
 
         // String types
@@ -822,7 +837,7 @@ fn days_in_month(year: i32, month: u32) -> u32 {
 
 /// Convert (year, month, day) to days since Unix epoch (1970-01-01), if valid.
 ///
-/// Inverse of `days_to_ymd()` in `protocol/prepared.rs` (Howard Hinnant algorithm).
+/// Inverse of `days_to_ymd()` (Howard Hinnant algorithm).
 fn ymd_to_days_since_unix_epoch(year: i32, month: u32, day: u32) -> Option<i32> {
     if year <= 0 || !(1..=12).contains(&month) {
         return None;
@@ -950,12 +965,45 @@ fn decode_binary_temporal_value(field_type: FieldType, data: &[u8]) -> Value {
     }
 }
 
+/// Determine the MySQL field type to advertise for a Value.
+pub fn value_to_field_type(value: &Value) -> FieldType {
+    match value {
+        Value::Null | Value::TypedNull(_) => FieldType::Null,
+        Value::Bool(_) => FieldType::Tiny,
+        Value::TinyInt(_) => FieldType::Tiny,
+        Value::SmallInt(_) => FieldType::Short,
+        Value::Int(_) => FieldType::Long,
+        Value::BigInt(_) => FieldType::LongLong,
+        Value::Float(_) => FieldType::Float,
+        Value::Double(_) => FieldType::Double,
+        Value::Decimal(_) => FieldType::NewDecimal,
+        Value::Text(_) => FieldType::VarString,
+        Value::Bytes(_) => FieldType::Blob,
+        Value::Json(_) => FieldType::Json,
+        Value::Date(_) => FieldType::Date,
+        Value::Time(_) => FieldType::Time,
+        Value::Timestamp(_) | Value::TimestampTz(_) => FieldType::DateTime,
+        Value::Uuid(_) => FieldType::Blob,
+        Value::Array(_) => FieldType::Json,
+        Value::Default => FieldType::Null,
+    }
+}
+
+/// Encode a dynamic Value for the binary protocol.
+///
+/// Returns the encoded bytes and the field type they were encoded as.
+/// `Value::Null` encodes to no bytes; NULLs travel in the NULL bitmap.
+pub fn encode_value(value: &Value) -> (Vec<u8>, FieldType) {
+    let field_type = value_to_field_type(value);
+    (encode_binary_value(value, field_type), field_type)
+}
+
 /// Encode a sqlmodel Value for binary protocol.
 ///
 /// Returns the encoded bytes for the value.
 pub fn encode_binary_value(value: &Value, field_type: FieldType) -> Vec<u8> {
     match value {
-        Value::Null => vec![],
+        Value::Null | Value::TypedNull(_) => vec![],
 
         Value::Bool(b) => vec![u8::from(*b)],
 
@@ -990,14 +1038,14 @@ pub fn encode_binary_value(value: &Value, field_type: FieldType) -> Vec<u8> {
             encode_length_prefixed_bytes(s.as_bytes())
         }
 
-        // Date is days since epoch (i32)
-        Value::Date(d) => d.to_le_bytes().to_vec(),
+        // Date is days since epoch: length + year(2) + month(1) + day(1)
+        Value::Date(d) => encode_binary_date(*d),
 
-        // Time is microseconds since midnight (i64)
-        Value::Time(t) => t.to_le_bytes().to_vec(),
+        // Time is microseconds since midnight: length + sign + days + h/m/s [+ micros]
+        Value::Time(t) => encode_binary_time(*t),
 
-        // Timestamp is microseconds since epoch (i64)
-        Value::Timestamp(t) | Value::TimestampTz(t) => t.to_le_bytes().to_vec(),
+        // Timestamp is microseconds since epoch: length + date [+ h/m/s [+ micros]]
+        Value::Timestamp(t) | Value::TimestampTz(t) => encode_binary_datetime(*t),
 
         // UUID is 16 bytes
         Value::Uuid(u) => encode_length_prefixed_bytes(u),
@@ -1014,6 +1062,169 @@ pub fn encode_binary_value(value: &Value, field_type: FieldType) -> Vec<u8> {
     }
 }
 
+/// Encode a date value (days since epoch) to MySQL binary format.
+fn encode_binary_date(days: i32) -> Vec<u8> {
+    let (year, month, day) = days_to_ymd(days);
+
+    let mut out = Vec::with_capacity(5);
+    out.push(4); // length
+    out.extend_from_slice(&(year as u16).to_le_bytes());
+    out.push(month as u8);
+    out.push(day as u8);
+    out
+}
+
+/// Encode a time value (microseconds since midnight) to MySQL binary format.
+fn encode_binary_time(micros: i64) -> Vec<u8> {
+    let is_negative = micros < 0;
+    let micros = micros.unsigned_abs();
+
+    let total_seconds = micros / 1_000_000;
+    let microseconds = (micros % 1_000_000) as u32;
+
+    let hours = total_seconds / 3600;
+    let minutes = (total_seconds % 3600) / 60;
+    let seconds = total_seconds % 60;
+
+    // For times > 24 hours, we need to include days
+    let days = hours / 24;
+    let hours = hours % 24;
+
+    if total_seconds == 0 && microseconds == 0 {
+        return vec![0]; // length 0 for zero time
+    }
+
+    let mut out = Vec::with_capacity(13);
+    out.push(if microseconds == 0 { 8 } else { 12 });
+    out.push(u8::from(is_negative));
+    out.extend_from_slice(&(days as u32).to_le_bytes());
+    out.push(hours as u8);
+    out.push(minutes as u8);
+    out.push(seconds as u8);
+    if microseconds != 0 {
+        out.extend_from_slice(&microseconds.to_le_bytes());
+    }
+    out
+}
+
+/// Encode a datetime value (microseconds since epoch) to MySQL binary format.
+fn encode_binary_datetime(micros: i64) -> Vec<u8> {
+    // Floor division keeps pre-1970 timestamps on the correct calendar day.
+    let total_seconds = micros.div_euclid(1_000_000);
+    let microseconds = micros.rem_euclid(1_000_000) as u32;
+
+    let days = total_seconds.div_euclid(86_400) as i32;
+    let time_of_day = total_seconds.rem_euclid(86_400);
+
+    let (year, month, day) = days_to_ymd(days);
+    let hour = (time_of_day / 3600) as u8;
+    let minute = ((time_of_day % 3600) / 60) as u8;
+    let second = (time_of_day % 60) as u8;
+
+    let mut out = Vec::with_capacity(12);
+    if time_of_day == 0 && microseconds == 0 {
+        out.push(4); // Date only
+    } else if microseconds == 0 {
+        out.push(7); // Date + time without microseconds
+    } else {
+        out.push(11); // Full datetime with microseconds
+    }
+    out.extend_from_slice(&(year as u16).to_le_bytes());
+    out.push(month as u8);
+    out.push(day as u8);
+    if time_of_day != 0 || microseconds != 0 {
+        out.push(hour);
+        out.push(minute);
+        out.push(second);
+    }
+    if microseconds != 0 {
+        out.extend_from_slice(&microseconds.to_le_bytes());
+    }
+    out
+}
+
+/// Convert days since Unix epoch to (year, month, day).
+///
+/// Uses the civil calendar algorithm from Howard Hinnant.
+/// Unix epoch is 1970-01-01 (day 0).
+fn days_to_ymd(days: i32) -> (i32, i32, i32) {
+    // Shift epoch from 1970-01-01 to 0000-03-01 (simplifies leap year handling)
+    // 719468 is the number of days from 0000-03-01 to 1970-01-01
+    let z = days + 719_468;
+
+    // Compute era (400-year period)
+    let era = if z >= 0 {
+        z / 146_097
+    } else {
+        (z - 146_096) / 146_097
+    };
+    let doe = (z - era * 146_097) as u32; // day of era [0, 146096]
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365; // year of era [0, 399]
+    let y = yoe as i32 + era * 400;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100); // day of year [0, 365]
+    let mp = (5 * doy + 2) / 153; // month in [0, 11] starting from March
+    let d = doy - (153 * mp + 2) / 5 + 1; // day [1, 31]
+    let m = if mp < 10 { mp + 3 } else { mp - 9 }; // month [1, 12]
+
+    // Adjust year if month is Jan or Feb (we shifted to March-based year)
+    let year = if m <= 2 { y + 1 } else { y };
+
+    (year, m as i32, d as i32)
+}
+
+/// Decode a binary protocol value to a dynamic Value.
+///
+/// # Arguments
+/// * `field_type` - The MySQL column type
+/// * `data` - The raw value bytes without any length prefix (None for NULL)
+/// * `is_unsigned` - Whether the column carries the UNSIGNED flag
+pub fn decode_value(field_type: FieldType, data: Option<&[u8]>, is_unsigned: bool) -> Value {
+    match data {
+        Some(data) => decode_binary_value(field_type, data, is_unsigned),
+        None => Value::Null,
+    }
+}
+
+/// Decode a binary protocol result row (`COM_STMT_EXECUTE` response).
+///
+/// Row layout:
+/// - 0x00 packet header
+/// - NULL bitmap of `(column_count + 7 + 2) / 8` bytes (first two bits reserved)
+/// - Values for non-NULL columns, fixed-width or length-encoded per type
+///
+/// Returns `None` if the packet is truncated or does not start with the
+/// binary row header.
+pub fn decode_binary_row(data: &[u8], columns: &[ColumnDef]) -> Option<Vec<Value>> {
+    if data.first() != Some(&0x00) {
+        return None;
+    }
+    let mut pos = 1;
+
+    let null_bitmap_len = (columns.len() + 7 + 2) / 8;
+    let null_bitmap = data.get(pos..pos + null_bitmap_len)?;
+    pos += null_bitmap_len;
+
+    let mut values = Vec::with_capacity(columns.len());
+    for (i, col) in columns.iter().enumerate() {
+        // Bit position is offset by 2 reserved bits
+        let bit_pos = i + 2;
+        if null_bitmap[bit_pos / 8] & (1 << (bit_pos % 8)) != 0 {
+            values.push(Value::Null);
+            continue;
+        }
+
+        let (value, consumed) =
+            decode_binary_value_with_len(&data[pos..], col.column_type, col.is_unsigned());
+        if consumed == 0 && col.column_type != FieldType::Null {
+            return None;
+        }
+        values.push(value);
+        pos += consumed;
+    }
+
+    Some(values)
+}
+
 /// Decode a binary protocol value and return bytes consumed.
 ///
 /// This is used when parsing binary result set rows where we need to know
@@ -1025,7 +1236,7 @@ pub fn encode_binary_value(value: &Value, field_type: FieldType) -> Vec<u8> {
 pub fn decode_binary_value_with_len(
     data: &[u8],
     field_type: FieldType,
-    _is_unsigned: bool,
+    is_unsigned: bool,
 ) -> (Value, usize) {
     match field_type {
         // Fixed-size integer types
@@ -1033,33 +1244,28 @@ pub fn decode_binary_value_with_len(
             if data.is_empty() {
                 return (Value::Null, 0);
             }
-            (Value::TinyInt(data[0] as i8), 1)
+            (decode_binary_value(field_type, &data[..1], is_unsigned), 1)
         }
 
         FieldType::Short | FieldType::Year => {
             if data.len() < 2 {
                 return (Value::Null, 0);
             }
-            let val = u16::from_le_bytes([data[0], data[1]]);
-            (Value::SmallInt(val as i16), 2)
+            (decode_binary_value(field_type, &data[..2], is_unsigned), 2)
         }
 
         FieldType::Long | FieldType::Int24 => {
             if data.len() < 4 {
                 return (Value::Null, 0);
             }
-            let val = u32::from_le_bytes([data[0], data[1], data[2], data[3]]);
-            (Value::Int(val as i32), 4)
+            (decode_binary_value(field_type, &data[..4], is_unsigned), 4)
         }
 
         FieldType::LongLong => {
             if data.len() < 8 {
                 return (Value::Null, 0);
             }
-            let val = u64::from_le_bytes([
-                data[0], data[1], data[2], data[3], data[4], data[5], data[6], data[7],
-            ]);
-            (Value::BigInt(val as i64), 8)
+            (decode_binary_value(field_type, &data[..8], is_unsigned), 8)
         }
 
         FieldType::Float => {
@@ -1165,6 +1371,9 @@ pub fn decode_binary_value_with_len(
                     serde_json::from_str(&text)
                         .map_or_else(|_| Value::Bytes(str_data.to_vec()), Value::Json)
                 }
+                FieldType::Decimal | FieldType::NewDecimal => {
+                    Value::Decimal(String::from_utf8_lossy(str_data).into_owned())
+                }
                 _ => Value::Text(String::from_utf8_lossy(str_data).into_owned()),
             };
             (value, total_len)
@@ -1273,7 +1482,7 @@ fn escape_bytes(data: &[u8]) -> String {
 /// This converts a Value to a properly escaped SQL literal string.
 pub fn format_value_for_sql(value: &Value) -> String {
     match value {
-        Value::Null => "NULL".to_string(),
+        Value::Null | Value::TypedNull(_) => "NULL".to_string(),
         Value::Bool(b) => if *b { "TRUE" } else { "FALSE" }.to_string(),
         Value::TinyInt(i) => i.to_string(),
         Value::SmallInt(i) => i.to_string(),
@@ -1514,6 +1723,65 @@ mod tests {
         assert!(matches!(val, Value::BigInt(-100)));
     }
 
+    #[test]
+    fn test_decode_unsigned_integers_widen() {
+        assert_eq!(
+            decode_text_value(FieldType::Tiny, b"255", true),
+            Value::SmallInt(255)
+        );
+        assert_eq!(
+            decode_text_value(FieldType::Short, b"65535", true),
+            Value::Int(65535)
+        );
+        assert_eq!(
+            decode_text_value(FieldType::Long, b"4294967295", true),
+            Value::BigInt(4_294_967_295)
+        );
+        assert_eq!(
+            decode_text_value(FieldType::LongLong, b"18446744073709551615", true),
+            Value::Decimal("18446744073709551615".to_string())
+        );
+
+        assert_eq!(
+            decode_binary_value_with_len(&[0xFF], FieldType::Tiny, true),
+            (Value::SmallInt(255), 1)
+        );
+        assert_eq!(
+            decode_binary_value_with_len(&[0xFF, 0xFF], FieldType::Short, true),
+            (Value::Int(65535), 2)
+        );
+        assert_eq!(
+            decode_binary_value_with_len(&u32::MAX.to_le_bytes(), FieldType::Long, true),
+            (Value::BigInt(i64::from(u32::MAX)), 4)
+        );
+        assert_eq!(
+            decode_binary_value_with_len(&42_u64.to_le_bytes(), FieldType::LongLong, true),
+            (Value::BigInt(42), 8)
+        );
+        assert_eq!(
+            decode_binary_value_with_len(&u64::MAX.to_le_bytes(), FieldType::LongLong, true),
+            (Value::Decimal(u64::MAX.to_string()), 8)
+        );
+        // Signed columns keep their width.
+        assert_eq!(
+            decode_binary_value_with_len(&[0xFF], FieldType::Tiny, false),
+            (Value::TinyInt(-1), 1)
+        );
+    }
+
+    #[test]
+    fn test_decode_decimal_same_variant_in_both_protocols() {
+        let text = decode_text_value(FieldType::NewDecimal, b"123.450", false);
+        let (binary, _) =
+            decode_binary_value_with_len(b"\x07123.450", FieldType::NewDecimal, false);
+        assert_eq!(text, Value::Decimal("123.450".to_string()));
+        assert_eq!(binary, text);
+        assert_eq!(
+            decode_binary_value(FieldType::NewDecimal, b"123.450", false),
+            text
+        );
+    }
+
     #[test]
     #[allow(clippy::approx_constant)]
     fn test_decode_text_float() {
@@ -1699,4 +1967,245 @@ mod tests {
         let result = encode_length_prefixed_bytes(b"");
         assert_eq!(result, vec![0]);
     }
+
+    #[test]
+    fn test_days_to_ymd() {
+        // Unix epoch
+        assert_eq!(days_to_ymd(0), (1970, 1, 1));
+
+        // 2000-01-01 is day 10957
+        assert_eq!(days_to_ymd(10957), (2000, 1, 1));
+
+        // 2024-02-29 (leap year) is day 19782
+        assert_eq!(days_to_ymd(19782), (2024, 2, 29));
+    }
+
+    fn binary_col(name: &str, column_type: FieldType) -> ColumnDef {
+        ColumnDef {
+            catalog: "def".to_string(),
+            schema: "test".to_string(),
+            table: "t".to_string(),
+            org_table: "t".to_string(),
+            name: name.to_string(),
+            org_name: name.to_string(),
+            charset: 33,
+            column_length: 0,
+            column_type,
+            flags: 0,
+            decimals: 0,
+        }
+    }
+
+    /// Encode a value, then decode it back through the row-level decoder
+    /// (which consumes any length prefix) to exercise both directions.
+    fn roundtrip(value: &Value) -> Value {
+        let (bytes, field_type) = encode_value(value);
+        let (decoded, consumed) = decode_binary_value_with_len(&bytes, field_type, false);
+        assert_eq!(consumed, bytes.len(), "consumed mismatch for {value:?}");
+        decoded
+    }
+
+    #[test]
+    fn test_encode_value_integers() {
+        assert_eq!(
+            encode_value(&Value::TinyInt(-1)),
+            (vec![0xFF], FieldType::Tiny)
+        );
+        assert_eq!(
+            encode_value(&Value::SmallInt(0x0102)),
+            (vec![0x02, 0x01], FieldType::Short)
+        );
+        assert_eq!(
+            encode_value(&Value::Int(42)),
+            (vec![42, 0, 0, 0], FieldType::Long)
+        );
+        assert_eq!(
+            encode_value(&Value::BigInt(-2)).0,
+            (-2_i64).to_le_bytes().to_vec()
+        );
+        assert_eq!(roundtrip(&Value::TinyInt(-7)), Value::TinyInt(-7));
+        assert_eq!(roundtrip(&Value::SmallInt(-300)), Value::SmallInt(-300));
+        assert_eq!(roundtrip(&Value::Int(i32::MIN)), Value::Int(i32::MIN));
+        assert_eq!(roundtrip(&Value::BigInt(i64::MAX)), Value::BigInt(i64::MAX));
+    }
+
+    #[test]
+    fn test_encode_value_narrows_bigint_to_field_type() {
+        assert_eq!(
+            encode_binary_value(&Value::BigInt(5), FieldType::Tiny),
+            vec![5]
+        );
+        assert_eq!(
+            encode_binary_value(&Value::BigInt(5), FieldType::Long),
+            vec![5, 0, 0, 0]
+        );
+    }
+
+    #[test]
+    fn test_encode_value_decimal_as_string() {
+        let (bytes, field_type) = encode_value(&Value::Decimal("123.450".to_string()));
+        assert_eq!(field_type, FieldType::NewDecimal);
+        assert_eq!(bytes[0], 7);
+        assert_eq!(&bytes[1..], b"123.450");
+        assert_eq!(
+            roundtrip(&Value::Decimal("123.450".to_string())),
+            Value::Decimal("123.450".to_string())
+        );
+    }
+
+    #[test]
+    fn test_encode_value_text_and_blob() {
+        let text = Value::Text("hello".to_string());
+        assert_eq!(encode_value(&text).1, FieldType::VarString);
+        assert_eq!(roundtrip(&text), text);
+
+        let blob = Value::Bytes(vec![0, 1, 2, 0xFF]);
+        assert_eq!(encode_value(&blob).1, FieldType::Blob);
+        assert_eq!(roundtrip(&blob), blob);
+
+        // Long payloads switch to the 0xFC two-byte length prefix
+        let long = Value::Text("x".repeat(300));
+        let (bytes, _) = encode_value(&long);
+        assert_eq!(bytes[0], 0xFC);
+        assert_eq!(u16::from_le_bytes([bytes[1], bytes[2]]), 300);
+        assert_eq!(roundtrip(&long), long);
+    }
+
+    #[test]
+    fn test_encode_value_json() {
+        let json = Value::Json(serde_json::json!({"a": [1, 2]}));
+        assert_eq!(encode_value(&json).1, FieldType::Json);
+        assert_eq!(roundtrip(&json), json);
+    }
+
+    #[test]
+    fn test_encode_value_date() {
+        let days = ymd_to_days_since_unix_epoch(2024, 2, 29).unwrap();
+        let (bytes, field_type) = encode_value(&Value::Date(days));
+        assert_eq!(field_type, FieldType::Date);
+        assert_eq!(bytes, vec![4, 0xE8, 0x07, 2, 29]);
+        assert_eq!(roundtrip(&Value::Date(days)), Value::Date(days));
+    }
+
+    #[test]
+    fn test_encode_value_time() {
+        // 26:03:04.000005 -> 1 day + 02:03:04 with microseconds
+        let micros = ((26_i64 * 3600) + 3 * 60 + 4) * 1_000_000 + 5;
+        let (bytes, field_type) = encode_value(&Value::Time(micros));
+        assert_eq!(field_type, FieldType::Time);
+        assert_eq!(bytes[0], 12);
+        assert_eq!(bytes[1], 0);
+        assert_eq!(
+            u32::from_le_bytes([bytes[2], bytes[3], bytes[4], bytes[5]]),
+            1
+        );
+        assert_eq!(&bytes[6..9], &[2, 3, 4]);
+        assert_eq!(roundtrip(&Value::Time(micros)), Value::Time(micros));
+
+        let negative = -(3600_i64 * 1_000_000);
+        assert_eq!(encode_value(&Value::Time(negative)).0[1], 1);
+        assert_eq!(roundtrip(&Value::Time(negative)), Value::Time(negative));
+
+        assert_eq!(encode_value(&Value::Time(0)).0, vec![0]);
+    }
+
+    #[test]
+    fn test_encode_value_datetime() {
+        let days = i64::from(ymd_to_days_since_unix_epoch(2020, 1, 2).unwrap());
+        let midnight = days * 86_400 * 1_000_000;
+        let (bytes, field_type) = encode_value(&Value::Timestamp(midnight));
+        assert_eq!(field_type, FieldType::DateTime);
+        assert_eq!(bytes.len(), 5);
+        assert_eq!(
+            roundtrip(&Value::Timestamp(midnight)),
+            Value::Timestamp(midnight)
+        );
+
+        let with_time = midnight + ((3_i64 * 3600 + 4 * 60 + 5) * 1_000_000);
+        assert_eq!(encode_value(&Value::Timestamp(with_time)).0.len(), 8);
+        assert_eq!(
+            roundtrip(&Value::Timestamp(with_time)),
+            Value::Timestamp(with_time)
+        );
+
+        let with_micros = with_time + 6;
+        assert_eq!(encode_value(&Value::Timestamp(with_micros)).0.len(), 12);
+        assert_eq!(
+            roundtrip(&Value::Timestamp(with_micros)),
+            Value::Timestamp(with_micros)
+        );
+    }
+
+    #[test]
+    fn test_encode_value_datetime_before_epoch() {
+        // 1969-12-31 23:59:59
+        let micros = -1_000_000;
+        let (bytes, _) = encode_value(&Value::Timestamp(micros));
+        assert_eq!(bytes, vec![7, 0xB1, 0x07, 12, 31, 23, 59, 59]);
+        assert_eq!(
+            roundtrip(&Value::Timestamp(micros)),
+            Value::Timestamp(micros)
+        );
+    }
+
+    #[test]
+    fn test_decode_value_null() {
+        assert_eq!(decode_value(FieldType::Long, None, false), Value::Null);
+        assert_eq!(
+            decode_value(FieldType::Long, Some(&[7, 0, 0, 0]), false),
+            Value::Int(7)
+        );
+    }
+
+    #[test]
+    fn test_decode_binary_row_with_null_bitmap() {
+        let columns = vec![
+            binary_col("id", FieldType::LongLong),
+            binary_col("name", FieldType::VarString),
+            binary_col("score", FieldType::Double),
+            binary_col("data", FieldType::Blob),
+        ];
+
+        let mut row = vec![0x00];
+        // 4 columns + 2 reserved bits -> 1 byte; "score" (index 2) is NULL -> bit 4
+        row.push(1 << 4);
+        row.extend_from_slice(&9_i64.to_le_bytes());
+        row.extend_from_slice(&[3, b'a', b'b', b'c']);
+        row.extend_from_slice(&[2, 0xDE, 0xAD]);
+
+        let values = decode_binary_row(&row, &columns).unwrap();
+        assert_eq!(
+            values,
+            vec![
+                Value::BigInt(9),
+                Value::Text("abc".to_string()),
+                Value::Null,
+                Value::Bytes(vec![0xDE, 0xAD]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_decode_binary_row_bitmap_spans_bytes() {
+        // 7 columns + 2 reserved bits need a 2-byte bitmap; the last column
+        // lands on bit 0 of the second byte.
+        let columns: Vec<_> = (0..7)
+            .map(|i| binary_col(&format!("c{i}"), FieldType::Tiny))
+            .collect();
+        let mut row = vec![0x00, 0x00, 0x01];
+        row.extend_from_slice(&[1, 2, 3, 4, 5, 6]);
+
+        let values = decode_binary_row(&row, &columns).unwrap();
+        assert_eq!(values.len(), 7);
+        assert_eq!(values[5], Value::TinyInt(6));
+        assert_eq!(values[6], Value::Null);
+    }
+
+    #[test]
+    fn test_decode_binary_row_truncated() {
+        let columns = vec![binary_col("id", FieldType::Long)];
+        assert!(decode_binary_row(&[0x00, 0x00, 1, 2], &columns).is_none());
+        assert!(decode_binary_row(&[0x00], &columns).is_none());
+        assert!(decode_binary_row(&[0xFE, 0x00, 0, 0, 0, 0], &columns).is_none());
+    }
 }
@@ -155,6 +155,10 @@ pub struct PoolStats {
     pub active_connections: usize,
     /// Number of pending acquire requests
     pub pending_requests: usize,
+    /// Configured maximum number of connections
+    pub max_connections: usize,
+    /// Configured minimum number of connections
+    pub min_connections: usize,
     /// Total number of connections created
     pub connections_created: u64,
     /// Total number of connections closed
@@ -165,6 +169,52 @@ pub struct PoolStats {
     pub timeouts: u64,
 }
 
+/// Lets `sqlmodel-console` render a live dashboard straight from
+/// [`Pool::stats`]:
+///
+/// ```rust,ignore
+/// let display = PoolStatusDisplay::from_stats(&pool.stats());
+/// println!("{}", display.render_plain());
+/// ```
+#[cfg(feature = "console")]
+impl sqlmodel_console::renderables::PoolStatsProvider for PoolStats {
+    fn active_connections(&self) -> usize {
+        self.active_connections
+    }
+
+    fn idle_connections(&self) -> usize {
+        self.idle_connections
+    }
+
+    fn max_connections(&self) -> usize {
+        self.max_connections
+    }
+
+    fn min_connections(&self) -> usize {
+        self.min_connections
+    }
+
+    fn pending_requests(&self) -> usize {
+        self.pending_requests
+    }
+
+    fn connections_created(&self) -> u64 {
+        self.connections_created
+    }
+
+    fn connections_closed(&self) -> u64 {
+        self.connections_closed
+    }
+
+    fn total_acquires(&self) -> u64 {
+        self.acquires
+    }
+
+    fn total_timeouts(&self) -> u64 {
+        self.timeouts
+    }
+}
+
 /// Metadata about a pooled connection.
 #[derive(Debug)]
 struct ConnectionMeta<C> {
@@ -237,6 +287,8 @@ impl<C: Connection> PoolInner<C> {
             idle_connections: self.idle.len(),
             active_connections: self.active_count,
             pending_requests: self.waiter_count,
+            max_connections: self.config.max_connections,
+            min_connections: self.config.min_connections,
             ..Default::default()
         }
     }
@@ -582,42 +634,43 @@ impl<C: Connection> Pool<C> {
         }
     }
 
-    /// Validate a connection and wrap it in a PooledConnection.
+    /// Reset and validate a recycled connection and wrap it in a PooledConnection.
+    ///
+    /// The connection is always [`reset`](Connection::reset) so no session
+    /// state leaks from its previous holder; drivers skip the work when the
+    /// previous holder left nothing behind. It is pinged as well when
+    /// `test_on_checkout` is enabled.
     async fn validate_and_wrap(
         &self,
         cx: &Cx,
         meta: ConnectionMeta<C>,
         test_on_checkout: bool,
     ) -> Outcome<PooledConnection<C>, Error> {
-        if test_on_checkout {
-            // Validate the connection
-            match meta.conn.ping(cx).await {
-                Outcome::Ok(()) => {
-                    self.shared.acquires.fetch_add(1, Ordering::Relaxed);
-                    Outcome::Ok(PooledConnection::new(meta, Arc::downgrade(&self.shared)))
-                }
-                Outcome::Err(_) | Outcome::Cancelled(_) | Outcome::Panicked(_) => {
-                    // Connection is invalid, decrement counts and try again
-                    {
-                        if let Ok(mut inner) = self.shared.lock_or_error("validate_cleanup") {
-                            inner.total_count -= 1;
-                            inner.active_count -= 1;
-                        }
-                    }
-                    self.shared
-                        .connections_closed
-                        .fetch_add(1, Ordering::Relaxed);
-                    // Return error - caller should retry
-                    Outcome::Err(Error::Connection(ConnectionError {
-                        kind: ConnectionErrorKind::Disconnected,
-                        message: "connection validation failed".to_string(),
-                        source: None,
-                    }))
-                }
-            }
-        } else {
+        let mut usable = matches!(meta.conn.reset(cx).await, Outcome::Ok(()));
+        if usable && test_on_checkout {
+            usable = matches!(meta.conn.ping(cx).await, Outcome::Ok(()));
+        }
+
+        if usable {
             self.shared.acquires.fetch_add(1, Ordering::Relaxed);
             Outcome::Ok(PooledConnection::new(meta, Arc::downgrade(&self.shared)))
+        } else {
+            // Connection is invalid, decrement counts and try again
+            {
+                if let Ok(mut inner) = self.shared.lock_or_error("validate_cleanup") {
+                    inner.total_count -= 1;
+                    inner.active_count -= 1;
+                }
+            }
+            self.shared
+                .connections_closed
+                .fetch_add(1, Ordering::Relaxed);
+            // Return error - caller should retry
+            Outcome::Err(Error::Connection(ConnectionError {
+                kind: ConnectionErrorKind::Disconnected,
+                message: "connection validation failed".to_string(),
+                source: None,
+            }))
         }
     }
 
@@ -1141,9 +1194,11 @@ mod tests {
 
     #[test]
     fn test_pool_stats_initial() {
-        let pool: Pool<MockConnection> = Pool::new(PoolConfig::new(5));
+        let pool: Pool<MockConnection> = Pool::new(PoolConfig::new(5).min_connections(1));
 
         let stats = pool.stats();
+        assert_eq!(stats.max_connections, 5);
+        assert_eq!(stats.min_connections, 1);
         assert_eq!(stats.total_connections, 0);
         assert_eq!(stats.idle_connections, 0);
         assert_eq!(stats.active_connections, 0);
@@ -1154,6 +1209,36 @@ mod tests {
         assert_eq!(stats.timeouts, 0);
     }
 
+    #[cfg(feature = "console")]
+    #[test]
+    fn test_stats_feed_pool_status_display() {
+        use sqlmodel_console::renderables::{PoolHealth, PoolStatusDisplay};
+
+        let stats = PoolStats {
+            total_connections: 10,
+            idle_connections: 1,
+            active_connections: 9,
+            max_connections: 10,
+            min_connections: 2,
+            acquires: 42,
+            ..Default::default()
+        };
+        let display = PoolStatusDisplay::from_stats(&stats);
+        assert_eq!(display.health(), PoolHealth::Busy);
+        assert!(display.render_plain().contains("9/10"));
+
+        let stats = PoolStats {
+            pending_requests: 3,
+            active_connections: 10,
+            idle_connections: 0,
+            ..stats
+        };
+        assert_eq!(
+            PoolStatusDisplay::from_stats(&stats).health(),
+            PoolHealth::Exhausted
+        );
+    }
+
     #[test]
     fn test_pool_close() {
         let pool: Pool<MockConnection> = Pool::new(PoolConfig::new(5));
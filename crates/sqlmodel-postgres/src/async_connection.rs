@@ -20,27 +20,35 @@ use std::future::Future;
 #[cfg(feature = "tls")]
 use std::io::{Read, Write};
 use std::sync::Arc;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::time::Duration;
 
 use asupersync::io::{AsyncRead, AsyncWrite, ReadBuf};
 use asupersync::net::TcpStream;
 use asupersync::sync::Mutex;
-use asupersync::{Cx, Outcome};
+use asupersync::time::{timeout, wall_now};
+use asupersync::{CancelReason, Cx, Outcome};
 
-use sqlmodel_core::connection::{Connection, IsolationLevel, PreparedStatement, TransactionOps};
+use sqlmodel_core::connection::{
+    Connection, IsolationLevel, PreparedStatement, TransactionMode, TransactionOps,
+    TransactionStatus,
+};
 use sqlmodel_core::error::{
     ConnectionError, ConnectionErrorKind, ProtocolError, QueryError, QueryErrorKind,
 };
 use sqlmodel_core::row::ColumnInfo;
-use sqlmodel_core::{Error, Row, Value};
+use sqlmodel_core::script::split_statements;
+use sqlmodel_core::{Error, Row, Value, quote_ident};
 
 use crate::auth::ScramClient;
 use crate::config::{PgConfig, SslMode};
 use crate::connection::{ConnectionState, TransactionStatusState};
 use crate::protocol::{
-    BackendMessage, DescribeKind, ErrorFields, FrontendMessage, MessageReader, MessageWriter,
-    PROTOCOL_VERSION,
+    BackendMessage, BinaryCopyDecoder, CommandTag, DescribeKind, ErrorFields, FrontendMessage,
+    MessageReader, MessageWriter, PROTOCOL_VERSION,
 };
-use crate::types::{Format, decode_value, encode_value};
+use crate::statement_cache::{CachedStatement, StatementCache, is_plannable};
+use crate::types::{Format, decode_value, encode_value, oid};
 
 #[cfg(feature = "tls")]
 use crate::tls;
@@ -280,6 +288,20 @@ async fn flush_plain_async(stream: &mut TcpStream) -> std::io::Result<()> {
     std::future::poll_fn(|cx| std::pin::Pin::new(&mut *stream).poll_flush(cx)).await
 }
 
+/// Time left before the `Cx` budget deadline, or `None` if it has none.
+fn budget_remaining(cx: &Cx) -> Option<Duration> {
+    let deadline = cx.budget().deadline?;
+    Some(Duration::from_nanos(
+        deadline.as_nanos().saturating_sub(wall_now().as_nanos()),
+    ))
+}
+
+/// Callback invoked with the new [`ConnectionState`] on every transition.
+///
+/// Listeners run inline on the connection's task, so they should be cheap
+/// (record a metric, flag the connection for eviction) and must not block.
+pub type StateListener = Arc<dyn Fn(ConnectionState) + Send + Sync>;
+
 /// Async PostgreSQL connection.
 ///
 /// This connection uses asupersync's TCP stream for non-blocking I/O and
@@ -292,10 +314,19 @@ pub struct PgAsyncConnection {
     parameters: HashMap<String, String>,
     next_prepared_id: u64,
     prepared: HashMap<u64, PgPreparedMeta>,
+    statement_cache: StatementCache,
+    /// Set once a utility command or an explicit `prepare` may have changed
+    /// session state; cleared by [`reset`](Self::reset).
+    /// Transaction control is tracked by `state` instead.
+    session_dirty: bool,
+    /// Last `ReadyForQuery` status, shared with [`SharedPgConnection`] so it
+    /// can be read without taking the async lock.
+    transaction_status: Arc<AtomicU8>,
     config: PgConfig,
     reader: MessageReader,
     writer: MessageWriter,
     read_buf: Vec<u8>,
+    state_listener: Option<StateListener>,
 }
 
 #[derive(Debug, Clone)]
@@ -318,7 +349,24 @@ impl std::fmt::Debug for PgAsyncConnection {
 
 impl PgAsyncConnection {
     /// Establish a new async connection to the PostgreSQL server.
-    pub async fn connect(_cx: &Cx, config: PgConfig) -> Outcome<Self, Error> {
+    pub async fn connect(cx: &Cx, config: PgConfig) -> Outcome<Self, Error> {
+        Self::connect_with_state_listener(cx, config, None).await
+    }
+
+    /// Establish a new connection, reporting every state transition to `listener`.
+    ///
+    /// The listener sees the whole handshake, starting with
+    /// [`ConnectionState::Connecting`], so pools and health checks can track a
+    /// connection from the moment it exists.
+    pub async fn connect_with_state_listener(
+        cx: &Cx,
+        config: PgConfig,
+        listener: Option<StateListener>,
+    ) -> Outcome<Self, Error> {
+        let setup_sql = match session_setup_sql(&config) {
+            Ok(sql) => sql,
+            Err(e) => return Outcome::Err(e),
+        };
         let addr = config.socket_addr();
         let socket_addr: std::net::SocketAddr = match addr.parse() {
             Ok(a) => a,
@@ -331,6 +379,7 @@ impl PgAsyncConnection {
             }
         };
 
+        config.report_phase("Connecting");
         let stream = match TcpStream::connect_timeout(socket_addr, config.connect_timeout).await {
             Ok(s) => s,
             Err(e) => {
@@ -357,14 +406,24 @@ impl PgAsyncConnection {
             parameters: HashMap::new(),
             next_prepared_id: 1,
             prepared: HashMap::new(),
+            statement_cache: StatementCache::new(config.statement_cache_size),
+            session_dirty: false,
+            transaction_status: Arc::new(AtomicU8::new(encode_transaction_status(
+                TransactionStatus::Idle,
+            ))),
             config,
             reader: MessageReader::new(),
             writer: MessageWriter::new(),
             read_buf: vec![0u8; 8192],
+            state_listener: listener,
         };
+        if let Some(listener) = &conn.state_listener {
+            listener(ConnectionState::Connecting);
+        }
 
         // SSL negotiation (feature-gated TLS)
         if conn.config.ssl_mode.should_try_ssl() {
+            conn.config.report_phase("Negotiating TLS");
             #[cfg(feature = "tls")]
             match conn.negotiate_ssl().await {
                 Outcome::Ok(()) => {}
@@ -385,7 +444,8 @@ impl PgAsyncConnection {
         if let Outcome::Err(e) = conn.send_startup().await {
             return Outcome::Err(e);
         }
-        conn.state = ConnectionState::Authenticating;
+        conn.set_state(ConnectionState::Authenticating);
+        conn.config.report_phase("Authenticating");
 
         match conn.handle_auth().await {
             Outcome::Ok(()) => {}
@@ -394,22 +454,39 @@ impl PgAsyncConnection {
             Outcome::Panicked(p) => return Outcome::Panicked(p),
         }
 
+        conn.config.report_phase("Starting session");
         match conn.read_startup_messages().await {
-            Outcome::Ok(()) => Outcome::Ok(conn),
-            Outcome::Err(e) => Outcome::Err(e),
-            Outcome::Cancelled(r) => Outcome::Cancelled(r),
-            Outcome::Panicked(p) => Outcome::Panicked(p),
+            Outcome::Ok(()) => {}
+            Outcome::Err(e) => return Outcome::Err(e),
+            Outcome::Cancelled(r) => return Outcome::Cancelled(r),
+            Outcome::Panicked(p) => return Outcome::Panicked(p),
+        }
+
+        // Session setup (SET ROLE / SET search_path)
+        for sql in &setup_sql {
+            match conn.execute_async(cx, sql, &[]).await {
+                Outcome::Ok(_) => {}
+                Outcome::Err(e) => return Outcome::Err(e),
+                Outcome::Cancelled(r) => return Outcome::Cancelled(r),
+                Outcome::Panicked(p) => return Outcome::Panicked(p),
+            }
         }
+        conn.session_dirty = false;
+
+        Outcome::Ok(conn)
     }
 
     /// Run a parameterized query and return all rows.
+    ///
+    /// Repeated SQL reuses a cached server-side prepared statement; see
+    /// [`PgConfig::statement_cache_size`].
     pub async fn query_async(
         &mut self,
         cx: &Cx,
         sql: &str,
         params: &[Value],
     ) -> Outcome<Vec<Row>, Error> {
-        match self.run_extended(cx, sql, params).await {
+        match self.run_cached(cx, sql, params).await {
             Outcome::Ok(result) => Outcome::Ok(result.rows),
             Outcome::Err(e) => Outcome::Err(e),
             Outcome::Cancelled(r) => Outcome::Cancelled(r),
@@ -418,16 +495,32 @@ impl PgAsyncConnection {
     }
 
     /// Execute a statement and return rows affected.
+    ///
+    /// Repeated SQL reuses a cached server-side prepared statement; see
+    /// [`PgConfig::statement_cache_size`].
     pub async fn execute_async(
         &mut self,
         cx: &Cx,
         sql: &str,
         params: &[Value],
     ) -> Outcome<u64, Error> {
-        match self.run_extended(cx, sql, params).await {
-            Outcome::Ok(result) => {
-                Outcome::Ok(parse_rows_affected(result.command_tag.as_deref()).unwrap_or(0))
-            }
+        self.execute_tagged_async(cx, sql, params)
+            .await
+            .map(|tag| tag.rows)
+    }
+
+    /// Execute a statement and return its parsed command tag.
+    ///
+    /// Unlike [`execute_async`](Self::execute_async) this tells an UPDATE that
+    /// matched nothing (`UPDATE 0`) apart from a statement that is not DML at all.
+    pub async fn execute_tagged_async(
+        &mut self,
+        cx: &Cx,
+        sql: &str,
+        params: &[Value],
+    ) -> Outcome<CommandTag, Error> {
+        match self.run_cached(cx, sql, params).await {
+            Outcome::Ok(result) => Outcome::Ok(result.command_tag()),
             Outcome::Err(e) => Outcome::Err(e),
             Outcome::Cancelled(r) => Outcome::Cancelled(r),
             Outcome::Panicked(p) => Outcome::Panicked(p),
@@ -478,6 +571,68 @@ impl PgAsyncConnection {
         self.execute_async(cx, "SELECT 1", &[]).await.map(|_| ())
     }
 
+    /// Return the connection to the state it had right after connecting.
+    ///
+    /// Rolls back any open transaction, then runs `DEALLOCATE ALL`,
+    /// `DISCARD TEMP` and `RESET ALL`, and finally re-applies the configured
+    /// role and search path. Prepared statement handles obtained earlier are
+    /// invalid afterwards. Pools call this before handing out a recycled
+    /// connection.
+    ///
+    /// Does nothing, and so keeps the statement cache, when the connection
+    /// is idle and has only run plannable statements (`SELECT`, `INSERT`,
+    /// `UPDATE`, `DELETE`, `WITH`, `VALUES`) and transaction control since it
+    /// was opened or last reset.
+    ///
+    /// Fails without sending anything when the connection is in
+    /// [`ConnectionState::Error`], e.g. after a query timed out mid-response.
+    pub async fn reset(&mut self, cx: &Cx) -> Outcome<(), Error> {
+        if matches!(self.state, ConnectionState::Error) {
+            return Outcome::Err(unusable_connection_error());
+        }
+        if !self.session_dirty
+            && matches!(
+                self.state,
+                ConnectionState::Ready(TransactionStatusState::Idle)
+            )
+        {
+            return Outcome::Ok(());
+        }
+        let mut statements = Vec::new();
+        if matches!(
+            self.state,
+            ConnectionState::Ready(
+                TransactionStatusState::InTransaction | TransactionStatusState::InFailed
+            )
+        ) {
+            statements.push("ROLLBACK".to_string());
+        }
+        statements.extend(
+            ["DEALLOCATE ALL", "DISCARD TEMP", "RESET ALL"]
+                .into_iter()
+                .map(str::to_string),
+        );
+        match session_setup_sql(&self.config) {
+            Ok(setup) => statements.extend(setup),
+            Err(e) => return Outcome::Err(e),
+        }
+
+        for sql in &statements {
+            match self.execute_async(cx, sql, &[]).await {
+                Outcome::Ok(_) => {}
+                Outcome::Err(e) => return Outcome::Err(e),
+                Outcome::Cancelled(r) => return Outcome::Cancelled(r),
+                Outcome::Panicked(p) => return Outcome::Panicked(p),
+            }
+            if sql == "DEALLOCATE ALL" {
+                self.prepared.clear();
+                self.statement_cache.clear();
+            }
+        }
+        self.session_dirty = false;
+        Outcome::Ok(())
+    }
+
     /// Close the connection.
     pub async fn close_async(&mut self, cx: &Cx) -> Outcome<(), Error> {
         // Best-effort terminate. If this fails, the drop will close the socket.
@@ -485,14 +640,91 @@ impl PgAsyncConnection {
         // Note: server-side prepared statements are released when the connection terminates;
         // explicit Close/DEALLOCATE is not required for correctness here.
         let _ = self.send_message(cx, &FrontendMessage::Terminate).await;
-        self.state = ConnectionState::Closed;
+        self.set_state(ConnectionState::Closed);
         Outcome::Ok(())
     }
 
     // ==================== Prepared statements ====================
 
+    /// Check that `sql` parses and type-checks without executing it.
+    ///
+    /// Sends only `Parse` (into the unnamed statement), `Close` and `Sync`, so
+    /// nothing runs and no transaction is opened. Returns the server's error
+    /// for syntax errors, unknown tables/columns and similar problems; the
+    /// connection stays usable either way.
+    ///
+    /// `sql` may be a script: comments are ignored and each `;`-separated
+    /// statement is checked on its own, stopping at the first error. Later
+    /// statements are checked against the current schema, so one that uses
+    /// a table created earlier in the same script is reported as an error.
+    pub async fn validate_sql(&mut self, cx: &Cx, sql: &str) -> Outcome<(), Error> {
+        for statement in split_statements(sql, sqlmodel_core::Dialect::Postgres) {
+            match self.validate_statement(cx, &statement).await {
+                Outcome::Ok(()) => {}
+                other => return other,
+            }
+        }
+        Outcome::Ok(())
+    }
+
+    /// [`validate_sql`](Self::validate_sql) for a single statement.
+    async fn validate_statement(&mut self, cx: &Cx, sql: &str) -> Outcome<(), Error> {
+        for msg in [
+            FrontendMessage::Parse {
+                name: String::new(),
+                query: sql.to_string(),
+                param_types: Vec::new(),
+            },
+            FrontendMessage::Close {
+                kind: DescribeKind::Statement,
+                name: String::new(),
+            },
+            FrontendMessage::Sync,
+        ] {
+            if let Outcome::Err(e) = self.send_message(cx, &msg).await {
+                return Outcome::Err(e);
+            }
+        }
+
+        let mut error = None;
+        loop {
+            let msg = match self.receive_message(cx).await {
+                Outcome::Ok(m) => m,
+                Outcome::Err(e) => return Outcome::Err(e),
+                Outcome::Cancelled(r) => return Outcome::Cancelled(r),
+                Outcome::Panicked(p) => return Outcome::Panicked(p),
+            };
+
+            match msg {
+                BackendMessage::ParseComplete
+                | BackendMessage::CloseComplete
+                | BackendMessage::NoticeResponse(_) => {}
+                BackendMessage::ErrorResponse(e) => {
+                    // The server skips to Sync after an error; keep reading
+                    // so the connection ends up ready for the next command.
+                    error = Some(error_from_fields(&e));
+                }
+                BackendMessage::ReadyForQuery(status) => {
+                    self.set_state(ConnectionState::Ready(TransactionStatusState::from(status)));
+                    break;
+                }
+                other => {
+                    return Outcome::Err(protocol_error(format!(
+                        "Unexpected message during validation: {other:?}"
+                    )));
+                }
+            }
+        }
+
+        match error {
+            Some(e) => Outcome::Err(e),
+            None => Outcome::Ok(()),
+        }
+    }
+
     /// Prepare a server-side statement and return a reusable handle.
     pub async fn prepare_async(&mut self, cx: &Cx, sql: &str) -> Outcome<PreparedStatement, Error> {
+        self.session_dirty = true;
         let stmt_id = self.next_prepared_id;
         self.next_prepared_id = self.next_prepared_id.saturating_add(1);
         let stmt_name = format!("sqlmodel_stmt_{stmt_id}");
@@ -554,12 +786,19 @@ impl PgAsyncConnection {
                     columns = Some(desc.iter().map(|f| f.name.clone()).collect());
                 }
                 BackendMessage::ReadyForQuery(status) => {
-                    self.state = ConnectionState::Ready(TransactionStatusState::from(status));
+                    self.set_state(ConnectionState::Ready(TransactionStatusState::from(status)));
                     break;
                 }
                 BackendMessage::ErrorResponse(e) => {
-                    self.state = ConnectionState::Error;
-                    return Outcome::Err(error_from_fields(&e));
+                    // The server skips to Sync after an error; drain so the
+                    // connection stays usable for the next command.
+                    let err = error_from_fields(&e);
+                    return match self.drain_until_ready(cx).await {
+                        Outcome::Ok(()) => Outcome::Err(err),
+                        Outcome::Err(e) => Outcome::Err(e),
+                        Outcome::Cancelled(r) => Outcome::Cancelled(r),
+                        Outcome::Panicked(p) => Outcome::Panicked(p),
+                    };
                 }
                 BackendMessage::NoticeResponse(_notice) => {}
                 other => {
@@ -657,15 +896,295 @@ impl PgAsyncConnection {
         }
 
         match self.run_prepared(cx, &meta, params).await {
-            Outcome::Ok(result) => {
-                Outcome::Ok(parse_rows_affected(result.command_tag.as_deref()).unwrap_or(0))
-            }
+            Outcome::Ok(result) => Outcome::Ok(result.command_tag().rows),
             Outcome::Err(e) => Outcome::Err(e),
             Outcome::Cancelled(r) => Outcome::Cancelled(r),
             Outcome::Panicked(p) => Outcome::Panicked(p),
         }
     }
 
+    /// Run a `COPY ... TO STDOUT (FORMAT binary)` statement and stream its rows.
+    ///
+    /// The binary COPY format carries no type information, so `columns` lists
+    /// the `(name, type_oid)` of each exported column in COPY order; values are
+    /// decoded with the same binary decoders used for query results.
+    ///
+    /// The returned stream borrows the connection and must be drained (until
+    /// `next_row` yields `None`) before the connection is used again.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let mut rows = conn
+    ///     .copy_out(&cx, "COPY heroes (id, name) TO STDOUT (FORMAT binary)",
+    ///         &[("id", oid::INT8), ("name", oid::TEXT)])
+    ///     .await?;
+    /// while let Some(row) = rows.next_row(&cx).await? {
+    ///     // ...
+    /// }
+    /// ```
+    pub async fn copy_out(
+        &mut self,
+        cx: &Cx,
+        sql: &str,
+        columns: &[(&str, u32)],
+    ) -> Outcome<CopyOutStream<'_>, Error> {
+        if let Outcome::Err(e) = self
+            .send_message(cx, &FrontendMessage::Query(sql.to_string()))
+            .await
+        {
+            return Outcome::Err(e);
+        }
+
+        loop {
+            let msg = match self.receive_message(cx).await {
+                Outcome::Ok(m) => m,
+                Outcome::Err(e) => return Outcome::Err(e),
+                Outcome::Cancelled(r) => return Outcome::Cancelled(r),
+                Outcome::Panicked(p) => return Outcome::Panicked(p),
+            };
+
+            match msg {
+                BackendMessage::CopyOutResponse { format: 1, .. } => {
+                    return Outcome::Ok(CopyOutStream {
+                        conn: self,
+                        decoder: BinaryCopyDecoder::new(columns),
+                        done: false,
+                    });
+                }
+                BackendMessage::CopyOutResponse { .. } => {
+                    self.set_state(ConnectionState::Error);
+                    return Outcome::Err(protocol_error(
+                        "copy_out requires COPY ... TO STDOUT (FORMAT binary)",
+                    ));
+                }
+                BackendMessage::ErrorResponse(e) => {
+                    let err = error_from_fields(&e);
+                    return match self.drain_until_ready(cx).await {
+                        Outcome::Ok(()) => Outcome::Err(err),
+                        Outcome::Err(e) => Outcome::Err(e),
+                        Outcome::Cancelled(r) => Outcome::Cancelled(r),
+                        Outcome::Panicked(p) => Outcome::Panicked(p),
+                    };
+                }
+                BackendMessage::ReadyForQuery(status) => {
+                    self.set_state(ConnectionState::Ready(TransactionStatusState::from(status)));
+                    return Outcome::Err(protocol_error(
+                        "statement did not start a COPY TO STDOUT",
+                    ));
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Copy every row of `table` from `from` into the same table on this
+    /// connection, returning the number of rows transferred.
+    ///
+    /// Runs `COPY ... TO STDOUT` on the source and `COPY ... FROM STDIN` here,
+    /// both in binary format, forwarding `CopyData` frames as they arrive so
+    /// the table is never held in memory. Both statements name the
+    /// destination's insertable columns explicitly, so the tables may declare
+    /// them in a different order; the column types must match.
+    ///
+    /// If the source fails mid-stream the destination COPY is aborted with
+    /// `CopyFail`, so no partial data is committed.
+    pub async fn copy_table(
+        &mut self,
+        cx: &Cx,
+        from: &mut PgAsyncConnection,
+        table: &str,
+    ) -> Outcome<u64, Error> {
+        // `regclass::text` quotes and qualifies the name as needed, so it is
+        // safe to splice into the COPY statements.
+        let rows = match self
+            .query_async(
+                cx,
+                "SELECT a.attname::text, a.attrelid::regclass::text FROM pg_attribute a \
+                 WHERE a.attrelid = $1::regclass AND a.attnum > 0 \
+                 AND NOT a.attisdropped AND a.attgenerated = '' ORDER BY a.attnum",
+                &[Value::Text(table.to_string())],
+            )
+            .await
+        {
+            Outcome::Ok(rows) => rows,
+            Outcome::Err(e) => return Outcome::Err(e),
+            Outcome::Cancelled(r) => return Outcome::Cancelled(r),
+            Outcome::Panicked(p) => return Outcome::Panicked(p),
+        };
+        let Some(relation) = rows
+            .first()
+            .and_then(|row| row.get(1))
+            .and_then(Value::as_str)
+            .map(str::to_string)
+        else {
+            return Outcome::Err(query_error_msg(
+                format!("table {table} has no columns to copy"),
+                QueryErrorKind::Database,
+            ));
+        };
+        let columns = rows
+            .iter()
+            .filter_map(|row| row.get(0).and_then(Value::as_str))
+            .map(quote_ident)
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        let copy_in = format!("COPY {relation} ({columns}) FROM STDIN (FORMAT binary)");
+        if let Outcome::Err(e) = self
+            .send_message(cx, &FrontendMessage::Query(copy_in))
+            .await
+        {
+            return Outcome::Err(e);
+        }
+        loop {
+            match self.receive_message(cx).await {
+                Outcome::Ok(BackendMessage::CopyInResponse { .. }) => break,
+                Outcome::Ok(BackendMessage::ErrorResponse(e)) => {
+                    let err = error_from_fields(&e);
+                    return match self.drain_until_ready(cx).await {
+                        Outcome::Ok(()) => Outcome::Err(err),
+                        Outcome::Err(e) => Outcome::Err(e),
+                        Outcome::Cancelled(r) => Outcome::Cancelled(r),
+                        Outcome::Panicked(p) => Outcome::Panicked(p),
+                    };
+                }
+                Outcome::Ok(BackendMessage::ReadyForQuery(status)) => {
+                    self.set_state(ConnectionState::Ready(TransactionStatusState::from(status)));
+                    return Outcome::Err(protocol_error(
+                        "statement did not start a COPY FROM STDIN",
+                    ));
+                }
+                Outcome::Ok(_) => {}
+                Outcome::Err(e) => return Outcome::Err(e),
+                Outcome::Cancelled(r) => return Outcome::Cancelled(r),
+                Outcome::Panicked(p) => return Outcome::Panicked(p),
+            }
+        }
+
+        let copy_out = format!("COPY {relation} ({columns}) TO STDOUT (FORMAT binary)");
+        match from.forward_copy_out(cx, copy_out, self).await {
+            Outcome::Ok(()) => {}
+            Outcome::Err(err) => {
+                // Abort the destination COPY; its error response is expected.
+                if let Outcome::Err(e) = self
+                    .send_message(
+                        cx,
+                        &FrontendMessage::CopyFail(format!("source COPY failed: {err}")),
+                    )
+                    .await
+                {
+                    return Outcome::Err(e);
+                }
+                return match self.drain_until_ready(cx).await {
+                    Outcome::Ok(()) => Outcome::Err(err),
+                    Outcome::Err(e) => Outcome::Err(e),
+                    Outcome::Cancelled(r) => Outcome::Cancelled(r),
+                    Outcome::Panicked(p) => Outcome::Panicked(p),
+                };
+            }
+            Outcome::Cancelled(r) => {
+                // The destination is still in COPY IN mode.
+                self.set_state(ConnectionState::Error);
+                return Outcome::Cancelled(r);
+            }
+            Outcome::Panicked(p) => {
+                self.set_state(ConnectionState::Error);
+                return Outcome::Panicked(p);
+            }
+        }
+
+        if let Outcome::Err(e) = self.send_message(cx, &FrontendMessage::CopyDone).await {
+            return Outcome::Err(e);
+        }
+        let mut transferred = 0;
+        let mut error = None;
+        loop {
+            match self.receive_message(cx).await {
+                Outcome::Ok(BackendMessage::CommandComplete(tag)) => {
+                    transferred = parse_rows_affected(Some(&tag)).unwrap_or(0);
+                }
+                Outcome::Ok(BackendMessage::ErrorResponse(e)) => {
+                    error = Some(error_from_fields(&e));
+                }
+                Outcome::Ok(BackendMessage::ReadyForQuery(status)) => {
+                    self.set_state(ConnectionState::Ready(TransactionStatusState::from(status)));
+                    break;
+                }
+                Outcome::Ok(_) => {}
+                Outcome::Err(e) => return Outcome::Err(e),
+                Outcome::Cancelled(r) => return Outcome::Cancelled(r),
+                Outcome::Panicked(p) => return Outcome::Panicked(p),
+            }
+        }
+        match error {
+            Some(err) => Outcome::Err(err),
+            None => Outcome::Ok(transferred),
+        }
+    }
+
+    /// Run `sql` (a binary `COPY ... TO STDOUT`) and forward its `CopyData`
+    /// frames to `to`, which must already be in COPY IN mode.
+    async fn forward_copy_out(
+        &mut self,
+        cx: &Cx,
+        sql: String,
+        to: &mut PgAsyncConnection,
+    ) -> Outcome<(), Error> {
+        if let Outcome::Err(e) = self.send_message(cx, &FrontendMessage::Query(sql)).await {
+            return Outcome::Err(e);
+        }
+
+        let mut error = None;
+        loop {
+            let msg = match self.receive_message(cx).await {
+                Outcome::Ok(m) => m,
+                Outcome::Err(e) => return Outcome::Err(e),
+                Outcome::Cancelled(r) => return Outcome::Cancelled(r),
+                Outcome::Panicked(p) => return Outcome::Panicked(p),
+            };
+
+            match msg {
+                BackendMessage::CopyOutResponse { format, .. } if format != 1 => {
+                    self.set_state(ConnectionState::Error);
+                    return Outcome::Err(protocol_error("source COPY is not in binary format"));
+                }
+                BackendMessage::CopyData(data) if error.is_none() => {
+                    if let Outcome::Err(e) =
+                        to.send_message(cx, &FrontendMessage::CopyData(data)).await
+                    {
+                        error = Some(e);
+                    }
+                }
+                BackendMessage::ErrorResponse(e) => error = Some(error_from_fields(&e)),
+                BackendMessage::ReadyForQuery(status) => {
+                    self.set_state(ConnectionState::Ready(TransactionStatusState::from(status)));
+                    return match error {
+                        Some(err) => Outcome::Err(err),
+                        None => Outcome::Ok(()),
+                    };
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Discard messages until `ReadyForQuery`, updating the connection state.
+    async fn drain_until_ready(&mut self, cx: &Cx) -> Outcome<(), Error> {
+        loop {
+            match self.receive_message(cx).await {
+                Outcome::Ok(BackendMessage::ReadyForQuery(status)) => {
+                    self.set_state(ConnectionState::Ready(TransactionStatusState::from(status)));
+                    return Outcome::Ok(());
+                }
+                Outcome::Ok(_) => {}
+                Outcome::Err(e) => return Outcome::Err(e),
+                Outcome::Cancelled(r) => return Outcome::Cancelled(r),
+                Outcome::Panicked(p) => return Outcome::Panicked(p),
+            }
+        }
+    }
+
     // ==================== Protocol: extended query ====================
 
     async fn read_extended_result(&mut self, cx: &Cx) -> Outcome<PgQueryResult, Error> {
@@ -697,49 +1216,29 @@ impl PgAsyncConnection {
                     field_descs = Some(desc);
                 }
                 BackendMessage::DataRow(raw_values) => {
-                    let Some(ref desc) = field_descs else {
-                        return Outcome::Err(protocol_error(
-                            "DataRow received before RowDescription",
-                        ));
-                    };
-                    let Some(ref cols) = columns else {
-                        return Outcome::Err(protocol_error("Row column metadata missing"));
-                    };
-                    if raw_values.len() != desc.len() {
-                        return Outcome::Err(protocol_error("DataRow field count mismatch"));
-                    }
-
-                    let mut values = Vec::with_capacity(raw_values.len());
-                    for (i, raw) in raw_values.into_iter().enumerate() {
-                        match raw {
-                            None => values.push(Value::Null),
-                            Some(bytes) => {
-                                let field = &desc[i];
-                                let format = Format::from_code(field.format);
-                                let decoded = match decode_value(
-                                    field.type_oid,
-                                    Some(bytes.as_slice()),
-                                    format,
-                                ) {
-                                    Ok(v) => v,
-                                    Err(e) => return Outcome::Err(e),
-                                };
-                                values.push(decoded);
-                            }
-                        }
+                    match decode_data_row(field_descs.as_deref(), columns.as_ref(), raw_values) {
+                        Ok(Some(row)) => rows.push(row),
+                        Ok(None) => {}
+                        Err(e) => return Outcome::Err(e),
                     }
-                    rows.push(Row::with_columns(Arc::clone(cols), values));
                 }
                 BackendMessage::CommandComplete(tag) => {
                     command_tag = Some(tag);
                 }
                 BackendMessage::ReadyForQuery(status) => {
-                    self.state = ConnectionState::Ready(TransactionStatusState::from(status));
+                    self.set_state(ConnectionState::Ready(TransactionStatusState::from(status)));
                     break;
                 }
                 BackendMessage::ErrorResponse(e) => {
-                    self.state = ConnectionState::Error;
-                    return Outcome::Err(error_from_fields(&e));
+                    // The server skips to Sync after an error; drain so the
+                    // connection stays usable for the next command.
+                    let err = error_from_fields(&e);
+                    return match self.drain_until_ready(cx).await {
+                        Outcome::Ok(()) => Outcome::Err(err),
+                        Outcome::Err(e) => Outcome::Err(e),
+                        Outcome::Cancelled(r) => Outcome::Cancelled(r),
+                        Outcome::Panicked(p) => Outcome::Panicked(p),
+                    };
                 }
                 BackendMessage::NoticeResponse(_notice) => {}
                 _ => {}
@@ -755,92 +1254,157 @@ impl PgAsyncConnection {
         sql: &str,
         params: &[Value],
     ) -> Outcome<PgQueryResult, Error> {
-        // Encode parameters
-        let mut param_types = Vec::with_capacity(params.len());
-        let mut param_values = Vec::with_capacity(params.len());
+        let encoded = match encode_params(params, self.config.preferred_format) {
+            Ok(encoded) => encoded,
+            Err(e) => return Outcome::Err(e),
+        };
+        // Parse + bind unnamed statement/portal
+        self.parse_and_execute(cx, "", sql, encoded).await
+    }
 
-        for v in params {
-            if matches!(v, Value::Null) {
-                param_types.push(0);
-                param_values.push(None);
-                continue;
-            }
-            match encode_value(v, Format::Text) {
-                Ok((bytes, oid)) => {
-                    param_types.push(oid);
-                    param_values.push(Some(bytes));
+    /// Run `sql` through the statement cache, preparing it on first use.
+    ///
+    /// The statement is parsed under a server-side name in the same round
+    /// trip as its first execution, so a cache miss costs nothing extra.
+    /// Later calls with the same SQL skip `Parse` and bind straight to the
+    /// cached statement, unless the parameter types changed, in which case
+    /// the call falls back to an unnamed statement.
+    ///
+    /// A cached statement the server rejects as stale (SQLSTATE `0A000`, e.g.
+    /// "cached plan must not change result type" after an `ALTER TABLE`, or
+    /// `26000` for a missing statement) is evicted, closed, and the call is
+    /// retried once as an unnamed statement. A statement whose first
+    /// execution fails is closed rather than cached.
+    async fn run_cached(
+        &mut self,
+        cx: &Cx,
+        sql: &str,
+        params: &[Value],
+    ) -> Outcome<PgQueryResult, Error> {
+        if changes_session_state(sql) {
+            self.session_dirty = true;
+        }
+        if !self.statement_cache.should_cache(sql) {
+            return self.run_extended(cx, sql, params).await;
+        }
+        let encoded = match encode_params(params, self.config.preferred_format) {
+            Ok(encoded) => encoded,
+            Err(e) => return Outcome::Err(e),
+        };
+
+        let hit = self
+            .statement_cache
+            .get(sql)
+            .map(|cached| (cached.accepts(&encoded.types), cached.name.clone()));
+        match hit {
+            Some((true, name)) => {
+                let result = self
+                    .bind_and_execute(cx, &name, encoded.formats, encoded.values)
+                    .await;
+                if !matches!(&result, Outcome::Err(e) if is_stale_cached_statement(e)) {
+                    return result;
                 }
-                Err(e) => return Outcome::Err(e),
+                self.statement_cache.remove(sql);
+                match self.close_statement(cx, &name).await {
+                    Outcome::Ok(()) => {}
+                    Outcome::Err(e) => return Outcome::Err(e),
+                    Outcome::Cancelled(r) => return Outcome::Cancelled(r),
+                    Outcome::Panicked(p) => return Outcome::Panicked(p),
+                }
+                // Inside a failed transaction the retry could only fail too.
+                if matches!(
+                    self.state,
+                    ConnectionState::Ready(TransactionStatusState::InFailed)
+                ) {
+                    return result;
+                }
+                return self.run_extended(cx, sql, params).await;
             }
+            Some((false, _)) => return self.parse_and_execute(cx, "", sql, encoded).await,
+            None => {}
         }
 
-        // Parse + bind unnamed statement/portal
-        if let Outcome::Err(e) = self
-            .send_message(
-                cx,
-                &FrontendMessage::Parse {
-                    name: String::new(),
-                    query: sql.to_string(),
-                    param_types,
-                },
-            )
-            .await
-        {
-            return Outcome::Err(e);
+        if let Some(evicted) = self.statement_cache.evict_if_full() {
+            let deallocate = format!("DEALLOCATE {}", evicted.name);
+            match self.run_extended(cx, &deallocate, &[]).await {
+                Outcome::Ok(_) => {}
+                Outcome::Err(e) => return Outcome::Err(e),
+                Outcome::Cancelled(r) => return Outcome::Cancelled(r),
+                Outcome::Panicked(p) => return Outcome::Panicked(p),
+            }
         }
 
-        let param_formats = if params.is_empty() {
-            Vec::new()
-        } else {
-            vec![Format::Text.code()]
-        };
-        if let Outcome::Err(e) = self
-            .send_message(
-                cx,
-                &FrontendMessage::Bind {
-                    portal: String::new(),
-                    statement: String::new(),
-                    param_formats,
-                    params: param_values,
-                    // Default result formats (text) when empty.
-                    result_formats: Vec::new(),
-                },
-            )
-            .await
-        {
-            return Outcome::Err(e);
+        let name = format!("sqlmodel_cached_{}", self.next_prepared_id);
+        self.next_prepared_id = self.next_prepared_id.saturating_add(1);
+        let param_types = encoded.types.clone();
+        let result = self.parse_and_execute(cx, &name, sql, encoded).await;
+        match &result {
+            Outcome::Ok(_) => {
+                self.statement_cache.insert(
+                    sql,
+                    CachedStatement {
+                        name,
+                        param_type_oids: param_types,
+                    },
+                );
+            }
+            Outcome::Err(Error::Query(_)) => {
+                // `Parse` may have succeeded before execution failed; don't
+                // leave the uncached statement behind on the server.
+                match self.close_statement(cx, &name).await {
+                    Outcome::Ok(()) => {}
+                    Outcome::Err(e) => return Outcome::Err(e),
+                    Outcome::Cancelled(r) => return Outcome::Cancelled(r),
+                    Outcome::Panicked(p) => return Outcome::Panicked(p),
+                }
+            }
+            _ => {}
         }
+        result
+    }
 
-        if let Outcome::Err(e) = self
-            .send_message(
-                cx,
-                &FrontendMessage::Describe {
-                    kind: DescribeKind::Portal,
-                    name: String::new(),
-                },
-            )
-            .await
-        {
-            return Outcome::Err(e);
+    /// Release server-side statement `name` with a protocol `Close`.
+    ///
+    /// Unlike `DEALLOCATE`, this is accepted inside a failed transaction, and
+    /// closing a statement that does not exist is not an error.
+    async fn close_statement(&mut self, cx: &Cx, name: &str) -> Outcome<(), Error> {
+        for msg in [
+            FrontendMessage::Close {
+                kind: DescribeKind::Statement,
+                name: name.to_string(),
+            },
+            FrontendMessage::Sync,
+        ] {
+            if let Outcome::Err(e) = self.send_message(cx, &msg).await {
+                return Outcome::Err(e);
+            }
         }
+        self.drain_until_ready(cx).await
+    }
 
+    /// Parse `sql` as statement `name` (empty for unnamed), then bind and run it.
+    async fn parse_and_execute(
+        &mut self,
+        cx: &Cx,
+        name: &str,
+        sql: &str,
+        params: EncodedParams,
+    ) -> Outcome<PgQueryResult, Error> {
         if let Outcome::Err(e) = self
             .send_message(
                 cx,
-                &FrontendMessage::Execute {
-                    portal: String::new(),
-                    max_rows: 0,
+                &FrontendMessage::Parse {
+                    name: name.to_string(),
+                    query: sql.to_string(),
+                    param_types: params.types,
                 },
             )
             .await
         {
             return Outcome::Err(e);
         }
-
-        if let Outcome::Err(e) = self.send_message(cx, &FrontendMessage::Sync).await {
-            return Outcome::Err(e);
-        }
-        self.read_extended_result(cx).await
+        self.bind_and_execute(cx, name, params.formats, params.values)
+            .await
     }
 
     async fn run_prepared(
@@ -849,14 +1413,17 @@ impl PgAsyncConnection {
         meta: &PgPreparedMeta,
         params: &[Value],
     ) -> Outcome<PgQueryResult, Error> {
+        let mut param_formats = Vec::with_capacity(params.len());
         let mut param_values = Vec::with_capacity(params.len());
 
         for (i, v) in params.iter().enumerate() {
-            if matches!(v, Value::Null) {
+            let format = self.config.preferred_format.for_value(v);
+            param_formats.push(format);
+            if v.is_null() {
                 param_values.push(None);
                 continue;
             }
-            match encode_value(v, Format::Text) {
+            match encode_value(v, format) {
                 Ok((bytes, oid)) => {
                     let expected = meta.param_type_oids.get(i).copied().unwrap_or(0);
                     if expected != 0 && expected != oid {
@@ -876,20 +1443,33 @@ impl PgAsyncConnection {
             }
         }
 
-        let param_formats = if params.is_empty() {
-            Vec::new()
-        } else {
-            vec![Format::Text.code()]
-        };
+        self.bind_and_execute(
+            cx,
+            &meta.name,
+            bind_format_codes(&param_formats),
+            param_values,
+        )
+        .await
+    }
 
+    /// Bind `param_values` to an already parsed statement, execute the
+    /// unnamed portal and collect the result.
+    async fn bind_and_execute(
+        &mut self,
+        cx: &Cx,
+        statement: &str,
+        param_formats: Vec<i16>,
+        param_values: Vec<Option<Vec<u8>>>,
+    ) -> Outcome<PgQueryResult, Error> {
         if let Outcome::Err(e) = self
             .send_message(
                 cx,
                 &FrontendMessage::Bind {
                     portal: String::new(),
-                    statement: meta.name.clone(),
+                    statement: statement.to_string(),
                     param_formats,
                     params: param_values,
+                    // Default result formats (text) when empty.
                     result_formats: Vec::new(),
                 },
             )
@@ -1084,7 +1664,7 @@ impl PgAsyncConnection {
                     }
                 }
                 BackendMessage::ErrorResponse(e) => {
-                    self.state = ConnectionState::Error;
+                    self.set_state(ConnectionState::Error);
                     return Outcome::Err(error_from_fields(&e));
                 }
                 other => {
@@ -1129,7 +1709,7 @@ impl PgAsyncConnection {
         let server_first_data = match msg {
             BackendMessage::AuthenticationSASLContinue(data) => data,
             BackendMessage::ErrorResponse(e) => {
-                self.state = ConnectionState::Error;
+                self.set_state(ConnectionState::Error);
                 return Outcome::Err(error_from_fields(&e));
             }
             other => {
@@ -1161,7 +1741,7 @@ impl PgAsyncConnection {
         let server_final_data = match msg {
             BackendMessage::AuthenticationSASLFinal(data) => data,
             BackendMessage::ErrorResponse(e) => {
-                self.state = ConnectionState::Error;
+                self.set_state(ConnectionState::Error);
                 return Outcome::Err(error_from_fields(&e));
             }
             other => {
@@ -1185,7 +1765,7 @@ impl PgAsyncConnection {
         match msg {
             BackendMessage::AuthenticationOk => Outcome::Ok(()),
             BackendMessage::ErrorResponse(e) => {
-                self.state = ConnectionState::Error;
+                self.set_state(ConnectionState::Error);
                 Outcome::Err(error_from_fields(&e))
             }
             other => Outcome::Err(protocol_error(format!(
@@ -1211,15 +1791,14 @@ impl PgAsyncConnection {
                     self.process_id = process_id;
                     self.secret_key = secret_key;
                 }
-                BackendMessage::ParameterStatus { name, value } => {
-                    self.parameters.insert(name, value);
-                }
+                // Recorded by `receive_message_no_cx`.
+                BackendMessage::ParameterStatus { .. } => {}
                 BackendMessage::ReadyForQuery(status) => {
-                    self.state = ConnectionState::Ready(TransactionStatusState::from(status));
+                    self.set_state(ConnectionState::Ready(TransactionStatusState::from(status)));
                     return Outcome::Ok(());
                 }
                 BackendMessage::ErrorResponse(e) => {
-                    self.state = ConnectionState::Error;
+                    self.set_state(ConnectionState::Error);
                     return Outcome::Err(error_from_fields(&e));
                 }
                 BackendMessage::NoticeResponse(_notice) => {}
@@ -1235,25 +1814,81 @@ impl PgAsyncConnection {
     // ==================== I/O ====================
 
     async fn send_message(&mut self, cx: &Cx, msg: &FrontendMessage) -> Outcome<(), Error> {
+        if matches!(self.state, ConnectionState::Error) {
+            return Outcome::Err(unusable_connection_error());
+        }
         // If cancelled, propagate early.
         if let Some(reason) = cx.cancel_reason() {
             return Outcome::Cancelled(reason);
         }
-        self.send_message_no_cx(msg).await
+        let Some(remaining) = budget_remaining(cx) else {
+            return self.send_message_no_cx(msg).await;
+        };
+        if remaining.is_zero() {
+            return self.abort_for_budget().await;
+        }
+        match timeout(wall_now(), remaining, self.send_message_no_cx(msg)).await {
+            Ok(outcome) => outcome,
+            Err(_elapsed) => self.abort_for_budget().await,
+        }
     }
 
     async fn receive_message(&mut self, cx: &Cx) -> Outcome<BackendMessage, Error> {
         if let Some(reason) = cx.cancel_reason() {
             return Outcome::Cancelled(reason);
         }
-        self.receive_message_no_cx().await
+        let Some(remaining) = budget_remaining(cx) else {
+            return self.receive_message_no_cx().await;
+        };
+        if remaining.is_zero() {
+            return self.abort_for_budget().await;
+        }
+        match timeout(wall_now(), remaining, self.receive_message_no_cx()).await {
+            Ok(outcome) => outcome,
+            Err(_elapsed) => self.abort_for_budget().await,
+        }
+    }
+
+    /// Give up on the in-flight exchange because the `Cx` deadline passed.
+    ///
+    /// Asks the server to cancel the running query over a side connection,
+    /// then marks this connection unusable: the protocol stream is mid-response
+    /// and cannot be resynchronised without reading past the deadline. Later
+    /// sends fail with a `Disconnected` error and `reset()` fails, so a pool
+    /// discards the connection instead of handing it out again.
+    async fn abort_for_budget<T>(&mut self) -> Outcome<T, Error> {
+        if self.process_id != 0 {
+            // Best effort: the query may already have finished server-side.
+            let _ = self.send_cancel_request().await;
+        }
+        self.set_state(ConnectionState::Error);
+        Outcome::Cancelled(CancelReason::timeout())
+    }
+
+    /// Send a `CancelRequest` for this backend on a fresh TCP connection.
+    async fn send_cancel_request(&self) -> std::io::Result<()> {
+        let addr: std::net::SocketAddr = self
+            .config
+            .socket_addr()
+            .parse()
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidInput, e))?;
+        let mut stream = TcpStream::connect_timeout(addr, self.config.connect_timeout).await?;
+        let mut writer = MessageWriter::new();
+        let data = writer
+            .write(&FrontendMessage::CancelRequest {
+                process_id: self.process_id,
+                secret_key: self.secret_key,
+            })
+            .to_vec();
+        write_all_plain_async(&mut stream, &data).await?;
+        flush_plain_async(&mut stream).await
     }
 
     async fn send_message_no_cx(&mut self, msg: &FrontendMessage) -> Outcome<(), Error> {
         let data = self.writer.write(msg).to_vec();
 
         if let Err(e) = self.stream.write_all(&data).await {
-            self.state = ConnectionState::Error;
+            self.set_state(ConnectionState::Error);
             return Outcome::Err(Error::Connection(ConnectionError {
                 kind: ConnectionErrorKind::Disconnected,
                 message: format!("Failed to write to server: {}", e),
@@ -1262,7 +1897,7 @@ impl PgAsyncConnection {
         }
 
         if let Err(e) = self.stream.flush().await {
-            self.state = ConnectionState::Error;
+            self.set_state(ConnectionState::Error);
             return Outcome::Err(Error::Connection(ConnectionError {
                 kind: ConnectionErrorKind::Disconnected,
                 message: format!("Failed to flush stream: {}", e),
@@ -1273,13 +1908,71 @@ impl PgAsyncConnection {
         Outcome::Ok(())
     }
 
+    /// Get a server parameter value, as last reported by the server.
+    pub fn parameter(&self, name: &str) -> Option<&str> {
+        self.parameters.get(name).map(String::as_str)
+    }
+
+    /// Get all server parameters.
+    pub fn parameters(&self) -> &HashMap<String, String> {
+        &self.parameters
+    }
+
+    /// Transaction state reported by the server's most recent `ReadyForQuery`.
+    pub fn transaction_status(&self) -> TransactionStatus {
+        decode_transaction_status(self.transaction_status.load(Ordering::Acquire))
+    }
+
+    /// Current connection state.
+    pub fn state(&self) -> ConnectionState {
+        self.state
+    }
+
+    /// Install or remove the listener notified on every state transition.
+    pub fn set_state_listener(&mut self, listener: Option<StateListener>) {
+        self.state_listener = listener;
+    }
+
+    fn set_state(&mut self, state: ConnectionState) {
+        if self.state == state {
+            return;
+        }
+        self.state = state;
+        if let Some(listener) = &self.state_listener {
+            listener(state);
+        }
+    }
+
+    fn record_transaction_status(&self, status: TransactionStatusState) {
+        let status = match status {
+            TransactionStatusState::Idle => TransactionStatus::Idle,
+            TransactionStatusState::InTransaction => TransactionStatus::InTransaction,
+            TransactionStatusState::InFailed => TransactionStatus::Failed,
+        };
+        self.transaction_status
+            .store(encode_transaction_status(status), Ordering::Release);
+    }
+
     async fn receive_message_no_cx(&mut self) -> Outcome<BackendMessage, Error> {
         loop {
             match self.reader.next_message() {
-                Ok(Some(msg)) => return Outcome::Ok(msg),
+                Ok(Some(msg)) => {
+                    // The server may report parameter changes at any time
+                    // (e.g. after `SET TimeZone`), not just during startup.
+                    match &msg {
+                        BackendMessage::ReadyForQuery(status) => {
+                            self.record_transaction_status(TransactionStatusState::from(*status));
+                        }
+                        BackendMessage::ParameterStatus { name, value } => {
+                            self.parameters.insert(name.clone(), value.clone());
+                        }
+                        _ => {}
+                    }
+                    return Outcome::Ok(msg);
+                }
                 Ok(None) => {}
                 Err(e) => {
-                    self.state = ConnectionState::Error;
+                    self.set_state(ConnectionState::Error);
                     return Outcome::Err(protocol_error(format!("Protocol error: {}", e)));
                 }
             }
@@ -1287,7 +1980,7 @@ impl PgAsyncConnection {
             let n = match self.stream.read_some(&mut self.read_buf).await {
                 Ok(n) => n,
                 Err(e) => {
-                    self.state = ConnectionState::Error;
+                    self.set_state(ConnectionState::Error);
                     return Outcome::Err(match e.kind() {
                         std::io::ErrorKind::TimedOut | std::io::ErrorKind::WouldBlock => {
                             Error::Timeout
@@ -1302,7 +1995,7 @@ impl PgAsyncConnection {
             };
 
             if n == 0 {
-                self.state = ConnectionState::Disconnected;
+                self.set_state(ConnectionState::Disconnected);
                 return Outcome::Err(Error::Connection(ConnectionError {
                     kind: ConnectionErrorKind::Disconnected,
                     message: "Connection closed by server".to_string(),
@@ -1318,6 +2011,77 @@ impl PgAsyncConnection {
             // next_message() would see an empty buffer and block forever on
             // the socket read.  (See issue #9.)
             self.reader.push(&self.read_buf[..n]);
+            self.config.report_tick();
+        }
+    }
+}
+
+/// Row stream produced by [`PgAsyncConnection::copy_out`].
+///
+/// Reassembles binary COPY tuples across `CopyData` frames and yields them as
+/// rows until the server sends `CopyDone`.
+pub struct CopyOutStream<'conn> {
+    conn: &'conn mut PgAsyncConnection,
+    decoder: BinaryCopyDecoder,
+    done: bool,
+}
+
+impl std::fmt::Debug for CopyOutStream<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CopyOutStream")
+            .field("done", &self.done)
+            .finish_non_exhaustive()
+    }
+}
+
+impl CopyOutStream<'_> {
+    /// Fetch the next row, or `None` once the COPY has completed.
+    pub async fn next_row(&mut self, cx: &Cx) -> Outcome<Option<Row>, Error> {
+        loop {
+            match self.decoder.next_row() {
+                Ok(Some(row)) => return Outcome::Ok(Some(row)),
+                Ok(None) => {}
+                Err(e) => {
+                    self.conn.set_state(ConnectionState::Error);
+                    return Outcome::Err(e);
+                }
+            }
+            if self.done {
+                return Outcome::Ok(None);
+            }
+
+            let msg = match self.conn.receive_message(cx).await {
+                Outcome::Ok(m) => m,
+                Outcome::Err(e) => return Outcome::Err(e),
+                Outcome::Cancelled(r) => return Outcome::Cancelled(r),
+                Outcome::Panicked(p) => return Outcome::Panicked(p),
+            };
+
+            match msg {
+                BackendMessage::CopyData(data) => self.decoder.push(&data),
+                BackendMessage::CopyDone => {
+                    if let Err(e) = self.decoder.finish() {
+                        self.conn.set_state(ConnectionState::Error);
+                        return Outcome::Err(e);
+                    }
+                }
+                BackendMessage::ReadyForQuery(status) => {
+                    self.conn
+                        .set_state(ConnectionState::Ready(TransactionStatusState::from(status)));
+                    self.done = true;
+                }
+                BackendMessage::ErrorResponse(e) => {
+                    self.done = true;
+                    let err = error_from_fields(&e);
+                    return match self.conn.drain_until_ready(cx).await {
+                        Outcome::Ok(()) => Outcome::Err(err),
+                        Outcome::Err(e) => Outcome::Err(e),
+                        Outcome::Cancelled(r) => Outcome::Cancelled(r),
+                        Outcome::Panicked(p) => Outcome::Panicked(p),
+                    };
+                }
+                _ => {}
+            }
         }
     }
 }
@@ -1325,17 +2089,30 @@ impl PgAsyncConnection {
 /// Shared, cloneable PostgreSQL connection with interior mutability.
 pub struct SharedPgConnection {
     inner: Arc<Mutex<PgAsyncConnection>>,
+    transaction_status: Arc<AtomicU8>,
 }
 
 impl SharedPgConnection {
     pub fn new(conn: PgAsyncConnection) -> Self {
         Self {
+            transaction_status: Arc::clone(&conn.transaction_status),
             inner: Arc::new(Mutex::new(conn)),
         }
     }
 
     pub async fn connect(cx: &Cx, config: PgConfig) -> Outcome<Self, Error> {
-        match PgAsyncConnection::connect(cx, config).await {
+        Self::connect_with_state_listener(cx, config, None).await
+    }
+
+    /// Connect, reporting every state transition to `listener`.
+    ///
+    /// See [`PgAsyncConnection::connect_with_state_listener`].
+    pub async fn connect_with_state_listener(
+        cx: &Cx,
+        config: PgConfig,
+        listener: Option<StateListener>,
+    ) -> Outcome<Self, Error> {
+        match PgAsyncConnection::connect_with_state_listener(cx, config, listener).await {
             Outcome::Ok(conn) => Outcome::Ok(Self::new(conn)),
             Outcome::Err(e) => Outcome::Err(e),
             Outcome::Cancelled(r) => Outcome::Cancelled(r),
@@ -1347,27 +2124,65 @@ impl SharedPgConnection {
         &self.inner
     }
 
+    /// Begin a transaction with explicit isolation, `READ ONLY` and
+    /// `DEFERRABLE` settings, e.g. `BEGIN ISOLATION LEVEL SERIALIZABLE READ ONLY DEFERRABLE`.
+    pub async fn begin_with_mode(
+        &self,
+        cx: &Cx,
+        mode: TransactionMode,
+    ) -> Outcome<SharedPgTransaction<'_>, Error> {
+        self.begin_transaction_impl(cx, mode).await
+    }
+
+    /// Copy every row of `table` from `from` into this connection; see
+    /// [`PgAsyncConnection::copy_table`].
+    pub async fn copy_table(
+        &self,
+        cx: &Cx,
+        from: &SharedPgConnection,
+        table: &str,
+    ) -> Outcome<u64, Error> {
+        if Arc::ptr_eq(&self.inner, &from.inner) {
+            return Outcome::Err(query_error_msg(
+                "copy_table needs two distinct connections",
+                QueryErrorKind::Database,
+            ));
+        }
+        // Lock in address order so concurrent copies in opposite directions
+        // between the same two connections cannot deadlock.
+        let source_first = Arc::as_ptr(&from.inner) < Arc::as_ptr(&self.inner);
+        let (first, second) = if source_first {
+            (&from.inner, &self.inner)
+        } else {
+            (&self.inner, &from.inner)
+        };
+        let Ok(mut first) = first.lock(cx).await else {
+            return Outcome::Err(connection_error("Failed to acquire connection lock"));
+        };
+        let Ok(mut second) = second.lock(cx).await else {
+            return Outcome::Err(connection_error("Failed to acquire connection lock"));
+        };
+        let (guard, source) = if source_first {
+            (&mut *second, &mut *first)
+        } else {
+            (&mut *first, &mut *second)
+        };
+        guard.copy_table(cx, source, table).await
+    }
+
     async fn begin_transaction_impl(
         &self,
         cx: &Cx,
-        isolation: Option<IsolationLevel>,
+        mode: TransactionMode,
     ) -> Outcome<SharedPgTransaction<'_>, Error> {
         let inner = Arc::clone(&self.inner);
         let Ok(mut guard) = inner.lock(cx).await else {
             return Outcome::Err(connection_error("Failed to acquire connection lock"));
         };
 
-        if let Some(level) = isolation {
-            let sql = format!("SET TRANSACTION ISOLATION LEVEL {}", level.as_sql());
-            match guard.execute_async(cx, &sql, &[]).await {
-                Outcome::Ok(_) => {}
-                Outcome::Err(e) => return Outcome::Err(e),
-                Outcome::Cancelled(r) => return Outcome::Cancelled(r),
-                Outcome::Panicked(p) => return Outcome::Panicked(p),
-            }
-        }
-
-        match guard.execute_async(cx, "BEGIN", &[]).await {
+        // Transaction characteristics go on the BEGIN itself: a standalone
+        // `SET TRANSACTION` outside a transaction block has no effect.
+        match guard.execute_async(cx, &mode.begin_sql(), &[]).await {
             Outcome::Ok(_) => {}
             Outcome::Err(e) => return Outcome::Err(e),
             Outcome::Cancelled(r) => return Outcome::Cancelled(r),
@@ -1387,6 +2202,7 @@ impl Clone for SharedPgConnection {
     fn clone(&self) -> Self {
         Self {
             inner: Arc::clone(&self.inner),
+            transaction_status: Arc::clone(&self.transaction_status),
         }
     }
 }
@@ -1431,6 +2247,10 @@ impl Connection for SharedPgConnection {
         sqlmodel_core::Dialect::Postgres
     }
 
+    fn transaction_status(&self) -> TransactionStatus {
+        decode_transaction_status(self.transaction_status.load(Ordering::Acquire))
+    }
+
     fn query(
         &self,
         cx: &Cx,
@@ -1538,7 +2358,7 @@ impl Connection for SharedPgConnection {
         cx: &Cx,
         isolation: IsolationLevel,
     ) -> impl Future<Output = Outcome<Self::Tx<'_>, Error>> + Send {
-        self.begin_transaction_impl(cx, Some(isolation))
+        self.begin_transaction_impl(cx, TransactionMode::new(isolation))
     }
 
     fn prepare(
@@ -1600,6 +2420,16 @@ impl Connection for SharedPgConnection {
         }
     }
 
+    fn reset(&self, cx: &Cx) -> impl Future<Output = Outcome<(), Error>> + Send {
+        let inner = Arc::clone(&self.inner);
+        async move {
+            let Ok(mut guard) = inner.lock(cx).await else {
+                return Outcome::Err(connection_error("Failed to acquire connection lock"));
+            };
+            guard.reset(cx).await
+        }
+    }
+
     async fn close(self, cx: &Cx) -> sqlmodel_core::Result<()> {
         let Ok(mut guard) = self.inner.lock(cx).await else {
             return Err(connection_error("Failed to acquire connection lock"));
@@ -1614,6 +2444,11 @@ impl Connection for SharedPgConnection {
                 sql: None,
                 detail: None,
                 hint: None,
+                schema: None,
+                table: None,
+                column: None,
+                constraint: None,
+                where_: None,
                 position: None,
                 source: None,
             })),
@@ -1768,6 +2603,13 @@ struct PgQueryResult {
     command_tag: Option<String>,
 }
 
+impl PgQueryResult {
+    /// Parsed command tag; an empty query yields an empty `Other` tag.
+    fn command_tag(&self) -> CommandTag {
+        CommandTag::parse(self.command_tag.as_deref().unwrap_or(""))
+    }
+}
+
 fn connection_error(msg: impl Into<String>) -> Error {
     Error::Connection(ConnectionError {
         kind: ConnectionErrorKind::Connect,
@@ -1776,6 +2618,22 @@ fn connection_error(msg: impl Into<String>) -> Error {
     })
 }
 
+/// Whether the server rejected a cached statement that must be re-prepared:
+/// `0A000` ("cached plan must not change result type") or `26000` (the
+/// statement no longer exists).
+fn is_stale_cached_statement(error: &Error) -> bool {
+    matches!(error.sqlstate(), Some("0A000" | "26000"))
+}
+
+/// The connection's protocol stream is out of sync and must be discarded.
+fn unusable_connection_error() -> Error {
+    Error::Connection(ConnectionError {
+        kind: ConnectionErrorKind::Disconnected,
+        message: "connection is unusable after an aborted exchange".to_string(),
+        source: None,
+    })
+}
+
 fn auth_error(msg: impl Into<String>) -> Error {
     Error::Connection(ConnectionError {
         kind: ConnectionErrorKind::Authentication,
@@ -1784,6 +2642,51 @@ fn auth_error(msg: impl Into<String>) -> Error {
     })
 }
 
+/// Decode a `DataRow` against the statement's `RowDescription`.
+///
+/// Statements without result columns (`SELECT;`, or a `DO` block or utility
+/// command answered with `NoData`) have nothing to decode: their rows yield
+/// `Ok(None)` and the result comes back empty. Only a field count that
+/// disagrees with a non-empty description is an error.
+fn decode_data_row(
+    desc: Option<&[crate::protocol::FieldDescription]>,
+    columns: Option<&Arc<ColumnInfo>>,
+    raw_values: Vec<Option<Vec<u8>>>,
+) -> Result<Option<Row>, Error> {
+    let Some(desc) = desc else {
+        if raw_values.is_empty() {
+            return Ok(None);
+        }
+        return Err(protocol_error("DataRow received before RowDescription"));
+    };
+    if desc.is_empty() {
+        return Ok(None);
+    }
+    let Some(columns) = columns else {
+        return Err(protocol_error("Row column metadata missing"));
+    };
+    if raw_values.len() != desc.len() {
+        return Err(protocol_error(format!(
+            "DataRow field count mismatch: expected {}, got {}",
+            desc.len(),
+            raw_values.len()
+        )));
+    }
+
+    let mut values = Vec::with_capacity(raw_values.len());
+    for (field, raw) in desc.iter().zip(raw_values) {
+        match raw {
+            None => values.push(Value::Null),
+            Some(bytes) => values.push(decode_value(
+                field.type_oid,
+                Some(bytes.as_slice()),
+                Format::from_code(field.format),
+            )?),
+        }
+    }
+    Ok(Some(Row::with_columns(Arc::clone(columns), values)))
+}
+
 fn protocol_error(msg: impl Into<String>) -> Error {
     Error::Protocol(ProtocolError {
         message: msg.into(),
@@ -1800,6 +2703,11 @@ fn query_error_msg(msg: impl Into<String>, kind: QueryErrorKind) -> Error {
         sql: None,
         detail: None,
         hint: None,
+        schema: None,
+        table: None,
+        column: None,
+        constraint: None,
+        where_: None,
         position: None,
         source: None,
     })
@@ -1847,48 +2755,125 @@ fn error_from_fields(fields: &ErrorFields) -> Error {
         message: fields.message.clone(),
         detail: fields.detail.clone(),
         hint: fields.hint.clone(),
+        schema: fields.schema.clone(),
+        table: fields.table.clone(),
+        column: fields.column.clone(),
+        constraint: fields.constraint.clone(),
+        where_: fields.where_.clone(),
         position: fields.position.map(|p| p as usize),
         source: None,
     })
 }
 
+/// Parameters encoded for a `Parse`/`Bind` pair.
+struct EncodedParams {
+    /// Type OID per parameter (0 lets the server infer it)
+    types: Vec<u32>,
+    /// Format codes for `Bind`, see [`bind_format_codes`]
+    formats: Vec<i16>,
+    /// Encoded values (`None` for NULL)
+    values: Vec<Option<Vec<u8>>>,
+}
+
+/// Encode parameters in the preferred format where each value supports it,
+/// returning their type OIDs, format codes and bytes.
+///
+/// Untyped NULLs are sent with OID 0 so the server infers their type; a
+/// [`Value::TypedNull`] sends the OID of its type when one is known.
+fn encode_params(params: &[Value], preferred: Format) -> Result<EncodedParams, Error> {
+    let mut types = Vec::with_capacity(params.len());
+    let mut formats = Vec::with_capacity(params.len());
+    let mut values = Vec::with_capacity(params.len());
+    for v in params {
+        let format = preferred.for_value(v);
+        formats.push(format);
+        if v.is_null() {
+            let type_oid = match v {
+                Value::TypedNull(sql_type) => oid::sql_type_oid(sql_type).unwrap_or(0),
+                _ => 0,
+            };
+            types.push(type_oid);
+            values.push(None);
+            continue;
+        }
+        let (bytes, oid) = encode_value(v, format)?;
+        types.push(oid);
+        values.push(Some(bytes));
+    }
+    Ok(EncodedParams {
+        types,
+        formats: bind_format_codes(&formats),
+        values,
+    })
+}
+
+/// Parameter format codes for `Bind`: a single code when every parameter
+/// shares it, one code per parameter otherwise.
+fn bind_format_codes(formats: &[Format]) -> Vec<i16> {
+    match formats.first() {
+        None => Vec::new(),
+        Some(&first) if formats.iter().all(|&f| f == first) => vec![first.code()],
+        Some(_) => formats.iter().copied().map(Format::code).collect(),
+    }
+}
+
+fn encode_transaction_status(status: TransactionStatus) -> u8 {
+    match status {
+        TransactionStatus::Idle => 0,
+        TransactionStatus::InTransaction => 1,
+        TransactionStatus::Failed => 2,
+    }
+}
+
+fn decode_transaction_status(raw: u8) -> TransactionStatus {
+    match raw {
+        1 => TransactionStatus::InTransaction,
+        2 => TransactionStatus::Failed,
+        _ => TransactionStatus::Idle,
+    }
+}
+
+/// Row count of a command tag; see [`CommandTag`] for the full parse.
 fn parse_rows_affected(tag: Option<&str>) -> Option<u64> {
-    let tag = tag?;
-    let mut parts = tag.split_whitespace().collect::<Vec<_>>();
-    parts.pop().and_then(|last| last.parse::<u64>().ok())
+    tag.map(|tag| CommandTag::parse(tag).rows)
 }
 
 /// Validate a savepoint name to reduce SQL injection risk.
 fn validate_savepoint_name(name: &str) -> sqlmodel_core::Result<()> {
+    validate_identifier("Savepoint name", name)
+}
+
+/// Validate a plain SQL identifier (letters, digits, underscores; at most 63 bytes).
+fn validate_identifier(what: &str, name: &str) -> sqlmodel_core::Result<()> {
     if name.is_empty() {
         return Err(query_error_msg(
-            "Savepoint name cannot be empty",
+            format!("{what} cannot be empty"),
             QueryErrorKind::Syntax,
         ));
     }
     if name.len() > 63 {
         return Err(query_error_msg(
-            "Savepoint name exceeds maximum length of 63 characters",
+            format!("{what} exceeds maximum length of 63 characters"),
             QueryErrorKind::Syntax,
         ));
     }
     let mut chars = name.chars();
     let Some(first) = chars.next() else {
         return Err(query_error_msg(
-            "Savepoint name cannot be empty",
+            format!("{what} cannot be empty"),
             QueryErrorKind::Syntax,
         ));
     };
     if !first.is_ascii_alphabetic() && first != '_' {
         return Err(query_error_msg(
-            "Savepoint name must start with a letter or underscore",
+            format!("{what} must start with a letter or underscore"),
             QueryErrorKind::Syntax,
         ));
     }
     for c in chars {
         if !c.is_ascii_alphanumeric() && c != '_' {
             return Err(query_error_msg(
-                format!("Savepoint name contains invalid character: '{c}'"),
+                format!("{what} contains invalid character: '{c}'"),
                 QueryErrorKind::Syntax,
             ));
         }
@@ -1896,6 +2881,62 @@ fn validate_savepoint_name(name: &str) -> sqlmodel_core::Result<()> {
     Ok(())
 }
 
+/// Build the statements that apply `PgConfig::role` and `PgConfig::search_path`.
+///
+/// Names are validated as plain identifiers and double-quoted so their case
+/// is preserved.
+/// Whether running `sql` may leave state behind in the session (`SET`,
+/// temporary tables, `LISTEN`, ...), as opposed to plannable statements and
+/// transaction control, which [`PgAsyncConnection::reset`] need not undo.
+fn changes_session_state(sql: &str) -> bool {
+    if is_plannable(sql) {
+        return false;
+    }
+    let keyword = sql
+        .trim_start()
+        .split(|c: char| !c.is_ascii_alphabetic())
+        .next()
+        .unwrap_or("");
+    ![
+        "BEGIN",
+        "START",
+        "COMMIT",
+        "END",
+        "ROLLBACK",
+        "ABORT",
+        "SAVEPOINT",
+        "RELEASE",
+    ]
+    .iter()
+    .any(|k| keyword.eq_ignore_ascii_case(k))
+}
+
+fn session_setup_sql(config: &PgConfig) -> sqlmodel_core::Result<Vec<String>> {
+    let mut sql = Vec::new();
+    if let Some(role) = &config.role {
+        validate_identifier("Role name", role)?;
+        sql.push(format!("SET ROLE \"{role}\""));
+    }
+    if let Some(schemas) = &config.search_path {
+        if schemas.is_empty() {
+            return Err(query_error_msg(
+                "search_path cannot be empty",
+                QueryErrorKind::Syntax,
+            ));
+        }
+        for schema in schemas {
+            validate_identifier("Schema name", schema)?;
+        }
+        let list = schemas
+            .iter()
+            .map(|s| format!("\"{s}\""))
+            .collect::<Vec<_>>()
+            .join(", ");
+        sql.push(format!("SET search_path TO {list}"));
+    }
+    Ok(sql)
+}
+
 fn md5_password(user: &str, password: &str, salt: [u8; 4]) -> String {
     use std::fmt::Write;
 
@@ -1913,3 +2954,784 @@ fn md5_password(user: &str, password: &str, salt: [u8; 4]) -> String {
 }
 
 // Note: read/write helpers are implemented above on PgAsyncStream.
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn int4_field(name: &str) -> crate::protocol::FieldDescription {
+        crate::protocol::FieldDescription {
+            name: name.to_string(),
+            table_oid: 0,
+            column_id: 0,
+            type_oid: oid::INT4,
+            type_size: 4,
+            type_modifier: -1,
+            format: 0,
+        }
+    }
+
+    #[test]
+    fn test_decode_data_row_zero_columns() {
+        // `SELECT;` describes zero columns and sends one empty DataRow.
+        let columns = Arc::new(ColumnInfo::new(Vec::new()));
+        let row = decode_data_row(Some(&[]), Some(&columns), Vec::new()).unwrap();
+        assert!(row.is_none());
+
+        // A DO block answers with NoData, so there is no description at all.
+        assert!(decode_data_row(None, None, Vec::new()).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_decode_data_row_checks_field_count() {
+        let desc = [int4_field("n")];
+        let columns = Arc::new(ColumnInfo::new(vec!["n".to_string()]));
+
+        let row = decode_data_row(Some(&desc), Some(&columns), vec![Some(b"7".to_vec())])
+            .unwrap()
+            .expect("one row");
+        assert_eq!(row.get_named::<i32>("n").unwrap(), 7);
+
+        let mismatch = decode_data_row(Some(&desc), Some(&columns), Vec::new());
+        assert!(matches!(mismatch, Err(Error::Protocol(_))));
+        let undescribed = decode_data_row(None, None, vec![None]);
+        assert!(matches!(undescribed, Err(Error::Protocol(_))));
+    }
+
+    #[test]
+    fn test_session_setup_sql() {
+        let config = PgConfig::new("localhost", "postgres", "testdb")
+            .role("app_reader")
+            .search_path(["tenant_42", "public"]);
+        assert_eq!(
+            session_setup_sql(&config).unwrap(),
+            vec![
+                "SET ROLE \"app_reader\"".to_string(),
+                "SET search_path TO \"tenant_42\", \"public\"".to_string(),
+            ]
+        );
+        assert!(session_setup_sql(&PgConfig::default()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_changes_session_state() {
+        for sql in [
+            "SET statement_timeout = 0",
+            "CREATE TEMP TABLE t (id int)",
+            "LISTEN jobs",
+        ] {
+            assert!(changes_session_state(sql), "{sql}");
+        }
+        for sql in [
+            "SELECT 1",
+            "  insert into heroes values (1)",
+            "BEGIN ISOLATION LEVEL SERIALIZABLE",
+            "ROLLBACK TO SAVEPOINT sp_1",
+            "commit",
+        ] {
+            assert!(!changes_session_state(sql), "{sql}");
+        }
+    }
+
+    #[test]
+    fn test_statement_cache_survives_checkout_cycle() {
+        use asupersync::runtime::RuntimeBuilder;
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = std::thread::spawn(move || {
+            let (mut socket, _) = listener.accept().unwrap();
+            let mut rest = Vec::new();
+            let _ = std::io::Read::read_to_end(&mut socket, &mut rest);
+            rest
+        });
+
+        let rt = RuntimeBuilder::current_thread().build().unwrap();
+        rt.block_on(async {
+            let stream = TcpStream::connect_timeout(addr, Duration::from_secs(5))
+                .await
+                .unwrap();
+            let mut conn = test_connection(stream, addr.port());
+            conn.statement_cache = StatementCache::new(4);
+            conn.statement_cache.insert(
+                "SELECT * FROM heroes",
+                CachedStatement {
+                    name: "sqlmodel_cached_1".to_string(),
+                    param_type_oids: Vec::new(),
+                },
+            );
+            let shared = SharedPgConnection::new(conn);
+            let cx = Cx::for_testing();
+
+            // The pool resets a recycled connection on every checkout.
+            for _ in 0..2 {
+                assert!(matches!(
+                    Connection::reset(&shared, &cx).await,
+                    Outcome::Ok(())
+                ));
+            }
+            let guard = shared.inner().lock(&cx).await.unwrap();
+            assert_eq!(guard.statement_cache.len(), 1);
+        });
+
+        // A clean, idle session needs no round trip.
+        assert!(server.join().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_session_setup_sql_rejects_injection() {
+        let config = PgConfig::default().role("admin\"; DROP TABLE users; --");
+        assert!(session_setup_sql(&config).is_err());
+
+        let config = PgConfig::default().search_path(["public", "x, pg_temp"]);
+        assert!(session_setup_sql(&config).is_err());
+
+        let config = PgConfig::default().search_path(Vec::<String>::new());
+        assert!(session_setup_sql(&config).is_err());
+    }
+
+    /// A ready connection over `stream`, skipping startup and authentication.
+    fn test_connection(stream: TcpStream, port: u16) -> PgAsyncConnection {
+        PgAsyncConnection {
+            stream: PgAsyncStream::Plain(stream),
+            state: ConnectionState::Ready(TransactionStatusState::Idle),
+            process_id: 42,
+            secret_key: 7,
+            parameters: HashMap::new(),
+            next_prepared_id: 1,
+            prepared: HashMap::new(),
+            statement_cache: StatementCache::new(0),
+            session_dirty: false,
+            transaction_status: Arc::new(AtomicU8::new(encode_transaction_status(
+                TransactionStatus::Idle,
+            ))),
+            config: PgConfig::new("127.0.0.1", "postgres", "testdb").port(port),
+            reader: MessageReader::new(),
+            writer: MessageWriter::new(),
+            read_buf: vec![0u8; 8192],
+            state_listener: None,
+        }
+    }
+
+    /// Encode a backend message: type byte, length, body.
+    fn backend_message(kind: u8, body: &[u8]) -> Vec<u8> {
+        let mut out = vec![kind];
+        out.extend_from_slice(&(body.len() as i32 + 4).to_be_bytes());
+        out.extend_from_slice(body);
+        out
+    }
+
+    #[test]
+    fn test_validate_sql_returns_parse_error_and_stays_ready() {
+        use asupersync::runtime::RuntimeBuilder;
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = std::thread::spawn(move || {
+            let (mut socket, _) = listener.accept().unwrap();
+            // Read until the Sync message arrives.
+            let mut request = Vec::new();
+            let mut buf = [0u8; 256];
+            while !request.ends_with(&[b'S', 0, 0, 0, 4]) {
+                let n = std::io::Read::read(&mut socket, &mut buf).unwrap();
+                assert!(n > 0, "client closed before Sync");
+                request.extend_from_slice(&buf[..n]);
+            }
+
+            let mut reply = backend_message(
+                b'E',
+                b"SERROR\0C42601\0Msyntax error at or near \"SELEC\"\0\0",
+            );
+            reply.extend(backend_message(b'Z', b"I"));
+            std::io::Write::write_all(&mut socket, &reply).unwrap();
+
+            // Hold the socket open until the client is done.
+            let mut rest = Vec::new();
+            let _ = std::io::Read::read_to_end(&mut socket, &mut rest);
+            request
+        });
+
+        let rt = RuntimeBuilder::current_thread().build().unwrap();
+        rt.block_on(async {
+            let stream = TcpStream::connect_timeout(addr, Duration::from_secs(5))
+                .await
+                .unwrap();
+            let mut conn = test_connection(stream, addr.port());
+            let cx = Cx::for_testing();
+
+            let outcome = conn.validate_sql(&cx, "SELEC 1").await;
+            match outcome {
+                Outcome::Err(Error::Query(e)) => {
+                    assert_eq!(e.kind, QueryErrorKind::Syntax);
+                    assert_eq!(e.sqlstate.as_deref(), Some("42601"));
+                }
+                other => panic!("expected syntax error, got {other:?}"),
+            }
+            assert!(matches!(
+                conn.state,
+                ConnectionState::Ready(TransactionStatusState::Idle)
+            ));
+        });
+
+        // Parse, Close and Sync only: nothing is bound or executed.
+        let request = server.join().unwrap();
+        let mut kinds = Vec::new();
+        let mut pos = 0;
+        while pos < request.len() {
+            kinds.push(request[pos]);
+            let len = i32::from_be_bytes(request[pos + 1..pos + 5].try_into().unwrap());
+            pos += 1 + len as usize;
+        }
+        assert_eq!(kinds, vec![b'P', b'C', b'S']);
+    }
+
+    #[test]
+    fn test_transaction_status_tracks_ready_for_query() {
+        use asupersync::runtime::RuntimeBuilder;
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = std::thread::spawn(move || {
+            let (mut socket, _) = listener.accept().unwrap();
+            let mut request = Vec::new();
+            let mut buf = [0u8; 256];
+            while !request.ends_with(&[b'S', 0, 0, 0, 4]) {
+                let n = std::io::Read::read(&mut socket, &mut buf).unwrap();
+                assert!(n > 0, "client closed before Sync");
+                request.extend_from_slice(&buf[..n]);
+            }
+
+            // The statement fails inside an open transaction block.
+            let mut reply = backend_message(
+                b'E',
+                b"SERROR\0C42601\0Msyntax error at or near \"SELEC\"\0\0",
+            );
+            reply.extend(backend_message(b'Z', b"E"));
+            std::io::Write::write_all(&mut socket, &reply).unwrap();
+
+            let mut rest = Vec::new();
+            let _ = std::io::Read::read_to_end(&mut socket, &mut rest);
+        });
+
+        let rt = RuntimeBuilder::current_thread().build().unwrap();
+        rt.block_on(async {
+            let stream = TcpStream::connect_timeout(addr, Duration::from_secs(5))
+                .await
+                .unwrap();
+            let mut conn = test_connection(stream, addr.port());
+            let cx = Cx::for_testing();
+            assert_eq!(conn.transaction_status(), TransactionStatus::Idle);
+
+            assert!(matches!(
+                conn.validate_sql(&cx, "SELEC 1").await,
+                Outcome::Err(_)
+            ));
+            assert_eq!(conn.transaction_status(), TransactionStatus::Failed);
+
+            let shared = SharedPgConnection::new(conn);
+            assert_eq!(
+                Connection::transaction_status(&shared),
+                TransactionStatus::Failed
+            );
+        });
+
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn test_state_listener_sees_each_transition() {
+        use asupersync::runtime::RuntimeBuilder;
+        use std::sync::Mutex as StdMutex;
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = std::thread::spawn(move || {
+            let (mut socket, _) = listener.accept().unwrap();
+            let mut request = Vec::new();
+            let mut buf = [0u8; 256];
+            while !request.ends_with(&[b'S', 0, 0, 0, 4]) {
+                let n = std::io::Read::read(&mut socket, &mut buf).unwrap();
+                assert!(n > 0, "client closed before Sync");
+                request.extend_from_slice(&buf[..n]);
+            }
+
+            // The error itself is not a state change; the failed transaction
+            // reported by ReadyForQuery is.
+            let mut reply = backend_message(
+                b'E',
+                b"SERROR\0C42601\0Msyntax error at or near \"SELEC\"\0\0",
+            );
+            reply.extend(backend_message(b'Z', b"E"));
+            std::io::Write::write_all(&mut socket, &reply).unwrap();
+
+            let mut rest = Vec::new();
+            let _ = std::io::Read::read_to_end(&mut socket, &mut rest);
+        });
+
+        let seen = Arc::new(StdMutex::new(Vec::new()));
+        let rt = RuntimeBuilder::current_thread().build().unwrap();
+        rt.block_on(async {
+            let stream = TcpStream::connect_timeout(addr, Duration::from_secs(5))
+                .await
+                .unwrap();
+            let mut conn = test_connection(stream, addr.port());
+            let sink = Arc::clone(&seen);
+            conn.set_state_listener(Some(Arc::new(move |state| {
+                sink.lock().unwrap().push(state);
+            })));
+            let cx = Cx::for_testing();
+
+            assert!(matches!(
+                conn.validate_sql(&cx, "SELEC 1").await,
+                Outcome::Err(_)
+            ));
+            assert_eq!(
+                conn.state(),
+                ConnectionState::Ready(TransactionStatusState::InFailed)
+            );
+            assert!(matches!(conn.close_async(&cx).await, Outcome::Ok(())));
+            assert_eq!(conn.state(), ConnectionState::Closed);
+        });
+
+        server.join().unwrap();
+        assert_eq!(
+            *seen.lock().unwrap(),
+            vec![
+                ConnectionState::Ready(TransactionStatusState::InFailed),
+                ConnectionState::Closed,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parameter_status_updates_mid_session() {
+        use asupersync::runtime::RuntimeBuilder;
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = std::thread::spawn(move || {
+            let (mut socket, _) = listener.accept().unwrap();
+            while read_frontend_message(&mut socket).0 != b'S' {}
+
+            let mut reply = backend_message(b'1', b"");
+            reply.extend(backend_message(b'2', b""));
+            reply.extend(backend_message(b'n', b""));
+            reply.extend(backend_message(b'S', b"TimeZone\0Asia/Tokyo\0"));
+            reply.extend(backend_message(b'C', b"SET\0"));
+            reply.extend(backend_message(b'Z', b"I"));
+            std::io::Write::write_all(&mut socket, &reply).unwrap();
+
+            let mut rest = Vec::new();
+            let _ = std::io::Read::read_to_end(&mut socket, &mut rest);
+        });
+
+        let rt = RuntimeBuilder::current_thread().build().unwrap();
+        rt.block_on(async {
+            let stream = TcpStream::connect_timeout(addr, Duration::from_secs(5))
+                .await
+                .unwrap();
+            let mut conn = test_connection(stream, addr.port());
+            conn.parameters
+                .insert("TimeZone".to_string(), "UTC".to_string());
+            let cx = Cx::for_testing();
+
+            match conn
+                .execute_async(&cx, "SET TimeZone = 'Asia/Tokyo'", &[])
+                .await
+            {
+                Outcome::Ok(_) => {}
+                other => panic!("expected SET to succeed, got {other:?}"),
+            }
+            assert_eq!(conn.parameter("TimeZone"), Some("Asia/Tokyo"));
+        });
+
+        server.join().unwrap();
+    }
+
+    /// Read one frontend message: type byte and body.
+    fn read_frontend_message(socket: &mut std::net::TcpStream) -> (u8, Vec<u8>) {
+        let mut header = [0u8; 5];
+        std::io::Read::read_exact(socket, &mut header).unwrap();
+        let len = i32::from_be_bytes(header[1..5].try_into().unwrap()) as usize;
+        let mut body = vec![0u8; len - 4];
+        std::io::Read::read_exact(socket, &mut body).unwrap();
+        (header[0], body)
+    }
+
+    #[test]
+    fn test_encode_params_types_typed_nulls() {
+        let encoded = encode_params(
+            &[
+                Value::Null,
+                Value::TypedNull(sqlmodel_core::SqlType::Integer),
+                Value::TypedNull(sqlmodel_core::SqlType::Custom("citext")),
+            ],
+            Format::Text,
+        )
+        .unwrap();
+        assert_eq!(encoded.types, vec![0, oid::INT4, 0]);
+        assert_eq!(encoded.values, vec![None, None, None]);
+    }
+
+    #[test]
+    fn test_encode_params_preferred_format() {
+        let params = [Value::Int(7), Value::Null];
+
+        let text = encode_params(&params, Format::Text).unwrap();
+        assert_eq!(text.formats, vec![Format::Text.code()]);
+        assert_eq!(text.values[0].as_deref(), Some(&b"7"[..]));
+
+        // NULL has no binary encoding and stays text; the codes go per parameter.
+        let binary = encode_params(&params, Format::Binary).unwrap();
+        assert_eq!(
+            binary.formats,
+            vec![Format::Binary.code(), Format::Text.code()]
+        );
+        assert_eq!(binary.types, vec![oid::INT4, 0]);
+        assert_eq!(binary.values[0].as_deref(), Some(&7_i32.to_be_bytes()[..]));
+
+        assert!(
+            encode_params(&[], Format::Binary)
+                .unwrap()
+                .formats
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_shared_copy_table_rejects_same_connection() {
+        use asupersync::runtime::RuntimeBuilder;
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = std::thread::spawn(move || {
+            let (mut socket, _) = listener.accept().unwrap();
+            let mut rest = Vec::new();
+            let _ = std::io::Read::read_to_end(&mut socket, &mut rest);
+            rest
+        });
+
+        let rt = RuntimeBuilder::current_thread().build().unwrap();
+        rt.block_on(async {
+            let stream = TcpStream::connect_timeout(addr, Duration::from_secs(5))
+                .await
+                .unwrap();
+            let shared = SharedPgConnection::new(test_connection(stream, addr.port()));
+            let cx = Cx::for_testing();
+            assert!(matches!(
+                shared.copy_table(&cx, &shared, "heroes").await,
+                Outcome::Err(_)
+            ));
+        });
+
+        // Nothing was sent to the server.
+        assert!(server.join().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_copy_table_forwards_binary_copy_data() {
+        use asupersync::runtime::RuntimeBuilder;
+
+        let chunks: [&[u8]; 2] = [b"PGCOPY\n\xff\r\n\0 header+row1", b" row2 trailer"];
+
+        let source_listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let source_addr = source_listener.local_addr().unwrap();
+        let source = std::thread::spawn(move || {
+            let (mut socket, _) = source_listener.accept().unwrap();
+            let (kind, query) = read_frontend_message(&mut socket);
+            assert_eq!(kind, b'Q');
+
+            let mut reply = backend_message(b'H', &[1, 0, 2, 0, 1, 0, 1]);
+            for chunk in chunks {
+                reply.extend(backend_message(b'd', chunk));
+            }
+            reply.extend(backend_message(b'c', b""));
+            reply.extend(backend_message(b'C', b"COPY 2\0"));
+            reply.extend(backend_message(b'Z', b"I"));
+            std::io::Write::write_all(&mut socket, &reply).unwrap();
+
+            let mut rest = Vec::new();
+            let _ = std::io::Read::read_to_end(&mut socket, &mut rest);
+            String::from_utf8(query).unwrap()
+        });
+
+        let dest_listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let dest_addr = dest_listener.local_addr().unwrap();
+        let dest = std::thread::spawn(move || {
+            let (mut socket, _) = dest_listener.accept().unwrap();
+            while read_frontend_message(&mut socket).0 != b'S' {}
+
+            // The destination declares the columns in a different order.
+            let mut desc = 2i16.to_be_bytes().to_vec();
+            for name in ["attname", "attrelid"] {
+                desc.extend_from_slice(name.as_bytes());
+                desc.push(0);
+                desc.extend_from_slice(&0i32.to_be_bytes());
+                desc.extend_from_slice(&0i16.to_be_bytes());
+                desc.extend_from_slice(&25i32.to_be_bytes());
+                desc.extend_from_slice(&(-1i16).to_be_bytes());
+                desc.extend_from_slice(&(-1i32).to_be_bytes());
+                desc.extend_from_slice(&0i16.to_be_bytes());
+            }
+            let mut reply = backend_message(b'1', b"");
+            reply.extend(backend_message(b'2', b""));
+            reply.extend(backend_message(b'T', &desc));
+            for column in ["name", "id"] {
+                let mut row = 2i16.to_be_bytes().to_vec();
+                for value in [column, "heroes"] {
+                    row.extend_from_slice(&(value.len() as i32).to_be_bytes());
+                    row.extend_from_slice(value.as_bytes());
+                }
+                reply.extend(backend_message(b'D', &row));
+            }
+            reply.extend(backend_message(b'C', b"SELECT 2\0"));
+            reply.extend(backend_message(b'Z', b"I"));
+            std::io::Write::write_all(&mut socket, &reply).unwrap();
+
+            let (kind, query) = read_frontend_message(&mut socket);
+            assert_eq!(kind, b'Q');
+            std::io::Write::write_all(&mut socket, &backend_message(b'G', &[1, 0, 2, 0, 1, 0, 1]))
+                .unwrap();
+
+            let mut data = Vec::new();
+            loop {
+                match read_frontend_message(&mut socket) {
+                    (b'd', body) => data.extend(body),
+                    (b'c', _) => break,
+                    (kind, _) => panic!("unexpected message {}", kind as char),
+                }
+            }
+            let mut reply = backend_message(b'C', b"COPY 2\0");
+            reply.extend(backend_message(b'Z', b"I"));
+            std::io::Write::write_all(&mut socket, &reply).unwrap();
+
+            let mut rest = Vec::new();
+            let _ = std::io::Read::read_to_end(&mut socket, &mut rest);
+            (String::from_utf8(query).unwrap(), data)
+        });
+
+        let rt = RuntimeBuilder::current_thread().build().unwrap();
+        rt.block_on(async {
+            let cx = Cx::for_testing();
+            let stream = TcpStream::connect_timeout(source_addr, Duration::from_secs(5))
+                .await
+                .unwrap();
+            let mut from = test_connection(stream, source_addr.port());
+            let stream = TcpStream::connect_timeout(dest_addr, Duration::from_secs(5))
+                .await
+                .unwrap();
+            let mut to = test_connection(stream, dest_addr.port());
+
+            match to.copy_table(&cx, &mut from, "heroes").await {
+                Outcome::Ok(rows) => assert_eq!(rows, 2),
+                other => panic!("expected rows copied, got {other:?}"),
+            }
+            assert!(matches!(
+                to.state,
+                ConnectionState::Ready(TransactionStatusState::Idle)
+            ));
+            assert!(matches!(
+                from.state,
+                ConnectionState::Ready(TransactionStatusState::Idle)
+            ));
+        });
+
+        let source_query = source.join().unwrap();
+        let (dest_query, data) = dest.join().unwrap();
+        assert_eq!(
+            source_query,
+            "COPY heroes (\"name\", \"id\") TO STDOUT (FORMAT binary)\0"
+        );
+        assert_eq!(
+            dest_query,
+            "COPY heroes (\"name\", \"id\") FROM STDIN (FORMAT binary)\0"
+        );
+        assert_eq!(data, chunks.concat());
+    }
+
+    #[test]
+    fn test_receive_message_aborts_when_budget_deadline_passes() {
+        use asupersync::Budget;
+        use asupersync::runtime::RuntimeBuilder;
+        use asupersync::types::Time;
+
+        // A server that accepts the query connection but never answers, then
+        // captures the CancelRequest sent on a second connection.
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = std::thread::spawn(move || {
+            let (_stalled, _) = listener.accept().unwrap();
+            let (mut cancel, _) = listener.accept().unwrap();
+            let mut request = [0u8; 16];
+            std::io::Read::read_exact(&mut cancel, &mut request).unwrap();
+            request
+        });
+
+        let rt = RuntimeBuilder::current_thread().build().unwrap();
+        rt.block_on(async {
+            let stream = TcpStream::connect_timeout(addr, Duration::from_secs(5))
+                .await
+                .unwrap();
+            let mut conn = test_connection(stream, addr.port());
+
+            let deadline = wall_now().as_nanos() + 50_000_000;
+            let cx = Cx::for_testing_with_budget(
+                Budget::new().with_deadline(Time::from_nanos(deadline)),
+            );
+            let started = std::time::Instant::now();
+            let outcome = conn.receive_message(&cx).await;
+
+            assert!(matches!(outcome, Outcome::Cancelled(_)));
+            assert!(started.elapsed() < Duration::from_secs(5));
+            assert!(matches!(conn.state, ConnectionState::Error));
+        });
+
+        let request = server.join().unwrap();
+        assert_eq!(&request[4..8], &80_877_102i32.to_be_bytes());
+        assert_eq!(&request[8..12], &42i32.to_be_bytes());
+        assert_eq!(&request[12..16], &7i32.to_be_bytes());
+    }
+
+    #[test]
+    fn test_connection_refuses_reuse_after_budget_abort() {
+        use asupersync::Budget;
+        use asupersync::runtime::RuntimeBuilder;
+        use asupersync::types::Time;
+
+        // Collect everything the client writes to the stalled connection after
+        // the timeout, until it hangs up.
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = std::thread::spawn(move || {
+            let (mut stalled, _) = listener.accept().unwrap();
+            let (mut cancel, _) = listener.accept().unwrap();
+            let mut request = [0u8; 16];
+            std::io::Read::read_exact(&mut cancel, &mut request).unwrap();
+            let mut after = Vec::new();
+            let _ = std::io::Read::read_to_end(&mut stalled, &mut after);
+            after
+        });
+
+        let rt = RuntimeBuilder::current_thread().build().unwrap();
+        rt.block_on(async {
+            let stream = TcpStream::connect_timeout(addr, Duration::from_secs(5))
+                .await
+                .unwrap();
+            let mut conn = test_connection(stream, addr.port());
+
+            let deadline = wall_now().as_nanos() + 50_000_000;
+            let cx = Cx::for_testing_with_budget(
+                Budget::new().with_deadline(Time::from_nanos(deadline)),
+            );
+            assert!(matches!(
+                conn.receive_message(&cx).await,
+                Outcome::Cancelled(_)
+            ));
+
+            let cx = Cx::for_testing();
+            for outcome in [conn.ping_async(&cx).await, conn.reset(&cx).await] {
+                match outcome {
+                    Outcome::Err(Error::Connection(e)) => {
+                        assert_eq!(e.kind, ConnectionErrorKind::Disconnected);
+                    }
+                    other => panic!("expected a disconnected error, got {other:?}"),
+                }
+            }
+        });
+
+        // Nothing was sent on the desynchronised stream.
+        assert!(server.join().unwrap().is_empty());
+    }
+
+    /// Read frontend messages up to and including `Sync`, returning their
+    /// type bytes and bodies.
+    fn read_until_sync(socket: &mut std::net::TcpStream) -> Vec<(u8, Vec<u8>)> {
+        let mut messages = Vec::new();
+        loop {
+            let mut header = [0u8; 5];
+            std::io::Read::read_exact(socket, &mut header).unwrap();
+            let len = i32::from_be_bytes(header[1..5].try_into().unwrap());
+            let mut body = vec![0u8; len as usize - 4];
+            std::io::Read::read_exact(socket, &mut body).unwrap();
+            messages.push((header[0], body));
+            if header[0] == b'S' {
+                return messages;
+            }
+        }
+    }
+
+    #[test]
+    fn test_run_cached_evicts_stale_statement_and_retries_unnamed() {
+        use asupersync::runtime::RuntimeBuilder;
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = std::thread::spawn(move || {
+            let (mut socket, _) = listener.accept().unwrap();
+            let mut kinds = Vec::new();
+
+            // Bind to the cached statement: its plan is stale.
+            let batch = read_until_sync(&mut socket);
+            kinds.extend(batch.iter().map(|(kind, _)| *kind));
+            let mut reply = backend_message(
+                b'E',
+                b"SERROR\0C0A000\0Mcached plan must not change result type\0\0",
+            );
+            reply.extend(backend_message(b'Z', b"I"));
+            std::io::Write::write_all(&mut socket, &reply).unwrap();
+
+            // Close of the stale statement.
+            let batch = read_until_sync(&mut socket);
+            assert_eq!(batch[0].1, b"Ssqlmodel_cached_1\0");
+            kinds.extend(batch.iter().map(|(kind, _)| *kind));
+            let mut reply = backend_message(b'3', b"");
+            reply.extend(backend_message(b'Z', b"I"));
+            std::io::Write::write_all(&mut socket, &reply).unwrap();
+
+            // Unnamed retry.
+            let batch = read_until_sync(&mut socket);
+            kinds.extend(batch.iter().map(|(kind, _)| *kind));
+            let mut reply = backend_message(b'1', b"");
+            reply.extend(backend_message(b'2', b""));
+            reply.extend(backend_message(b'n', b""));
+            reply.extend(backend_message(b'C', b"SELECT 0\0"));
+            reply.extend(backend_message(b'Z', b"I"));
+            std::io::Write::write_all(&mut socket, &reply).unwrap();
+
+            let mut rest = Vec::new();
+            let _ = std::io::Read::read_to_end(&mut socket, &mut rest);
+            kinds
+        });
+
+        let rt = RuntimeBuilder::current_thread().build().unwrap();
+        rt.block_on(async {
+            let stream = TcpStream::connect_timeout(addr, Duration::from_secs(5))
+                .await
+                .unwrap();
+            let mut conn = test_connection(stream, addr.port());
+            conn.statement_cache = StatementCache::new(4);
+            conn.statement_cache.insert(
+                "SELECT * FROM heroes",
+                CachedStatement {
+                    name: "sqlmodel_cached_1".to_string(),
+                    param_type_oids: Vec::new(),
+                },
+            );
+            let cx = Cx::for_testing();
+
+            match conn.run_cached(&cx, "SELECT * FROM heroes", &[]).await {
+                Outcome::Ok(result) => assert!(result.rows.is_empty()),
+                Outcome::Err(e) => panic!("expected the retry to succeed, got {e}"),
+                _ => panic!("expected the retry to succeed"),
+            }
+            assert!(conn.statement_cache.is_empty());
+        });
+
+        assert_eq!(
+            server.join().unwrap(),
+            [
+                b'B', b'D', b'E', b'S', b'C', b'S', b'P', b'B', b'D', b'E', b'S'
+            ]
+        );
+    }
+}
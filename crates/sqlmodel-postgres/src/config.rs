@@ -4,8 +4,13 @@
 //! including authentication, SSL, and connection options.
 
 use std::collections::HashMap;
+use std::sync::Arc;
 use std::time::Duration;
 
+use sqlmodel_core::ProgressReporter;
+
+use crate::types::Format;
+
 /// SSL mode for PostgreSQL connections.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum SslMode {
@@ -37,6 +42,11 @@ impl SslMode {
     }
 }
 
+/// `application_name` sent when the configuration doesn't set one, so the
+/// connection is identifiable in `pg_stat_activity`.
+pub const DEFAULT_APPLICATION_NAME: &str =
+    concat!(env!("CARGO_PKG_NAME"), " ", env!("CARGO_PKG_VERSION"));
+
 /// PostgreSQL connection configuration.
 #[derive(Debug, Clone)]
 pub struct PgConfig {
@@ -50,7 +60,8 @@ pub struct PgConfig {
     pub password: Option<String>,
     /// Database name to connect to
     pub database: String,
-    /// Application name (visible in pg_stat_activity)
+    /// Application name (visible in pg_stat_activity); defaults to
+    /// [`DEFAULT_APPLICATION_NAME`], `None` sends no name
     pub application_name: Option<String>,
     /// Connection timeout
     pub connect_timeout: Duration,
@@ -58,6 +69,18 @@ pub struct PgConfig {
     pub ssl_mode: SslMode,
     /// Additional connection parameters
     pub options: HashMap<String, String>,
+    /// Role to switch to with `SET ROLE` after startup
+    pub role: Option<String>,
+    /// Schemas to install with `SET search_path` after startup
+    pub search_path: Option<Vec<String>>,
+    /// Maximum number of prepared statements cached per connection (0 disables)
+    pub statement_cache_size: usize,
+    /// Wire format for query parameters (default: text). Parameters without
+    /// a binary encoding are always sent as text.
+    pub preferred_format: Format,
+    /// Receives connect phases ("Connecting", "Negotiating TLS",
+    /// "Authenticating", "Starting session") and a tick per socket read
+    pub progress: Option<Arc<dyn ProgressReporter>>,
 }
 
 impl Default for PgConfig {
@@ -68,10 +91,15 @@ impl Default for PgConfig {
             user: String::new(),
             password: None,
             database: String::new(),
-            application_name: None,
+            application_name: Some(DEFAULT_APPLICATION_NAME.to_string()),
             connect_timeout: Duration::from_secs(30),
             ssl_mode: SslMode::default(),
             options: HashMap::new(),
+            role: None,
+            search_path: None,
+            statement_cache_size: 100,
+            preferred_format: Format::Text,
+            progress: None,
         }
     }
 }
@@ -109,6 +137,18 @@ impl PgConfig {
         self
     }
 
+    /// Set the wire format to prefer for query parameters.
+    pub fn preferred_format(mut self, format: Format) -> Self {
+        self.preferred_format = format;
+        self
+    }
+
+    /// Report connect phases and read activity to `reporter`.
+    pub fn progress(mut self, reporter: Arc<dyn ProgressReporter>) -> Self {
+        self.progress = Some(reporter);
+        self
+    }
+
     /// Set the connection timeout.
     pub fn connect_timeout(mut self, timeout: Duration) -> Self {
         self.connect_timeout = timeout;
@@ -127,6 +167,45 @@ impl PgConfig {
         self
     }
 
+    /// Set the role to assume once the connection is established.
+    ///
+    /// Issued as `SET ROLE` right after startup. The name must be a plain
+    /// identifier (letters, digits, underscores); connecting fails otherwise.
+    ///
+    /// This is session state: `PgAsyncConnection::reset`, which pools run
+    /// when recycling a connection, resets everything and then re-applies it.
+    pub fn role(mut self, role: impl Into<String>) -> Self {
+        self.role = Some(role.into());
+        self
+    }
+
+    /// Set the schema search path to install once the connection is established.
+    ///
+    /// Issued as `SET search_path` right after startup (after `SET ROLE`).
+    /// Each schema must be a plain identifier; connecting fails otherwise.
+    ///
+    /// Like [`role`](Self::role), this is re-applied when a pooled
+    /// connection is reset.
+    pub fn search_path<I, S>(mut self, schemas: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.search_path = Some(schemas.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Set how many prepared statements each connection caches.
+    ///
+    /// `query`/`execute` calls with SQL seen before reuse the server-side
+    /// statement prepared the first time. When the cache is full the least
+    /// recently used statement is released with `DEALLOCATE`. Pass 0 to
+    /// disable the cache.
+    pub fn statement_cache_size(mut self, size: usize) -> Self {
+        self.statement_cache_size = size;
+        self
+    }
+
     /// Build the startup parameters to send to the server.
     pub fn startup_params(&self) -> Vec<(String, String)> {
         let mut params = vec![
@@ -150,6 +229,20 @@ impl PgConfig {
     pub fn socket_addr(&self) -> String {
         format!("{}:{}", self.host, self.port)
     }
+
+    /// Tell the progress reporter, if any, that `phase` has started.
+    pub(crate) fn report_phase(&self, phase: &str) {
+        if let Some(progress) = &self.progress {
+            progress.on_phase(phase);
+        }
+    }
+
+    /// Tell the progress reporter, if any, that data arrived.
+    pub(crate) fn report_tick(&self) {
+        if let Some(progress) = &self.progress {
+            progress.tick();
+        }
+    }
 }
 
 #[cfg(test)]
@@ -175,6 +268,31 @@ mod tests {
         assert_eq!(config.connect_timeout, Duration::from_secs(10));
         assert_eq!(config.ssl_mode, SslMode::Prefer);
         assert_eq!(config.options.get("timezone"), Some(&"UTC".to_string()));
+        assert_eq!(config.role, None);
+        assert_eq!(config.search_path, None);
+        assert_eq!(config.statement_cache_size, 100);
+        assert_eq!(config.preferred_format, Format::Text);
+        assert_eq!(config.statement_cache_size(0).statement_cache_size, 0);
+    }
+
+    #[test]
+    fn test_role_and_search_path() {
+        let config = PgConfig::new("localhost", "postgres", "testdb")
+            .role("app_reader")
+            .search_path(["tenant_42", "public"]);
+
+        assert_eq!(config.role.as_deref(), Some("app_reader"));
+        assert_eq!(
+            config.search_path,
+            Some(vec!["tenant_42".to_string(), "public".to_string()])
+        );
+        // Applied with SET after startup, not sent as startup parameters.
+        assert!(
+            !config
+                .startup_params()
+                .iter()
+                .any(|(k, _)| k == "role" || k == "search_path")
+        );
     }
 
     #[test]
@@ -200,6 +318,58 @@ mod tests {
         assert!(params.iter().any(|(k, v)| k == "timezone" && v == "UTC"));
     }
 
+    #[test]
+    fn test_default_application_name() {
+        let config = PgConfig::new("localhost", "postgres", "testdb");
+        assert!(config.startup_params().contains(&(
+            "application_name".to_string(),
+            DEFAULT_APPLICATION_NAME.to_string()
+        )));
+        assert!(DEFAULT_APPLICATION_NAME.starts_with("sqlmodel-postgres "));
+
+        let config = PgConfig {
+            application_name: None,
+            ..config
+        };
+        assert!(
+            !config
+                .startup_params()
+                .iter()
+                .any(|(k, _)| k == "application_name")
+        );
+    }
+
+    #[derive(Debug, Default)]
+    struct RecordingProgress {
+        events: std::sync::Mutex<Vec<String>>,
+    }
+
+    impl ProgressReporter for RecordingProgress {
+        fn on_phase(&self, phase: &str) {
+            self.events.lock().unwrap().push(phase.to_string());
+        }
+
+        fn tick(&self) {
+            self.events.lock().unwrap().push("tick".to_string());
+        }
+    }
+
+    #[test]
+    fn test_progress_reporter() {
+        // Without a reporter these are no-ops.
+        PgConfig::default().report_phase("Connecting");
+
+        let progress = Arc::new(RecordingProgress::default());
+        let config = PgConfig::new("localhost", "user", "db").progress(progress.clone());
+        config.report_phase("Connecting");
+        config.report_tick();
+        config.clone().report_phase("Authenticating");
+        assert_eq!(
+            *progress.events.lock().unwrap(),
+            ["Connecting", "tick", "Authenticating"]
+        );
+    }
+
     #[test]
     fn test_socket_addr() {
         let config = PgConfig::new("db.example.com", "user", "db").port(5433);
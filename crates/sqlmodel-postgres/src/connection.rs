@@ -209,6 +209,7 @@ impl PgConnection {
     #[allow(clippy::result_large_err)]
     pub fn connect(config: PgConfig) -> Result<Self, Error> {
         // 1. TCP connection with timeout
+        config.report_phase("Connecting");
         let stream = TcpStream::connect_timeout(
             &config.socket_addr().parse().map_err(|e| {
                 Error::Connection(ConnectionError {
@@ -253,6 +254,7 @@ impl PgConnection {
 
         // 2. SSL negotiation (if configured)
         if conn.config.ssl_mode.should_try_ssl() {
+            conn.config.report_phase("Negotiating TLS");
             #[cfg(feature = "tls")]
             conn.negotiate_ssl()?;
 
@@ -273,9 +275,11 @@ impl PgConnection {
         conn.state = ConnectionState::Authenticating;
 
         // 4. Handle authentication
+        conn.config.report_phase("Authenticating");
         conn.handle_auth()?;
 
         // 5. Read remaining startup messages until ReadyForQuery
+        conn.config.report_phase("Starting session");
         conn.read_startup_messages()?;
 
         Ok(conn)
@@ -577,9 +581,8 @@ impl PgConnection {
                     self.process_id = process_id;
                     self.secret_key = secret_key;
                 }
-                BackendMessage::ParameterStatus { name, value } => {
-                    self.parameters.insert(name, value);
-                }
+                // Recorded by `receive_message`.
+                BackendMessage::ParameterStatus { .. } => {}
                 BackendMessage::ReadyForQuery(status) => {
                     self.state = ConnectionState::Ready(status.into());
                     return Ok(());
@@ -623,7 +626,13 @@ impl PgConnection {
         // Try to parse any complete messages from buffer first
         loop {
             match self.reader.next_message() {
-                Ok(Some(msg)) => return Ok(msg),
+                Ok(Some(msg)) => {
+                    // Parameter changes can arrive at any time, not just at startup.
+                    if let BackendMessage::ParameterStatus { name, value } = &msg {
+                        self.parameters.insert(name.clone(), value.clone());
+                    }
+                    return Ok(msg);
+                }
                 Ok(None) => {
                     // Need more data
                     let n = self.stream.read(&mut self.read_buf).map_err(|e| {
@@ -650,14 +659,12 @@ impl PgConnection {
                         }));
                     }
 
-                    // Feed data to reader
-                    self.reader.feed(&self.read_buf[..n]).map_err(|e| {
-                        Error::Protocol(ProtocolError {
-                            message: format!("Protocol error: {}", e),
-                            raw_data: None,
-                            source: None,
-                        })
-                    })?;
+                    // Only buffer the bytes; the next_message() call at the top
+                    // of the loop parses them. feed() would parse and return
+                    // every complete message here, and this loop would drop
+                    // all of them.
+                    self.reader.push(&self.read_buf[..n]);
+                    self.config.report_tick();
                 }
                 Err(e) => {
                     self.state = ConnectionState::Error;
@@ -850,6 +857,11 @@ fn error_from_fields(fields: &ErrorFields) -> Error {
         message: fields.message.clone(),
         detail: fields.detail.clone(),
         hint: fields.hint.clone(),
+        schema: fields.schema.clone(),
+        table: fields.table.clone(),
+        column: fields.column.clone(),
+        constraint: fields.constraint.clone(),
+        where_: fields.where_.clone(),
         position: fields.position.map(|p| p as usize),
         source: None,
     })
@@ -889,10 +901,19 @@ mod tests {
             severity: "ERROR".to_string(),
             code: "23505".to_string(),
             message: "unique violation".to_string(),
+            schema: Some("public".to_string()),
+            table: Some("users".to_string()),
+            constraint: Some("users_email_key".to_string()),
             ..Default::default()
         };
-        let err = error_from_fields(&fields);
-        assert!(matches!(err, Error::Query(q) if q.kind == QueryErrorKind::Constraint));
+        let Error::Query(q) = error_from_fields(&fields) else {
+            panic!("expected a query error");
+        };
+        assert_eq!(q.kind, QueryErrorKind::Constraint);
+        assert_eq!(q.schema.as_deref(), Some("public"));
+        assert_eq!(q.table.as_deref(), Some("users"));
+        assert_eq!(q.column, None);
+        assert!(q.is_constraint("users_email_key"));
 
         let fields = ErrorFields {
             severity: "FATAL".to_string(),
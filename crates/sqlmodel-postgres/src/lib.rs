@@ -47,12 +47,16 @@ pub mod auth;
 pub mod config;
 pub mod connection;
 pub mod protocol;
+pub mod statement_cache;
 pub mod tls;
 pub mod types;
 
-pub use async_connection::{PgAsyncConnection, SharedPgConnection, SharedPgTransaction};
-pub use config::{PgConfig, SslMode};
+pub use async_connection::{
+    CopyOutStream, PgAsyncConnection, SharedPgConnection, SharedPgTransaction, StateListener,
+};
+pub use config::{DEFAULT_APPLICATION_NAME, PgConfig, SslMode};
 pub use connection::{ConnectionState, PgConnection, TransactionStatusState};
+pub use protocol::{CommandKind, CommandTag};
 pub use types::{Format, TypeCategory, TypeInfo, TypeRegistry};
 
 // Console integration (feature-gated)
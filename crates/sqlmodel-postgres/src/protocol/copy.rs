@@ -0,0 +1,284 @@
+//! Binary COPY format decoding.
+//!
+//! `COPY ... TO STDOUT (FORMAT binary)` streams a file-like payload split
+//! across `CopyData` messages. Message boundaries are not guaranteed to line
+//! up with tuple boundaries, so the decoder buffers input and only yields a
+//! row once all of its bytes have arrived.
+//!
+//! # Format
+//!
+//! ```text
+//! Header:  "PGCOPY\n\xff\r\n\0" | flags: i32 | ext_len: i32 | ext bytes
+//! Tuple:   field_count: i16 | (len: i32, bytes)*   (len = -1 for NULL)
+//! Trailer: field_count = -1
+//! ```
+
+use std::sync::Arc;
+
+use sqlmodel_core::error::ProtocolError;
+use sqlmodel_core::row::ColumnInfo;
+use sqlmodel_core::{Error, Row, Value};
+
+use crate::types::{Format, decode_value};
+
+/// Signature that opens every binary COPY stream.
+pub const BINARY_COPY_SIGNATURE: &[u8; 11] = b"PGCOPY\n\xff\r\n\0";
+
+/// Incremental decoder for the binary COPY format.
+///
+/// Feed raw `CopyData` payloads with [`push`](Self::push) and pull complete
+/// rows with [`next_row`](Self::next_row). Values are decoded using the
+/// column type OIDs supplied at construction, since the binary COPY stream
+/// itself carries no type information.
+#[derive(Debug)]
+pub struct BinaryCopyDecoder {
+    columns: Arc<ColumnInfo>,
+    type_oids: Vec<u32>,
+    buf: Vec<u8>,
+    pos: usize,
+    header_done: bool,
+    trailer_seen: bool,
+}
+
+impl BinaryCopyDecoder {
+    /// Create a decoder for columns given as `(name, type_oid)` pairs in COPY order.
+    pub fn new(columns: &[(&str, u32)]) -> Self {
+        let names = columns
+            .iter()
+            .map(|(name, _)| (*name).to_string())
+            .collect();
+        Self {
+            columns: Arc::new(ColumnInfo::new(names)),
+            type_oids: columns.iter().map(|(_, oid)| *oid).collect(),
+            buf: Vec::new(),
+            pos: 0,
+            header_done: false,
+            trailer_seen: false,
+        }
+    }
+
+    /// Append a `CopyData` payload to the decode buffer.
+    pub fn push(&mut self, data: &[u8]) {
+        // Drop already-decoded bytes so the buffer only holds a partial tuple.
+        if self.pos > 0 {
+            self.buf.drain(..self.pos);
+            self.pos = 0;
+        }
+        self.buf.extend_from_slice(data);
+    }
+
+    /// Whether the end-of-data trailer has been decoded.
+    pub fn is_finished(&self) -> bool {
+        self.trailer_seen
+    }
+
+    /// Decode the next complete row, or `None` if more data is needed
+    /// (or the trailer has been reached).
+    pub fn next_row(&mut self) -> Result<Option<Row>, Error> {
+        if !self.header_done && !self.read_header()? {
+            return Ok(None);
+        }
+        if self.trailer_seen {
+            return Ok(None);
+        }
+
+        let start = self.pos;
+        let Some(field_count) = self.peek_i16(start) else {
+            return Ok(None);
+        };
+        if field_count == -1 {
+            self.pos = start + 2;
+            self.trailer_seen = true;
+            return Ok(None);
+        }
+        if usize::try_from(field_count).ok() != Some(self.type_oids.len()) {
+            return Err(copy_error(format!(
+                "binary COPY tuple has {field_count} fields, expected {}",
+                self.type_oids.len()
+            )));
+        }
+
+        // Locate every field before decoding so a partial tuple leaves the
+        // buffer untouched.
+        let mut spans = Vec::with_capacity(self.type_oids.len());
+        let mut cursor = start + 2;
+        for _ in 0..self.type_oids.len() {
+            let Some(len) = self.peek_i32(cursor) else {
+                return Ok(None);
+            };
+            cursor += 4;
+            if len == -1 {
+                spans.push(None);
+                continue;
+            }
+            let Ok(len) = usize::try_from(len) else {
+                return Err(copy_error(format!(
+                    "invalid binary COPY field length {len}"
+                )));
+            };
+            if self.buf.len() < cursor + len {
+                return Ok(None);
+            }
+            spans.push(Some(cursor..cursor + len));
+            cursor += len;
+        }
+
+        let mut values = Vec::with_capacity(spans.len());
+        for (span, &oid) in spans.into_iter().zip(&self.type_oids) {
+            values.push(decode_value(
+                oid,
+                span.map(|r| &self.buf[r]),
+                Format::Binary,
+            )?);
+        }
+        self.pos = cursor;
+
+        Ok(Some(Row::with_columns(Arc::clone(&self.columns), values)))
+    }
+
+    /// Verify that the stream ended cleanly with a trailer and no leftover bytes.
+    pub fn finish(&self) -> Result<(), Error> {
+        if !self.trailer_seen {
+            return Err(copy_error("binary COPY stream ended without trailer"));
+        }
+        if self.pos != self.buf.len() {
+            return Err(copy_error("unexpected data after binary COPY trailer"));
+        }
+        Ok(())
+    }
+
+    fn read_header(&mut self) -> Result<bool, Error> {
+        let fixed = BINARY_COPY_SIGNATURE.len() + 8;
+        if self.buf.len() < self.pos + fixed {
+            return Ok(false);
+        }
+        let sig_end = self.pos + BINARY_COPY_SIGNATURE.len();
+        if &self.buf[self.pos..sig_end] != BINARY_COPY_SIGNATURE {
+            return Err(copy_error("invalid binary COPY signature"));
+        }
+        let Some(ext_len) = self.peek_i32(sig_end + 4) else {
+            return Ok(false);
+        };
+        let Ok(ext_len) = usize::try_from(ext_len) else {
+            return Err(copy_error("invalid binary COPY header extension length"));
+        };
+        let header_end = self.pos + fixed + ext_len;
+        if self.buf.len() < header_end {
+            return Ok(false);
+        }
+        self.pos = header_end;
+        self.header_done = true;
+        Ok(true)
+    }
+
+    fn peek_i16(&self, at: usize) -> Option<i16> {
+        let bytes = self.buf.get(at..at + 2)?;
+        Some(i16::from_be_bytes([bytes[0], bytes[1]]))
+    }
+
+    fn peek_i32(&self, at: usize) -> Option<i32> {
+        let bytes = self.buf.get(at..at + 4)?;
+        Some(i32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+}
+
+fn copy_error(msg: impl Into<String>) -> Error {
+    Error::Protocol(ProtocolError {
+        message: msg.into(),
+        raw_data: None,
+        source: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::oid;
+
+    fn sample_stream() -> Vec<u8> {
+        let mut data = Vec::new();
+        data.extend_from_slice(BINARY_COPY_SIGNATURE);
+        data.extend_from_slice(&0i32.to_be_bytes());
+        data.extend_from_slice(&2i32.to_be_bytes());
+        data.extend_from_slice(&[0xAA, 0xBB]);
+
+        // (1, 'alice')
+        data.extend_from_slice(&2i16.to_be_bytes());
+        data.extend_from_slice(&4i32.to_be_bytes());
+        data.extend_from_slice(&1i32.to_be_bytes());
+        data.extend_from_slice(&5i32.to_be_bytes());
+        data.extend_from_slice(b"alice");
+
+        // (2, NULL)
+        data.extend_from_slice(&2i16.to_be_bytes());
+        data.extend_from_slice(&4i32.to_be_bytes());
+        data.extend_from_slice(&2i32.to_be_bytes());
+        data.extend_from_slice(&(-1i32).to_be_bytes());
+
+        data.extend_from_slice(&(-1i16).to_be_bytes());
+        data
+    }
+
+    fn decoder() -> BinaryCopyDecoder {
+        BinaryCopyDecoder::new(&[("id", oid::INT4), ("name", oid::TEXT)])
+    }
+
+    #[test]
+    fn test_decode_whole_stream() {
+        let mut dec = decoder();
+        dec.push(&sample_stream());
+
+        let first = dec.next_row().unwrap().unwrap();
+        assert_eq!(first.get_named::<i32>("id").unwrap(), 1);
+        assert_eq!(first.get_named::<String>("name").unwrap(), "alice");
+
+        let second = dec.next_row().unwrap().unwrap();
+        assert_eq!(second.get(0), Some(&Value::Int(2)));
+        assert_eq!(second.get(1), Some(&Value::Null));
+
+        assert!(dec.next_row().unwrap().is_none());
+        assert!(dec.is_finished());
+        dec.finish().unwrap();
+    }
+
+    #[test]
+    fn test_decode_byte_at_a_time() {
+        let mut dec = decoder();
+        let mut rows = Vec::new();
+        for byte in sample_stream() {
+            dec.push(&[byte]);
+            while let Some(row) = dec.next_row().unwrap() {
+                rows.push(row);
+            }
+        }
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[1].get(0), Some(&Value::Int(2)));
+        dec.finish().unwrap();
+    }
+
+    #[test]
+    fn test_invalid_signature() {
+        let mut dec = decoder();
+        let mut data = sample_stream();
+        data[0] = b'X';
+        dec.push(&data);
+        assert!(dec.next_row().is_err());
+    }
+
+    #[test]
+    fn test_field_count_mismatch() {
+        let mut dec = BinaryCopyDecoder::new(&[("id", oid::INT4)]);
+        dec.push(&sample_stream());
+        assert!(dec.next_row().is_err());
+    }
+
+    #[test]
+    fn test_finish_without_trailer() {
+        let mut dec = decoder();
+        let data = sample_stream();
+        dec.push(&data[..data.len() - 2]);
+        while dec.next_row().unwrap().is_some() {}
+        assert!(!dec.is_finished());
+        assert!(dec.finish().is_err());
+    }
+}
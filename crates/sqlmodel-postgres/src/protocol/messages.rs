@@ -431,6 +431,69 @@ impl fmt::Display for ErrorFields {
     }
 }
 
+/// Kind of statement a `CommandComplete` tag reports.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CommandKind {
+    Insert,
+    Update,
+    Delete,
+    Merge,
+    Select,
+    Move,
+    Fetch,
+    Copy,
+    /// Any other tag (`CREATE TABLE`, `BEGIN`, `SET`, ...), verbatim without a row count.
+    Other(String),
+}
+
+impl CommandKind {
+    /// Whether the statement modifies rows (INSERT, UPDATE, DELETE or MERGE).
+    pub fn is_dml(&self) -> bool {
+        matches!(
+            self,
+            CommandKind::Insert | CommandKind::Update | CommandKind::Delete | CommandKind::Merge
+        )
+    }
+}
+
+/// Parsed `CommandComplete` tag, e.g. `INSERT 0 5` or `UPDATE 3`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommandTag {
+    /// Statement kind.
+    pub kind: CommandKind,
+    /// Rows affected or returned; 0 for tags without a count.
+    pub rows: u64,
+}
+
+impl CommandTag {
+    /// Parse a command tag as sent by the server.
+    ///
+    /// INSERT tags carry a legacy OID field (always 0 since PostgreSQL 12)
+    /// before the row count; it is skipped.
+    pub fn parse(tag: &str) -> Self {
+        let mut words: Vec<&str> = tag.split_whitespace().collect();
+        let rows = match words.last().and_then(|w| w.parse::<u64>().ok()) {
+            Some(rows) => {
+                words.pop();
+                rows
+            }
+            None => 0,
+        };
+        let kind = match words.as_slice() {
+            ["INSERT", ..] => CommandKind::Insert,
+            ["UPDATE"] => CommandKind::Update,
+            ["DELETE"] => CommandKind::Delete,
+            ["MERGE"] => CommandKind::Merge,
+            ["SELECT"] => CommandKind::Select,
+            ["MOVE"] => CommandKind::Move,
+            ["FETCH"] => CommandKind::Fetch,
+            ["COPY"] => CommandKind::Copy,
+            _ => CommandKind::Other(words.join(" ")),
+        };
+        Self { kind, rows }
+    }
+}
+
 // ==================== Message Type Bytes ====================
 
 /// Message type bytes for frontend messages.
@@ -512,6 +575,28 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_command_tag_parse() {
+        let insert = CommandTag::parse("INSERT 0 5");
+        assert_eq!(insert.kind, CommandKind::Insert);
+        assert_eq!(insert.rows, 5);
+        assert!(insert.kind.is_dml());
+
+        let update = CommandTag::parse("UPDATE 0");
+        assert_eq!(update.kind, CommandKind::Update);
+        assert_eq!(update.rows, 0);
+
+        assert_eq!(CommandTag::parse("DELETE 2").kind, CommandKind::Delete);
+        assert_eq!(CommandTag::parse("SELECT 7").rows, 7);
+        assert!(!CommandTag::parse("SELECT 7").kind.is_dml());
+        assert_eq!(CommandTag::parse("COPY 100").kind, CommandKind::Copy);
+
+        let create = CommandTag::parse("CREATE TABLE");
+        assert_eq!(create.kind, CommandKind::Other("CREATE TABLE".to_string()));
+        assert_eq!(create.rows, 0);
+        assert!(!create.kind.is_dml());
+    }
+
     #[test]
     fn test_describe_kind_roundtrip() {
         for kind in [DescribeKind::Statement, DescribeKind::Portal] {
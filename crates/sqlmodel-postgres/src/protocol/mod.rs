@@ -25,10 +25,12 @@
 //!
 //! No type byte for startup message.
 
+mod copy;
 mod messages;
 mod reader;
 mod writer;
 
+pub use copy::{BINARY_COPY_SIGNATURE, BinaryCopyDecoder};
 pub use messages::*;
 pub use reader::MessageReader;
 pub use writer::MessageWriter;
@@ -100,6 +100,9 @@ impl MessageReader {
     }
 
     /// Feed bytes into the reader and return any complete messages.
+    ///
+    /// Bytes of a trailing partial message stay buffered, so data may be fed
+    /// in chunks split at any byte, including inside the length prefix.
     pub fn feed(&mut self, data: &[u8]) -> Result<Vec<BackendMessage>, ProtocolError> {
         self.buf.extend_from_slice(data);
 
@@ -111,6 +114,10 @@ impl MessageReader {
     }
 
     /// Attempt to parse the next message from the internal buffer.
+    ///
+    /// Returns `Ok(None)` without consuming anything until a whole frame is
+    /// buffered, so a caller whose socket read is cancelled can simply push
+    /// the next bytes it receives and call this again.
     pub fn next_message(&mut self) -> Result<Option<BackendMessage>, ProtocolError> {
         if self.buf.len() < 5 {
             return Ok(None);
@@ -595,6 +602,102 @@ mod tests {
         assert_eq!(second.len(), 1);
     }
 
+    /// A short conversation: RowDescription, two DataRows (one with a NULL),
+    /// CommandComplete and ReadyForQuery.
+    fn query_response() -> Vec<u8> {
+        let mut field = b"name\0".to_vec();
+        field.extend_from_slice(&16_384_u32.to_be_bytes());
+        field.extend_from_slice(&2_i16.to_be_bytes());
+        field.extend_from_slice(&25_u32.to_be_bytes());
+        field.extend_from_slice(&(-1_i16).to_be_bytes());
+        field.extend_from_slice(&(-1_i32).to_be_bytes());
+        field.extend_from_slice(&0_i16.to_be_bytes());
+        let mut row_desc = 1_i16.to_be_bytes().to_vec();
+        row_desc.extend_from_slice(&field);
+
+        let data_row = |value: Option<&[u8]>| {
+            let mut payload = 1_i16.to_be_bytes().to_vec();
+            match value {
+                Some(v) => {
+                    payload.extend_from_slice(&i32::try_from(v.len()).unwrap().to_be_bytes());
+                    payload.extend_from_slice(v);
+                }
+                None => payload.extend_from_slice(&(-1_i32).to_be_bytes()),
+            }
+            build_message(backend_type::DATA_ROW, &payload)
+        };
+
+        let mut stream = build_message(backend_type::ROW_DESCRIPTION, &row_desc);
+        stream.extend(data_row(Some(b"a somewhat longer text value")));
+        stream.extend(data_row(None));
+        stream.extend(build_message(backend_type::COMMAND_COMPLETE, b"SELECT 2\0"));
+        stream.extend(build_message(
+            backend_type::READY_FOR_QUERY,
+            &[TransactionStatus::Idle.as_byte()],
+        ));
+        stream
+    }
+
+    #[test]
+    fn reader_byte_at_a_time_matches_whole_feed() {
+        let stream = query_response();
+        let whole = MessageReader::new().feed(&stream).unwrap();
+        assert_eq!(whole.len(), 5);
+
+        let mut reader = MessageReader::new();
+        let mut trickled = Vec::new();
+        for byte in &stream {
+            trickled.extend(reader.feed(std::slice::from_ref(byte)).unwrap());
+        }
+        assert_eq!(trickled, whole);
+        assert_eq!(reader.buffered_len(), 0);
+
+        // Same through push + next_message, the path the async connection uses.
+        let mut reader = MessageReader::new();
+        let mut pulled = Vec::new();
+        for byte in &stream {
+            reader.push(std::slice::from_ref(byte));
+            while let Some(msg) = reader.next_message().unwrap() {
+                pulled.push(msg);
+            }
+        }
+        assert_eq!(pulled, whole);
+    }
+
+    #[test]
+    fn reader_resumes_across_every_split() {
+        let stream = query_response();
+        let whole = MessageReader::new().feed(&stream).unwrap();
+
+        // Two cut points cover splits inside the type byte, the length
+        // prefix and the payload, and a DataRow spread over three reads.
+        for first in 0..=stream.len() {
+            for second in first..=stream.len() {
+                let mut reader = MessageReader::new();
+                let mut messages = reader.feed(&stream[..first]).unwrap();
+                messages.extend(reader.feed(&stream[first..second]).unwrap());
+                messages.extend(reader.feed(&stream[second..]).unwrap());
+                assert_eq!(messages, whole, "split at {first} and {second}");
+            }
+        }
+    }
+
+    #[test]
+    fn reader_keeps_partial_length_prefix() {
+        let msg = build_message(backend_type::COMMAND_COMPLETE, b"SELECT 1\0");
+        let mut reader = MessageReader::new();
+        assert!(reader.feed(&msg[..3]).unwrap().is_empty());
+        assert_eq!(reader.buffered_len(), 3);
+        assert!(reader.next_message().unwrap().is_none());
+        assert_eq!(reader.buffered_len(), 3);
+
+        let messages = reader.feed(&msg[3..]).unwrap();
+        assert_eq!(
+            messages,
+            vec![BackendMessage::CommandComplete("SELECT 1".to_string())]
+        );
+    }
+
     #[test]
     fn parse_row_description_negative_count_rejected() {
         // ROW_DESCRIPTION with negative field count (-1)
@@ -0,0 +1,216 @@
+//! Bounded LRU cache of server-side prepared statements.
+//!
+//! [`PgAsyncConnection`](crate::PgAsyncConnection) keys this cache by SQL
+//! text so repeated `query`/`execute` calls reuse the plan prepared the first
+//! time instead of re-parsing on every call. The cache only does the
+//! bookkeeping; the connection is responsible for issuing `DEALLOCATE` for
+//! whatever [`StatementCache::evict_if_full`] evicts.
+
+use std::collections::HashMap;
+
+/// Statements longer than this many bytes are never cached.
+///
+/// Very long SQL is usually generated (large `IN` lists, bulk `VALUES`) and
+/// rarely repeats verbatim, so caching it would only churn the cache.
+pub const MAX_CACHED_SQL_LEN: usize = 4096;
+
+/// Whether `sql` is a plannable statement (`SELECT`, `INSERT`, `UPDATE`,
+/// `DELETE`, `WITH`, `VALUES`) rather than a utility command.
+pub fn is_plannable(sql: &str) -> bool {
+    let keyword = sql
+        .trim_start()
+        .split(|c: char| !c.is_ascii_alphabetic())
+        .next()
+        .unwrap_or("");
+    ["SELECT", "INSERT", "UPDATE", "DELETE", "WITH", "VALUES"]
+        .iter()
+        .any(|k| keyword.eq_ignore_ascii_case(k))
+}
+
+/// A prepared statement held by the cache.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CachedStatement {
+    /// Server-side statement name.
+    pub name: String,
+    /// Parameter type OIDs the statement was parsed with (0 = server-inferred).
+    pub param_type_oids: Vec<u32>,
+}
+
+impl CachedStatement {
+    /// Whether parameters with the given type OIDs can be bound to this
+    /// statement without a type mismatch.
+    ///
+    /// An OID of 0 on either side (server-inferred type, or a NULL parameter)
+    /// matches anything.
+    pub fn accepts(&self, param_type_oids: &[u32]) -> bool {
+        self.param_type_oids.len() == param_type_oids.len()
+            && self
+                .param_type_oids
+                .iter()
+                .zip(param_type_oids)
+                .all(|(&cached, &given)| cached == 0 || given == 0 || cached == given)
+    }
+}
+
+/// Least-recently-used map from SQL text to [`CachedStatement`].
+#[derive(Debug, Default)]
+pub struct StatementCache {
+    capacity: usize,
+    entries: HashMap<String, (CachedStatement, u64)>,
+    tick: u64,
+}
+
+impl StatementCache {
+    /// Create a cache holding at most `capacity` statements.
+    ///
+    /// A capacity of 0 disables caching.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: HashMap::new(),
+            tick: 0,
+        }
+    }
+
+    /// Whether `sql` is eligible for caching at all.
+    ///
+    /// Only [plannable](is_plannable) statements are cached; utility
+    /// commands such as `BEGIN`, `SET` or `DEALLOCATE` gain nothing from a
+    /// prepared plan.
+    pub fn should_cache(&self, sql: &str) -> bool {
+        self.capacity > 0 && sql.len() <= MAX_CACHED_SQL_LEN && is_plannable(sql)
+    }
+
+    /// Look up `sql`, marking it as most recently used.
+    pub fn get(&mut self, sql: &str) -> Option<&CachedStatement> {
+        self.tick += 1;
+        let tick = self.tick;
+        self.entries.get_mut(sql).map(|(stmt, used)| {
+            *used = tick;
+            &*stmt
+        })
+    }
+
+    /// Remove the least recently used statement if the cache is full.
+    ///
+    /// Call this before preparing a new statement so the returned statement
+    /// can be deallocated on the server first.
+    pub fn evict_if_full(&mut self) -> Option<CachedStatement> {
+        if self.capacity == 0 || self.entries.len() < self.capacity {
+            return None;
+        }
+        let oldest = self
+            .entries
+            .iter()
+            .min_by_key(|(_, (_, used))| *used)
+            .map(|(sql, _)| sql.clone())?;
+        self.entries.remove(&oldest).map(|(stmt, _)| stmt)
+    }
+
+    /// Insert a freshly prepared statement for `sql`.
+    ///
+    /// Returns the least recently used statement if one had to be evicted to
+    /// make room.
+    pub fn insert(&mut self, sql: &str, stmt: CachedStatement) -> Option<CachedStatement> {
+        let evicted = if self.entries.contains_key(sql) {
+            None
+        } else {
+            self.evict_if_full()
+        };
+        self.tick += 1;
+        self.entries.insert(sql.to_string(), (stmt, self.tick));
+        evicted
+    }
+
+    /// Forget the statement cached for `sql`, returning it.
+    ///
+    /// Used when the server rejects a cached statement, e.g. because a schema
+    /// change invalidated its plan.
+    pub fn remove(&mut self, sql: &str) -> Option<CachedStatement> {
+        self.entries.remove(sql).map(|(stmt, _)| stmt)
+    }
+
+    /// Forget every statement (e.g. after `DEALLOCATE ALL`).
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Number of cached statements.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether the cache is empty.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Maximum number of cached statements.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stmt(name: &str) -> CachedStatement {
+        CachedStatement {
+            name: name.to_string(),
+            param_type_oids: vec![20],
+        }
+    }
+
+    #[test]
+    fn test_evicts_least_recently_used() {
+        let mut cache = StatementCache::new(2);
+        assert_eq!(cache.insert("SELECT 1", stmt("s1")), None);
+        assert_eq!(cache.insert("SELECT 2", stmt("s2")), None);
+
+        // Touch s1 so s2 becomes the eviction candidate.
+        assert_eq!(cache.get("SELECT 1").map(|s| s.name.as_str()), Some("s1"));
+
+        let evicted = cache.insert("SELECT 3", stmt("s3"));
+        assert_eq!(evicted.map(|s| s.name), Some("s2".to_string()));
+        assert_eq!(cache.len(), 2);
+        assert!(cache.get("SELECT 2").is_none());
+        assert!(cache.get("SELECT 1").is_some());
+        assert!(cache.get("SELECT 3").is_some());
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut cache = StatementCache::new(2);
+        cache.insert("SELECT 1", stmt("s1"));
+        assert_eq!(
+            cache.remove("SELECT 1").map(|s| s.name),
+            Some("s1".to_string())
+        );
+        assert!(cache.is_empty());
+        assert_eq!(cache.remove("SELECT 1"), None);
+    }
+
+    #[test]
+    fn test_should_cache() {
+        let cache = StatementCache::new(8);
+        assert!(cache.should_cache("SELECT 1"));
+        assert!(cache.should_cache("  insert into t values ($1)"));
+        assert!(!cache.should_cache("BEGIN"));
+        assert!(!cache.should_cache("DEALLOCATE sqlmodel_stmt_1"));
+        assert!(!cache.should_cache(&"x".repeat(MAX_CACHED_SQL_LEN + 1)));
+        assert!(!StatementCache::new(0).should_cache("SELECT 1"));
+    }
+
+    #[test]
+    fn test_accepts_param_types() {
+        let cached = CachedStatement {
+            name: "s".to_string(),
+            param_type_oids: vec![20, 0],
+        };
+        assert!(cached.accepts(&[20, 25]));
+        assert!(cached.accepts(&[0, 25]));
+        assert!(!cached.accepts(&[25, 25]));
+        assert!(!cached.accepts(&[20]));
+    }
+}
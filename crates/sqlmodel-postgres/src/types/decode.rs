@@ -13,7 +13,7 @@ use sqlmodel_core::Error;
 use sqlmodel_core::error::TypeError;
 use sqlmodel_core::value::Value;
 
-use super::encode::Format;
+use super::encode::{Format, NUMERIC_NAN, NUMERIC_NEG, NUMERIC_NINF, NUMERIC_PINF, NUMERIC_POS};
 use super::oid;
 
 /// Decode a value from PostgreSQL text format.
@@ -397,6 +397,111 @@ pub fn parse_timestamp_string(s: &str) -> Result<i64, Error> {
     Ok(i64::from(days) * 86_400 * 1_000_000 + time_micros)
 }
 
+/// Parse a `timestamptz` string into microseconds since the Unix epoch, UTC.
+///
+/// PostgreSQL renders `timestamptz` text in the session `TimeZone` with a
+/// trailing UTC offset (`+09`, `-04:00`, `+05:45:30`); the offset is applied
+/// so the result is the same instant whatever the session time zone. A
+/// missing offset or a `Z` suffix is taken as UTC.
+pub fn parse_timestamptz_string(s: &str) -> Result<i64, Error> {
+    let s = s.trim();
+    let s = s.strip_suffix('Z').unwrap_or(s);
+
+    // The offset sign can only appear after the date and time separator.
+    let time_start = s.find([' ', 'T']).map_or(s.len(), |pos| pos + 1);
+    let Some(sign_pos) = s[time_start..]
+        .rfind(['+', '-'])
+        .map(|pos| time_start + pos)
+    else {
+        return parse_timestamp_string(s);
+    };
+
+    let (local, offset) = s.split_at(sign_pos);
+    let sign: i64 = if offset.starts_with('-') { -1 } else { 1 };
+    let mut offset_secs = 0_i64;
+    let mut unit = 3600_i64;
+    for part in offset[1..].split(':') {
+        if unit == 0 || part.is_empty() || !part.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(type_error("timestamptz", s));
+        }
+        let n: i64 = part.parse().map_err(|_| type_error("timestamptz", s))?;
+        offset_secs += n * unit;
+        unit /= 60;
+    }
+
+    Ok(parse_timestamp_string(local)? - sign * offset_secs * 1_000_000)
+}
+
+// ==================== Numeric ====================
+
+/// Decode a binary `numeric` to its text form.
+///
+/// The result is what PostgreSQL itself prints: digits with the column's
+/// display scale (`-12.340`), or `NaN`, `Infinity` and `-Infinity` for the
+/// special values.
+pub fn decode_numeric_binary(data: &[u8]) -> Result<String, Error> {
+    let invalid = || type_error("numeric", format!("{} bytes of binary numeric", data.len()));
+    let word = |at: usize| -> Result<u16, Error> {
+        data.get(at..at + 2)
+            .map(|b| u16::from_be_bytes([b[0], b[1]]))
+            .ok_or_else(invalid)
+    };
+    let ndigits = usize::from(word(0)?);
+    let weight = i32::from(i16::from_be_bytes(word(2)?.to_be_bytes()));
+    let sign = word(4)?;
+    let dscale = usize::from(word(6)?);
+
+    match sign {
+        NUMERIC_NAN => return Ok("NaN".to_string()),
+        NUMERIC_PINF => return Ok("Infinity".to_string()),
+        NUMERIC_NINF => return Ok("-Infinity".to_string()),
+        NUMERIC_POS | NUMERIC_NEG => {}
+        _ => return Err(invalid()),
+    }
+    if data.len() != 8 + 2 * ndigits {
+        return Err(invalid());
+    }
+    let digits: Vec<u16> = (0..ndigits)
+        .map(|i| word(8 + 2 * i))
+        .collect::<Result<_, _>>()?;
+    if digits.iter().any(|d| *d > 9999) {
+        return Err(invalid());
+    }
+    // Base-10000 digit `i` has weight `weight - i`; missing digits are zero.
+    let digit = |i: i32| -> u16 {
+        usize::try_from(i)
+            .ok()
+            .and_then(|i| digits.get(i))
+            .copied()
+            .unwrap_or(0)
+    };
+
+    let mut out = String::new();
+    if sign == NUMERIC_NEG {
+        out.push('-');
+    }
+    if weight < 0 {
+        out.push('0');
+    } else {
+        out.push_str(&digit(0).to_string());
+        for i in 1..=weight {
+            out.push_str(&format!("{:04}", digit(i)));
+        }
+    }
+    if dscale > 0 {
+        let mut frac = String::with_capacity(dscale + 3);
+        let mut i = weight + 1;
+        while frac.len() < dscale {
+            frac.push_str(&format!("{:04}", digit(i)));
+            i += 1;
+        }
+        frac.truncate(dscale);
+        out.push('.');
+        out.push_str(&frac);
+    }
+    Ok(out)
+}
+
 // ==================== Value Decoding ====================
 
 /// Decode a PostgreSQL value to a dynamic Value.
@@ -450,8 +555,10 @@ pub fn decode_value(type_oid: u32, data: Option<&[u8]>, format: Format) -> Resul
             Ok(Value::Double(f64::decode_text(s)?))
         }
 
-        // Numeric (decimal)
-        (oid::NUMERIC, _) => {
+        // Numeric (decimal); NaN and the infinities arrive as `NaN`,
+        // `Infinity` and `-Infinity` in either format
+        (oid::NUMERIC, Format::Binary) => Ok(Value::Decimal(decode_numeric_binary(data)?)),
+        (oid::NUMERIC, Format::Text) => {
             let s = std::str::from_utf8(data).map_err(utf8_error)?;
             Ok(Value::Decimal(s.to_string()))
         }
@@ -505,7 +612,7 @@ pub fn decode_value(type_oid: u32, data: Option<&[u8]>, format: Format) -> Resul
         }
         (oid::TIMESTAMPTZ, Format::Text) => {
             let s = std::str::from_utf8(data).map_err(utf8_error)?;
-            Ok(Value::TimestampTz(parse_timestamp_string(s)?))
+            Ok(Value::TimestampTz(parse_timestamptz_string(s)?))
         }
 
         // UUID
@@ -754,4 +861,105 @@ mod tests {
         let value = decode_value(oid::INT4, Some(&[0, 0, 0, 42]), Format::Binary).unwrap();
         assert!(matches!(value, Value::Int(42)));
     }
+
+    #[test]
+    fn test_timestamptz_text_applies_offset_across_dst() {
+        // America/New_York around the 2024 spring-forward and fall-back
+        // transitions, as a non-UTC session `TimeZone` would render them.
+        let cases = [
+            ("2024-03-10 01:59:59-05", 1_710_053_999_000_000),
+            ("2024-03-10 03:00:00-04", 1_710_054_000_000_000),
+            ("2024-11-03 01:30:00-04", 1_730_611_800_000_000),
+            ("2024-11-03 01:30:00-05", 1_730_615_400_000_000),
+            ("2024-03-10 15:59:59+09", 1_710_053_999_000_000),
+            ("2024-03-10 12:44:59+05:45", 1_710_053_999_000_000),
+            ("2024-03-10 06:59:59+00", 1_710_053_999_000_000),
+            ("2024-03-10T06:59:59Z", 1_710_053_999_000_000),
+        ];
+        for (text, utc_micros) in cases {
+            let value = decode_value(oid::TIMESTAMPTZ, Some(text.as_bytes()), Format::Text);
+            assert_eq!(value.unwrap(), Value::TimestampTz(utc_micros), "{text}");
+        }
+        assert!(parse_timestamptz_string("2024-03-10 01:59:59+5:x").is_err());
+
+        // Binary values are already UTC and are not shifted again.
+        let utc_micros = 1_710_054_000_000_000_i64;
+        let pg_micros = utc_micros - PG_EPOCH_OFFSET_MICROS;
+        let binary = decode_value(
+            oid::TIMESTAMPTZ,
+            Some(&pg_micros.to_be_bytes()),
+            Format::Binary,
+        );
+        assert_eq!(binary.unwrap(), Value::TimestampTz(utc_micros));
+    }
+
+    #[test]
+    fn test_numeric_binary_round_trip() {
+        // 'NaN'::numeric and '-12.340'::numeric as PostgreSQL sends them.
+        let nan = [0, 0, 0, 0, 0xC0, 0, 0, 0];
+        assert_eq!(decode_numeric_binary(&nan).unwrap(), "NaN");
+        let negative = [0, 2, 0, 0, 0x40, 0, 0, 3, 0, 12, 0x0D, 0x48];
+        assert_eq!(decode_numeric_binary(&negative).unwrap(), "-12.340");
+        assert!(decode_numeric_binary(&[0, 1, 0, 0]).is_err());
+        assert!(decode_numeric_binary(&[0, 0, 0, 0, 0x12, 0x34, 0, 0]).is_err());
+
+        for text in [
+            "NaN",
+            "Infinity",
+            "-Infinity",
+            "0",
+            "-12.340",
+            "0.0001",
+            "0.00000001",
+            "123456789.5",
+            "10000",
+            "0.50",
+        ] {
+            let original = Value::Decimal(text.to_string());
+            let (buf, type_oid) =
+                crate::types::encode::encode_value(&original, Format::Binary).unwrap();
+            assert_eq!(type_oid, oid::NUMERIC);
+            let decoded = decode_value(type_oid, Some(&buf), Format::Binary).unwrap();
+            assert_eq!(decoded, original, "{text}");
+        }
+        assert!(
+            crate::types::encode::encode_value(&Value::Decimal("1e5".into()), Format::Binary)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_float_special_values_round_trip() {
+        for value in [f64::INFINITY, f64::NEG_INFINITY] {
+            let original = Value::Double(value);
+            for format in [Format::Text, Format::Binary] {
+                let (buf, type_oid) =
+                    crate::types::encode::encode_value(&original, format).unwrap();
+                assert_eq!(
+                    decode_value(type_oid, Some(&buf), format).unwrap(),
+                    original
+                );
+            }
+        }
+        let nan = decode_value(oid::FLOAT8, Some(&f64::NAN.to_be_bytes()), Format::Binary);
+        assert!(matches!(nan, Ok(Value::Double(v)) if v.is_nan()));
+        let nan = decode_value(oid::FLOAT4, Some(b"NaN"), Format::Text);
+        assert!(matches!(nan, Ok(Value::Float(v)) if v.is_nan()));
+    }
+
+    #[test]
+    fn test_timestamp_and_timestamptz_stay_distinct() {
+        let text = b"2024-03-10 01:59:59";
+        let naive = decode_value(oid::TIMESTAMP, Some(text), Format::Text).unwrap();
+        let aware = decode_value(oid::TIMESTAMPTZ, Some(text), Format::Text).unwrap();
+        assert_eq!(naive, Value::Timestamp(1_710_035_999_000_000));
+        assert_eq!(aware, Value::TimestampTz(1_710_035_999_000_000));
+
+        // Round trip through the text encoder (which writes `+00`).
+        let original = Value::TimestampTz(1_730_615_400_123_456);
+        let (buf, type_oid) = crate::types::encode::encode_value(&original, Format::Text).unwrap();
+        assert_eq!(type_oid, oid::TIMESTAMPTZ);
+        let decoded = decode_value(type_oid, Some(&buf), Format::Text).unwrap();
+        assert_eq!(decoded, original);
+    }
 }
@@ -42,6 +42,16 @@ impl Format {
             _ => Format::Text,
         }
     }
+
+    /// The format to send `value` in when `self` is preferred: `self` if
+    /// [`encode_value`] has an encoding for the value in it, text otherwise.
+    #[must_use]
+    pub fn for_value(self, value: &Value) -> Self {
+        match value {
+            Value::Null | Value::TypedNull(_) | Value::Default | Value::Array(_) => Format::Text,
+            _ => self,
+        }
+    }
 }
 
 /// Encode a value to PostgreSQL text format.
@@ -378,6 +388,104 @@ pub fn encode_time_micros(micros_since_midnight: i64) -> i64 {
     micros_since_midnight
 }
 
+// ==================== Numeric ====================
+
+/// Sign word of a binary `numeric`: positive.
+pub(super) const NUMERIC_POS: u16 = 0x0000;
+/// Sign word of a binary `numeric`: negative.
+pub(super) const NUMERIC_NEG: u16 = 0x4000;
+/// Sign word of a binary `numeric`: NaN.
+pub(super) const NUMERIC_NAN: u16 = 0xC000;
+/// Sign word of a binary `numeric`: positive infinity.
+pub(super) const NUMERIC_PINF: u16 = 0xD000;
+/// Sign word of a binary `numeric`: negative infinity.
+pub(super) const NUMERIC_NINF: u16 = 0xF000;
+
+/// Encode the text form of a `numeric` (`-12.340`, `NaN`, `Infinity`,
+/// `-Infinity`) in PostgreSQL's binary layout: digit count, weight, sign and
+/// display scale, then base-10000 digits.
+pub fn encode_numeric_binary(text: &str, buf: &mut Vec<u8>) -> Result<(), Error> {
+    let text = text.trim();
+    let special = match text.to_ascii_lowercase().as_str() {
+        "nan" => Some(NUMERIC_NAN),
+        "infinity" | "+infinity" | "inf" | "+inf" => Some(NUMERIC_PINF),
+        "-infinity" | "-inf" => Some(NUMERIC_NINF),
+        _ => None,
+    };
+    if let Some(sign) = special {
+        buf.extend_from_slice(&[0, 0, 0, 0]);
+        buf.extend_from_slice(&sign.to_be_bytes());
+        buf.extend_from_slice(&[0, 0]);
+        return Ok(());
+    }
+
+    let invalid = || {
+        Error::Type(TypeError {
+            expected: "numeric",
+            actual: format!("invalid value: {text}"),
+            column: None,
+            rust_type: None,
+        })
+    };
+    let (negative, unsigned) = match text.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, text.strip_prefix('+').unwrap_or(text)),
+    };
+    let (int_part, frac_part) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+    if (int_part.is_empty() && frac_part.is_empty())
+        || !int_part
+            .bytes()
+            .chain(frac_part.bytes())
+            .all(|b| b.is_ascii_digit())
+    {
+        return Err(invalid());
+    }
+    let dscale = u16::try_from(frac_part.len()).map_err(|_| invalid())?;
+
+    // Align both parts on 4-digit groups around the decimal point.
+    let int_pad = (4 - int_part.len() % 4) % 4;
+    let frac_pad = (4 - frac_part.len() % 4) % 4;
+    let aligned: Vec<u8> = std::iter::repeat_n(b'0', int_pad)
+        .chain(int_part.bytes())
+        .chain(frac_part.bytes())
+        .chain(std::iter::repeat_n(b'0', frac_pad))
+        .collect();
+    let mut digits: Vec<i16> = aligned
+        .chunks(4)
+        .map(|group| {
+            group
+                .iter()
+                .fold(0i16, |acc, b| acc * 10 + i16::from(b - b'0'))
+        })
+        .collect();
+    let mut weight = i16::try_from((int_part.len() + int_pad) / 4).map_err(|_| invalid())? - 1;
+
+    let leading = digits.iter().take_while(|d| **d == 0).count();
+    digits.drain(..leading);
+    weight -= i16::try_from(leading).map_err(|_| invalid())?;
+    while digits.last() == Some(&0) {
+        digits.pop();
+    }
+    let sign = if negative && !digits.is_empty() {
+        NUMERIC_NEG
+    } else {
+        NUMERIC_POS
+    };
+    if digits.is_empty() {
+        weight = 0;
+    }
+
+    let ndigits = i16::try_from(digits.len()).map_err(|_| invalid())?;
+    buf.extend_from_slice(&ndigits.to_be_bytes());
+    buf.extend_from_slice(&weight.to_be_bytes());
+    buf.extend_from_slice(&sign.to_be_bytes());
+    buf.extend_from_slice(&dscale.to_be_bytes());
+    for digit in digits {
+        buf.extend_from_slice(&digit.to_be_bytes());
+    }
+    Ok(())
+}
+
 // ==================== Optional Values ====================
 
 impl<T: TextEncode> TextEncode for Option<T> {
@@ -408,6 +516,9 @@ pub fn encode_value(value: &Value, format: Format) -> Result<(Vec<u8>, u32), Err
     let mut buf = Vec::new();
     let type_oid = match value {
         Value::Null => return Ok((vec![], oid::UNKNOWN)),
+        Value::TypedNull(sql_type) => {
+            return Ok((vec![], oid::sql_type_oid(sql_type).unwrap_or(oid::UNKNOWN)));
+        }
         Value::Bool(v) => {
             match format {
                 Format::Text => buf.extend(v.encode_text().as_bytes()),
@@ -461,7 +572,10 @@ pub fn encode_value(value: &Value, format: Format) -> Result<(Vec<u8>, u32), Err
             oid::FLOAT8
         }
         Value::Decimal(v) => {
-            buf.extend(v.as_bytes());
+            match format {
+                Format::Text => buf.extend(v.as_bytes()),
+                Format::Binary => encode_numeric_binary(v, &mut buf)?,
+            }
             oid::NUMERIC
         }
         Value::Text(v) => {
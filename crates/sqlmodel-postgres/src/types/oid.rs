@@ -3,6 +3,8 @@
 //! PostgreSQL identifies types by numeric OIDs. This module defines
 //! the well-known OIDs for built-in types.
 
+use sqlmodel_core::SqlType;
+
 /// Boolean type
 pub const BOOL: u32 = 16;
 
@@ -253,6 +255,37 @@ pub const fn array_oid(element_oid: u32) -> Option<u32> {
     }
 }
 
+/// Get the type OID matching a model-level SQL type.
+///
+/// Returns `None` for enum and custom types, whose OIDs are assigned per
+/// database.
+#[must_use]
+pub fn sql_type_oid(sql_type: &SqlType) -> Option<u32> {
+    let type_oid = match sql_type {
+        SqlType::TinyInt | SqlType::SmallInt => INT2,
+        SqlType::Integer => INT4,
+        SqlType::BigInt => INT8,
+        SqlType::Real => FLOAT4,
+        SqlType::Double => FLOAT8,
+        SqlType::Numeric { .. } | SqlType::Decimal { .. } => NUMERIC,
+        SqlType::Boolean => BOOL,
+        SqlType::Char(_) => BPCHAR,
+        SqlType::VarChar(_) => VARCHAR,
+        SqlType::Text => TEXT,
+        SqlType::Binary(_) | SqlType::VarBinary(_) | SqlType::Blob => BYTEA,
+        SqlType::Date => DATE,
+        SqlType::Time => TIME,
+        SqlType::DateTime | SqlType::Timestamp => TIMESTAMP,
+        SqlType::TimestampTz => TIMESTAMPTZ,
+        SqlType::Uuid => UUID,
+        SqlType::Json => JSON,
+        SqlType::JsonB => JSONB,
+        SqlType::Array(element) => return sql_type_oid(element).and_then(array_oid),
+        SqlType::Enum(_) | SqlType::Custom(_) => return None,
+    };
+    Some(type_oid)
+}
+
 /// Check if the OID represents an array type.
 #[must_use]
 pub const fn is_array(type_oid: u32) -> bool {
@@ -356,6 +389,17 @@ mod tests {
         assert!(!is_array(TEXT));
     }
 
+    #[test]
+    fn test_sql_type_oid() {
+        assert_eq!(sql_type_oid(&SqlType::Integer), Some(INT4));
+        assert_eq!(sql_type_oid(&SqlType::VarChar(20)), Some(VARCHAR));
+        assert_eq!(
+            sql_type_oid(&SqlType::Array(Box::new(SqlType::BigInt))),
+            Some(INT8_ARRAY)
+        );
+        assert_eq!(sql_type_oid(&SqlType::Custom("citext")), None);
+    }
+
     #[test]
     fn test_type_names() {
         assert_eq!(type_name(INT4), "int4");
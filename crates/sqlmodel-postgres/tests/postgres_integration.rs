@@ -102,6 +102,11 @@ fn compact_sql_fragment(input: &str) -> String {
         .to_ascii_lowercase()
 }
 
+async fn show_setting(conn: &SharedPgConnection, cx: &Cx, name: &str) -> String {
+    let rows = unwrap_outcome(conn.query(cx, &format!("SHOW {name}"), &[]).await);
+    rows[0].get_as::<String>(0).expect("SHOW result as text")
+}
+
 fn unique_suffix() -> u128 {
     SystemTime::now()
         .duration_since(UNIX_EPOCH)
@@ -134,6 +139,54 @@ fn postgres_connect_select_1() {
     });
 }
 
+#[test]
+fn postgres_numeric_and_float_special_values() {
+    let Some(cfg) = postgres_test_config() else {
+        eprintln!("skipping Postgres integration tests: set {POSTGRES_URL_ENV}");
+        return;
+    };
+
+    let rt = RuntimeBuilder::current_thread()
+        .build()
+        .expect("create asupersync runtime");
+    let cx = Cx::for_testing();
+
+    rt.block_on(async {
+        let conn = unwrap_outcome(SharedPgConnection::connect(&cx, cfg).await);
+        let rows = unwrap_outcome(
+            conn.query(
+                &cx,
+                "SELECT 'NaN'::numeric, 'Infinity'::float8, '-Infinity'::float8, 'NaN'::float8",
+                &[],
+            )
+            .await,
+        );
+        assert_eq!(rows[0].get(0), Some(&Value::Decimal("NaN".into())));
+        assert_eq!(rows[0].get_as::<f64>(1).expect("infinity"), f64::INFINITY);
+        assert_eq!(
+            rows[0].get_as::<f64>(2).expect("-infinity"),
+            f64::NEG_INFINITY
+        );
+        assert!(rows[0].get_as::<f64>(3).expect("nan").is_nan());
+
+        // The same values survive a round trip as parameters.
+        let rows = unwrap_outcome(
+            conn.query(
+                &cx,
+                "SELECT $1::numeric, $2::float8, $1::numeric = 'NaN'::numeric",
+                &[
+                    Value::Decimal("NaN".into()),
+                    Value::Double(f64::NEG_INFINITY),
+                ],
+            )
+            .await,
+        );
+        assert_eq!(rows[0].get(0), Some(&Value::Decimal("NaN".into())));
+        assert_eq!(rows[0].get(1), Some(&Value::Double(f64::NEG_INFINITY)));
+        assert_eq!(rows[0].get(2), Some(&Value::Bool(true)));
+    });
+}
+
 #[test]
 fn postgres_insert_and_select_roundtrip() {
     let Some(cfg) = postgres_test_config() else {
@@ -226,6 +279,50 @@ fn postgres_transaction_rollback_discards_changes() {
     });
 }
 
+#[test]
+fn postgres_reset_reverts_session_state() {
+    let Some(cfg) = postgres_test_config() else {
+        eprintln!("skipping Postgres integration tests: set {POSTGRES_URL_ENV}");
+        return;
+    };
+
+    let rt = RuntimeBuilder::current_thread()
+        .build()
+        .expect("create asupersync runtime");
+    let cx = Cx::for_testing();
+
+    rt.block_on(async {
+        let conn = unwrap_outcome(SharedPgConnection::connect(&cx, cfg).await);
+
+        let original = show_setting(&conn, &cx, "statement_timeout").await;
+        unwrap_outcome(
+            conn.execute(&cx, "SET statement_timeout = '4321ms'", &[])
+                .await,
+        );
+        assert_eq!(
+            show_setting(&conn, &cx, "statement_timeout").await,
+            "4321ms"
+        );
+
+        // Leave a transaction and a prepared statement behind as well.
+        unwrap_outcome(conn.execute(&cx, "BEGIN", &[]).await);
+        let stmt = unwrap_outcome(conn.prepare(&cx, "SELECT 1").await);
+
+        unwrap_outcome(conn.reset(&cx).await);
+
+        assert_eq!(
+            show_setting(&conn, &cx, "statement_timeout").await,
+            original
+        );
+        assert!(matches!(
+            conn.query_prepared(&cx, &stmt, &[]).await,
+            Outcome::Err(_)
+        ));
+        // Not in a transaction anymore, so COMMIT only warns.
+        unwrap_outcome(conn.execute(&cx, "COMMIT", &[]).await);
+    });
+}
+
 #[test]
 fn postgres_unique_violation_maps_to_constraint() {
     let Some(cfg) = postgres_test_config() else {
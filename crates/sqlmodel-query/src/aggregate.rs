@@ -0,0 +1,353 @@
+//! Aggregate queries with GROUP BY and HAVING.
+//!
+//! [`Aggregate`] selects grouping columns and aggregate expressions from a
+//! model's table. The result shape differs from the model, so it returns raw
+//! [`Row`]s; aggregates are read back by their alias.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use sqlmodel_query::{Aggregate, Expr};
+//!
+//! let rows = Aggregate::<Hero>::new()
+//!     .column("team_id")
+//!     .count("heroes")
+//!     .avg(Hero::age, "avg_age")
+//!     .group_by(&["team_id"])
+//!     .having(Expr::count_star().gt(2))
+//!     .order_by(Expr::col("team_id").asc())
+//!     .all(cx, &conn)
+//!     .await?;
+//! // SELECT team_id, COUNT(*) AS "heroes", AVG("age") AS "avg_age" FROM heroes
+//! //     GROUP BY team_id HAVING COUNT(*) > $1 ORDER BY "team_id" ASC
+//!
+//! for row in &rows {
+//!     let avg: f64 = row.get_named("avg_age")?;
+//! }
+//! ```
+
+use crate::clause::{OrderBy, Where};
+use crate::expr::{Dialect, Expr};
+use asupersync::{Cx, Outcome};
+use sqlmodel_core::{Connection, Field, Model, Row, Value};
+use std::marker::PhantomData;
+
+/// One entry of the select list.
+#[derive(Debug, Clone)]
+enum AggregateItem {
+    /// A plain column, which must also be grouped by.
+    Column(String),
+    /// An aggregate expression and its output alias.
+    Aggregate { expr: Expr, alias: String },
+}
+
+/// A SELECT of grouping columns and aggregates over `M`'s table.
+#[derive(Debug, Clone)]
+pub struct Aggregate<M: Model> {
+    items: Vec<AggregateItem>,
+    where_clause: Option<Where>,
+    group_by: Vec<String>,
+    having: Option<Where>,
+    order_by: Vec<OrderBy>,
+    limit: Option<u64>,
+    offset: Option<u64>,
+    _marker: PhantomData<M>,
+}
+
+impl<M: Model> Aggregate<M> {
+    /// Create an empty aggregate query for the model's table.
+    pub fn new() -> Self {
+        Self {
+            items: Vec::new(),
+            where_clause: None,
+            group_by: Vec::new(),
+            having: None,
+            order_by: Vec::new(),
+            limit: None,
+            offset: None,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Select a plain column. It must also appear in [`group_by`](Self::group_by).
+    pub fn column(mut self, col: &str) -> Self {
+        self.items.push(AggregateItem::Column(col.to_string()));
+        self
+    }
+
+    /// Select an arbitrary aggregate expression as `alias`.
+    pub fn aggregate(mut self, expr: Expr, alias: &str) -> Self {
+        self.items.push(AggregateItem::Aggregate {
+            expr,
+            alias: alias.to_string(),
+        });
+        self
+    }
+
+    /// Select `COUNT(*)` as `alias`.
+    pub fn count(self, alias: &str) -> Self {
+        self.aggregate(Expr::count_star(), alias)
+    }
+
+    /// Select `COUNT(field)`, the number of non-NULL values, as `alias`.
+    pub fn count_field<T>(self, field: Field<T>, alias: &str) -> Self {
+        self.aggregate(Expr::from(field).count(), alias)
+    }
+
+    /// Select `SUM(field)` as `alias`.
+    pub fn sum<T>(self, field: Field<T>, alias: &str) -> Self {
+        self.aggregate(Expr::from(field).sum(), alias)
+    }
+
+    /// Select `AVG(field)` as `alias`.
+    pub fn avg<T>(self, field: Field<T>, alias: &str) -> Self {
+        self.aggregate(Expr::from(field).avg(), alias)
+    }
+
+    /// Select `MIN(field)` as `alias`.
+    pub fn min<T>(self, field: Field<T>, alias: &str) -> Self {
+        self.aggregate(Expr::from(field).min(), alias)
+    }
+
+    /// Select `MAX(field)` as `alias`.
+    pub fn max<T>(self, field: Field<T>, alias: &str) -> Self {
+        self.aggregate(Expr::from(field).max(), alias)
+    }
+
+    /// Add a WHERE condition, applied before grouping.
+    pub fn filter(mut self, expr: Expr) -> Self {
+        self.where_clause = Some(match self.where_clause {
+            Some(existing) => existing.and(expr),
+            None => Where::new(expr),
+        });
+        self
+    }
+
+    /// Add GROUP BY columns.
+    pub fn group_by(mut self, cols: &[&str]) -> Self {
+        self.group_by.extend(cols.iter().map(|&s| s.to_string()));
+        self
+    }
+
+    /// Add a HAVING condition, applied to each group.
+    pub fn having(mut self, expr: Expr) -> Self {
+        self.having = Some(match self.having {
+            Some(existing) => existing.and(expr),
+            None => Where::new(expr),
+        });
+        self
+    }
+
+    /// Add ORDER BY clause.
+    pub fn order_by(mut self, order: OrderBy) -> Self {
+        self.order_by.push(order);
+        self
+    }
+
+    /// Set LIMIT.
+    pub fn limit(mut self, n: u64) -> Self {
+        self.limit = Some(n);
+        self
+    }
+
+    /// Set OFFSET.
+    pub fn offset(mut self, n: u64) -> Self {
+        self.offset = Some(n);
+        self
+    }
+
+    /// Build the SQL query and parameters with default dialect (Postgres).
+    #[allow(clippy::result_large_err)]
+    pub fn build(&self) -> Result<(String, Vec<Value>), sqlmodel_core::Error> {
+        self.build_with_dialect(Dialect::default())
+    }
+
+    /// Build the SQL query and parameters with a specific dialect.
+    ///
+    /// Errors if nothing is selected, or if a plain column is selected
+    /// without being grouped by (which most databases reject, and SQLite
+    /// answers with an arbitrary row's value).
+    #[allow(clippy::result_large_err)]
+    pub fn build_with_dialect(
+        &self,
+        dialect: Dialect,
+    ) -> Result<(String, Vec<Value>), sqlmodel_core::Error> {
+        if self.items.is_empty() {
+            return Err(sqlmodel_core::Error::Custom(format!(
+                "aggregate query on {} selects no columns",
+                M::TABLE_NAME
+            )));
+        }
+        for item in &self.items {
+            if let AggregateItem::Column(col) = item {
+                if !self.group_by.contains(col) {
+                    return Err(sqlmodel_core::Error::Custom(format!(
+                        "column '{col}' is selected in an aggregate query on {} but is not in GROUP BY",
+                        M::TABLE_NAME
+                    )));
+                }
+            }
+        }
+
+        let mut params = Vec::new();
+        let select_list: Vec<String> = self
+            .items
+            .iter()
+            .map(|item| match item {
+                AggregateItem::Column(col) => col.clone(),
+                AggregateItem::Aggregate { expr, alias } => format!(
+                    "{} AS {}",
+                    expr.build_with_dialect(dialect, &mut params, 0),
+                    dialect.quote_identifier(alias)
+                ),
+            })
+            .collect();
+
+        let mut sql = format!("SELECT {} FROM {}", select_list.join(", "), M::TABLE_NAME);
+
+        if let Some(where_clause) = &self.where_clause {
+            let (where_sql, where_params) = where_clause.build_with_dialect(dialect, params.len());
+            sql.push_str(" WHERE ");
+            sql.push_str(&where_sql);
+            params.extend(where_params);
+        }
+
+        if !self.group_by.is_empty() {
+            sql.push_str(" GROUP BY ");
+            sql.push_str(&self.group_by.join(", "));
+        }
+
+        if let Some(having) = &self.having {
+            let (having_sql, having_params) = having.build_with_dialect(dialect, params.len());
+            sql.push_str(" HAVING ");
+            sql.push_str(&having_sql);
+            params.extend(having_params);
+        }
+
+        if !self.order_by.is_empty() {
+            let order_strs: Vec<_> = self
+                .order_by
+                .iter()
+                .map(|o| o.build(dialect, &mut params, 0))
+                .collect();
+            sql.push_str(" ORDER BY ");
+            sql.push_str(&order_strs.join(", "));
+        }
+
+        sql.push_str(&dialect.limit_offset(self.limit, self.offset));
+
+        Ok((sql, params))
+    }
+
+    /// Execute the query and return one row per group.
+    pub async fn all<C: Connection>(
+        self,
+        cx: &Cx,
+        conn: &C,
+    ) -> Outcome<Vec<Row>, sqlmodel_core::Error> {
+        let (sql, params) = match self.build_with_dialect(conn.dialect()) {
+            Ok(built) => built,
+            Err(e) => return Outcome::Err(e),
+        };
+        conn.query(cx, &sql, &params).await
+    }
+}
+
+impl<M: Model> Default for Aggregate<M> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sqlmodel_core::{Error, FieldInfo, Result};
+
+    #[derive(Debug, Clone)]
+    struct Hero;
+
+    impl Hero {
+        const AGE: Field<i32> = Field::new("age");
+    }
+
+    impl Model for Hero {
+        const TABLE_NAME: &'static str = "heroes";
+        const PRIMARY_KEY: &'static [&'static str] = &["id"];
+
+        fn fields() -> &'static [FieldInfo] {
+            &[]
+        }
+
+        fn to_row(&self) -> Vec<(&'static str, Value)> {
+            Vec::new()
+        }
+
+        fn from_row(_row: &Row) -> Result<Self> {
+            Err(Error::Custom("not used in tests".to_string()))
+        }
+
+        fn primary_key_value(&self) -> Vec<Value> {
+            Vec::new()
+        }
+
+        fn is_new(&self) -> bool {
+            true
+        }
+    }
+
+    #[test]
+    fn test_aggregate_group_by_having() {
+        let (sql, params) = Aggregate::<Hero>::new()
+            .column("team_id")
+            .count("heroes")
+            .avg(Hero::AGE, "avg_age")
+            .max(Hero::AGE, "oldest")
+            .filter(Expr::col("active").eq(true))
+            .group_by(&["team_id"])
+            .having(Expr::count_star().gt(2))
+            .order_by(Expr::col("team_id").asc())
+            .limit(10)
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            sql,
+            "SELECT team_id, COUNT(*) AS \"heroes\", AVG(\"age\") AS \"avg_age\", \
+             MAX(\"age\") AS \"oldest\" FROM heroes WHERE \"active\" = $1 \
+             GROUP BY team_id HAVING COUNT(*) > $2 ORDER BY \"team_id\" ASC LIMIT 10"
+        );
+        assert_eq!(params, vec![Value::Bool(true), Value::Int(2)]);
+    }
+
+    #[test]
+    fn test_aggregate_without_group_by() {
+        let (sql, params) = Aggregate::<Hero>::new()
+            .sum(Hero::AGE, "total")
+            .min(Hero::AGE, "youngest")
+            .count_field(Hero::AGE, "with_age")
+            .build_with_dialect(Dialect::Mysql)
+            .unwrap();
+
+        assert_eq!(
+            sql,
+            "SELECT SUM(`age`) AS `total`, MIN(`age`) AS `youngest`, \
+             COUNT(`age`) AS `with_age` FROM heroes"
+        );
+        assert!(params.is_empty());
+    }
+
+    #[test]
+    fn test_aggregate_rejects_ungrouped_column() {
+        let err = Aggregate::<Hero>::new()
+            .column("name")
+            .count("n")
+            .group_by(&["team_id"])
+            .build()
+            .unwrap_err();
+        assert!(err.to_string().contains("'name'"));
+        assert!(err.to_string().contains("GROUP BY"));
+
+        assert!(Aggregate::<Hero>::new().build().is_err());
+    }
+}
@@ -536,7 +536,7 @@ fn build_insert_sql_for_table_with_columns(
         .map(|(name, value)| {
             let field = fields.iter().find(|f| f.column_name == *name);
             if let Some(f) = field {
-                if f.auto_increment && matches!(value, Value::Null) {
+                if f.auto_increment && value.is_null() {
                     return (*name, Value::Default);
                 }
             }
@@ -565,7 +565,7 @@ fn build_insert_sql_for_table_with_columns(
     }
 
     let mut sql = if columns.is_empty() {
-        format!("INSERT INTO {} DEFAULT VALUES", table)
+        dialect.default_values_insert(table)
     } else {
         format!(
             "INSERT INTO {} ({}) VALUES ({})",
@@ -892,7 +892,7 @@ impl<'a, M: Model> InsertBuilder<'a, M> {
             .map(|(name, value)| {
                 let field = fields.iter().find(|f| f.column_name == *name);
                 if let Some(f) = field {
-                    if f.auto_increment && matches!(value, Value::Null) {
+                    if f.auto_increment && value.is_null() {
                         return (*name, Value::Default);
                     }
                 }
@@ -921,7 +921,7 @@ impl<'a, M: Model> InsertBuilder<'a, M> {
         }
 
         let mut sql = if columns.is_empty() {
-            format!("INSERT INTO {} DEFAULT VALUES", M::TABLE_NAME)
+            dialect.default_values_insert(M::TABLE_NAME)
         } else {
             format!(
                 "INSERT INTO {} ({}) VALUES ({})",
@@ -1573,7 +1573,7 @@ impl<'a, M: Model> InsertManyBuilder<'a, M> {
                 let has_value = rows.iter().any(|row| {
                     row.iter()
                         .find(|(name, _)| name == &field.column_name)
-                        .is_some_and(|(_, v)| !matches!(v, Value::Null))
+                        .is_some_and(|(_, v)| !v.is_null())
                 });
                 if has_value {
                     Some(field.column_name)
@@ -1597,7 +1597,7 @@ impl<'a, M: Model> InsertManyBuilder<'a, M> {
 
                 // Map Null auto-increment fields to DEFAULT
                 if let Some(f) = fields.iter().find(|f| f.column_name == *col) {
-                    if f.auto_increment && matches!(val, Value::Null) {
+                    if f.auto_increment && val.is_null() {
                         val = Value::Default;
                     }
                 }
@@ -1631,7 +1631,7 @@ impl<'a, M: Model> InsertManyBuilder<'a, M> {
         for batch in batches {
             match batch {
                 Batch::DefaultValues => {
-                    let mut sql = format!("INSERT INTO {} DEFAULT VALUES", M::TABLE_NAME);
+                    let mut sql = dialect.default_values_insert(M::TABLE_NAME);
                     self.append_on_conflict(dialect, &mut sql, &[]);
                     self.append_returning(&mut sql);
                     statements.push((sql, Vec::new()));
@@ -1662,7 +1662,7 @@ impl<'a, M: Model> InsertManyBuilder<'a, M> {
                 let has_value = rows.iter().any(|row| {
                     row.iter()
                         .find(|(name, _)| name == &field.column_name)
-                        .is_some_and(|(_, v)| !matches!(v, Value::Null))
+                        .is_some_and(|(_, v)| !v.is_null())
                 });
                 if has_value {
                     Some(field.column_name)
@@ -1687,7 +1687,7 @@ impl<'a, M: Model> InsertManyBuilder<'a, M> {
                     // Map Null auto-increment fields to DEFAULT
                     let field = fields.iter().find(|f| f.column_name == *col);
                     if let Some(f) = field {
-                        if f.auto_increment && matches!(val, Value::Null) {
+                        if f.auto_increment && val.is_null() {
                             return Value::Default;
                         }
                     }
@@ -1744,7 +1744,7 @@ impl<'a, M: Model> InsertManyBuilder<'a, M> {
         }
 
         let mut sql = if columns.is_empty() {
-            format!("INSERT INTO {} DEFAULT VALUES", M::TABLE_NAME)
+            dialect.default_values_insert(M::TABLE_NAME)
         } else {
             format!(
                 "INSERT INTO {} ({}) VALUES {}",
@@ -102,22 +102,51 @@ impl OrderBy {
     }
 
     /// Build SQL for this ORDER BY clause.
+    ///
+    /// Without an explicit [`NullsOrder`] NULLs sort wherever the dialect puts
+    /// them natively (last for ascending on Postgres, first on SQLite and
+    /// MySQL). Postgres gets a `NULLS FIRST`/`NULLS LAST` suffix; SQLite (before
+    /// 3.30) and MySQL lack that syntax, so when their native placement is
+    /// wrong the sort is prefixed with a `CASE WHEN expr IS NULL` flag instead.
     pub fn build(&self, dialect: Dialect, params: &mut Vec<Value>, offset: usize) -> String {
-        let mut sql = self.expr.build_with_dialect(dialect, params, offset);
-
-        sql.push_str(match self.direction {
+        let direction = match self.direction {
             OrderDirection::Asc => " ASC",
             OrderDirection::Desc => " DESC",
-        });
-
-        if let Some(nulls) = self.nulls {
-            sql.push_str(match nulls {
-                NullsOrder::First => " NULLS FIRST",
-                NullsOrder::Last => " NULLS LAST",
-            });
+        };
+
+        let Some(nulls) = self.nulls else {
+            let sql = self.expr.build_with_dialect(dialect, params, offset);
+            return format!("{sql}{direction}");
+        };
+
+        match dialect {
+            Dialect::Postgres => {
+                let sql = self.expr.build_with_dialect(dialect, params, offset);
+                let nulls = match nulls {
+                    NullsOrder::First => " NULLS FIRST",
+                    NullsOrder::Last => " NULLS LAST",
+                };
+                format!("{sql}{direction}{nulls}")
+            }
+            Dialect::Sqlite | Dialect::Mysql => {
+                // Both treat NULL as smaller than any value.
+                let native_first = self.direction == OrderDirection::Asc;
+                if (nulls == NullsOrder::First) == native_first {
+                    let sql = self.expr.build_with_dialect(dialect, params, offset);
+                    return format!("{sql}{direction}");
+                }
+                let (null_rank, value_rank) = match nulls {
+                    NullsOrder::First => (0, 1),
+                    NullsOrder::Last => (1, 0),
+                };
+                // The flag comes first in the SQL, so its parameters must too.
+                let flag = self.expr.build_with_dialect(dialect, params, offset);
+                let sql = self.expr.build_with_dialect(dialect, params, offset);
+                format!(
+                    "CASE WHEN {flag} IS NULL THEN {null_rank} ELSE {value_rank} END, {sql}{direction}"
+                )
+            }
         }
-
-        sql
     }
 }
 
@@ -5,7 +5,7 @@
 
 use crate::clause::{OrderBy, OrderDirection};
 use crate::subquery::SelectQuery;
-use sqlmodel_core::Value;
+use sqlmodel_core::{Field, Value};
 
 /// SQL dialect for generating dialect-specific SQL.
 ///
@@ -84,6 +84,8 @@ pub enum Expr {
         pattern: String,
         negated: bool,
         case_insensitive: bool,
+        /// Character named in an `ESCAPE` clause, if any.
+        escape: Option<char>,
     },
 
     /// Subquery (stores the SQL string)
@@ -647,6 +649,7 @@ impl Expr {
             pattern: pattern.into(),
             negated: false,
             case_insensitive: false,
+            escape: None,
         }
     }
 
@@ -657,6 +660,7 @@ impl Expr {
             pattern: pattern.into(),
             negated: true,
             case_insensitive: false,
+            escape: None,
         }
     }
 
@@ -667,6 +671,7 @@ impl Expr {
             pattern: pattern.into(),
             negated: false,
             case_insensitive: true,
+            escape: None,
         }
     }
 
@@ -677,90 +682,79 @@ impl Expr {
             pattern: pattern.into(),
             negated: true,
             case_insensitive: true,
+            escape: None,
         }
     }
 
     /// Check if column contains the given substring (LIKE '%pattern%').
     ///
+    /// `%`, `_` and `\\` in `pattern` match literally.
+    ///
     /// # Example
     /// ```ignore
     /// Expr::col("name").contains("man")
-    /// // Generates: "name" LIKE '%man%'
+    /// // Generates: "name" LIKE $1 ESCAPE '\\' with $1 = '%man%'
     /// ```
     pub fn contains(self, pattern: impl AsRef<str>) -> Self {
-        let pattern = format!("%{}%", pattern.as_ref());
-        Expr::Like {
-            expr: Box::new(self),
-            pattern,
-            negated: false,
-            case_insensitive: false,
-        }
+        self.like_literal("%", pattern.as_ref(), "%", false)
     }
 
     /// Check if column starts with the given prefix (LIKE 'pattern%').
     ///
+    /// `%`, `_` and `\\` in `pattern` match literally.
+    ///
     /// # Example
     /// ```ignore
     /// Expr::col("name").starts_with("Spider")
-    /// // Generates: "name" LIKE 'Spider%'
+    /// // Generates: "name" LIKE $1 ESCAPE '\\' with $1 = 'Spider%'
     /// ```
     pub fn starts_with(self, pattern: impl AsRef<str>) -> Self {
-        let pattern = format!("{}%", pattern.as_ref());
-        Expr::Like {
-            expr: Box::new(self),
-            pattern,
-            negated: false,
-            case_insensitive: false,
-        }
+        self.like_literal("", pattern.as_ref(), "%", false)
     }
 
     /// Check if column ends with the given suffix (LIKE '%pattern').
     ///
+    /// `%`, `_` and `\\` in `pattern` match literally.
+    ///
     /// # Example
     /// ```ignore
     /// Expr::col("name").ends_with("man")
-    /// // Generates: "name" LIKE '%man'
+    /// // Generates: "name" LIKE $1 ESCAPE '\\' with $1 = '%man'
     /// ```
     pub fn ends_with(self, pattern: impl AsRef<str>) -> Self {
-        let pattern = format!("%{}", pattern.as_ref());
-        Expr::Like {
-            expr: Box::new(self),
-            pattern,
-            negated: false,
-            case_insensitive: false,
-        }
+        self.like_literal("%", pattern.as_ref(), "", false)
     }
 
     /// Case-insensitive contains (ILIKE '%pattern%' or LOWER fallback).
     pub fn icontains(self, pattern: impl AsRef<str>) -> Self {
-        let pattern = format!("%{}%", pattern.as_ref());
-        Expr::Like {
-            expr: Box::new(self),
-            pattern,
-            negated: false,
-            case_insensitive: true,
-        }
+        self.like_literal("%", pattern.as_ref(), "%", true)
     }
 
     /// Case-insensitive starts_with (ILIKE 'pattern%' or LOWER fallback).
     pub fn istarts_with(self, pattern: impl AsRef<str>) -> Self {
-        let pattern = format!("{}%", pattern.as_ref());
-        Expr::Like {
-            expr: Box::new(self),
-            pattern,
-            negated: false,
-            case_insensitive: true,
-        }
+        self.like_literal("", pattern.as_ref(), "%", true)
     }
 
     /// Case-insensitive ends_with (ILIKE '%pattern' or LOWER fallback).
     pub fn iends_with(self, pattern: impl AsRef<str>) -> Self {
-        let pattern = format!("%{}", pattern.as_ref());
+        self.like_literal("%", pattern.as_ref(), "", true)
+    }
+
+    /// LIKE against `literal` wrapped in wildcards, with the literal escaped.
+    fn like_literal(
+        self,
+        prefix: &str,
+        literal: &str,
+        suffix: &str,
+        case_insensitive: bool,
+    ) -> Self {
+        let escaped = Value::like_escape(literal, LIKE_ESCAPE);
         Expr::Like {
             expr: Box::new(self),
-            pattern,
+            pattern: format!("{prefix}{escaped}{suffix}"),
             negated: false,
-            case_insensitive: true,
+            case_insensitive,
+            escape: Some(LIKE_ESCAPE),
         }
     }
 
@@ -1813,20 +1807,23 @@ impl Expr {
                 pattern,
                 negated,
                 case_insensitive,
+                escape,
             } => {
                 let expr_sql = expr.build_with_dialect(dialect, params, offset);
                 params.push(Value::Text(pattern.clone()));
                 let param = dialect.placeholder(offset + params.len());
                 let not_str = if *negated { "NOT " } else { "" };
+                let escape_sql =
+                    escape.map_or_else(String::new, |ch| like_escape_clause(dialect, ch));
                 let op = if *case_insensitive && dialect.supports_ilike() {
                     "ILIKE"
                 } else if *case_insensitive {
                     // Fallback for dialects without ILIKE
-                    return format!("LOWER({expr_sql}) {not_str}LIKE LOWER({param})");
+                    return format!("LOWER({expr_sql}) {not_str}LIKE LOWER({param}){escape_sql}");
                 } else {
                     "LIKE"
                 };
-                format!("{expr_sql} {not_str}{op} {param}")
+                format!("{expr_sql} {not_str}{op} {param}{escape_sql}")
             }
 
             Expr::Subquery(sql) => format!("({sql})"),
@@ -1904,19 +1901,7 @@ impl Expr {
                 if !order_by.is_empty() {
                     let order_sqls: Vec<_> = order_by
                         .iter()
-                        .map(|o| {
-                            let expr_sql = o.expr.build_with_dialect(dialect, params, offset);
-                            let dir = match o.direction {
-                                OrderDirection::Asc => "ASC",
-                                OrderDirection::Desc => "DESC",
-                            };
-                            let nulls = match o.nulls {
-                                Some(crate::clause::NullsOrder::First) => " NULLS FIRST",
-                                Some(crate::clause::NullsOrder::Last) => " NULLS LAST",
-                                None => "",
-                            };
-                            format!("{expr_sql} {dir}{nulls}")
-                        })
+                        .map(|o| o.build(dialect, params, offset))
                         .collect();
                     over_parts.push(format!("ORDER BY {}", order_sqls.join(", ")));
                 }
@@ -2363,8 +2348,172 @@ impl From<f32> for Expr {
     }
 }
 
+// ==================== Typed Field Operators ====================
+
+impl<T> From<Field<T>> for Expr {
+    fn from(field: Field<T>) -> Self {
+        Expr::col(field.name)
+    }
+}
+
+/// Comparison operators on typed [`Field`] references.
+///
+/// Each operator produces a predicate [`Expr`] with the value bound as a
+/// parameter. Values must convert into the field's type, so comparing a
+/// column with a value of the wrong type is a compile error:
+///
+/// ```compile_fail
+/// use sqlmodel_core::Field;
+/// use sqlmodel_query::FieldExt;
+///
+/// const AGE: Field<i32> = Field::new("age");
+/// let _ = AGE.eq("x");
+/// ```
+pub trait FieldExt<T>: Sized {
+    /// Equal to (=)
+    fn eq(self, value: impl Into<T>) -> Expr;
+    /// Not equal to (<>)
+    fn ne(self, value: impl Into<T>) -> Expr;
+    /// Less than (<)
+    fn lt(self, value: impl Into<T>) -> Expr;
+    /// Less than or equal to (<=)
+    fn le(self, value: impl Into<T>) -> Expr;
+    /// Greater than (>)
+    fn gt(self, value: impl Into<T>) -> Expr;
+    /// Greater than or equal to (>=)
+    fn ge(self, value: impl Into<T>) -> Expr;
+    /// IN list of values (an empty list matches nothing)
+    fn in_<V: Into<T>>(self, values: impl IntoIterator<Item = V>) -> Expr;
+    /// IS NULL
+    fn is_null(self) -> Expr;
+    /// IS NOT NULL
+    fn is_not_null(self) -> Expr;
+}
+
+impl<T: Into<Value>> FieldExt<T> for Field<T> {
+    fn eq(self, value: impl Into<T>) -> Expr {
+        Expr::from(self).eq(Expr::lit(value.into()))
+    }
+
+    fn ne(self, value: impl Into<T>) -> Expr {
+        Expr::from(self).ne(Expr::lit(value.into()))
+    }
+
+    fn lt(self, value: impl Into<T>) -> Expr {
+        Expr::from(self).lt(Expr::lit(value.into()))
+    }
+
+    fn le(self, value: impl Into<T>) -> Expr {
+        Expr::from(self).le(Expr::lit(value.into()))
+    }
+
+    fn gt(self, value: impl Into<T>) -> Expr {
+        Expr::from(self).gt(Expr::lit(value.into()))
+    }
+
+    fn ge(self, value: impl Into<T>) -> Expr {
+        Expr::from(self).ge(Expr::lit(value.into()))
+    }
+
+    fn in_<V: Into<T>>(self, values: impl IntoIterator<Item = V>) -> Expr {
+        let values: Vec<Expr> = values.into_iter().map(|v| Expr::lit(v.into())).collect();
+        Expr::from(self).in_list(values)
+    }
+
+    fn is_null(self) -> Expr {
+        Expr::from(self).is_null()
+    }
+
+    fn is_not_null(self) -> Expr {
+        Expr::from(self).is_not_null()
+    }
+}
+
+/// Pattern-matching operators on text [`Field`] references.
+pub trait TextFieldExt: Sized {
+    /// LIKE pattern match (the pattern is bound as a parameter)
+    fn like(self, pattern: impl Into<String>) -> Expr;
+    /// NOT LIKE pattern match
+    fn not_like(self, pattern: impl Into<String>) -> Expr;
+    /// Case-insensitive LIKE (ILIKE on PostgreSQL, LOWER() elsewhere)
+    fn ilike(self, pattern: impl Into<String>) -> Expr;
+    /// Substring match with LIKE wildcards in `text` escaped
+    fn contains(self, text: impl AsRef<str>) -> Expr;
+    /// Prefix match with LIKE wildcards in `text` escaped
+    fn starts_with(self, text: impl AsRef<str>) -> Expr;
+    /// Suffix match with LIKE wildcards in `text` escaped
+    fn ends_with(self, text: impl AsRef<str>) -> Expr;
+}
+
+impl TextFieldExt for Field<String> {
+    fn like(self, pattern: impl Into<String>) -> Expr {
+        Expr::from(self).like(pattern)
+    }
+
+    fn not_like(self, pattern: impl Into<String>) -> Expr {
+        Expr::from(self).not_like(pattern)
+    }
+
+    fn ilike(self, pattern: impl Into<String>) -> Expr {
+        Expr::from(self).ilike(pattern)
+    }
+
+    fn contains(self, text: impl AsRef<str>) -> Expr {
+        Expr::from(self).contains(text)
+    }
+
+    fn starts_with(self, text: impl AsRef<str>) -> Expr {
+        Expr::from(self).starts_with(text)
+    }
+
+    fn ends_with(self, text: impl AsRef<str>) -> Expr {
+        Expr::from(self).ends_with(text)
+    }
+}
+
+impl TextFieldExt for Field<Option<String>> {
+    fn like(self, pattern: impl Into<String>) -> Expr {
+        Expr::from(self).like(pattern)
+    }
+
+    fn not_like(self, pattern: impl Into<String>) -> Expr {
+        Expr::from(self).not_like(pattern)
+    }
+
+    fn ilike(self, pattern: impl Into<String>) -> Expr {
+        Expr::from(self).ilike(pattern)
+    }
+
+    fn contains(self, text: impl AsRef<str>) -> Expr {
+        Expr::from(self).contains(text)
+    }
+
+    fn starts_with(self, text: impl AsRef<str>) -> Expr {
+        Expr::from(self).starts_with(text)
+    }
+
+    fn ends_with(self, text: impl AsRef<str>) -> Expr {
+        Expr::from(self).ends_with(text)
+    }
+}
+
 // ==================== Helper Functions ====================
 
+/// Escape character used by the literal LIKE helpers (`contains` and friends).
+const LIKE_ESCAPE: char = '\\';
+
+/// ` ESCAPE '<ch>'` for a LIKE clause.
+///
+/// MySQL treats backslash as an escape inside string literals, so a
+/// backslash escape character has to be written doubled there.
+fn like_escape_clause(dialect: Dialect, ch: char) -> String {
+    match ch {
+        '\\' if dialect == Dialect::Mysql => " ESCAPE '\\\\'".to_string(),
+        '\'' => " ESCAPE ''''".to_string(),
+        _ => format!(" ESCAPE '{ch}'"),
+    }
+}
+
 /// Adjust placeholder indices in a SQL string.
 ///
 /// Rewrites $1, $2, etc. to $offset+1, $offset+2, etc. for PostgreSQL,
@@ -2710,6 +2859,35 @@ mod tests {
         assert_eq!(sql, "LOWER(\"name\") LIKE LOWER(?1)");
     }
 
+    #[test]
+    fn test_contains_escapes_wildcards() {
+        let mut params = Vec::new();
+        let sql = Expr::col("code").contains("50%_off").build_with_dialect(
+            Dialect::Postgres,
+            &mut params,
+            0,
+        );
+        assert_eq!(sql, "\"code\" LIKE $1 ESCAPE '\\'");
+        assert_eq!(params, vec![Value::Text("%50\\%\\_off%".to_string())]);
+
+        let mut params = Vec::new();
+        let sql = Expr::col("path").starts_with("C:\\tmp").build_with_dialect(
+            Dialect::Mysql,
+            &mut params,
+            0,
+        );
+        assert_eq!(sql, "`path` LIKE ? ESCAPE '\\\\'");
+        assert_eq!(params, vec![Value::Text("C:\\\\tmp%".to_string())]);
+
+        let mut params = Vec::new();
+        let sql =
+            Expr::col("name")
+                .iends_with("_X")
+                .build_with_dialect(Dialect::Sqlite, &mut params, 0);
+        assert_eq!(sql, "LOWER(\"name\") LIKE LOWER(?1) ESCAPE '\\'");
+        assert_eq!(params, vec![Value::Text("%\\_X".to_string())]);
+    }
+
     // ==================== IN Tests ====================
 
     #[test]
@@ -2916,6 +3094,49 @@ mod tests {
         assert_eq!(sql, "\"name\" DESC NULLS LAST");
     }
 
+    #[test]
+    fn test_order_nulls_emulated_without_nulls_clause() {
+        let mut params = Vec::new();
+
+        // Already the native placement on SQLite and MySQL.
+        let sql = Expr::col("name")
+            .asc()
+            .nulls_first()
+            .build(Dialect::Sqlite, &mut params, 0);
+        assert_eq!(sql, "\"name\" ASC");
+
+        let sql = Expr::col("name")
+            .asc()
+            .nulls_last()
+            .build(Dialect::Sqlite, &mut params, 0);
+        assert_eq!(
+            sql,
+            "CASE WHEN \"name\" IS NULL THEN 1 ELSE 0 END, \"name\" ASC"
+        );
+
+        let sql = Expr::col("name")
+            .desc()
+            .nulls_first()
+            .build(Dialect::Mysql, &mut params, 0);
+        assert_eq!(
+            sql,
+            "CASE WHEN `name` IS NULL THEN 0 ELSE 1 END, `name` DESC"
+        );
+        assert!(params.is_empty());
+
+        let sql =
+            Expr::col("score")
+                .add(5)
+                .asc()
+                .nulls_last()
+                .build(Dialect::Sqlite, &mut params, 0);
+        assert_eq!(
+            sql,
+            "CASE WHEN \"score\" + ?1 IS NULL THEN 1 ELSE 0 END, \"score\" + ?2 ASC"
+        );
+        assert_eq!(params, vec![Value::Int(5), Value::Int(5)]);
+    }
+
     // ==================== Dialect Tests ====================
 
     #[test]
@@ -3789,4 +4010,63 @@ mod tests {
         assert_eq!(sql, "$1 = ANY(\"tags\")");
         assert_eq!(params.len(), 1);
     }
+
+    // ==================== Typed Field Tests ====================
+
+    const AGE: Field<i32> = Field::new("age");
+    const NAME: Field<String> = Field::new("name");
+    const NICKNAME: Field<Option<String>> = Field::new("nickname");
+
+    #[test]
+    fn test_field_comparisons_bind_params() {
+        let expr = AGE.gt(18).and(NAME.eq("Spider-Man"));
+        let mut params = Vec::new();
+        let sql = expr.build_with_dialect(Dialect::Postgres, &mut params, 0);
+        assert_eq!(sql, "\"age\" > $1 AND \"name\" = $2");
+        assert_eq!(
+            params,
+            vec![Value::Int(18), Value::Text("Spider-Man".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_field_in_and_null_checks() {
+        let mut params = Vec::new();
+        let sql = AGE
+            .in_([18, 21])
+            .build_with_dialect(Dialect::Sqlite, &mut params, 0);
+        assert_eq!(sql, "\"age\" IN (?1, ?2)");
+        assert_eq!(params, vec![Value::Int(18), Value::Int(21)]);
+
+        let mut params = Vec::new();
+        assert_eq!(AGE.in_(Vec::<i32>::new()).build(&mut params, 0), "1 = 0");
+        assert_eq!(
+            NICKNAME.is_null().build(&mut params, 0),
+            "\"nickname\" IS NULL"
+        );
+        assert!(params.is_empty());
+    }
+
+    #[test]
+    fn test_text_field_like() {
+        let mut params = Vec::new();
+        let sql = NAME.like("%man").build(&mut params, 0);
+        assert_eq!(sql, "\"name\" LIKE $1");
+        assert_eq!(params, vec![Value::Text("%man".to_string())]);
+
+        let mut params = Vec::new();
+        let sql = NICKNAME
+            .eq(Some("Spidey".to_string()))
+            .or(NICKNAME.like("Web%"))
+            .build(&mut params, 0);
+        assert_eq!(sql, "\"nickname\" = $1 OR \"nickname\" LIKE $2");
+    }
+
+    #[test]
+    fn test_text_field_contains() {
+        let mut params = Vec::new();
+        let sql = NAME.contains("100%").build(&mut params, 0);
+        assert_eq!(sql, "\"name\" LIKE $1 ESCAPE '\\'");
+        assert_eq!(params, vec![Value::Text("%100\\%%".to_string())]);
+    }
 }
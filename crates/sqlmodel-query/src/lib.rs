@@ -7,11 +7,14 @@
 //!
 //! - **Query macros**: `select!`, `insert!`, `update!`, `delete!` build typed queries.
 //! - **Expression DSL**: `Expr` and operators build WHERE/HAVING clauses safely.
+//! - **Aggregates**: `Aggregate` runs GROUP BY/HAVING queries and returns raw rows.
+//! - **Typed fields**: `FieldExt` compares `Field<T>` columns against values of type `T`.
 //! - **Dialect support**: generates SQL for Postgres, MySQL, and SQLite.
 //!
 //! The resulting queries execute through the `Connection` trait from `sqlmodel-core`.
 //! Most users access these builders via the `sqlmodel` facade crate.
 
+pub mod aggregate;
 pub mod builder;
 pub mod cache;
 pub mod clause;
@@ -23,16 +26,18 @@ pub mod select;
 pub mod set_ops;
 pub mod subquery;
 
+pub use aggregate::Aggregate;
 pub use builder::{
     DeleteBuilder, InsertBuilder, InsertManyBuilder, OnConflict, QueryBuilder, SetClause,
     UpdateBuilder,
 };
 pub use cache::{StatementCache, cache_key};
-pub use clause::{Limit, Offset, OrderBy, Where};
+pub use clause::{Limit, NullsOrder, Offset, OrderBy, Where};
 pub use cte::{Cte, CteRef, WithQuery};
 pub use eager::{EagerLoader, IncludePath};
 pub use expr::{
-    BinaryOp, Dialect, Expr, UnaryOp, WindowBuilder, WindowFrame, WindowFrameBound, WindowFrameType,
+    BinaryOp, Dialect, Expr, FieldExt, TextFieldExt, UnaryOp, WindowBuilder, WindowFrame,
+    WindowFrameBound, WindowFrameType,
 };
 pub use join::{Join, JoinType};
 pub use select::{
@@ -422,15 +422,10 @@ impl<M: Model> Select<M> {
             sql.push_str(&order_strs.join(", "));
         }
 
-        // LIMIT
-        if let Some(Limit(n)) = self.limit {
-            sql.push_str(&format!(" LIMIT {}", n));
-        }
-
-        // OFFSET
-        if let Some(Offset(n)) = self.offset {
-            sql.push_str(&format!(" OFFSET {}", n));
-        }
+        // LIMIT / OFFSET
+        sql.push_str(
+            &dialect.limit_offset(self.limit.map(|Limit(n)| n), self.offset.map(|Offset(n)| n)),
+        );
 
         (sql, params, join_info)
     }
@@ -623,15 +618,10 @@ impl<M: Model> Select<M> {
             sql.push_str(&order_strs.join(", "));
         }
 
-        // LIMIT
-        if let Some(Limit(n)) = self.limit {
-            sql.push_str(&format!(" LIMIT {}", n));
-        }
-
-        // OFFSET
-        if let Some(Offset(n)) = self.offset {
-            sql.push_str(&format!(" OFFSET {}", n));
-        }
+        // LIMIT / OFFSET
+        sql.push_str(
+            &dialect.limit_offset(self.limit.map(|Limit(n)| n), self.offset.map(|Offset(n)| n)),
+        );
 
         // FOR UPDATE
         if self.for_update {
@@ -1780,6 +1770,29 @@ mod tests {
         assert!(params.is_empty());
     }
 
+    #[test]
+    fn test_select_limit_offset_per_dialect() {
+        let paged = Select::<Hero>::new().limit(10).offset(20);
+        let skipped = Select::<Hero>::new().offset(20);
+
+        assert_eq!(
+            paged.build_with_dialect(Dialect::Sqlite).0,
+            "SELECT * FROM heroes LIMIT 10 OFFSET 20"
+        );
+        assert_eq!(
+            skipped.build_with_dialect(Dialect::Sqlite).0,
+            "SELECT * FROM heroes LIMIT -1 OFFSET 20"
+        );
+        assert_eq!(
+            paged.build_with_dialect(Dialect::Mysql).0,
+            "SELECT * FROM heroes LIMIT 20, 10"
+        );
+        assert_eq!(
+            skipped.build_with_dialect(Dialect::Mysql).0,
+            "SELECT * FROM heroes LIMIT 20, 18446744073709551615"
+        );
+    }
+
     #[test]
     fn test_select_with_group_by() {
         let query = Select::<Hero>::new()
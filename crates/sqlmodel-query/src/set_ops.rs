@@ -179,34 +179,13 @@ impl SetOperation {
             let order_strs: Vec<String> = self
                 .order_by
                 .iter()
-                .map(|o| {
-                    let expr_sql = o.expr.build_with_dialect(dialect, &mut params, 0);
-                    let dir = match o.direction {
-                        crate::clause::OrderDirection::Asc => "ASC",
-                        crate::clause::OrderDirection::Desc => "DESC",
-                    };
-                    let nulls = match o.nulls {
-                        Some(crate::clause::NullsOrder::First) => " NULLS FIRST",
-                        Some(crate::clause::NullsOrder::Last) => " NULLS LAST",
-                        None => "",
-                    };
-                    format!("{expr_sql} {dir}{nulls}")
-                })
+                .map(|o| o.build(dialect, &mut params, 0))
                 .collect();
             sql.push_str(&order_strs.join(", "));
         }
 
-        // LIMIT
-        if let Some(limit) = self.limit {
-            sql.push_str(" LIMIT ");
-            sql.push_str(&limit.to_string());
-        }
-
-        // OFFSET
-        if let Some(offset) = self.offset {
-            sql.push_str(" OFFSET ");
-            sql.push_str(&offset.to_string());
-        }
+        // LIMIT / OFFSET
+        sql.push_str(&dialect.limit_offset(self.limit, self.offset));
 
         (sql, params)
     }
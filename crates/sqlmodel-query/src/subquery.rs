@@ -95,15 +95,10 @@ impl SelectQuery {
             sql.push_str(&order_strs.join(", "));
         }
 
-        // LIMIT
-        if let Some(Limit(n)) = self.limit {
-            sql.push_str(&format!(" LIMIT {}", n));
-        }
-
-        // OFFSET
-        if let Some(Offset(n)) = self.offset {
-            sql.push_str(&format!(" OFFSET {}", n));
-        }
+        // LIMIT / OFFSET
+        sql.push_str(
+            &dialect.limit_offset(self.limit.map(|Limit(n)| n), self.offset.map(|Offset(n)| n)),
+        );
 
         // FOR UPDATE
         if self.for_update {
@@ -1,12 +1,14 @@
 //! CREATE TABLE statement builder.
 
-use sqlmodel_core::{FieldInfo, InheritanceStrategy, Model, quote_ident};
+use crate::ddl::constraint_name;
+use sqlmodel_core::{FieldInfo, InheritanceStrategy, Model, TableConstraint, quote_ident};
 use std::marker::PhantomData;
 
 /// Builder for CREATE TABLE statements.
 #[derive(Debug)]
 pub struct CreateTable<M: Model> {
     if_not_exists: bool,
+    unique_including_deleted: bool,
     _marker: PhantomData<M>,
 }
 
@@ -15,6 +17,7 @@ impl<M: Model> CreateTable<M> {
     pub fn new() -> Self {
         Self {
             if_not_exists: false,
+            unique_including_deleted: false,
             _marker: PhantomData,
         }
     }
@@ -25,6 +28,69 @@ impl<M: Model> CreateTable<M> {
         self
     }
 
+    /// Enforce unique fields across soft-deleted rows too.
+    ///
+    /// By default, unique fields of a model with a
+    /// [`Model::SOFT_DELETE_COLUMN`] are enforced by partial unique indexes
+    /// (see [`CreateTable::build_indexes`]) that ignore soft-deleted rows.
+    /// This restores plain `UNIQUE` table constraints instead, e.g. for MySQL,
+    /// which has no partial indexes.
+    pub fn unique_including_deleted(mut self) -> Self {
+        self.unique_including_deleted = true;
+        self
+    }
+
+    /// The soft-delete column unique fields are filtered on, if any.
+    fn soft_delete_column(&self) -> Option<&'static str> {
+        M::SOFT_DELETE_COLUMN.filter(|_| !self.unique_including_deleted)
+    }
+
+    /// Build the statements that must run after CREATE TABLE.
+    ///
+    /// For a soft-deletable model this is one partial unique index per unique
+    /// field, filtered on the soft-delete column being `NULL`, so a value can
+    /// be reused once the row holding it is soft-deleted. Partial indexes are
+    /// supported by PostgreSQL and SQLite.
+    pub fn build_indexes(&self) -> Vec<String> {
+        let Some(deleted_column) = self.soft_delete_column() else {
+            return Vec::new();
+        };
+        if Self::should_skip_table_creation() {
+            return Vec::new();
+        }
+
+        let if_not_exists = if self.if_not_exists {
+            "IF NOT EXISTS "
+        } else {
+            ""
+        };
+        let single = M::fields()
+            .iter()
+            .filter(|field| field.unique && !field.primary_key)
+            .map(|field| std::slice::from_ref(&field.column_name));
+        let composite = M::TABLE_CONSTRAINTS
+            .iter()
+            .filter_map(|constraint| match constraint {
+                TableConstraint::Unique(columns) => Some(*columns),
+                TableConstraint::Check(_) => None,
+            });
+        single
+            .chain(composite)
+            .map(|columns| {
+                let index_name = constraint_name("uk", M::TABLE_NAME, &columns.join("_"));
+                let quoted: Vec<String> = columns.iter().map(|c| quote_ident(c)).collect();
+                format!(
+                    "CREATE UNIQUE INDEX {}{} ON {} ({}) WHERE {} IS NULL",
+                    if_not_exists,
+                    quote_ident(&index_name),
+                    quote_ident(M::TABLE_NAME),
+                    quoted.join(", "),
+                    quote_ident(deleted_column)
+                )
+            })
+            .collect()
+    }
+
     /// Build the CREATE TABLE SQL.
     ///
     /// # Inheritance Handling
@@ -81,9 +147,10 @@ impl<M: Model> CreateTable<M> {
             });
             column_defs.push(self.column_definition(field, embed_pk));
 
-            // Collect constraints
-            if field.unique && !field.primary_key {
-                let constraint_name = format!("uk_{}_{}", M::TABLE_NAME, field.column_name);
+            // Collect constraints; soft-deletable models get partial unique
+            // indexes from `build_indexes` instead.
+            if field.unique && !field.primary_key && self.soft_delete_column().is_none() {
+                let constraint_name = constraint_name("uk", M::TABLE_NAME, field.column_name);
                 let constraint = format!(
                     "CONSTRAINT {} UNIQUE ({})",
                     quote_ident(&constraint_name),
@@ -95,7 +162,7 @@ impl<M: Model> CreateTable<M> {
             if let Some(fk) = field.foreign_key {
                 let parts: Vec<&str> = fk.split('.').collect();
                 if parts.len() == 2 {
-                    let constraint_name = format!("fk_{}_{}", M::TABLE_NAME, field.column_name);
+                    let constraint_name = constraint_name("fk", M::TABLE_NAME, field.column_name);
                     let mut fk_sql = format!(
                         "CONSTRAINT {} FOREIGN KEY ({}) REFERENCES {}({})",
                         quote_ident(&constraint_name),
@@ -116,11 +183,48 @@ impl<M: Model> CreateTable<M> {
                         fk_sql.push_str(on_update.as_sql());
                     }
 
+                    if field.deferrable {
+                        fk_sql.push_str(" DEFERRABLE");
+                        if field.initially_deferred {
+                            fk_sql.push_str(" INITIALLY DEFERRED");
+                        }
+                    }
+
                     constraints.push(fk_sql);
                 }
             }
         }
 
+        // Model-level constraints: composite UNIQUE (partial indexes for
+        // soft-deletable models, like single-column uniques) and CHECK.
+        let mut check_number = 0;
+        for table_constraint in M::TABLE_CONSTRAINTS {
+            match table_constraint {
+                TableConstraint::Unique(columns) => {
+                    if self.soft_delete_column().is_some() {
+                        continue;
+                    }
+                    let constraint_name = constraint_name("uk", M::TABLE_NAME, &columns.join("_"));
+                    let quoted: Vec<String> = columns.iter().map(|c| quote_ident(c)).collect();
+                    constraints.push(format!(
+                        "CONSTRAINT {} UNIQUE ({})",
+                        quote_ident(&constraint_name),
+                        quoted.join(", ")
+                    ));
+                }
+                TableConstraint::Check(expression) => {
+                    check_number += 1;
+                    let constraint_name =
+                        constraint_name("ck", M::TABLE_NAME, &check_number.to_string());
+                    constraints.push(format!(
+                        "CONSTRAINT {} CHECK ({})",
+                        quote_ident(&constraint_name),
+                        expression
+                    ));
+                }
+            }
+        }
+
         // For joined table inheritance child models, add FK to parent table
         if inheritance.strategy == InheritanceStrategy::Joined {
             if let Some(parent_table) = inheritance.parent {
@@ -131,7 +235,7 @@ impl<M: Model> CreateTable<M> {
                     let quoted_child_cols: Vec<String> =
                         pk_cols.iter().map(|c| quote_ident(c)).collect();
                     let quoted_parent_cols = quoted_child_cols.clone();
-                    let constraint_name = format!("fk_{}_parent", M::TABLE_NAME);
+                    let constraint_name = constraint_name("fk", M::TABLE_NAME, "parent");
                     let fk_sql = format!(
                         "CONSTRAINT {} FOREIGN KEY ({}) REFERENCES {}({}) ON DELETE CASCADE",
                         quote_ident(&constraint_name),
@@ -191,6 +295,11 @@ impl<M: Model> CreateTable<M> {
         def.push(' ');
         def.push_str(&sql_type);
 
+        if let Some(collation) = field.collation {
+            def.push_str(" COLLATE ");
+            def.push_str(&quote_ident(collation));
+        }
+
         if embed_primary_key {
             def.push_str(" PRIMARY KEY");
         } else if !field.nullable && !field.auto_increment {
@@ -341,6 +450,151 @@ mod tests {
         assert!(statements[0].contains("ON \"heroes\" (\"name\", \"age\")"));
     }
 
+    // Test model with a soft-delete column
+    struct TestSoftDeleteUser;
+
+    impl Model for TestSoftDeleteUser {
+        const TABLE_NAME: &'static str = "users";
+        const PRIMARY_KEY: &'static [&'static str] = &["id"];
+        const SOFT_DELETE_COLUMN: Option<&'static str> = Some("deleted_at");
+
+        fn fields() -> &'static [FieldInfo] {
+            static FIELDS: &[FieldInfo] = &[
+                FieldInfo::new("id", "id", SqlType::BigInt).primary_key(true),
+                FieldInfo::new("email", "email", SqlType::Text).unique(true),
+                FieldInfo::new("deleted_at", "deleted_at", SqlType::Timestamp).nullable(true),
+            ];
+            FIELDS
+        }
+
+        fn to_row(&self) -> Vec<(&'static str, Value)> {
+            vec![]
+        }
+
+        fn from_row(_row: &Row) -> sqlmodel_core::Result<Self> {
+            Ok(TestSoftDeleteUser)
+        }
+
+        fn primary_key_value(&self) -> Vec<Value> {
+            vec![]
+        }
+
+        fn is_new(&self) -> bool {
+            true
+        }
+    }
+
+    #[test]
+    fn test_soft_delete_unique_uses_partial_index() {
+        let create = CreateTable::<TestSoftDeleteUser>::new();
+        assert!(!create.build().contains("UNIQUE"));
+        assert_eq!(
+            create.build_indexes(),
+            vec![
+                "CREATE UNIQUE INDEX \"uk_users_email\" ON \"users\" (\"email\") \
+                 WHERE \"deleted_at\" IS NULL"
+                    .to_string()
+            ]
+        );
+
+        let statements = SchemaBuilder::new()
+            .create_table::<TestSoftDeleteUser>()
+            .build();
+        assert_eq!(statements.len(), 2);
+        assert!(statements[1].starts_with("CREATE UNIQUE INDEX IF NOT EXISTS"));
+
+        // Opting out restores the plain table constraint.
+        let create = CreateTable::<TestSoftDeleteUser>::new().unique_including_deleted();
+        assert!(
+            create
+                .build()
+                .contains("CONSTRAINT \"uk_users_email\" UNIQUE (\"email\")")
+        );
+        assert!(create.build_indexes().is_empty());
+        assert!(CreateTable::<TestHero>::new().build_indexes().is_empty());
+    }
+
+    #[test]
+    fn test_soft_delete_partial_index_round_trips_through_postgres() {
+        use crate::diff::{SchemaOperation, schema_diff};
+        use crate::expected::expected_schema;
+        use crate::introspect::Dialect;
+
+        let expected = expected_schema::<TestSoftDeleteUser>(Dialect::Postgres);
+
+        // What the Postgres introspector reads back for the index created above.
+        let mut current = expected.clone();
+        let users = current.tables.get_mut("users").unwrap();
+        users.indexes[0].predicate = Some("(deleted_at IS NULL)".to_string());
+        assert!(schema_diff(&current, &expected).is_empty());
+
+        // A different predicate is drift: the index is recreated.
+        let users = current.tables.get_mut("users").unwrap();
+        users.indexes[0].predicate = Some("deleted_at IS NOT NULL".to_string());
+        let diff = schema_diff(&current, &expected);
+        assert!(diff.operations.iter().any(|op| matches!(
+            op,
+            SchemaOperation::CreateIndex { index, .. }
+                if index.predicate.as_deref() == Some("deleted_at IS NULL")
+        )));
+    }
+
+    struct TestBooking;
+
+    impl Model for TestBooking {
+        const TABLE_NAME: &'static str = "bookings";
+        const PRIMARY_KEY: &'static [&'static str] = &["id"];
+        const TABLE_CONSTRAINTS: &'static [TableConstraint] = &[
+            TableConstraint::Unique(&["room_id", "starts_at"]),
+            TableConstraint::Check("ends_at > starts_at"),
+        ];
+
+        fn fields() -> &'static [FieldInfo] {
+            static FIELDS: &[FieldInfo] = &[
+                FieldInfo::new("id", "id", SqlType::BigInt).primary_key(true),
+                FieldInfo::new("room_id", "room_id", SqlType::BigInt),
+                FieldInfo::new("starts_at", "starts_at", SqlType::Timestamp),
+                FieldInfo::new("ends_at", "ends_at", SqlType::Timestamp),
+            ];
+            FIELDS
+        }
+
+        fn to_row(&self) -> Vec<(&'static str, Value)> {
+            vec![]
+        }
+
+        fn from_row(_row: &Row) -> sqlmodel_core::Result<Self> {
+            Ok(TestBooking)
+        }
+
+        fn primary_key_value(&self) -> Vec<Value> {
+            vec![]
+        }
+
+        fn is_new(&self) -> bool {
+            true
+        }
+    }
+
+    #[test]
+    fn test_table_constraints_in_create_table() {
+        let sql = CreateTable::<TestBooking>::new().build();
+        assert!(sql.contains(
+            "CONSTRAINT \"uk_bookings_room_id_starts_at\" UNIQUE (\"room_id\", \"starts_at\")"
+        ));
+        assert!(sql.contains("CONSTRAINT \"ck_bookings_1\" CHECK (ends_at > starts_at)"));
+
+        let schema = crate::expected::table_schema_from_model::<TestBooking>();
+        assert_eq!(
+            schema.unique_constraints[0].name.as_deref(),
+            Some("uk_bookings_room_id_starts_at")
+        );
+        assert_eq!(
+            schema.check_constraints[0].name.as_deref(),
+            Some("ck_bookings_1")
+        );
+    }
+
     // Test model with default values
     struct TestWithDefault;
 
@@ -1249,8 +1503,9 @@ impl SchemaBuilder {
             return self;
         }
 
-        self.statements
-            .push(CreateTable::<M>::new().if_not_exists().build());
+        let create = CreateTable::<M>::new().if_not_exists();
+        self.statements.push(create.build());
+        self.statements.extend(create.build_indexes());
         self
     }
 
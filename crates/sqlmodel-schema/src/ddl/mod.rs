@@ -11,10 +11,12 @@ pub use mysql::MysqlDdlGenerator;
 pub use postgres::PostgresDdlGenerator;
 pub use sqlite::SqliteDdlGenerator;
 
-use crate::diff::SchemaOperation;
+use crate::diff::{SchemaOperation, fk_effective_name, unique_effective_name};
 use crate::introspect::{
-    ColumnInfo, Dialect, ForeignKeyInfo, IndexInfo, TableInfo, UniqueConstraintInfo,
+    CheckConstraintInfo, ColumnInfo, Dialect, ForeignKeyInfo, IndexInfo, TableInfo,
+    UniqueConstraintInfo,
 };
+use sqlmodel_core::error::{Error, SchemaError, SchemaErrorKind};
 
 /// Generates DDL SQL statements from schema operations.
 pub trait DdlGenerator {
@@ -27,10 +29,34 @@ pub trait DdlGenerator {
     fn generate(&self, op: &SchemaOperation) -> Vec<String>;
 
     /// Generate DDL statements for multiple operations.
+    ///
+    /// Identifiers over the dialect's length limit are logged as warnings;
+    /// use [`try_generate_all`](Self::try_generate_all) to reject them.
     fn generate_all(&self, ops: &[SchemaOperation]) -> Vec<String> {
+        warn_identifier_lengths(self.dialect(), ops);
         ops.iter().flat_map(|op| self.generate(op)).collect()
     }
 
+    /// Generate DDL statements for multiple operations, applying `config`'s
+    /// [`IdentifierLengthPolicy`] first.
+    ///
+    /// # Errors
+    ///
+    /// Fails without generating anything when the policy is
+    /// [`IdentifierLengthPolicy::Error`] and an identifier is too long.
+    fn try_generate_all(
+        &self,
+        ops: &[SchemaOperation],
+        config: &DdlConfig,
+    ) -> Result<Vec<String>, Error> {
+        if config.identifier_length_policy == IdentifierLengthPolicy::Error
+            && let Some(dialect) = dialect_named(self.dialect())
+        {
+            validate_identifier_lengths(ops, dialect, IdentifierLengthPolicy::Error)?;
+        }
+        Ok(self.generate_all(ops))
+    }
+
     /// Generate rollback DDL statements (inverse operations).
     ///
     /// Returns statements in reverse order, suitable for undoing the original operations.
@@ -44,6 +70,13 @@ pub trait DdlGenerator {
     }
 }
 
+/// Options for [`DdlGenerator::try_generate_all`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DdlConfig {
+    /// How identifiers over the dialect's length limit are handled.
+    pub identifier_length_policy: IdentifierLengthPolicy,
+}
+
 /// Create a DDL generator for the given dialect.
 pub fn generator_for_dialect(dialect: Dialect) -> Box<dyn DdlGenerator> {
     match dialect {
@@ -53,6 +86,234 @@ pub fn generator_for_dialect(dialect: Dialect) -> Box<dyn DdlGenerator> {
     }
 }
 
+// ============================================================================
+// Identifier Length Validation
+// ============================================================================
+
+/// Length limit applied to auto-generated constraint and index names.
+///
+/// This is the PostgreSQL limit (63 bytes), which is also within MySQL's 64,
+/// so generated names are stable regardless of the target dialect.
+pub const GENERATED_NAME_MAX_LEN: usize = 63;
+
+/// Build an auto-generated constraint/index name such as `uk_heroes_name`.
+///
+/// Produces `{prefix}_{table}_{suffix}`, shortened with [`shorten_identifier`]
+/// when it would exceed [`GENERATED_NAME_MAX_LEN`].
+pub fn constraint_name(prefix: &str, table: &str, suffix: &str) -> String {
+    shorten_identifier(
+        &format!("{}_{}_{}", prefix, table, suffix),
+        GENERATED_NAME_MAX_LEN,
+    )
+}
+
+/// Shorten an identifier to at most `max_len` bytes with a hashed suffix.
+///
+/// Names that already fit are returned unchanged. Longer names keep as much
+/// of their prefix as fits and end in `_` plus 8 hex digits of a hash of the
+/// full name, so two long names sharing a prefix still shorten to different
+/// identifiers. The result is deterministic across runs and platforms.
+pub fn shorten_identifier(name: &str, max_len: usize) -> String {
+    if name.len() <= max_len {
+        return name.to_string();
+    }
+
+    let suffix = format!("_{:08x}", fnv1a_32(name.as_bytes()));
+    let mut keep = max_len.saturating_sub(suffix.len());
+    while !name.is_char_boundary(keep) {
+        keep -= 1;
+    }
+
+    let mut shortened = name[..keep].trim_end_matches('_').to_string();
+    shortened.push_str(&suffix);
+    shortened
+}
+
+/// 32-bit FNV-1a hash (stable, unlike `std`'s `DefaultHasher`).
+fn fnv1a_32(bytes: &[u8]) -> u32 {
+    let mut hash: u32 = 0x811c_9dc5;
+    for &b in bytes {
+        hash ^= u32::from(b);
+        hash = hash.wrapping_mul(0x0100_0193);
+    }
+    hash
+}
+
+/// How [`validate_identifier_lengths`] reacts to identifiers over the limit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum IdentifierLengthPolicy {
+    /// Log each violation via `tracing` and return them to the caller.
+    #[default]
+    Warn,
+    /// Fail with a schema error listing every violation.
+    Error,
+}
+
+/// An identifier that exceeds the target dialect's length limit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IdentifierLengthViolation {
+    /// What the identifier names (e.g. "index", "foreign key").
+    pub kind: &'static str,
+    /// Table the identifier belongs to.
+    pub table: String,
+    /// The offending identifier.
+    pub name: String,
+    /// The dialect's maximum identifier length in bytes.
+    pub max_len: usize,
+}
+
+impl std::fmt::Display for IdentifierLengthViolation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} name '{}' on table '{}' is {} bytes (limit {})",
+            self.kind,
+            self.name,
+            self.table,
+            self.name.len(),
+            self.max_len
+        )
+    }
+}
+
+/// Check identifiers created by `ops` against the dialect's length limit.
+///
+/// PostgreSQL silently truncates identifiers past 63 bytes and MySQL rejects
+/// names past 64, so two long constraint names can collide or fail only once
+/// the DDL runs. This pass catches them before generation. Unnamed foreign
+/// keys and unique constraints are checked under the name the generators
+/// will assign them.
+///
+/// With [`IdentifierLengthPolicy::Warn`] the violations are logged and
+/// returned; with [`IdentifierLengthPolicy::Error`] any violation fails.
+pub fn validate_identifier_lengths(
+    ops: &[SchemaOperation],
+    dialect: Dialect,
+    policy: IdentifierLengthPolicy,
+) -> Result<Vec<IdentifierLengthViolation>, Error> {
+    let Some(max_len) = dialect.max_identifier_length() else {
+        return Ok(Vec::new());
+    };
+
+    let mut violations = Vec::new();
+    let mut check = |kind: &'static str, table: &str, name: &str| {
+        if name.len() > max_len {
+            violations.push(IdentifierLengthViolation {
+                kind,
+                table: table.to_string(),
+                name: name.to_string(),
+                max_len,
+            });
+        }
+    };
+
+    for op in ops {
+        match op {
+            SchemaOperation::CreateTable(table) => {
+                check("table", &table.name, &table.name);
+                for col in &table.columns {
+                    check("column", &table.name, &col.name);
+                }
+                for fk in &table.foreign_keys {
+                    check(
+                        "foreign key",
+                        &table.name,
+                        &fk_effective_name(&table.name, fk),
+                    );
+                }
+                for uk in &table.unique_constraints {
+                    check(
+                        "unique constraint",
+                        &table.name,
+                        &unique_effective_name(&table.name, uk),
+                    );
+                }
+                for ck in table
+                    .check_constraints
+                    .iter()
+                    .filter_map(|ck| ck.name.as_ref())
+                {
+                    check("check constraint", &table.name, ck);
+                }
+                for idx in &table.indexes {
+                    check("index", &table.name, &idx.name);
+                }
+            }
+            SchemaOperation::RenameTable { to, .. } => check("table", to, to),
+            SchemaOperation::AddColumn { table, column } => check("column", table, &column.name),
+            SchemaOperation::RenameColumn { table, to, .. } => check("column", table, to),
+            SchemaOperation::AddForeignKey { table, fk, .. } => {
+                check("foreign key", table, &fk_effective_name(table, fk));
+            }
+            SchemaOperation::AddUnique {
+                table, constraint, ..
+            } => check(
+                "unique constraint",
+                table,
+                &unique_effective_name(table, constraint),
+            ),
+            SchemaOperation::AddCheck {
+                table, constraint, ..
+            } => {
+                if let Some(name) = &constraint.name {
+                    check("check constraint", table, name);
+                }
+            }
+            SchemaOperation::CreateIndex { table, index } => check("index", table, &index.name),
+            _ => {}
+        }
+    }
+
+    if violations.is_empty() {
+        return Ok(violations);
+    }
+
+    match policy {
+        IdentifierLengthPolicy::Warn => {
+            for v in &violations {
+                tracing::warn!(
+                    kind = v.kind,
+                    table = %v.table,
+                    name = %v.name,
+                    max_len = v.max_len,
+                    "Identifier exceeds dialect length limit"
+                );
+            }
+            Ok(violations)
+        }
+        IdentifierLengthPolicy::Error => {
+            let details: Vec<String> = violations.iter().map(ToString::to_string).collect();
+            Err(Error::Schema(SchemaError {
+                kind: SchemaErrorKind::Invalid,
+                message: format!(
+                    "identifiers exceed the {}-byte limit: {}",
+                    max_len,
+                    details.join("; ")
+                ),
+                source: None,
+            }))
+        }
+    }
+}
+
+/// Map a generator's [`DdlGenerator::dialect`] name to its [`Dialect`].
+fn dialect_named(name: &str) -> Option<Dialect> {
+    match name {
+        "sqlite" => Some(Dialect::Sqlite),
+        "mysql" => Some(Dialect::Mysql),
+        "postgres" => Some(Dialect::Postgres),
+        _ => None,
+    }
+}
+
+/// Run the identifier length pass under [`IdentifierLengthPolicy::Warn`].
+fn warn_identifier_lengths(dialect: &str, ops: &[SchemaOperation]) {
+    if let Some(dialect) = dialect_named(dialect) {
+        // Warn never fails; violations are logged by the pass itself.
+        let _ = validate_identifier_lengths(ops, dialect, IdentifierLengthPolicy::Warn);
+    }
+}
+
 // ============================================================================
 // Shared Helpers
 // ============================================================================
@@ -71,14 +332,36 @@ fn quote_identifier(name: &str, dialect: Dialect) -> String {
 
 /// Format a column definition for CREATE TABLE or ADD COLUMN.
 fn format_column_def(col: &ColumnInfo, dialect: Dialect) -> String {
-    let mut parts = vec![quote_identifier(&col.name, dialect), col.sql_type.clone()];
+    let sql_type = match col.custom_type {
+        Some(custom) if col.sql_type == custom.name() => match dialect {
+            // PostgreSQL creates custom types under quoted names; refer to
+            // them the same way so mixed-case names resolve.
+            Dialect::Postgres => quote_identifier(&col.sql_type, dialect),
+            // No enum/domain types here: use the built-in base type.
+            Dialect::Sqlite | Dialect::Mysql => custom.base_type().to_string(),
+        },
+        _ => col.sql_type.clone(),
+    };
+    let mut parts = vec![quote_identifier(&col.name, dialect), sql_type];
+
+    if let Some(ref collation) = col.collation {
+        parts.push(format!("COLLATE {}", quote_identifier(collation, dialect)));
+    }
 
     if !col.nullable {
         parts.push("NOT NULL".to_string());
     }
 
-    if let Some(ref default) = col.default {
-        parts.push(format!("DEFAULT {}", default));
+    if let Some(ref expression) = col.generated {
+        // PostgreSQL only supports stored generated columns.
+        let kind = if col.stored || dialect == Dialect::Postgres {
+            "STORED"
+        } else {
+            "VIRTUAL"
+        };
+        parts.push(format!("GENERATED ALWAYS AS ({expression}) {kind}"));
+    } else if let Some(ref default) = col.default {
+        parts.push(format!("DEFAULT {}", dialect.default_expr(default)));
     }
 
     // Auto-increment handling varies by dialect
@@ -131,6 +414,14 @@ fn format_fk_constraint(fk: &ForeignKeyInfo, dialect: Dialect) -> String {
         sql.push_str(&format!(" ON UPDATE {}", on_update));
     }
 
+    // MySQL has no deferrable constraints; PostgreSQL and SQLite share the syntax.
+    if fk.deferrable && dialect != Dialect::Mysql {
+        sql.push_str(" DEFERRABLE");
+        if fk.initially_deferred {
+            sql.push_str(" INITIALLY DEFERRED");
+        }
+    }
+
     sql
 }
 
@@ -153,6 +444,19 @@ fn format_unique_constraint(unique: &UniqueConstraintInfo, dialect: Dialect) ->
     }
 }
 
+/// Format a check constraint clause.
+fn format_check_constraint(check: &CheckConstraintInfo, dialect: Dialect) -> String {
+    if let Some(ref name) = check.name {
+        format!(
+            "CONSTRAINT {} CHECK ({})",
+            quote_identifier(name, dialect),
+            check.expression
+        )
+    } else {
+        format!("CHECK ({})", check.expression)
+    }
+}
+
 /// Generate CREATE TABLE SQL with configurable `IF NOT EXISTS`.
 ///
 /// Kept private to `ddl` and its submodules (SQLite drop-column needs a
@@ -195,6 +499,11 @@ fn generate_create_table_with_if_not_exists(
         parts.push(format!("  {}", format_unique_constraint(unique, dialect)));
     }
 
+    // Check constraints
+    for check in &table.check_constraints {
+        parts.push(format!("  {}", format_check_constraint(check, dialect)));
+    }
+
     // Foreign key constraints
     for fk in &table.foreign_keys {
         parts.push(format!("  {}", format_fk_constraint(fk, dialect)));
@@ -302,6 +611,7 @@ fn generate_create_index(table: &str, index: &IndexInfo, dialect: Dialect) -> St
         .columns
         .iter()
         .map(|c| quote_identifier(c, dialect))
+        .chain(index.expressions.iter().map(|e| format!("({e})")))
         .collect();
 
     // Include index type for databases that support it
@@ -327,13 +637,20 @@ fn generate_create_index(table: &str, index: &IndexInfo, dialect: Dialect) -> St
         Dialect::Sqlite => String::new(),
     };
 
+    // MySQL has no partial indexes.
+    let predicate = match (&index.predicate, dialect) {
+        (Some(predicate), Dialect::Postgres | Dialect::Sqlite) => format!(" WHERE {predicate}"),
+        _ => String::new(),
+    };
+
     format!(
-        "CREATE {}INDEX {} ON {}{}({})",
+        "CREATE {}INDEX {} ON {}{}({}){}",
         unique,
         quote_identifier(&index.name, dialect),
         quote_identifier(table, dialect),
         using,
-        cols.join(", ")
+        cols.join(", "),
+        predicate
     )
 }
 
@@ -365,6 +682,7 @@ fn generate_drop_index(table: &str, index_name: &str, dialect: Dialect) -> Strin
 mod tests {
     use super::*;
     use crate::introspect::ParsedSqlType;
+    use sqlmodel_core::CustomSqlType;
 
     fn make_column(name: &str, sql_type: &str, nullable: bool) -> ColumnInfo {
         ColumnInfo {
@@ -376,6 +694,10 @@ mod tests {
             primary_key: false,
             auto_increment: false,
             comment: None,
+            custom_type: None,
+            generated: None,
+            stored: false,
+            collation: None,
         }
     }
 
@@ -433,6 +755,65 @@ mod tests {
         assert!(def.contains("DEFAULT 'active'"));
     }
 
+    #[test]
+    fn test_format_column_def_custom_type_falls_back_to_base_type() {
+        let mut col = make_column("status", "Status", false);
+        col.custom_type = Some(CustomSqlType::Enum {
+            name: "Status",
+            variants: &["active", "retired"],
+        });
+        assert!(format_column_def(&col, Dialect::Postgres).contains("\"Status\" NOT NULL"));
+        assert!(format_column_def(&col, Dialect::Sqlite).contains("\"status\" TEXT NOT NULL"));
+        assert!(format_column_def(&col, Dialect::Mysql).contains("`status` TEXT NOT NULL"));
+
+        let mut col = make_column("power", "power_level", false);
+        col.custom_type = Some(CustomSqlType::Domain {
+            name: "power_level",
+            base_type: "INTEGER",
+            check: Some("VALUE >= 0"),
+        });
+        assert!(format_column_def(&col, Dialect::Mysql).contains("`power` INTEGER NOT NULL"));
+    }
+
+    #[test]
+    fn test_format_column_def_boolean_default_per_dialect() {
+        let mut col = make_column("active", "BOOLEAN", false);
+        col.default = Some("true".to_string());
+        assert_eq!(
+            format_column_def(&col, Dialect::Postgres),
+            "\"active\" BOOLEAN NOT NULL DEFAULT TRUE"
+        );
+        assert_eq!(
+            format_column_def(&col, Dialect::Sqlite),
+            "\"active\" BOOLEAN NOT NULL DEFAULT 1"
+        );
+        col.default = Some("FALSE".to_string());
+        assert_eq!(
+            format_column_def(&col, Dialect::Mysql),
+            "`active` BOOLEAN NOT NULL DEFAULT 0"
+        );
+    }
+
+    #[test]
+    fn test_format_column_def_generated() {
+        let mut col = make_column("total", "INTEGER", true);
+        col.generated = Some("price * qty".to_string());
+        col.default = Some("0".to_string());
+        assert_eq!(
+            format_column_def(&col, Dialect::Sqlite),
+            "\"total\" INTEGER GENERATED ALWAYS AS (price * qty) VIRTUAL"
+        );
+        assert_eq!(
+            format_column_def(&col, Dialect::Postgres),
+            "\"total\" INTEGER GENERATED ALWAYS AS (price * qty) STORED"
+        );
+        col.stored = true;
+        assert_eq!(
+            format_column_def(&col, Dialect::Mysql),
+            "`total` INTEGER GENERATED ALWAYS AS (price * qty) STORED"
+        );
+    }
+
     #[test]
     fn test_format_column_def_auto_increment_mysql() {
         let mut col = make_column("id", "INT", false);
@@ -476,6 +857,8 @@ mod tests {
             foreign_column: "id".to_string(),
             on_delete: Some("CASCADE".to_string()),
             on_update: None,
+            deferrable: false,
+            initially_deferred: false,
         });
 
         let sql = generate_create_table(&table, Dialect::Sqlite);
@@ -527,6 +910,8 @@ mod tests {
             unique: false,
             index_type: None,
             primary: false,
+            expressions: Vec::new(),
+            predicate: None,
         };
         let sql = generate_create_index("heroes", &index, Dialect::Sqlite);
         assert!(sql.contains("CREATE INDEX"));
@@ -542,11 +927,31 @@ mod tests {
             unique: true,
             index_type: None,
             primary: false,
+            expressions: Vec::new(),
+            predicate: None,
         };
         let sql = generate_create_index("heroes", &index, Dialect::Sqlite);
         assert!(sql.contains("CREATE UNIQUE INDEX"));
     }
 
+    #[test]
+    fn test_generate_create_partial_expression_index() {
+        let index = IndexInfo {
+            name: "uk_users_email".to_string(),
+            columns: Vec::new(),
+            unique: true,
+            index_type: None,
+            primary: false,
+            expressions: vec!["lower(email)".to_string()],
+            predicate: Some("deleted_at IS NULL".to_string()),
+        };
+        assert_eq!(
+            generate_create_index("users", &index, Dialect::Postgres),
+            "CREATE UNIQUE INDEX \"uk_users_email\" ON \"users\"((lower(email))) WHERE deleted_at IS NULL"
+        );
+        assert!(!generate_create_index("users", &index, Dialect::Mysql).contains("WHERE"));
+    }
+
     #[test]
     fn test_generate_drop_index_sqlite() {
         let sql = generate_drop_index("heroes", "idx_heroes_name", Dialect::Sqlite);
@@ -588,4 +993,148 @@ mod tests {
         );
         assert_eq!(format_referential_action(None), "NO ACTION");
     }
+
+    #[test]
+    fn test_constraint_name_short_is_unchanged() {
+        assert_eq!(constraint_name("uk", "heroes", "name"), "uk_heroes_name");
+        assert_eq!(
+            constraint_name("fk", "heroes", "team_id"),
+            "fk_heroes_team_id"
+        );
+    }
+
+    #[test]
+    fn test_shorten_identifier_fits_and_is_deterministic() {
+        let long = format!("uk_{}_{}", "t".repeat(40), "c".repeat(40));
+        let short = shorten_identifier(&long, 63);
+        assert_eq!(short.len(), 63);
+        assert!(short.starts_with("uk_tttt"));
+        assert_eq!(short, shorten_identifier(&long, 63));
+        assert_eq!(
+            constraint_name("uk", &"t".repeat(40), &"c".repeat(40)),
+            short
+        );
+    }
+
+    #[test]
+    fn test_shorten_identifier_distinguishes_shared_prefixes() {
+        let table = "verylongtablename_with_many_words_in_it";
+        let a = constraint_name("uk", table, "verylongcolumnname_alpha_first");
+        let b = constraint_name("uk", table, "verylongcolumnname_alpha_second");
+        assert!(a.len() <= GENERATED_NAME_MAX_LEN);
+        assert!(b.len() <= GENERATED_NAME_MAX_LEN);
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn test_shorten_identifier_respects_char_boundaries() {
+        let long = "é".repeat(40);
+        let short = shorten_identifier(&long, 20);
+        assert!(short.len() <= 20);
+        assert!(short.starts_with('é'));
+    }
+
+    #[test]
+    fn test_validate_identifier_lengths_warn() {
+        let mut table = make_table(
+            "heroes",
+            vec![make_column("id", "INTEGER", false)],
+            vec!["id"],
+        );
+        table.indexes.push(IndexInfo {
+            name: "i".repeat(70),
+            columns: vec!["id".to_string()],
+            unique: false,
+            index_type: None,
+            primary: false,
+            expressions: Vec::new(),
+            predicate: None,
+        });
+        let ops = vec![SchemaOperation::CreateTable(table)];
+
+        let violations =
+            validate_identifier_lengths(&ops, Dialect::Postgres, IdentifierLengthPolicy::Warn)
+                .unwrap();
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].kind, "index");
+        assert_eq!(violations[0].max_len, 63);
+
+        // SQLite has no identifier limit.
+        let violations =
+            validate_identifier_lengths(&ops, Dialect::Sqlite, IdentifierLengthPolicy::Error)
+                .unwrap();
+        assert!(violations.is_empty());
+    }
+
+    #[test]
+    fn test_try_generate_all_applies_identifier_policy() {
+        let ops = vec![SchemaOperation::CreateIndex {
+            table: "heroes".to_string(),
+            index: IndexInfo {
+                name: "i".repeat(70),
+                columns: vec!["id".to_string()],
+                unique: false,
+                index_type: None,
+                primary: false,
+                expressions: Vec::new(),
+                predicate: None,
+            },
+        }];
+        let strict = DdlConfig {
+            identifier_length_policy: IdentifierLengthPolicy::Error,
+        };
+
+        let err = PostgresDdlGenerator
+            .try_generate_all(&ops, &strict)
+            .unwrap_err();
+        assert!(err.to_string().contains("index"));
+
+        // Warn still generates the statements.
+        let stmts = MysqlDdlGenerator
+            .try_generate_all(&ops, &DdlConfig::default())
+            .unwrap();
+        assert_eq!(stmts, MysqlDdlGenerator.generate_all(&ops));
+        assert_eq!(stmts.len(), 1);
+
+        // SQLite has no limit to enforce.
+        assert!(SqliteDdlGenerator.try_generate_all(&ops, &strict).is_ok());
+    }
+
+    #[test]
+    fn test_validate_identifier_lengths_error() {
+        let ops = vec![SchemaOperation::AddUnique {
+            table: "heroes".to_string(),
+            constraint: UniqueConstraintInfo {
+                name: Some("u".repeat(65)),
+                columns: vec!["name".to_string()],
+            },
+            table_info: None,
+        }];
+
+        let err = validate_identifier_lengths(&ops, Dialect::Mysql, IdentifierLengthPolicy::Error)
+            .unwrap_err();
+        assert!(err.to_string().contains("unique constraint"));
+
+        // 64 bytes is within MySQL's limit but over PostgreSQL's.
+        let ops = vec![SchemaOperation::CreateIndex {
+            table: "heroes".to_string(),
+            index: IndexInfo {
+                name: "i".repeat(64),
+                columns: vec!["name".to_string()],
+                unique: false,
+                index_type: None,
+                primary: false,
+                expressions: Vec::new(),
+                predicate: None,
+            },
+        }];
+        assert!(
+            validate_identifier_lengths(&ops, Dialect::Mysql, IdentifierLengthPolicy::Error)
+                .is_ok()
+        );
+        assert!(
+            validate_identifier_lengths(&ops, Dialect::Postgres, IdentifierLengthPolicy::Error)
+                .is_err()
+        );
+    }
 }
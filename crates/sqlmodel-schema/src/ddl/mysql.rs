@@ -3,9 +3,10 @@
 //! MySQL has comprehensive ALTER TABLE support for most schema operations.
 
 use super::{
-    DdlGenerator, format_column_def, format_fk_constraint, generate_add_column,
-    generate_create_index, generate_create_table, generate_drop_index, generate_drop_table,
-    generate_rename_column, generate_rename_table, quote_identifier,
+    DdlGenerator, constraint_name, format_check_constraint, format_column_def,
+    format_fk_constraint, generate_add_column, generate_create_index, generate_create_table,
+    generate_drop_index, generate_drop_table, generate_rename_column, generate_rename_table,
+    quote_identifier,
 };
 use crate::diff::SchemaOperation;
 use crate::introspect::Dialect;
@@ -55,15 +56,21 @@ impl DdlGenerator for MysqlDdlGenerator {
                 table,
                 column,
                 to_type,
+                to_collation,
                 ..
             } => {
-                // MySQL uses MODIFY COLUMN for type changes
-                vec![format!(
+                // MySQL uses MODIFY COLUMN for type and collation changes
+                let mut stmt = format!(
                     "ALTER TABLE {} MODIFY COLUMN {} {}",
                     quote_identifier(table, Dialect::Mysql),
                     quote_identifier(column, Dialect::Mysql),
                     to_type
-                )]
+                );
+                if let Some(collation) = to_collation {
+                    stmt.push_str(" COLLATE ");
+                    stmt.push_str(&quote_identifier(collation, Dialect::Mysql));
+                }
+                vec![stmt]
             }
             SchemaOperation::AlterColumnNullable {
                 table,
@@ -93,7 +100,7 @@ impl DdlGenerator for MysqlDdlGenerator {
                         "ALTER TABLE {} ALTER COLUMN {} SET DEFAULT {}",
                         quote_identifier(table, Dialect::Mysql),
                         quote_identifier(column, Dialect::Mysql),
-                        default
+                        Dialect::Mysql.default_expr(default)
                     )]
                 } else {
                     vec![format!(
@@ -131,7 +138,7 @@ impl DdlGenerator for MysqlDdlGenerator {
                 let constraint_name = fk
                     .name
                     .clone()
-                    .unwrap_or_else(|| format!("fk_{}_{}", table, fk.column));
+                    .unwrap_or_else(|| constraint_name("fk", table, &fk.column));
                 vec![format!(
                     "ALTER TABLE {} ADD CONSTRAINT {} {}",
                     quote_identifier(table, Dialect::Mysql),
@@ -159,7 +166,7 @@ impl DdlGenerator for MysqlDdlGenerator {
                 let name = constraint
                     .name
                     .clone()
-                    .unwrap_or_else(|| format!("uk_{}_{}", table, constraint.columns.join("_")));
+                    .unwrap_or_else(|| constraint_name("uk", table, &constraint.columns.join("_")));
                 vec![format!(
                     "ALTER TABLE {} ADD CONSTRAINT {} UNIQUE ({})",
                     quote_identifier(table, Dialect::Mysql),
@@ -176,6 +183,24 @@ impl DdlGenerator for MysqlDdlGenerator {
                 )]
             }
 
+            // Check Constraints
+            SchemaOperation::AddCheck {
+                table, constraint, ..
+            } => {
+                vec![format!(
+                    "ALTER TABLE {} ADD {}",
+                    quote_identifier(table, Dialect::Mysql),
+                    format_check_constraint(constraint, Dialect::Mysql)
+                )]
+            }
+            SchemaOperation::DropCheck { table, name, .. } => {
+                vec![format!(
+                    "ALTER TABLE {} DROP CHECK {}",
+                    quote_identifier(table, Dialect::Mysql),
+                    quote_identifier(name, Dialect::Mysql)
+                )]
+            }
+
             // Indexes
             SchemaOperation::CreateIndex { table, index } => {
                 vec![generate_create_index(table, index, Dialect::Mysql)]
@@ -202,7 +227,8 @@ mod tests {
     use super::*;
     use crate::diff::SchemaOperation;
     use crate::introspect::{
-        ColumnInfo, ForeignKeyInfo, IndexInfo, ParsedSqlType, TableInfo, UniqueConstraintInfo,
+        CheckConstraintInfo, ColumnInfo, ForeignKeyInfo, IndexInfo, ParsedSqlType, TableInfo,
+        UniqueConstraintInfo,
     };
 
     fn make_column(name: &str, sql_type: &str, nullable: bool) -> ColumnInfo {
@@ -215,6 +241,10 @@ mod tests {
             primary_key: false,
             auto_increment: false,
             comment: None,
+            custom_type: None,
+            generated: None,
+            stored: false,
+            collation: None,
         }
     }
 
@@ -267,6 +297,8 @@ mod tests {
             unique: false,
             index_type: None,
             primary: false,
+            expressions: Vec::new(),
+            predicate: None,
         });
 
         let op = SchemaOperation::CreateTable(table);
@@ -338,6 +370,8 @@ mod tests {
             column: "age".to_string(),
             from_type: "INT".to_string(),
             to_type: "BIGINT".to_string(),
+            from_collation: None,
+            to_collation: None,
             table_info: None,
         };
         let stmts = ddl.generate(&op);
@@ -347,6 +381,26 @@ mod tests {
         assert!(stmts[0].contains("BIGINT"));
     }
 
+    #[test]
+    fn test_alter_column_collation() {
+        let ddl = MysqlDdlGenerator;
+        let op = SchemaOperation::AlterColumnType {
+            table: "heroes".to_string(),
+            column: "name".to_string(),
+            from_type: "VARCHAR(100)".to_string(),
+            to_type: "VARCHAR(100)".to_string(),
+            from_collation: Some("utf8mb4_0900_ai_ci".to_string()),
+            to_collation: Some("utf8mb4_bin".to_string()),
+            table_info: None,
+        };
+        let stmts = ddl.generate(&op);
+
+        assert_eq!(
+            stmts,
+            vec!["ALTER TABLE `heroes` MODIFY COLUMN `name` VARCHAR(100) COLLATE `utf8mb4_bin`"]
+        );
+    }
+
     #[test]
     fn test_alter_column_default_set() {
         let ddl = MysqlDdlGenerator;
@@ -433,6 +487,8 @@ mod tests {
                 foreign_column: "id".to_string(),
                 on_delete: Some("CASCADE".to_string()),
                 on_update: None,
+                deferrable: false,
+                initially_deferred: false,
             },
             table_info: None,
         };
@@ -490,6 +546,22 @@ mod tests {
         assert!(stmts[0].contains("DROP INDEX"));
     }
 
+    #[test]
+    fn test_drop_check() {
+        let ddl = MysqlDdlGenerator;
+        let op = SchemaOperation::DropCheck {
+            table: "heroes".to_string(),
+            name: "ck_heroes_1".to_string(),
+            table_info: None,
+        };
+        let stmts = ddl.generate(&op);
+
+        assert_eq!(
+            stmts,
+            vec!["ALTER TABLE `heroes` DROP CHECK `ck_heroes_1`".to_string()]
+        );
+    }
+
     #[test]
     fn test_create_index() {
         let ddl = MysqlDdlGenerator;
@@ -501,6 +573,8 @@ mod tests {
                 unique: false,
                 index_type: None,
                 primary: false,
+                expressions: Vec::new(),
+                predicate: None,
             },
         };
         let stmts = ddl.generate(&op);
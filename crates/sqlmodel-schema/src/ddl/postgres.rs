@@ -3,12 +3,15 @@
 //! PostgreSQL has excellent ALTER TABLE support with fine-grained control over schema changes.
 
 use super::{
-    DdlGenerator, format_fk_constraint, generate_add_column, generate_create_index,
-    generate_create_table, generate_drop_index, generate_drop_table, generate_rename_column,
-    generate_rename_table, quote_identifier,
+    DdlGenerator, constraint_name, format_check_constraint, format_fk_constraint,
+    generate_add_column, generate_create_index, generate_create_table, generate_drop_index,
+    generate_drop_table, generate_rename_column, generate_rename_table, quote_identifier,
+    warn_identifier_lengths,
 };
 use crate::diff::SchemaOperation;
 use crate::introspect::Dialect;
+use sqlmodel_core::CustomSqlType;
+use std::collections::HashSet;
 
 /// DDL generator for PostgreSQL.
 pub struct PostgresDdlGenerator;
@@ -55,16 +58,22 @@ impl DdlGenerator for PostgresDdlGenerator {
                 table,
                 column,
                 to_type,
+                to_collation,
                 ..
             } => {
-                // PostgreSQL uses ALTER COLUMN ... TYPE
-                // USING clause may be needed for type conversion
-                vec![format!(
+                // PostgreSQL uses ALTER COLUMN ... TYPE, which also carries
+                // collation changes. USING clause may be needed for type conversion
+                let mut stmt = format!(
                     "ALTER TABLE {} ALTER COLUMN {} TYPE {}",
                     quote_identifier(table, Dialect::Postgres),
                     quote_identifier(column, Dialect::Postgres),
                     to_type
-                )]
+                );
+                if let Some(collation) = to_collation {
+                    stmt.push_str(" COLLATE ");
+                    stmt.push_str(&quote_identifier(collation, Dialect::Postgres));
+                }
+                vec![stmt]
             }
             SchemaOperation::AlterColumnNullable {
                 table,
@@ -97,7 +106,7 @@ impl DdlGenerator for PostgresDdlGenerator {
                         "ALTER TABLE {} ALTER COLUMN {} SET DEFAULT {}",
                         quote_identifier(table, Dialect::Postgres),
                         quote_identifier(column, Dialect::Postgres),
-                        default
+                        Dialect::Postgres.default_expr(default)
                     )]
                 } else {
                     vec![format!(
@@ -139,7 +148,7 @@ impl DdlGenerator for PostgresDdlGenerator {
                 let constraint_name = fk
                     .name
                     .clone()
-                    .unwrap_or_else(|| format!("fk_{}_{}", table, fk.column));
+                    .unwrap_or_else(|| constraint_name("fk", table, &fk.column));
                 vec![format!(
                     "ALTER TABLE {} ADD CONSTRAINT {} {}",
                     quote_identifier(table, Dialect::Postgres),
@@ -167,7 +176,7 @@ impl DdlGenerator for PostgresDdlGenerator {
                 let name = constraint
                     .name
                     .clone()
-                    .unwrap_or_else(|| format!("uk_{}_{}", table, constraint.columns.join("_")));
+                    .unwrap_or_else(|| constraint_name("uk", table, &constraint.columns.join("_")));
                 vec![format!(
                     "ALTER TABLE {} ADD CONSTRAINT {} UNIQUE ({})",
                     quote_identifier(table, Dialect::Postgres),
@@ -183,6 +192,24 @@ impl DdlGenerator for PostgresDdlGenerator {
                 )]
             }
 
+            // Check Constraints
+            SchemaOperation::AddCheck {
+                table, constraint, ..
+            } => {
+                vec![format!(
+                    "ALTER TABLE {} ADD {}",
+                    quote_identifier(table, Dialect::Postgres),
+                    format_check_constraint(constraint, Dialect::Postgres)
+                )]
+            }
+            SchemaOperation::DropCheck { table, name, .. } => {
+                vec![format!(
+                    "ALTER TABLE {} DROP CONSTRAINT {}",
+                    quote_identifier(table, Dialect::Postgres),
+                    quote_identifier(name, Dialect::Postgres)
+                )]
+            }
+
             // Indexes
             SchemaOperation::CreateIndex { table, index } => {
                 vec![generate_create_index(table, index, Dialect::Postgres)]
@@ -198,6 +225,104 @@ impl DdlGenerator for PostgresDdlGenerator {
 
         statements
     }
+
+    fn generate_all(&self, ops: &[SchemaOperation]) -> Vec<String> {
+        warn_identifier_lengths(self.dialect(), ops);
+        let mut statements: Vec<String> = collect_custom_types(ops)
+            .iter()
+            .map(generate_create_custom_type)
+            .collect();
+        statements.extend(ops.iter().flat_map(|op| self.generate(op)));
+        statements
+    }
+
+    fn generate_rollback(&self, ops: &[SchemaOperation]) -> Vec<String> {
+        let mut statements: Vec<String> = ops
+            .iter()
+            .rev()
+            .filter_map(|op| op.inverse())
+            .flat_map(|op| self.generate(&op))
+            .collect();
+        statements.extend(
+            collect_custom_types(ops)
+                .iter()
+                .rev()
+                .map(generate_drop_custom_type),
+        );
+        statements
+    }
+}
+
+/// Collect the enum/domain types referenced by `CreateTable` operations.
+///
+/// Each type appears once (first definition wins), with enums ordered before
+/// domains so a domain may be based on an enum created in the same batch.
+fn collect_custom_types(ops: &[SchemaOperation]) -> Vec<CustomSqlType> {
+    let mut seen = HashSet::new();
+    let mut types: Vec<CustomSqlType> = ops
+        .iter()
+        .filter_map(|op| match op {
+            SchemaOperation::CreateTable(table) => Some(table),
+            _ => None,
+        })
+        .flat_map(|table| table.columns.iter().filter_map(|col| col.custom_type))
+        .filter(|custom| seen.insert(custom.name()))
+        .collect();
+    types.sort_by_key(|custom| matches!(custom, CustomSqlType::Domain { .. }));
+    types
+}
+
+/// Generate CREATE TYPE ... AS ENUM / CREATE DOMAIN SQL.
+///
+/// PostgreSQL has no `IF NOT EXISTS` for either statement, so it is wrapped
+/// in a `DO` block that first looks the type up in `pg_type`, keeping re-runs
+/// of `generate_all` idempotent like `CREATE TABLE IF NOT EXISTS`. An existing
+/// type is left as is; the schema diff reports enums whose labels differ.
+fn generate_create_custom_type(custom: &CustomSqlType) -> String {
+    let create = match custom {
+        CustomSqlType::Enum { name, variants } => {
+            let labels: Vec<String> = variants
+                .iter()
+                .map(|v| format!("'{}'", v.replace('\'', "''")))
+                .collect();
+            format!(
+                "CREATE TYPE {} AS ENUM ({})",
+                quote_identifier(name, Dialect::Postgres),
+                labels.join(", ")
+            )
+        }
+        CustomSqlType::Domain {
+            name,
+            base_type,
+            check,
+        } => {
+            let name = quote_identifier(name, Dialect::Postgres);
+            match check {
+                Some(check) => format!("CREATE DOMAIN {} AS {} CHECK ({})", name, base_type, check),
+                None => format!("CREATE DOMAIN {} AS {}", name, base_type),
+            }
+        }
+    };
+    format!(
+        "DO $$ BEGIN IF NOT EXISTS (SELECT 1 FROM pg_type WHERE typname = '{}' \
+         AND typnamespace = current_schema()::regnamespace) THEN {}; END IF; END $$",
+        custom.name().replace('\'', "''"),
+        create
+    )
+}
+
+/// Generate DROP TYPE / DROP DOMAIN SQL.
+fn generate_drop_custom_type(custom: &CustomSqlType) -> String {
+    match custom {
+        CustomSqlType::Enum { name, .. } => format!(
+            "DROP TYPE IF EXISTS {}",
+            quote_identifier(name, Dialect::Postgres)
+        ),
+        CustomSqlType::Domain { name, .. } => format!(
+            "DROP DOMAIN IF EXISTS {}",
+            quote_identifier(name, Dialect::Postgres)
+        ),
+    }
 }
 
 // ============================================================================
@@ -209,7 +334,8 @@ mod tests {
     use super::*;
     use crate::diff::SchemaOperation;
     use crate::introspect::{
-        ColumnInfo, ForeignKeyInfo, IndexInfo, ParsedSqlType, TableInfo, UniqueConstraintInfo,
+        CheckConstraintInfo, ColumnInfo, ForeignKeyInfo, IndexInfo, ParsedSqlType, TableInfo,
+        UniqueConstraintInfo,
     };
 
     fn make_column(name: &str, sql_type: &str, nullable: bool) -> ColumnInfo {
@@ -222,6 +348,10 @@ mod tests {
             primary_key: false,
             auto_increment: false,
             comment: None,
+            custom_type: None,
+            generated: None,
+            stored: false,
+            collation: None,
         }
     }
 
@@ -274,6 +404,8 @@ mod tests {
             unique: false,
             index_type: Some("btree".to_string()),
             primary: false,
+            expressions: Vec::new(),
+            predicate: None,
         });
 
         let op = SchemaOperation::CreateTable(table);
@@ -285,6 +417,85 @@ mod tests {
         assert!(stmts[1].contains("\"idx_heroes_name\""));
     }
 
+    #[test]
+    fn test_generate_all_creates_shared_enum_once_before_tables() {
+        let ddl = PostgresDdlGenerator;
+        let status = CustomSqlType::Enum {
+            name: "hero_status",
+            variants: &["active", "retired", "o'brien"],
+        };
+        let power = CustomSqlType::Domain {
+            name: "power_level",
+            base_type: "INTEGER",
+            check: Some("VALUE BETWEEN 0 AND 9000"),
+        };
+
+        let mut heroes_status = make_column("status", "hero_status", false);
+        heroes_status.custom_type = Some(status);
+        let mut heroes_power = make_column("power", "power_level", false);
+        heroes_power.custom_type = Some(power);
+        let mut villains_status = make_column("status", "hero_status", false);
+        villains_status.custom_type = Some(status);
+
+        let ops = vec![
+            SchemaOperation::CreateTable(make_table(
+                "heroes",
+                vec![
+                    make_column("id", "SERIAL", false),
+                    heroes_power,
+                    heroes_status,
+                ],
+                vec!["id"],
+            )),
+            SchemaOperation::CreateTable(make_table(
+                "villains",
+                vec![make_column("id", "SERIAL", false), villains_status],
+                vec!["id"],
+            )),
+        ];
+
+        let stmts = ddl.generate_all(&ops);
+        assert_eq!(stmts.len(), 4);
+        assert_eq!(
+            stmts[0],
+            "DO $$ BEGIN IF NOT EXISTS (SELECT 1 FROM pg_type WHERE typname = 'hero_status' \
+             AND typnamespace = current_schema()::regnamespace) THEN \
+             CREATE TYPE \"hero_status\" AS ENUM ('active', 'retired', 'o''brien'); END IF; END $$"
+        );
+        assert_eq!(
+            stmts[1],
+            "DO $$ BEGIN IF NOT EXISTS (SELECT 1 FROM pg_type WHERE typname = 'power_level' \
+             AND typnamespace = current_schema()::regnamespace) THEN \
+             CREATE DOMAIN \"power_level\" AS INTEGER CHECK (VALUE BETWEEN 0 AND 9000); END IF; END $$"
+        );
+        assert!(stmts[2].contains("CREATE TABLE IF NOT EXISTS \"heroes\""));
+        assert!(stmts[2].contains("\"status\" \"hero_status\" NOT NULL"));
+        assert!(stmts[3].contains("CREATE TABLE IF NOT EXISTS \"villains\""));
+
+        let rollback = ddl.generate_rollback(&ops);
+        assert_eq!(
+            rollback,
+            vec![
+                "DROP TABLE IF EXISTS \"villains\"".to_string(),
+                "DROP TABLE IF EXISTS \"heroes\"".to_string(),
+                "DROP DOMAIN IF EXISTS \"power_level\"".to_string(),
+                "DROP TYPE IF EXISTS \"hero_status\"".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_generate_all_without_custom_types_is_unchanged() {
+        let ddl = PostgresDdlGenerator;
+        let ops = vec![SchemaOperation::CreateTable(make_table(
+            "heroes",
+            vec![make_column("id", "SERIAL", false)],
+            vec!["id"],
+        ))];
+        assert_eq!(ddl.generate_all(&ops).len(), 1);
+        assert_eq!(ddl.generate_rollback(&ops).len(), 1);
+    }
+
     #[test]
     fn test_drop_table() {
         let ddl = PostgresDdlGenerator;
@@ -346,6 +557,8 @@ mod tests {
             column: "age".to_string(),
             from_type: "INTEGER".to_string(),
             to_type: "BIGINT".to_string(),
+            from_collation: None,
+            to_collation: None,
             table_info: None,
         };
         let stmts = ddl.generate(&op);
@@ -355,6 +568,34 @@ mod tests {
         assert!(stmts[0].contains("TYPE BIGINT"));
     }
 
+    #[test]
+    fn test_alter_column_collation() {
+        let ddl = PostgresDdlGenerator;
+        let op = SchemaOperation::AlterColumnType {
+            table: "heroes".to_string(),
+            column: "name".to_string(),
+            from_type: "TEXT".to_string(),
+            to_type: "TEXT".to_string(),
+            from_collation: None,
+            to_collation: Some("C".to_string()),
+            table_info: None,
+        };
+        let stmts = ddl.generate(&op);
+
+        assert_eq!(
+            stmts,
+            vec!["ALTER TABLE \"heroes\" ALTER COLUMN \"name\" TYPE TEXT COLLATE \"C\""]
+        );
+
+        let mut col = make_column("name", "TEXT", false);
+        col.collation = Some("C".to_string());
+        let stmts = ddl.generate(&SchemaOperation::AddColumn {
+            table: "heroes".to_string(),
+            column: col,
+        });
+        assert!(stmts[0].contains("\"name\" TEXT COLLATE \"C\" NOT NULL"));
+    }
+
     #[test]
     fn test_alter_column_set_not_null() {
         let ddl = PostgresDdlGenerator;
@@ -474,6 +715,8 @@ mod tests {
                 foreign_column: "id".to_string(),
                 on_delete: Some("CASCADE".to_string()),
                 on_update: None,
+                deferrable: false,
+                initially_deferred: false,
             },
             table_info: None,
         };
@@ -485,6 +728,29 @@ mod tests {
         assert!(stmts[0].contains("ON DELETE CASCADE"));
     }
 
+    #[test]
+    fn test_add_deferrable_foreign_key() {
+        let ddl = PostgresDdlGenerator;
+        let op = SchemaOperation::AddForeignKey {
+            table: "teams".to_string(),
+            fk: ForeignKeyInfo {
+                name: Some("fk_teams_captain".to_string()),
+                column: "captain_id".to_string(),
+                foreign_table: "heroes".to_string(),
+                foreign_column: "id".to_string(),
+                on_delete: None,
+                on_update: None,
+                deferrable: true,
+                initially_deferred: true,
+            },
+            table_info: None,
+        };
+        let stmts = ddl.generate(&op);
+
+        assert_eq!(stmts.len(), 1);
+        assert!(stmts[0].ends_with("REFERENCES \"heroes\"(\"id\") DEFERRABLE INITIALLY DEFERRED"));
+    }
+
     #[test]
     fn test_drop_foreign_key() {
         let ddl = PostgresDdlGenerator;
@@ -532,6 +798,32 @@ mod tests {
         assert!(stmts[0].contains("DROP CONSTRAINT"));
     }
 
+    #[test]
+    fn test_add_and_drop_check() {
+        let ddl = PostgresDdlGenerator;
+        let op = SchemaOperation::AddCheck {
+            table: "heroes".to_string(),
+            constraint: CheckConstraintInfo {
+                name: Some("ck_heroes_1".to_string()),
+                expression: "age >= 0".to_string(),
+            },
+            table_info: None,
+        };
+        assert_eq!(
+            ddl.generate(&op),
+            vec![
+                "ALTER TABLE \"heroes\" ADD CONSTRAINT \"ck_heroes_1\" CHECK (age >= 0)"
+                    .to_string()
+            ]
+        );
+
+        let stmts = ddl.generate(&op.inverse().unwrap());
+        assert_eq!(
+            stmts,
+            vec!["ALTER TABLE \"heroes\" DROP CONSTRAINT \"ck_heroes_1\"".to_string()]
+        );
+    }
+
     #[test]
     fn test_create_index() {
         let ddl = PostgresDdlGenerator;
@@ -543,6 +835,8 @@ mod tests {
                 unique: false,
                 index_type: Some("btree".to_string()),
                 primary: false,
+                expressions: Vec::new(),
+                predicate: None,
             },
         };
         let stmts = ddl.generate(&op);
@@ -563,6 +857,8 @@ mod tests {
                 unique: false,
                 index_type: Some("gin".to_string()),
                 primary: false,
+                expressions: Vec::new(),
+                predicate: None,
             },
         };
         let stmts = ddl.generate(&op);
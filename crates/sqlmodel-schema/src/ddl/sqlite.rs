@@ -3,12 +3,14 @@
 //! SQLite has limited ALTER TABLE support, requiring table recreation for some operations.
 
 use super::{
-    DdlGenerator, generate_add_column, generate_create_index, generate_create_table,
-    generate_drop_index, generate_drop_table, generate_rename_column, generate_rename_table,
-    quote_identifier,
+    DdlGenerator, constraint_name, generate_add_column, generate_create_index,
+    generate_create_table, generate_drop_index, generate_drop_table, generate_rename_column,
+    generate_rename_table, quote_identifier,
+};
+use crate::diff::{SchemaOperation, check_effective_name};
+use crate::introspect::{
+    CheckConstraintInfo, Dialect, ForeignKeyInfo, TableInfo, UniqueConstraintInfo,
 };
-use crate::diff::SchemaOperation;
-use crate::introspect::{Dialect, ForeignKeyInfo, TableInfo, UniqueConstraintInfo};
 
 /// DDL generator for SQLite.
 pub struct SqliteDdlGenerator;
@@ -37,10 +39,9 @@ impl DdlGenerator for SqliteDdlGenerator {
                         .iter()
                         .map(|c| quote_identifier(c, Dialect::Sqlite))
                         .collect();
-                    let name = uk
-                        .name
-                        .clone()
-                        .unwrap_or_else(|| format!("uk_{}_{}", table.name, uk.columns.join("_")));
+                    let name = uk.name.clone().unwrap_or_else(|| {
+                        constraint_name("uk", &table.name, &uk.columns.join("_"))
+                    });
                     stmts.push(format!(
                         "CREATE UNIQUE INDEX {} ON {}({})",
                         quote_identifier(&name, Dialect::Sqlite),
@@ -91,11 +92,18 @@ impl DdlGenerator for SqliteDdlGenerator {
                 table,
                 column,
                 to_type,
+                to_collation,
                 table_info,
                 ..
             } => {
+                // SQLite can't alter a column's type or collation in place.
                 if let Some(table_info) = table_info {
-                    sqlite_alter_column_type_recreate(table_info, column, to_type)
+                    sqlite_alter_column_type_recreate(
+                        table_info,
+                        column,
+                        to_type,
+                        to_collation.as_deref(),
+                    )
                 } else {
                     vec![format!(
                         "SELECT __sqlmodel_error__('SQLite ALTER COLUMN TYPE requires table_info: {}.{} -> {}')",
@@ -214,7 +222,7 @@ impl DdlGenerator for SqliteDdlGenerator {
                 let name = constraint
                     .name
                     .clone()
-                    .unwrap_or_else(|| format!("uk_{}_{}", table, constraint.columns.join("_")));
+                    .unwrap_or_else(|| constraint_name("uk", table, &constraint.columns.join("_")));
                 vec![format!(
                     "CREATE UNIQUE INDEX {} ON {}({})",
                     quote_identifier(&name, Dialect::Sqlite),
@@ -244,6 +252,38 @@ impl DdlGenerator for SqliteDdlGenerator {
                 }
             }
 
+            // Check Constraints
+            SchemaOperation::AddCheck {
+                table,
+                constraint,
+                table_info,
+            } => {
+                if let Some(table_info) = table_info {
+                    sqlite_add_check_recreate(table_info, constraint)
+                } else {
+                    vec![format!(
+                        "SELECT __sqlmodel_error__('SQLite ADD CHECK requires table_info: {}.{}')",
+                        sanitize_temp_ident(table),
+                        sanitize_temp_ident(&check_effective_name(table, constraint))
+                    )]
+                }
+            }
+            SchemaOperation::DropCheck {
+                table,
+                name,
+                table_info,
+            } => {
+                if let Some(table_info) = table_info {
+                    sqlite_drop_check_recreate(table_info, name)
+                } else {
+                    vec![format!(
+                        "SELECT __sqlmodel_error__('SQLite DROP CHECK requires table_info: {}.{}')",
+                        sanitize_temp_ident(table),
+                        sanitize_temp_ident(name)
+                    )]
+                }
+            }
+
             // Indexes
             SchemaOperation::CreateIndex { table, index } => {
                 vec![generate_create_index(table, index, Dialect::Sqlite)]
@@ -296,6 +336,20 @@ fn sqlite_recreate_table(
         super::generate_create_table_with_if_not_exists(&create_table, Dialect::Sqlite, false),
     ];
 
+    // Generated columns are recomputed by SQLite and cannot be inserted into.
+    let generated: Vec<String> = new_table
+        .columns
+        .iter()
+        .filter(|c| c.generated.is_some())
+        .map(|c| quote_identifier(&c.name, Dialect::Sqlite))
+        .collect();
+    let (insert_cols, select_exprs): (Vec<String>, Vec<String>) = insert_cols
+        .iter()
+        .zip(select_exprs)
+        .filter(|(col, _)| !generated.contains(col))
+        .map(|(col, expr)| (col.clone(), expr.clone()))
+        .unzip();
+
     stmts.push(format!(
         "INSERT INTO {} ({}) SELECT {} FROM {}",
         quote_identifier(table_name, Dialect::Sqlite),
@@ -315,7 +369,7 @@ fn sqlite_recreate_table(
         let name = uk
             .name
             .clone()
-            .unwrap_or_else(|| format!("uk_{}_{}", table_name, uk.columns.join("_")));
+            .unwrap_or_else(|| constraint_name("uk", table_name, &uk.columns.join("_")));
         stmts.push(format!(
             "CREATE UNIQUE INDEX {} ON {}({})",
             quote_identifier(&name, Dialect::Sqlite),
@@ -339,13 +393,13 @@ fn sqlite_recreate_table(
 fn sqlite_fk_effective_name(table: &str, fk: &ForeignKeyInfo) -> String {
     fk.name
         .clone()
-        .unwrap_or_else(|| format!("fk_{}_{}", table, fk.column))
+        .unwrap_or_else(|| constraint_name("fk", table, &fk.column))
 }
 
 fn sqlite_unique_effective_name(table: &str, uk: &UniqueConstraintInfo) -> String {
     uk.name
         .clone()
-        .unwrap_or_else(|| format!("uk_{}_{}", table, uk.columns.join("_")))
+        .unwrap_or_else(|| constraint_name("uk", table, &uk.columns.join("_")))
 }
 
 fn sqlite_add_primary_key_recreate(table: &TableInfo, pk_columns: &[String]) -> Vec<String> {
@@ -452,6 +506,52 @@ fn sqlite_drop_unique_recreate(table: &TableInfo, name: &str) -> Vec<String> {
     sqlite_recreate_table(&new_table, &tmp_old, &cols, &cols)
 }
 
+fn sqlite_add_check_recreate(table: &TableInfo, check: &CheckConstraintInfo) -> Vec<String> {
+    let table_name = table.name.as_str();
+    let name = check_effective_name(table_name, check);
+    let tmp_old = format!(
+        "__sqlmodel_old_{}_add_ck_{}",
+        sanitize_temp_ident(table_name),
+        sanitize_temp_ident(&name)
+    );
+
+    let mut new_table = table.clone();
+    new_table
+        .check_constraints
+        .retain(|ck| check_effective_name(table_name, ck) != name);
+    new_table.check_constraints.push(check.clone());
+
+    let cols: Vec<String> = new_table
+        .columns
+        .iter()
+        .map(|c| quote_identifier(&c.name, Dialect::Sqlite))
+        .collect();
+
+    sqlite_recreate_table(&new_table, &tmp_old, &cols, &cols)
+}
+
+fn sqlite_drop_check_recreate(table: &TableInfo, name: &str) -> Vec<String> {
+    let table_name = table.name.as_str();
+    let tmp_old = format!(
+        "__sqlmodel_old_{}_drop_ck_{}",
+        sanitize_temp_ident(table_name),
+        sanitize_temp_ident(name)
+    );
+
+    let mut new_table = table.clone();
+    new_table
+        .check_constraints
+        .retain(|ck| check_effective_name(table_name, ck) != name);
+
+    let cols: Vec<String> = new_table
+        .columns
+        .iter()
+        .map(|c| quote_identifier(&c.name, Dialect::Sqlite))
+        .collect();
+
+    sqlite_recreate_table(&new_table, &tmp_old, &cols, &cols)
+}
+
 fn sqlite_drop_column_recreate(table: &TableInfo, drop_column: &str) -> Vec<String> {
     let table_name = table.name.as_str();
     let drop_column = drop_column.to_string();
@@ -473,6 +573,12 @@ fn sqlite_drop_column_recreate(table: &TableInfo, drop_column: &str) -> Vec<Stri
     new_table
         .indexes
         .retain(|idx| !idx.columns.iter().any(|c| c == &drop_column));
+    // A check naming the dropped column would fail the CREATE TABLE.
+    new_table.check_constraints.retain(|ck| {
+        !ck.expression
+            .split(|c: char| !(c.is_alphanumeric() || c == '_'))
+            .any(|word| word.eq_ignore_ascii_case(&drop_column))
+    });
 
     if new_table.columns.is_empty() {
         return vec![format!(
@@ -500,6 +606,7 @@ fn sqlite_alter_column_type_recreate(
     table: &TableInfo,
     column: &str,
     to_type: &str,
+    to_collation: Option<&str>,
 ) -> Vec<String> {
     let table_name = table.name.as_str();
     let tmp_old = format!(
@@ -513,6 +620,7 @@ fn sqlite_alter_column_type_recreate(
         if col.name == column {
             col.sql_type = to_type.to_string();
             col.parsed_type = crate::introspect::ParsedSqlType::parse(to_type);
+            col.collation = to_collation.map(String::from);
         }
     }
 
@@ -616,6 +724,10 @@ mod tests {
             primary_key: false,
             auto_increment: false,
             comment: None,
+            custom_type: None,
+            generated: None,
+            stored: false,
+            collation: None,
         }
     }
 
@@ -668,6 +780,8 @@ mod tests {
             unique: false,
             index_type: None,
             primary: false,
+            expressions: Vec::new(),
+            predicate: None,
         });
         let op = SchemaOperation::CreateTable(table);
         let stmts = ddl.generate(&op);
@@ -736,6 +850,8 @@ mod tests {
                 unique: false,
                 index_type: None,
                 primary: false,
+                expressions: Vec::new(),
+                predicate: None,
             },
             IndexInfo {
                 name: "idx_old_field".to_string(),
@@ -743,6 +859,8 @@ mod tests {
                 unique: false,
                 index_type: None,
                 primary: false,
+                expressions: Vec::new(),
+                predicate: None,
             },
         ];
         let op = SchemaOperation::DropColumn {
@@ -782,6 +900,38 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_recreate_skips_generated_columns_in_copy() {
+        let ddl = SqliteDdlGenerator;
+        let mut total = make_column("total", "INTEGER", true);
+        total.generated = Some("price * qty".to_string());
+        let table = make_table(
+            "orders",
+            vec![
+                make_column("id", "INTEGER", false),
+                make_column("price", "INTEGER", false),
+                make_column("qty", "INTEGER", false),
+                total,
+                make_column("note", "TEXT", true),
+            ],
+            vec!["id"],
+        );
+        let stmts = ddl.generate(&SchemaOperation::DropColumn {
+            table: "orders".to_string(),
+            column: "note".to_string(),
+            table_info: Some(table),
+        });
+
+        assert!(
+            stmts
+                .iter()
+                .any(|s| s.contains("CREATE TABLE") && s.contains("GENERATED ALWAYS AS"))
+        );
+        let insert = stmts.iter().find(|s| s.starts_with("INSERT INTO")).unwrap();
+        assert!(insert.contains("\"price\", \"qty\""));
+        assert!(!insert.contains("\"total\""));
+    }
+
     #[test]
     fn test_alter_column_type_via_recreate() {
         let ddl = SqliteDdlGenerator;
@@ -798,6 +948,8 @@ mod tests {
             column: "age".to_string(),
             from_type: "INTEGER".to_string(),
             to_type: "TEXT".to_string(),
+            from_collation: None,
+            to_collation: None,
             table_info: Some(table),
         };
         let stmts = ddl.generate(&op);
@@ -811,6 +963,73 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_alter_column_collation_via_recreate() {
+        let ddl = SqliteDdlGenerator;
+        let table = make_table(
+            "heroes",
+            vec![
+                make_column("id", "INTEGER", false),
+                make_column("name", "TEXT", false),
+            ],
+            vec!["id"],
+        );
+        let op = SchemaOperation::AlterColumnType {
+            table: "heroes".to_string(),
+            column: "name".to_string(),
+            from_type: "TEXT".to_string(),
+            to_type: "TEXT".to_string(),
+            from_collation: None,
+            to_collation: Some("NOCASE".to_string()),
+            table_info: Some(table),
+        };
+        let stmts = ddl.generate(&op);
+
+        let create = stmts
+            .iter()
+            .find(|s| s.contains("CREATE TABLE \"heroes\""))
+            .unwrap();
+        assert!(create.contains("\"name\" TEXT COLLATE \"NOCASE\" NOT NULL"));
+    }
+
+    #[test]
+    fn test_check_constraints_via_recreate() {
+        let ddl = SqliteDdlGenerator;
+        let mut table = make_table(
+            "heroes",
+            vec![
+                make_column("id", "INTEGER", false),
+                make_column("age", "INTEGER", false),
+            ],
+            vec!["id"],
+        );
+        let op = SchemaOperation::AddCheck {
+            table: "heroes".to_string(),
+            constraint: CheckConstraintInfo {
+                name: Some("ck_heroes_1".to_string()),
+                expression: "age >= 0".to_string(),
+            },
+            table_info: Some(table.clone()),
+        };
+        let stmts = ddl.generate(&op);
+        assert!(stmts.iter().any(|s| s.contains("CREATE TABLE \"heroes\"")
+            && s.contains("CONSTRAINT \"ck_heroes_1\" CHECK (age >= 0)")));
+
+        // Dropping the column a check names drops the check with it.
+        table.check_constraints.push(CheckConstraintInfo {
+            name: None,
+            expression: "age >= 0".to_string(),
+        });
+        let op = SchemaOperation::DropColumn {
+            table: "heroes".to_string(),
+            column: "age".to_string(),
+            table_info: Some(table),
+        };
+        let stmts = ddl.generate(&op);
+        assert!(stmts.iter().any(|s| s.contains("CREATE TABLE \"heroes\"")));
+        assert!(!stmts.iter().any(|s| s.contains("CHECK")));
+    }
+
     #[test]
     fn test_rename_column() {
         let ddl = SqliteDdlGenerator;
@@ -836,6 +1055,8 @@ mod tests {
                 unique: false,
                 index_type: None,
                 primary: false,
+                expressions: Vec::new(),
+                predicate: None,
             },
         };
         let stmts = ddl.generate(&op);
@@ -856,6 +1077,8 @@ mod tests {
                 unique: true,
                 index_type: None,
                 primary: false,
+                expressions: Vec::new(),
+                predicate: None,
             },
         };
         let stmts = ddl.generate(&op);
@@ -906,6 +1129,8 @@ mod tests {
                 foreign_column: "id".to_string(),
                 on_delete: None,
                 on_update: None,
+                deferrable: false,
+                initially_deferred: false,
             },
             table_info: None,
         };
@@ -939,6 +1164,8 @@ mod tests {
                     unique: false,
                     index_type: None,
                     primary: false,
+                    expressions: Vec::new(),
+                    predicate: None,
                 },
             },
         ];
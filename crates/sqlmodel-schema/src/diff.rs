@@ -4,23 +4,37 @@
 //! against an expected schema and generate operations to bring them
 //! into alignment.
 
+use crate::ddl::constraint_name;
 use crate::introspect::{
-    ColumnInfo, DatabaseSchema, Dialect, ForeignKeyInfo, IndexInfo, ParsedSqlType, TableInfo,
-    UniqueConstraintInfo,
+    CheckConstraintInfo, ColumnInfo, DatabaseSchema, Dialect, ForeignKeyInfo, IndexInfo,
+    ParsedSqlType, TableInfo, UniqueConstraintInfo,
 };
+use sqlmodel_core::CustomSqlType;
 use std::collections::{HashMap, HashSet};
 
-fn fk_effective_name(table: &str, fk: &ForeignKeyInfo) -> String {
+pub(crate) fn fk_effective_name(table: &str, fk: &ForeignKeyInfo) -> String {
     fk.name
         .clone()
-        .unwrap_or_else(|| format!("fk_{}_{}", table, fk.column))
+        .unwrap_or_else(|| constraint_name("fk", table, &fk.column))
 }
 
-fn unique_effective_name(table: &str, constraint: &UniqueConstraintInfo) -> String {
+pub(crate) fn unique_effective_name(table: &str, constraint: &UniqueConstraintInfo) -> String {
     constraint
         .name
         .clone()
-        .unwrap_or_else(|| format!("uk_{}_{}", table, constraint.columns.join("_")))
+        .unwrap_or_else(|| constraint_name("uk", table, &constraint.columns.join("_")))
+}
+
+/// Name of a check constraint, deriving one from its expression when unnamed
+/// (SQLite reports unnamed checks).
+pub(crate) fn check_effective_name(table: &str, constraint: &CheckConstraintInfo) -> String {
+    constraint.name.clone().unwrap_or_else(|| {
+        constraint_name(
+            "ck",
+            table,
+            &normalize_generation_expression(&constraint.expression),
+        )
+    })
 }
 
 // ============================================================================
@@ -51,12 +65,16 @@ pub enum SchemaOperation {
         column: String,
         table_info: Option<TableInfo>,
     },
-    /// Change a column's type.
+    /// Change a column's type and/or collation.
+    ///
+    /// A collation-only change keeps `from_type == to_type`.
     AlterColumnType {
         table: String,
         column: String,
         from_type: String,
         to_type: String,
+        from_collation: Option<String>,
+        to_collation: Option<String>,
         table_info: Option<TableInfo>,
     },
     /// Change a column's nullability.
@@ -123,6 +141,20 @@ pub enum SchemaOperation {
         table_info: Option<TableInfo>,
     },
 
+    // Check Constraints
+    /// Add a check constraint.
+    AddCheck {
+        table: String,
+        constraint: CheckConstraintInfo,
+        table_info: Option<TableInfo>,
+    },
+    /// Drop a check constraint.
+    DropCheck {
+        table: String,
+        name: String,
+        table_info: Option<TableInfo>,
+    },
+
     // Indexes
     /// Create an index.
     CreateIndex { table: String, index: IndexInfo },
@@ -133,12 +165,13 @@ pub enum SchemaOperation {
 impl SchemaOperation {
     /// Check if this operation potentially loses data.
     pub fn is_destructive(&self) -> bool {
-        matches!(
-            self,
-            SchemaOperation::DropTable(_)
-                | SchemaOperation::DropColumn { .. }
-                | SchemaOperation::AlterColumnType { .. }
-        )
+        match self {
+            SchemaOperation::DropTable(_) | SchemaOperation::DropColumn { .. } => true,
+            SchemaOperation::AlterColumnType {
+                from_type, to_type, ..
+            } => from_type != to_type,
+            _ => false,
+        }
     }
 
     /// Get the inverse operation for rollback, if possible.
@@ -166,12 +199,16 @@ impl SchemaOperation {
                 column,
                 from_type,
                 to_type,
+                from_collation,
+                to_collation,
                 ..
             } => Some(SchemaOperation::AlterColumnType {
                 table: table.clone(),
                 column: column.clone(),
                 from_type: to_type.clone(),
                 to_type: from_type.clone(),
+                from_collation: to_collation.clone(),
+                to_collation: from_collation.clone(),
                 table_info: None,
             }),
             SchemaOperation::AlterColumnNullable {
@@ -232,6 +269,14 @@ impl SchemaOperation {
                 table_info: None,
             }),
             SchemaOperation::DropUnique { .. } => None,
+            SchemaOperation::AddCheck {
+                table, constraint, ..
+            } => Some(SchemaOperation::DropCheck {
+                table: table.clone(),
+                name: check_effective_name(table, constraint),
+                table_info: None,
+            }),
+            SchemaOperation::DropCheck { .. } => None,
             SchemaOperation::CreateIndex { table, index } => Some(SchemaOperation::DropIndex {
                 table: table.clone(),
                 name: index.name.clone(),
@@ -258,6 +303,8 @@ impl SchemaOperation {
             | SchemaOperation::DropForeignKey { table, .. }
             | SchemaOperation::AddUnique { table, .. }
             | SchemaOperation::DropUnique { table, .. }
+            | SchemaOperation::AddCheck { table, .. }
+            | SchemaOperation::DropCheck { table, .. }
             | SchemaOperation::CreateIndex { table, .. }
             | SchemaOperation::DropIndex { table, .. } => Some(table),
         }
@@ -268,21 +315,21 @@ impl SchemaOperation {
         // Order:
         // 1. Drop foreign keys (remove constraints before modifying)
         // 2. Drop indexes
-        // 3. Drop unique constraints
+        // 3. Drop unique and check constraints
         // 4. Drop primary keys
         // 5. Drop columns
         // 6. Alter columns
         // 7. Add columns
         // 8. Create tables (in FK order)
         // 9. Add primary keys
-        // 10. Add unique constraints
+        // 10. Add unique and check constraints
         // 11. Add indexes
         // 12. Add foreign keys
         // 13. Drop tables (last, after FK removal)
         match self {
             SchemaOperation::DropForeignKey { .. } => 1,
             SchemaOperation::DropIndex { .. } => 2,
-            SchemaOperation::DropUnique { .. } => 3,
+            SchemaOperation::DropUnique { .. } | SchemaOperation::DropCheck { .. } => 3,
             SchemaOperation::DropPrimaryKey { .. } => 4,
             SchemaOperation::DropColumn { .. } => 5,
             SchemaOperation::AlterColumnType { .. } => 6,
@@ -293,7 +340,7 @@ impl SchemaOperation {
             SchemaOperation::RenameTable { .. } => 11,
             SchemaOperation::RenameColumn { .. } => 12,
             SchemaOperation::AddPrimaryKey { .. } => 13,
-            SchemaOperation::AddUnique { .. } => 14,
+            SchemaOperation::AddUnique { .. } | SchemaOperation::AddCheck { .. } => 14,
             SchemaOperation::CreateIndex { .. } => 15,
             SchemaOperation::AddForeignKey { .. } => 16,
             SchemaOperation::DropTable(_) => 17,
@@ -444,7 +491,9 @@ impl SchemaDiff {
                 | SchemaOperation::AddForeignKey { table_info, .. }
                 | SchemaOperation::DropForeignKey { table_info, .. }
                 | SchemaOperation::AddUnique { table_info, .. }
-                | SchemaOperation::DropUnique { table_info, .. } => {
+                | SchemaOperation::DropUnique { table_info, .. }
+                | SchemaOperation::AddCheck { table_info, .. }
+                | SchemaOperation::DropCheck { table_info, .. } => {
                     table_info.clone_from(&before);
                 }
                 _ => {}
@@ -600,6 +649,8 @@ impl SchemaDiffer {
             }
         }
 
+        diff_enum_types(current, expected, &mut diff);
+
         // Order operations for safe execution
         diff.order_operations();
 
@@ -628,11 +679,15 @@ fn sqlite_apply_op_to_table_info(table: &mut TableInfo, op: &SchemaOperation) {
                 .retain(|idx| !idx.columns.iter().any(|c| c == column));
         }
         SchemaOperation::AlterColumnType {
-            column, to_type, ..
+            column,
+            to_type,
+            to_collation,
+            ..
         } => {
             if let Some(col) = table.columns.iter_mut().find(|c| c.name == *column) {
                 col.sql_type.clone_from(to_type);
                 col.parsed_type = ParsedSqlType::parse(to_type);
+                col.collation.clone_from(to_collation);
             }
         }
         SchemaOperation::AlterColumnNullable {
@@ -716,6 +771,18 @@ fn sqlite_apply_op_to_table_info(table: &mut TableInfo, op: &SchemaOperation) {
                 .unique_constraints
                 .retain(|uc| unique_effective_name(&table.name, uc) != *name);
         }
+        SchemaOperation::AddCheck { constraint, .. } => {
+            let name = check_effective_name(&table.name, constraint);
+            table
+                .check_constraints
+                .retain(|existing| check_effective_name(&table.name, existing) != name);
+            table.check_constraints.push(constraint.clone());
+        }
+        SchemaOperation::DropCheck { name, .. } => {
+            table
+                .check_constraints
+                .retain(|ck| check_effective_name(&table.name, ck) != *name);
+        }
         SchemaOperation::CreateIndex { index, .. } => {
             table.indexes.retain(|i| i.name != index.name);
             table.indexes.push(index.clone());
@@ -745,8 +812,11 @@ fn diff_table(current: &TableInfo, expected: &TableInfo, dialect: Dialect, diff:
     // Diff unique constraints
     diff_unique_constraints(current, &expected.unique_constraints, diff);
 
+    // Diff check constraints
+    diff_check_constraints(current, &expected.check_constraints, diff);
+
     // Diff indexes
-    diff_indexes(table, &current.indexes, &expected.indexes, diff);
+    diff_indexes(table, &current.indexes, &expected.indexes, dialect, diff);
 }
 
 /// Compare columns between tables.
@@ -837,10 +907,54 @@ fn diff_column_details(
     let table = current_table.name.as_str();
     let col = &current.name;
 
+    // Generated columns. A model that doesn't describe a generation
+    // expression accepts whatever the database computes. A changed expression
+    // can't be altered in place portably, so the column is dropped and
+    // re-added; its values are derived, so nothing is lost.
+    if let Some(expected_expr) = &expected.generated {
+        let unchanged = current.generated.as_deref().is_some_and(|current_expr| {
+            normalize_generation_expression(current_expr)
+                == normalize_generation_expression(expected_expr)
+                && (current.stored == expected.stored || dialect == Dialect::Postgres)
+        });
+        if !unchanged {
+            diff.add_destructive_op(
+                SchemaOperation::DropColumn {
+                    table: table.to_string(),
+                    column: col.clone(),
+                    table_info: Some(current_table.clone()),
+                },
+                WarningSeverity::Warning,
+                format!(
+                    "Recreating '{}.{}' to change its generation expression",
+                    table, col
+                ),
+            );
+            diff.add_op(SchemaOperation::AddColumn {
+                table: table.to_string(),
+                column: (*expected).clone(),
+            });
+            return;
+        }
+    }
+    let generated = current.generated.is_some() || expected.generated.is_some();
+
     // Type change (normalize for comparison)
     let current_type = normalize_type(&current.sql_type, dialect);
     let expected_type = normalize_type(&expected.sql_type, dialect);
 
+    // Collation change. A model without a collation accepts the database's
+    // (MySQL reports one for every string column).
+    let collation_changed = expected
+        .collation
+        .as_deref()
+        .is_some_and(|expected_collation| {
+            !current
+                .collation
+                .as_deref()
+                .is_some_and(|c| c.eq_ignore_ascii_case(expected_collation))
+        });
+
     if current_type != expected_type {
         diff.add_destructive_op(
             SchemaOperation::AlterColumnType {
@@ -848,6 +962,8 @@ fn diff_column_details(
                 column: col.clone(),
                 from_type: current.sql_type.clone(),
                 to_type: expected.sql_type.clone(),
+                from_collation: current.collation.clone(),
+                to_collation: expected.collation.clone(),
                 table_info: Some(current_table.clone()),
             },
             WarningSeverity::Warning,
@@ -856,6 +972,24 @@ fn diff_column_details(
                 table, col, current.sql_type, expected.sql_type
             ),
         );
+    } else if collation_changed {
+        let op_index = diff.add_op(SchemaOperation::AlterColumnType {
+            table: table.to_string(),
+            column: col.clone(),
+            from_type: current.sql_type.clone(),
+            to_type: current.sql_type.clone(),
+            from_collation: current.collation.clone(),
+            to_collation: expected.collation.clone(),
+            table_info: Some(current_table.clone()),
+        });
+        diff.warn(
+            WarningSeverity::Info,
+            format!(
+                "Changing collation of '{}.{}' affects sorting, comparisons and unique constraints",
+                table, col
+            ),
+            Some(op_index),
+        );
     }
 
     // Nullable change
@@ -880,8 +1014,13 @@ fn diff_column_details(
         }
     }
 
-    // Default change
-    if current.default != expected.default {
+    // Default change (generated columns have no default)
+    let render = |col: &ColumnInfo| {
+        col.default
+            .as_deref()
+            .map(|d| dialect.default_expr(d).into_owned())
+    };
+    if !generated && render(current) != render(expected) {
         diff.add_op(SchemaOperation::AlterColumnDefault {
             table: table.to_string(),
             column: col.clone(),
@@ -1030,8 +1169,116 @@ fn diff_unique_constraints(
     }
 }
 
+/// Warn about enum types whose labels in the database differ from the model.
+///
+/// Existing types are never recreated, so changed labels need a hand-written
+/// migration (`ALTER TYPE ... ADD VALUE` and friends).
+fn diff_enum_types(current: &DatabaseSchema, expected: &DatabaseSchema, diff: &mut SchemaDiff) {
+    let mut tables: Vec<&TableInfo> = expected.tables.values().collect();
+    tables.sort_by(|a, b| a.name.cmp(&b.name));
+
+    let mut seen = HashSet::new();
+    for column in tables.iter().flat_map(|table| &table.columns) {
+        let Some(CustomSqlType::Enum { name, variants }) = column.custom_type else {
+            continue;
+        };
+        if !seen.insert(name) {
+            continue;
+        }
+        if let Some(labels) = current.enum_types.get(name)
+            && labels
+                .iter()
+                .map(String::as_str)
+                .ne(variants.iter().copied())
+        {
+            diff.warn(
+                WarningSeverity::Warning,
+                format!(
+                    "Enum type '{}' has labels [{}] in the database but the model declares [{}]",
+                    name,
+                    labels.join(", "),
+                    variants.join(", ")
+                ),
+                None,
+            );
+        }
+    }
+}
+
+/// Whether a database check constraint was created from a model.
+///
+/// Model checks are named `ck_<table>_<n>` (see
+/// [`table_schema_from_model`](crate::expected::table_schema_from_model)).
+fn is_model_check(table: &str, constraint: &CheckConstraintInfo) -> bool {
+    constraint
+        .name
+        .as_deref()
+        .is_some_and(|name| name.starts_with(&format!("ck_{}_", table)))
+}
+
+/// Compare check constraints.
+///
+/// Databases echo check expressions back reformatted, so a check matches if
+/// either its name or its normalized expression matches. Only checks the
+/// model created are dropped; any other unmatched check is left in place
+/// with a warning.
+fn diff_check_constraints(
+    current_table: &TableInfo,
+    expected: &[CheckConstraintInfo],
+    diff: &mut SchemaDiff,
+) {
+    let table = current_table.name.as_str();
+    let current = current_table.check_constraints.as_slice();
+    let same = |a: &CheckConstraintInfo, b: &CheckConstraintInfo| {
+        (a.name.is_some() && a.name == b.name)
+            || normalize_generation_expression(&a.expression)
+                == normalize_generation_expression(&b.expression)
+    };
+
+    // Find constraints to add
+    for constraint in expected {
+        if !current.iter().any(|c| same(c, constraint)) {
+            diff.add_op(SchemaOperation::AddCheck {
+                table: table.to_string(),
+                constraint: constraint.clone(),
+                table_info: Some(current_table.clone()),
+            });
+        }
+    }
+
+    // Find constraints to drop
+    for constraint in current {
+        if expected.iter().any(|e| same(constraint, e)) {
+            continue;
+        }
+        let name = check_effective_name(table, constraint);
+        if is_model_check(table, constraint) {
+            diff.add_op(SchemaOperation::DropCheck {
+                table: table.to_string(),
+                name,
+                table_info: Some(current_table.clone()),
+            });
+        } else {
+            diff.warn(
+                WarningSeverity::Warning,
+                format!(
+                    "Check constraint '{}' on '{}' is not declared by the model; leaving it in place",
+                    name, table
+                ),
+                None,
+            );
+        }
+    }
+}
+
 /// Compare indexes.
-fn diff_indexes(table: &str, current: &[IndexInfo], expected: &[IndexInfo], diff: &mut SchemaDiff) {
+fn diff_indexes(
+    table: &str,
+    current: &[IndexInfo],
+    expected: &[IndexInfo],
+    dialect: Dialect,
+    diff: &mut SchemaDiff,
+) {
     // Skip primary key indexes as they're handled separately
     let current_filtered: Vec<_> = current.iter().filter(|i| !i.primary).collect();
     let expected_filtered: Vec<_> = expected.iter().filter(|i| !i.primary).collect();
@@ -1066,11 +1313,13 @@ fn diff_indexes(table: &str, current: &[IndexInfo], expected: &[IndexInfo], diff
         }
     }
 
-    // Changed indexes (check columns and unique flag)
+    // Changed indexes (check columns, unique flag and, where introspected,
+    // expressions and partial-index predicates)
     for (name, expected_idx) in &expected_map {
         if let Some(current_idx) = current_map.get(name) {
             if current_idx.columns != expected_idx.columns
                 || current_idx.unique != expected_idx.unique
+                || (dialect == Dialect::Postgres && !same_index_shape(current_idx, expected_idx))
             {
                 // Drop and recreate
                 diff.add_op(SchemaOperation::DropIndex {
@@ -1092,13 +1341,73 @@ fn diff_indexes(table: &str, current: &[IndexInfo], expected: &[IndexInfo], diff
 
 fn column_signature(col: &ColumnInfo, dialect: Dialect) -> String {
     let ty = normalize_type(&col.sql_type, dialect);
-    let default = col.default.as_deref().unwrap_or("");
+    let default = col
+        .default
+        .as_deref()
+        .map(|d| dialect.default_expr(d))
+        .unwrap_or_default();
+    let generated = col
+        .generated
+        .as_deref()
+        .map(normalize_generation_expression)
+        .unwrap_or_default();
     format!(
-        "type={};nullable={};default={};pk={};ai={}",
-        ty, col.nullable, default, col.primary_key, col.auto_increment
+        "type={};nullable={};default={};pk={};ai={};generated={}",
+        ty, col.nullable, default, col.primary_key, col.auto_increment, generated
     )
 }
 
+/// Normalize a generation or check expression for comparison.
+///
+/// Databases echo expressions back differently (PostgreSQL wraps them in
+/// parentheses, MySQL quotes identifiers with backticks), so outer
+/// parentheses, identifier quotes, whitespace and case are ignored.
+fn normalize_generation_expression(expr: &str) -> String {
+    let mut expr: String = expr
+        .chars()
+        .filter(|c| !matches!(c, '"' | '`'))
+        .collect::<String>()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase();
+    while expr.starts_with('(') && expr.ends_with(')') && wraps_whole(&expr) {
+        expr = expr[1..expr.len() - 1].trim().to_string();
+    }
+    expr
+}
+
+/// Whether two indexes have the same key expressions and predicate, ignoring
+/// how the database reformats them.
+fn same_index_shape(current: &IndexInfo, expected: &IndexInfo) -> bool {
+    let normalize = |expr: &Option<String>| expr.as_deref().map(normalize_generation_expression);
+    current.expressions.len() == expected.expressions.len()
+        && current
+            .expressions
+            .iter()
+            .zip(&expected.expressions)
+            .all(|(a, b)| normalize_generation_expression(a) == normalize_generation_expression(b))
+        && normalize(&current.predicate) == normalize(&expected.predicate)
+}
+
+/// Whether the opening parenthesis at the start of `expr` closes at its end.
+fn wraps_whole(expr: &str) -> bool {
+    let mut depth = 0usize;
+    for (i, c) in expr.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => {
+                depth = depth.saturating_sub(1);
+                if depth == 0 {
+                    return i == expr.len() - 1;
+                }
+            }
+            _ => {}
+        }
+    }
+    false
+}
+
 fn detect_column_renames(
     removed: &[&ColumnInfo],
     added: &[&ColumnInfo],
@@ -1325,6 +1634,10 @@ mod tests {
             primary_key: false,
             auto_increment: false,
             comment: None,
+            custom_type: None,
+            generated: None,
+            stored: false,
+            collation: None,
         }
     }
 
@@ -1491,6 +1804,25 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_boolean_default_matches_dialect_literal() {
+        let mut active = make_column("active", "BOOLEAN", false);
+        active.default = Some("1".to_string());
+        let mut current = DatabaseSchema::new(Dialect::Sqlite);
+        current.tables.insert(
+            "heroes".to_string(),
+            make_table("heroes", vec![active.clone()]),
+        );
+
+        active.default = Some("true".to_string());
+        let mut expected = DatabaseSchema::new(Dialect::Sqlite);
+        expected
+            .tables
+            .insert("heroes".to_string(), make_table("heroes", vec![active]));
+
+        assert!(schema_diff(&current, &expected).is_empty());
+    }
+
     #[test]
     fn test_sqlite_refreshes_table_info_for_multiple_recreate_ops_on_same_table() {
         let mut current = DatabaseSchema::new(Dialect::Sqlite);
@@ -1595,6 +1927,88 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_schema_diff_column_collation() {
+        let schema = |collation: Option<&str>| {
+            let mut col = make_column("email", "TEXT", false);
+            col.collation = collation.map(String::from);
+            let mut schema = DatabaseSchema::new(Dialect::Sqlite);
+            schema
+                .tables
+                .insert("users".to_string(), make_table("users", vec![col]));
+            schema
+        };
+
+        let diff = schema_diff(&schema(None), &schema(Some("NOCASE")));
+        assert_eq!(diff.operations.len(), 1);
+        let op = &diff.operations[0];
+        assert!(matches!(
+            op,
+            SchemaOperation::AlterColumnType { from_type, to_type, from_collation: None, to_collation: Some(c), .. }
+                if from_type == "TEXT" && to_type == "TEXT" && c == "NOCASE"
+        ));
+        assert!(!op.is_destructive());
+
+        // Same collation modulo case, and a model that doesn't ask for one.
+        assert!(
+            schema_diff(&schema(Some("nocase")), &schema(Some("NOCASE")))
+                .operations
+                .is_empty()
+        );
+        assert!(
+            schema_diff(&schema(Some("NOCASE")), &schema(None))
+                .operations
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_schema_diff_generated_columns() {
+        let generated = |expr: &str| {
+            let mut col = make_column("total", "INTEGER", true);
+            col.generated = Some(expr.to_string());
+            col.stored = true;
+            col
+        };
+        let schema = |col: ColumnInfo| {
+            let mut schema = DatabaseSchema::new(Dialect::Postgres);
+            schema
+                .tables
+                .insert("orders".to_string(), make_table("orders", vec![col]));
+            schema
+        };
+
+        // PostgreSQL echoes the expression back wrapped in parentheses.
+        let current = schema(generated("(price * qty)"));
+        assert!(schema_diff(&current, &schema(generated("price * qty"))).is_empty());
+
+        // A model that doesn't describe the generation accepts it as is.
+        assert!(schema_diff(&current, &schema(make_column("total", "INTEGER", true))).is_empty());
+
+        // A changed expression recreates the column.
+        let diff = schema_diff(&current, &schema(generated("price * qty * 2")));
+        assert!(diff.operations.iter().any(
+            |op| matches!(op, SchemaOperation::DropColumn { column, .. } if column == "total")
+        ));
+        assert!(diff.operations.iter().any(|op| matches!(
+            op,
+            SchemaOperation::AddColumn { column, .. }
+                if column.generated.as_deref() == Some("price * qty * 2")
+        )));
+    }
+
+    #[test]
+    fn test_normalize_generation_expression() {
+        assert_eq!(
+            normalize_generation_expression("((`price` *  qty))"),
+            "price * qty"
+        );
+        assert_eq!(
+            normalize_generation_expression("(a + 1) * (b + 1)"),
+            "(a + 1) * (b + 1)"
+        );
+    }
+
     #[test]
     fn test_schema_diff_alter_nullable() {
         let mut current = DatabaseSchema::new(Dialect::Sqlite);
@@ -1646,6 +2060,8 @@ mod tests {
             foreign_column: "id".to_string(),
             on_delete: Some("CASCADE".to_string()),
             on_update: None,
+            deferrable: false,
+            initially_deferred: false,
         });
         expected.tables.insert("heroes".to_string(), heroes);
 
@@ -1726,6 +2142,108 @@ mod tests {
         assert!(op.unwrap().is_some(), "Expected table_info on AddUnique op");
     }
 
+    #[test]
+    fn test_schema_diff_check_constraints() {
+        let mut current = DatabaseSchema::new(Dialect::Postgres);
+        let mut current_table = make_table("heroes", vec![make_column("age", "INTEGER", false)]);
+        current_table.check_constraints.push(CheckConstraintInfo {
+            name: Some("heroes_age_check".to_string()),
+            expression: "(age >= 0)".to_string(),
+        });
+        current_table.check_constraints.push(CheckConstraintInfo {
+            name: Some("ck_heroes_legacy".to_string()),
+            expression: "age < 1000".to_string(),
+        });
+        current.tables.insert("heroes".to_string(), current_table);
+
+        let mut expected = DatabaseSchema::new(Dialect::Postgres);
+        let mut expected_table = make_table("heroes", vec![make_column("age", "INTEGER", false)]);
+        expected_table.check_constraints.push(CheckConstraintInfo {
+            name: Some("ck_heroes_1".to_string()),
+            expression: "age >= 0".to_string(),
+        });
+        expected_table.check_constraints.push(CheckConstraintInfo {
+            name: Some("ck_heroes_2".to_string()),
+            expression: "age <= 150".to_string(),
+        });
+        expected.tables.insert("heroes".to_string(), expected_table);
+
+        // The reformatted `(age >= 0)` matches by expression; the others differ.
+        let diff = schema_diff(&current, &expected);
+        assert_eq!(diff.len(), 2);
+        assert!(diff.operations.iter().any(|op| matches!(
+            op,
+            SchemaOperation::AddCheck { constraint, .. }
+                if constraint.name.as_deref() == Some("ck_heroes_2")
+        )));
+        let drop = diff
+            .operations
+            .iter()
+            .find(|op| matches!(op, SchemaOperation::DropCheck { name, .. } if name == "ck_heroes_legacy"))
+            .expect("Expected DropCheck for ck_heroes_legacy");
+        assert!(drop.inverse().is_none());
+    }
+
+    #[test]
+    fn test_schema_diff_keeps_unmodelled_check_constraint() {
+        let mut current = DatabaseSchema::new(Dialect::Postgres);
+        let mut current_table = make_table("heroes", vec![make_column("age", "INTEGER", false)]);
+        current_table.check_constraints.push(CheckConstraintInfo {
+            name: Some("heroes_age_check".to_string()),
+            expression: "(age >= 0)".to_string(),
+        });
+        current.tables.insert("heroes".to_string(), current_table);
+
+        let mut expected = DatabaseSchema::new(Dialect::Postgres);
+        expected.tables.insert(
+            "heroes".to_string(),
+            make_table("heroes", vec![make_column("age", "INTEGER", false)]),
+        );
+
+        // A check added outside the model survives, reported as a warning.
+        let diff = schema_diff(&current, &expected);
+        assert!(diff.is_empty());
+        assert_eq!(diff.warnings.len(), 1);
+        assert_eq!(diff.warnings[0].severity, WarningSeverity::Warning);
+        assert!(diff.warnings[0].message.contains("heroes_age_check"));
+    }
+
+    #[test]
+    fn test_schema_diff_reports_enum_label_mismatch() {
+        let mut current = DatabaseSchema::new(Dialect::Postgres);
+        current.tables.insert(
+            "heroes".to_string(),
+            make_table("heroes", vec![make_column("status", "hero_status", false)]),
+        );
+        current.enum_types.insert(
+            "hero_status".to_string(),
+            vec!["active".to_string(), "retired".to_string()],
+        );
+
+        let mut expected = DatabaseSchema::new(Dialect::Postgres);
+        let mut status = make_column("status", "hero_status", false);
+        status.custom_type = Some(CustomSqlType::Enum {
+            name: "hero_status",
+            variants: &["active", "retired", "missing"],
+        });
+        expected
+            .tables
+            .insert("heroes".to_string(), make_table("heroes", vec![status]));
+
+        let diff = schema_diff(&current, &expected);
+        assert!(diff.is_empty());
+        assert_eq!(diff.warnings.len(), 1);
+        assert!(diff.warnings[0].message.contains("hero_status"));
+
+        // Matching labels are not reported.
+        current
+            .enum_types
+            .get_mut("hero_status")
+            .unwrap()
+            .push("missing".to_string());
+        assert!(schema_diff(&current, &expected).warnings.is_empty());
+    }
+
     #[test]
     fn test_schema_diff_index_add() {
         let mut current = DatabaseSchema::new(Dialect::Sqlite);
@@ -1742,6 +2260,8 @@ mod tests {
             unique: false,
             index_type: None,
             primary: false,
+            expressions: Vec::new(),
+            predicate: None,
         });
         expected.tables.insert("heroes".to_string(), heroes);
 
@@ -1765,6 +2285,8 @@ mod tests {
                 foreign_column: "id".to_string(),
                 on_delete: None,
                 on_update: None,
+                deferrable: false,
+                initially_deferred: false,
             },
             table_info: None,
         });
@@ -1835,6 +2357,8 @@ mod tests {
                 column: "age".to_string(),
                 from_type: "TEXT".to_string(),
                 to_type: "INTEGER".to_string(),
+                from_collation: None,
+                to_collation: None,
                 table_info: None,
             }
             .is_destructive()
@@ -1859,6 +2383,8 @@ mod tests {
             column: "age".to_string(),
             from_type: "TEXT".to_string(),
             to_type: "INTEGER".to_string(),
+            from_collation: None,
+            to_collation: None,
             table_info: None,
         };
         assert!(
@@ -4,11 +4,12 @@
 //! from Rust Model definitions, which can then be compared against the
 //! actual database schema obtained via introspection.
 
+use crate::ddl::constraint_name;
 use crate::introspect::{
-    ColumnInfo, DatabaseSchema, Dialect, ForeignKeyInfo, IndexInfo, ParsedSqlType, TableInfo,
-    UniqueConstraintInfo,
+    CheckConstraintInfo, ColumnInfo, DatabaseSchema, Dialect, ForeignKeyInfo, IndexInfo,
+    ParsedSqlType, TableInfo, UniqueConstraintInfo,
 };
-use sqlmodel_core::{FieldInfo, Model};
+use sqlmodel_core::{FieldInfo, Model, TableConstraint};
 
 // ============================================================================
 // Extension Trait for Model
@@ -53,8 +54,52 @@ impl<M: Model> ModelSchema for M {}
 // ============================================================================
 
 /// Extract a TableInfo from a Model type.
+///
+/// Includes the model's [`Model::TABLE_CONSTRAINTS`], named the same way
+/// [`CreateTable`](crate::CreateTable) names them.
+///
+/// Unique fields of a soft-deletable model are reported as unique indexes,
+/// matching the partial indexes [`CreateTable`](crate::CreateTable) emits
+/// for them.
 pub fn table_schema_from_model<M: Model>() -> TableInfo {
-    table_schema_from_fields(M::TABLE_NAME, M::fields(), M::PRIMARY_KEY)
+    let mut table = table_schema_from_fields(M::TABLE_NAME, M::fields(), M::PRIMARY_KEY);
+    let mut check_number = 0;
+    for constraint in M::TABLE_CONSTRAINTS {
+        match constraint {
+            TableConstraint::Unique(columns) => {
+                table.unique_constraints.push(UniqueConstraintInfo {
+                    name: Some(constraint_name("uk", M::TABLE_NAME, &columns.join("_"))),
+                    columns: columns.iter().map(|c| c.to_string()).collect(),
+                });
+            }
+            TableConstraint::Check(expression) => {
+                check_number += 1;
+                table.check_constraints.push(CheckConstraintInfo {
+                    name: Some(constraint_name(
+                        "ck",
+                        M::TABLE_NAME,
+                        &check_number.to_string(),
+                    )),
+                    expression: expression.to_string(),
+                });
+            }
+        }
+    }
+    if let Some(deleted_column) = M::SOFT_DELETE_COLUMN {
+        let unique_constraints = std::mem::take(&mut table.unique_constraints);
+        table
+            .indexes
+            .extend(unique_constraints.into_iter().map(|uk| IndexInfo {
+                name: uk.name.unwrap_or_default(),
+                columns: uk.columns,
+                unique: true,
+                index_type: None,
+                primary: false,
+                expressions: Vec::new(),
+                predicate: Some(format!("{deleted_column} IS NULL")),
+            }));
+    }
+    table
 }
 
 /// Convert field metadata to a TableInfo.
@@ -84,18 +129,24 @@ pub fn table_schema_from_fields(
             primary_key: field.primary_key,
             auto_increment: field.auto_increment,
             comment: None,
+            custom_type: field.custom_type,
+            generated: None,
+            stored: false,
+            collation: field.collation.map(String::from),
         });
 
         // Extract foreign key if present
         if let Some(fk_ref) = field.foreign_key {
             if let Some((ref_table, ref_col)) = parse_fk_reference(fk_ref) {
                 foreign_keys.push(ForeignKeyInfo {
-                    name: Some(format!("fk_{}_{}", table_name, field.column_name)),
+                    name: Some(constraint_name("fk", table_name, field.column_name)),
                     column: field.column_name.to_string(),
                     foreign_table: ref_table,
                     foreign_column: ref_col,
                     on_delete: field.on_delete.map(|a| a.as_sql().to_string()),
                     on_update: field.on_update.map(|a| a.as_sql().to_string()),
+                    deferrable: field.deferrable,
+                    initially_deferred: field.initially_deferred,
                 });
             }
         }
@@ -103,7 +154,7 @@ pub fn table_schema_from_fields(
         // Extract unique constraint if present (and not part of PK)
         if field.unique && !field.primary_key {
             unique_constraints.push(UniqueConstraintInfo {
-                name: Some(format!("uk_{}_{}", table_name, field.column_name)),
+                name: Some(constraint_name("uk", table_name, field.column_name)),
                 columns: vec![field.column_name.to_string()],
             });
         }
@@ -116,6 +167,8 @@ pub fn table_schema_from_fields(
                 unique: false,
                 index_type: None,
                 primary: false,
+                expressions: Vec::new(),
+                predicate: None,
             });
         }
     }
@@ -155,11 +208,26 @@ fn parse_fk_reference(reference: &str) -> Option<(String, String)> {
 /// ```
 pub fn expected_schema<M: Model>(dialect: Dialect) -> DatabaseSchema {
     let mut schema = DatabaseSchema::new(dialect);
-    let table_info = table_schema_from_model::<M>();
+    let table_info = table_for_dialect(table_schema_from_model::<M>(), dialect);
     schema.tables.insert(table_info.name.clone(), table_info);
     schema
 }
 
+/// Spell enum/domain columns as their base type on dialects without custom types.
+fn table_for_dialect(mut table: TableInfo, dialect: Dialect) -> TableInfo {
+    if dialect != Dialect::Postgres {
+        for column in &mut table.columns {
+            if let Some(custom) = column.custom_type.take()
+                && column.sql_type == custom.name()
+            {
+                column.sql_type = custom.base_type().to_string();
+                column.parsed_type = ParsedSqlType::parse(&column.sql_type);
+            }
+        }
+    }
+    table
+}
+
 /// Trait for tuples of Models to aggregate their schemas.
 ///
 /// This allows building a complete expected schema from multiple models.
@@ -171,6 +239,7 @@ pub trait ModelTuple {
     fn database_schema(dialect: Dialect) -> DatabaseSchema {
         let mut schema = DatabaseSchema::new(dialect);
         for table in Self::all_table_schemas() {
+            let table = table_for_dialect(table, dialect);
             schema.tables.insert(table.name.clone(), table);
         }
         schema
@@ -339,7 +408,7 @@ pub fn normalize_sql_type(sql_type: &str, dialect: Dialect) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use sqlmodel_core::{ReferentialAction, Row, SqlType, Value};
+    use sqlmodel_core::{CustomSqlType, ReferentialAction, Row, SqlType, Value};
 
     // Test model
     struct TestHero;
@@ -451,6 +520,26 @@ mod tests {
         assert!(schema.table("heroes").is_some());
     }
 
+    #[test]
+    fn test_custom_type_column_uses_base_type_off_postgres() {
+        let fields = [
+            FieldInfo::new("power", "power", SqlType::Integer).custom_type(CustomSqlType::Domain {
+                name: "power_level",
+                base_type: "INTEGER",
+                check: None,
+            }),
+        ];
+        let table = table_schema_from_fields("heroes", &fields, &[]);
+
+        let postgres = table_for_dialect(table.clone(), Dialect::Postgres);
+        assert_eq!(postgres.columns[0].sql_type, "power_level");
+        assert!(postgres.columns[0].custom_type.is_some());
+
+        let sqlite = table_for_dialect(table, Dialect::Sqlite);
+        assert_eq!(sqlite.columns[0].sql_type, "INTEGER");
+        assert!(sqlite.columns[0].custom_type.is_none());
+    }
+
     #[test]
     fn test_model_tuple_two() {
         struct TestTeam;
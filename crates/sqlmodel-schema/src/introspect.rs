@@ -4,7 +4,9 @@
 //! It extracts metadata about tables, columns, constraints, and indexes.
 
 use asupersync::{Cx, Outcome};
-use sqlmodel_core::{Connection, Error};
+use serde::{Deserialize, Serialize};
+use sqlmodel_core::{Connection, CustomSqlType, Error};
+use std::borrow::Cow;
 use std::collections::HashMap;
 
 #[cfg(test)]
@@ -21,6 +23,9 @@ pub struct DatabaseSchema {
     pub tables: HashMap<String, TableInfo>,
     /// Database dialect
     pub dialect: Dialect,
+    /// Enum types and their labels in sort order, keyed by type name
+    /// (PostgreSQL only)
+    pub enum_types: HashMap<String, Vec<String>>,
 }
 
 impl DatabaseSchema {
@@ -29,6 +34,7 @@ impl DatabaseSchema {
         Self {
             tables: HashMap::new(),
             dialect,
+            enum_types: HashMap::new(),
         }
     }
 
@@ -44,7 +50,7 @@ impl DatabaseSchema {
 }
 
 /// Parsed SQL type with extracted metadata.
-#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ParsedSqlType {
     /// Base type name (e.g., VARCHAR, INTEGER, DECIMAL)
     pub base_type: String,
@@ -163,7 +169,7 @@ impl ParsedSqlType {
 }
 
 /// Unique constraint information.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UniqueConstraintInfo {
     /// Constraint name
     pub name: Option<String>,
@@ -172,7 +178,7 @@ pub struct UniqueConstraintInfo {
 }
 
 /// Check constraint information.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CheckConstraintInfo {
     /// Constraint name
     pub name: Option<String>,
@@ -181,7 +187,7 @@ pub struct CheckConstraintInfo {
 }
 
 /// Information about a database table.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TableInfo {
     /// Table name
     pub name: String,
@@ -217,7 +223,7 @@ impl TableInfo {
 }
 
 /// Information about a table column.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ColumnInfo {
     /// Column name
     pub name: String,
@@ -235,10 +241,26 @@ pub struct ColumnInfo {
     pub auto_increment: bool,
     /// Column comment (if any)
     pub comment: Option<String>,
+    /// User-defined enum/domain type the column depends on (from model metadata)
+    ///
+    /// Not serialized: it borrows `'static` model metadata and is never
+    /// populated by introspection.
+    #[serde(skip)]
+    pub custom_type: Option<CustomSqlType>,
+    /// Generation expression if this is a generated (computed) column
+    pub generated: Option<String>,
+    /// Whether a generated column is stored (`STORED`) rather than computed on read (`VIRTUAL`)
+    pub stored: bool,
+    /// Explicit collation (e.g. `"C"`, `NOCASE`, `utf8mb4_bin`), if any
+    ///
+    /// MySQL reports the effective collation of every string column, so
+    /// this is only compared when the model asks for a specific one.
+    #[serde(default)]
+    pub collation: Option<String>,
 }
 
 /// Information about a foreign key constraint.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ForeignKeyInfo {
     /// Constraint name
     pub name: Option<String>,
@@ -252,10 +274,14 @@ pub struct ForeignKeyInfo {
     pub on_delete: Option<String>,
     /// ON UPDATE action
     pub on_update: Option<String>,
+    /// Whether the constraint is DEFERRABLE
+    pub deferrable: bool,
+    /// Whether the constraint is INITIALLY DEFERRED
+    pub initially_deferred: bool,
 }
 
 /// Information about an index.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct IndexInfo {
     /// Index name
     pub name: String,
@@ -267,6 +293,12 @@ pub struct IndexInfo {
     pub index_type: Option<String>,
     /// Whether this is a primary key index
     pub primary: bool,
+    /// Key expressions of a functional index, e.g. `lower(email)` (Postgres only)
+    #[serde(default)]
+    pub expressions: Vec<String>,
+    /// WHERE predicate of a partial index (Postgres only)
+    #[serde(default)]
+    pub predicate: Option<String>,
 }
 
 #[derive(Default)]
@@ -284,7 +316,7 @@ pub struct Introspector {
 }
 
 /// Supported database dialects.
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Dialect {
     /// SQLite
     #[default]
@@ -295,6 +327,37 @@ pub enum Dialect {
     Mysql,
 }
 
+impl Dialect {
+    /// Maximum identifier length in bytes, if the dialect enforces one.
+    ///
+    /// PostgreSQL truncates longer names to 63 bytes (`NAMEDATALEN - 1`);
+    /// MySQL rejects names over 64 characters. SQLite has no limit.
+    pub const fn max_identifier_length(self) -> Option<usize> {
+        match self {
+            Dialect::Sqlite => None,
+            Dialect::Postgres => Some(63),
+            Dialect::Mysql => Some(64),
+        }
+    }
+
+    /// Render a column `DEFAULT` expression for this dialect.
+    ///
+    /// See [`sqlmodel_core::Dialect::default_expr`].
+    pub fn default_expr(self, expr: &str) -> Cow<'_, str> {
+        sqlmodel_core::Dialect::from(self).default_expr(expr)
+    }
+}
+
+impl From<Dialect> for sqlmodel_core::Dialect {
+    fn from(dialect: Dialect) -> Self {
+        match dialect {
+            Dialect::Sqlite => sqlmodel_core::Dialect::Sqlite,
+            Dialect::Postgres => sqlmodel_core::Dialect::Postgres,
+            Dialect::Mysql => sqlmodel_core::Dialect::Mysql,
+        }
+    }
+}
+
 impl Introspector {
     /// Create a new introspector for the given dialect.
     pub fn new(dialect: Dialect) -> Self {
@@ -521,9 +584,52 @@ impl Introspector {
             schema.tables.insert(name, info);
         }
 
+        if self.dialect == Dialect::Postgres {
+            schema.enum_types = match self.postgres_enum_types(cx, conn).await {
+                Outcome::Ok(types) => types,
+                Outcome::Err(e) => return Outcome::Err(e),
+                Outcome::Cancelled(r) => return Outcome::Cancelled(r),
+                Outcome::Panicked(p) => return Outcome::Panicked(p),
+            };
+        }
+
         Outcome::Ok(schema)
     }
 
+    /// Get the enum types in the current schema with their labels.
+    async fn postgres_enum_types<C: Connection>(
+        &self,
+        cx: &Cx,
+        conn: &C,
+    ) -> Outcome<HashMap<String, Vec<String>>, Error> {
+        let sql = "SELECT t.typname AS type_name, e.enumlabel AS label
+                   FROM pg_type t
+                   JOIN pg_enum e ON e.enumtypid = t.oid
+                   JOIN pg_namespace n ON n.oid = t.typnamespace
+                   WHERE n.nspname = current_schema()
+                   ORDER BY t.typname, e.enumsortorder";
+
+        let rows = match conn.query(cx, sql, &[]).await {
+            Outcome::Ok(rows) => rows,
+            Outcome::Err(e) => return Outcome::Err(e),
+            Outcome::Cancelled(r) => return Outcome::Cancelled(r),
+            Outcome::Panicked(p) => return Outcome::Panicked(p),
+        };
+
+        let mut types: HashMap<String, Vec<String>> = HashMap::new();
+        for row in &rows {
+            let (Ok(type_name), Ok(label)) = (
+                row.get_named::<String>("type_name"),
+                row.get_named::<String>("label"),
+            ) else {
+                continue;
+            };
+            types.entry(type_name).or_default().push(label);
+        }
+
+        Outcome::Ok(types)
+    }
+
     /// Get column information for a table.
     async fn columns<C: Connection>(
         &self,
@@ -544,7 +650,12 @@ impl Introspector {
         conn: &C,
         table_name: &str,
     ) -> Outcome<Vec<ColumnInfo>, Error> {
-        let sql = format!("PRAGMA table_info({})", quote_sqlite_identifier(table_name));
+        // table_xinfo (unlike table_info) also lists generated columns, with
+        // hidden = 2 for VIRTUAL and 3 for STORED.
+        let sql = format!(
+            "PRAGMA table_xinfo({})",
+            quote_sqlite_identifier(table_name)
+        );
         let rows = match conn.query(cx, &sql, &[]).await {
             Outcome::Ok(rows) => rows,
             Outcome::Err(e) => return Outcome::Err(e),
@@ -552,6 +663,15 @@ impl Introspector {
             Outcome::Panicked(p) => return Outcome::Panicked(p),
         };
 
+        // Generation expressions and collations are only recorded in the
+        // table's CREATE statement.
+        let create_sql = match self.sqlite_create_sql(cx, conn, table_name).await {
+            Outcome::Ok(sql) => sql,
+            Outcome::Err(e) => return Outcome::Err(e),
+            Outcome::Cancelled(r) => return Outcome::Cancelled(r),
+            Outcome::Panicked(p) => return Outcome::Panicked(p),
+        };
+
         let columns: Vec<ColumnInfo> = rows
             .iter()
             .filter_map(|row| {
@@ -560,6 +680,24 @@ impl Introspector {
                 let notnull = row.get_named::<i64>("notnull").ok().unwrap_or(0);
                 let dflt_value = row.get_named::<String>("dflt_value").ok();
                 let pk = row.get_named::<i64>("pk").ok().unwrap_or(0);
+                let hidden = row.get_named::<i64>("hidden").ok().unwrap_or(0);
+                if hidden == 1 {
+                    // Hidden virtual-table column, not part of the schema.
+                    return None;
+                }
+                let generated = if hidden == 2 || hidden == 3 {
+                    Some(
+                        create_sql
+                            .as_deref()
+                            .and_then(|sql| extract_sqlite_generation_expression(sql, &name))
+                            .unwrap_or_default(),
+                    )
+                } else {
+                    None
+                };
+                let collation = create_sql
+                    .as_deref()
+                    .and_then(|sql| extract_sqlite_collation(sql, &name));
                 let parsed_type = ParsedSqlType::parse(&sql_type);
 
                 Some(ColumnInfo {
@@ -571,6 +709,10 @@ impl Introspector {
                     primary_key: pk > 0,
                     auto_increment: false, // SQLite doesn't report this via PRAGMA
                     comment: None,         // SQLite doesn't support column comments
+                    custom_type: None,
+                    generated,
+                    stored: hidden == 3,
+                    collation,
                 })
             })
             .collect();
@@ -594,6 +736,9 @@ impl Introspector {
                        c.numeric_scale,
                        c.is_nullable,
                        c.column_default,
+                       c.is_generated,
+                       c.generation_expression,
+                       c.collation_name,
                        COALESCE(d.description, '') as column_comment
                    FROM information_schema.columns c
                    LEFT JOIN pg_catalog.pg_statio_all_tables st
@@ -629,6 +774,18 @@ impl Introspector {
                 let nullable_str = row.get_named::<String>("is_nullable").ok()?;
                 let default = row.get_named::<String>("column_default").ok();
                 let comment = row.get_named::<String>("column_comment").ok();
+                // NULL unless the column overrides the database default.
+                let collation = row.get_named::<String>("collation_name").ok();
+                // PostgreSQL generated columns are always STORED.
+                let generated = row
+                    .get_named::<String>("is_generated")
+                    .ok()
+                    .filter(|g| g == "ALWAYS")
+                    .map(|_| {
+                        row.get_named::<String>("generation_expression")
+                            .ok()
+                            .unwrap_or_default()
+                    });
 
                 // Build a complete SQL type string
                 let sql_type =
@@ -647,6 +804,10 @@ impl Introspector {
                     primary_key: false, // Determined via separate index query
                     auto_increment,
                     comment: comment.filter(|s| !s.is_empty()),
+                    custom_type: None,
+                    stored: generated.is_some(),
+                    generated,
+                    collation,
                 })
             })
             .collect();
@@ -672,6 +833,41 @@ impl Introspector {
             Outcome::Panicked(p) => return Outcome::Panicked(p),
         };
 
+        // SHOW COLUMNS flags generated columns in `Extra` but omits the
+        // expression, so fetch expressions separately when there are any.
+        let has_generated = rows.iter().any(|row| {
+            row.get_named::<String>("Extra")
+                .is_ok_and(|extra| mysql_generated_kind(&extra).is_some())
+        });
+        let expressions: HashMap<String, String> = if has_generated {
+            let sql = "SELECT COLUMN_NAME, GENERATION_EXPRESSION
+                       FROM information_schema.columns
+                       WHERE TABLE_SCHEMA = DATABASE() AND TABLE_NAME = ?";
+            match conn
+                .query(
+                    cx,
+                    sql,
+                    &[sqlmodel_core::Value::Text(table_name.to_string())],
+                )
+                .await
+            {
+                Outcome::Ok(rows) => rows
+                    .iter()
+                    .filter_map(|row| {
+                        Some((
+                            row.get_named::<String>("COLUMN_NAME").ok()?,
+                            row.get_named::<String>("GENERATION_EXPRESSION").ok()?,
+                        ))
+                    })
+                    .collect(),
+                Outcome::Err(e) => return Outcome::Err(e),
+                Outcome::Cancelled(r) => return Outcome::Cancelled(r),
+                Outcome::Panicked(p) => return Outcome::Panicked(p),
+            }
+        } else {
+            HashMap::new()
+        };
+
         let columns: Vec<ColumnInfo> = rows
             .iter()
             .filter_map(|row| {
@@ -682,6 +878,10 @@ impl Introspector {
                 let default = row.get_named::<String>("Default").ok();
                 let extra = row.get_named::<String>("Extra").ok().unwrap_or_default();
                 let comment = row.get_named::<String>("Comment").ok();
+                let collation = row.get_named::<String>("Collation").ok();
+                let generated_kind = mysql_generated_kind(&extra);
+                let generated =
+                    generated_kind.map(|_| expressions.get(&name).cloned().unwrap_or_default());
                 let parsed_type = ParsedSqlType::parse(&sql_type);
 
                 Some(ColumnInfo {
@@ -693,6 +893,10 @@ impl Introspector {
                     primary_key: key == "PRI",
                     auto_increment: extra.contains("auto_increment"),
                     comment: comment.filter(|s| !s.is_empty()),
+                    custom_type: None,
+                    generated,
+                    stored: generated_kind == Some("STORED"),
+                    collation,
                 })
             })
             .collect();
@@ -723,6 +927,22 @@ impl Introspector {
         conn: &C,
         table_name: &str,
     ) -> Outcome<Vec<CheckConstraintInfo>, Error> {
+        match self.sqlite_create_sql(cx, conn, table_name).await {
+            Outcome::Ok(Some(sql)) => Outcome::Ok(extract_sqlite_check_constraints(&sql)),
+            Outcome::Ok(None) => Outcome::Ok(Vec::new()),
+            Outcome::Err(e) => Outcome::Err(e),
+            Outcome::Cancelled(r) => Outcome::Cancelled(r),
+            Outcome::Panicked(p) => Outcome::Panicked(p),
+        }
+    }
+
+    /// The `CREATE TABLE` statement SQLite stored for `table_name`, if any.
+    async fn sqlite_create_sql<C: Connection>(
+        &self,
+        cx: &Cx,
+        conn: &C,
+        table_name: &str,
+    ) -> Outcome<Option<String>, Error> {
         let sql = "SELECT sql FROM sqlite_master WHERE type='table' AND name=?1";
         let rows = match conn
             .query(
@@ -738,17 +958,12 @@ impl Introspector {
             Outcome::Panicked(p) => return Outcome::Panicked(p),
         };
 
-        let create_sql = rows.iter().find_map(|row| {
+        Outcome::Ok(rows.iter().find_map(|row| {
             row.get_named::<String>("sql").ok().or_else(|| {
                 row.get(0)
                     .and_then(|value| value.as_str().map(ToString::to_string))
             })
-        });
-
-        match create_sql {
-            Some(sql) => Outcome::Ok(extract_sqlite_check_constraints(&sql)),
-            None => Outcome::Ok(Vec::new()),
-        }
+        }))
     }
 
     async fn postgres_check_constraints<C: Connection>(
@@ -986,6 +1201,8 @@ impl Introspector {
                     foreign_column: to,
                     on_delete: on_delete.filter(|s| s != "NO ACTION"),
                     on_update: on_update.filter(|s| s != "NO ACTION"),
+                    deferrable: false,
+                    initially_deferred: false,
                 })
             })
             .collect();
@@ -1005,7 +1222,9 @@ impl Introspector {
                        ccu.table_name AS foreign_table_name,
                        ccu.column_name AS foreign_column_name,
                        rc.delete_rule,
-                       rc.update_rule
+                       rc.update_rule,
+                       tc.is_deferrable,
+                       tc.initially_deferred
                    FROM information_schema.table_constraints AS tc
                    JOIN information_schema.key_column_usage AS kcu
                        ON tc.constraint_name = kcu.constraint_name
@@ -1043,6 +1262,10 @@ impl Introspector {
                 let foreign_column = row.get_named::<String>("foreign_column_name").ok()?;
                 let on_delete = row.get_named::<String>("delete_rule").ok();
                 let on_update = row.get_named::<String>("update_rule").ok();
+                let is_yes = |col: &str| {
+                    row.get_named::<String>(col)
+                        .is_ok_and(|v| v.eq_ignore_ascii_case("YES"))
+                };
 
                 Some(ForeignKeyInfo {
                     name,
@@ -1051,6 +1274,8 @@ impl Introspector {
                     foreign_column,
                     on_delete: on_delete.filter(|s| s != "NO ACTION"),
                     on_update: on_update.filter(|s| s != "NO ACTION"),
+                    deferrable: is_yes("is_deferrable"),
+                    initially_deferred: is_yes("initially_deferred"),
                 })
             })
             .collect();
@@ -1110,6 +1335,8 @@ impl Introspector {
                     foreign_column,
                     on_delete: on_delete.filter(|s| s != "NO ACTION"),
                     on_update: on_update.filter(|s| s != "NO ACTION"),
+                    deferrable: false,
+                    initially_deferred: false,
                 })
             })
             .collect();
@@ -1179,6 +1406,8 @@ impl Introspector {
                 unique,
                 index_type: None, // SQLite doesn't expose index type
                 primary,
+                expressions: Vec::new(),
+                predicate: None,
             });
         }
 
@@ -1194,20 +1423,28 @@ impl Introspector {
         // Exclude indexes backing PRIMARY KEY / UNIQUE constraints; those are represented
         // via TableInfo.primary_key and TableInfo.unique_constraints so the diff engine
         // doesn't try to DROP/CREATE constraint-backed indexes.
+        //
+        // Expression key parts have attnum 0 and no pg_attribute row; their text
+        // comes from pg_get_indexdef. Partial indexes carry their WHERE clause in
+        // indpred.
         let sql = "SELECT
                        i.relname AS index_name,
                        a.attname AS column_name,
+                       CASE WHEN k.attnum = 0
+                           THEN pg_get_indexdef(i.oid, k.ord::int, true)
+                       END AS expression,
                        k.ord AS column_ord,
                        ix.indisunique AS is_unique,
                        ix.indisprimary AS is_primary,
-                       am.amname AS index_type
+                       am.amname AS index_type,
+                       pg_get_expr(ix.indpred, ix.indrelid, true) AS predicate
                    FROM pg_class t
                    JOIN pg_namespace n ON n.oid = t.relnamespace
                    JOIN pg_index ix ON t.oid = ix.indrelid
                    JOIN LATERAL unnest(ix.indkey) WITH ORDINALITY AS k(attnum, ord) ON true
                    JOIN pg_class i ON i.oid = ix.indexrelid
                    JOIN pg_am am ON i.relam = am.oid
-                   JOIN pg_attribute a ON a.attrelid = t.oid AND a.attnum = k.attnum
+                   LEFT JOIN pg_attribute a ON a.attrelid = t.oid AND a.attnum = k.attnum
                    WHERE t.relname = $1
                        AND n.nspname = current_schema()
                        AND t.relkind = 'r'
@@ -1241,23 +1478,30 @@ impl Introspector {
             let Ok(name) = row.get_named::<String>("index_name") else {
                 continue;
             };
-            let Ok(column) = row.get_named::<String>("column_name") else {
+            let column = row.get_named::<String>("column_name").ok();
+            let expression = row.get_named::<String>("expression").ok();
+            if column.is_none() && expression.is_none() {
                 continue;
-            };
+            }
             let unique = row.get_named::<bool>("is_unique").ok().unwrap_or(false);
             let primary = row.get_named::<bool>("is_primary").ok().unwrap_or(false);
             let index_type = row.get_named::<String>("index_type").ok();
+            let predicate = row.get_named::<String>("predicate").ok();
 
-            index_map
-                .entry(name.clone())
-                .and_modify(|idx| idx.columns.push(column.clone()))
-                .or_insert_with(|| IndexInfo {
-                    name,
-                    columns: vec![column],
-                    unique,
-                    index_type,
-                    primary,
-                });
+            let index = index_map.entry(name.clone()).or_insert_with(|| IndexInfo {
+                name,
+                columns: Vec::new(),
+                unique,
+                index_type,
+                primary,
+                expressions: Vec::new(),
+                predicate,
+            });
+            match (column, expression) {
+                (Some(column), _) => index.columns.push(column),
+                (None, Some(expression)) => index.expressions.push(expression),
+                (None, None) => {}
+            }
         }
 
         Outcome::Ok(index_map.into_values().collect())
@@ -1316,6 +1560,8 @@ impl Introspector {
                     unique: acc.unique,
                     index_type: acc.index_type,
                     primary: acc.primary,
+                    expressions: Vec::new(),
+                    predicate: None,
                 }
             })
             .collect();
@@ -1385,6 +1631,60 @@ fn normalize_check_expression(definition: &str) -> String {
     trimmed.to_string()
 }
 
+/// `"STORED"` or `"VIRTUAL"` if a MySQL `SHOW COLUMNS` `Extra` value marks a
+/// generated column.
+///
+/// `DEFAULT_GENERATED` (an expression default such as `CURRENT_TIMESTAMP`) is
+/// not a generated column.
+fn mysql_generated_kind(extra: &str) -> Option<&'static str> {
+    let extra = extra.to_ascii_uppercase();
+    if extra.contains("STORED GENERATED") {
+        Some("STORED")
+    } else if extra.contains("VIRTUAL GENERATED") {
+        Some("VIRTUAL")
+    } else {
+        None
+    }
+}
+
+/// Extract the `GENERATED ALWAYS AS (expr)` / `AS (expr)` expression of
+/// `column` from a SQLite `CREATE TABLE` statement.
+/// The definition of `column` within a SQLite `CREATE TABLE` statement.
+fn sqlite_column_definition<'a>(create_table_sql: &'a str, column: &str) -> Option<&'a str> {
+    let definitions = sqlite_table_definitions(create_table_sql)?;
+    split_sqlite_definitions(definitions)
+        .into_iter()
+        .map(str::trim)
+        .find(|definition| {
+            parse_sqlite_identifier_token(definition, 0)
+                .is_some_and(|(name, _)| name.eq_ignore_ascii_case(column))
+        })
+}
+
+fn extract_sqlite_generation_expression(create_table_sql: &str, column: &str) -> Option<String> {
+    let definition = sqlite_column_definition(create_table_sql, column)?;
+
+    keyword_positions_outside_quotes(definition, "AS")
+        .into_iter()
+        .find_map(|as_pos| {
+            let mut cursor = as_pos + "AS".len();
+            while cursor < definition.len() && definition.as_bytes()[cursor].is_ascii_whitespace() {
+                cursor += 1;
+            }
+            extract_parenthesized(definition, cursor).map(|(expr, _)| expr)
+        })
+}
+
+fn extract_sqlite_collation(create_table_sql: &str, column: &str) -> Option<String> {
+    let definition = sqlite_column_definition(create_table_sql, column)?;
+    let collate_pos = *keyword_positions_outside_quotes(definition, "COLLATE").first()?;
+    let mut cursor = collate_pos + "COLLATE".len();
+    while cursor < definition.len() && definition.as_bytes()[cursor].is_ascii_whitespace() {
+        cursor += 1;
+    }
+    parse_sqlite_identifier_token(definition, cursor).map(|(name, _)| name)
+}
+
 fn extract_sqlite_check_constraints(create_table_sql: &str) -> Vec<CheckConstraintInfo> {
     let Some(definitions) = sqlite_table_definitions(create_table_sql) else {
         return Vec::new();
@@ -1864,6 +2164,10 @@ mod tests {
                 primary_key: true,
                 auto_increment: true,
                 comment: None,
+                custom_type: None,
+                generated: None,
+                stored: false,
+                collation: None,
             }],
             primary_key: vec!["id".to_string()],
             foreign_keys: Vec::new(),
@@ -1957,6 +2261,56 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_extract_sqlite_generation_expression() {
+        let sql = "CREATE TABLE orders (
+            id INTEGER PRIMARY KEY,
+            price INTEGER CHECK (CAST(price AS INTEGER) > 0),
+            qty INTEGER,
+            total INTEGER GENERATED ALWAYS AS (price * qty) STORED,
+            \"label\" TEXT AS (upper(note)) VIRTUAL,
+            note TEXT
+        )";
+        assert_eq!(
+            extract_sqlite_generation_expression(sql, "total").as_deref(),
+            Some("price * qty")
+        );
+        assert_eq!(
+            extract_sqlite_generation_expression(sql, "label").as_deref(),
+            Some("upper(note)")
+        );
+        assert_eq!(extract_sqlite_generation_expression(sql, "price"), None);
+        assert_eq!(extract_sqlite_generation_expression(sql, "missing"), None);
+    }
+
+    #[test]
+    fn test_extract_sqlite_collation() {
+        let sql = "CREATE TABLE users (
+            id INTEGER PRIMARY KEY,
+            email TEXT NOT NULL COLLATE NOCASE,
+            \"code\" TEXT COLLATE \"binary\" UNIQUE,
+            note TEXT DEFAULT 'COLLATE x'
+        )";
+        assert_eq!(
+            extract_sqlite_collation(sql, "email").as_deref(),
+            Some("NOCASE")
+        );
+        assert_eq!(
+            extract_sqlite_collation(sql, "code").as_deref(),
+            Some("binary")
+        );
+        assert_eq!(extract_sqlite_collation(sql, "note"), None);
+        assert_eq!(extract_sqlite_collation(sql, "id"), None);
+    }
+
+    #[test]
+    fn test_mysql_generated_kind() {
+        assert_eq!(mysql_generated_kind("STORED GENERATED"), Some("STORED"));
+        assert_eq!(mysql_generated_kind("VIRTUAL GENERATED"), Some("VIRTUAL"));
+        assert_eq!(mysql_generated_kind("DEFAULT_GENERATED"), None);
+        assert_eq!(mysql_generated_kind("auto_increment"), None);
+    }
+
     #[test]
     fn test_extract_sqlite_check_constraints_named_and_unnamed() {
         let sql = r"
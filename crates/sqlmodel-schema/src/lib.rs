@@ -9,6 +9,7 @@
 //! - **Diff engine**: compare desired vs. actual schema for migration planning.
 //! - **DDL generation**: emit dialect-specific SQL for SQLite, MySQL, and Postgres.
 //! - **Migration runner**: track, apply, and validate migrations.
+//! - **Snapshots**: serialize a schema to JSON as an offline diff baseline.
 //!
 //! Applications typically use this via `sqlmodel::SchemaBuilder`, but it can also be
 //! embedded in custom tooling or CI migration checks.
@@ -19,11 +20,13 @@ pub mod diff;
 pub mod expected;
 pub mod introspect;
 pub mod migrate;
+pub mod snapshot;
 
 pub use create::{CreateTable, SchemaBuilder};
 pub use ddl::{
-    DdlGenerator, MysqlDdlGenerator, PostgresDdlGenerator, SqliteDdlGenerator,
-    generator_for_dialect,
+    DdlConfig, DdlGenerator, IdentifierLengthPolicy, IdentifierLengthViolation, MysqlDdlGenerator,
+    PostgresDdlGenerator, SqliteDdlGenerator, constraint_name, generator_for_dialect,
+    shorten_identifier, validate_identifier_lengths,
 };
 pub use expected::{
     ModelSchema, ModelTuple, expected_schema, normalize_sql_type, table_schema_from_fields,
@@ -33,7 +36,11 @@ pub use introspect::{
     CheckConstraintInfo, ColumnInfo, DatabaseSchema, Dialect, ForeignKeyInfo, IndexInfo,
     Introspector, ParsedSqlType, TableInfo, UniqueConstraintInfo,
 };
-pub use migrate::{Migration, MigrationFormat, MigrationRunner, MigrationStatus, MigrationWriter};
+pub use migrate::{
+    Migration, MigrationError, MigrationFormat, MigrationRunner, MigrationStatus, MigrationWriter,
+    run_migration,
+};
+pub use snapshot::{SNAPSHOT_VERSION, SchemaSnapshot};
 
 use asupersync::{Cx, Outcome};
 use sqlmodel_core::{Connection, Model, quote_ident};
@@ -5,11 +5,15 @@
 //! - Writing migrations to disk (SQL or Rust format)
 //! - Running migrations against a database
 //! - Tracking applied migrations
+//! - Running a list of statements atomically ([`run_migration`])
 
 use crate::ddl::DdlGenerator;
 use crate::diff::SchemaOperation;
 use asupersync::{Cx, Outcome};
-use sqlmodel_core::{Connection, Error, Value};
+use sqlmodel_core::connection::TransactionOps;
+use sqlmodel_core::error::{SchemaError, SchemaErrorKind};
+use sqlmodel_core::script::split_statements;
+use sqlmodel_core::{Connection, Dialect, Error, Value};
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 
@@ -135,6 +139,27 @@ impl Migration {
             down,
         }
     }
+
+    /// The UP script split into statements, ready for [`run_migration`].
+    pub fn up_statements(&self, dialect: Dialect) -> Vec<String> {
+        split_statements(&self.up, dialect)
+    }
+
+    /// The DOWN script split into statements, ready for [`run_migration`].
+    pub fn down_statements(&self, dialect: Dialect) -> Vec<String> {
+        split_statements(&self.down, dialect)
+    }
+
+    /// Describe this migration as a pending entry of the console's
+    /// migration status table, with both scripts as SQL previews.
+    #[cfg(feature = "console")]
+    #[must_use]
+    pub fn to_record(&self) -> sqlmodel_console::renderables::MigrationRecord {
+        let preview = |sql: &str| (!sql.trim().is_empty()).then(|| sql.to_string());
+        sqlmodel_console::renderables::MigrationRecord::new(&self.id, &self.description)
+            .up_sql(preview(&self.up))
+            .down_sql(preview(&self.down))
+    }
 }
 
 /// Check if a year is a leap year.
@@ -451,8 +476,8 @@ impl MigrationRunner {
                 };
 
                 // Execute the up migration
-                match conn.execute(cx, &migration.up, &[]).await {
-                    Outcome::Ok(_) => {}
+                match execute_script(cx, conn, &migration.up).await {
+                    Outcome::Ok(()) => {}
                     Outcome::Err(e) => return Outcome::Err(e),
                     Outcome::Cancelled(r) => return Outcome::Cancelled(r),
                     Outcome::Panicked(p) => return Outcome::Panicked(p),
@@ -530,8 +555,8 @@ impl MigrationRunner {
         };
 
         // Execute the down migration
-        match conn.execute(cx, &migration.down, &[]).await {
-            Outcome::Ok(_) => {}
+        match execute_script(cx, conn, &migration.down).await {
+            Outcome::Ok(()) => {}
             Outcome::Err(e) => return Outcome::Err(e),
             Outcome::Cancelled(r) => return Outcome::Cancelled(r),
             Outcome::Panicked(p) => return Outcome::Panicked(p),
@@ -553,6 +578,132 @@ impl MigrationRunner {
     }
 }
 
+/// Execute a migration script one statement at a time.
+///
+/// Drivers run a single statement per call, so the script is split with
+/// [`split_statements`](sqlmodel_core::script::split_statements) first.
+async fn execute_script<C: Connection>(cx: &Cx, conn: &C, script: &str) -> Outcome<(), Error> {
+    for statement in split_statements(script, conn.dialect()) {
+        match conn.execute(cx, &statement, &[]).await {
+            Outcome::Ok(_) => {}
+            Outcome::Err(e) => return Outcome::Err(e),
+            Outcome::Cancelled(r) => return Outcome::Cancelled(r),
+            Outcome::Panicked(p) => return Outcome::Panicked(p),
+        }
+    }
+    Outcome::Ok(())
+}
+
+/// A statement of a migration script that failed.
+///
+/// Returned by [`run_migration`] after the transaction has been rolled back.
+#[derive(Debug)]
+pub struct MigrationError {
+    /// Position of the failed statement in the script, or `None` if the
+    /// surrounding `BEGIN` or `COMMIT` failed.
+    pub index: Option<usize>,
+    /// The statement that failed.
+    pub statement: String,
+    /// The underlying database error.
+    pub source: Error,
+}
+
+impl std::fmt::Display for MigrationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.index {
+            Some(index) => write!(
+                f,
+                "migration statement {} failed: {} ({})",
+                index + 1,
+                self.source,
+                self.statement
+            ),
+            None => write!(f, "migration {} failed: {}", self.statement, self.source),
+        }
+    }
+}
+
+impl std::error::Error for MigrationError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+impl From<MigrationError> for Error {
+    fn from(err: MigrationError) -> Self {
+        Error::Schema(SchemaError {
+            kind: SchemaErrorKind::Migration,
+            message: err.to_string(),
+            source: Some(Box::new(err)),
+        })
+    }
+}
+
+/// Execute `statements` in order inside a single transaction.
+///
+/// Commits if every statement succeeds. On the first failure the transaction
+/// is rolled back and the failing statement is reported.
+///
+/// Atomicity depends on the database:
+/// - SQLite runs all DDL transactionally.
+/// - PostgreSQL runs most DDL transactionally, but statements such as
+///   `CREATE INDEX CONCURRENTLY`, `CREATE DATABASE` and `VACUUM` refuse to run
+///   inside a transaction block and will fail here; apply them separately.
+///   `ALTER TYPE ... ADD VALUE` also cannot be used in the same transaction
+///   that adds the value before PostgreSQL 12.
+/// - MySQL implicitly commits around every DDL statement, so earlier
+///   statements are *not* undone when a later one fails.
+pub async fn run_migration<C: Connection>(
+    cx: &Cx,
+    conn: &C,
+    statements: &[String],
+) -> Outcome<(), MigrationError> {
+    let tx = match conn.begin(cx).await {
+        Outcome::Ok(tx) => tx,
+        Outcome::Err(source) => {
+            return Outcome::Err(MigrationError {
+                index: None,
+                statement: "BEGIN".to_string(),
+                source,
+            });
+        }
+        Outcome::Cancelled(r) => return Outcome::Cancelled(r),
+        Outcome::Panicked(p) => return Outcome::Panicked(p),
+    };
+
+    for (index, statement) in statements.iter().enumerate() {
+        match tx.execute(cx, statement, &[]).await {
+            Outcome::Ok(_) => {}
+            Outcome::Err(source) => {
+                // The statement error is what the caller needs; a failed
+                // rollback only means the connection is already unusable.
+                let _ = tx.rollback(cx).await;
+                return Outcome::Err(MigrationError {
+                    index: Some(index),
+                    statement: statement.clone(),
+                    source,
+                });
+            }
+            Outcome::Cancelled(r) => {
+                let _ = tx.rollback(cx).await;
+                return Outcome::Cancelled(r);
+            }
+            Outcome::Panicked(p) => return Outcome::Panicked(p),
+        }
+    }
+
+    match tx.commit(cx).await {
+        Outcome::Ok(()) => Outcome::Ok(()),
+        Outcome::Err(source) => Outcome::Err(MigrationError {
+            index: None,
+            statement: "COMMIT".to_string(),
+            source,
+        }),
+        Outcome::Cancelled(r) => Outcome::Cancelled(r),
+        Outcome::Panicked(p) => Outcome::Panicked(p),
+    }
+}
+
 // ============================================================================
 // Unit Tests
 // ============================================================================
@@ -573,6 +724,26 @@ mod tests {
         assert!((2020..=2100).contains(&year));
     }
 
+    #[test]
+    fn test_migration_error_reports_failed_statement() {
+        let err = MigrationError {
+            index: Some(1),
+            statement: "ALTER TABLE heroes ADD COLUMN age INTEGER".to_string(),
+            source: Error::Custom("duplicate column".to_string()),
+        };
+        let message = err.to_string();
+        assert!(message.starts_with("migration statement 2 failed"));
+        assert!(message.contains("ALTER TABLE heroes ADD COLUMN age INTEGER"));
+
+        match Error::from(err) {
+            Error::Schema(schema) => {
+                assert!(matches!(schema.kind, SchemaErrorKind::Migration));
+                assert!(schema.source.is_some());
+            }
+            other => panic!("expected schema error, got {other:?}"),
+        }
+    }
+
     #[test]
     fn test_version_ordering() {
         // Test that version strings are lexicographically sortable
@@ -620,6 +791,10 @@ mod tests {
                     primary_key: true,
                     auto_increment: true,
                     comment: None,
+                    custom_type: None,
+                    generated: None,
+                    stored: false,
+                    collation: None,
                 },
                 ColumnInfo {
                     name: "name".to_string(),
@@ -630,6 +805,10 @@ mod tests {
                     primary_key: false,
                     auto_increment: false,
                     comment: None,
+                    custom_type: None,
+                    generated: None,
+                    stored: false,
+                    collation: None,
                 },
             ],
             primary_key: vec!["id".to_string()],
@@ -649,6 +828,30 @@ mod tests {
         assert!(m.up.contains("CREATE TABLE"));
         assert!(m.up.contains("heroes"));
         assert!(m.down.contains("DROP TABLE"));
+
+        // Each direction splits back into the generated statements.
+        assert_eq!(m.up_statements(Dialect::Sqlite), ddl.generate_all(&ops));
+        assert_eq!(
+            m.down_statements(Dialect::Sqlite),
+            ddl.generate_rollback(&ops)
+        );
+    }
+
+    #[cfg(feature = "console")]
+    #[test]
+    fn test_migration_to_record() {
+        use sqlmodel_console::renderables::MigrationState;
+
+        let m = Migration::new("001", "create heroes", "CREATE TABLE heroes (id INT);", "");
+        let record = m.to_record();
+        assert_eq!(record.version, "001");
+        assert_eq!(record.name, "create heroes");
+        assert_eq!(record.state, MigrationState::Pending);
+        assert_eq!(
+            record.up_sql.as_deref(),
+            Some("CREATE TABLE heroes (id INT);")
+        );
+        assert_eq!(record.down_sql, None);
     }
 
     #[test]
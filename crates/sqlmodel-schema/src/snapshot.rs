@@ -0,0 +1,217 @@
+//! Serializable schema snapshots.
+//!
+//! A [`SchemaSnapshot`] captures a full [`DatabaseSchema`] as JSON so it can be
+//! committed as a migration baseline and diffed against the current models in
+//! CI without a live database:
+//!
+//! ```ignore
+//! let baseline = SchemaSnapshot::from_json(&std::fs::read_to_string("schema.json")?)?;
+//! let diff = schema_diff(&baseline.to_schema(), &expected_schema::<Models>(baseline.dialect));
+//! assert!(diff.is_empty(), "models drifted from the baseline");
+//! ```
+
+use crate::introspect::{DatabaseSchema, Dialect, TableInfo};
+use serde::{Deserialize, Serialize};
+use sqlmodel_core::Error;
+use sqlmodel_core::error::{SchemaError, SchemaErrorKind};
+
+/// Snapshot format version written by this release.
+///
+/// Bumped whenever the format changes incompatibly; [`SchemaSnapshot::from_json`]
+/// rejects snapshots written by a newer version instead of misreading them.
+pub const SNAPSHOT_VERSION: u32 = 1;
+
+/// A point-in-time copy of a database schema.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SchemaSnapshot {
+    /// Format version the snapshot was written with.
+    pub version: u32,
+    /// Dialect the schema belongs to.
+    pub dialect: Dialect,
+    /// Tables, sorted by name so snapshots diff cleanly under version control.
+    pub tables: Vec<TableInfo>,
+}
+
+impl SchemaSnapshot {
+    /// Create a snapshot of the given tables.
+    pub fn new(dialect: Dialect, mut tables: Vec<TableInfo>) -> Self {
+        tables.sort_by(|a, b| a.name.cmp(&b.name));
+        Self {
+            version: SNAPSHOT_VERSION,
+            dialect,
+            tables,
+        }
+    }
+
+    /// Create a snapshot of an introspected or expected schema.
+    pub fn from_schema(schema: &DatabaseSchema) -> Self {
+        Self::new(schema.dialect, schema.tables.values().cloned().collect())
+    }
+
+    /// Rebuild the schema this snapshot was taken from.
+    pub fn to_schema(&self) -> DatabaseSchema {
+        let mut schema = DatabaseSchema::new(self.dialect);
+        for table in &self.tables {
+            schema.tables.insert(table.name.clone(), table.clone());
+        }
+        schema
+    }
+
+    /// Serialize the snapshot as pretty-printed JSON.
+    pub fn to_json(&self) -> Result<String, Error> {
+        serde_json::to_string_pretty(self).map_err(|e| Error::Serde(e.to_string()))
+    }
+
+    /// Parse a snapshot previously written by [`SchemaSnapshot::to_json`].
+    pub fn from_json(json: &str) -> Result<Self, Error> {
+        let snapshot: Self = serde_json::from_str(json).map_err(|e| Error::Serde(e.to_string()))?;
+        if snapshot.version > SNAPSHOT_VERSION {
+            return Err(Error::Schema(SchemaError {
+                kind: SchemaErrorKind::Invalid,
+                message: format!(
+                    "schema snapshot version {} is newer than the supported version {}",
+                    snapshot.version, SNAPSHOT_VERSION
+                ),
+                source: None,
+            }));
+        }
+        Ok(snapshot)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::diff::schema_diff;
+    use crate::introspect::{
+        CheckConstraintInfo, ColumnInfo, ForeignKeyInfo, IndexInfo, ParsedSqlType,
+        UniqueConstraintInfo,
+    };
+
+    fn column(name: &str, sql_type: &str, primary_key: bool) -> ColumnInfo {
+        ColumnInfo {
+            name: name.to_string(),
+            sql_type: sql_type.to_string(),
+            parsed_type: ParsedSqlType::parse(sql_type),
+            nullable: !primary_key,
+            default: None,
+            primary_key,
+            auto_increment: primary_key,
+            comment: None,
+            custom_type: None,
+            generated: None,
+            stored: false,
+            collation: None,
+        }
+    }
+
+    fn sample_schema() -> DatabaseSchema {
+        let mut schema = DatabaseSchema::new(Dialect::Postgres);
+        schema.tables.insert(
+            "teams".to_string(),
+            TableInfo {
+                name: "teams".to_string(),
+                columns: vec![
+                    column("id", "BIGINT", true),
+                    column("name", "VARCHAR(100)", false),
+                ],
+                primary_key: vec!["id".to_string()],
+                foreign_keys: Vec::new(),
+                unique_constraints: vec![UniqueConstraintInfo {
+                    name: Some("uk_teams_name".to_string()),
+                    columns: vec!["name".to_string()],
+                }],
+                check_constraints: Vec::new(),
+                indexes: Vec::new(),
+                comment: Some("All teams".to_string()),
+            },
+        );
+        let mut total = column("total", "NUMERIC(10,2)", false);
+        total.generated = Some("price * qty".to_string());
+        total.stored = true;
+        schema.tables.insert(
+            "heroes".to_string(),
+            TableInfo {
+                name: "heroes".to_string(),
+                columns: vec![
+                    column("id", "BIGINT", true),
+                    column("team_id", "BIGINT", false),
+                    total,
+                ],
+                primary_key: vec!["id".to_string()],
+                foreign_keys: vec![ForeignKeyInfo {
+                    name: Some("fk_heroes_team_id".to_string()),
+                    column: "team_id".to_string(),
+                    foreign_table: "teams".to_string(),
+                    foreign_column: "id".to_string(),
+                    on_delete: Some("CASCADE".to_string()),
+                    on_update: None,
+                    deferrable: true,
+                    initially_deferred: false,
+                }],
+                unique_constraints: Vec::new(),
+                check_constraints: vec![CheckConstraintInfo {
+                    name: Some("ck_heroes_total".to_string()),
+                    expression: "total >= 0".to_string(),
+                }],
+                indexes: vec![IndexInfo {
+                    name: "idx_heroes_team_id".to_string(),
+                    columns: vec!["team_id".to_string()],
+                    unique: false,
+                    index_type: Some("btree".to_string()),
+                    primary: false,
+                    expressions: Vec::new(),
+                    predicate: None,
+                }],
+                comment: None,
+            },
+        );
+        schema
+    }
+
+    #[test]
+    fn test_snapshot_round_trip_is_lossless() {
+        let schema = sample_schema();
+        let snapshot = SchemaSnapshot::from_schema(&schema);
+        assert_eq!(snapshot.version, SNAPSHOT_VERSION);
+        assert_eq!(
+            snapshot
+                .tables
+                .iter()
+                .map(|t| t.name.as_str())
+                .collect::<Vec<_>>(),
+            vec!["heroes", "teams"]
+        );
+
+        let json = snapshot.to_json().unwrap();
+        let restored = SchemaSnapshot::from_json(&json).unwrap();
+        assert_eq!(restored.to_json().unwrap(), json);
+        assert_eq!(restored.dialect, Dialect::Postgres);
+
+        let heroes = restored.to_schema().tables["heroes"].clone();
+        assert_eq!(heroes.foreign_keys[0].on_delete.as_deref(), Some("CASCADE"));
+        assert!(heroes.foreign_keys[0].deferrable);
+        assert_eq!(heroes.check_constraints[0].expression, "total >= 0");
+        assert_eq!(heroes.indexes[0].index_type.as_deref(), Some("btree"));
+        let total = heroes.column("total").unwrap();
+        assert_eq!(total.generated.as_deref(), Some("price * qty"));
+        assert_eq!(total.parsed_type.precision, Some(10));
+
+        assert!(schema_diff(&restored.to_schema(), &schema).is_empty());
+    }
+
+    #[test]
+    fn test_snapshot_rejects_newer_version() {
+        let mut snapshot = SchemaSnapshot::from_schema(&sample_schema());
+        snapshot.version = SNAPSHOT_VERSION + 1;
+        let json = serde_json::to_string(&snapshot).unwrap();
+        assert!(matches!(
+            SchemaSnapshot::from_json(&json),
+            Err(Error::Schema(_))
+        ));
+        assert!(matches!(
+            SchemaSnapshot::from_json("{\"version\": 1}"),
+            Err(Error::Serde(_))
+        ));
+    }
+}
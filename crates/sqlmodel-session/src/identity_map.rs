@@ -54,7 +54,7 @@ fn hash_single_value(v: &Value, hasher: &mut impl std::hash::Hasher) {
     use std::hash::Hash;
 
     match v {
-        Value::Null => 0u8.hash(hasher),
+        Value::Null | Value::TypedNull(_) => 0u8.hash(hasher),
         Value::Bool(b) => {
             1u8.hash(hasher);
             b.hash(hasher);
@@ -55,11 +55,18 @@ pub use unit_of_work::{PendingCounts, UnitOfWork, UowError};
 
 use asupersync::{Cx, Outcome};
 use serde::{Deserialize, Serialize};
-use sqlmodel_core::{Connection, Error, Lazy, LazyLoader, Model, Value};
+use sqlmodel_core::error::{StaleObjectError, TransactionError, TransactionErrorKind, TypeError};
+use sqlmodel_core::{
+    Clock, Connection, Dialect, Error, Lazy, LazyLoader, Model, ProgressReporter, SystemClock,
+    Timestamps, TransactionMode, TransactionStatus, Value, quote_ident_checked,
+};
+use sqlmodel_query::Expr;
 use std::any::{Any, TypeId};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, hash_map};
 use std::future::Future;
 use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 // ============================================================================
 // Session Events
@@ -71,6 +78,82 @@ use std::hash::{Hash, Hasher};
 /// Returning `Err` will abort the operation (e.g., prevent commit).
 type SessionEventFn = Box<dyn FnMut() -> Result<(), Error> + Send>;
 
+/// Callback receiving per-chunk progress from bulk operations and flush.
+type BatchProgressFn = Box<dyn FnMut(BatchProgress) + Send>;
+
+/// Progress of a bulk insert or flush, reported after each chunk executes.
+///
+/// `bulk_insert` counts `completed` and `total` in rows; `flush` counts them
+/// in statements, and reports as `rows_in_batch` the rows each statement
+/// affected according to the database (an INSERT returning a generated key
+/// counts as one row). Reported rows have been sent to the database but are
+/// not durable until the surrounding transaction commits; a later rollback
+/// discards them even though progress was already reported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BatchProgress {
+    /// Table the chunk was written to.
+    pub table: &'static str,
+    /// Rows covered by this chunk (rows affected, for flush).
+    pub rows_in_batch: u64,
+    /// Rows (statements, for flush) completed so far, including this chunk.
+    pub completed: u64,
+    /// Total rows (statements, for flush) the operation expects to process.
+    pub total: u64,
+}
+
+/// Kind of statement listed by `Session::preview_flush()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PreviewKind {
+    Delete,
+    Insert,
+    Update,
+}
+
+/// A statement `flush()` would execute, with its bound parameters.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PreviewStatement {
+    /// Whether this is a DELETE, INSERT or UPDATE.
+    pub kind: PreviewKind,
+    /// Table the statement targets.
+    pub table: &'static str,
+    /// SQL text in the connection's dialect.
+    pub sql: String,
+    /// Parameters bound to the placeholders in `sql`.
+    pub params: Vec<Value>,
+}
+
+/// Alias of the window-count column added by [`Session::paginate`].
+const PAGINATE_TOTAL_COLUMN: &str = "__total_count";
+
+/// One page of results from [`Session::paginate`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Page<M> {
+    /// Objects on this page, in primary key order.
+    pub items: Vec<M>,
+    /// Number of rows matching the predicate across all pages.
+    pub total: u64,
+    /// 1-based page number that was requested.
+    pub page: u64,
+    /// Maximum number of items per page.
+    pub page_size: u64,
+    /// Number of pages needed to hold `total` items (0 when there are none).
+    pub total_pages: u64,
+}
+
+impl<M> Page<M> {
+    /// Whether a page follows this one.
+    #[must_use]
+    pub fn has_next(&self) -> bool {
+        self.page < self.total_pages
+    }
+
+    /// Whether a page precedes this one.
+    #[must_use]
+    pub fn has_previous(&self) -> bool {
+        self.page > 1
+    }
+}
+
 /// Holds registered session-level event callbacks.
 ///
 /// These are fired at key points in the session lifecycle:
@@ -140,7 +223,61 @@ pub struct SessionConfig {
     /// Whether to auto-flush before queries (not recommended for performance).
     pub auto_flush: bool,
     /// Whether to expire objects after commit (reload from DB on next access).
+    ///
+    /// When off, objects stay `Persistent` and are marked clean: what commit
+    /// flushed becomes their original state, so only later changes are
+    /// written. Values the database filled in itself (defaults, triggers)
+    /// are not seen until `Session::refresh`.
     pub expire_on_commit: bool,
+    /// Whether `flush()` records the SQL and duration of every statement it
+    /// executes (see `Session::last_flush_timings()`).
+    pub collect_timings: bool,
+    /// How `add()` keys objects whose auto-increment primary key is still unset.
+    pub unset_pk_policy: UnsetPkPolicy,
+    /// Characteristics of transactions started by `begin()` (including auto-begin).
+    /// The default emits a plain `BEGIN`.
+    pub transaction_mode: TransactionMode,
+    /// Start every transaction as `READ ONLY`, regardless of `transaction_mode`.
+    /// SQLite has no read-only transactions, so it has no effect there.
+    pub read_only: bool,
+    /// Estimated plan cost above which SELECTs log a warning.
+    ///
+    /// When set, SELECTs run through `query_as()`, `get_by()` and `paginate()`
+    /// are first `EXPLAIN`ed and a `tracing` warning is emitted if the top plan
+    /// node's total cost exceeds the threshold. This costs an extra round trip
+    /// per query and is meant for catching accidental sequential scans in
+    /// development. SQLite reports no cost estimates and is never checked.
+    pub explain_threshold: Option<f64>,
+    /// Estimated row count above which SELECTs log a warning.
+    ///
+    /// Checked alongside `explain_threshold` from the same `EXPLAIN`: the top
+    /// plan node's `rows=` estimate on PostgreSQL, the largest
+    /// `rows_examined_per_scan` on MySQL.
+    pub explain_rows_threshold: Option<u64>,
+    /// Time source for `Session::now()` and timestamp stamping.
+    ///
+    /// `None` (the default) reads the system clock directly; set a
+    /// `FixedClock` in tests to get deterministic timestamps.
+    pub clock: Option<Arc<dyn Clock>>,
+    /// Maximum bound parameters per statement; `None` uses the connection's
+    /// limit (`Capabilities::max_bind_params`).
+    ///
+    /// IN-list loads and deletes, `bulk_update_by_ids` and the bulk inserts
+    /// split their work into several statements to stay under this limit.
+    pub max_params: Option<usize>,
+    /// Fail `flush()` with `Error::StaleObject` when a single-object UPDATE or
+    /// DELETE affects no rows, instead of logging a warning.
+    ///
+    /// A zero count means the row was deleted or re-keyed elsewhere since it
+    /// was loaded. MySQL reports only *changed* rows unless the connection
+    /// sets `CLIENT_FOUND_ROWS`, so an UPDATE that writes identical values
+    /// also counts as stale there. UPDATEs of models with a `VERSION_COLUMN`
+    /// always fail on a zero count, regardless of this setting.
+    pub detect_stale: bool,
+    /// Receives the "Flushing" and "Committing" phases, e.g. to label a CLI
+    /// spinner. The driver's own reporter (such as `PgConfig::progress`)
+    /// covers connecting and reading results.
+    pub progress: Option<Arc<dyn ProgressReporter>>,
 }
 
 impl Default for SessionConfig {
@@ -149,10 +286,38 @@ impl Default for SessionConfig {
             auto_begin: true,
             auto_flush: false,
             expire_on_commit: true,
+            collect_timings: false,
+            unset_pk_policy: UnsetPkPolicy::default(),
+            transaction_mode: TransactionMode::default(),
+            read_only: false,
+            explain_threshold: None,
+            explain_rows_threshold: None,
+            clock: None,
+            max_params: None,
+            detect_stale: false,
+            progress: None,
         }
     }
 }
 
+/// How `Session::add()` handles objects whose auto-increment primary key has
+/// not been assigned yet (`NULL` or `0`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum UnsetPkPolicy {
+    /// Key the object by its placeholder primary key value as-is. Adding two
+    /// unsaved objects of the same type makes the second replace the first.
+    Placeholder,
+    /// Track the object under a unique temporary key and leave the primary key
+    /// column out of its INSERT. The generated id is backfilled on flush and the
+    /// object is re-keyed in the identity map.
+    ///
+    /// Because the temporary key is not derived from the object, adding the same
+    /// unsaved object twice schedules two INSERTs, and `contains()`, `delete()`
+    /// and `expunge()` only find the object once a flush has assigned its id.
+    #[default]
+    TemporaryKey,
+}
+
 /// Options for `Session::get_with_options()`.
 #[derive(Debug, Clone, Default)]
 pub struct GetOptions {
@@ -224,6 +389,19 @@ impl ObjectKey {
         }
     }
 
+    /// Create a unique placeholder key for an object whose primary key has not
+    /// been generated yet.
+    fn temporary<M: 'static>(seq: u64) -> Self {
+        use std::collections::hash_map::DefaultHasher;
+        let mut hasher = DefaultHasher::new();
+        u8::MAX.hash(&mut hasher);
+        seq.hash(&mut hasher);
+        Self {
+            type_id: TypeId::of::<M>(),
+            pk_hash: hasher.finish(),
+        }
+    }
+
     /// Get the primary key hash.
     pub fn pk_hash(&self) -> u64 {
         self.pk_hash
@@ -242,7 +420,7 @@ fn hash_values(values: &[Value]) -> u64 {
     for v in values {
         // Hash based on value variant and content
         match v {
-            Value::Null => 0u8.hash(&mut hasher),
+            Value::Null | Value::TypedNull(_) => 0u8.hash(&mut hasher),
             Value::Bool(b) => {
                 1u8.hash(&mut hasher);
                 b.hash(&mut hasher);
@@ -327,7 +505,7 @@ fn hash_values(values: &[Value]) -> u64 {
 /// Hash a single value into the hasher.
 fn hash_value(v: &Value, hasher: &mut impl Hasher) {
     match v {
-        Value::Null => 0u8.hash(hasher),
+        Value::Null | Value::TypedNull(_) => 0u8.hash(hasher),
         Value::Bool(b) => {
             1u8.hash(hasher);
             b.hash(hasher);
@@ -440,17 +618,241 @@ struct TrackedObject {
     pk_values: Vec<Value>,
     /// Static relationship metadata for this object's model type.
     relationships: &'static [sqlmodel_core::RelationshipInfo],
+    /// Optimistic-concurrency version column, if the model declares one.
+    version: Option<VersionColumn>,
     /// Set of expired attribute names (None = all expired, Some(empty) = none expired).
     /// When Some(non-empty), only those specific attributes need reload.
     expired_attributes: Option<std::collections::HashSet<String>>,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+impl TrackedObject {
+    /// Columns whose value in `current` differs from the state loaded from
+    /// the database, or `None` if that state was not captured.
+    ///
+    /// Values are compared in their serialized form, the same form
+    /// `original_state` holds.
+    fn changed_columns(&self, current: &[(&'static str, Value)]) -> Option<Vec<&'static str>> {
+        let original: Vec<serde_json::Value> =
+            serde_json::from_slice(self.original_state.as_ref()?).ok()?;
+        let changed = self
+            .column_names
+            .iter()
+            .enumerate()
+            .filter(|&(i, col)| {
+                let now = current
+                    .iter()
+                    .find(|(name, _)| name == col)
+                    .and_then(|(_, value)| serde_json::to_value(value).ok());
+                now.as_ref() != original.get(i)
+            })
+            .map(|(_, col)| *col)
+            .collect();
+        Some(changed)
+    }
+
+    /// Borrow the tracked object as `M` on an identity-map hit.
+    ///
+    /// Keys embed `M`'s `TypeId`, so a failed downcast means the map holds an
+    /// object of the wrong type under `M`'s key. That is a programming error
+    /// and is reported instead of being masked by a database reload.
+    fn downcast<M: Model + 'static>(&self) -> Result<&M, Error> {
+        debug_assert_eq!(
+            self.table_name,
+            M::TABLE_NAME,
+            "identity map entry for {} is tracked under table {}",
+            std::any::type_name::<M>(),
+            self.table_name
+        );
+        self.object.downcast_ref::<M>().ok_or_else(|| {
+            tracing::error!(
+                table = self.table_name,
+                expected = std::any::type_name::<M>(),
+                "Identity map entry has the wrong type"
+            );
+            Error::Type(TypeError {
+                expected: std::any::type_name::<M>(),
+                actual: format!("identity map entry for table '{}'", self.table_name),
+                column: None,
+                rust_type: None,
+            })
+        })
+    }
+}
+
+/// A model's `VERSION_COLUMN`, with what flush needs to bump it in memory.
+#[derive(Clone, Copy)]
+struct VersionColumn {
+    column: &'static str,
+    /// Rebuilds the typed object once the new version is known.
+    rebuild: fn(&[&'static str], &[Value]) -> Option<Box<dyn Any + Send + Sync>>,
+}
+
+impl VersionColumn {
+    fn of<M: Model + Send + Sync + 'static>() -> Option<Self> {
+        M::VERSION_COLUMN.map(|column| Self {
+            column,
+            rebuild: rebuild_tracked_object::<M>,
+        })
+    }
+}
+
+/// Auto-increment primary key awaiting backfill for an object tracked under a
+/// temporary key.
+#[derive(Clone, Copy)]
+struct PendingPk {
+    /// The primary key column the database will generate.
+    column: &'static str,
+    /// Rebuilds the typed object from its columns once the id is known.
+    rebuild: fn(&[&'static str], &[Value]) -> Option<Box<dyn Any + Send + Sync>>,
+}
+
+fn rebuild_tracked_object<M: Model + Send + Sync + 'static>(
+    columns: &[&'static str],
+    values: &[Value],
+) -> Option<Box<dyn Any + Send + Sync>> {
+    let row = sqlmodel_core::Row::new(
+        columns.iter().map(|c| (*c).to_string()).collect(),
+        values.to_vec(),
+    );
+    M::from_row(&row)
+        .ok()
+        .map(|obj| Box::new(obj) as Box<dyn Any + Send + Sync>)
+}
+
+/// The auto-increment primary key column of `obj`, if its value is still unset.
+fn unset_auto_increment_pk<M: Model>(obj: &M) -> Option<&'static str> {
+    let [pk_column] = M::PRIMARY_KEY else {
+        return None;
+    };
+    let [field] = M::primary_key_fields()[..] else {
+        return None;
+    };
+    if !field.auto_increment {
+        return None;
+    }
+    let unset = match obj.primary_key_value().as_slice() {
+        [value] => {
+            value.is_null()
+                || matches!(
+                    value,
+                    Value::TinyInt(0) | Value::SmallInt(0) | Value::Int(0) | Value::BigInt(0)
+                )
+        }
+        _ => false,
+    };
+    unset.then_some(*pk_column)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 struct CascadeChildDeleteKey {
     table: &'static str,
     fk_cols: Vec<&'static str>,
 }
 
+/// Explicit cascade DELETEs derived from the relationships of deleted parents,
+/// keyed by target table and column(s) with deduplicated parent key values.
+#[derive(Debug, Default)]
+struct CascadeDeletePlan {
+    /// One-to-many / one-to-one children keyed by a single foreign key column.
+    child_single: BTreeMap<(&'static str, &'static str), Vec<Value>>,
+    /// Children keyed by a composite foreign key.
+    child_composite: BTreeMap<CascadeChildDeleteKey, Vec<Vec<Value>>>,
+    /// Many-to-many link rows keyed by a single local column.
+    link_single: BTreeMap<(&'static str, &'static str), Vec<Value>>,
+    /// Many-to-many link rows keyed by composite local columns.
+    link_composite: BTreeMap<CascadeChildDeleteKey, Vec<Vec<Value>>>,
+}
+
+/// Tracked dependents to drop once a cascade DELETE has run: rows of `table`
+/// whose `fk_cols` hold one of the `parents` keys.
+#[derive(Debug)]
+struct CascadeCleanup {
+    table: &'static str,
+    fk_cols: Vec<&'static str>,
+    parents: Vec<Vec<Value>>,
+}
+
+/// What a planned flush statement writes, for the bookkeeping after it runs.
+#[derive(Debug)]
+enum FlushTarget {
+    /// Cascade DELETE of dependent rows; the cleanup rides on the last
+    /// statement of each group.
+    Cascade(Option<CascadeCleanup>),
+    /// DELETE of a deleted object.
+    Delete(ObjectKey),
+    /// INSERT of a new object.
+    Insert(ObjectKey),
+    /// UPDATE of a modified persistent object.
+    Update(ObjectKey),
+}
+
+/// One statement of a flush plan; see `Session::plan_flush`.
+#[derive(Debug)]
+struct FlushStep {
+    statement: PreviewStatement,
+    target: FlushTarget,
+}
+
+/// `(state, original_state)` of a tracked object.
+type SavedState = (ObjectState, Option<Vec<u8>>);
+
+/// Identity-map changes since a transaction or savepoint began, undone when
+/// it rolls back.
+///
+/// Only objects touched since then are recorded, each the first time it
+/// changes, so taking a snapshot costs nothing up front.
+#[derive(Default)]
+struct IdentitySnapshot {
+    /// Touched objects in first-touch order.
+    touched: Vec<ObjectKey>,
+    /// Saved state per touched object; `None` if it was not tracked when the
+    /// snapshot began.
+    saved: HashMap<ObjectKey, Option<SavedState>>,
+    /// Objects tracked at the snapshot that have since left the identity map.
+    removed: HashMap<ObjectKey, TrackedObject>,
+    /// Objects moved from a temporary key to their generated primary key.
+    rekeyed: Vec<(ObjectKey, ObjectKey, PendingPk)>,
+}
+
+impl IdentitySnapshot {
+    /// Record `key`'s current entry unless it was already recorded.
+    fn touch(&mut self, identity_map: &HashMap<ObjectKey, TrackedObject>, key: ObjectKey) {
+        if let hash_map::Entry::Vacant(entry) = self.saved.entry(key) {
+            entry.insert(
+                identity_map
+                    .get(&key)
+                    .map(|tracked| (tracked.state, tracked.original_state.clone())),
+            );
+            self.touched.push(key);
+        }
+    }
+
+    /// Whether `key` was tracked when the snapshot began.
+    fn was_tracked(&self, key: &ObjectKey) -> bool {
+        self.saved.get(key).is_some_and(Option::is_some)
+    }
+
+    /// Fold a released inner snapshot into this one; entries recorded here
+    /// first are older and win.
+    fn absorb(&mut self, mut inner: Self) {
+        for key in inner.touched {
+            let Some(saved) = inner.saved.remove(&key) else {
+                continue;
+            };
+            if let hash_map::Entry::Vacant(entry) = self.saved.entry(key) {
+                entry.insert(saved);
+                self.touched.push(key);
+            }
+        }
+        for (key, tracked) in inner.removed {
+            if self.was_tracked(&key) {
+                self.removed.entry(key).or_insert(tracked);
+            }
+        }
+        self.rekeyed.extend(inner.rekeyed);
+    }
+}
+
 // ============================================================================
 // Session
 // ============================================================================
@@ -478,6 +880,23 @@ pub struct Session<C: Connection> {
     n1_tracker: Option<N1QueryTracker>,
     /// Session-level event callbacks.
     event_callbacks: SessionEventCallbacks,
+    /// Per-statement timings from the most recent flush (`None` unless `collect_timings` is set).
+    flush_timings: Option<Vec<(String, Duration)>>,
+    /// Active savepoint names, outermost first.
+    savepoints: Vec<String>,
+    /// Monotonic counter used to generate savepoint names.
+    next_savepoint_id: u64,
+    /// Optional progress callback for bulk operations and flush.
+    batch_progress: Option<BatchProgressFn>,
+    /// Objects tracked under a temporary key until their generated id is known.
+    pending_pks: HashMap<ObjectKey, PendingPk>,
+    /// Monotonic counter used to generate temporary object keys.
+    next_temporary_key: u64,
+    /// The open transaction was begun by the caller, who also ends it.
+    external_transaction: bool,
+    /// Rollback snapshots: one for a transaction begun by this session, then
+    /// one per entry of `savepoints`.
+    snapshots: Vec<IdentitySnapshot>,
 }
 
 impl<C: Connection> Session<C> {
@@ -498,9 +917,57 @@ impl<C: Connection> Session<C> {
             config,
             n1_tracker: None,
             event_callbacks: SessionEventCallbacks::default(),
+            flush_timings: None,
+            savepoints: Vec::new(),
+            next_savepoint_id: 0,
+            batch_progress: None,
+            pending_pks: HashMap::new(),
+            next_temporary_key: 0,
+            external_transaction: false,
+            snapshots: Vec::new(),
+        }
+    }
+
+    /// Create a session that joins a transaction already open on `connection`.
+    ///
+    /// See [`Session::attach_transaction`]. Pass `auto_begin: false` in
+    /// `config` if the session should also be usable after
+    /// [`Session::detach_transaction`] without beginning its own transactions.
+    pub fn with_transaction(connection: C, config: SessionConfig) -> Self {
+        let mut session = Self::with_config(connection, config);
+        session.attach_transaction();
+        session
+    }
+
+    /// Adopt a transaction that is open on the connection but managed
+    /// elsewhere (e.g. by request middleware). Emits no SQL.
+    ///
+    /// Flushes run inside the outer transaction. `commit()` flushes but sends
+    /// no `COMMIT`, and `rollback()` fails with
+    /// [`TransactionErrorKind::External`]; ending the transaction is left to
+    /// its owner. Savepoints still work.
+    pub fn attach_transaction(&mut self) {
+        self.in_transaction = true;
+        self.external_transaction = true;
+    }
+
+    /// Stop participating in an external transaction after its owner ended it.
+    ///
+    /// Emits no SQL. Savepoints created inside it are forgotten.
+    pub fn detach_transaction(&mut self) {
+        if self.external_transaction {
+            self.in_transaction = false;
+            self.external_transaction = false;
+            self.savepoints.clear();
+            self.snapshots.clear();
         }
     }
 
+    /// Whether the session is running inside an attached external transaction.
+    pub fn has_external_transaction(&self) -> bool {
+        self.external_transaction
+    }
+
     /// Get a reference to the underlying connection.
     pub fn connection(&self) -> &C {
         &self.connection
@@ -511,6 +978,50 @@ impl<C: Connection> Session<C> {
         &self.config
     }
 
+    /// Bound-parameter limit per statement: the configured override, or the
+    /// connection's own limit.
+    fn max_params(&self) -> usize {
+        self.config
+            .max_params
+            .unwrap_or_else(|| self.connection.capabilities().max_bind_params)
+            .max(1)
+    }
+
+    /// Tell the configured progress reporter, if any, that `phase` has started.
+    fn report_phase(&self, phase: &str) {
+        if let Some(progress) = &self.config.progress {
+            progress.on_phase(phase);
+        }
+    }
+
+    /// Current time from the configured clock, in microseconds since the epoch.
+    pub fn now(&self) -> i64 {
+        match &self.config.clock {
+            Some(clock) => clock.now(),
+            None => SystemClock::now_micros(),
+        }
+    }
+
+    /// Set both `created_at` and `updated_at` on a new object to [`now()`](Self::now).
+    pub fn stamp_created<M: Timestamps>(&self, obj: &mut M) {
+        let now = self.now();
+        obj.set_created_at(now);
+        obj.set_updated_at(now);
+    }
+
+    /// Set `updated_at` on a modified object to [`now()`](Self::now).
+    pub fn stamp_updated<M: Timestamps>(&self, obj: &mut M) {
+        obj.set_updated_at(self.now());
+    }
+
+    /// Statements executed by the most recent `flush()` with their durations.
+    ///
+    /// Empty unless `SessionConfig::collect_timings` is enabled. The list is
+    /// reset at the start of every flush.
+    pub fn last_flush_timings(&self) -> &[(String, Duration)] {
+        self.flush_timings.as_deref().unwrap_or_default()
+    }
+
     // ========================================================================
     // Session Events
     // ========================================================================
@@ -527,6 +1038,30 @@ impl<C: Connection> Session<C> {
         self.event_callbacks.after_flush.push(Box::new(f));
     }
 
+    /// Register a callback reporting progress of `bulk_insert` and `flush`.
+    ///
+    /// `bulk_insert` reports once per chunk. `flush` reports once per executed
+    /// statement: the cascade and link-table DELETEs it issues, then the
+    /// INSERT/UPDATE/DELETE of each tracked object that needs one, with `total`
+    /// counting those statements up front. Progress reflects statements sent,
+    /// not committed: see [`BatchProgress`]. Replaces any previously set
+    /// callback.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let mut tracker = BatchOperationTracker::new("import", batches, rows);
+    /// session.on_batch_progress(move |p| tracker.complete_batch(p.rows_in_batch));
+    /// ```
+    pub fn on_batch_progress(&mut self, f: impl FnMut(BatchProgress) + Send + 'static) {
+        self.batch_progress = Some(Box::new(f));
+    }
+
+    /// Remove the batch progress callback.
+    pub fn clear_batch_progress(&mut self) {
+        self.batch_progress = None;
+    }
+
     /// Register a callback to run before commit (after flush).
     ///
     /// The callback can abort the commit by returning `Err`.
@@ -550,19 +1085,28 @@ impl<C: Connection> Session<C> {
 
     /// Add a new object to the session.
     ///
-    /// The object will be INSERTed on the next `flush()` call.
+    /// The object will be INSERTed on the next `flush()` call. Objects whose
+    /// auto-increment primary key is still unset are handled according to
+    /// `SessionConfig::unset_pk_policy`.
     pub fn add<M: Model + Clone + Send + Sync + Serialize + 'static>(&mut self, obj: &M) {
-        let key = ObjectKey::from_model(obj);
+        self.stage(obj.clone());
+    }
+
+    /// Track `obj` as new, or refresh the tracked copy if its key is known.
+    fn stage<M: Model + Clone + Send + Sync + Serialize + 'static>(&mut self, obj: M) {
+        let key = self
+            .temporary_key_for(&obj)
+            .unwrap_or_else(|| ObjectKey::from_model(&obj));
+        self.touch(key);
 
         // If already tracked, update the object and its values
         if let Some(tracked) = self.identity_map.get_mut(&key) {
-            tracked.object = Box::new(obj.clone());
-
             // Update stored values to match the new object state
             let row_data = obj.to_row();
             tracked.column_names = row_data.iter().map(|(name, _)| *name).collect();
             tracked.values = row_data.into_iter().map(|(_, v)| v).collect();
             tracked.pk_values = obj.primary_key_value();
+            tracked.object = Box::new(obj);
 
             if tracked.state == ObjectState::Deleted {
                 // Un-delete: remove from pending_delete and restore state
@@ -592,7 +1136,7 @@ impl<C: Connection> Session<C> {
         let pk_values = obj.primary_key_value();
 
         let tracked = TrackedObject {
-            object: Box::new(obj.clone()),
+            object: Box::new(obj),
             original_state: None, // New objects have no original state
             state: ObjectState::New,
             table_name: M::TABLE_NAME,
@@ -601,6 +1145,7 @@ impl<C: Connection> Session<C> {
             pk_columns,
             pk_values,
             relationships: M::RELATIONSHIPS,
+            version: VersionColumn::of::<M>(),
             expired_attributes: None,
         };
 
@@ -608,6 +1153,28 @@ impl<C: Connection> Session<C> {
         self.pending_new.push(key);
     }
 
+    /// Allocate a temporary identity-map key if `obj` has an unset
+    /// auto-increment primary key and the policy calls for one.
+    fn temporary_key_for<M: Model + Send + Sync + 'static>(
+        &mut self,
+        obj: &M,
+    ) -> Option<ObjectKey> {
+        if self.config.unset_pk_policy != UnsetPkPolicy::TemporaryKey {
+            return None;
+        }
+        let column = unset_auto_increment_pk(obj)?;
+        self.next_temporary_key += 1;
+        let key = ObjectKey::temporary::<M>(self.next_temporary_key);
+        self.pending_pks.insert(
+            key,
+            PendingPk {
+                column,
+                rebuild: rebuild_tracked_object::<M>,
+            },
+        );
+        Some(key)
+    }
+
     /// Add multiple objects to the session at once.
     ///
     /// This is equivalent to calling `add()` for each object, but provides a more
@@ -634,17 +1201,36 @@ impl<C: Connection> Session<C> {
         }
     }
 
+    /// Add multiple owned objects to the session at once.
+    ///
+    /// Like [`add_all`](Self::add_all), but moves each object into the
+    /// session instead of cloning it, for callers that are done with them:
+    ///
+    /// ```ignore
+    /// session.add_all_owned(parse_import(&file)?);
+    /// ```
+    pub fn add_all_owned<M, I>(&mut self, objects: I)
+    where
+        M: Model + Clone + Send + Sync + Serialize + 'static,
+        I: IntoIterator<Item = M>,
+    {
+        for obj in objects {
+            self.stage(obj);
+        }
+    }
+
     /// Delete an object from the session.
     ///
     /// The object will be DELETEd on the next `flush()` call.
     pub fn delete<M: Model + 'static>(&mut self, obj: &M) {
         let key = ObjectKey::from_model(obj);
+        self.touch(key);
 
         if let Some(tracked) = self.identity_map.get_mut(&key) {
             match tracked.state {
                 ObjectState::New => {
                     // If it's new, just remove it entirely
-                    self.identity_map.remove(&key);
+                    self.untrack(key);
                     self.pending_new.retain(|k| k != &key);
                 }
                 ObjectState::Persistent | ObjectState::Expired => {
@@ -664,6 +1250,10 @@ impl<C: Connection> Session<C> {
     /// This updates the stored values from the object and schedules an UPDATE.
     /// Only works for objects that are already tracked as Persistent.
     ///
+    /// For models with a `VERSION_COLUMN`, the session keeps its own version
+    /// value rather than the object's, so the same in-memory object can be
+    /// edited and flushed repeatedly after earlier UPDATEs bumped the version.
+    ///
     /// # Example
     ///
     /// ```ignore
@@ -681,6 +1271,16 @@ impl<C: Connection> Session<C> {
                 return;
             }
 
+            // The tracked copy owns the version: after a flush it holds the
+            // bumped value, while the caller's object still has the old one.
+            let known_version = tracked.version.and_then(|version| {
+                let idx = tracked
+                    .column_names
+                    .iter()
+                    .position(|c| *c == version.column)?;
+                Some((version, tracked.values[idx].clone()))
+            });
+
             // Update the stored object and values
             tracked.object = Box::new(obj.clone());
             let row_data = obj.to_row();
@@ -688,6 +1288,18 @@ impl<C: Connection> Session<C> {
             tracked.values = row_data.into_iter().map(|(_, v)| v).collect();
             tracked.pk_values = obj.primary_key_value();
 
+            if let Some((version, value)) = known_version
+                && let Some(idx) = tracked
+                    .column_names
+                    .iter()
+                    .position(|c| *c == version.column)
+            {
+                tracked.values[idx] = value;
+                if let Some(object) = (version.rebuild)(&tracked.column_names, &tracked.values) {
+                    tracked.object = object;
+                }
+            }
+
             // Add to pending dirty if not already there
             if !self.pending_dirty.contains(&key) {
                 self.pending_dirty.push(key);
@@ -720,19 +1332,27 @@ impl<C: Connection> Session<C> {
                     tracing::debug!("Object is expired, reloading from database");
                 }
                 ObjectState::New | ObjectState::Persistent => {
-                    if let Some(obj) = tracked.object.downcast_ref::<M>() {
-                        return Outcome::Ok(Some(obj.clone()));
-                    }
+                    return match tracked.downcast::<M>() {
+                        Ok(obj) => Outcome::Ok(Some(obj.clone())),
+                        Err(e) => Outcome::Err(e),
+                    };
                 }
             }
         }
 
         // Query from database
         let pk_col = M::PRIMARY_KEY.first().unwrap_or(&"id");
+        let dialect = self.connection.dialect();
+        let (table, pk_col) = match (
+            quote_ident_checked(M::TABLE_NAME, dialect),
+            quote_ident_checked(pk_col, dialect),
+        ) {
+            (Ok(table), Ok(pk_col)) => (table, pk_col),
+            (Err(e), _) | (_, Err(e)) => return Outcome::Err(e),
+        };
         let sql = format!(
-            "SELECT * FROM \"{}\" WHERE \"{}\" = $1 LIMIT 1",
-            M::TABLE_NAME,
-            pk_col
+            "SELECT * FROM {table} WHERE {pk_col} = {} LIMIT 1",
+            dialect.placeholder(1)
         );
 
         let rows = match self.connection.query(cx, &sql, &[pk_value]).await {
@@ -774,6 +1394,7 @@ impl<C: Connection> Session<C> {
             pk_columns,
             pk_values: obj_pk_values,
             relationships: M::RELATIONSHIPS,
+            version: VersionColumn::of::<M>(),
             expired_attributes: None,
         };
 
@@ -806,6 +1427,74 @@ impl<C: Connection> Session<C> {
             .await
     }
 
+    /// Get several objects by primary key in as few queries as possible.
+    ///
+    /// Each entry of `pks` holds one object's primary-key values in
+    /// `M::PRIMARY_KEY` order, so composite keys such as link tables work too.
+    /// Objects already in the identity map are returned from it; the rest are
+    /// loaded with [`Dialect::tuple_in_clause`] queries split to stay under the
+    /// parameter limit. Results follow the order of `pks`, skipping keys with
+    /// no row.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let items = session.get_many::<OrderItem>(&cx, &[
+    ///     vec![Value::BigInt(1), Value::BigInt(10)],
+    ///     vec![Value::BigInt(1), Value::BigInt(11)],
+    /// ]).await?;
+    /// ```
+    pub async fn get_many<
+        M: Model + Clone + Send + Sync + Serialize + for<'de> Deserialize<'de> + 'static,
+    >(
+        &mut self,
+        cx: &Cx,
+        pks: &[Vec<Value>],
+    ) -> Outcome<Vec<M>, Error> {
+        let is_live = |tracked: &TrackedObject| {
+            matches!(tracked.state, ObjectState::New | ObjectState::Persistent)
+        };
+
+        let mut missing: Vec<Vec<Value>> = Vec::new();
+        for pk in pks {
+            let key = ObjectKey::from_pk::<M>(pk);
+            let cached = self.identity_map.get(&key).is_some_and(is_live);
+            if !cached && !missing.contains(pk) {
+                missing.push(pk.clone());
+            }
+        }
+
+        let dialect = self.connection.dialect();
+        let table = dialect.quote_identifier(M::TABLE_NAME);
+        let chunk_len = (self.max_params() / M::PRIMARY_KEY.len().max(1)).max(1);
+        for chunk in missing.chunks(chunk_len) {
+            let (in_sql, params) = dialect.tuple_in_clause(M::PRIMARY_KEY, chunk, 1);
+            let sql = format!("SELECT * FROM {table} WHERE {in_sql}");
+            let rows = match self.connection.query(cx, &sql, &params).await {
+                Outcome::Ok(rows) => rows,
+                Outcome::Err(e) => return Outcome::Err(e),
+                Outcome::Cancelled(r) => return Outcome::Cancelled(r),
+                Outcome::Panicked(p) => return Outcome::Panicked(p),
+            };
+            if let Err(e) = self.track_loaded_rows::<M>(&rows) {
+                return Outcome::Err(e);
+            }
+        }
+
+        let mut results = Vec::with_capacity(pks.len());
+        for pk in pks {
+            let key = ObjectKey::from_pk::<M>(pk);
+            let Some(tracked) = self.identity_map.get(&key).filter(|t| is_live(t)) else {
+                continue;
+            };
+            match tracked.downcast::<M>() {
+                Ok(obj) => results.push(obj.clone()),
+                Err(e) => return Outcome::Err(e),
+            }
+        }
+        Outcome::Ok(results)
+    }
+
     /// Get an object by primary key with options.
     ///
     /// This is the most flexible form of `get()` supporting:
@@ -840,9 +1529,10 @@ impl<C: Connection> Session<C> {
                         tracing::debug!("Object is expired, reloading from database");
                     }
                     ObjectState::New | ObjectState::Persistent => {
-                        if let Some(obj) = tracked.object.downcast_ref::<M>() {
-                            return Outcome::Ok(Some(obj.clone()));
-                        }
+                        return match tracked.downcast::<M>() {
+                            Ok(obj) => Outcome::Ok(Some(obj.clone())),
+                            Err(e) => Outcome::Err(e),
+                        };
                     }
                 }
             }
@@ -858,15 +1548,23 @@ impl<C: Connection> Session<C> {
             )));
         }
 
-        let where_parts: Vec<String> = pk_columns
+        let dialect = self.connection.dialect();
+        let where_parts: Result<Vec<String>, Error> = pk_columns
             .iter()
             .enumerate()
-            .map(|(i, col)| format!("\"{}\" = ${}", col, i + 1))
+            .map(|(i, col)| {
+                quote_ident_checked(col, dialect)
+                    .map(|col| format!("{col} = {}", dialect.placeholder(i + 1)))
+            })
             .collect();
+        let (table, where_parts) = match (quote_ident_checked(M::TABLE_NAME, dialect), where_parts)
+        {
+            (Ok(table), Ok(where_parts)) => (table, where_parts),
+            (Err(e), _) | (_, Err(e)) => return Outcome::Err(e),
+        };
 
         let mut sql = format!(
-            "SELECT * FROM \"{}\" WHERE {} LIMIT 1",
-            M::TABLE_NAME,
+            "SELECT * FROM {table} WHERE {} LIMIT 1",
             where_parts.join(" AND ")
         );
 
@@ -919,6 +1617,7 @@ impl<C: Connection> Session<C> {
             pk_columns: pk_cols,
             pk_values: obj_pk_values,
             relationships: M::RELATIONSHIPS,
+            version: VersionColumn::of::<M>(),
             expired_attributes: None,
         };
 
@@ -927,2532 +1626,6136 @@ impl<C: Connection> Session<C> {
         Outcome::Ok(Some(obj))
     }
 
-    /// Check if an object is tracked by this session.
-    pub fn contains<M: Model + 'static>(&self, obj: &M) -> bool {
-        let key = ObjectKey::from_model(obj);
-        self.identity_map.contains_key(&key)
-    }
-
-    /// Detach an object from the session.
-    pub fn expunge<M: Model + 'static>(&mut self, obj: &M) {
-        let key = ObjectKey::from_model(obj);
-        if let Some(tracked) = self.identity_map.get_mut(&key) {
-            tracked.state = ObjectState::Detached;
-        }
-        self.pending_new.retain(|k| k != &key);
-        self.pending_delete.retain(|k| k != &key);
-        self.pending_dirty.retain(|k| k != &key);
-    }
-
-    /// Detach all objects from the session.
-    pub fn expunge_all(&mut self) {
-        for tracked in self.identity_map.values_mut() {
-            tracked.state = ObjectState::Detached;
-        }
-        self.pending_new.clear();
-        self.pending_delete.clear();
-        self.pending_dirty.clear();
-    }
-
-    // ========================================================================
-    // Dirty Checking
-    // ========================================================================
-
-    /// Check if an object has pending changes.
+    /// Get an object by a unique (or indexed) non-primary-key column.
     ///
-    /// Returns `true` if:
-    /// - Object is new (pending INSERT)
-    /// - Object has been modified since load (pending UPDATE)
-    /// - Object is marked for deletion (pending DELETE)
-    ///
-    /// Returns `false` if:
-    /// - Object is not tracked
-    /// - Object is clean (unchanged since load)
-    /// - Object is detached or expired
+    /// `column` must name a field of `M` (by column or field name) that is marked
+    /// `unique`, `index`, or `primary_key`. The lookup fetches at most two rows and
+    /// returns an error if more than one matches, since that violates the uniqueness
+    /// assumption. The result is registered in the identity map under its real
+    /// primary key; if that object is already tracked, the tracked instance wins.
     ///
     /// # Example
     ///
     /// ```ignore
-    /// let user = session.get::<User>(1).await?.unwrap();
-    /// assert!(!session.is_modified(&user));  // Fresh from DB
-    ///
-    /// // Modify and re-check
-    /// let mut user_mut = user.clone();
-    /// user_mut.name = "New Name".to_string();
-    /// session.mark_dirty(&user_mut);
-    /// assert!(session.is_modified(&user_mut));  // Now dirty
+    /// let user = session
+    ///     .get_by::<User>(&cx, "email", Value::Text("a@example.com".into()))
+    ///     .await?;
     /// ```
-    pub fn is_modified<M: Model + Serialize + 'static>(&self, obj: &M) -> bool {
-        let key = ObjectKey::from_model(obj);
-
-        let Some(tracked) = self.identity_map.get(&key) else {
-            return false;
+    pub async fn get_by<
+        M: Model + Clone + Send + Sync + Serialize + for<'de> Deserialize<'de> + 'static,
+    >(
+        &mut self,
+        cx: &Cx,
+        column: &str,
+        value: Value,
+    ) -> Outcome<Option<M>, Error> {
+        let Some(field) = M::fields()
+            .iter()
+            .find(|f| f.column_name == column || f.name == column)
+        else {
+            return Outcome::Err(Error::Custom(format!(
+                "get_by: {} has no column named '{}'",
+                M::TABLE_NAME,
+                column
+            )));
         };
+        if !(field.unique || field.primary_key || field.index.is_some()) {
+            return Outcome::Err(Error::Custom(format!(
+                "get_by: column '{}.{}' is not unique or indexed",
+                M::TABLE_NAME,
+                field.column_name
+            )));
+        }
 
-        match tracked.state {
-            // New objects are always "modified" (pending INSERT)
-            ObjectState::New => true,
+        let dialect = self.connection.dialect();
+        let (table, column) = match (
+            quote_ident_checked(M::TABLE_NAME, dialect),
+            quote_ident_checked(field.column_name, dialect),
+        ) {
+            (Ok(table), Ok(column)) => (table, column),
+            (Err(e), _) | (_, Err(e)) => return Outcome::Err(e),
+        };
+        let sql = format!(
+            "SELECT * FROM {table} WHERE {column} = {} LIMIT 2",
+            dialect.placeholder(1)
+        );
 
-            // Deleted objects are "modified" (pending DELETE)
-            ObjectState::Deleted => true,
+        let params = [value];
+        match self.check_plan_cost(cx, &sql, &params).await {
+            Outcome::Ok(()) => {}
+            Outcome::Err(e) => return Outcome::Err(e),
+            Outcome::Cancelled(r) => return Outcome::Cancelled(r),
+            Outcome::Panicked(p) => return Outcome::Panicked(p),
+        }
 
-            // Detached/expired objects aren't modified in session context
-            ObjectState::Detached | ObjectState::Expired => false,
+        let rows = match self.connection.query(cx, &sql, &params).await {
+            Outcome::Ok(rows) => rows,
+            Outcome::Err(e) => return Outcome::Err(e),
+            Outcome::Cancelled(r) => return Outcome::Cancelled(r),
+            Outcome::Panicked(p) => return Outcome::Panicked(p),
+        };
 
-            // For persistent objects, compare current values to original
-            ObjectState::Persistent => {
-                // Check if explicitly marked dirty
-                if self.pending_dirty.contains(&key) {
-                    return true;
-                }
+        if rows.len() > 1 {
+            return Outcome::Err(Error::Custom(format!(
+                "get_by: more than one row in {} matches {} (expected a unique column)",
+                M::TABLE_NAME,
+                field.column_name
+            )));
+        }
 
-                // Compare serialized values
-                let current_state = serde_json::to_vec(&tracked.values).unwrap_or_default();
-                tracked.original_state.as_ref() != Some(&current_state)
-            }
+        // Register by the real primary key, deferring to any already-tracked instance.
+        match self.track_loaded_rows::<M>(&rows) {
+            Ok(objects) => Outcome::Ok(objects.into_iter().next()),
+            Err(e) => Outcome::Err(e),
         }
     }
 
-    /// Get the list of modified attribute names for an object.
+    /// Fetch the object matching every `lookup` column, or create it from
+    /// `defaults` if there is none.
     ///
-    /// Returns the column names that have changed since the object was loaded.
-    /// Returns an empty vector if:
-    /// - Object is not tracked
-    /// - Object is new (all fields are "modified")
-    /// - Object is clean (no changes)
+    /// Returns the object and whether it was created. `lookup` pairs a field
+    /// (by column or field name) with the value it must equal; `defaults`
+    /// should carry the same values so the created row matches the lookup.
+    /// A transaction is begun if none is active, so the lookup and the insert
+    /// commit together.
+    ///
+    /// On PostgreSQL the insert runs immediately as
+    /// `INSERT ... ON CONFLICT DO NOTHING RETURNING *`: if a concurrent
+    /// transaction inserted a matching row after the lookup, the conflict is
+    /// swallowed and that row is returned instead. Other backends stage
+    /// `defaults` as new, to be inserted by the next flush.
     ///
     /// # Example
     ///
     /// ```ignore
-    /// let mut user = session.get::<User>(1).await?.unwrap();
-    /// user.name = "New Name".to_string();
-    /// session.mark_dirty(&user);
-    ///
-    /// let changed = session.modified_attributes(&user);
-    /// assert!(changed.contains(&"name"));
+    /// let (tag, created) = session
+    ///     .get_or_create(&cx, &[("slug", Value::Text("rust".into()))], Tag::new("rust"))
+    ///     .await?;
     /// ```
-    pub fn modified_attributes<M: Model + Serialize + 'static>(
-        &self,
-        obj: &M,
-    ) -> Vec<&'static str> {
-        let key = ObjectKey::from_model(obj);
-
-        let Some(tracked) = self.identity_map.get(&key) else {
-            return Vec::new();
-        };
+    pub async fn get_or_create<
+        M: Model + Clone + Send + Sync + Serialize + for<'de> Deserialize<'de> + 'static,
+    >(
+        &mut self,
+        cx: &Cx,
+        lookup: &[(&str, Value)],
+        defaults: M,
+    ) -> Outcome<(M, bool), Error> {
+        if lookup.is_empty() {
+            return Outcome::Err(Error::Custom(
+                "get_or_create: lookup must name at least one column".to_string(),
+            ));
+        }
+        let mut columns = Vec::with_capacity(lookup.len());
+        for (column, _) in lookup {
+            let Some(field) = M::fields()
+                .iter()
+                .find(|f| f.column_name == *column || f.name == *column)
+            else {
+                return Outcome::Err(Error::Custom(format!(
+                    "get_or_create: {} has no column named '{}'",
+                    M::TABLE_NAME,
+                    column
+                )));
+            };
+            columns.push(field.column_name);
+        }
 
-        // Only meaningful for persistent objects
-        if tracked.state != ObjectState::Persistent {
-            return Vec::new();
+        if !self.in_transaction {
+            match self.begin(cx).await {
+                Outcome::Ok(()) => {}
+                Outcome::Err(e) => return Outcome::Err(e),
+                Outcome::Cancelled(r) => return Outcome::Cancelled(r),
+                Outcome::Panicked(p) => return Outcome::Panicked(p),
+            }
         }
 
-        // Need original state for comparison
-        let Some(original_bytes) = &tracked.original_state else {
-            return Vec::new();
+        let dialect = self.connection.dialect();
+        let predicate: Result<Vec<String>, Error> = columns
+            .iter()
+            .enumerate()
+            .map(|(i, column)| {
+                quote_ident_checked(column, dialect)
+                    .map(|column| format!("{column} = {}", dialect.placeholder(i + 1)))
+            })
+            .collect();
+        let (table, predicate) = match (quote_ident_checked(M::TABLE_NAME, dialect), predicate) {
+            (Ok(table), Ok(predicate)) => (table, predicate.join(" AND ")),
+            (Err(e), _) | (_, Err(e)) => return Outcome::Err(e),
         };
+        let sql = format!("SELECT * FROM {table} WHERE {predicate} LIMIT 2");
+        let params: Vec<Value> = lookup.iter().map(|(_, value)| value.clone()).collect();
 
-        // Deserialize original values
-        let Ok(original_values): Result<Vec<Value>, _> = serde_json::from_slice(original_bytes)
-        else {
-            return Vec::new();
-        };
+        match self
+            .lookup_one::<M>(cx, "get_or_create", &sql, &params)
+            .await
+        {
+            Outcome::Ok(Some(obj)) => return Outcome::Ok((obj, false)),
+            Outcome::Ok(None) => {}
+            Outcome::Err(e) => return Outcome::Err(e),
+            Outcome::Cancelled(r) => return Outcome::Cancelled(r),
+            Outcome::Panicked(p) => return Outcome::Panicked(p),
+        }
 
-        // Compare each column
-        let mut modified = Vec::new();
-        for (i, col) in tracked.column_names.iter().enumerate() {
-            let current = tracked.values.get(i);
-            let original = original_values.get(i);
+        if dialect != sqlmodel_core::Dialect::Postgres {
+            self.stage(defaults.clone());
+            return Outcome::Ok((defaults, true));
+        }
 
-            if current != original {
-                modified.push(*col);
-            }
+        let inserted = match sqlmodel_query::InsertBuilder::new(&defaults)
+            .on_conflict_do_nothing()
+            .execute_returning(cx, &self.connection)
+            .await
+        {
+            Outcome::Ok(row) => row,
+            Outcome::Err(e) => return Outcome::Err(e),
+            Outcome::Cancelled(r) => return Outcome::Cancelled(r),
+            Outcome::Panicked(p) => return Outcome::Panicked(p),
+        };
+        if let Some(row) = inserted {
+            return match self.track_loaded_rows::<M>(&[row]) {
+                Ok(objects) => Outcome::Ok((objects.into_iter().next().unwrap_or(defaults), true)),
+                Err(e) => Outcome::Err(e),
+            };
         }
 
-        modified
+        // Lost the race: another transaction inserted a conflicting row.
+        match self
+            .lookup_one::<M>(cx, "get_or_create", &sql, &params)
+            .await
+        {
+            Outcome::Ok(Some(obj)) => Outcome::Ok((obj, false)),
+            Outcome::Ok(None) => Outcome::Err(Error::Custom(format!(
+                "get_or_create: insert into {} conflicted, but no row matches the lookup",
+                M::TABLE_NAME
+            ))),
+            Outcome::Err(e) => Outcome::Err(e),
+            Outcome::Cancelled(r) => Outcome::Cancelled(r),
+            Outcome::Panicked(p) => Outcome::Panicked(p),
+        }
     }
 
-    /// Get the state of a tracked object.
-    ///
-    /// Returns `None` if the object is not tracked by this session.
-    pub fn object_state<M: Model + 'static>(&self, obj: &M) -> Option<ObjectState> {
-        let key = ObjectKey::from_model(obj);
-        self.identity_map.get(&key).map(|t| t.state)
+    /// Run a lookup query expected to match at most one `M` and track the
+    /// result. Errors if more than one row matches; `caller` names the public
+    /// method in that error.
+    async fn lookup_one<M>(
+        &mut self,
+        cx: &Cx,
+        caller: &str,
+        sql: &str,
+        params: &[Value],
+    ) -> Outcome<Option<M>, Error>
+    where
+        M: Model + Clone + Send + Sync + Serialize + 'static,
+    {
+        let rows = match self.connection.query(cx, sql, params).await {
+            Outcome::Ok(rows) => rows,
+            Outcome::Err(e) => return Outcome::Err(e),
+            Outcome::Cancelled(r) => return Outcome::Cancelled(r),
+            Outcome::Panicked(p) => return Outcome::Panicked(p),
+        };
+        if rows.len() > 1 {
+            return Outcome::Err(Error::Custom(format!(
+                "{caller}: more than one row in {} matches the lookup",
+                M::TABLE_NAME
+            )));
+        }
+        match self.track_loaded_rows::<M>(&rows) {
+            Ok(objects) => Outcome::Ok(objects.into_iter().next()),
+            Err(e) => Outcome::Err(e),
+        }
     }
 
-    // ========================================================================
-    // Expiration
-    // ========================================================================
-
-    /// Expire an object's cached attributes, forcing reload on next access.
+    /// Get an object by primary key, loading only the given columns.
     ///
-    /// After calling this method, the next `get()` call for this object will reload
-    /// from the database instead of returning the cached version.
-    ///
-    /// # Arguments
+    /// `pk_values` are given in `M::PRIMARY_KEY` order, so composite keys work
+    /// too. Primary key columns are always selected. Unselected fields are filled
+    /// by [`Model::from_partial_row`] (for derived models: the declared
+    /// `default`, else `Default::default()`), and the object is tracked as
+    /// `Expired` with the unselected columns listed in `expired_attributes()`,
+    /// so the next `get()` reloads it in full. If the object is already fully
+    /// loaded in the identity map, that instance is returned without a query.
     ///
-    /// * `obj` - The object to expire.
-    /// * `attributes` - Optional list of attribute names to expire. If `None`, all
-    ///   attributes are expired.
+    /// A partially loaded object holds placeholder values: do not `add()` it
+    /// back or otherwise flush it as a full-row UPDATE, or the defaults will
+    /// overwrite the real column values. Reload it with `get()` or `refresh()`
+    /// before modifying it.
     ///
     /// # Example
     ///
     /// ```ignore
-    /// // Expire all attributes
-    /// session.expire(&user, None);
-    ///
-    /// // Expire specific attributes
-    /// session.expire(&user, Some(&["name", "email"]));
-    ///
-    /// // Next get() will reload from database
-    /// let refreshed = session.get::<User>(cx, user.id).await?;
+    /// // Skip the large `avatar` blob
+    /// let user = session
+    ///     .get_columns::<User>(cx, &[Value::BigInt(1)], &["name", "email"])
+    ///     .await?;
     /// ```
-    ///
-    /// # Notes
-    ///
-    /// - Expiring an object does not discard pending changes. If the object has been
-    ///   modified but not flushed, those changes remain pending.
-    /// - Expiring a detached or new object has no effect.
-    #[tracing::instrument(level = "debug", skip(self, obj), fields(table = M::TABLE_NAME))]
-    pub fn expire<M: Model + 'static>(&mut self, obj: &M, attributes: Option<&[&str]>) {
-        let key = ObjectKey::from_model(obj);
-
-        let Some(tracked) = self.identity_map.get_mut(&key) else {
-            tracing::debug!("Object not tracked, nothing to expire");
-            return;
-        };
-
-        // Only expire persistent objects
-        match tracked.state {
-            ObjectState::New | ObjectState::Detached | ObjectState::Deleted => {
-                tracing::debug!(state = ?tracked.state, "Cannot expire object in this state");
-                return;
-            }
-            ObjectState::Persistent | ObjectState::Expired => {}
+    pub async fn get_columns<
+        M: Model + Clone + Send + Sync + Serialize + for<'de> Deserialize<'de> + 'static,
+    >(
+        &mut self,
+        cx: &Cx,
+        pk_values: &[Value],
+        columns: &[&str],
+    ) -> Outcome<Option<M>, Error> {
+        if M::PRIMARY_KEY.len() != pk_values.len() {
+            return Outcome::Err(Error::Custom(format!(
+                "Primary key mismatch: expected {} values, got {}",
+                M::PRIMARY_KEY.len(),
+                pk_values.len()
+            )));
         }
+        let key = ObjectKey::from_pk::<M>(pk_values);
 
-        match attributes {
-            None => {
-                // Expire all attributes
-                tracked.state = ObjectState::Expired;
-                tracked.expired_attributes = None;
-                tracing::debug!("Expired all attributes");
-            }
-            Some(attrs) => {
-                // Expire specific attributes
-                let mut expired = tracked.expired_attributes.take().unwrap_or_default();
-                for attr in attrs {
-                    expired.insert((*attr).to_string());
-                }
-                tracked.expired_attributes = Some(expired);
-
-                // If any attributes are expired, mark the object as expired
-                if tracked.state == ObjectState::Persistent {
-                    tracked.state = ObjectState::Expired;
+        if let Some(tracked) = self.identity_map.get(&key) {
+            match tracked.state {
+                ObjectState::Deleted => return Outcome::Ok(None),
+                // A detached object's row still exists: load it afresh.
+                ObjectState::Detached | ObjectState::Expired => {}
+                ObjectState::New | ObjectState::Persistent => {
+                    return match tracked.downcast::<M>() {
+                        Ok(obj) => Outcome::Ok(Some(obj.clone())),
+                        Err(e) => Outcome::Err(e),
+                    };
                 }
-                tracing::debug!(attributes = ?attrs, "Expired specific attributes");
             }
         }
-    }
 
-    /// Expire all objects in the session.
-    ///
-    /// After calling this method, all tracked objects will be marked as expired.
-    /// The next access to any object will reload from the database.
-    ///
-    /// # Example
-    ///
-    /// ```ignore
-    /// // Expire everything in the session
-    /// session.expire_all();
-    ///
-    /// // All subsequent get() calls will reload from database
-    /// let user = session.get::<User>(cx, 1).await?;  // Reloads from DB
-    /// let team = session.get::<Team>(cx, 1).await?;  // Reloads from DB
-    /// ```
-    ///
-    /// # Notes
-    ///
-    /// - This does not affect new or deleted objects.
-    /// - Pending changes are not discarded.
-    #[tracing::instrument(level = "debug", skip(self))]
-    pub fn expire_all(&mut self) {
-        let mut expired_count = 0;
-        for tracked in self.identity_map.values_mut() {
-            if tracked.state == ObjectState::Persistent {
-                tracked.state = ObjectState::Expired;
-                tracked.expired_attributes = None;
-                expired_count += 1;
+        let mut selected: Vec<&str> = M::PRIMARY_KEY.to_vec();
+        for column in columns {
+            let Some(field) = M::fields()
+                .iter()
+                .find(|f| f.column_name == *column || f.name == *column)
+            else {
+                return Outcome::Err(Error::Custom(format!(
+                    "get_columns: {} has no column named '{}'",
+                    M::TABLE_NAME,
+                    column
+                )));
+            };
+            if !selected.contains(&field.column_name) {
+                selected.push(field.column_name);
             }
         }
-        tracing::debug!(count = expired_count, "Expired all session objects");
-    }
 
-    /// Check if an object is expired (needs reload from database).
-    ///
-    /// Returns `true` if the object is marked as expired and will be reloaded
-    /// on the next access.
-    pub fn is_expired<M: Model + 'static>(&self, obj: &M) -> bool {
-        let key = ObjectKey::from_model(obj);
-        self.identity_map
-            .get(&key)
-            .is_some_and(|t| t.state == ObjectState::Expired)
-    }
+        let dialect = self.connection.dialect();
+        let select_list: Vec<String> = selected
+            .iter()
+            .map(|c| dialect.quote_identifier(c))
+            .collect();
+        let where_parts: Vec<String> = M::PRIMARY_KEY
+            .iter()
+            .enumerate()
+            .map(|(i, col)| {
+                format!(
+                    "{} = {}",
+                    dialect.quote_identifier(col),
+                    dialect.placeholder(i + 1)
+                )
+            })
+            .collect();
+        let sql = format!(
+            "SELECT {} FROM {} WHERE {} LIMIT 1",
+            select_list.join(", "),
+            dialect.quote_identifier(M::TABLE_NAME),
+            where_parts.join(" AND ")
+        );
 
-    /// Get the list of expired attribute names for an object.
-    ///
-    /// Returns:
-    /// - `None` if the object is not tracked or not expired
-    /// - `Some(None)` if all attributes are expired
-    /// - `Some(Some(set))` if only specific attributes are expired
-    pub fn expired_attributes<M: Model + 'static>(
-        &self,
-        obj: &M,
-    ) -> Option<Option<&std::collections::HashSet<String>>> {
-        let key = ObjectKey::from_model(obj);
-        let tracked = self.identity_map.get(&key)?;
+        let rows = match self.connection.query(cx, &sql, pk_values).await {
+            Outcome::Ok(rows) => rows,
+            Outcome::Err(e) => return Outcome::Err(e),
+            Outcome::Cancelled(r) => return Outcome::Cancelled(r),
+            Outcome::Panicked(p) => return Outcome::Panicked(p),
+        };
+        let Some(row) = rows.first() else {
+            return Outcome::Ok(None);
+        };
 
-        if tracked.state != ObjectState::Expired {
-            return None;
-        }
+        // Only trust the columns we asked for.
+        let (names, values): (Vec<String>, Vec<Value>) = row
+            .iter()
+            .filter(|(name, _)| selected.contains(name))
+            .map(|(name, value)| (name.to_string(), value.clone()))
+            .unzip();
+        let (obj, loaded) = match M::from_partial_row(&sqlmodel_core::Row::new(names, values)) {
+            Ok(partial) => partial,
+            Err(e) => return Outcome::Err(e),
+        };
+        let row_data = obj.to_row();
+        let unloaded: std::collections::HashSet<String> = row_data
+            .iter()
+            .filter(|(name, _)| !loaded.contains(name))
+            .map(|(name, _)| (*name).to_string())
+            .collect();
+        let column_names: Vec<&'static str> = row_data.iter().map(|(name, _)| *name).collect();
+        let values: Vec<Value> = row_data.into_iter().map(|(_, v)| v).collect();
+        let serialized = serde_json::to_vec(&values).ok();
 
-        Some(tracked.expired_attributes.as_ref())
+        let tracked = TrackedObject {
+            object: Box::new(obj.clone()),
+            original_state: serialized,
+            state: if unloaded.is_empty() {
+                ObjectState::Persistent
+            } else {
+                ObjectState::Expired
+            },
+            table_name: M::TABLE_NAME,
+            column_names,
+            values,
+            pk_columns: M::PRIMARY_KEY.to_vec(),
+            pk_values: obj.primary_key_value(),
+            relationships: M::RELATIONSHIPS,
+            version: VersionColumn::of::<M>(),
+            expired_attributes: (!unloaded.is_empty()).then_some(unloaded),
+        };
+
+        self.identity_map.insert(key, tracked);
+
+        Outcome::Ok(Some(obj))
     }
 
-    /// Refresh an object by reloading it from the database.
-    ///
-    /// This method immediately reloads the object from the database, updating
-    /// the cached copy in the session. Unlike `expire()`, which defers the reload
-    /// until the next access, `refresh()` performs the reload immediately.
-    ///
-    /// # Arguments
-    ///
-    /// * `cx` - The async context for database operations.
-    /// * `obj` - The object to refresh.
-    ///
-    /// # Returns
+    /// Run arbitrary SQL and decode each row into `M`, tracking the results.
     ///
-    /// Returns `Ok(Some(refreshed))` if the object was found in the database,
-    /// `Ok(None)` if the object no longer exists in the database, or an error.
+    /// This is the escape hatch for SELECTs the model-aware API can't express.
+    /// Every decoded object is registered in the identity map as `Persistent`
+    /// (with its original state captured for dirty checking). Rows whose
+    /// object is already tracked resolve to the tracked instance, so pending
+    /// in-session changes are not clobbered; rows for objects deleted in this
+    /// session are skipped.
     ///
     /// # Example
     ///
     /// ```ignore
-    /// // Immediately reload from database
-    /// let refreshed = session.refresh(&cx, &user).await?;
-    ///
-    /// if let Some(user) = refreshed {
-    ///     println!("Refreshed: {}", user.name);
-    /// } else {
-    ///     println!("User was deleted from database");
-    /// }
+    /// let heroes = session
+    ///     .query_as::<Hero>(
+    ///         &cx,
+    ///         "SELECT h.* FROM heroes h JOIN teams t ON t.id = h.team_id WHERE t.name = $1",
+    ///         &[Value::Text("Avengers".into())],
+    ///     )
+    ///     .await?;
     /// ```
-    ///
-    /// # Notes
-    ///
-    /// - This discards any changes in the session's cached copy.
-    /// - If the object has pending changes, they will be lost.
-    /// - If the object no longer exists in the database, it is removed from the session.
-    #[tracing::instrument(level = "debug", skip(self, cx, obj), fields(table = M::TABLE_NAME))]
-    pub async fn refresh<
+    pub async fn query_as<
         M: Model + Clone + Send + Sync + Serialize + for<'de> Deserialize<'de> + 'static,
     >(
         &mut self,
         cx: &Cx,
-        obj: &M,
-    ) -> Outcome<Option<M>, Error> {
-        let pk_values = obj.primary_key_value();
-        let key = ObjectKey::from_model(obj);
+        sql: &str,
+        params: &[Value],
+    ) -> Outcome<Vec<M>, Error> {
+        match self.check_plan_cost(cx, sql, params).await {
+            Outcome::Ok(()) => {}
+            Outcome::Err(e) => return Outcome::Err(e),
+            Outcome::Cancelled(r) => return Outcome::Cancelled(r),
+            Outcome::Panicked(p) => return Outcome::Panicked(p),
+        }
 
-        tracing::debug!(pk = ?pk_values, "Refreshing object from database");
+        let rows = match self.connection.query(cx, sql, params).await {
+            Outcome::Ok(rows) => rows,
+            Outcome::Err(e) => return Outcome::Err(e),
+            Outcome::Cancelled(r) => return Outcome::Cancelled(r),
+            Outcome::Panicked(p) => return Outcome::Panicked(p),
+        };
 
-        // Remove from pending queues since we're reloading
-        self.pending_dirty.retain(|k| k != &key);
+        match self.track_loaded_rows::<M>(&rows) {
+            Ok(results) => Outcome::Ok(results),
+            Err(e) => Outcome::Err(e),
+        }
+    }
 
-        // Remove from identity map to force reload
-        self.identity_map.remove(&key);
+    /// Warn when a SELECT's estimated plan cost exceeds `explain_threshold`
+    /// or its estimated rows exceed `explain_rows_threshold`.
+    ///
+    /// EXPLAIN failures are logged and ignored; only cancellation and panics
+    /// propagate. Inside a PostgreSQL transaction the EXPLAIN runs under a
+    /// savepoint, so a failed EXPLAIN does not abort the transaction the real
+    /// query runs in.
+    async fn check_plan_cost(&self, cx: &Cx, sql: &str, params: &[Value]) -> Outcome<(), Error> {
+        let cost_threshold = self.config.explain_threshold;
+        let rows_threshold = self.config.explain_rows_threshold;
+        if cost_threshold.is_none() && rows_threshold.is_none() {
+            return Outcome::Ok(());
+        }
+        let is_select = sql
+            .trim_start()
+            .get(..6)
+            .is_some_and(|kw| kw.eq_ignore_ascii_case("SELECT"));
+        let explain_sql = match self.connection.dialect() {
+            Dialect::Postgres if is_select => format!("EXPLAIN {sql}"),
+            Dialect::Mysql if is_select => format!("EXPLAIN FORMAT=JSON {sql}"),
+            _ => return Outcome::Ok(()),
+        };
 
-        // Reload from database
-        let result = self.get_by_pk::<M>(cx, &pk_values).await;
+        // A failed statement aborts a PostgreSQL transaction; isolate the
+        // EXPLAIN so its failure can be rolled back.
+        let guarded = self.in_transaction && self.connection.dialect() == Dialect::Postgres;
+        if guarded {
+            match self
+                .connection
+                .execute(cx, "SAVEPOINT sqlmodel_explain", &[])
+                .await
+            {
+                Outcome::Ok(_) => {}
+                Outcome::Err(e) => {
+                    tracing::debug!(error = %e, "savepoint for plan cost check failed");
+                    return Outcome::Ok(());
+                }
+                Outcome::Cancelled(r) => return Outcome::Cancelled(r),
+                Outcome::Panicked(p) => return Outcome::Panicked(p),
+            }
+        }
 
-        match &result {
-            Outcome::Ok(Some(_)) => {
-                tracing::debug!("Object refreshed successfully");
+        let explained = self.connection.query(cx, &explain_sql, params).await;
+        if guarded {
+            if matches!(explained, Outcome::Err(_)) {
+                match self
+                    .connection
+                    .execute(cx, "ROLLBACK TO SAVEPOINT sqlmodel_explain", &[])
+                    .await
+                {
+                    Outcome::Ok(_) => {}
+                    Outcome::Err(e) => return Outcome::Err(e),
+                    Outcome::Cancelled(r) => return Outcome::Cancelled(r),
+                    Outcome::Panicked(p) => return Outcome::Panicked(p),
+                }
             }
-            Outcome::Ok(None) => {
-                tracing::debug!("Object no longer exists in database");
+            match self
+                .connection
+                .execute(cx, "RELEASE SAVEPOINT sqlmodel_explain", &[])
+                .await
+            {
+                Outcome::Ok(_) => {}
+                Outcome::Err(e) => return Outcome::Err(e),
+                Outcome::Cancelled(r) => return Outcome::Cancelled(r),
+                Outcome::Panicked(p) => return Outcome::Panicked(p),
             }
-            _ => {}
         }
 
-        result
-    }
-
-    // ========================================================================
-    // Transaction Management
-    // ========================================================================
+        let rows = match explained {
+            Outcome::Ok(rows) => rows,
+            Outcome::Err(e) => {
+                tracing::debug!(error = %e, "EXPLAIN for plan cost check failed");
+                return Outcome::Ok(());
+            }
+            Outcome::Cancelled(r) => return Outcome::Cancelled(r),
+            Outcome::Panicked(p) => return Outcome::Panicked(p),
+        };
 
-    /// Begin a transaction.
-    pub async fn begin(&mut self, cx: &Cx) -> Outcome<(), Error> {
-        if self.in_transaction {
+        let Some(plan) = rows
+            .first()
+            .and_then(|row| row.get(0))
+            .and_then(Value::as_str)
+        else {
             return Outcome::Ok(());
+        };
+        if let Some(threshold) = cost_threshold
+            && let Some(cost) = parse_plan_cost(plan).filter(|c| *c > threshold)
+        {
+            tracing::warn!(
+                cost,
+                threshold,
+                sql,
+                "query plan cost exceeds explain_threshold"
+            );
         }
+        if let Some(threshold) = rows_threshold
+            && let Some(rows) = parse_plan_rows(plan).filter(|r| *r > threshold)
+        {
+            tracing::warn!(
+                rows,
+                threshold,
+                sql,
+                "query plan row estimate exceeds explain_rows_threshold"
+            );
+        }
+        Outcome::Ok(())
+    }
 
-        match self.connection.execute(cx, "BEGIN", &[]).await {
-            Outcome::Ok(_) => {
-                self.in_transaction = true;
-                Outcome::Ok(())
+    /// Decode loaded rows into `M` and register them in the identity map.
+    ///
+    /// Objects already tracked resolve to the tracked instance; rows for
+    /// objects deleted in this session are skipped.
+    fn track_loaded_rows<M>(&mut self, rows: &[sqlmodel_core::Row]) -> Result<Vec<M>, Error>
+    where
+        M: Model + Clone + Send + Sync + Serialize + 'static,
+    {
+        let mut results = Vec::with_capacity(rows.len());
+        for row in rows {
+            let obj = M::from_row(row)?;
+
+            let obj_pk_values = obj.primary_key_value();
+            let key = ObjectKey::from_pk::<M>(&obj_pk_values);
+            if let Some(tracked) = self.identity_map.get(&key) {
+                match tracked.state {
+                    ObjectState::Deleted => continue,
+                    ObjectState::Detached | ObjectState::Expired => {}
+                    ObjectState::New | ObjectState::Persistent => {
+                        results.push(tracked.downcast::<M>()?.clone());
+                        continue;
+                    }
+                }
             }
-            Outcome::Err(e) => Outcome::Err(e),
-            Outcome::Cancelled(r) => Outcome::Cancelled(r),
-            Outcome::Panicked(p) => Outcome::Panicked(p),
+
+            let row_data = obj.to_row();
+            let column_names: Vec<&'static str> = row_data.iter().map(|(name, _)| *name).collect();
+            let values: Vec<Value> = row_data.into_iter().map(|(_, v)| v).collect();
+            let serialized = serde_json::to_vec(&values).ok();
+
+            let tracked = TrackedObject {
+                object: Box::new(obj.clone()),
+                original_state: serialized,
+                state: ObjectState::Persistent,
+                table_name: M::TABLE_NAME,
+                column_names,
+                values,
+                pk_columns: M::PRIMARY_KEY.to_vec(),
+                pk_values: obj_pk_values,
+                relationships: M::RELATIONSHIPS,
+                version: VersionColumn::of::<M>(),
+                expired_attributes: None,
+            };
+
+            self.identity_map.insert(key, tracked);
+            results.push(obj);
         }
+
+        Ok(results)
     }
 
-    /// Flush pending changes to the database.
+    /// Load one page of `M` rows matching `predicate`, ordered by primary key.
     ///
-    /// This executes INSERT, UPDATE, and DELETE statements but does NOT commit.
-    pub async fn flush(&mut self, cx: &Cx) -> Outcome<(), Error> {
-        // Fire before_flush event
-        if let Err(e) = self.event_callbacks.fire(SessionEvent::BeforeFlush) {
-            return Outcome::Err(e);
+    /// `page` is 1-based. The page and the total match count are fetched in
+    /// a single query using `COUNT(*) OVER ()`; only when a page past the end
+    /// comes back empty is a separate `COUNT(*)` issued to report the total.
+    /// Loaded objects are tracked in the identity map like
+    /// [`query_as`](Self::query_as) results.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let active = Expr::col("active").eq(true);
+    /// let page = session.paginate::<Hero>(&cx, Some(&active), 2, 20).await?;
+    /// println!("page {}/{} ({} heroes)", page.page, page.total_pages, page.total);
+    /// ```
+    pub async fn paginate<
+        M: Model + Clone + Send + Sync + Serialize + for<'de> Deserialize<'de> + 'static,
+    >(
+        &mut self,
+        cx: &Cx,
+        predicate: Option<&Expr>,
+        page: u64,
+        page_size: u64,
+    ) -> Outcome<Page<M>, Error> {
+        if page == 0 || page_size == 0 {
+            return Outcome::Err(Error::Custom(format!(
+                "paginate: page and page_size must be at least 1 (got page={page}, page_size={page_size})"
+            )));
         }
 
-        // Auto-begin transaction if configured
-        if self.config.auto_begin && !self.in_transaction {
-            match self.begin(cx).await {
-                Outcome::Ok(()) => {}
+        let dialect = self.connection.dialect();
+        let table = dialect.quote_identifier(M::TABLE_NAME);
+        let mut params = Vec::new();
+        let where_clause = predicate.map_or_else(String::new, |pred| {
+            format!(
+                " WHERE {}",
+                pred.build_with_dialect(dialect, &mut params, 0)
+            )
+        });
+        let order_by = M::PRIMARY_KEY
+            .iter()
+            .map(|col| dialect.quote_identifier(col))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let offset = (page - 1).saturating_mul(page_size);
+        let sql = format!(
+            "SELECT *, COUNT(*) OVER () AS {} FROM {table}{where_clause} ORDER BY {order_by}{}",
+            dialect.quote_identifier(PAGINATE_TOTAL_COLUMN),
+            dialect.limit_offset(Some(page_size), Some(offset))
+        );
+
+        match self.check_plan_cost(cx, &sql, &params).await {
+            Outcome::Ok(()) => {}
+            Outcome::Err(e) => return Outcome::Err(e),
+            Outcome::Cancelled(r) => return Outcome::Cancelled(r),
+            Outcome::Panicked(p) => return Outcome::Panicked(p),
+        }
+
+        let rows = match self.connection.query(cx, &sql, &params).await {
+            Outcome::Ok(rows) => rows,
+            Outcome::Err(e) => return Outcome::Err(e),
+            Outcome::Cancelled(r) => return Outcome::Cancelled(r),
+            Outcome::Panicked(p) => return Outcome::Panicked(p),
+        };
+
+        let total = if let Some(row) = rows.first() {
+            match row.get_named::<i64>(PAGINATE_TOTAL_COLUMN) {
+                Ok(n) => u64::try_from(n).unwrap_or(0),
+                Err(e) => return Outcome::Err(e),
+            }
+        } else if page == 1 {
+            0
+        } else {
+            // Past the last page: the window count has no row to ride on.
+            let count_sql = format!("SELECT COUNT(*) FROM {table}{where_clause}");
+            match self.count_query(cx, &count_sql, &params).await {
+                Outcome::Ok(n) => n,
                 Outcome::Err(e) => return Outcome::Err(e),
                 Outcome::Cancelled(r) => return Outcome::Cancelled(r),
                 Outcome::Panicked(p) => return Outcome::Panicked(p),
             }
-        }
+        };
 
-        let dialect = self.connection.dialect();
+        let items = match self.track_loaded_rows::<M>(&rows) {
+            Ok(items) => items,
+            Err(e) => return Outcome::Err(e),
+        };
 
-        // 1. Execute DELETEs first (to respect FK constraints), including explicit cascades.
-        let deletes: Vec<ObjectKey> = std::mem::take(&mut self.pending_delete);
+        Outcome::Ok(Page {
+            items,
+            total,
+            page,
+            page_size,
+            total_pages: total.div_ceil(page_size),
+        })
+    }
 
-        // Cascade planning: use relationship metadata on each deleted parent to proactively
-        // delete dependent rows (and clean up link tables) when `passive_deletes` is not set.
-        //
-        // This is intentionally explicit (no hidden queries): we emit concrete DELETE statements.
-        let mut cascade_child_deletes_single: HashMap<(&'static str, &'static str), Vec<Value>> =
-            HashMap::new();
-        let mut cascade_child_deletes_composite: HashMap<CascadeChildDeleteKey, Vec<Vec<Value>>> =
-            HashMap::new();
-        let mut cascade_link_deletes_single: HashMap<(&'static str, &'static str), Vec<Value>> =
-            HashMap::new();
-        let mut cascade_link_deletes_composite: HashMap<CascadeChildDeleteKey, Vec<Vec<Value>>> =
-            HashMap::new();
-
-        for key in &deletes {
-            let Some(tracked) = self.identity_map.get(key) else {
-                continue;
-            };
-            if tracked.state != ObjectState::Deleted {
-                continue;
-            }
-            let parent_pk_values = tracked.pk_values.clone();
+    /// Check whether any `M` row matches `predicate`.
+    ///
+    /// Issues `SELECT 1 ... LIMIT 1` without hydrating or tracking any
+    /// object. Only flushed state is visible: pending `add()`s and
+    /// `delete()`s are not taken into account.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let taken = Expr::col("email").eq("a@example.com");
+    /// if !session.exists::<User>(&cx, &taken).await? {
+    ///     session.add(&new_user);
+    /// }
+    /// ```
+    pub async fn exists<M: Model + 'static>(
+        &mut self,
+        cx: &Cx,
+        predicate: &Expr,
+    ) -> Outcome<bool, Error> {
+        let dialect = self.connection.dialect();
+        let mut params = Vec::new();
+        let sql = format!(
+            "SELECT 1 FROM {} WHERE {} LIMIT 1",
+            dialect.quote_identifier(M::TABLE_NAME),
+            predicate.build_with_dialect(dialect, &mut params, 0)
+        );
+        self.exists_query(cx, &sql, &params).await
+    }
 
-            for rel in tracked.relationships {
-                if !rel.cascade_delete || rel.is_passive_deletes_all() {
-                    continue;
-                }
+    /// Check whether an `M` row with the given primary key exists.
+    ///
+    /// A tracked new or persistent object counts as existing and a tracked
+    /// object pending deletion as missing, without touching the database;
+    /// otherwise this queries like [`exists`](Self::exists).
+    pub async fn exists_by_pk<M: Model + 'static>(
+        &mut self,
+        cx: &Cx,
+        pk_values: &[Value],
+    ) -> Outcome<bool, Error> {
+        let key = ObjectKey::from_pk::<M>(pk_values);
+        match self.identity_map.get(&key).map(|tracked| tracked.state) {
+            Some(ObjectState::New | ObjectState::Persistent) => return Outcome::Ok(true),
+            Some(ObjectState::Deleted) => return Outcome::Ok(false),
+            Some(ObjectState::Detached | ObjectState::Expired) | None => {}
+        }
 
-                match rel.kind {
-                    sqlmodel_core::RelationshipKind::OneToMany
-                    | sqlmodel_core::RelationshipKind::OneToOne => {
-                        // With passive_deletes, the DB will delete children when the parent is deleted.
-                        // Orphan tracking for Passive is handled after the parent delete succeeds.
-                        if matches!(rel.passive_deletes, sqlmodel_core::PassiveDeletes::Passive) {
-                            continue;
-                        }
-                        let fk_cols = rel.remote_key_cols();
-                        if fk_cols.is_empty() {
-                            continue;
-                        }
-                        if fk_cols.len() == 1 && parent_pk_values.len() == 1 {
-                            cascade_child_deletes_single
-                                .entry((rel.related_table, fk_cols[0]))
-                                .or_default()
-                                .push(parent_pk_values[0].clone());
-                        } else {
-                            // Composite FK: column order must match parent PK value ordering.
-                            if fk_cols.len() != parent_pk_values.len() {
-                                continue;
-                            }
-                            cascade_child_deletes_composite
-                                .entry(CascadeChildDeleteKey {
-                                    table: rel.related_table,
-                                    fk_cols: fk_cols.to_vec(),
-                                })
-                                .or_default()
-                                .push(parent_pk_values.clone());
-                        }
-                    }
-                    sqlmodel_core::RelationshipKind::ManyToMany => {
-                        if matches!(rel.passive_deletes, sqlmodel_core::PassiveDeletes::Passive) {
-                            continue;
-                        }
-                        let Some(link) = rel.link_table else {
-                            continue;
-                        };
-                        let local_cols = link.local_cols();
-                        if local_cols.is_empty() {
-                            continue;
-                        }
-                        if local_cols.len() == 1 && parent_pk_values.len() == 1 {
-                            cascade_link_deletes_single
-                                .entry((link.table_name, local_cols[0]))
-                                .or_default()
-                                .push(parent_pk_values[0].clone());
-                        } else {
-                            if local_cols.len() != parent_pk_values.len() {
-                                continue;
-                            }
-                            cascade_link_deletes_composite
-                                .entry(CascadeChildDeleteKey {
-                                    table: link.table_name,
-                                    fk_cols: local_cols.to_vec(),
-                                })
-                                .or_default()
-                                .push(parent_pk_values.clone());
-                        }
-                    }
-                    sqlmodel_core::RelationshipKind::ManyToOne => {}
+        let pk_columns = M::PRIMARY_KEY;
+        if pk_columns.len() != pk_values.len() {
+            return Outcome::Err(Error::Custom(format!(
+                "Primary key mismatch: expected {} values, got {}",
+                pk_columns.len(),
+                pk_values.len()
+            )));
+        }
+
+        let dialect = self.connection.dialect();
+        let where_parts: Vec<String> = pk_columns
+            .iter()
+            .enumerate()
+            .map(|(i, col)| {
+                format!(
+                    "{} = {}",
+                    dialect.quote_identifier(col),
+                    dialect.placeholder(i + 1)
+                )
+            })
+            .collect();
+        let sql = format!(
+            "SELECT 1 FROM {} WHERE {} LIMIT 1",
+            dialect.quote_identifier(M::TABLE_NAME),
+            where_parts.join(" AND ")
+        );
+        self.exists_query(cx, &sql, pk_values).await
+    }
+
+    /// Run an existence probe built by `exists`/`exists_by_pk`.
+    async fn exists_query(&self, cx: &Cx, sql: &str, params: &[Value]) -> Outcome<bool, Error> {
+        match self.check_plan_cost(cx, sql, params).await {
+            Outcome::Ok(()) => {}
+            Outcome::Err(e) => return Outcome::Err(e),
+            Outcome::Cancelled(r) => return Outcome::Cancelled(r),
+            Outcome::Panicked(p) => return Outcome::Panicked(p),
+        }
+
+        match self.connection.query(cx, sql, params).await {
+            Outcome::Ok(rows) => Outcome::Ok(!rows.is_empty()),
+            Outcome::Err(e) => Outcome::Err(e),
+            Outcome::Cancelled(r) => Outcome::Cancelled(r),
+            Outcome::Panicked(p) => Outcome::Panicked(p),
+        }
+    }
+
+    /// Count the `M` rows matching `predicate` (all rows if `None`).
+    ///
+    /// Issues `SELECT COUNT(*)` without hydrating or tracking any object.
+    /// Like [`exists`](Self::exists), only flushed state is counted.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let active = session.count::<User>(&cx, Some(&Expr::col("active").eq(true))).await?;
+    /// ```
+    pub async fn count<M: Model + 'static>(
+        &mut self,
+        cx: &Cx,
+        predicate: Option<&Expr>,
+    ) -> Outcome<u64, Error> {
+        self.count_expr::<M>(cx, "*", predicate).await
+    }
+
+    /// Count the distinct non-NULL values of `column` among the `M` rows
+    /// matching `predicate`, as `COUNT(DISTINCT column)`.
+    ///
+    /// `column` must name a field of `M`, by column or field name.
+    pub async fn count_distinct<M: Model + 'static>(
+        &mut self,
+        cx: &Cx,
+        column: &str,
+        predicate: Option<&Expr>,
+    ) -> Outcome<u64, Error> {
+        let Some(field) = M::fields()
+            .iter()
+            .find(|f| f.column_name == column || f.name == column)
+        else {
+            return Outcome::Err(Error::Custom(format!(
+                "count_distinct: {} has no column named '{}'",
+                M::TABLE_NAME,
+                column
+            )));
+        };
+        let column = match quote_ident_checked(field.column_name, self.connection.dialect()) {
+            Ok(column) => column,
+            Err(e) => return Outcome::Err(e),
+        };
+        let expr = format!("DISTINCT {column}");
+        self.count_expr::<M>(cx, &expr, predicate).await
+    }
+
+    async fn count_expr<M: Model + 'static>(
+        &self,
+        cx: &Cx,
+        expr: &str,
+        predicate: Option<&Expr>,
+    ) -> Outcome<u64, Error> {
+        let dialect = self.connection.dialect();
+        let mut params = Vec::new();
+        let where_clause = predicate.map_or_else(String::new, |pred| {
+            format!(
+                " WHERE {}",
+                pred.build_with_dialect(dialect, &mut params, 0)
+            )
+        });
+        let sql = format!(
+            "SELECT COUNT({expr}) FROM {}{where_clause}",
+            dialect.quote_identifier(M::TABLE_NAME)
+        );
+
+        match self.check_plan_cost(cx, &sql, &params).await {
+            Outcome::Ok(()) => {}
+            Outcome::Err(e) => return Outcome::Err(e),
+            Outcome::Cancelled(r) => return Outcome::Cancelled(r),
+            Outcome::Panicked(p) => return Outcome::Panicked(p),
+        }
+        self.count_query(cx, &sql, &params).await
+    }
+
+    /// Run a `SELECT COUNT(...)` and read the count from its first column.
+    async fn count_query(&self, cx: &Cx, sql: &str, params: &[Value]) -> Outcome<u64, Error> {
+        match self.connection.query(cx, sql, params).await {
+            Outcome::Ok(rows) => match rows.first().map(|row| row.get_as::<i64>(0)) {
+                Some(Ok(n)) => Outcome::Ok(u64::try_from(n).unwrap_or(0)),
+                Some(Err(e)) => Outcome::Err(e),
+                None => Outcome::Ok(0),
+            },
+            Outcome::Err(e) => Outcome::Err(e),
+            Outcome::Cancelled(r) => Outcome::Cancelled(r),
+            Outcome::Panicked(p) => Outcome::Panicked(p),
+        }
+    }
+
+    /// Delete `obj`'s row immediately and return the row as it was before
+    /// the delete.
+    ///
+    /// Emits `DELETE FROM t WHERE pk = $1 RETURNING *` and decodes the
+    /// returned row, e.g. for an audit log. The object is removed from the
+    /// identity map and any pending insert, update or delete of it is
+    /// dropped. Errors if no row has `obj`'s primary key.
+    ///
+    /// Backends without `DELETE ... RETURNING` (MySQL, SQLite before 3.35) run
+    /// `SELECT ... FOR UPDATE` followed by the `DELETE` instead: one extra round
+    /// trip, made atomic by running both in the session's transaction, which
+    /// is begun first if none is active.
+    pub async fn delete_returning<M: Model + 'static>(
+        &mut self,
+        cx: &Cx,
+        obj: &M,
+    ) -> Outcome<M, Error> {
+        let pk_columns = M::PRIMARY_KEY;
+        let pk_values = obj.primary_key_value();
+        if pk_columns.len() != pk_values.len() {
+            return Outcome::Err(Error::Custom(format!(
+                "Primary key mismatch: expected {} values, got {}",
+                pk_columns.len(),
+                pk_values.len()
+            )));
+        }
+
+        let dialect = self.connection.dialect();
+        let table = dialect.quote_identifier(M::TABLE_NAME);
+        let where_clause = pk_columns
+            .iter()
+            .enumerate()
+            .map(|(i, col)| {
+                format!(
+                    "{} = {}",
+                    dialect.quote_identifier(col),
+                    dialect.placeholder(i + 1)
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(" AND ");
+
+        let rows = if self.connection.capabilities().returning_delete {
+            let sql = format!("DELETE FROM {table} WHERE {where_clause} RETURNING *");
+            match self.connection.query(cx, &sql, &pk_values).await {
+                Outcome::Ok(rows) => rows,
+                Outcome::Err(e) => return Outcome::Err(e),
+                Outcome::Cancelled(r) => return Outcome::Cancelled(r),
+                Outcome::Panicked(p) => return Outcome::Panicked(p),
+            }
+        } else {
+            if !self.in_transaction {
+                match self.begin(cx).await {
+                    Outcome::Ok(()) => {}
+                    Outcome::Err(e) => return Outcome::Err(e),
+                    Outcome::Cancelled(r) => return Outcome::Cancelled(r),
+                    Outcome::Panicked(p) => return Outcome::Panicked(p),
+                }
+            }
+            let select = format!("SELECT * FROM {table} WHERE {where_clause} FOR UPDATE");
+            let rows = match self.connection.query(cx, &select, &pk_values).await {
+                Outcome::Ok(rows) => rows,
+                Outcome::Err(e) => return Outcome::Err(e),
+                Outcome::Cancelled(r) => return Outcome::Cancelled(r),
+                Outcome::Panicked(p) => return Outcome::Panicked(p),
+            };
+            if !rows.is_empty() {
+                let delete = format!("DELETE FROM {table} WHERE {where_clause}");
+                match self.connection.execute(cx, &delete, &pk_values).await {
+                    Outcome::Ok(_) => {}
+                    Outcome::Err(e) => return Outcome::Err(e),
+                    Outcome::Cancelled(r) => return Outcome::Cancelled(r),
+                    Outcome::Panicked(p) => return Outcome::Panicked(p),
                 }
             }
+            rows
+        };
+
+        let Some(row) = rows.first() else {
+            return Outcome::Err(Error::Custom(format!(
+                "delete_returning: no {} row with primary key {:?}",
+                M::TABLE_NAME,
+                pk_values
+            )));
+        };
+        let deleted = match M::from_row(row) {
+            Ok(deleted) => deleted,
+            Err(e) => return Outcome::Err(e),
+        };
+
+        let key = ObjectKey::from_pk::<M>(&pk_values);
+        self.untrack(key);
+        self.pending_new.retain(|k| k != &key);
+        self.pending_delete.retain(|k| k != &key);
+        self.pending_dirty.retain(|k| k != &key);
+
+        Outcome::Ok(deleted)
+    }
+
+    /// Check if an object is tracked by this session.
+    pub fn contains<M: Model + 'static>(&self, obj: &M) -> bool {
+        let key = ObjectKey::from_model(obj);
+        self.identity_map.contains_key(&key)
+    }
+
+    /// Detach an object from the session.
+    pub fn expunge<M: Model + 'static>(&mut self, obj: &M) {
+        let key = ObjectKey::from_model(obj);
+        if let Some(tracked) = self.identity_map.get_mut(&key) {
+            tracked.state = ObjectState::Detached;
+        }
+        self.pending_new.retain(|k| k != &key);
+        self.pending_delete.retain(|k| k != &key);
+        self.pending_dirty.retain(|k| k != &key);
+    }
+
+    /// Detach all objects from the session.
+    pub fn expunge_all(&mut self) {
+        for tracked in self.identity_map.values_mut() {
+            tracked.state = ObjectState::Detached;
+        }
+        self.pending_new.clear();
+        self.pending_delete.clear();
+        self.pending_dirty.clear();
+    }
+
+    // ========================================================================
+    // Dirty Checking
+    // ========================================================================
+
+    /// Check if an object has pending changes.
+    ///
+    /// `obj` is compared as it is now against the state loaded from the
+    /// database, so edits to a copy count even before `mark_dirty()`.
+    ///
+    /// Returns `true` if:
+    /// - Object is new (pending INSERT)
+    /// - Object has been modified since load (pending UPDATE)
+    /// - Object is marked for deletion (pending DELETE)
+    ///
+    /// Returns `false` if:
+    /// - Object is not tracked
+    /// - Object is clean (unchanged since load)
+    /// - Object is detached or expired
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let user = session.get::<User>(1).await?.unwrap();
+    /// assert!(!session.is_modified(&user));  // Fresh from DB
+    ///
+    /// // Modify and re-check
+    /// let mut user_mut = user.clone();
+    /// user_mut.name = "New Name".to_string();
+    /// session.mark_dirty(&user_mut);
+    /// assert!(session.is_modified(&user_mut));  // Now dirty
+    /// ```
+    pub fn is_modified<M: Model + Serialize + 'static>(&self, obj: &M) -> bool {
+        let key = ObjectKey::from_model(obj);
+
+        let Some(tracked) = self.identity_map.get(&key) else {
+            return false;
+        };
+
+        match tracked.state {
+            // New objects are always "modified" (pending INSERT)
+            ObjectState::New => true,
+
+            // Deleted objects are "modified" (pending DELETE)
+            ObjectState::Deleted => true,
+
+            // Detached/expired objects aren't modified in session context
+            ObjectState::Detached | ObjectState::Expired => false,
+
+            // For persistent objects, compare current values to original
+            ObjectState::Persistent => {
+                // Check if explicitly marked dirty
+                if self.pending_dirty.contains(&key) {
+                    return true;
+                }
+
+                tracked
+                    .changed_columns(&obj.to_row())
+                    .is_none_or(|changed| !changed.is_empty())
+            }
+        }
+    }
+
+    /// Get the list of modified attribute names for an object.
+    ///
+    /// Returns the column names whose value in `obj` differs from the state
+    /// loaded from the database, whether or not `mark_dirty()` was called.
+    /// Returns an empty vector if:
+    /// - Object is not tracked
+    /// - Object is new (all fields are "modified")
+    /// - Object is clean (no changes)
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let mut user = session.get::<User>(1).await?.unwrap();
+    /// user.name = "New Name".to_string();
+    /// session.mark_dirty(&user);
+    ///
+    /// let changed = session.modified_attributes(&user);
+    /// assert!(changed.contains(&"name"));
+    /// ```
+    pub fn modified_attributes<M: Model + Serialize + 'static>(
+        &self,
+        obj: &M,
+    ) -> Vec<&'static str> {
+        let key = ObjectKey::from_model(obj);
+
+        let Some(tracked) = self.identity_map.get(&key) else {
+            return Vec::new();
+        };
+
+        // Only meaningful for persistent objects
+        if tracked.state != ObjectState::Persistent {
+            return Vec::new();
+        }
+
+        tracked.changed_columns(&obj.to_row()).unwrap_or_default()
+    }
+
+    /// Get the state of a tracked object.
+    ///
+    /// Returns `None` if the object is not tracked by this session.
+    pub fn object_state<M: Model + 'static>(&self, obj: &M) -> Option<ObjectState> {
+        let key = ObjectKey::from_model(obj);
+        self.identity_map.get(&key).map(|t| t.state)
+    }
+
+    // ========================================================================
+    // Expiration
+    // ========================================================================
+
+    /// Expire an object's cached attributes, forcing reload on next access.
+    ///
+    /// After calling this method, the next `get()` call for this object will reload
+    /// from the database instead of returning the cached version.
+    ///
+    /// # Arguments
+    ///
+    /// * `obj` - The object to expire.
+    /// * `attributes` - Optional list of attribute names to expire. If `None`, all
+    ///   attributes are expired.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// // Expire all attributes
+    /// session.expire(&user, None);
+    ///
+    /// // Expire specific attributes
+    /// session.expire(&user, Some(&["name", "email"]));
+    ///
+    /// // Next get() will reload from database
+    /// let refreshed = session.get::<User>(cx, user.id).await?;
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - Expiring an object does not discard pending changes. If the object has been
+    ///   modified but not flushed, those changes remain pending.
+    /// - Expiring a detached or new object has no effect.
+    #[tracing::instrument(level = "debug", skip(self, obj), fields(table = M::TABLE_NAME))]
+    pub fn expire<M: Model + 'static>(&mut self, obj: &M, attributes: Option<&[&str]>) {
+        let key = ObjectKey::from_model(obj);
+
+        let Some(tracked) = self.identity_map.get_mut(&key) else {
+            tracing::debug!("Object not tracked, nothing to expire");
+            return;
+        };
+
+        // Only expire persistent objects
+        match tracked.state {
+            ObjectState::New | ObjectState::Detached | ObjectState::Deleted => {
+                tracing::debug!(state = ?tracked.state, "Cannot expire object in this state");
+                return;
+            }
+            ObjectState::Persistent | ObjectState::Expired => {}
+        }
+
+        match attributes {
+            None => {
+                // Expire all attributes
+                tracked.state = ObjectState::Expired;
+                tracked.expired_attributes = None;
+                tracing::debug!("Expired all attributes");
+            }
+            Some(attrs) => {
+                // Expire specific attributes
+                let mut expired = tracked.expired_attributes.take().unwrap_or_default();
+                for attr in attrs {
+                    expired.insert((*attr).to_string());
+                }
+                tracked.expired_attributes = Some(expired);
+
+                // If any attributes are expired, mark the object as expired
+                if tracked.state == ObjectState::Persistent {
+                    tracked.state = ObjectState::Expired;
+                }
+                tracing::debug!(attributes = ?attrs, "Expired specific attributes");
+            }
+        }
+    }
+
+    /// Expire all objects in the session.
+    ///
+    /// After calling this method, all tracked objects will be marked as expired.
+    /// The next access to any object will reload from the database.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// // Expire everything in the session
+    /// session.expire_all();
+    ///
+    /// // All subsequent get() calls will reload from database
+    /// let user = session.get::<User>(cx, 1).await?;  // Reloads from DB
+    /// let team = session.get::<Team>(cx, 1).await?;  // Reloads from DB
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - This does not affect new or deleted objects.
+    /// - Pending changes are not discarded.
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub fn expire_all(&mut self) {
+        let mut expired_count = 0;
+        for tracked in self.identity_map.values_mut() {
+            if tracked.state == ObjectState::Persistent {
+                tracked.state = ObjectState::Expired;
+                tracked.expired_attributes = None;
+                expired_count += 1;
+            }
+        }
+        tracing::debug!(count = expired_count, "Expired all session objects");
+    }
+
+    /// Check if an object is expired (needs reload from database).
+    ///
+    /// Returns `true` if the object is marked as expired and will be reloaded
+    /// on the next access.
+    pub fn is_expired<M: Model + 'static>(&self, obj: &M) -> bool {
+        let key = ObjectKey::from_model(obj);
+        self.identity_map
+            .get(&key)
+            .is_some_and(|t| t.state == ObjectState::Expired)
+    }
+
+    /// Get the list of expired attribute names for an object.
+    ///
+    /// Returns:
+    /// - `None` if the object is not tracked or not expired
+    /// - `Some(None)` if all attributes are expired
+    /// - `Some(Some(set))` if only specific attributes are expired
+    pub fn expired_attributes<M: Model + 'static>(
+        &self,
+        obj: &M,
+    ) -> Option<Option<&std::collections::HashSet<String>>> {
+        let key = ObjectKey::from_model(obj);
+        let tracked = self.identity_map.get(&key)?;
+
+        if tracked.state != ObjectState::Expired {
+            return None;
+        }
+
+        Some(tracked.expired_attributes.as_ref())
+    }
+
+    /// Refresh an object by reloading it from the database.
+    ///
+    /// This method immediately reloads the object from the database, updating
+    /// the cached copy in the session. Unlike `expire()`, which defers the reload
+    /// until the next access, `refresh()` performs the reload immediately.
+    ///
+    /// # Arguments
+    ///
+    /// * `cx` - The async context for database operations.
+    /// * `obj` - The object to refresh.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(Some(refreshed))` if the object was found in the database,
+    /// `Ok(None)` if the object no longer exists in the database, or an error.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// // Immediately reload from database
+    /// let refreshed = session.refresh(&cx, &user).await?;
+    ///
+    /// if let Some(user) = refreshed {
+    ///     println!("Refreshed: {}", user.name);
+    /// } else {
+    ///     println!("User was deleted from database");
+    /// }
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - This discards any changes in the session's cached copy.
+    /// - If the object has pending changes, they will be lost.
+    /// - If the object no longer exists in the database, it is removed from the session.
+    #[tracing::instrument(level = "debug", skip(self, cx, obj), fields(table = M::TABLE_NAME))]
+    pub async fn refresh<
+        M: Model + Clone + Send + Sync + Serialize + for<'de> Deserialize<'de> + 'static,
+    >(
+        &mut self,
+        cx: &Cx,
+        obj: &M,
+    ) -> Outcome<Option<M>, Error> {
+        let pk_values = obj.primary_key_value();
+        let key = ObjectKey::from_model(obj);
+
+        tracing::debug!(pk = ?pk_values, "Refreshing object from database");
+
+        // Remove from pending queues since we're reloading
+        self.pending_dirty.retain(|k| k != &key);
+
+        // Remove from identity map to force reload
+        self.identity_map.remove(&key);
+
+        // Reload from database
+        let result = self.get_by_pk::<M>(cx, &pk_values).await;
+
+        match &result {
+            Outcome::Ok(Some(_)) => {
+                tracing::debug!("Object refreshed successfully");
+            }
+            Outcome::Ok(None) => {
+                tracing::debug!("Object no longer exists in database");
+            }
+            _ => {}
+        }
+
+        result
+    }
+
+    // ========================================================================
+    // Transaction Management
+    // ========================================================================
+
+    /// Begin a transaction.
+    ///
+    /// Uses `SessionConfig::transaction_mode`, forced to `READ ONLY` when
+    /// `SessionConfig::read_only` is set.
+    pub async fn begin(&mut self, cx: &Cx) -> Outcome<(), Error> {
+        let mut mode = self.config.transaction_mode;
+        mode.read_only |= self.config.read_only;
+        self.begin_with_mode(cx, mode).await
+    }
+
+    /// Begin a transaction with explicit isolation level and `READ ONLY` /
+    /// `DEFERRABLE` modifiers.
+    ///
+    /// The mode is rendered for the connection's dialect; see
+    /// [`TransactionMode::begin_statements`]. Modifiers the dialect cannot
+    /// express fail with a configuration error before anything is sent.
+    ///
+    /// Does nothing if a transaction is already active.
+    pub async fn begin_with_mode(&mut self, cx: &Cx, mode: TransactionMode) -> Outcome<(), Error> {
+        if self.in_transaction {
+            return Outcome::Ok(());
+        }
+
+        let statements = match mode.begin_statements(self.connection.dialect()) {
+            Ok(statements) => statements,
+            Err(e) => return Outcome::Err(e),
+        };
+        for sql in &statements {
+            match self.connection.execute(cx, sql, &[]).await {
+                Outcome::Ok(_) => {}
+                Outcome::Err(e) => return Outcome::Err(e),
+                Outcome::Cancelled(r) => return Outcome::Cancelled(r),
+                Outcome::Panicked(p) => return Outcome::Panicked(p),
+            }
+        }
+        self.in_transaction = true;
+        self.snapshots = vec![IdentitySnapshot::default()];
+        Outcome::Ok(())
+    }
+
+    /// Defer `DEFERRABLE` foreign key checks to commit for the current
+    /// transaction, beginning one if needed.
+    ///
+    /// Call this before flushing an object graph whose foreign keys form a
+    /// cycle (e.g. a team referencing its captain hero, and the hero
+    /// referencing the team). No insert order satisfies such a graph, but
+    /// with the constraints declared `deferrable` they are only checked when
+    /// the transaction commits.
+    pub async fn defer_constraints(&mut self, cx: &Cx) -> Outcome<(), Error> {
+        if !self.in_transaction {
+            match self.begin(cx).await {
+                Outcome::Ok(()) => {}
+                Outcome::Err(e) => return Outcome::Err(e),
+                Outcome::Cancelled(r) => return Outcome::Cancelled(r),
+                Outcome::Panicked(p) => return Outcome::Panicked(p),
+            }
+        }
+        self.connection.defer_constraints(cx).await
+    }
+
+    /// List the statements `flush()` would execute right now, without running
+    /// anything.
+    ///
+    /// Statements appear in flush order (cascade DELETEs, object DELETEs,
+    /// INSERTs, then UPDATEs) and come from the same plan `flush()` executes.
+    /// Unchanged dirty objects are omitted, just as flush skips them. Side
+    /// effects a real flush has on later statements, such as dropping
+    /// cascaded children from the pending lists, are not simulated.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// for stmt in session.preview_flush() {
+    ///     println!("{}  {:?}", stmt.sql, stmt.params);
+    /// }
+    /// ```
+    pub fn preview_flush(&self) -> Vec<PreviewStatement> {
+        self.plan_flush()
+            .into_iter()
+            .map(|step| step.statement)
+            .collect()
+    }
+
+    /// Plan the statements a flush runs for the pending changes: cascade
+    /// DELETEs, a DELETE for each deleted object with a key, an INSERT for
+    /// each new object, then an UPDATE for each persistent object whose values
+    /// changed.
+    fn plan_flush(&self) -> Vec<FlushStep> {
+        let dialect = self.connection.dialect();
+        let max_params = self.max_params();
+        let mut steps = Vec::new();
+
+        // Cascade planning: use relationship metadata on each deleted parent to proactively
+        // delete dependent rows (and clean up link tables) when `passive_deletes` is not set.
+        let plan = self.plan_cascade_deletes(&self.pending_delete);
+        for ((table, column), pks) in plan.child_single {
+            let statements = build_in_deletes(dialect, table, column, &pks, max_params);
+            let cleanup = CascadeCleanup {
+                table,
+                fk_cols: vec![column],
+                parents: pks.into_iter().map(|pk| vec![pk]).collect(),
+            };
+            push_cascade_steps(&mut steps, table, statements, Some(cleanup));
+        }
+        for (key, tuples) in plan.child_composite {
+            let statements =
+                build_tuple_in_deletes(dialect, key.table, &key.fk_cols, &tuples, max_params);
+            let cleanup = CascadeCleanup {
+                table: key.table,
+                fk_cols: key.fk_cols,
+                parents: tuples,
+            };
+            push_cascade_steps(&mut steps, key.table, statements, Some(cleanup));
+        }
+        // Link-table rows are association rows only; nothing tracked to drop.
+        for ((table, column), pks) in plan.link_single {
+            let statements = build_in_deletes(dialect, table, column, &pks, max_params);
+            push_cascade_steps(&mut steps, table, statements, None);
+        }
+        for (key, tuples) in plan.link_composite {
+            let statements =
+                build_tuple_in_deletes(dialect, key.table, &key.fk_cols, &tuples, max_params);
+            push_cascade_steps(&mut steps, key.table, statements, None);
+        }
+
+        for key in &self.pending_delete {
+            let Some(tracked) = self.identity_map.get(key) else {
+                continue;
+            };
+            // Skip if object was un-deleted (state changed from Deleted)
+            if tracked.state != ObjectState::Deleted {
+                continue;
+            }
+            // Skip objects without primary keys - cannot safely DELETE without WHERE clause
+            if tracked.pk_columns.is_empty() || tracked.pk_values.is_empty() {
+                tracing::warn!(
+                    table = tracked.table_name,
+                    "Skipping DELETE for object without primary key - cannot identify row"
+                );
+                continue;
+            }
+            steps.push(FlushStep {
+                statement: PreviewStatement {
+                    kind: PreviewKind::Delete,
+                    table: tracked.table_name,
+                    sql: build_pk_delete(dialect, tracked),
+                    params: tracked.pk_values.clone(),
+                },
+                target: FlushTarget::Delete(*key),
+            });
+        }
+
+        for key in &self.pending_new {
+            let Some(tracked) = self.identity_map.get(key) else {
+                continue;
+            };
+            // Skip if already persistent (was inserted in a previous attempt before error)
+            if tracked.state == ObjectState::Persistent {
+                continue;
+            }
+            // Leave out a primary key the database still has to generate.
+            let generated_column = self.pending_pks.get(key).map(|p| p.column);
+            let (sql, params) = build_insert(dialect, tracked, generated_column);
+            steps.push(FlushStep {
+                statement: PreviewStatement {
+                    kind: PreviewKind::Insert,
+                    table: tracked.table_name,
+                    sql,
+                    params,
+                },
+                target: FlushTarget::Insert(*key),
+            });
+        }
+
+        for key in &self.pending_dirty {
+            let Some(tracked) = self.identity_map.get(key) else {
+                continue;
+            };
+            // Only UPDATE persistent objects
+            if tracked.state != ObjectState::Persistent {
+                continue;
+            }
+            // Skip objects without primary keys - cannot safely UPDATE without WHERE clause
+            if tracked.pk_columns.is_empty() || tracked.pk_values.is_empty() {
+                tracing::warn!(
+                    table = tracked.table_name,
+                    "Skipping UPDATE for object without primary key - cannot identify row"
+                );
+                continue;
+            }
+            // Check if actually dirty by comparing serialized state
+            let current_state = serde_json::to_vec(&tracked.values).unwrap_or_default();
+            if tracked.original_state.as_ref() == Some(&current_state) {
+                continue;
+            }
+            let Some((sql, params)) = build_update(dialect, tracked) else {
+                continue; // No non-PK columns to update
+            };
+            steps.push(FlushStep {
+                statement: PreviewStatement {
+                    kind: PreviewKind::Update,
+                    table: tracked.table_name,
+                    sql,
+                    params,
+                },
+                target: FlushTarget::Update(*key),
+            });
+        }
+
+        steps
+    }
+
+    /// Run one planned flush statement and the bookkeeping that follows it.
+    ///
+    /// Returns the rows the database reported affected (an INSERT returning a
+    /// generated key counts as one), or `None` when an earlier statement
+    /// already removed the object, e.g. a cascaded child.
+    async fn run_flush_step(
+        &mut self,
+        cx: &Cx,
+        step: FlushStep,
+        detect_stale: bool,
+    ) -> Outcome<Option<u64>, Error> {
+        let FlushStep { statement, target } = step;
+        let PreviewStatement {
+            table, sql, params, ..
+        } = statement;
+
+        match target {
+            FlushTarget::Cascade(cleanup) => {
+                match execute_flush_statement(
+                    &self.connection,
+                    cx,
+                    &sql,
+                    &params,
+                    self.flush_timings.as_mut(),
+                )
+                .await
+                {
+                    Outcome::Ok(affected) => {
+                        if let Some(cleanup) = cleanup {
+                            self.untrack_cascaded(&cleanup);
+                        }
+                        Outcome::Ok(Some(affected))
+                    }
+                    Outcome::Err(e) => Outcome::Err(e),
+                    Outcome::Cancelled(r) => Outcome::Cancelled(r),
+                    Outcome::Panicked(p) => Outcome::Panicked(p),
+                }
+            }
+            FlushTarget::Delete(key) => {
+                let Some(tracked) = self.identity_map.get(&key) else {
+                    return Outcome::Ok(None);
+                };
+                let relationships = tracked.relationships;
+
+                let outcome = match execute_flush_statement(
+                    &self.connection,
+                    cx,
+                    &sql,
+                    &params,
+                    self.flush_timings.as_mut(),
+                )
+                .await
+                {
+                    Outcome::Ok(affected) => {
+                        check_row_affected(affected, detect_stale, "DELETE", table, &params)
+                    }
+                    other => other,
+                };
+                let affected = match outcome {
+                    Outcome::Ok(affected) => affected,
+                    Outcome::Err(e) => return Outcome::Err(e),
+                    Outcome::Cancelled(r) => return Outcome::Cancelled(r),
+                    Outcome::Panicked(p) => return Outcome::Panicked(p),
+                };
+
+                // PassiveDeletes::Passive orphan tracking: the DB will delete children,
+                // so eagerly detach them from the identity map after the parent delete succeeds.
+                let mut to_remove: Vec<ObjectKey> = Vec::new();
+                for rel in relationships {
+                    if !rel.cascade_delete
+                        || !matches!(rel.passive_deletes, sqlmodel_core::PassiveDeletes::Passive)
+                    {
+                        continue;
+                    }
+                    if !matches!(
+                        rel.kind,
+                        sqlmodel_core::RelationshipKind::OneToMany
+                            | sqlmodel_core::RelationshipKind::OneToOne
+                    ) {
+                        continue;
+                    }
+
+                    let fk_cols = rel.remote_key_cols();
+                    if fk_cols.is_empty() || fk_cols.len() != params.len() {
+                        continue;
+                    }
+
+                    for (k, t) in &self.identity_map {
+                        if t.table_name != rel.related_table {
+                            continue;
+                        }
+                        let matches_parent = fk_cols.iter().zip(&params).all(|(fk_col, parent)| {
+                            t.column_names
+                                .iter()
+                                .position(|col| col == fk_col)
+                                .is_some_and(|idx| &t.values[idx] == parent)
+                        });
+                        if matches_parent {
+                            to_remove.push(*k);
+                        }
+                    }
+                }
+                to_remove.push(key);
+                self.untrack_all(&to_remove);
+                Outcome::Ok(Some(affected))
+            }
+            FlushTarget::Insert(key) => {
+                self.touch(key);
+                let pending_pk = self.pending_pks.get(&key).copied();
+                let Some(tracked) = self.identity_map.get_mut(&key) else {
+                    return Outcome::Ok(None);
+                };
+
+                let outcome = if pending_pk.is_some() {
+                    insert_flush_statement(
+                        &self.connection,
+                        cx,
+                        &sql,
+                        &params,
+                        self.flush_timings.as_mut(),
+                    )
+                    .await
+                    .map(|id| (1, Some(id)))
+                } else {
+                    execute_flush_statement(
+                        &self.connection,
+                        cx,
+                        &sql,
+                        &params,
+                        self.flush_timings.as_mut(),
+                    )
+                    .await
+                    .map(|affected| (affected, None))
+                };
+                let (affected, generated_id) = match outcome {
+                    Outcome::Ok(result) => result,
+                    Outcome::Err(e) => return Outcome::Err(e),
+                    Outcome::Cancelled(r) => return Outcome::Cancelled(r),
+                    Outcome::Panicked(p) => return Outcome::Panicked(p),
+                };
+
+                let mut rekey = None;
+                if let (Some(pending), Some(id)) = (pending_pk, generated_id) {
+                    // Backfill the generated id and move the object to its real key.
+                    let id = Value::BigInt(id);
+                    if let Some(idx) = tracked
+                        .column_names
+                        .iter()
+                        .position(|c| *c == pending.column)
+                    {
+                        tracked.values[idx] = id.clone();
+                    }
+                    tracked.pk_values = vec![id];
+                    if let Some(object) = (pending.rebuild)(&tracked.column_names, &tracked.values)
+                    {
+                        tracked.object = object;
+                    }
+                    let real_key = ObjectKey {
+                        type_id: key.type_id,
+                        pk_hash: hash_values(&tracked.pk_values),
+                    };
+                    rekey = Some((real_key, pending));
+                }
+                tracked.state = ObjectState::Persistent;
+                // Set original_state for future dirty checking (serialize current values)
+                tracked.original_state =
+                    Some(serde_json::to_vec(&tracked.values).unwrap_or_default());
+
+                if let Some((real_key, pending)) = rekey {
+                    self.pending_pks.remove(&key);
+                    if let Some(tracked) = self.identity_map.remove(&key) {
+                        self.identity_map.insert(real_key, tracked);
+                    }
+                    if let Some(snapshot) = self.snapshots.last_mut() {
+                        snapshot.rekeyed.push((key, real_key, pending));
+                    }
+                    for pending in &mut self.pending_dirty {
+                        if *pending == key {
+                            *pending = real_key;
+                        }
+                    }
+                }
+                Outcome::Ok(Some(affected))
+            }
+            FlushTarget::Update(key) => {
+                self.touch(key);
+                let Some(tracked) = self.identity_map.get_mut(&key) else {
+                    return Outcome::Ok(None);
+                };
+                let current_state = serde_json::to_vec(&tracked.values).unwrap_or_default();
+
+                let outcome = match execute_flush_statement(
+                    &self.connection,
+                    cx,
+                    &sql,
+                    &params,
+                    self.flush_timings.as_mut(),
+                )
+                .await
+                {
+                    Outcome::Ok(affected) => check_row_affected(
+                        affected,
+                        detect_stale || tracked.version.is_some(),
+                        "UPDATE",
+                        table,
+                        &tracked.pk_values,
+                    ),
+                    other => other,
+                };
+                match outcome {
+                    Outcome::Ok(affected) => {
+                        // Update original_state to current state
+                        tracked.original_state = Some(current_state);
+                        if bump_version(tracked) {
+                            tracked.original_state =
+                                Some(serde_json::to_vec(&tracked.values).unwrap_or_default());
+                        }
+                        Outcome::Ok(Some(affected))
+                    }
+                    Outcome::Err(e) => Outcome::Err(e),
+                    Outcome::Cancelled(r) => Outcome::Cancelled(r),
+                    Outcome::Panicked(p) => Outcome::Panicked(p),
+                }
+            }
+        }
+    }
+
+    /// Drop tracked rows whose foreign key columns hold the key of a parent
+    /// whose dependents were just deleted by a cascade, preventing stale reads.
+    fn untrack_cascaded(&mut self, cleanup: &CascadeCleanup) {
+        let parent_hashes: std::collections::HashSet<u64> =
+            cleanup.parents.iter().map(|p| hash_values(p)).collect();
+        let to_remove: Vec<ObjectKey> = self
+            .identity_map
+            .iter()
+            .filter(|(_, t)| t.table_name == cleanup.table)
+            .filter_map(|(k, t)| {
+                let fk: Option<Vec<Value>> = cleanup
+                    .fk_cols
+                    .iter()
+                    .map(|fk_col| {
+                        let idx = t.column_names.iter().position(|col| col == fk_col)?;
+                        Some(t.values[idx].clone())
+                    })
+                    .collect();
+                fk.filter(|fk| parent_hashes.contains(&hash_values(fk)))
+                    .map(|_| *k)
+            })
+            .collect();
+        self.untrack_all(&to_remove);
+    }
+
+    /// Untrack `keys` and drop them from every pending list.
+    fn untrack_all(&mut self, keys: &[ObjectKey]) {
+        if keys.is_empty() {
+            return;
+        }
+        for k in keys {
+            self.untrack(*k);
+        }
+        self.pending_new.retain(|k| !keys.contains(k));
+        self.pending_dirty.retain(|k| !keys.contains(k));
+        self.pending_delete.retain(|k| !keys.contains(k));
+    }
+
+    /// Plan the explicit cascade DELETEs for a set of pending parent deletes.
+    fn plan_cascade_deletes(&self, deletes: &[ObjectKey]) -> CascadeDeletePlan {
+        // This is intentionally explicit (no hidden queries): we emit concrete DELETE statements.
+        let mut plan = CascadeDeletePlan::default();
+
+        for key in deletes {
+            let Some(tracked) = self.identity_map.get(key) else {
+                continue;
+            };
+            if tracked.state != ObjectState::Deleted {
+                continue;
+            }
+            let parent_pk_values = tracked.pk_values.clone();
+
+            for rel in tracked.relationships {
+                if !rel.cascade_delete || rel.is_passive_deletes_all() {
+                    continue;
+                }
+
+                match rel.kind {
+                    sqlmodel_core::RelationshipKind::OneToMany
+                    | sqlmodel_core::RelationshipKind::OneToOne => {
+                        // With passive_deletes, the DB will delete children when the parent is deleted.
+                        // Orphan tracking for Passive is handled after the parent delete succeeds.
+                        if matches!(rel.passive_deletes, sqlmodel_core::PassiveDeletes::Passive) {
+                            continue;
+                        }
+                        let fk_cols = rel.remote_key_cols();
+                        if fk_cols.is_empty() {
+                            continue;
+                        }
+                        if fk_cols.len() == 1 && parent_pk_values.len() == 1 {
+                            plan.child_single
+                                .entry((rel.related_table, fk_cols[0]))
+                                .or_default()
+                                .push(parent_pk_values[0].clone());
+                        } else {
+                            // Composite FK: column order must match parent PK value ordering.
+                            if fk_cols.len() != parent_pk_values.len() {
+                                continue;
+                            }
+                            plan.child_composite
+                                .entry(CascadeChildDeleteKey {
+                                    table: rel.related_table,
+                                    fk_cols: fk_cols.to_vec(),
+                                })
+                                .or_default()
+                                .push(parent_pk_values.clone());
+                        }
+                    }
+                    sqlmodel_core::RelationshipKind::ManyToMany => {
+                        if matches!(rel.passive_deletes, sqlmodel_core::PassiveDeletes::Passive) {
+                            continue;
+                        }
+                        let Some(link) = rel.link_table else {
+                            continue;
+                        };
+                        let local_cols = link.local_cols();
+                        if local_cols.is_empty() {
+                            continue;
+                        }
+                        if local_cols.len() == 1 && parent_pk_values.len() == 1 {
+                            plan.link_single
+                                .entry((link.table_name, local_cols[0]))
+                                .or_default()
+                                .push(parent_pk_values[0].clone());
+                        } else {
+                            if local_cols.len() != parent_pk_values.len() {
+                                continue;
+                            }
+                            plan.link_composite
+                                .entry(CascadeChildDeleteKey {
+                                    table: link.table_name,
+                                    fk_cols: local_cols.to_vec(),
+                                })
+                                .or_default()
+                                .push(parent_pk_values.clone());
+                        }
+                    }
+                    sqlmodel_core::RelationshipKind::ManyToOne => {}
+                }
+            }
+        }
+
+        // Deduplicate parent keys so each child row is targeted once.
+        for pks in plan
+            .child_single
+            .values_mut()
+            .chain(plan.link_single.values_mut())
+        {
+            let mut seen: std::collections::HashSet<u64> = std::collections::HashSet::new();
+            pks.retain(|v| seen.insert(hash_values(std::slice::from_ref(v))));
+        }
+        for tuples in plan
+            .child_composite
+            .values_mut()
+            .chain(plan.link_composite.values_mut())
+        {
+            let mut seen: std::collections::HashSet<u64> = std::collections::HashSet::new();
+            tuples.retain(|t| seen.insert(hash_values(t)));
+        }
+
+        plan
+    }
+
+    /// Flush pending changes to the database.
+    ///
+    /// This executes INSERT, UPDATE, and DELETE statements but does NOT commit.
+    ///
+    /// If the connection reports a failed transaction, the transaction is
+    /// rolled back (discarding pending changes) and an
+    /// [`TransactionErrorKind::Aborted`] error is returned.
+    pub async fn flush(&mut self, cx: &Cx) -> Outcome<(), Error> {
+        // Fire before_flush event
+        if let Err(e) = self.event_callbacks.fire(SessionEvent::BeforeFlush) {
+            return Outcome::Err(e);
+        }
+
+        // Every statement in an aborted transaction would fail; roll it back
+        // and report the abort instead of firing them.
+        if self.connection.transaction_status() == TransactionStatus::Failed {
+            if self.external_transaction {
+                return Outcome::Err(Error::Transaction(TransactionError {
+                    kind: TransactionErrorKind::Aborted,
+                    message: "external transaction was aborted by an earlier error; \
+                              its owner must roll it back"
+                        .to_string(),
+                }));
+            }
+            // The server holds an open (failed) block even if it wasn't begun
+            // through this session.
+            self.in_transaction = true;
+            match self.rollback(cx).await {
+                Outcome::Ok(()) => {}
+                Outcome::Err(e) => return Outcome::Err(e),
+                Outcome::Cancelled(r) => return Outcome::Cancelled(r),
+                Outcome::Panicked(p) => return Outcome::Panicked(p),
+            }
+            return Outcome::Err(Error::Transaction(TransactionError {
+                kind: TransactionErrorKind::Aborted,
+                message: "transaction was aborted by an earlier error and has been rolled back; \
+                          pending changes were discarded"
+                    .to_string(),
+            }));
+        }
+
+        self.flush_timings = self.config.collect_timings.then(Vec::new);
+        if !(self.pending_delete.is_empty()
+            && self.pending_new.is_empty()
+            && self.pending_dirty.is_empty())
+        {
+            self.report_phase("Flushing");
+        }
+
+        // Auto-begin transaction if configured
+        if self.config.auto_begin && !self.in_transaction {
+            match self.begin(cx).await {
+                Outcome::Ok(()) => {}
+                Outcome::Err(e) => return Outcome::Err(e),
+                Outcome::Cancelled(r) => return Outcome::Cancelled(r),
+                Outcome::Panicked(p) => return Outcome::Panicked(p),
+            }
+        }
+
+        let detect_stale = self.config.detect_stale;
+
+        // DELETEs run first (to respect FK constraints), including explicit
+        // cascades, then INSERTs, then UPDATEs. The pending lists stay in
+        // place until every statement has run, so a failed flush can be
+        // retried: objects already written are skipped by the next plan.
+        let steps = self.plan_flush();
+        let mut progress_total = steps.len() as u64;
+        let mut progress_completed: u64 = 0;
+
+        for step in steps {
+            let table = step.statement.table;
+            let affected = match self.run_flush_step(cx, step, detect_stale).await {
+                Outcome::Ok(Some(affected)) => affected,
+                Outcome::Ok(None) => {
+                    progress_total -= 1;
+                    continue;
+                }
+                Outcome::Err(e) => return Outcome::Err(e),
+                Outcome::Cancelled(r) => return Outcome::Cancelled(r),
+                Outcome::Panicked(p) => return Outcome::Panicked(p),
+            };
+            progress_completed += 1;
+            report_batch_progress(
+                &mut self.batch_progress,
+                table,
+                affected,
+                progress_completed,
+                progress_total,
+            );
+        }
+
+        // Whatever is still pending needed no statement: unchanged, already
+        // inserted, un-deleted or without a primary key.
+        let settled: Vec<ObjectKey> = self
+            .pending_new
+            .iter()
+            .chain(&self.pending_dirty)
+            .copied()
+            .collect();
+        for key in settled {
+            self.touch(key);
+        }
+        self.pending_delete.clear();
+        self.pending_new.clear();
+        self.pending_dirty.clear();
+
+        // Fire after_flush event
+        if let Err(e) = self.event_callbacks.fire(SessionEvent::AfterFlush) {
+            return Outcome::Err(e);
+        }
+
+        Outcome::Ok(())
+    }
+
+    /// Commit the current transaction.
+    ///
+    /// Inside an attached external transaction this only flushes; the owner
+    /// commits.
+    pub async fn commit(&mut self, cx: &Cx) -> Outcome<(), Error> {
+        // Flush any pending changes first
+        match self.flush(cx).await {
+            Outcome::Ok(()) => {}
+            Outcome::Err(e) => return Outcome::Err(e),
+            Outcome::Cancelled(r) => return Outcome::Cancelled(r),
+            Outcome::Panicked(p) => return Outcome::Panicked(p),
+        }
+
+        // Fire before_commit event (can abort)
+        if let Err(e) = self.event_callbacks.fire(SessionEvent::BeforeCommit) {
+            return Outcome::Err(e);
+        }
+
+        if self.in_transaction && !self.external_transaction {
+            self.report_phase("Committing");
+            match self.connection.execute(cx, "COMMIT", &[]).await {
+                Outcome::Ok(_) => {
+                    self.in_transaction = false;
+                    self.savepoints.clear();
+                    self.snapshots.clear();
+                }
+                Outcome::Err(e) => return Outcome::Err(e),
+                Outcome::Cancelled(r) => return Outcome::Cancelled(r),
+                Outcome::Panicked(p) => return Outcome::Panicked(p),
+            }
+        }
+
+        // Expire objects if configured (nothing was committed in an external
+        // transaction); otherwise the committed values are the new baseline.
+        if !self.external_transaction {
+            for tracked in self.identity_map.values_mut() {
+                if tracked.state != ObjectState::Persistent {
+                    continue;
+                }
+                if self.config.expire_on_commit {
+                    tracked.state = ObjectState::Expired;
+                } else {
+                    tracked.original_state = serde_json::to_vec(&tracked.values).ok();
+                }
+            }
+        }
+
+        // Fire after_commit event
+        if let Err(e) = self.event_callbacks.fire(SessionEvent::AfterCommit) {
+            return Outcome::Err(e);
+        }
+
+        Outcome::Ok(())
+    }
+
+    /// Rollback the current transaction.
+    ///
+    /// Objects touched during the transaction get back the state they had
+    /// when it began (see [`Session::rollback_to_savepoint`]); then pending
+    /// operations are discarded, new objects are dropped and deleted ones
+    /// are tracked again.
+    ///
+    /// Fails with [`TransactionErrorKind::External`] inside an attached
+    /// external transaction.
+    pub async fn rollback(&mut self, cx: &Cx) -> Outcome<(), Error> {
+        if self.external_transaction {
+            return Outcome::Err(Error::Transaction(TransactionError {
+                kind: TransactionErrorKind::External,
+                message: "transaction is managed outside the session; roll it back through \
+                          its owner"
+                    .to_string(),
+            }));
+        }
+        if self.in_transaction {
+            match self.connection.execute(cx, "ROLLBACK", &[]).await {
+                Outcome::Ok(_) => {
+                    self.in_transaction = false;
+                    self.savepoints.clear();
+                    while let Some(snapshot) = self.snapshots.pop() {
+                        self.restore_snapshot(snapshot);
+                    }
+                }
+                Outcome::Err(e) => return Outcome::Err(e),
+                Outcome::Cancelled(r) => return Outcome::Cancelled(r),
+                Outcome::Panicked(p) => return Outcome::Panicked(p),
+            }
+        }
+
+        // Clear pending operations
+        self.pending_new.clear();
+        self.pending_delete.clear();
+        self.pending_dirty.clear();
+
+        // Revert objects to original state or remove new ones
+        let mut to_remove = Vec::new();
+        for (key, tracked) in &mut self.identity_map {
+            match tracked.state {
+                ObjectState::New => {
+                    to_remove.push(*key);
+                }
+                ObjectState::Deleted => {
+                    tracked.state = ObjectState::Persistent;
+                }
+                _ => {}
+            }
+        }
+
+        for key in to_remove {
+            self.identity_map.remove(&key);
+            self.pending_pks.remove(&key);
+        }
+
+        // Fire after_rollback event
+        if let Err(e) = self.event_callbacks.fire(SessionEvent::AfterRollback) {
+            return Outcome::Err(e);
+        }
+
+        Outcome::Ok(())
+    }
+
+    // ========================================================================
+    // Savepoints
+    // ========================================================================
+
+    /// Create a savepoint inside the current transaction and return its name.
+    ///
+    /// Names are generated from a per-session monotonic counter (`sp_1`,
+    /// `sp_2`, ...) so they never collide, even after earlier savepoints have
+    /// been released or rolled back. Begins a transaction first if none is
+    /// active.
+    pub async fn savepoint(&mut self, cx: &Cx) -> Outcome<String, Error> {
+        if !self.in_transaction {
+            match self.begin(cx).await {
+                Outcome::Ok(()) => {}
+                Outcome::Err(e) => return Outcome::Err(e),
+                Outcome::Cancelled(r) => return Outcome::Cancelled(r),
+                Outcome::Panicked(p) => return Outcome::Panicked(p),
+            }
+        }
+
+        let name = self.next_savepoint_name();
+        let sql = format!("SAVEPOINT {name}");
+        match self.connection.execute(cx, &sql, &[]).await {
+            Outcome::Ok(_) => {
+                self.savepoints.push(name.clone());
+                self.snapshots.push(IdentitySnapshot::default());
+                Outcome::Ok(name)
+            }
+            Outcome::Err(e) => Outcome::Err(e),
+            Outcome::Cancelled(r) => Outcome::Cancelled(r),
+            Outcome::Panicked(p) => Outcome::Panicked(p),
+        }
+    }
+
+    /// Release a savepoint, keeping its changes in the enclosing transaction.
+    ///
+    /// Savepoints nested inside `name` are released along with it.
+    pub async fn release_savepoint(&mut self, cx: &Cx, name: &str) -> Outcome<(), Error> {
+        self.end_savepoint(cx, name, false).await
+    }
+
+    /// Roll back to a savepoint, discarding changes made since it was created.
+    ///
+    /// Savepoints nested inside `name` are popped from the session's
+    /// savepoint stack; `name` itself stays active, so it can be rolled back
+    /// to again or released. Tracked objects touched since the savepoint get back
+    /// the state and original state they had then: objects added since are
+    /// dropped, objects deleted since are tracked again, and pending
+    /// operations are rescheduled to match. In-memory field values are kept,
+    /// so an object whose UPDATE was rolled back shows as modified again.
+    pub async fn rollback_to_savepoint(&mut self, cx: &Cx, name: &str) -> Outcome<(), Error> {
+        self.end_savepoint(cx, name, true).await
+    }
+
+    /// Run `f` inside a savepoint, for partial rollback within a larger transaction.
+    ///
+    /// The savepoint is released if `f` returns `Ok` and rolled back to
+    /// otherwise. If `f` panics, the rollback is issued before the panic
+    /// resumes. Calls nest: `f` may call `nested` again on the session it is
+    /// given. Begins a transaction first if none is active.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// session
+    ///     .nested(&cx, async |s| {
+    ///         s.add(&audit_entry);
+    ///         s.flush(&cx).await
+    ///     })
+    ///     .await?;
+    /// ```
+    pub async fn nested<T, F>(&mut self, cx: &Cx, f: F) -> Outcome<T, Error>
+    where
+        F: AsyncFnOnce(&mut Self) -> Outcome<T, Error>,
+    {
+        let name = match self.savepoint(cx).await {
+            Outcome::Ok(name) => name,
+            Outcome::Err(e) => return Outcome::Err(e),
+            Outcome::Cancelled(r) => return Outcome::Cancelled(r),
+            Outcome::Panicked(p) => return Outcome::Panicked(p),
+        };
+
+        let result = {
+            let mut fut = std::pin::pin!(f(&mut *self));
+            std::future::poll_fn(|task_cx| {
+                match std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                    fut.as_mut().poll(task_cx)
+                })) {
+                    Ok(std::task::Poll::Ready(out)) => std::task::Poll::Ready(Ok(out)),
+                    Ok(std::task::Poll::Pending) => std::task::Poll::Pending,
+                    Err(payload) => std::task::Poll::Ready(Err(payload)),
+                }
+            })
+            .await
+        };
+
+        match result {
+            Ok(Outcome::Ok(value)) => match self.release_savepoint(cx, &name).await {
+                Outcome::Ok(()) => Outcome::Ok(value),
+                Outcome::Err(e) => Outcome::Err(e),
+                Outcome::Cancelled(r) => Outcome::Cancelled(r),
+                Outcome::Panicked(p) => Outcome::Panicked(p),
+            },
+            Ok(other) => {
+                // The closure's outcome wins over a failed rollback.
+                self.discard_savepoint(cx, &name).await;
+                other
+            }
+            Err(payload) => {
+                self.discard_savepoint(cx, &name).await;
+                std::panic::resume_unwind(payload)
+            }
+        }
+    }
+
+    /// Roll back to a savepoint and release it, ignoring failures.
+    async fn discard_savepoint(&mut self, cx: &Cx, name: &str) {
+        if let Outcome::Ok(()) = self.rollback_to_savepoint(cx, name).await {
+            let _ = self.release_savepoint(cx, name).await;
+        }
+    }
+
+    /// Active savepoint names, outermost first.
+    pub fn savepoints(&self) -> &[String] {
+        &self.savepoints
+    }
+
+    /// Record `key`'s state in the innermost rollback snapshot before it changes.
+    fn touch(&mut self, key: ObjectKey) {
+        if let Some(snapshot) = self.snapshots.last_mut() {
+            snapshot.touch(&self.identity_map, key);
+        }
+    }
+
+    /// Remove `key` from the identity map, keeping the object for rollback
+    /// if it was tracked when the innermost snapshot began.
+    fn untrack(&mut self, key: ObjectKey) {
+        self.touch(key);
+        let Some(tracked) = self.identity_map.remove(&key) else {
+            return;
+        };
+        if let Some(snapshot) = self
+            .snapshots
+            .last_mut()
+            .filter(|snapshot| snapshot.was_tracked(&key))
+        {
+            snapshot.removed.entry(key).or_insert(tracked);
+        }
+    }
+
+    /// Put every object touched since `snapshot` began back the way it was,
+    /// rescheduling its pending INSERT, DELETE or UPDATE to match.
+    fn restore_snapshot(&mut self, snapshot: IdentitySnapshot) {
+        let IdentitySnapshot {
+            touched,
+            mut saved,
+            mut removed,
+            rekeyed,
+        } = snapshot;
+
+        for (temporary, real, pending) in rekeyed.into_iter().rev() {
+            if let Some(tracked) = self.identity_map.remove(&real) {
+                self.identity_map.insert(temporary, tracked);
+                self.pending_pks.insert(temporary, pending);
+            }
+        }
+
+        for key in touched {
+            self.pending_new.retain(|k| k != &key);
+            self.pending_delete.retain(|k| k != &key);
+            self.pending_dirty.retain(|k| k != &key);
+
+            let Some((state, original_state)) = saved.remove(&key).flatten() else {
+                self.identity_map.remove(&key);
+                self.pending_pks.remove(&key);
+                continue;
+            };
+            let Some(mut tracked) = self
+                .identity_map
+                .remove(&key)
+                .or_else(|| removed.remove(&key))
+            else {
+                continue;
+            };
+            let modified = original_state.as_ref()
+                != Some(&serde_json::to_vec(&tracked.values).unwrap_or_default());
+            tracked.state = state;
+            tracked.original_state = original_state;
+            match state {
+                ObjectState::New => self.pending_new.push(key),
+                ObjectState::Deleted => self.pending_delete.push(key),
+                ObjectState::Persistent if modified => self.pending_dirty.push(key),
+                _ => {}
+            }
+            self.identity_map.insert(key, tracked);
+        }
+    }
+
+    fn next_savepoint_name(&mut self) -> String {
+        self.next_savepoint_id += 1;
+        format!("sp_{}", self.next_savepoint_id)
+    }
+
+    async fn end_savepoint(&mut self, cx: &Cx, name: &str, rollback: bool) -> Outcome<(), Error> {
+        let Some(pos) = self.savepoints.iter().position(|sp| sp == name) else {
+            return Outcome::Err(Error::Transaction(TransactionError {
+                kind: TransactionErrorKind::SavepointNotFound,
+                message: format!("no active savepoint named '{name}'"),
+            }));
+        };
+
+        let command = if rollback {
+            "ROLLBACK TO SAVEPOINT"
+        } else {
+            "RELEASE SAVEPOINT"
+        };
+        let sql = format!("{command} {name}");
+        match self.connection.execute(cx, &sql, &[]).await {
+            Outcome::Ok(_) => {
+                let first = self
+                    .snapshots
+                    .len()
+                    .saturating_sub(self.savepoints.len() - pos);
+                let ended = self.snapshots.split_off(first);
+                if rollback {
+                    // The savepoint itself survives ROLLBACK TO; only the
+                    // ones nested inside it are gone.
+                    self.savepoints.truncate(pos + 1);
+                    for snapshot in ended.into_iter().rev() {
+                        self.restore_snapshot(snapshot);
+                    }
+                    self.snapshots.push(IdentitySnapshot::default());
+                } else {
+                    self.savepoints.truncate(pos);
+                    if let Some(outer) = self.snapshots.last_mut() {
+                        // Outermost first, so the oldest saved state wins.
+                        for snapshot in ended {
+                            outer.absorb(snapshot);
+                        }
+                    }
+                }
+                Outcome::Ok(())
+            }
+            Outcome::Err(e) => Outcome::Err(e),
+            Outcome::Cancelled(r) => Outcome::Cancelled(r),
+            Outcome::Panicked(p) => Outcome::Panicked(p),
+        }
+    }
+
+    // ========================================================================
+    // Lazy Loading
+    // ========================================================================
+
+    /// Load a single lazy relationship.
+    ///
+    /// Fetches the related object from the database and caches it in the Lazy wrapper.
+    /// If the relationship has already been loaded, returns the cached value.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// session.load_lazy(&hero.team, &cx).await?;
+    /// let team = hero.team.get(); // Now available
+    /// ```
+    #[tracing::instrument(level = "debug", skip(self, lazy, cx))]
+    pub async fn load_lazy<
+        T: Model + Clone + Send + Sync + Serialize + for<'de> Deserialize<'de> + 'static,
+    >(
+        &mut self,
+        lazy: &Lazy<T>,
+        cx: &Cx,
+    ) -> Outcome<bool, Error> {
+        tracing::debug!(
+            model = std::any::type_name::<T>(),
+            fk = ?lazy.fk(),
+            already_loaded = lazy.is_loaded(),
+            "Loading lazy relationship"
+        );
+
+        // If already loaded, return success
+        if lazy.is_loaded() {
+            tracing::trace!("Already loaded");
+            return Outcome::Ok(lazy.get().is_some());
+        }
+
+        // If no FK, set as empty and return
+        let Some(fk) = lazy.fk() else {
+            let _ = lazy.set_loaded(None);
+            return Outcome::Ok(false);
+        };
+
+        // Fetch from database using get()
+        let obj = match self.get::<T>(cx, fk.clone()).await {
+            Outcome::Ok(obj) => obj,
+            Outcome::Err(e) => return Outcome::Err(e),
+            Outcome::Cancelled(r) => return Outcome::Cancelled(r),
+            Outcome::Panicked(p) => return Outcome::Panicked(p),
+        };
+
+        let found = obj.is_some();
+
+        // Cache the result
+        let _ = lazy.set_loaded(obj);
+
+        tracing::debug!(found = found, "Lazy load complete");
+
+        Outcome::Ok(found)
+    }
+
+    /// Batch load lazy relationships for multiple objects.
+    ///
+    /// This method collects all FK values, executes a single query, and populates
+    /// each Lazy field. This prevents the N+1 query problem when iterating over
+    /// a collection and accessing lazy relationships.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// // Load 100 heroes
+    /// let mut heroes = session.query::<Hero>().all().await?;
+    ///
+    /// // Without batch loading: 100 queries (N+1 problem)
+    /// // With batch loading: 1 query
+    /// session.load_many(&cx, &mut heroes, |h| &h.team).await?;
+    ///
+    /// // All teams now loaded
+    /// for hero in &heroes {
+    ///     if let Some(team) = hero.team.get() {
+    ///         println!("{} is on {}", hero.name, team.name);
+    ///     }
+    /// }
+    /// ```
+    #[tracing::instrument(level = "debug", skip(self, cx, objects, accessor))]
+    pub async fn load_many<P, T, F>(
+        &mut self,
+        cx: &Cx,
+        objects: &[P],
+        accessor: F,
+    ) -> Outcome<usize, Error>
+    where
+        P: Model + 'static,
+        T: Model + Clone + Send + Sync + Serialize + for<'de> Deserialize<'de> + 'static,
+        F: Fn(&P) -> &Lazy<T>,
+    {
+        // Collect all FK values that need loading
+        let mut fk_values: Vec<Value> = Vec::new();
+        let mut fk_indices: Vec<usize> = Vec::new();
+
+        for (idx, obj) in objects.iter().enumerate() {
+            let lazy = accessor(obj);
+            if !lazy.is_loaded() && !lazy.is_empty() {
+                if let Some(fk) = lazy.fk() {
+                    fk_values.push(fk.clone());
+                    fk_indices.push(idx);
+                }
+            }
+        }
+
+        let fk_count = fk_values.len();
+        tracing::info!(
+            parent_model = std::any::type_name::<P>(),
+            related_model = std::any::type_name::<T>(),
+            parent_count = objects.len(),
+            fk_count = fk_count,
+            "Batch loading lazy relationships"
+        );
+
+        if fk_values.is_empty() {
+            // Nothing to load - mark all empty/loaded Lazy fields
+            for obj in objects {
+                let lazy = accessor(obj);
+                if !lazy.is_loaded() && lazy.is_empty() {
+                    let _ = lazy.set_loaded(None);
+                }
+            }
+            return Outcome::Ok(0);
+        }
+
+        // Build query with IN clause (dialect-correct placeholders/quoting).
+        let dialect = self.connection.dialect();
+        let pk_col = T::PRIMARY_KEY.first().unwrap_or(&"id");
+        let table = dialect.quote_identifier(T::TABLE_NAME);
+        let mut rows = Vec::new();
+        for chunk in fk_values.chunks(self.max_params()) {
+            let (in_sql, params) = dialect.in_clause(pk_col, chunk, 1);
+            let sql = format!("SELECT * FROM {table} WHERE {in_sql}");
+            match self.connection.query(cx, &sql, &params).await {
+                Outcome::Ok(chunk_rows) => rows.extend(chunk_rows),
+                Outcome::Err(e) => return Outcome::Err(e),
+                Outcome::Cancelled(r) => return Outcome::Cancelled(r),
+                Outcome::Panicked(p) => return Outcome::Panicked(p),
+            }
+        }
+
+        // Convert rows to objects and build PK hash -> object lookup
+        let mut lookup: HashMap<u64, T> = HashMap::new();
+        for row in &rows {
+            match T::from_row(row) {
+                Ok(obj) => {
+                    let pk_values = obj.primary_key_value();
+                    let pk_hash = hash_values(&pk_values);
+
+                    // Add to session identity map
+                    let key = ObjectKey::from_pk::<T>(&pk_values);
+
+                    // Extract column data from the model while we have the concrete type
+                    let row_data = obj.to_row();
+                    let column_names: Vec<&'static str> =
+                        row_data.iter().map(|(name, _)| *name).collect();
+                    let values: Vec<Value> = row_data.into_iter().map(|(_, v)| v).collect();
+
+                    // Serialize values for dirty checking (must match format used in flush)
+                    let serialized = serde_json::to_vec(&values).ok();
+
+                    let tracked = TrackedObject {
+                        object: Box::new(obj.clone()),
+                        original_state: serialized,
+                        state: ObjectState::Persistent,
+                        table_name: T::TABLE_NAME,
+                        column_names,
+                        values,
+                        pk_columns: T::PRIMARY_KEY.to_vec(),
+                        pk_values: pk_values.clone(),
+                        relationships: T::RELATIONSHIPS,
+                        version: VersionColumn::of::<T>(),
+                        expired_attributes: None,
+                    };
+                    self.identity_map.insert(key, tracked);
+
+                    // Add to lookup
+                    lookup.insert(pk_hash, obj);
+                }
+                Err(_) => continue,
+            }
+        }
+
+        // Populate each Lazy field
+        let mut loaded_count = 0;
+        for obj in objects {
+            let lazy = accessor(obj);
+            if !lazy.is_loaded() {
+                if let Some(fk) = lazy.fk() {
+                    let fk_hash = hash_values(std::slice::from_ref(fk));
+                    let related = lookup.get(&fk_hash).cloned();
+                    let found = related.is_some();
+                    let _ = lazy.set_loaded(related);
+                    if found {
+                        loaded_count += 1;
+                    }
+                } else {
+                    let _ = lazy.set_loaded(None);
+                }
+            }
+        }
+
+        tracing::debug!(
+            query_count = 1,
+            loaded_count = loaded_count,
+            "Batch load complete"
+        );
+
+        Outcome::Ok(loaded_count)
+    }
+
+    /// Batch load many-to-many relationships for multiple parent objects.
+    ///
+    /// This method loads related objects via a link table in a single query,
+    /// avoiding the N+1 problem for many-to-many relationships.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// // Load 100 heroes
+    /// let mut heroes = session.query::<Hero>().all().await?;
+    ///
+    /// // Without batch loading: 100 queries (N+1 problem)
+    /// // With batch loading: 1 query via JOIN
+    /// let link_info = LinkTableInfo::new("hero_powers", "hero_id", "power_id");
+    /// session.load_many_to_many(&cx, &mut heroes, |h| &mut h.powers, |h| h.id.unwrap(), &link_info).await?;
+    ///
+    /// // All powers now loaded
+    /// for hero in &heroes {
+    ///     if let Some(powers) = hero.powers.get() {
+    ///         println!("{} has {} powers", hero.name, powers.len());
+    ///     }
+    /// }
+    /// ```
+    #[tracing::instrument(level = "debug", skip(self, cx, objects, accessor, parent_pk))]
+    pub async fn load_many_to_many<P, Child, FA, FP>(
+        &mut self,
+        cx: &Cx,
+        objects: &mut [P],
+        accessor: FA,
+        parent_pk: FP,
+        link_table: &sqlmodel_core::LinkTableInfo,
+    ) -> Outcome<usize, Error>
+    where
+        P: Model + 'static,
+        Child: Model + Clone + Send + Sync + Serialize + for<'de> Deserialize<'de> + 'static,
+        FA: Fn(&mut P) -> &mut sqlmodel_core::RelatedMany<Child>,
+        FP: Fn(&P) -> Value,
+    {
+        self.load_many_to_many_pk(cx, objects, accessor, |p| vec![parent_pk(p)], link_table)
+            .await
+    }
+
+    /// Batch load many-to-many relationships for multiple parent objects using composite keys.
+    ///
+    /// This is the generalized form of `load_many_to_many` that supports composite parent and/or
+    /// child primary keys via `LinkTableInfo::composite(...)`.
+    #[tracing::instrument(level = "debug", skip(self, cx, objects, accessor, parent_pk))]
+    pub async fn load_many_to_many_pk<P, Child, FA, FP>(
+        &mut self,
+        cx: &Cx,
+        objects: &mut [P],
+        accessor: FA,
+        parent_pk: FP,
+        link_table: &sqlmodel_core::LinkTableInfo,
+    ) -> Outcome<usize, Error>
+    where
+        P: Model + 'static,
+        Child: Model + Clone + Send + Sync + Serialize + for<'de> Deserialize<'de> + 'static,
+        FA: Fn(&mut P) -> &mut sqlmodel_core::RelatedMany<Child>,
+        FP: Fn(&P) -> Vec<Value>,
+    {
+        // Collect all parent PK tuples.
+        let mut pk_tuples: Vec<Vec<Value>> = Vec::with_capacity(objects.len());
+        let mut pk_by_index: Vec<(usize, Vec<Value>)> = Vec::new();
+        for (idx, obj) in objects.iter().enumerate() {
+            let pk = parent_pk(obj);
+            pk_tuples.push(pk.clone());
+            pk_by_index.push((idx, pk));
+        }
+
+        tracing::info!(
+            parent_model = std::any::type_name::<P>(),
+            related_model = std::any::type_name::<Child>(),
+            parent_count = pk_tuples.len(),
+            link_table = link_table.table_name,
+            "Batch loading many-to-many relationships"
+        );
+
+        if pk_tuples.is_empty() {
+            return Outcome::Ok(0);
+        }
+
+        // Build query with JOIN through link table (dialect-correct placeholders/quoting):
+        // SELECT child.*, link.<local_cols...> as __parent_pk{N}
+        // FROM child
+        // JOIN link ON child.<pk_cols...> = link.<remote_cols...>
+        // WHERE link.<local_cols...> IN (...)
+        let dialect = self.connection.dialect();
+        let local_cols = link_table.local_cols();
+        let remote_cols = link_table.remote_cols();
+        if local_cols.is_empty() || remote_cols.is_empty() {
+            return Outcome::Err(Error::Custom(
+                "link_table must specify local/remote columns".to_string(),
+            ));
+        }
+        if remote_cols.len() != Child::PRIMARY_KEY.len() {
+            return Outcome::Err(Error::Custom(format!(
+                "link_table remote cols count ({}) must match child PRIMARY_KEY len ({})",
+                remote_cols.len(),
+                Child::PRIMARY_KEY.len()
+            )));
+        }
+
+        let child_table = dialect.quote_identifier(Child::TABLE_NAME);
+        let link_table_q = dialect.quote_identifier(link_table.table_name);
+
+        let parent_select_parts: String = local_cols
+            .iter()
+            .enumerate()
+            .map(|(i, col)| {
+                format!(
+                    "{link_table_q}.{} AS __parent_pk{}",
+                    dialect.quote_identifier(col),
+                    i
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        let join_parts: String = remote_cols
+            .iter()
+            .zip(Child::PRIMARY_KEY.iter().copied())
+            .map(|(link_col, child_col)| {
+                format!(
+                    "{child_table}.{} = {link_table_q}.{}",
+                    dialect.quote_identifier(child_col),
+                    dialect.quote_identifier(link_col)
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(" AND ");
+
+        // Split the IN list so each statement stays under the parameter limit.
+        let chunk_len = (self.max_params() / local_cols.len()).max(1);
+        let mut rows = Vec::new();
+        for chunk in pk_tuples.chunks(chunk_len) {
+            let (where_sql, params) = if local_cols.len() == 1 {
+                let mut params: Vec<Value> = Vec::with_capacity(chunk.len());
+                for t in chunk {
+                    if let Some(v) = t.first() {
+                        params.push(v.clone());
+                    }
+                }
+                let (in_sql, params) = dialect.in_clause(local_cols[0], &params, 1);
+                (format!("{link_table_q}.{in_sql}"), params)
+            } else {
+                let mut tuples: Vec<Vec<Value>> = Vec::with_capacity(chunk.len());
+                for t in chunk {
+                    if t.len() == local_cols.len() {
+                        tuples.push(t.clone());
+                    }
+                }
+
+                let mut params: Vec<Value> = Vec::with_capacity(tuples.len() * local_cols.len());
+                let mut idx = 1;
+                let tuple_sql: Vec<String> = tuples
+                    .iter()
+                    .map(|t| {
+                        for v in t {
+                            params.push(v.clone());
+                        }
+                        let inner = (0..local_cols.len())
+                            .map(|_| {
+                                let ph = dialect.placeholder(idx);
+                                idx += 1;
+                                ph
+                            })
+                            .collect::<Vec<_>>()
+                            .join(", ");
+                        format!("({})", inner)
+                    })
+                    .collect();
+
+                let col_list = local_cols
+                    .iter()
+                    .map(|c| format!("{link_table_q}.{}", dialect.quote_identifier(c)))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                let where_sql = format!("({}) IN ({})", col_list, tuple_sql.join(", "));
+                (where_sql, params)
+            };
+
+            let sql = format!(
+                "SELECT {child_table}.*, {parent_select_parts} FROM {child_table} \
+                 JOIN {link_table_q} ON {join_parts} \
+                 WHERE {where_sql}"
+            );
+
+            tracing::trace!(sql = %sql, "Many-to-many batch SQL");
+
+            match self.connection.query(cx, &sql, &params).await {
+                Outcome::Ok(chunk_rows) => rows.extend(chunk_rows),
+                Outcome::Err(e) => return Outcome::Err(e),
+                Outcome::Cancelled(r) => return Outcome::Cancelled(r),
+                Outcome::Panicked(p) => return Outcome::Panicked(p),
+            }
+        }
+
+        // Group children by parent PK
+        let mut by_parent: HashMap<u64, Vec<Child>> = HashMap::new();
+        for row in &rows {
+            // Extract the parent PK tuple from the __parent_pk{N} aliases.
+            let mut parent_tuple: Vec<Value> = Vec::with_capacity(local_cols.len());
+            let mut missing = false;
+            for i in 0..local_cols.len() {
+                let col = format!("__parent_pk{}", i);
+                let Some(v) = row.get_by_name(&col) else {
+                    missing = true;
+                    break;
+                };
+                parent_tuple.push(v.clone());
+            }
+            if missing {
+                continue;
+            }
+            let parent_pk_hash = hash_values(&parent_tuple);
+
+            // Parse the child model
+            match Child::from_row(row) {
+                Ok(child) => {
+                    by_parent.entry(parent_pk_hash).or_default().push(child);
+                }
+                Err(_) => continue,
+            }
+        }
+
+        // Populate each RelatedMany field
+        let mut loaded_count = 0;
+        for (idx, pk_tuple) in pk_by_index {
+            let pk_hash = hash_values(&pk_tuple);
+            // Don't `remove()` here: callers might pass the same parent more than once.
+            let children = by_parent.get(&pk_hash).cloned().unwrap_or_default();
+            let child_count = children.len();
+
+            let related = accessor(&mut objects[idx]);
+            if pk_tuple.len() == 1 {
+                related.set_parent_pk(pk_tuple[0].clone());
+            } else {
+                related.set_parent_pk(Value::Array(pk_tuple.clone()));
+            }
+            let _ = related.set_loaded(children);
+            loaded_count += child_count;
+        }
+
+        tracing::debug!(
+            query_count = 1,
+            total_children = loaded_count,
+            "Many-to-many batch load complete"
+        );
+
+        Outcome::Ok(loaded_count)
+    }
+
+    /// Batch load one-to-many relationships for multiple parent objects.
+    ///
+    /// This populates `RelatedMany<Child>` where the child table has a foreign key column pointing
+    /// back to the parent. It runs a single query:
+    ///
+    /// `SELECT *, <fk_col> AS __parent_pk FROM <child_table> WHERE <fk_col> IN (...)`
+    ///
+    /// and then groups results per parent PK to populate each `RelatedMany`.
+    #[tracing::instrument(level = "debug", skip(self, cx, objects, accessor, parent_pk))]
+    pub async fn load_one_to_many<P, Child, FA, FP>(
+        &mut self,
+        cx: &Cx,
+        objects: &mut [P],
+        accessor: FA,
+        parent_pk: FP,
+    ) -> Outcome<usize, Error>
+    where
+        P: Model + 'static,
+        Child: Model + Clone + Send + Sync + Serialize + for<'de> Deserialize<'de> + 'static,
+        FA: Fn(&mut P) -> &mut sqlmodel_core::RelatedMany<Child>,
+        FP: Fn(&P) -> Value,
+    {
+        // Collect parent PKs for objects that still need loading.
+        let mut pks: Vec<Value> = Vec::new();
+        let mut pk_by_index: Vec<(usize, Value)> = Vec::new();
+        for (idx, obj) in objects.iter_mut().enumerate() {
+            let pk = parent_pk(&*obj);
+            let related = accessor(obj);
+            if related.is_loaded() {
+                continue;
+            }
+
+            related.set_parent_pk(pk.clone());
+
+            if pk.is_null() {
+                // Unsaved parent: empty collection, mark loaded.
+                let _ = related.set_loaded(Vec::new());
+                continue;
+            }
+
+            pks.push(pk.clone());
+            pk_by_index.push((idx, pk));
+        }
+
+        tracing::info!(
+            parent_model = std::any::type_name::<P>(),
+            related_model = std::any::type_name::<Child>(),
+            parent_count = objects.len(),
+            query_parent_count = pks.len(),
+            "Batch loading one-to-many relationships"
+        );
+
+        if pks.is_empty() {
+            return Outcome::Ok(0);
+        }
+
+        // Use the FK column from the RelatedMany field on the first object.
+        let fk_column = accessor(&mut objects[pk_by_index[0].0]).fk_column();
+        let dialect = self.connection.dialect();
+        let child_table = dialect.quote_identifier(Child::TABLE_NAME);
+        let fk_q = dialect.quote_identifier(fk_column);
+        let mut rows = Vec::new();
+        for chunk in pks.chunks(self.max_params()) {
+            let (in_sql, params) = dialect.in_clause(fk_column, chunk, 1);
+            let sql = format!("SELECT *, {fk_q} AS __parent_pk FROM {child_table} WHERE {in_sql}");
+
+            tracing::trace!(sql = %sql, "One-to-many batch SQL");
+
+            match self.connection.query(cx, &sql, &params).await {
+                Outcome::Ok(chunk_rows) => rows.extend(chunk_rows),
+                Outcome::Err(e) => return Outcome::Err(e),
+                Outcome::Cancelled(r) => return Outcome::Cancelled(r),
+                Outcome::Panicked(p) => return Outcome::Panicked(p),
+            }
+        }
+
+        // Group by parent PK
+        let mut by_parent: HashMap<u64, Vec<Child>> = HashMap::new();
+        for row in &rows {
+            let parent_pk_value: Value = match row.get_by_name("__parent_pk") {
+                Some(v) => v.clone(),
+                None => continue,
+            };
+            let parent_pk_hash = hash_values(std::slice::from_ref(&parent_pk_value));
+            match Child::from_row(row) {
+                Ok(child) => {
+                    // Add to session identity map so later `get()` calls can reuse loaded instances.
+                    let pk_values = child.primary_key_value();
+                    let key = ObjectKey::from_pk::<Child>(&pk_values);
+
+                    self.identity_map.entry(key).or_insert_with(|| {
+                        // Extract column data from the model while we have the concrete type
+                        let row_data = child.to_row();
+                        let column_names: Vec<&'static str> =
+                            row_data.iter().map(|(name, _)| *name).collect();
+                        let values: Vec<Value> = row_data.into_iter().map(|(_, v)| v).collect();
+
+                        // Serialize values for dirty checking (must match format used in flush)
+                        let serialized = serde_json::to_vec(&values).ok();
+
+                        TrackedObject {
+                            object: Box::new(child.clone()),
+                            original_state: serialized,
+                            state: ObjectState::Persistent,
+                            table_name: Child::TABLE_NAME,
+                            column_names,
+                            values,
+                            pk_columns: Child::PRIMARY_KEY.to_vec(),
+                            pk_values: pk_values.clone(),
+                            relationships: Child::RELATIONSHIPS,
+                            version: VersionColumn::of::<Child>(),
+                            expired_attributes: None,
+                        }
+                    });
+
+                    by_parent.entry(parent_pk_hash).or_default().push(child);
+                }
+                Err(_) => continue,
+            }
+        }
+
+        // Populate each RelatedMany.
+        let mut loaded_count = 0;
+        for (idx, pk) in pk_by_index {
+            let pk_hash = hash_values(std::slice::from_ref(&pk));
+            // Don't `remove()` here: callers might pass the same parent more than once.
+            let children = by_parent.get(&pk_hash).cloned().unwrap_or_default();
+            loaded_count += children.len();
+
+            let related = accessor(&mut objects[idx]);
+            let _ = related.set_loaded(children);
+        }
+
+        Outcome::Ok(loaded_count)
+    }
+
+    /// Flush pending link/unlink operations for many-to-many relationships.
+    ///
+    /// This method persists pending link and unlink operations that were tracked
+    /// via `RelatedMany::link()` and `RelatedMany::unlink()` calls.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// // Add a power to a hero
+    /// hero.powers.link(&fly_power);
+    ///
+    /// // Remove a power from a hero
+    /// hero.powers.unlink(&x_ray_vision);
+    ///
+    /// // Flush the link table operations
+    /// let link_info = LinkTableInfo::new("hero_powers", "hero_id", "power_id");
+    /// session.flush_related_many(&cx, &mut [hero], |h| &mut h.powers, |h| h.id.unwrap(), &link_info).await?;
+    /// ```
+    #[tracing::instrument(level = "debug", skip(self, cx, objects, accessor, parent_pk))]
+    pub async fn flush_related_many<P, Child, FA, FP>(
+        &mut self,
+        cx: &Cx,
+        objects: &mut [P],
+        accessor: FA,
+        parent_pk: FP,
+        link_table: &sqlmodel_core::LinkTableInfo,
+    ) -> Outcome<usize, Error>
+    where
+        P: Model + 'static,
+        Child: Model + 'static,
+        FA: Fn(&mut P) -> &mut sqlmodel_core::RelatedMany<Child>,
+        FP: Fn(&P) -> Value,
+    {
+        self.flush_related_many_pk(cx, objects, accessor, |p| vec![parent_pk(p)], link_table)
+            .await
+    }
+
+    /// Flush pending link/unlink operations for many-to-many relationships (composite keys).
+    #[tracing::instrument(level = "debug", skip(self, cx, objects, accessor, parent_pk))]
+    pub async fn flush_related_many_pk<P, Child, FA, FP>(
+        &mut self,
+        cx: &Cx,
+        objects: &mut [P],
+        accessor: FA,
+        parent_pk: FP,
+        link_table: &sqlmodel_core::LinkTableInfo,
+    ) -> Outcome<usize, Error>
+    where
+        P: Model + 'static,
+        Child: Model + 'static,
+        FA: Fn(&mut P) -> &mut sqlmodel_core::RelatedMany<Child>,
+        FP: Fn(&P) -> Vec<Value>,
+    {
+        let mut ops = Vec::new();
+        let local_cols = link_table.local_cols();
+        let remote_cols = link_table.remote_cols();
+        if local_cols.is_empty() || remote_cols.is_empty() {
+            return Outcome::Err(Error::Custom(
+                "link_table must specify local/remote columns".to_string(),
+            ));
+        }
+
+        // Collect pending operations from all objects
+        for obj in objects.iter_mut() {
+            let parent_pk_values = parent_pk(obj);
+            if parent_pk_values.len() != local_cols.len() {
+                return Outcome::Err(Error::Custom(format!(
+                    "parent_pk len ({}) must match link_table local cols len ({})",
+                    parent_pk_values.len(),
+                    local_cols.len()
+                )));
+            }
+            let related = accessor(obj);
+
+            // Collect pending links
+            for child_pk_values in related.take_pending_links() {
+                if child_pk_values.len() != remote_cols.len() {
+                    return Outcome::Err(Error::Custom(format!(
+                        "child pk len ({}) must match link_table remote cols len ({})",
+                        child_pk_values.len(),
+                        remote_cols.len()
+                    )));
+                }
+                ops.push(LinkTableOp::link_multi(
+                    link_table.table_name.to_string(),
+                    local_cols.iter().map(|c| (*c).to_string()).collect(),
+                    parent_pk_values.clone(),
+                    remote_cols.iter().map(|c| (*c).to_string()).collect(),
+                    child_pk_values,
+                ));
+            }
+
+            // Collect pending unlinks
+            for child_pk_values in related.take_pending_unlinks() {
+                if child_pk_values.len() != remote_cols.len() {
+                    return Outcome::Err(Error::Custom(format!(
+                        "child pk len ({}) must match link_table remote cols len ({})",
+                        child_pk_values.len(),
+                        remote_cols.len()
+                    )));
+                }
+                ops.push(LinkTableOp::unlink_multi(
+                    link_table.table_name.to_string(),
+                    local_cols.iter().map(|c| (*c).to_string()).collect(),
+                    parent_pk_values.clone(),
+                    remote_cols.iter().map(|c| (*c).to_string()).collect(),
+                    child_pk_values,
+                ));
+            }
+        }
+
+        if ops.is_empty() {
+            return Outcome::Ok(0);
+        }
+
+        tracing::info!(
+            parent_model = std::any::type_name::<P>(),
+            related_model = std::any::type_name::<Child>(),
+            link_count = ops
+                .iter()
+                .filter(|o| matches!(o, LinkTableOp::Link { .. }))
+                .count(),
+            unlink_count = ops
+                .iter()
+                .filter(|o| matches!(o, LinkTableOp::Unlink { .. }))
+                .count(),
+            link_table = link_table.table_name,
+            "Flushing many-to-many relationship changes"
+        );
+
+        flush::execute_link_table_ops(cx, &self.connection, &ops).await
+    }
+
+    // ========================================================================
+    // Bidirectional Relationship Sync (back_populates)
+    // ========================================================================
+
+    /// Relate a child to a parent with bidirectional sync.
+    ///
+    /// Sets the parent on the child (ManyToOne side) and adds the child to the
+    /// parent's collection (OneToMany side) if `back_populates` is defined.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// // Hero has a ManyToOne relationship to Team (hero.team)
+    /// // Team has a OneToMany relationship to Hero (team.heroes) with back_populates
+    ///
+    /// session.relate_to_one(
+    ///     &mut hero,
+    ///     |h| &mut h.team,
+    ///     |h| h.team_id = team.id,  // Set FK
+    ///     &mut team,
+    ///     |t| &mut t.heroes,
+    /// );
+    /// // Now hero.team is set AND team.heroes includes hero
+    /// ```
+    pub fn relate_to_one<Child, Parent, FC, FP, FK>(
+        &self,
+        child: &mut Child,
+        child_accessor: FC,
+        set_fk: FK,
+        parent: &mut Parent,
+        parent_accessor: FP,
+    ) where
+        Child: Model + Clone + 'static,
+        Parent: Model + Clone + 'static,
+        FC: FnOnce(&mut Child) -> &mut sqlmodel_core::Related<Parent>,
+        FP: FnOnce(&mut Parent) -> &mut sqlmodel_core::RelatedMany<Child>,
+        FK: FnOnce(&mut Child),
+    {
+        // Set the forward direction: child.parent = Related::loaded(parent)
+        let related = child_accessor(child);
+        let _ = related.set_loaded(Some(parent.clone()));
+
+        // Set the FK value
+        set_fk(child);
+
+        // Set the reverse direction: parent.children.link(child)
+        let related_many = parent_accessor(parent);
+        related_many.link(child);
+
+        tracing::debug!(
+            child_model = std::any::type_name::<Child>(),
+            parent_model = std::any::type_name::<Parent>(),
+            "Established bidirectional ManyToOne <-> OneToMany relationship"
+        );
+    }
+
+    /// Unrelate a child from a parent with bidirectional sync.
+    ///
+    /// Clears the parent on the child and removes the child from the parent's collection.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// session.unrelate_from_one(
+    ///     &mut hero,
+    ///     |h| &mut h.team,
+    ///     |h| h.team_id = None,  // Clear FK
+    ///     &mut team,
+    ///     |t| &mut t.heroes,
+    /// );
+    /// ```
+    pub fn unrelate_from_one<Child, Parent, FC, FP, FK>(
+        &self,
+        child: &mut Child,
+        child_accessor: FC,
+        clear_fk: FK,
+        parent: &mut Parent,
+        parent_accessor: FP,
+    ) where
+        Child: Model + Clone + 'static,
+        Parent: Model + Clone + 'static,
+        FC: FnOnce(&mut Child) -> &mut sqlmodel_core::Related<Parent>,
+        FP: FnOnce(&mut Parent) -> &mut sqlmodel_core::RelatedMany<Child>,
+        FK: FnOnce(&mut Child),
+    {
+        // Clear the forward direction by assigning an empty Related
+        let related = child_accessor(child);
+        *related = sqlmodel_core::Related::empty();
+
+        // Clear the FK value
+        clear_fk(child);
+
+        // Remove from the reverse direction
+        let related_many = parent_accessor(parent);
+        related_many.unlink(child);
+
+        tracing::debug!(
+            child_model = std::any::type_name::<Child>(),
+            parent_model = std::any::type_name::<Parent>(),
+            "Removed bidirectional ManyToOne <-> OneToMany relationship"
+        );
+    }
+
+    /// Relate two objects in a many-to-many relationship with bidirectional sync.
+    ///
+    /// Adds each object to the other's collection.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// // Hero has ManyToMany to Power via hero_powers link table
+    /// // Power has ManyToMany to Hero via hero_powers link table (back_populates)
+    ///
+    /// session.relate_many_to_many(
+    ///     &mut hero,
+    ///     |h| &mut h.powers,
+    ///     &mut power,
+    ///     |p| &mut p.heroes,
+    /// );
+    /// // Now hero.powers includes power AND power.heroes includes hero
+    /// ```
+    pub fn relate_many_to_many<Left, Right, FL, FR>(
+        &self,
+        left: &mut Left,
+        left_accessor: FL,
+        right: &mut Right,
+        right_accessor: FR,
+    ) where
+        Left: Model + Clone + 'static,
+        Right: Model + Clone + 'static,
+        FL: FnOnce(&mut Left) -> &mut sqlmodel_core::RelatedMany<Right>,
+        FR: FnOnce(&mut Right) -> &mut sqlmodel_core::RelatedMany<Left>,
+    {
+        // Add right to left's collection
+        let left_coll = left_accessor(left);
+        left_coll.link(right);
+
+        // Add left to right's collection (back_populates)
+        let right_coll = right_accessor(right);
+        right_coll.link(left);
+
+        tracing::debug!(
+            left_model = std::any::type_name::<Left>(),
+            right_model = std::any::type_name::<Right>(),
+            "Established bidirectional ManyToMany relationship"
+        );
+    }
+
+    /// Unrelate two objects in a many-to-many relationship with bidirectional sync.
+    ///
+    /// Removes each object from the other's collection.
+    pub fn unrelate_many_to_many<Left, Right, FL, FR>(
+        &self,
+        left: &mut Left,
+        left_accessor: FL,
+        right: &mut Right,
+        right_accessor: FR,
+    ) where
+        Left: Model + Clone + 'static,
+        Right: Model + Clone + 'static,
+        FL: FnOnce(&mut Left) -> &mut sqlmodel_core::RelatedMany<Right>,
+        FR: FnOnce(&mut Right) -> &mut sqlmodel_core::RelatedMany<Left>,
+    {
+        // Remove right from left's collection
+        let left_coll = left_accessor(left);
+        left_coll.unlink(right);
+
+        // Remove left from right's collection (back_populates)
+        let right_coll = right_accessor(right);
+        right_coll.unlink(left);
+
+        tracing::debug!(
+            left_model = std::any::type_name::<Left>(),
+            right_model = std::any::type_name::<Right>(),
+            "Removed bidirectional ManyToMany relationship"
+        );
+    }
+
+    // ========================================================================
+    // N+1 Query Detection
+    // ========================================================================
+
+    /// Enable N+1 query detection with the specified threshold.
+    ///
+    /// When the number of lazy loads for a single relationship reaches the
+    /// threshold, a warning is emitted suggesting batch loading.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// session.enable_n1_detection(3);  // Warn after 3 lazy loads
+    ///
+    /// // This will trigger a warning:
+    /// for hero in &mut heroes {
+    ///     hero.team.load(&mut session).await?;
+    /// }
+    ///
+    /// // Check stats
+    /// if let Some(stats) = session.n1_stats() {
+    ///     println!("Potential N+1 issues: {}", stats.potential_n1);
+    /// }
+    /// ```
+    pub fn enable_n1_detection(&mut self, threshold: usize) {
+        self.n1_tracker = Some(N1QueryTracker::new().with_threshold(threshold));
+    }
+
+    /// Disable N+1 query detection and clear the tracker.
+    pub fn disable_n1_detection(&mut self) {
+        self.n1_tracker = None;
+    }
+
+    /// Check if N+1 detection is enabled.
+    #[must_use]
+    pub fn n1_detection_enabled(&self) -> bool {
+        self.n1_tracker.is_some()
+    }
+
+    /// Get mutable access to the N+1 tracker (for recording loads).
+    pub fn n1_tracker_mut(&mut self) -> Option<&mut N1QueryTracker> {
+        self.n1_tracker.as_mut()
+    }
+
+    /// Get N+1 detection statistics.
+    #[must_use]
+    pub fn n1_stats(&self) -> Option<N1Stats> {
+        self.n1_tracker.as_ref().map(|t| t.stats())
+    }
+
+    /// Reset N+1 detection counts (call at start of new request/transaction).
+    pub fn reset_n1_tracking(&mut self) {
+        if let Some(tracker) = &mut self.n1_tracker {
+            tracker.reset();
+        }
+    }
+
+    /// Record a lazy load for N+1 detection.
+    ///
+    /// This is called automatically by lazy loading methods.
+    #[track_caller]
+    pub fn record_lazy_load(&mut self, parent_type: &'static str, relationship: &'static str) {
+        if let Some(tracker) = &mut self.n1_tracker {
+            tracker.record_load(parent_type, relationship);
+        }
+    }
+
+    // ========================================================================
+    // Merge (Detached Object Reattachment)
+    // ========================================================================
+
+    /// Merge a detached object back into the session.
+    ///
+    /// This method reattaches a detached or externally-created object to the session,
+    /// copying its state to the session-tracked instance if one exists.
+    ///
+    /// # Behavior
+    ///
+    /// 1. **If object with same PK exists in session**: Updates the tracked object
+    ///    with values from the provided object and returns a clone of the tracked version.
+    ///
+    /// 2. **If `load` is true and object not in session**: Queries the database for
+    ///    an existing row, merges the provided values onto it, and tracks it.
+    ///
+    /// 3. **If object not in session or DB**: Treats it as new (will INSERT on flush).
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// // Object from previous session or external source
+    /// let mut detached_user = User { id: Some(1), name: "Updated Name".into(), .. };
+    ///
+    /// // Merge into current session
+    /// let attached_user = session.merge(&cx, detached_user, true).await?;
+    ///
+    /// // attached_user is now tracked, changes will be persisted on flush
+    /// session.flush(&cx).await?;
+    /// ```
+    ///
+    /// # Parameters
+    ///
+    /// - `cx`: The async context for database operations.
+    /// - `model`: The detached model instance to merge.
+    /// - `load`: If true, load from database when not in identity map.
+    ///
+    /// # Returns
+    ///
+    /// The session-attached version of the object. If the object was already tracked,
+    /// returns a clone of the updated tracked object. Otherwise, returns a clone of
+    /// the newly tracked object.
+    #[tracing::instrument(level = "debug", skip(self, cx, model), fields(table = M::TABLE_NAME))]
+    pub async fn merge<
+        M: Model + Clone + Send + Sync + Serialize + for<'de> Deserialize<'de> + 'static,
+    >(
+        &mut self,
+        cx: &Cx,
+        model: M,
+        load: bool,
+    ) -> Outcome<M, Error> {
+        let pk_values = model.primary_key_value();
+        let key = ObjectKey::from_model(&model);
+
+        tracing::debug!(
+            pk = ?pk_values,
+            load = load,
+            in_identity_map = self.identity_map.contains_key(&key),
+            "Merging object"
+        );
+
+        // 1. Check identity map first
+        if let Some(tracked) = self.identity_map.get_mut(&key) {
+            // Skip if detached - we shouldn't merge into detached objects
+            if tracked.state == ObjectState::Detached {
+                tracing::debug!("Found detached object, treating as new");
+            } else {
+                tracing::debug!(
+                    state = ?tracked.state,
+                    "Found tracked object, updating with merged values"
+                );
+
+                // Update the tracked object with values from the provided model
+                let row_data = model.to_row();
+                tracked.object = Box::new(model.clone());
+                tracked.column_names = row_data.iter().map(|(name, _)| *name).collect();
+                tracked.values = row_data.into_iter().map(|(_, v)| v).collect();
+                tracked.pk_values.clone_from(&pk_values);
+
+                // If persistent, mark as dirty for UPDATE
+                if tracked.state == ObjectState::Persistent && !self.pending_dirty.contains(&key) {
+                    self.pending_dirty.push(key);
+                }
+
+                // Return clone of the tracked object
+                if let Some(obj) = tracked.object.downcast_ref::<M>() {
+                    return Outcome::Ok(obj.clone());
+                }
+            }
+        }
+
+        // 2. If load=true, try to fetch from database
+        if load {
+            // Check if we have a valid primary key (not null/default)
+            let has_valid_pk = pk_values
+                .iter()
+                .all(|v| !v.is_null() && !matches!(v, Value::Default));
+
+            if has_valid_pk {
+                tracing::debug!("Loading from database");
+
+                let db_result = self.get_by_pk::<M>(cx, &pk_values).await;
+                match db_result {
+                    Outcome::Ok(Some(_existing)) => {
+                        // Now update the tracked object (which was added by get_by_pk)
+                        // with the values from our model
+                        if let Some(tracked) = self.identity_map.get_mut(&key) {
+                            let row_data = model.to_row();
+                            tracked.object = Box::new(model.clone());
+                            tracked.column_names = row_data.iter().map(|(name, _)| *name).collect();
+                            tracked.values = row_data.into_iter().map(|(_, v)| v).collect();
+                            // pk_values stay the same from DB
+
+                            // Mark as dirty since we're updating with new values
+                            if !self.pending_dirty.contains(&key) {
+                                self.pending_dirty.push(key);
+                            }
+
+                            tracing::debug!("Merged values onto DB object");
+
+                            if let Some(obj) = tracked.object.downcast_ref::<M>() {
+                                return Outcome::Ok(obj.clone());
+                            }
+                        }
+                    }
+                    Outcome::Ok(None) => {
+                        tracing::debug!("Object not found in database, treating as new");
+                    }
+                    Outcome::Err(e) => return Outcome::Err(e),
+                    Outcome::Cancelled(r) => return Outcome::Cancelled(r),
+                    Outcome::Panicked(p) => return Outcome::Panicked(p),
+                }
+            }
+        }
+
+        // 3. Treat as new - add to session
+        tracing::debug!("Adding as new object");
+        self.add(&model);
+
+        Outcome::Ok(model)
+    }
+
+    /// Merge a detached object without loading from database.
+    ///
+    /// This is a convenience method equivalent to `merge(cx, model, false)`.
+    /// Use this when you know the object doesn't exist in the database or
+    /// you don't want to query the database.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let attached = session.merge_without_load(&cx, detached_user).await?;
+    /// ```
+    pub async fn merge_without_load<
+        M: Model + Clone + Send + Sync + Serialize + for<'de> Deserialize<'de> + 'static,
+    >(
+        &mut self,
+        cx: &Cx,
+        model: M,
+    ) -> Outcome<M, Error> {
+        self.merge(cx, model, false).await
+    }
+
+    // ========================================================================
+    // Debug Diagnostics
+    // ========================================================================
+
+    /// Get count of objects pending INSERT.
+    pub fn pending_new_count(&self) -> usize {
+        self.pending_new.len()
+    }
+
+    /// Get count of objects pending DELETE.
+    pub fn pending_delete_count(&self) -> usize {
+        self.pending_delete.len()
+    }
+
+    /// Get count of dirty objects pending UPDATE.
+    pub fn pending_dirty_count(&self) -> usize {
+        self.pending_dirty.len()
+    }
+
+    /// Get total tracked object count.
+    pub fn tracked_count(&self) -> usize {
+        self.identity_map.len()
+    }
+
+    /// Whether we're in a transaction.
+    pub fn in_transaction(&self) -> bool {
+        self.in_transaction
+    }
+
+    /// Dump session state for debugging.
+    pub fn debug_state(&self) -> SessionDebugInfo {
+        SessionDebugInfo {
+            tracked: self.tracked_count(),
+            pending_new: self.pending_new_count(),
+            pending_delete: self.pending_delete_count(),
+            pending_dirty: self.pending_dirty_count(),
+            in_transaction: self.in_transaction,
+        }
+    }
+
+    // ========================================================================
+    // Bulk Operations
+    // ========================================================================
+
+    /// Bulk insert multiple model instances without object tracking.
+    ///
+    /// This generates a single multi-row INSERT statement and bypasses
+    /// the identity map entirely, making it much faster for large batches.
+    ///
+    /// Models are inserted in chunks of `batch_size` to avoid excessively
+    /// large SQL statements. The default batch size is 1000; chunks are made
+    /// smaller when needed to stay under the parameter limit
+    /// (`SessionConfig::max_params`).
+    ///
+    /// Returns the total number of rows inserted. Progress is reported per
+    /// chunk to the callback registered with `on_batch_progress()`.
+    pub async fn bulk_insert<M: Model + Clone + Send + Sync + 'static>(
+        &mut self,
+        cx: &Cx,
+        models: &[M],
+    ) -> Outcome<u64, Error> {
+        self.bulk_insert_with_batch_size(cx, models, 1000).await
+    }
+
+    /// Bulk insert with a custom batch size.
+    pub async fn bulk_insert_with_batch_size<M: Model + Clone + Send + Sync + 'static>(
+        &mut self,
+        cx: &Cx,
+        models: &[M],
+        batch_size: usize,
+    ) -> Outcome<u64, Error> {
+        if models.is_empty() {
+            return Outcome::Ok(0);
+        }
+
+        let batch_size = match self.insert_chunk_len::<M>(batch_size) {
+            Ok(n) => n,
+            Err(e) => return Outcome::Err(e),
+        };
+        let mut total_inserted: u64 = 0;
+        let total = models.len() as u64;
+        let mut completed: u64 = 0;
+
+        for chunk in models.chunks(batch_size) {
+            let builder = sqlmodel_query::InsertManyBuilder::new(chunk);
+            match builder.execute(cx, &self.connection).await {
+                Outcome::Ok(count) => {
+                    total_inserted += count;
+                    completed += chunk.len() as u64;
+                    report_batch_progress(
+                        &mut self.batch_progress,
+                        M::TABLE_NAME,
+                        chunk.len() as u64,
+                        completed,
+                        total,
+                    );
+                }
+                Outcome::Err(e) => return Outcome::Err(e),
+                Outcome::Cancelled(r) => return Outcome::Cancelled(r),
+                Outcome::Panicked(p) => return Outcome::Panicked(p),
+            }
+        }
+
+        Outcome::Ok(total_inserted)
+    }
+
+    /// Bulk insert and hydrate every inserted object via `INSERT ... RETURNING *`.
+    ///
+    /// Like [`bulk_insert`](Self::bulk_insert), models are inserted in
+    /// multi-row chunks of 1000, but each chunk returns the stored rows so the
+    /// result carries generated ids and database defaults. The returned
+    /// objects are registered in the identity map as persistent.
+    ///
+    /// Requires a backend with multi-row `RETURNING` support (PostgreSQL,
+    /// SQLite 3.35+, MariaDB 10.5+); others return an error without executing
+    /// anything.
+    pub async fn bulk_insert_returning<M>(
+        &mut self,
+        cx: &Cx,
+        models: &[M],
+    ) -> Outcome<Vec<M>, Error>
+    where
+        M: Model + Clone + Send + Sync + Serialize + 'static,
+    {
+        let dialect = self.connection.dialect();
+        if !self.connection.capabilities().multi_row_returning {
+            return Outcome::Err(Error::Custom(format!(
+                "bulk_insert_returning requires multi-row RETURNING support, which this {dialect:?} backend lacks"
+            )));
+        }
+        if models.is_empty() {
+            return Outcome::Ok(Vec::new());
+        }
+
+        let batch_size = match self.insert_chunk_len::<M>(1000) {
+            Ok(n) => n,
+            Err(e) => return Outcome::Err(e),
+        };
+        let total = models.len() as u64;
+        let mut completed: u64 = 0;
+        let mut inserted = Vec::with_capacity(models.len());
+
+        for chunk in models.chunks(batch_size) {
+            let rows = match sqlmodel_query::InsertManyBuilder::new(chunk)
+                .execute_returning(cx, &self.connection)
+                .await
+            {
+                Outcome::Ok(rows) => rows,
+                Outcome::Err(e) => return Outcome::Err(e),
+                Outcome::Cancelled(r) => return Outcome::Cancelled(r),
+                Outcome::Panicked(p) => return Outcome::Panicked(p),
+            };
+            match self.track_loaded_rows::<M>(&rows) {
+                Ok(objects) => inserted.extend(objects),
+                Err(e) => return Outcome::Err(e),
+            }
+
+            completed += chunk.len() as u64;
+            report_batch_progress(
+                &mut self.batch_progress,
+                M::TABLE_NAME,
+                chunk.len() as u64,
+                completed,
+                total,
+            );
+        }
+
+        Outcome::Ok(inserted)
+    }
+
+    /// Rows per multi-row INSERT of `M`, capped at `batch_size` and small
+    /// enough that a chunk stays under the parameter limit.
+    fn insert_chunk_len<M: Model>(&self, batch_size: usize) -> Result<usize, Error> {
+        // Each row binds at most one parameter per field.
+        let per_row = M::fields().len().max(1);
+        let max_params = self.max_params();
+        if per_row > max_params {
+            return Err(Error::Custom(format!(
+                "cannot insert into {}: a single row binds up to {per_row} parameters, \
+                 exceeding the {max_params}-parameter limit",
+                M::TABLE_NAME
+            )));
+        }
+        Ok(batch_size.max(1).min(max_params / per_row))
+    }
+
+    /// Bulk update multiple model instances without individual tracking.
+    ///
+    /// Each model is updated individually using its primary key, but
+    /// all updates are executed in a single transaction without going
+    /// through the identity map or change tracking.
+    ///
+    /// Returns the total number of rows updated.
+    pub async fn bulk_update<M: Model + Clone + Send + Sync + 'static>(
+        &mut self,
+        cx: &Cx,
+        models: &[M],
+    ) -> Outcome<u64, Error> {
+        if models.is_empty() {
+            return Outcome::Ok(0);
+        }
+
+        let mut total_updated: u64 = 0;
+
+        for model in models {
+            let builder = sqlmodel_query::UpdateBuilder::new(model);
+            let (sql, params) = builder.build_with_dialect(self.connection.dialect());
+
+            if sql.is_empty() {
+                continue;
+            }
+
+            match self.connection.execute(cx, &sql, &params).await {
+                Outcome::Ok(count) => total_updated += count,
+                Outcome::Err(e) => return Outcome::Err(e),
+                Outcome::Cancelled(r) => return Outcome::Cancelled(r),
+                Outcome::Panicked(p) => return Outcome::Panicked(p),
+            }
+        }
+
+        Outcome::Ok(total_updated)
+    }
+
+    /// Set columns on many rows by primary key without loading them.
+    ///
+    /// Emits a single `UPDATE table SET col = ?, ... WHERE pk IN (...)` and
+    /// returns the number of rows affected; id lists that would exceed the
+    /// parameter limit are split across several statements. Tracked objects
+    /// with one of the given ids are expired so the next access reloads them.
+    ///
+    /// Assignment columns must exist on the model and must not be computed
+    /// or const fields. Requires a single-column primary key.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let archived = session
+    ///     .bulk_update_by_ids::<Order>(&cx, &ids, &[("status", Value::Text("archived".into()))])
+    ///     .await?;
+    /// ```
+    pub async fn bulk_update_by_ids<M: Model + 'static>(
+        &mut self,
+        cx: &Cx,
+        ids: &[Value],
+        assignments: &[(&str, Value)],
+    ) -> Outcome<u64, Error> {
+        if assignments.is_empty() {
+            return Outcome::Err(Error::Custom(format!(
+                "bulk_update_by_ids on {} requires at least one assignment",
+                M::TABLE_NAME
+            )));
+        }
+        let [pk_col] = M::PRIMARY_KEY else {
+            return Outcome::Err(Error::Custom(format!(
+                "bulk_update_by_ids requires a single-column primary key; {} has {}",
+                M::TABLE_NAME,
+                M::PRIMARY_KEY.len()
+            )));
+        };
+        for (column, _) in assignments {
+            let Some(field) = M::fields().iter().find(|f| f.column_name == *column) else {
+                return Outcome::Err(Error::Custom(format!(
+                    "bulk_update_by_ids: {} has no column '{column}'",
+                    M::TABLE_NAME
+                )));
+            };
+            if field.computed || field.const_field {
+                return Outcome::Err(Error::Custom(format!(
+                    "bulk_update_by_ids: column '{}.{column}' is computed or const and cannot be set",
+                    M::TABLE_NAME
+                )));
+            }
+        }
+        if ids.is_empty() {
+            return Outcome::Ok(0);
+        }
+
+        let dialect = self.connection.dialect();
+        let set_sql: Vec<String> = match assignments
+            .iter()
+            .enumerate()
+            .map(|(i, (column, _))| {
+                quote_ident_checked(column, dialect)
+                    .map(|column| format!("{column} = {}", dialect.placeholder(i + 1)))
+            })
+            .collect()
+        {
+            Ok(set_sql) => set_sql,
+            Err(e) => return Outcome::Err(e),
+        };
+        let Some(chunk_len) = self
+            .max_params()
+            .checked_sub(assignments.len())
+            .filter(|&n| n > 0)
+        else {
+            return Outcome::Err(Error::Custom(format!(
+                "bulk_update_by_ids: {} assignments leave no room for ids within the {}-parameter limit",
+                assignments.len(),
+                self.max_params()
+            )));
+        };
+        let table = match quote_ident_checked(M::TABLE_NAME, dialect) {
+            Ok(table) => table,
+            Err(e) => return Outcome::Err(e),
+        };
+        let set_sql = set_sql.join(", ");
+
+        let mut affected: u64 = 0;
+        for chunk in ids.chunks(chunk_len) {
+            let (in_sql, id_params) = dialect.in_clause(pk_col, chunk, assignments.len() + 1);
+            let sql = format!("UPDATE {table} SET {set_sql} WHERE {in_sql}");
+            let mut params: Vec<Value> = assignments.iter().map(|(_, v)| v.clone()).collect();
+            params.extend(id_params);
+
+            match self.connection.execute(cx, &sql, &params).await {
+                Outcome::Ok(count) => affected += count,
+                Outcome::Err(e) => return Outcome::Err(e),
+                Outcome::Cancelled(r) => return Outcome::Cancelled(r),
+                Outcome::Panicked(p) => return Outcome::Panicked(p),
+            }
+        }
+
+        for id in ids {
+            let key = ObjectKey::from_pk::<M>(std::slice::from_ref(id));
+            if let Some(tracked) = self
+                .identity_map
+                .get_mut(&key)
+                .filter(|t| t.state == ObjectState::Persistent)
+            {
+                tracked.state = ObjectState::Expired;
+                tracked.expired_attributes = None;
+            }
+        }
+
+        Outcome::Ok(affected)
+    }
+}
+
+impl<C, M> LazyLoader<M> for Session<C>
+where
+    C: Connection,
+    M: Model + Clone + Send + Sync + Serialize + for<'de> Deserialize<'de> + 'static,
+{
+    fn get(
+        &mut self,
+        cx: &Cx,
+        pk: Value,
+    ) -> impl Future<Output = Outcome<Option<M>, Error>> + Send {
+        Session::get(self, cx, pk)
+    }
+}
+
+/// Debug information about session state.
+#[derive(Debug, Clone)]
+pub struct SessionDebugInfo {
+    /// Total tracked objects.
+    pub tracked: usize,
+    /// Objects pending INSERT.
+    pub pending_new: usize,
+    /// Objects pending DELETE.
+    pub pending_delete: usize,
+    /// Objects pending UPDATE.
+    pub pending_dirty: usize,
+    /// Whether in a transaction.
+    pub in_transaction: bool,
+}
+
+/// Total estimated cost of the top plan node in EXPLAIN output.
+///
+/// Understands PostgreSQL text plans (`... (cost=0.00..35.50 rows=2550 ...)`)
+/// and MySQL JSON plans (`"query_cost": "12.50"`).
+fn parse_plan_cost(plan: &str) -> Option<f64> {
+    if let Some(start) = plan.find("cost=") {
+        let range = &plan[start + "cost=".len()..];
+        let end = range.find(' ').unwrap_or(range.len());
+        let (_, total) = range[..end].split_once("..")?;
+        return total.parse().ok();
+    }
+    let start = plan.find("\"query_cost\"")? + "\"query_cost\"".len();
+    let value = plan[start..]
+        .trim_start()
+        .strip_prefix(':')?
+        .trim_start()
+        .trim_start_matches('"');
+    let end = value
+        .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .unwrap_or(value.len());
+    value[..end].parse().ok()
+}
+
+/// Estimated row count from EXPLAIN output.
+///
+/// Reads the top node's `rows=` from PostgreSQL text plans and the largest
+/// `"rows_examined_per_scan"` from MySQL JSON plans.
+fn parse_plan_rows(plan: &str) -> Option<u64> {
+    if let Some(start) = plan.find(" rows=") {
+        let range = &plan[start + " rows=".len()..];
+        let end = range
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(range.len());
+        return range[..end].parse().ok();
+    }
+    plan.match_indices("\"rows_examined_per_scan\"")
+        .filter_map(|(start, key)| {
+            let value = plan[start + key.len()..]
+                .trim_start()
+                .strip_prefix(':')?
+                .trim_start()
+                .trim_start_matches('"');
+            let end = value
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(value.len());
+            value[..end].parse().ok()
+        })
+        .max()
+}
+
+/// Invoke the batch progress callback, if one is registered.
+fn report_batch_progress(
+    callback: &mut Option<BatchProgressFn>,
+    table: &'static str,
+    rows_in_batch: u64,
+    completed: u64,
+    total: u64,
+) {
+    if let Some(cb) = callback.as_mut() {
+        cb(BatchProgress {
+            table,
+            rows_in_batch,
+            completed,
+            total,
+        });
+    }
+}
+
+/// Mirror the database's version increment in a tracked object after its
+/// UPDATE succeeded. Returns whether the version was bumped.
+fn bump_version(tracked: &mut TrackedObject) -> bool {
+    let Some(version) = tracked.version else {
+        return false;
+    };
+    let Some(idx) = tracked
+        .column_names
+        .iter()
+        .position(|c| *c == version.column)
+    else {
+        return false;
+    };
+    let next = match tracked.values[idx] {
+        Value::TinyInt(v) => Value::TinyInt(v.wrapping_add(1)),
+        Value::SmallInt(v) => Value::SmallInt(v.wrapping_add(1)),
+        Value::Int(v) => Value::Int(v.wrapping_add(1)),
+        Value::BigInt(v) => Value::BigInt(v.wrapping_add(1)),
+        _ => return false,
+    };
+    tracked.values[idx] = next;
+    if let Some(object) = (version.rebuild)(&tracked.column_names, &tracked.values) {
+        tracked.object = object;
+    }
+    true
+}
+
+/// Handle the affected-row count of a single-object UPDATE or DELETE.
+///
+/// Zero rows means the object is stale: an error when `detect_stale` is set.
+/// With it off, stale objects are expected and only logged at debug level.
+fn check_row_affected(
+    affected: u64,
+    detect_stale: bool,
+    statement: &'static str,
+    table: &'static str,
+    pk: &[Value],
+) -> Outcome<u64, Error> {
+    if affected > 0 {
+        return Outcome::Ok(affected);
+    }
+    if detect_stale {
+        return Outcome::Err(Error::StaleObject(StaleObjectError {
+            table: table.to_string(),
+            pk: pk.to_vec(),
+            statement,
+        }));
+    }
+    tracing::debug!(
+        table,
+        statement,
+        pk = ?pk,
+        "Flush statement affected no rows; the object may be stale"
+    );
+    Outcome::Ok(affected)
+}
+
+// ============================================================================
+// Flush SQL Builders
+// ============================================================================
+
+/// Append the cascade DELETE `statements` for one group of dependent rows,
+/// attaching `cleanup` to the last of them.
+fn push_cascade_steps(
+    steps: &mut Vec<FlushStep>,
+    table: &'static str,
+    statements: Vec<(String, Vec<Value>)>,
+    mut cleanup: Option<CascadeCleanup>,
+) {
+    let last = statements.len().saturating_sub(1);
+    for (i, (sql, params)) in statements.into_iter().enumerate() {
+        steps.push(FlushStep {
+            statement: PreviewStatement {
+                kind: PreviewKind::Delete,
+                table,
+                sql,
+                params,
+            },
+            target: FlushTarget::Cascade(if i == last { cleanup.take() } else { None }),
+        });
+    }
+}
+
+/// `DELETE FROM table WHERE column IN (...)` binding `values`, split into
+/// statements of at most `max_params` parameters each.
+fn build_in_deletes(
+    dialect: Dialect,
+    table: &str,
+    column: &str,
+    values: &[Value],
+    max_params: usize,
+) -> Vec<(String, Vec<Value>)> {
+    values
+        .chunks(max_params.max(1))
+        .map(|chunk| {
+            let (in_sql, params) = dialect.in_clause(column, chunk, 1);
+            (
+                format!(
+                    "DELETE FROM {} WHERE {in_sql}",
+                    dialect.quote_identifier(table)
+                ),
+                params,
+            )
+        })
+        .collect()
+}
+
+/// Row-value IN deletes for composite keys, split into statements of at most
+/// `max_params` parameters each.
+fn build_tuple_in_deletes(
+    dialect: Dialect,
+    table: &str,
+    columns: &[&str],
+    tuples: &[Vec<Value>],
+    max_params: usize,
+) -> Vec<(String, Vec<Value>)> {
+    tuples
+        .chunks((max_params / columns.len().max(1)).max(1))
+        .map(|chunk| build_tuple_in_delete(dialect, table, columns, chunk))
+        .collect()
+}
+
+/// `DELETE FROM table WHERE (a, b) IN ((...), ...)` for composite keys.
+fn build_tuple_in_delete(
+    dialect: Dialect,
+    table: &str,
+    columns: &[&str],
+    tuples: &[Vec<Value>],
+) -> (String, Vec<Value>) {
+    let (in_sql, params) = dialect.tuple_in_clause(columns, tuples, 1);
+    let sql = format!(
+        "DELETE FROM {} WHERE {in_sql}",
+        dialect.quote_identifier(table)
+    );
+    (sql, params)
+}
+
+/// `DELETE` of a single tracked object by primary key. Parameters are `tracked.pk_values`.
+fn build_pk_delete(dialect: Dialect, tracked: &TrackedObject) -> String {
+    let where_parts: Vec<String> = tracked
+        .pk_columns
+        .iter()
+        .enumerate()
+        .map(|(i, col)| {
+            format!(
+                "{} = {}",
+                dialect.quote_identifier(col),
+                dialect.placeholder(i + 1)
+            )
+        })
+        .collect();
+
+    format!(
+        "DELETE FROM {} WHERE {}",
+        dialect.quote_identifier(tracked.table_name),
+        where_parts.join(" AND ")
+    )
+}
+
+/// `INSERT` of a tracked object, leaving out `generated_column` (and returning
+/// it on Postgres) when the database still has to generate the primary key.
+fn build_insert(
+    dialect: Dialect,
+    tracked: &TrackedObject,
+    generated_column: Option<&str>,
+) -> (String, Vec<Value>) {
+    let (columns, params): (Vec<&'static str>, Vec<Value>) = tracked
+        .column_names
+        .iter()
+        .zip(&tracked.values)
+        .filter(|(c, _)| Some(**c) != generated_column)
+        .map(|(c, v)| (*c, v.clone()))
+        .unzip();
+    let columns_sql: Vec<String> = columns
+        .iter()
+        .map(|c| dialect.quote_identifier(c))
+        .collect();
+    let placeholders: Vec<String> = (1..=columns.len())
+        .map(|i| dialect.placeholder(i))
+        .collect();
+
+    let table = dialect.quote_identifier(tracked.table_name);
+    let mut sql = if columns.is_empty() {
+        // Only the generated key: every column takes its default.
+        dialect.default_values_insert(&table)
+    } else {
+        format!(
+            "INSERT INTO {} ({}) VALUES ({})",
+            table,
+            columns_sql.join(", "),
+            placeholders.join(", ")
+        )
+    };
+    if let Some(column) = generated_column.filter(|_| dialect == Dialect::Postgres) {
+        sql.push_str(" RETURNING ");
+        sql.push_str(&dialect.quote_identifier(column));
+    }
+    (sql, params)
+}
+
+/// `UPDATE` of all non-PK columns of a tracked object, or `None` if it has no
+/// non-PK columns.
+fn build_update(dialect: Dialect, tracked: &TrackedObject) -> Option<(String, Vec<Value>)> {
+    let mut set_parts = Vec::new();
+    let mut params = Vec::new();
+    let mut param_idx = 1;
+
+    let version = tracked.version.and_then(|v| {
+        let idx = tracked.column_names.iter().position(|c| *c == v.column)?;
+        Some((v.column, idx))
+    });
+
+    for (i, col) in tracked.column_names.iter().enumerate() {
+        if version.is_some_and(|(_, idx)| idx == i) {
+            // The database increments the version itself.
+            let quoted = dialect.quote_identifier(col);
+            set_parts.push(format!("{quoted} = {quoted} + 1"));
+            continue;
+        }
+        // Skip primary key columns in SET clause
+        if !tracked.pk_columns.contains(col) {
+            set_parts.push(format!(
+                "{} = {}",
+                dialect.quote_identifier(col),
+                dialect.placeholder(param_idx)
+            ));
+            params.push(tracked.values[i].clone());
+            param_idx += 1;
+        }
+    }
+    if set_parts.is_empty() {
+        return None;
+    }
+
+    // Add WHERE clause for primary key
+    let mut where_parts: Vec<String> = tracked
+        .pk_columns
+        .iter()
+        .map(|col| {
+            let clause = format!(
+                "{} = {}",
+                dialect.quote_identifier(col),
+                dialect.placeholder(param_idx)
+            );
+            param_idx += 1;
+            clause
+        })
+        .collect();
+    params.extend(tracked.pk_values.iter().cloned());
+    if let Some((column, idx)) = version {
+        where_parts.push(format!(
+            "{} = {}",
+            dialect.quote_identifier(column),
+            dialect.placeholder(param_idx)
+        ));
+        params.push(tracked.values[idx].clone());
+    }
+
+    let sql = format!(
+        "UPDATE {} SET {} WHERE {}",
+        dialect.quote_identifier(tracked.table_name),
+        set_parts.join(", "),
+        where_parts.join(" AND ")
+    );
+    Some((sql, params))
+}
+
+/// Execute one flush statement, recording its SQL and duration when `timings` is provided.
+async fn execute_flush_statement<C: Connection>(
+    connection: &C,
+    cx: &Cx,
+    sql: &str,
+    params: &[Value],
+    timings: Option<&mut Vec<(String, Duration)>>,
+) -> Outcome<u64, Error> {
+    let Some(timings) = timings else {
+        return connection.execute(cx, sql, params).await;
+    };
+    let start = Instant::now();
+    let outcome = connection.execute(cx, sql, params).await;
+    timings.push((sql.to_string(), start.elapsed()));
+    outcome
+}
+
+/// Like `execute_flush_statement`, but returns the generated id of an INSERT.
+async fn insert_flush_statement<C: Connection>(
+    connection: &C,
+    cx: &Cx,
+    sql: &str,
+    params: &[Value],
+    timings: Option<&mut Vec<(String, Duration)>>,
+) -> Outcome<i64, Error> {
+    let Some(timings) = timings else {
+        return connection.insert(cx, sql, params).await;
+    };
+    let start = Instant::now();
+    let outcome = connection.insert(cx, sql, params).await;
+    timings.push((sql.to_string(), start.elapsed()));
+    outcome
+}
+
+// ============================================================================
+// Unit Tests
+// ============================================================================
+
+#[cfg(test)]
+#[allow(clippy::manual_async_fn)] // Mock trait impls must match trait signatures
+mod tests {
+    use super::*;
+    use asupersync::runtime::RuntimeBuilder;
+    use sqlmodel_core::Row;
+    use std::sync::{Arc, Mutex};
+
+    #[test]
+    fn test_session_config_defaults() {
+        let config = SessionConfig::default();
+        assert!(config.auto_begin);
+        assert!(!config.auto_flush);
+        assert!(config.expire_on_commit);
+        assert!(!config.collect_timings);
+        assert_eq!(config.unset_pk_policy, UnsetPkPolicy::TemporaryKey);
+        assert_eq!(config.transaction_mode, TransactionMode::default());
+        assert!(!config.read_only);
+    }
+
+    #[test]
+    fn test_object_key_hash_consistency() {
+        let values1 = vec![Value::BigInt(42)];
+        let values2 = vec![Value::BigInt(42)];
+        let hash1 = hash_values(&values1);
+        let hash2 = hash_values(&values2);
+        assert_eq!(hash1, hash2);
+    }
+
+    #[test]
+    fn test_object_key_hash_different_values() {
+        let values1 = vec![Value::BigInt(42)];
+        let values2 = vec![Value::BigInt(43)];
+        let hash1 = hash_values(&values1);
+        let hash2 = hash_values(&values2);
+        assert_ne!(hash1, hash2);
+    }
+
+    #[test]
+    fn test_object_key_hash_different_types() {
+        let values1 = vec![Value::BigInt(42)];
+        let values2 = vec![Value::Text("42".to_string())];
+        let hash1 = hash_values(&values1);
+        let hash2 = hash_values(&values2);
+        assert_ne!(hash1, hash2);
+    }
+
+    #[test]
+    fn test_session_debug_info() {
+        let info = SessionDebugInfo {
+            tracked: 5,
+            pending_new: 2,
+            pending_delete: 1,
+            pending_dirty: 0,
+            in_transaction: true,
+        };
+        assert_eq!(info.tracked, 5);
+        assert_eq!(info.pending_new, 2);
+        assert!(info.in_transaction);
+    }
+
+    fn unwrap_outcome<T: std::fmt::Debug>(outcome: Outcome<T, Error>) -> T {
+        match outcome {
+            Outcome::Ok(v) => v,
+            other => std::panic::panic_any(format!("unexpected outcome: {other:?}")),
+        }
+    }
+
+    #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+    struct Team {
+        id: Option<i64>,
+        name: String,
+    }
+
+    impl Model for Team {
+        const TABLE_NAME: &'static str = "teams";
+        const PRIMARY_KEY: &'static [&'static str] = &["id"];
+
+        fn fields() -> &'static [sqlmodel_core::FieldInfo] {
+            &[]
+        }
+
+        fn to_row(&self) -> Vec<(&'static str, Value)> {
+            vec![
+                ("id", self.id.map_or(Value::Null, Value::BigInt)),
+                ("name", Value::Text(self.name.clone())),
+            ]
+        }
+
+        fn from_row(row: &Row) -> sqlmodel_core::Result<Self> {
+            let id: i64 = row.get_named("id")?;
+            let name: String = row.get_named("name")?;
+            Ok(Self { id: Some(id), name })
+        }
+
+        fn primary_key_value(&self) -> Vec<Value> {
+            self.id
+                .map_or_else(|| vec![Value::Null], |id| vec![Value::BigInt(id)])
+        }
+
+        fn is_new(&self) -> bool {
+            self.id.is_none()
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Roster {
+        id: i64,
+        name: String,
+        version: i64,
+    }
+
+    impl Model for Roster {
+        const TABLE_NAME: &'static str = "rosters";
+        const PRIMARY_KEY: &'static [&'static str] = &["id"];
+        const VERSION_COLUMN: Option<&'static str> = Some("version");
+
+        fn fields() -> &'static [sqlmodel_core::FieldInfo] {
+            &[]
+        }
+
+        fn to_row(&self) -> Vec<(&'static str, Value)> {
+            vec![
+                ("id", Value::BigInt(self.id)),
+                ("name", Value::Text(self.name.clone())),
+                ("version", Value::BigInt(self.version)),
+            ]
+        }
+
+        fn from_row(row: &Row) -> sqlmodel_core::Result<Self> {
+            Ok(Self {
+                id: row.get_named("id")?,
+                name: row.get_named("name")?,
+                version: row.get_named("version")?,
+            })
+        }
+
+        fn primary_key_value(&self) -> Vec<Value> {
+            vec![Value::BigInt(self.id)]
         }
 
-        let dedup_by_hash = |vals: &mut Vec<Value>| {
-            let mut seen: std::collections::HashSet<u64> = std::collections::HashSet::new();
-            vals.retain(|v| seen.insert(hash_values(std::slice::from_ref(v))));
-        };
-
-        // (a) Delete children first (one-to-many / one-to-one).
-        for ((child_table, fk_col), mut pks) in cascade_child_deletes_single {
-            dedup_by_hash(&mut pks);
-            if pks.is_empty() {
-                continue;
-            }
+        fn is_new(&self) -> bool {
+            false
+        }
+    }
 
-            let placeholders: Vec<String> =
-                (1..=pks.len()).map(|i| dialect.placeholder(i)).collect();
-            let sql = format!(
-                "DELETE FROM {} WHERE {} IN ({})",
-                dialect.quote_identifier(child_table),
-                dialect.quote_identifier(fk_col),
-                placeholders.join(", ")
-            );
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    struct Hero {
+        id: Option<i64>,
+        team: Lazy<Team>,
+    }
 
-            match self.connection.execute(cx, &sql, &pks).await {
-                Outcome::Ok(_) => {}
-                Outcome::Err(e) => {
-                    self.pending_delete = deletes;
-                    return Outcome::Err(e);
-                }
-                Outcome::Cancelled(r) => {
-                    self.pending_delete = deletes;
-                    return Outcome::Cancelled(r);
-                }
-                Outcome::Panicked(p) => {
-                    self.pending_delete = deletes;
-                    return Outcome::Panicked(p);
-                }
-            }
+    impl Model for Hero {
+        const TABLE_NAME: &'static str = "heroes";
+        const PRIMARY_KEY: &'static [&'static str] = &["id"];
 
-            // Remove now-deleted children from the identity map to prevent stale reads.
-            let pk_hashes: std::collections::HashSet<u64> = pks
-                .iter()
-                .map(|v| hash_values(std::slice::from_ref(v)))
-                .collect();
-            let mut to_remove: Vec<ObjectKey> = Vec::new();
-            for (k, t) in &self.identity_map {
-                if t.table_name != child_table {
-                    continue;
-                }
-                let Some(idx) = t.column_names.iter().position(|col| *col == fk_col) else {
-                    continue;
-                };
-                let fk_val = &t.values[idx];
-                if pk_hashes.contains(&hash_values(std::slice::from_ref(fk_val))) {
-                    to_remove.push(*k);
-                }
-            }
-            for k in &to_remove {
-                self.identity_map.remove(k);
-            }
-            self.pending_new.retain(|k| !to_remove.contains(k));
-            self.pending_dirty.retain(|k| !to_remove.contains(k));
-            self.pending_delete.retain(|k| !to_remove.contains(k));
+        fn fields() -> &'static [sqlmodel_core::FieldInfo] {
+            &[]
         }
 
-        // (a2) Delete children for composite foreign keys using row-value IN.
-        for (key, mut tuples) in cascade_child_deletes_composite {
-            if tuples.is_empty() {
-                continue;
-            }
+        fn to_row(&self) -> Vec<(&'static str, Value)> {
+            vec![]
+        }
 
-            let mut seen: std::collections::HashSet<u64> = std::collections::HashSet::new();
-            tuples.retain(|t| seen.insert(hash_values(t)));
+        fn from_row(_row: &Row) -> sqlmodel_core::Result<Self> {
+            Ok(Self {
+                id: None,
+                team: Lazy::empty(),
+            })
+        }
 
-            if tuples.is_empty() {
-                continue;
-            }
+        fn primary_key_value(&self) -> Vec<Value> {
+            self.id
+                .map_or_else(|| vec![Value::Null], |id| vec![Value::BigInt(id)])
+        }
 
-            let col_list = key
-                .fk_cols
-                .iter()
-                .map(|c| dialect.quote_identifier(c))
-                .collect::<Vec<_>>()
-                .join(", ");
+        fn is_new(&self) -> bool {
+            self.id.is_none()
+        }
+    }
 
-            let mut params: Vec<Value> = Vec::with_capacity(tuples.len() * key.fk_cols.len());
-            let mut idx = 1;
-            let tuple_sql: Vec<String> = tuples
-                .iter()
-                .map(|t| {
-                    for v in t {
-                        params.push(v.clone());
-                    }
-                    let inner = (0..key.fk_cols.len())
-                        .map(|_| {
-                            let ph = dialect.placeholder(idx);
-                            idx += 1;
-                            ph
-                        })
-                        .collect::<Vec<_>>()
-                        .join(", ");
-                    format!("({})", inner)
-                })
-                .collect();
+    #[derive(Debug, Default)]
+    struct MockState {
+        query_calls: usize,
+        last_sql: Option<String>,
+        execute_calls: usize,
+        executed: Vec<(String, Vec<Value>)>,
+        last_insert_id: i64,
+        transaction_status: TransactionStatus,
+        rows_affected: u64,
+        query_one_sql: Vec<String>,
+    }
 
-            let sql = format!(
-                "DELETE FROM {} WHERE ({}) IN ({})",
-                dialect.quote_identifier(key.table),
-                col_list,
-                tuple_sql.join(", ")
-            );
+    #[derive(Debug, Clone)]
+    struct MockConnection {
+        state: Arc<Mutex<MockState>>,
+        dialect: sqlmodel_core::Dialect,
+    }
 
-            match self.connection.execute(cx, &sql, &params).await {
-                Outcome::Ok(_) => {}
-                Outcome::Err(e) => {
-                    self.pending_delete = deletes;
-                    return Outcome::Err(e);
-                }
-                Outcome::Cancelled(r) => {
-                    self.pending_delete = deletes;
-                    return Outcome::Cancelled(r);
-                }
-                Outcome::Panicked(p) => {
-                    self.pending_delete = deletes;
-                    return Outcome::Panicked(p);
-                }
+    impl MockConnection {
+        fn new(state: Arc<Mutex<MockState>>) -> Self {
+            Self {
+                state,
+                dialect: sqlmodel_core::Dialect::Postgres,
             }
+        }
+    }
 
-            // Remove now-deleted children from the identity map to prevent stale reads.
-            let tuple_hashes: std::collections::HashSet<u64> =
-                tuples.iter().map(|t| hash_values(t)).collect();
-            let mut to_remove: Vec<ObjectKey> = Vec::new();
-            for (k, t) in &self.identity_map {
-                if t.table_name != key.table {
-                    continue;
-                }
+    impl sqlmodel_core::Connection for MockConnection {
+        type Tx<'conn>
+            = MockTransaction
+        where
+            Self: 'conn;
 
-                let mut child_fk: Vec<Value> = Vec::with_capacity(key.fk_cols.len());
-                let mut missing = false;
-                for fk_col in &key.fk_cols {
-                    let Some(idx) = t.column_names.iter().position(|col| col == fk_col) else {
-                        missing = true;
-                        break;
-                    };
-                    child_fk.push(t.values[idx].clone());
-                }
-                if missing {
-                    continue;
-                }
-                if tuple_hashes.contains(&hash_values(&child_fk)) {
-                    to_remove.push(*k);
-                }
-            }
-            for k in &to_remove {
-                self.identity_map.remove(k);
-            }
-            self.pending_new.retain(|k| !to_remove.contains(k));
-            self.pending_dirty.retain(|k| !to_remove.contains(k));
-            self.pending_delete.retain(|k| !to_remove.contains(k));
+        fn dialect(&self) -> sqlmodel_core::Dialect {
+            self.dialect
         }
 
-        // (b) Clean up link-table rows for many-to-many relationships (association rows only).
-        for ((link_table, local_col), mut pks) in cascade_link_deletes_single {
-            dedup_by_hash(&mut pks);
-            if pks.is_empty() {
-                continue;
-            }
+        fn transaction_status(&self) -> TransactionStatus {
+            self.state.lock().expect("lock poisoned").transaction_status
+        }
 
-            let placeholders: Vec<String> =
-                (1..=pks.len()).map(|i| dialect.placeholder(i)).collect();
-            let sql = format!(
-                "DELETE FROM {} WHERE {} IN ({})",
-                dialect.quote_identifier(link_table),
-                dialect.quote_identifier(local_col),
-                placeholders.join(", ")
-            );
+        fn query(
+            &self,
+            _cx: &Cx,
+            sql: &str,
+            params: &[Value],
+        ) -> impl Future<Output = Outcome<Vec<Row>, Error>> + Send {
+            let params = params.to_vec();
+            let state = Arc::clone(&self.state);
+            let sql = sql.to_string();
+            async move {
+                {
+                    let mut guard = state.lock().expect("lock poisoned");
+                    guard.query_calls += 1;
+                    guard.last_sql = Some(sql.clone());
+                }
 
-            match self.connection.execute(cx, &sql, &pks).await {
-                Outcome::Ok(_) => {}
-                Outcome::Err(e) => {
-                    self.pending_delete = deletes;
-                    return Outcome::Err(e);
+                if sql.starts_with("EXPLAIN ") {
+                    return Outcome::Ok(vec![Row::new(
+                        vec!["QUERY PLAN".into()],
+                        vec![Value::Text(
+                            "Seq Scan on teams  (cost=0.00..1234.50 rows=1000 width=36)".into(),
+                        )],
+                    )]);
                 }
-                Outcome::Cancelled(r) => {
-                    self.pending_delete = deletes;
-                    return Outcome::Cancelled(r);
+
+                let mut rows = Vec::new();
+                let is_teams = sql.contains("teams");
+                let is_heroes = sql.contains("heroes");
+
+                for v in params {
+                    if is_teams {
+                        match v {
+                            Value::BigInt(1) => rows.push(Row::new(
+                                vec!["id".into(), "name".into()],
+                                vec![Value::BigInt(1), Value::Text("Avengers".into())],
+                            )),
+                            Value::BigInt(2) => rows.push(Row::new(
+                                vec!["id".into(), "name".into()],
+                                vec![Value::BigInt(2), Value::Text("X-Men".into())],
+                            )),
+                            _ => {}
+                        }
+                    } else if is_heroes {
+                        // One-to-many child rows keyed by team_id (the query parameter).
+                        match v {
+                            Value::BigInt(1) => {
+                                rows.push(Row::new(
+                                    vec!["id".into(), "team_id".into(), "__parent_pk".into()],
+                                    vec![Value::BigInt(101), Value::BigInt(1), Value::BigInt(1)],
+                                ));
+                                rows.push(Row::new(
+                                    vec!["id".into(), "team_id".into(), "__parent_pk".into()],
+                                    vec![Value::BigInt(102), Value::BigInt(1), Value::BigInt(1)],
+                                ));
+                            }
+                            Value::BigInt(2) => rows.push(Row::new(
+                                vec!["id".into(), "team_id".into(), "__parent_pk".into()],
+                                vec![Value::BigInt(201), Value::BigInt(2), Value::BigInt(2)],
+                            )),
+                            _ => {}
+                        }
+                    }
                 }
-                Outcome::Panicked(p) => {
-                    self.pending_delete = deletes;
-                    return Outcome::Panicked(p);
+
+                // Emulate the window count `paginate` appends to its query.
+                if sql.contains("COUNT(*) OVER ()") {
+                    let total = Value::BigInt(rows.len() as i64);
+                    rows = rows
+                        .into_iter()
+                        .map(|row| {
+                            let mut names: Vec<String> =
+                                row.column_names().map(str::to_string).collect();
+                            let mut values: Vec<Value> = row.values().cloned().collect();
+                            names.push(PAGINATE_TOTAL_COLUMN.to_string());
+                            values.push(total.clone());
+                            Row::new(names, values)
+                        })
+                        .collect();
                 }
+
+                Outcome::Ok(rows)
             }
         }
 
-        // (b2) Clean up link-table rows for composite parent keys using row-value IN.
-        for (key, mut tuples) in cascade_link_deletes_composite {
-            if tuples.is_empty() {
-                continue;
-            }
+        fn query_one(
+            &self,
+            _cx: &Cx,
+            sql: &str,
+            _params: &[Value],
+        ) -> impl Future<Output = Outcome<Option<Row>, Error>> + Send {
+            self.state
+                .lock()
+                .expect("lock poisoned")
+                .query_one_sql
+                .push(sql.to_string());
+            async { Outcome::Ok(None) }
+        }
 
-            let mut seen: std::collections::HashSet<u64> = std::collections::HashSet::new();
-            tuples.retain(|t| seen.insert(hash_values(t)));
+        fn execute(
+            &self,
+            _cx: &Cx,
+            sql: &str,
+            params: &[Value],
+        ) -> impl Future<Output = Outcome<u64, Error>> + Send {
+            let state = Arc::clone(&self.state);
+            let sql = sql.to_string();
+            let params = params.to_vec();
+            async move {
+                let mut guard = state.lock().expect("lock poisoned");
+                guard.execute_calls += 1;
+                guard.executed.push((sql, params));
+                Outcome::Ok(guard.rows_affected)
+            }
+        }
 
-            if tuples.is_empty() {
-                continue;
+        fn insert(
+            &self,
+            _cx: &Cx,
+            sql: &str,
+            params: &[Value],
+        ) -> impl Future<Output = Outcome<i64, Error>> + Send {
+            let state = Arc::clone(&self.state);
+            let sql = sql.to_string();
+            let params = params.to_vec();
+            async move {
+                let mut guard = state.lock().expect("lock poisoned");
+                guard.executed.push((sql, params));
+                guard.last_insert_id += 1;
+                Outcome::Ok(guard.last_insert_id)
             }
+        }
 
-            let col_list = key
-                .fk_cols
-                .iter()
-                .map(|c| dialect.quote_identifier(c))
-                .collect::<Vec<_>>()
-                .join(", ");
+        fn batch(
+            &self,
+            _cx: &Cx,
+            _statements: &[(String, Vec<Value>)],
+        ) -> impl Future<Output = Outcome<Vec<u64>, Error>> + Send {
+            async { Outcome::Ok(vec![]) }
+        }
 
-            let mut params: Vec<Value> = Vec::with_capacity(tuples.len() * key.fk_cols.len());
-            let mut idx = 1;
-            let tuple_sql: Vec<String> = tuples
-                .iter()
-                .map(|t| {
-                    for v in t {
-                        params.push(v.clone());
-                    }
-                    let inner = (0..key.fk_cols.len())
-                        .map(|_| {
-                            let ph = dialect.placeholder(idx);
-                            idx += 1;
-                            ph
-                        })
-                        .collect::<Vec<_>>()
-                        .join(", ");
-                    format!("({})", inner)
-                })
-                .collect();
+        fn begin(&self, _cx: &Cx) -> impl Future<Output = Outcome<Self::Tx<'_>, Error>> + Send {
+            async { Outcome::Ok(MockTransaction) }
+        }
 
-            let sql = format!(
-                "DELETE FROM {} WHERE ({}) IN ({})",
-                dialect.quote_identifier(key.table),
-                col_list,
-                tuple_sql.join(", ")
-            );
+        fn begin_with(
+            &self,
+            _cx: &Cx,
+            _isolation: sqlmodel_core::connection::IsolationLevel,
+        ) -> impl Future<Output = Outcome<Self::Tx<'_>, Error>> + Send {
+            async { Outcome::Ok(MockTransaction) }
+        }
 
-            match self.connection.execute(cx, &sql, &params).await {
-                Outcome::Ok(_) => {}
-                Outcome::Err(e) => {
-                    self.pending_delete = deletes;
-                    return Outcome::Err(e);
-                }
-                Outcome::Cancelled(r) => {
-                    self.pending_delete = deletes;
-                    return Outcome::Cancelled(r);
-                }
-                Outcome::Panicked(p) => {
-                    self.pending_delete = deletes;
-                    return Outcome::Panicked(p);
-                }
+        fn prepare(
+            &self,
+            _cx: &Cx,
+            _sql: &str,
+        ) -> impl Future<Output = Outcome<sqlmodel_core::connection::PreparedStatement, Error>> + Send
+        {
+            async {
+                Outcome::Ok(sqlmodel_core::connection::PreparedStatement::new(
+                    0,
+                    String::new(),
+                    0,
+                ))
             }
         }
 
-        let mut actually_deleted: Vec<ObjectKey> = Vec::new();
-        for key in &deletes {
-            if let Some(tracked) = self.identity_map.get(key) {
-                // Skip if object was un-deleted (state changed from Deleted)
-                if tracked.state != ObjectState::Deleted {
-                    continue;
-                }
-
-                // Skip objects without primary keys - cannot safely DELETE without WHERE clause
-                if tracked.pk_columns.is_empty() || tracked.pk_values.is_empty() {
-                    tracing::warn!(
-                        table = tracked.table_name,
-                        "Skipping DELETE for object without primary key - cannot identify row"
-                    );
-                    continue;
-                }
+        fn query_prepared(
+            &self,
+            _cx: &Cx,
+            _stmt: &sqlmodel_core::connection::PreparedStatement,
+            _params: &[Value],
+        ) -> impl Future<Output = Outcome<Vec<Row>, Error>> + Send {
+            async { Outcome::Ok(vec![]) }
+        }
 
-                // Copy needed metadata so we can mutate the identity map after the DB op.
-                let pk_columns = tracked.pk_columns.clone();
-                let pk_values = tracked.pk_values.clone();
-                let table_name = tracked.table_name;
-                let relationships = tracked.relationships;
+        fn execute_prepared(
+            &self,
+            _cx: &Cx,
+            _stmt: &sqlmodel_core::connection::PreparedStatement,
+            _params: &[Value],
+        ) -> impl Future<Output = Outcome<u64, Error>> + Send {
+            async { Outcome::Ok(0) }
+        }
 
-                // Build WHERE clause from primary key columns and values
-                let where_parts: Vec<String> = pk_columns
-                    .iter()
-                    .enumerate()
-                    .map(|(i, col)| {
-                        format!(
-                            "{} = {}",
-                            dialect.quote_identifier(col),
-                            dialect.placeholder(i + 1)
-                        )
-                    })
-                    .collect();
+        fn ping(&self, _cx: &Cx) -> impl Future<Output = Outcome<(), Error>> + Send {
+            async { Outcome::Ok(()) }
+        }
 
-                let sql = format!(
-                    "DELETE FROM {} WHERE {}",
-                    dialect.quote_identifier(table_name),
-                    where_parts.join(" AND ")
-                );
+        fn close(self, _cx: &Cx) -> impl Future<Output = sqlmodel_core::Result<()>> + Send {
+            async { Ok(()) }
+        }
+    }
 
-                match self.connection.execute(cx, &sql, &pk_values).await {
-                    Outcome::Ok(_) => {
-                        actually_deleted.push(*key);
-
-                        // PassiveDeletes::Passive orphan tracking: the DB will delete children,
-                        // so eagerly detach them from the identity map after the parent delete succeeds.
-                        if !pk_values.is_empty() {
-                            let mut to_remove: Vec<ObjectKey> = Vec::new();
-                            for rel in relationships {
-                                if !rel.cascade_delete
-                                    || !matches!(
-                                        rel.passive_deletes,
-                                        sqlmodel_core::PassiveDeletes::Passive
-                                    )
-                                {
-                                    continue;
-                                }
-                                if !matches!(
-                                    rel.kind,
-                                    sqlmodel_core::RelationshipKind::OneToMany
-                                        | sqlmodel_core::RelationshipKind::OneToOne
-                                ) {
-                                    continue;
-                                }
-
-                                let fk_cols = rel.remote_key_cols();
-                                if fk_cols.is_empty() || fk_cols.len() != pk_values.len() {
-                                    continue;
-                                }
-
-                                for (k, t) in &self.identity_map {
-                                    if t.table_name != rel.related_table {
-                                        continue;
-                                    }
-                                    let mut matches_parent = true;
-                                    for (fk_col, parent_val) in fk_cols.iter().zip(&pk_values) {
-                                        let Some(idx) =
-                                            t.column_names.iter().position(|col| col == fk_col)
-                                        else {
-                                            matches_parent = false;
-                                            break;
-                                        };
-                                        if &t.values[idx] != parent_val {
-                                            matches_parent = false;
-                                            break;
-                                        }
-                                    }
-                                    if matches_parent {
-                                        to_remove.push(*k);
-                                    }
-                                }
-                            }
+    struct MockTransaction;
 
-                            for k in &to_remove {
-                                self.identity_map.remove(k);
-                            }
-                            self.pending_new.retain(|k| !to_remove.contains(k));
-                            self.pending_dirty.retain(|k| !to_remove.contains(k));
-                            self.pending_delete.retain(|k| !to_remove.contains(k));
-                        }
-                    }
-                    Outcome::Err(e) => {
-                        // Only restore deletes that weren't already executed
-                        // (exclude actually_deleted items from restoration)
-                        self.pending_delete = deletes
-                            .into_iter()
-                            .filter(|k| !actually_deleted.contains(k))
-                            .collect();
-                        // Remove successfully deleted objects before returning error
-                        for key in &actually_deleted {
-                            self.identity_map.remove(key);
-                        }
-                        return Outcome::Err(e);
-                    }
-                    Outcome::Cancelled(r) => {
-                        // Same handling for cancellation
-                        self.pending_delete = deletes
-                            .into_iter()
-                            .filter(|k| !actually_deleted.contains(k))
-                            .collect();
-                        for key in &actually_deleted {
-                            self.identity_map.remove(key);
-                        }
-                        return Outcome::Cancelled(r);
-                    }
-                    Outcome::Panicked(p) => {
-                        // Same handling for panic
-                        self.pending_delete = deletes
-                            .into_iter()
-                            .filter(|k| !actually_deleted.contains(k))
-                            .collect();
-                        for key in &actually_deleted {
-                            self.identity_map.remove(key);
-                        }
-                        return Outcome::Panicked(p);
-                    }
-                }
-            }
+    impl sqlmodel_core::connection::TransactionOps for MockTransaction {
+        fn query(
+            &self,
+            _cx: &Cx,
+            _sql: &str,
+            _params: &[Value],
+        ) -> impl Future<Output = Outcome<Vec<Row>, Error>> + Send {
+            async { Outcome::Ok(vec![]) }
         }
 
-        // Remove only actually deleted objects from identity map
-        for key in &actually_deleted {
-            self.identity_map.remove(key);
+        fn query_one(
+            &self,
+            _cx: &Cx,
+            _sql: &str,
+            _params: &[Value],
+        ) -> impl Future<Output = Outcome<Option<Row>, Error>> + Send {
+            async { Outcome::Ok(None) }
         }
 
-        // 2. Execute INSERTs
-        let inserts: Vec<ObjectKey> = std::mem::take(&mut self.pending_new);
-        for key in &inserts {
-            if let Some(tracked) = self.identity_map.get_mut(key) {
-                // Skip if already persistent (was inserted in a previous attempt before error)
-                if tracked.state == ObjectState::Persistent {
-                    continue;
-                }
+        fn execute(
+            &self,
+            _cx: &Cx,
+            _sql: &str,
+            _params: &[Value],
+        ) -> impl Future<Output = Outcome<u64, Error>> + Send {
+            async { Outcome::Ok(0) }
+        }
 
-                // Build INSERT statement using stored column names and values
-                let columns = &tracked.column_names;
-                let columns_sql: Vec<String> = columns
-                    .iter()
-                    .map(|c| dialect.quote_identifier(c))
-                    .collect();
-                let placeholders: Vec<String> = (1..=columns.len())
-                    .map(|i| dialect.placeholder(i))
-                    .collect();
+        fn savepoint(
+            &self,
+            _cx: &Cx,
+            _name: &str,
+        ) -> impl Future<Output = Outcome<(), Error>> + Send {
+            async { Outcome::Ok(()) }
+        }
 
-                let sql = format!(
-                    "INSERT INTO {} ({}) VALUES ({})",
-                    dialect.quote_identifier(tracked.table_name),
-                    columns_sql.join(", "),
-                    placeholders.join(", ")
-                );
+        fn rollback_to(
+            &self,
+            _cx: &Cx,
+            _name: &str,
+        ) -> impl Future<Output = Outcome<(), Error>> + Send {
+            async { Outcome::Ok(()) }
+        }
 
-                match self.connection.execute(cx, &sql, &tracked.values).await {
-                    Outcome::Ok(_) => {
-                        tracked.state = ObjectState::Persistent;
-                        // Set original_state for future dirty checking (serialize current values)
-                        tracked.original_state =
-                            Some(serde_json::to_vec(&tracked.values).unwrap_or_default());
-                    }
-                    Outcome::Err(e) => {
-                        // Restore pending_new for retry
-                        self.pending_new = inserts;
-                        return Outcome::Err(e);
-                    }
-                    Outcome::Cancelled(r) => {
-                        // Restore pending_new for retry (same as Err handling)
-                        self.pending_new = inserts;
-                        return Outcome::Cancelled(r);
-                    }
-                    Outcome::Panicked(p) => {
-                        // Restore pending_new for retry (same as Err handling)
-                        self.pending_new = inserts;
-                        return Outcome::Panicked(p);
-                    }
-                }
-            }
+        fn release(
+            &self,
+            _cx: &Cx,
+            _name: &str,
+        ) -> impl Future<Output = Outcome<(), Error>> + Send {
+            async { Outcome::Ok(()) }
         }
 
-        // 3. Execute UPDATEs for dirty objects
-        let dirty: Vec<ObjectKey> = std::mem::take(&mut self.pending_dirty);
-        for key in &dirty {
-            if let Some(tracked) = self.identity_map.get_mut(key) {
-                // Only UPDATE persistent objects
-                if tracked.state != ObjectState::Persistent {
-                    continue;
-                }
+        fn commit(self, _cx: &Cx) -> impl Future<Output = Outcome<(), Error>> + Send {
+            async { Outcome::Ok(()) }
+        }
 
-                // Skip objects without primary keys - cannot safely UPDATE without WHERE clause
-                if tracked.pk_columns.is_empty() || tracked.pk_values.is_empty() {
-                    tracing::warn!(
-                        table = tracked.table_name,
-                        "Skipping UPDATE for object without primary key - cannot identify row"
-                    );
-                    continue;
-                }
+        fn rollback(self, _cx: &Cx) -> impl Future<Output = Outcome<(), Error>> + Send {
+            async { Outcome::Ok(()) }
+        }
+    }
 
-                // Check if actually dirty by comparing serialized state
-                let current_state = serde_json::to_vec(&tracked.values).unwrap_or_default();
-                let is_dirty = tracked.original_state.as_ref() != Some(&current_state);
+    #[test]
+    fn test_load_many_single_query_and_populates_lazy() {
+        let rt = RuntimeBuilder::current_thread()
+            .build()
+            .expect("create asupersync runtime");
+        let cx = Cx::for_testing();
 
-                if !is_dirty {
-                    continue;
-                }
+        let state = Arc::new(Mutex::new(MockState::default()));
+        let conn = MockConnection::new(Arc::clone(&state));
+        let mut session = Session::new(conn);
 
-                // Build UPDATE statement with all non-PK columns
-                let mut set_parts = Vec::new();
-                let mut params = Vec::new();
-                let mut param_idx = 1;
-
-                for (i, col) in tracked.column_names.iter().enumerate() {
-                    // Skip primary key columns in SET clause
-                    if !tracked.pk_columns.contains(col) {
-                        set_parts.push(format!(
-                            "{} = {}",
-                            dialect.quote_identifier(col),
-                            dialect.placeholder(param_idx)
-                        ));
-                        params.push(tracked.values[i].clone());
-                        param_idx += 1;
-                    }
-                }
+        let heroes = vec![
+            Hero {
+                id: Some(1),
+                team: Lazy::from_fk(1_i64),
+            },
+            Hero {
+                id: Some(2),
+                team: Lazy::from_fk(2_i64),
+            },
+            Hero {
+                id: Some(3),
+                team: Lazy::from_fk(1_i64),
+            },
+            Hero {
+                id: Some(4),
+                team: Lazy::empty(),
+            },
+            Hero {
+                id: Some(5),
+                team: Lazy::from_fk(999_i64),
+            },
+        ];
 
-                // Add WHERE clause for primary key
-                let where_parts: Vec<String> = tracked
-                    .pk_columns
-                    .iter()
-                    .map(|col| {
-                        let clause = format!(
-                            "{} = {}",
-                            dialect.quote_identifier(col),
-                            dialect.placeholder(param_idx)
-                        );
-                        param_idx += 1;
-                        clause
-                    })
-                    .collect();
+        rt.block_on(async {
+            let loaded = unwrap_outcome(
+                session
+                    .load_many::<Hero, Team, _>(&cx, &heroes, |h| &h.team)
+                    .await,
+            );
+            assert_eq!(loaded, 3);
 
-                // Add PK values to params
-                params.extend(tracked.pk_values.clone());
+            // Populated / cached
+            assert!(heroes[0].team.is_loaded());
+            assert_eq!(heroes[0].team.get().unwrap().name, "Avengers");
+            assert_eq!(heroes[1].team.get().unwrap().name, "X-Men");
+            assert_eq!(heroes[2].team.get().unwrap().name, "Avengers");
 
-                if set_parts.is_empty() {
-                    continue; // No non-PK columns to update
-                }
+            // Empty FK gets cached as loaded-none
+            assert!(heroes[3].team.is_loaded());
+            assert!(heroes[3].team.get().is_none());
 
-                let sql = format!(
-                    "UPDATE {} SET {} WHERE {}",
-                    dialect.quote_identifier(tracked.table_name),
-                    set_parts.join(", "),
-                    where_parts.join(" AND ")
-                );
+            // Missing object gets cached as loaded-none
+            assert!(heroes[4].team.is_loaded());
+            assert!(heroes[4].team.get().is_none());
 
-                match self.connection.execute(cx, &sql, &params).await {
-                    Outcome::Ok(_) => {
-                        // Update original_state to current state
-                        tracked.original_state = Some(current_state);
-                    }
-                    Outcome::Err(e) => {
-                        // Restore pending_dirty for retry
-                        self.pending_dirty = dirty;
-                        return Outcome::Err(e);
-                    }
-                    Outcome::Cancelled(r) => {
-                        // Restore pending_dirty for retry (same as Err handling)
-                        self.pending_dirty = dirty;
-                        return Outcome::Cancelled(r);
-                    }
-                    Outcome::Panicked(p) => {
-                        // Restore pending_dirty for retry (same as Err handling)
-                        self.pending_dirty = dirty;
-                        return Outcome::Panicked(p);
-                    }
-                }
-            }
-        }
+            // Identity map populated: get() should not hit the connection again
+            let team1 = unwrap_outcome(session.get::<Team>(&cx, 1_i64).await);
+            assert_eq!(
+                team1,
+                Some(Team {
+                    id: Some(1),
+                    name: "Avengers".to_string()
+                })
+            );
+        });
 
-        // Fire after_flush event
-        if let Err(e) = self.event_callbacks.fire(SessionEvent::AfterFlush) {
-            return Outcome::Err(e);
-        }
+        assert_eq!(state.lock().expect("lock poisoned").query_calls, 1);
+    }
 
-        Outcome::Ok(())
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    struct HeroChild {
+        id: Option<i64>,
+        team_id: i64,
     }
 
-    /// Commit the current transaction.
-    pub async fn commit(&mut self, cx: &Cx) -> Outcome<(), Error> {
-        // Flush any pending changes first
-        match self.flush(cx).await {
-            Outcome::Ok(()) => {}
-            Outcome::Err(e) => return Outcome::Err(e),
-            Outcome::Cancelled(r) => return Outcome::Cancelled(r),
-            Outcome::Panicked(p) => return Outcome::Panicked(p),
-        }
+    impl Model for HeroChild {
+        const TABLE_NAME: &'static str = "heroes";
+        const PRIMARY_KEY: &'static [&'static str] = &["id"];
 
-        // Fire before_commit event (can abort)
-        if let Err(e) = self.event_callbacks.fire(SessionEvent::BeforeCommit) {
-            return Outcome::Err(e);
+        fn fields() -> &'static [sqlmodel_core::FieldInfo] {
+            &[]
         }
 
-        if self.in_transaction {
-            match self.connection.execute(cx, "COMMIT", &[]).await {
-                Outcome::Ok(_) => {
-                    self.in_transaction = false;
-                }
-                Outcome::Err(e) => return Outcome::Err(e),
-                Outcome::Cancelled(r) => return Outcome::Cancelled(r),
-                Outcome::Panicked(p) => return Outcome::Panicked(p),
-            }
+        fn to_row(&self) -> Vec<(&'static str, Value)> {
+            vec![
+                ("id", self.id.map_or(Value::Null, Value::BigInt)),
+                ("team_id", Value::BigInt(self.team_id)),
+            ]
         }
 
-        // Expire objects if configured
-        if self.config.expire_on_commit {
-            for tracked in self.identity_map.values_mut() {
-                if tracked.state == ObjectState::Persistent {
-                    tracked.state = ObjectState::Expired;
-                }
-            }
+        fn from_row(row: &Row) -> sqlmodel_core::Result<Self> {
+            let id: i64 = row.get_named("id")?;
+            let team_id: i64 = row.get_named("team_id")?;
+            Ok(Self {
+                id: Some(id),
+                team_id,
+            })
         }
 
-        // Fire after_commit event
-        if let Err(e) = self.event_callbacks.fire(SessionEvent::AfterCommit) {
-            return Outcome::Err(e);
+        fn primary_key_value(&self) -> Vec<Value> {
+            self.id
+                .map_or_else(|| vec![Value::Null], |id| vec![Value::BigInt(id)])
         }
 
-        Outcome::Ok(())
+        fn is_new(&self) -> bool {
+            self.id.is_none()
+        }
     }
 
-    /// Rollback the current transaction.
-    pub async fn rollback(&mut self, cx: &Cx) -> Outcome<(), Error> {
-        if self.in_transaction {
-            match self.connection.execute(cx, "ROLLBACK", &[]).await {
-                Outcome::Ok(_) => {
-                    self.in_transaction = false;
-                }
-                Outcome::Err(e) => return Outcome::Err(e),
-                Outcome::Cancelled(r) => return Outcome::Cancelled(r),
-                Outcome::Panicked(p) => return Outcome::Panicked(p),
-            }
-        }
+    /// Hero model whose `team_id` is declared unique, for `get_by` tests.
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    struct HeroByTeam {
+        id: Option<i64>,
+        team_id: i64,
+    }
 
-        // Clear pending operations
-        self.pending_new.clear();
-        self.pending_delete.clear();
-        self.pending_dirty.clear();
+    impl Model for HeroByTeam {
+        const TABLE_NAME: &'static str = "heroes";
+        const PRIMARY_KEY: &'static [&'static str] = &["id"];
 
-        // Revert objects to original state or remove new ones
-        let mut to_remove = Vec::new();
-        for (key, tracked) in &mut self.identity_map {
-            match tracked.state {
-                ObjectState::New => {
-                    to_remove.push(*key);
-                }
-                ObjectState::Deleted => {
-                    tracked.state = ObjectState::Persistent;
-                }
-                _ => {}
-            }
+        fn fields() -> &'static [sqlmodel_core::FieldInfo] {
+            const FIELDS: &[sqlmodel_core::FieldInfo] = &[
+                sqlmodel_core::FieldInfo::new("id", "id", sqlmodel_core::SqlType::BigInt)
+                    .primary_key(true),
+                sqlmodel_core::FieldInfo::new("team_id", "team_id", sqlmodel_core::SqlType::BigInt)
+                    .unique(true),
+                sqlmodel_core::FieldInfo::new("nickname", "nickname", sqlmodel_core::SqlType::Text),
+            ];
+            FIELDS
         }
 
-        for key in to_remove {
-            self.identity_map.remove(&key);
+        fn to_row(&self) -> Vec<(&'static str, Value)> {
+            vec![
+                ("id", self.id.map_or(Value::Null, Value::BigInt)),
+                ("team_id", Value::BigInt(self.team_id)),
+            ]
         }
 
-        // Fire after_rollback event
-        if let Err(e) = self.event_callbacks.fire(SessionEvent::AfterRollback) {
-            return Outcome::Err(e);
+        fn from_row(row: &Row) -> sqlmodel_core::Result<Self> {
+            let id: i64 = row.get_named("id")?;
+            let team_id: i64 = row.get_named("team_id")?;
+            Ok(Self {
+                id: Some(id),
+                team_id,
+            })
         }
 
-        Outcome::Ok(())
+        fn primary_key_value(&self) -> Vec<Value> {
+            self.id
+                .map_or_else(|| vec![Value::Null], |id| vec![Value::BigInt(id)])
+        }
+
+        fn is_new(&self) -> bool {
+            self.id.is_none()
+        }
     }
 
-    // ========================================================================
-    // Lazy Loading
-    // ========================================================================
+    #[test]
+    fn test_get_by_unique_column_registers_by_primary_key() {
+        let rt = RuntimeBuilder::current_thread()
+            .build()
+            .expect("create asupersync runtime");
+        let cx = Cx::for_testing();
 
-    /// Load a single lazy relationship.
-    ///
-    /// Fetches the related object from the database and caches it in the Lazy wrapper.
-    /// If the relationship has already been loaded, returns the cached value.
-    ///
-    /// # Example
-    ///
-    /// ```ignore
-    /// session.load_lazy(&hero.team, &cx).await?;
-    /// let team = hero.team.get(); // Now available
-    /// ```
-    #[tracing::instrument(level = "debug", skip(self, lazy, cx))]
-    pub async fn load_lazy<
-        T: Model + Clone + Send + Sync + Serialize + for<'de> Deserialize<'de> + 'static,
-    >(
-        &mut self,
-        lazy: &Lazy<T>,
-        cx: &Cx,
-    ) -> Outcome<bool, Error> {
-        tracing::debug!(
-            model = std::any::type_name::<T>(),
-            fk = ?lazy.fk(),
-            already_loaded = lazy.is_loaded(),
-            "Loading lazy relationship"
-        );
+        let state = Arc::new(Mutex::new(MockState::default()));
+        let conn = MockConnection::new(Arc::clone(&state));
+        let mut session = Session::new(conn);
 
-        // If already loaded, return success
-        if lazy.is_loaded() {
-            tracing::trace!("Already loaded");
-            return Outcome::Ok(lazy.get().is_some());
-        }
+        rt.block_on(async {
+            let hero = unwrap_outcome(
+                session
+                    .get_by::<HeroByTeam>(&cx, "team_id", Value::BigInt(2))
+                    .await,
+            )
+            .expect("hero found");
+            assert_eq!(hero.id, Some(201));
 
-        // If no FK, set as empty and return
-        let Some(fk) = lazy.fk() else {
-            let _ = lazy.set_loaded(None);
-            return Outcome::Ok(false);
-        };
+            // Now tracked under its primary key: a PK lookup hits the identity map.
+            let by_pk = unwrap_outcome(session.get::<HeroByTeam>(&cx, 201_i64).await);
+            assert!(by_pk.is_some());
+        });
 
-        // Fetch from database using get()
-        let obj = match self.get::<T>(cx, fk.clone()).await {
-            Outcome::Ok(obj) => obj,
-            Outcome::Err(e) => return Outcome::Err(e),
-            Outcome::Cancelled(r) => return Outcome::Cancelled(r),
-            Outcome::Panicked(p) => return Outcome::Panicked(p),
-        };
+        let guard = state.lock().expect("lock poisoned");
+        assert_eq!(guard.query_calls, 1);
+        assert_eq!(
+            guard.last_sql.as_deref(),
+            Some("SELECT * FROM \"heroes\" WHERE \"team_id\" = $1 LIMIT 2")
+        );
+    }
 
-        let found = obj.is_some();
+    #[test]
+    fn test_get_by_reloads_detached_object() {
+        let rt = RuntimeBuilder::current_thread()
+            .build()
+            .expect("create asupersync runtime");
+        let cx = Cx::for_testing();
 
-        // Cache the result
-        let _ = lazy.set_loaded(obj);
+        let state = Arc::new(Mutex::new(MockState::default()));
+        let conn = MockConnection::new(Arc::clone(&state));
+        let mut session = Session::new(conn);
 
-        tracing::debug!(found = found, "Lazy load complete");
+        rt.block_on(async {
+            let hero = unwrap_outcome(
+                session
+                    .get_by::<HeroByTeam>(&cx, "team_id", Value::BigInt(2))
+                    .await,
+            )
+            .expect("hero found");
+            session.expunge(&hero);
 
-        Outcome::Ok(found)
+            // The row still exists: a detached object is reloaded, not reported missing.
+            let again = unwrap_outcome(
+                session
+                    .get_by::<HeroByTeam>(&cx, "team_id", Value::BigInt(2))
+                    .await,
+            )
+            .expect("hero found");
+            assert_eq!(session.object_state(&again), Some(ObjectState::Persistent));
+        });
+        assert_eq!(state.lock().expect("lock poisoned").query_calls, 2);
     }
 
-    /// Batch load lazy relationships for multiple objects.
-    ///
-    /// This method collects all FK values, executes a single query, and populates
-    /// each Lazy field. This prevents the N+1 query problem when iterating over
-    /// a collection and accessing lazy relationships.
-    ///
-    /// # Example
-    ///
-    /// ```ignore
-    /// // Load 100 heroes
-    /// let mut heroes = session.query::<Hero>().all().await?;
-    ///
-    /// // Without batch loading: 100 queries (N+1 problem)
-    /// // With batch loading: 1 query
-    /// session.load_many(&cx, &mut heroes, |h| &h.team).await?;
-    ///
-    /// // All teams now loaded
-    /// for hero in &heroes {
-    ///     if let Some(team) = hero.team.get() {
-    ///         println!("{} is on {}", hero.name, team.name);
-    ///     }
-    /// }
-    /// ```
-    #[tracing::instrument(level = "debug", skip(self, cx, objects, accessor))]
-    pub async fn load_many<P, T, F>(
-        &mut self,
-        cx: &Cx,
-        objects: &[P],
-        accessor: F,
-    ) -> Outcome<usize, Error>
-    where
-        P: Model + 'static,
-        T: Model + Clone + Send + Sync + Serialize + for<'de> Deserialize<'de> + 'static,
-        F: Fn(&P) -> &Lazy<T>,
-    {
-        // Collect all FK values that need loading
-        let mut fk_values: Vec<Value> = Vec::new();
-        let mut fk_indices: Vec<usize> = Vec::new();
+    #[test]
+    fn test_get_by_errors_when_multiple_rows_match() {
+        let rt = RuntimeBuilder::current_thread()
+            .build()
+            .expect("create asupersync runtime");
+        let cx = Cx::for_testing();
 
-        for (idx, obj) in objects.iter().enumerate() {
-            let lazy = accessor(obj);
-            if !lazy.is_loaded() && !lazy.is_empty() {
-                if let Some(fk) = lazy.fk() {
-                    fk_values.push(fk.clone());
-                    fk_indices.push(idx);
-                }
-            }
-        }
+        let state = Arc::new(Mutex::new(MockState::default()));
+        let conn = MockConnection::new(Arc::clone(&state));
+        let mut session = Session::new(conn);
 
-        let fk_count = fk_values.len();
-        tracing::info!(
-            parent_model = std::any::type_name::<P>(),
-            related_model = std::any::type_name::<T>(),
-            parent_count = objects.len(),
-            fk_count = fk_count,
-            "Batch loading lazy relationships"
-        );
+        rt.block_on(async {
+            let outcome = session
+                .get_by::<HeroByTeam>(&cx, "team_id", Value::BigInt(1))
+                .await;
+            assert!(matches!(outcome, Outcome::Err(Error::Custom(_))));
+        });
+        assert_eq!(session.tracked_count(), 0);
+    }
 
-        if fk_values.is_empty() {
-            // Nothing to load - mark all empty/loaded Lazy fields
-            for obj in objects {
-                let lazy = accessor(obj);
-                if !lazy.is_loaded() && lazy.is_empty() {
-                    let _ = lazy.set_loaded(None);
-                }
+    #[test]
+    fn test_get_by_rejects_non_unique_or_unknown_column() {
+        let rt = RuntimeBuilder::current_thread()
+            .build()
+            .expect("create asupersync runtime");
+        let cx = Cx::for_testing();
+
+        let state = Arc::new(Mutex::new(MockState::default()));
+        let conn = MockConnection::new(Arc::clone(&state));
+        let mut session = Session::new(conn);
+
+        rt.block_on(async {
+            for column in ["nickname", "missing"] {
+                let outcome = session
+                    .get_by::<HeroByTeam>(&cx, column, Value::Text("x".into()))
+                    .await;
+                assert!(matches!(outcome, Outcome::Err(Error::Custom(_))));
             }
-            return Outcome::Ok(0);
-        }
+        });
+        assert_eq!(state.lock().expect("lock poisoned").query_calls, 0);
+    }
 
-        // Build query with IN clause (dialect-correct placeholders/quoting).
-        let dialect = self.connection.dialect();
-        let pk_col = T::PRIMARY_KEY.first().unwrap_or(&"id");
-        let placeholders: Vec<String> = (1..=fk_values.len())
-            .map(|i| dialect.placeholder(i))
-            .collect();
-        let sql = format!(
-            "SELECT * FROM {} WHERE {} IN ({})",
-            dialect.quote_identifier(T::TABLE_NAME),
-            dialect.quote_identifier(pk_col),
-            placeholders.join(", ")
-        );
+    #[test]
+    fn test_get_or_create() {
+        let rt = RuntimeBuilder::current_thread()
+            .build()
+            .expect("create asupersync runtime");
+        let cx = Cx::for_testing();
+
+        // Found: returned as-is inside a fresh transaction, nothing inserted.
+        let state = Arc::new(Mutex::new(MockState::default()));
+        let mut session = Session::new(MockConnection::new(Arc::clone(&state)));
+        let avengers = Team {
+            id: Some(1),
+            name: "Avengers".to_string(),
+        };
+        let (team, created) = rt.block_on(async {
+            unwrap_outcome(
+                session
+                    .get_or_create(&cx, &[("id", Value::BigInt(1))], avengers)
+                    .await,
+            )
+        });
+        assert!(!created);
+        assert_eq!(team.name, "Avengers");
+        assert!(session.in_transaction());
+        assert!(session.contains(&team));
+        {
+            let guard = state.lock().expect("lock poisoned");
+            assert_eq!(guard.executed.len(), 1);
+            assert_eq!(
+                guard.last_sql.as_deref(),
+                Some("SELECT * FROM \"teams\" WHERE \"id\" = $1 LIMIT 2")
+            );
+        }
 
-        let rows = match self.connection.query(cx, &sql, &fk_values).await {
-            Outcome::Ok(rows) => rows,
-            Outcome::Err(e) => return Outcome::Err(e),
-            Outcome::Cancelled(r) => return Outcome::Cancelled(r),
-            Outcome::Panicked(p) => return Outcome::Panicked(p),
+        // Missing on SQLite: `defaults` is staged for the next flush.
+        let state = Arc::new(Mutex::new(MockState::default()));
+        let mut session = Session::new(MockConnection {
+            state: Arc::clone(&state),
+            dialect: sqlmodel_core::Dialect::Sqlite,
+        });
+        let hulks = Team {
+            id: None,
+            name: "Hulks".to_string(),
         };
+        let (team, created) = rt.block_on(async {
+            unwrap_outcome(
+                session
+                    .get_or_create(&cx, &[("name", Value::Text("Hulks".into()))], hulks)
+                    .await,
+            )
+        });
+        assert!(created);
+        assert_eq!(team.name, "Hulks");
+        assert_eq!(session.pending_new_count(), 1);
 
-        // Convert rows to objects and build PK hash -> object lookup
-        let mut lookup: HashMap<u64, T> = HashMap::new();
-        for row in &rows {
-            match T::from_row(row) {
-                Ok(obj) => {
-                    let pk_values = obj.primary_key_value();
-                    let pk_hash = hash_values(&pk_values);
+        // Missing on Postgres: inserted at once with ON CONFLICT DO NOTHING.
+        let state = Arc::new(Mutex::new(MockState::default()));
+        let mut session = Session::new(MockConnection::new(Arc::clone(&state)));
+        let hulks = Team {
+            id: None,
+            name: "Hulks".to_string(),
+        };
+        rt.block_on(async {
+            // The mock's RETURNING yields no row and the re-lookup finds
+            // nothing either, which is reported rather than looping.
+            let outcome = session
+                .get_or_create(&cx, &[("name", Value::Text("Hulks".into()))], hulks)
+                .await;
+            assert!(matches!(outcome, Outcome::Err(Error::Custom(_))));
+        });
+        let guard = state.lock().expect("lock poisoned");
+        assert_eq!(guard.query_calls, 2);
+        assert_eq!(guard.query_one_sql.len(), 1);
+        assert!(guard.query_one_sql[0].starts_with("INSERT INTO teams"));
+        assert!(guard.query_one_sql[0].ends_with(" ON CONFLICT DO NOTHING RETURNING *"));
+        drop(guard);
+        assert_eq!(session.pending_new_count(), 0);
+    }
 
-                    // Add to session identity map
-                    let key = ObjectKey::from_pk::<T>(&pk_values);
+    /// A team without `Default`: absent columns come from the declared default.
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct TeamCard {
+        id: i64,
+        name: String,
+    }
 
-                    // Extract column data from the model while we have the concrete type
-                    let row_data = obj.to_row();
-                    let column_names: Vec<&'static str> =
-                        row_data.iter().map(|(name, _)| *name).collect();
-                    let values: Vec<Value> = row_data.into_iter().map(|(_, v)| v).collect();
+    impl Model for TeamCard {
+        const TABLE_NAME: &'static str = "teams";
+        const PRIMARY_KEY: &'static [&'static str] = &["id"];
 
-                    // Serialize values for dirty checking (must match format used in flush)
-                    let serialized = serde_json::to_vec(&values).ok();
+        fn fields() -> &'static [sqlmodel_core::FieldInfo] {
+            const FIELDS: &[sqlmodel_core::FieldInfo] = &[
+                sqlmodel_core::FieldInfo::new("id", "id", sqlmodel_core::SqlType::BigInt)
+                    .primary_key(true),
+                sqlmodel_core::FieldInfo::new("name", "name", sqlmodel_core::SqlType::Text)
+                    .default("'Unnamed'"),
+            ];
+            FIELDS
+        }
 
-                    let tracked = TrackedObject {
-                        object: Box::new(obj.clone()),
-                        original_state: serialized,
-                        state: ObjectState::Persistent,
-                        table_name: T::TABLE_NAME,
-                        column_names,
-                        values,
-                        pk_columns: T::PRIMARY_KEY.to_vec(),
-                        pk_values: pk_values.clone(),
-                        relationships: T::RELATIONSHIPS,
-                        expired_attributes: None,
-                    };
-                    self.identity_map.insert(key, tracked);
+        fn to_row(&self) -> Vec<(&'static str, Value)> {
+            vec![
+                ("id", Value::BigInt(self.id)),
+                ("name", Value::Text(self.name.clone())),
+            ]
+        }
 
-                    // Add to lookup
-                    lookup.insert(pk_hash, obj);
-                }
-                Err(_) => continue,
-            }
+        fn from_row(row: &Row) -> sqlmodel_core::Result<Self> {
+            Ok(Self {
+                id: row.get_named("id")?,
+                name: row.get_named("name")?,
+            })
         }
 
-        // Populate each Lazy field
-        let mut loaded_count = 0;
-        for obj in objects {
-            let lazy = accessor(obj);
-            if !lazy.is_loaded() {
-                if let Some(fk) = lazy.fk() {
-                    let fk_hash = hash_values(std::slice::from_ref(fk));
-                    let related = lookup.get(&fk_hash).cloned();
-                    let found = related.is_some();
-                    let _ = lazy.set_loaded(related);
-                    if found {
-                        loaded_count += 1;
-                    }
-                } else {
-                    let _ = lazy.set_loaded(None);
-                }
-            }
+        // What `#[derive(Model)]` generates: `name` falls back to its declared default.
+        fn from_partial_row(row: &Row) -> sqlmodel_core::Result<(Self, Vec<&'static str>)> {
+            let mut loaded = vec!["id"];
+            let name = if row.contains_column("name") {
+                loaded.push("name");
+                row.get_named("name")?
+            } else {
+                "Unnamed".to_string()
+            };
+            Ok((
+                Self {
+                    id: row.get_named("id")?,
+                    name,
+                },
+                loaded,
+            ))
         }
 
-        tracing::debug!(
-            query_count = 1,
-            loaded_count = loaded_count,
-            "Batch load complete"
-        );
+        fn primary_key_value(&self) -> Vec<Value> {
+            vec![Value::BigInt(self.id)]
+        }
 
-        Outcome::Ok(loaded_count)
+        fn is_new(&self) -> bool {
+            false
+        }
     }
 
-    /// Batch load many-to-many relationships for multiple parent objects.
-    ///
-    /// This method loads related objects via a link table in a single query,
-    /// avoiding the N+1 problem for many-to-many relationships.
-    ///
-    /// # Example
-    ///
-    /// ```ignore
-    /// // Load 100 heroes
-    /// let mut heroes = session.query::<Hero>().all().await?;
-    ///
-    /// // Without batch loading: 100 queries (N+1 problem)
-    /// // With batch loading: 1 query via JOIN
-    /// let link_info = LinkTableInfo::new("hero_powers", "hero_id", "power_id");
-    /// session.load_many_to_many(&cx, &mut heroes, |h| &mut h.powers, |h| h.id.unwrap(), &link_info).await?;
-    ///
-    /// // All powers now loaded
-    /// for hero in &heroes {
-    ///     if let Some(powers) = hero.powers.get() {
-    ///         println!("{} has {} powers", hero.name, powers.len());
-    ///     }
-    /// }
-    /// ```
-    #[tracing::instrument(level = "debug", skip(self, cx, objects, accessor, parent_pk))]
-    pub async fn load_many_to_many<P, Child, FA, FP>(
-        &mut self,
-        cx: &Cx,
-        objects: &mut [P],
-        accessor: FA,
-        parent_pk: FP,
-        link_table: &sqlmodel_core::LinkTableInfo,
-    ) -> Outcome<usize, Error>
-    where
-        P: Model + 'static,
-        Child: Model + Clone + Send + Sync + Serialize + for<'de> Deserialize<'de> + 'static,
-        FA: Fn(&mut P) -> &mut sqlmodel_core::RelatedMany<Child>,
-        FP: Fn(&P) -> Value,
-    {
-        self.load_many_to_many_pk(cx, objects, accessor, |p| vec![parent_pk(p)], link_table)
-            .await
-    }
+    #[test]
+    fn test_get_columns_loads_partial_object() {
+        let rt = RuntimeBuilder::current_thread()
+            .build()
+            .expect("create asupersync runtime");
+        let cx = Cx::for_testing();
 
-    /// Batch load many-to-many relationships for multiple parent objects using composite keys.
-    ///
-    /// This is the generalized form of `load_many_to_many` that supports composite parent and/or
-    /// child primary keys via `LinkTableInfo::composite(...)`.
-    #[tracing::instrument(level = "debug", skip(self, cx, objects, accessor, parent_pk))]
-    pub async fn load_many_to_many_pk<P, Child, FA, FP>(
-        &mut self,
-        cx: &Cx,
-        objects: &mut [P],
-        accessor: FA,
-        parent_pk: FP,
-        link_table: &sqlmodel_core::LinkTableInfo,
-    ) -> Outcome<usize, Error>
-    where
-        P: Model + 'static,
-        Child: Model + Clone + Send + Sync + Serialize + for<'de> Deserialize<'de> + 'static,
-        FA: Fn(&mut P) -> &mut sqlmodel_core::RelatedMany<Child>,
-        FP: Fn(&P) -> Vec<Value>,
-    {
-        // Collect all parent PK tuples.
-        let mut pk_tuples: Vec<Vec<Value>> = Vec::with_capacity(objects.len());
-        let mut pk_by_index: Vec<(usize, Vec<Value>)> = Vec::new();
-        for (idx, obj) in objects.iter().enumerate() {
-            let pk = parent_pk(obj);
-            pk_tuples.push(pk.clone());
-            pk_by_index.push((idx, pk));
-        }
+        let state = Arc::new(Mutex::new(MockState::default()));
+        let conn = MockConnection::new(Arc::clone(&state));
+        let mut session = Session::new(conn);
 
-        tracing::info!(
-            parent_model = std::any::type_name::<P>(),
-            related_model = std::any::type_name::<Child>(),
-            parent_count = pk_tuples.len(),
-            link_table = link_table.table_name,
-            "Batch loading many-to-many relationships"
-        );
+        rt.block_on(async {
+            let team: TeamCard =
+                unwrap_outcome(session.get_columns(&cx, &[Value::BigInt(1)], &["id"]).await)
+                    .expect("team found");
+            // `name` was not selected, so it holds the declared default.
+            assert_eq!(team.id, 1);
+            assert_eq!(team.name, "Unnamed");
+
+            let expired = session.expired_attributes(&team).expect("partially loaded");
+            let expired: Vec<&str> = expired
+                .expect("specific attributes")
+                .iter()
+                .map(String::as_str)
+                .collect();
+            assert_eq!(expired, ["name"]);
+            assert_eq!(
+                state.lock().expect("lock poisoned").last_sql.as_deref(),
+                Some("SELECT \"id\" FROM \"teams\" WHERE \"id\" = $1 LIMIT 1")
+            );
 
-        if pk_tuples.is_empty() {
-            return Outcome::Ok(0);
-        }
+            // A full get() reloads the unloaded columns.
+            let full: TeamCard = unwrap_outcome(session.get(&cx, 1_i64).await).expect("team found");
+            assert_eq!(full.name, "Avengers");
+            assert!(!session.is_expired(&full));
+        });
+    }
 
-        // Build query with JOIN through link table (dialect-correct placeholders/quoting):
-        // SELECT child.*, link.<local_cols...> as __parent_pk{N}
-        // FROM child
-        // JOIN link ON child.<pk_cols...> = link.<remote_cols...>
-        // WHERE link.<local_cols...> IN (...)
-        let dialect = self.connection.dialect();
-        let local_cols = link_table.local_cols();
-        let remote_cols = link_table.remote_cols();
-        if local_cols.is_empty() || remote_cols.is_empty() {
-            return Outcome::Err(Error::Custom(
-                "link_table must specify local/remote columns".to_string(),
-            ));
-        }
-        if remote_cols.len() != Child::PRIMARY_KEY.len() {
-            return Outcome::Err(Error::Custom(format!(
-                "link_table remote cols count ({}) must match child PRIMARY_KEY len ({})",
-                remote_cols.len(),
-                Child::PRIMARY_KEY.len()
-            )));
-        }
+    #[test]
+    fn test_get_columns_rejects_unknown_column() {
+        let rt = RuntimeBuilder::current_thread()
+            .build()
+            .expect("create asupersync runtime");
+        let cx = Cx::for_testing();
 
-        let child_table = dialect.quote_identifier(Child::TABLE_NAME);
-        let link_table_q = dialect.quote_identifier(link_table.table_name);
+        let state = Arc::new(Mutex::new(MockState::default()));
+        let conn = MockConnection::new(Arc::clone(&state));
+        let mut session = Session::new(conn);
 
-        let parent_select_parts: String = local_cols
-            .iter()
-            .enumerate()
-            .map(|(i, col)| {
-                format!(
-                    "{link_table_q}.{} AS __parent_pk{}",
-                    dialect.quote_identifier(col),
-                    i
-                )
-            })
-            .collect::<Vec<_>>()
-            .join(", ");
+        rt.block_on(async {
+            let outcome = session
+                .get_columns::<TeamCard>(&cx, &[Value::BigInt(1)], &["missing"])
+                .await;
+            assert!(matches!(outcome, Outcome::Err(Error::Custom(_))));
+        });
+        assert_eq!(state.lock().expect("lock poisoned").query_calls, 0);
+    }
 
-        let join_parts: String = remote_cols
-            .iter()
-            .zip(Child::PRIMARY_KEY.iter().copied())
-            .map(|(link_col, child_col)| {
-                format!(
-                    "{child_table}.{} = {link_table_q}.{}",
-                    dialect.quote_identifier(child_col),
-                    dialect.quote_identifier(link_col)
-                )
-            })
-            .collect::<Vec<_>>()
-            .join(" AND ");
+    #[test]
+    fn test_get_columns_matches_every_primary_key_column() {
+        let rt = RuntimeBuilder::current_thread()
+            .build()
+            .expect("create asupersync runtime");
+        let cx = Cx::for_testing();
 
-        let (where_sql, params) = if local_cols.len() == 1 {
-            let mut params: Vec<Value> = Vec::with_capacity(pk_tuples.len());
-            for t in &pk_tuples {
-                if let Some(v) = t.first() {
-                    params.push(v.clone());
-                }
-            }
-            let placeholders: Vec<String> =
-                (1..=params.len()).map(|i| dialect.placeholder(i)).collect();
-            let where_sql = format!(
-                "{link_table_q}.{} IN ({})",
-                dialect.quote_identifier(local_cols[0]),
-                placeholders.join(", ")
-            );
-            (where_sql, params)
-        } else {
-            let mut tuples: Vec<Vec<Value>> = Vec::with_capacity(pk_tuples.len());
-            for t in &pk_tuples {
-                if t.len() == local_cols.len() {
-                    tuples.push(t.clone());
-                }
-            }
+        let state = Arc::new(Mutex::new(MockState::default()));
+        let conn = MockConnection::new(Arc::clone(&state));
+        let mut session = Session::new(conn);
 
-            let mut params: Vec<Value> = Vec::with_capacity(tuples.len() * local_cols.len());
-            let mut idx = 1;
-            let tuple_sql: Vec<String> = tuples
-                .iter()
-                .map(|t| {
-                    for v in t {
-                        params.push(v.clone());
-                    }
-                    let inner = (0..local_cols.len())
-                        .map(|_| {
-                            let ph = dialect.placeholder(idx);
-                            idx += 1;
-                            ph
-                        })
-                        .collect::<Vec<_>>()
-                        .join(", ");
-                    format!("({})", inner)
-                })
-                .collect();
+        rt.block_on(async {
+            let pk = [Value::BigInt(5), Value::BigInt(6)];
+            let team = unwrap_outcome(session.get_columns::<TeamComposite>(&cx, &pk, &[]).await);
+            assert!(team.is_none());
+            assert_eq!(
+                state.lock().expect("lock poisoned").last_sql.as_deref(),
+                Some(
+                    "SELECT \"id1\", \"id2\" FROM \"teams_composite\" \
+                     WHERE \"id1\" = $1 AND \"id2\" = $2 LIMIT 1"
+                )
+            );
 
-            let col_list = local_cols
-                .iter()
-                .map(|c| format!("{link_table_q}.{}", dialect.quote_identifier(c)))
-                .collect::<Vec<_>>()
-                .join(", ");
+            let outcome = session
+                .get_columns::<TeamComposite>(&cx, &[Value::BigInt(5)], &[])
+                .await;
+            assert!(matches!(outcome, Outcome::Err(Error::Custom(_))));
+        });
+    }
 
-            let where_sql = format!("({}) IN ({})", col_list, tuple_sql.join(", "));
-            (where_sql, params)
-        };
+    #[test]
+    fn test_get_columns_reloads_detached_object() {
+        let rt = RuntimeBuilder::current_thread()
+            .build()
+            .expect("create asupersync runtime");
+        let cx = Cx::for_testing();
 
-        let sql = format!(
-            "SELECT {child_table}.*, {parent_select_parts} FROM {child_table} \
-             JOIN {link_table_q} ON {join_parts} \
-             WHERE {where_sql}"
-        );
+        let state = Arc::new(Mutex::new(MockState::default()));
+        let conn = MockConnection::new(Arc::clone(&state));
+        let mut session = Session::new(conn);
 
-        tracing::trace!(sql = %sql, "Many-to-many batch SQL");
+        rt.block_on(async {
+            let team: TeamCard = unwrap_outcome(session.get(&cx, 1_i64).await).expect("team found");
+            session.expunge(&team);
 
-        let rows = match self.connection.query(cx, &sql, &params).await {
-            Outcome::Ok(rows) => rows,
-            Outcome::Err(e) => return Outcome::Err(e),
-            Outcome::Cancelled(r) => return Outcome::Cancelled(r),
-            Outcome::Panicked(p) => return Outcome::Panicked(p),
-        };
+            let partial: Option<TeamCard> =
+                unwrap_outcome(session.get_columns(&cx, &[Value::BigInt(1)], &["id"]).await);
+            assert!(partial.is_some());
+        });
+        assert_eq!(state.lock().expect("lock poisoned").query_calls, 2);
+    }
 
-        // Group children by parent PK
-        let mut by_parent: HashMap<u64, Vec<Child>> = HashMap::new();
-        for row in &rows {
-            // Extract the parent PK tuple from the __parent_pk{N} aliases.
-            let mut parent_tuple: Vec<Value> = Vec::with_capacity(local_cols.len());
-            let mut missing = false;
-            for i in 0..local_cols.len() {
-                let col = format!("__parent_pk{}", i);
-                let Some(v) = row.get_by_name(&col) else {
-                    missing = true;
-                    break;
-                };
-                parent_tuple.push(v.clone());
-            }
-            if missing {
-                continue;
-            }
-            let parent_pk_hash = hash_values(&parent_tuple);
+    #[test]
+    fn test_paginate_returns_page_and_tracks_items() {
+        let rt = RuntimeBuilder::current_thread()
+            .build()
+            .expect("create asupersync runtime");
+        let cx = Cx::for_testing();
 
-            // Parse the child model
-            match Child::from_row(row) {
-                Ok(child) => {
-                    by_parent.entry(parent_pk_hash).or_default().push(child);
-                }
-                Err(_) => continue,
-            }
-        }
+        let state = Arc::new(Mutex::new(MockState::default()));
+        let conn = MockConnection::new(Arc::clone(&state));
+        let mut session = Session::new(conn);
 
-        // Populate each RelatedMany field
-        let mut loaded_count = 0;
-        for (idx, pk_tuple) in pk_by_index {
-            let pk_hash = hash_values(&pk_tuple);
-            // Don't `remove()` here: callers might pass the same parent more than once.
-            let children = by_parent.get(&pk_hash).cloned().unwrap_or_default();
-            let child_count = children.len();
+        let predicate = Expr::col("id").eq(1_i64).or(Expr::col("id").eq(2_i64));
+        let page = rt.block_on(async {
+            unwrap_outcome(session.paginate::<Team>(&cx, Some(&predicate), 1, 10).await)
+        });
 
-            let related = accessor(&mut objects[idx]);
-            if pk_tuple.len() == 1 {
-                related.set_parent_pk(pk_tuple[0].clone());
-            } else {
-                related.set_parent_pk(Value::Array(pk_tuple.clone()));
-            }
-            let _ = related.set_loaded(children);
-            loaded_count += child_count;
-        }
+        assert_eq!(page.items.len(), 2);
+        assert_eq!(page.items[1].name, "X-Men");
+        assert_eq!(page.total, 2);
+        assert_eq!(page.total_pages, 1);
+        assert!(!page.has_next());
+        assert!(!page.has_previous());
+        assert_eq!(session.tracked_count(), 2);
 
-        tracing::debug!(
-            query_count = 1,
-            total_children = loaded_count,
-            "Many-to-many batch load complete"
-        );
+        let sql = state
+            .lock()
+            .expect("lock poisoned")
+            .last_sql
+            .clone()
+            .expect("query issued");
+        assert!(sql.contains("COUNT(*) OVER ()"));
+        assert!(sql.ends_with("ORDER BY \"id\" LIMIT 10 OFFSET 0"));
 
-        Outcome::Ok(loaded_count)
+        rt.block_on(async {
+            let outcome = session.paginate::<Team>(&cx, None, 0, 10).await;
+            assert!(matches!(outcome, Outcome::Err(Error::Custom(_))));
+        });
     }
 
-    /// Batch load one-to-many relationships for multiple parent objects.
-    ///
-    /// This populates `RelatedMany<Child>` where the child table has a foreign key column pointing
-    /// back to the parent. It runs a single query:
-    ///
-    /// `SELECT *, <fk_col> AS __parent_pk FROM <child_table> WHERE <fk_col> IN (...)`
-    ///
-    /// and then groups results per parent PK to populate each `RelatedMany`.
-    #[tracing::instrument(level = "debug", skip(self, cx, objects, accessor, parent_pk))]
-    pub async fn load_one_to_many<P, Child, FA, FP>(
-        &mut self,
-        cx: &Cx,
-        objects: &mut [P],
-        accessor: FA,
-        parent_pk: FP,
-    ) -> Outcome<usize, Error>
-    where
-        P: Model + 'static,
-        Child: Model + Clone + Send + Sync + Serialize + for<'de> Deserialize<'de> + 'static,
-        FA: Fn(&mut P) -> &mut sqlmodel_core::RelatedMany<Child>,
-        FP: Fn(&P) -> Value,
-    {
-        // Collect parent PKs for objects that still need loading.
-        let mut pks: Vec<Value> = Vec::new();
-        let mut pk_by_index: Vec<(usize, Value)> = Vec::new();
-        for (idx, obj) in objects.iter_mut().enumerate() {
-            let pk = parent_pk(&*obj);
-            let related = accessor(obj);
-            if related.is_loaded() {
-                continue;
-            }
-
-            related.set_parent_pk(pk.clone());
+    #[test]
+    fn test_exists_probes_without_tracking() {
+        let rt = RuntimeBuilder::current_thread()
+            .build()
+            .expect("create asupersync runtime");
+        let cx = Cx::for_testing();
 
-            if matches!(pk, Value::Null) {
-                // Unsaved parent: empty collection, mark loaded.
-                let _ = related.set_loaded(Vec::new());
-                continue;
-            }
+        let state = Arc::new(Mutex::new(MockState::default()));
+        let conn = MockConnection::new(Arc::clone(&state));
+        let mut session = Session::new(conn);
 
-            pks.push(pk.clone());
-            pk_by_index.push((idx, pk));
+        rt.block_on(async {
+            let found = session
+                .exists::<Team>(&cx, &Expr::col("id").eq(2_i64))
+                .await;
+            assert!(unwrap_outcome(found));
+            let missing = session.exists_by_pk::<Team>(&cx, &[Value::BigInt(3)]).await;
+            assert!(!unwrap_outcome(missing));
+        });
+        assert_eq!(session.tracked_count(), 0);
+        {
+            let guard = state.lock().expect("lock poisoned");
+            assert_eq!(guard.query_calls, 2);
+            assert_eq!(
+                guard.last_sql.as_deref(),
+                Some("SELECT 1 FROM \"teams\" WHERE \"id\" = $1 LIMIT 1")
+            );
         }
 
-        tracing::info!(
-            parent_model = std::any::type_name::<P>(),
-            related_model = std::any::type_name::<Child>(),
-            parent_count = objects.len(),
-            query_parent_count = pks.len(),
-            "Batch loading one-to-many relationships"
-        );
+        // Tracked objects answer from the identity map.
+        session.add(&Team {
+            id: Some(9),
+            name: "Defenders".into(),
+        });
+        rt.block_on(async {
+            let tracked = session.exists_by_pk::<Team>(&cx, &[Value::BigInt(9)]).await;
+            assert!(unwrap_outcome(tracked));
+        });
+        assert_eq!(state.lock().expect("lock poisoned").query_calls, 2);
+    }
 
-        if pks.is_empty() {
-            return Outcome::Ok(0);
-        }
+    #[test]
+    fn test_count_and_count_distinct() {
+        let rt = RuntimeBuilder::current_thread()
+            .build()
+            .expect("create asupersync runtime");
+        let cx = Cx::for_testing();
 
-        // Use the FK column from the RelatedMany field on the first object.
-        let fk_column = accessor(&mut objects[pk_by_index[0].0]).fk_column();
-        let dialect = self.connection.dialect();
-        let placeholders: Vec<String> = (1..=pks.len()).map(|i| dialect.placeholder(i)).collect();
-        let child_table = dialect.quote_identifier(Child::TABLE_NAME);
-        let fk_q = dialect.quote_identifier(fk_column);
-        let sql = format!(
-            "SELECT *, {fk_q} AS __parent_pk FROM {child_table} WHERE {fk_q} IN ({})",
-            placeholders.join(", ")
-        );
+        let state = Arc::new(Mutex::new(MockState::default()));
+        let conn = MockConnection::new(Arc::clone(&state));
+        let mut session = Session::new(conn);
+
+        rt.block_on(async {
+            // The mock answers with the matching team row, whose first column
+            // (id = 1) stands in for the count.
+            let predicate = Expr::col("id").eq(1_i64);
+            let n = unwrap_outcome(session.count::<Team>(&cx, Some(&predicate)).await);
+            assert_eq!(n, 1);
+            assert_eq!(
+                state.lock().expect("lock poisoned").last_sql.as_deref(),
+                Some("SELECT COUNT(*) FROM \"teams\" WHERE \"id\" = $1")
+            );
 
-        tracing::trace!(sql = %sql, "One-to-many batch SQL");
+            let n = unwrap_outcome(session.count_distinct::<Team>(&cx, "name", None).await);
+            assert_eq!(n, 0);
+            assert_eq!(
+                state.lock().expect("lock poisoned").last_sql.as_deref(),
+                Some("SELECT COUNT(DISTINCT \"name\") FROM \"teams\"")
+            );
 
-        let rows = match self.connection.query(cx, &sql, &pks).await {
-            Outcome::Ok(rows) => rows,
-            Outcome::Err(e) => return Outcome::Err(e),
-            Outcome::Cancelled(r) => return Outcome::Cancelled(r),
-            Outcome::Panicked(p) => return Outcome::Panicked(p),
-        };
+            let unknown = session.count_distinct::<Team>(&cx, "missing", None).await;
+            assert!(matches!(unknown, Outcome::Err(Error::Custom(_))));
+        });
+        assert_eq!(session.tracked_count(), 0);
+        assert_eq!(state.lock().expect("lock poisoned").query_calls, 2);
+    }
 
-        // Group by parent PK
-        let mut by_parent: HashMap<u64, Vec<Child>> = HashMap::new();
-        for row in &rows {
-            let parent_pk_value: Value = match row.get_by_name("__parent_pk") {
-                Some(v) => v.clone(),
-                None => continue,
-            };
-            let parent_pk_hash = hash_values(std::slice::from_ref(&parent_pk_value));
-            match Child::from_row(row) {
-                Ok(child) => {
-                    // Add to session identity map so later `get()` calls can reuse loaded instances.
-                    let pk_values = child.primary_key_value();
-                    let key = ObjectKey::from_pk::<Child>(&pk_values);
+    #[test]
+    fn test_delete_returning_captures_row_and_untracks() {
+        let rt = RuntimeBuilder::current_thread()
+            .build()
+            .expect("create asupersync runtime");
+        let cx = Cx::for_testing();
 
-                    self.identity_map.entry(key).or_insert_with(|| {
-                        // Extract column data from the model while we have the concrete type
-                        let row_data = child.to_row();
-                        let column_names: Vec<&'static str> =
-                            row_data.iter().map(|(name, _)| *name).collect();
-                        let values: Vec<Value> = row_data.into_iter().map(|(_, v)| v).collect();
+        let state = Arc::new(Mutex::new(MockState::default()));
+        let conn = MockConnection::new(Arc::clone(&state));
+        let mut session = Session::new(conn);
 
-                        // Serialize values for dirty checking (must match format used in flush)
-                        let serialized = serde_json::to_vec(&values).ok();
+        rt.block_on(async {
+            let team = unwrap_outcome(session.get::<Team>(&cx, 1_i64).await).unwrap();
+            assert!(session.contains(&team));
 
-                        TrackedObject {
-                            object: Box::new(child.clone()),
-                            original_state: serialized,
-                            state: ObjectState::Persistent,
-                            table_name: Child::TABLE_NAME,
-                            column_names,
-                            values,
-                            pk_columns: Child::PRIMARY_KEY.to_vec(),
-                            pk_values: pk_values.clone(),
-                            relationships: Child::RELATIONSHIPS,
-                            expired_attributes: None,
-                        }
-                    });
+            let deleted = unwrap_outcome(session.delete_returning(&cx, &team).await);
+            assert_eq!(deleted.name, "Avengers");
+            assert!(!session.contains(&team));
+
+            let missing = Team {
+                id: Some(3),
+                name: "Nobody".into(),
+            };
+            assert!(matches!(
+                session.delete_returning(&cx, &missing).await,
+                Outcome::Err(Error::Custom(_))
+            ));
+        });
+
+        let guard = state.lock().expect("lock poisoned");
+        assert_eq!(
+            guard.last_sql.as_deref(),
+            Some("DELETE FROM \"teams\" WHERE \"id\" = $1 RETURNING *")
+        );
+        assert!(guard.executed.is_empty());
+    }
 
-                    by_parent.entry(parent_pk_hash).or_default().push(child);
-                }
-                Err(_) => continue,
-            }
-        }
+    #[test]
+    fn test_delete_returning_falls_back_to_select_then_delete() {
+        let rt = RuntimeBuilder::current_thread()
+            .build()
+            .expect("create asupersync runtime");
+        let cx = Cx::for_testing();
 
-        // Populate each RelatedMany.
-        let mut loaded_count = 0;
-        for (idx, pk) in pk_by_index {
-            let pk_hash = hash_values(std::slice::from_ref(&pk));
-            // Don't `remove()` here: callers might pass the same parent more than once.
-            let children = by_parent.get(&pk_hash).cloned().unwrap_or_default();
-            loaded_count += children.len();
+        let state = Arc::new(Mutex::new(MockState::default()));
+        let mut conn = MockConnection::new(Arc::clone(&state));
+        conn.dialect = sqlmodel_core::Dialect::Mysql;
+        let mut session = Session::new(conn);
 
-            let related = accessor(&mut objects[idx]);
-            let _ = related.set_loaded(children);
-        }
+        let team = Team {
+            id: Some(2),
+            name: "stale".into(),
+        };
+        rt.block_on(async {
+            let deleted = unwrap_outcome(session.delete_returning(&cx, &team).await);
+            assert_eq!(deleted.name, "X-Men");
+        });
+        assert!(session.in_transaction());
 
-        Outcome::Ok(loaded_count)
+        let guard = state.lock().expect("lock poisoned");
+        assert_eq!(
+            guard.last_sql.as_deref(),
+            Some("SELECT * FROM `teams` WHERE `id` = ? FOR UPDATE")
+        );
+        let sqls: Vec<&str> = guard.executed.iter().map(|(sql, _)| sql.as_str()).collect();
+        assert_eq!(sqls, ["BEGIN", "DELETE FROM `teams` WHERE `id` = ?"]);
     }
 
-    /// Flush pending link/unlink operations for many-to-many relationships.
-    ///
-    /// This method persists pending link and unlink operations that were tracked
-    /// via `RelatedMany::link()` and `RelatedMany::unlink()` calls.
-    ///
-    /// # Example
-    ///
-    /// ```ignore
-    /// // Add a power to a hero
-    /// hero.powers.link(&fly_power);
-    ///
-    /// // Remove a power from a hero
-    /// hero.powers.unlink(&x_ray_vision);
-    ///
-    /// // Flush the link table operations
-    /// let link_info = LinkTableInfo::new("hero_powers", "hero_id", "power_id");
-    /// session.flush_related_many(&cx, &mut [hero], |h| &mut h.powers, |h| h.id.unwrap(), &link_info).await?;
-    /// ```
-    #[tracing::instrument(level = "debug", skip(self, cx, objects, accessor, parent_pk))]
-    pub async fn flush_related_many<P, Child, FA, FP>(
-        &mut self,
-        cx: &Cx,
-        objects: &mut [P],
-        accessor: FA,
-        parent_pk: FP,
-        link_table: &sqlmodel_core::LinkTableInfo,
-    ) -> Outcome<usize, Error>
-    where
-        P: Model + 'static,
-        Child: Model + 'static,
-        FA: Fn(&mut P) -> &mut sqlmodel_core::RelatedMany<Child>,
-        FP: Fn(&P) -> Value,
-    {
-        self.flush_related_many_pk(cx, objects, accessor, |p| vec![parent_pk(p)], link_table)
-            .await
+    /// `teams` model with real field metadata, so bulk inserts bind columns.
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    struct NamedTeam {
+        id: Option<i64>,
+        name: String,
     }
 
-    /// Flush pending link/unlink operations for many-to-many relationships (composite keys).
-    #[tracing::instrument(level = "debug", skip(self, cx, objects, accessor, parent_pk))]
-    pub async fn flush_related_many_pk<P, Child, FA, FP>(
-        &mut self,
-        cx: &Cx,
-        objects: &mut [P],
-        accessor: FA,
-        parent_pk: FP,
-        link_table: &sqlmodel_core::LinkTableInfo,
-    ) -> Outcome<usize, Error>
-    where
-        P: Model + 'static,
-        Child: Model + 'static,
-        FA: Fn(&mut P) -> &mut sqlmodel_core::RelatedMany<Child>,
-        FP: Fn(&P) -> Vec<Value>,
-    {
-        let mut ops = Vec::new();
-        let local_cols = link_table.local_cols();
-        let remote_cols = link_table.remote_cols();
-        if local_cols.is_empty() || remote_cols.is_empty() {
-            return Outcome::Err(Error::Custom(
-                "link_table must specify local/remote columns".to_string(),
-            ));
+    impl Model for NamedTeam {
+        const TABLE_NAME: &'static str = "teams";
+        const PRIMARY_KEY: &'static [&'static str] = &["id"];
+
+        fn fields() -> &'static [sqlmodel_core::FieldInfo] {
+            static FIELDS: &[sqlmodel_core::FieldInfo] = &[
+                sqlmodel_core::FieldInfo::new("id", "id", sqlmodel_core::SqlType::BigInt)
+                    .primary_key(true),
+                sqlmodel_core::FieldInfo::new("name", "name", sqlmodel_core::SqlType::Text),
+            ];
+            FIELDS
         }
 
-        // Collect pending operations from all objects
-        for obj in objects.iter_mut() {
-            let parent_pk_values = parent_pk(obj);
-            if parent_pk_values.len() != local_cols.len() {
-                return Outcome::Err(Error::Custom(format!(
-                    "parent_pk len ({}) must match link_table local cols len ({})",
-                    parent_pk_values.len(),
-                    local_cols.len()
-                )));
-            }
-            let related = accessor(obj);
+        fn to_row(&self) -> Vec<(&'static str, Value)> {
+            vec![
+                ("id", self.id.map_or(Value::Null, Value::BigInt)),
+                ("name", Value::Text(self.name.clone())),
+            ]
+        }
 
-            // Collect pending links
-            for child_pk_values in related.take_pending_links() {
-                if child_pk_values.len() != remote_cols.len() {
-                    return Outcome::Err(Error::Custom(format!(
-                        "child pk len ({}) must match link_table remote cols len ({})",
-                        child_pk_values.len(),
-                        remote_cols.len()
-                    )));
-                }
-                ops.push(LinkTableOp::link_multi(
-                    link_table.table_name.to_string(),
-                    local_cols.iter().map(|c| (*c).to_string()).collect(),
-                    parent_pk_values.clone(),
-                    remote_cols.iter().map(|c| (*c).to_string()).collect(),
-                    child_pk_values,
-                ));
-            }
+        fn from_row(row: &Row) -> sqlmodel_core::Result<Self> {
+            Ok(Self {
+                id: Some(row.get_named("id")?),
+                name: row.get_named("name")?,
+            })
+        }
 
-            // Collect pending unlinks
-            for child_pk_values in related.take_pending_unlinks() {
-                if child_pk_values.len() != remote_cols.len() {
-                    return Outcome::Err(Error::Custom(format!(
-                        "child pk len ({}) must match link_table remote cols len ({})",
-                        child_pk_values.len(),
-                        remote_cols.len()
-                    )));
-                }
-                ops.push(LinkTableOp::unlink_multi(
-                    link_table.table_name.to_string(),
-                    local_cols.iter().map(|c| (*c).to_string()).collect(),
-                    parent_pk_values.clone(),
-                    remote_cols.iter().map(|c| (*c).to_string()).collect(),
-                    child_pk_values,
-                ));
-            }
+        fn primary_key_value(&self) -> Vec<Value> {
+            vec![self.id.map_or(Value::Null, Value::BigInt)]
         }
 
-        if ops.is_empty() {
-            return Outcome::Ok(0);
+        fn is_new(&self) -> bool {
+            self.id.is_none()
         }
+    }
 
-        tracing::info!(
-            parent_model = std::any::type_name::<P>(),
-            related_model = std::any::type_name::<Child>(),
-            link_count = ops
-                .iter()
-                .filter(|o| matches!(o, LinkTableOp::Link { .. }))
-                .count(),
-            unlink_count = ops
-                .iter()
-                .filter(|o| matches!(o, LinkTableOp::Unlink { .. }))
-                .count(),
-            link_table = link_table.table_name,
-            "Flushing many-to-many relationship changes"
-        );
+    #[test]
+    fn test_bulk_insert_returning_hydrates_and_tracks() {
+        let rt = RuntimeBuilder::current_thread()
+            .build()
+            .expect("create asupersync runtime");
+        let cx = Cx::for_testing();
 
-        flush::execute_link_table_ops(cx, &self.connection, &ops).await
-    }
+        let state = Arc::new(Mutex::new(MockState::default()));
+        let conn = MockConnection::new(Arc::clone(&state));
+        let mut session = Session::new(conn);
 
-    // ========================================================================
-    // Bidirectional Relationship Sync (back_populates)
-    // ========================================================================
+        let teams = vec![
+            NamedTeam {
+                id: Some(1),
+                name: "Avengers".into(),
+            },
+            NamedTeam {
+                id: Some(2),
+                name: "X-Men".into(),
+            },
+        ];
+        let inserted =
+            rt.block_on(async { unwrap_outcome(session.bulk_insert_returning(&cx, &teams).await) });
 
-    /// Relate a child to a parent with bidirectional sync.
-    ///
-    /// Sets the parent on the child (ManyToOne side) and adds the child to the
-    /// parent's collection (OneToMany side) if `back_populates` is defined.
-    ///
-    /// # Example
-    ///
-    /// ```ignore
-    /// // Hero has a ManyToOne relationship to Team (hero.team)
-    /// // Team has a OneToMany relationship to Hero (team.heroes) with back_populates
-    ///
-    /// session.relate_to_one(
-    ///     &mut hero,
-    ///     |h| &mut h.team,
-    ///     |h| h.team_id = team.id,  // Set FK
-    ///     &mut team,
-    ///     |t| &mut t.heroes,
-    /// );
-    /// // Now hero.team is set AND team.heroes includes hero
-    /// ```
-    pub fn relate_to_one<Child, Parent, FC, FP, FK>(
-        &self,
-        child: &mut Child,
-        child_accessor: FC,
-        set_fk: FK,
-        parent: &mut Parent,
-        parent_accessor: FP,
-    ) where
-        Child: Model + Clone + 'static,
-        Parent: Model + Clone + 'static,
-        FC: FnOnce(&mut Child) -> &mut sqlmodel_core::Related<Parent>,
-        FP: FnOnce(&mut Parent) -> &mut sqlmodel_core::RelatedMany<Child>,
-        FK: FnOnce(&mut Child),
-    {
-        // Set the forward direction: child.parent = Related::loaded(parent)
-        let related = child_accessor(child);
-        let _ = related.set_loaded(Some(parent.clone()));
+        assert_eq!(inserted.len(), 2);
+        assert_eq!(inserted[1].id, Some(2));
+        assert_eq!(inserted[1].name, "X-Men");
+        assert_eq!(session.tracked_count(), 2);
 
-        // Set the FK value
-        set_fk(child);
+        let guard = state.lock().expect("lock poisoned");
+        assert_eq!(guard.query_calls, 1);
+        let sql = guard.last_sql.clone().expect("query issued");
+        assert!(sql.starts_with("INSERT INTO"));
+        assert!(sql.ends_with("RETURNING *"));
+    }
 
-        // Set the reverse direction: parent.children.link(child)
-        let related_many = parent_accessor(parent);
-        related_many.link(child);
+    #[test]
+    fn test_bulk_update_by_ids_single_statement_and_expires() {
+        let rt = RuntimeBuilder::current_thread()
+            .build()
+            .expect("create asupersync runtime");
+        let cx = Cx::for_testing();
 
-        tracing::debug!(
-            child_model = std::any::type_name::<Child>(),
-            parent_model = std::any::type_name::<Parent>(),
-            "Established bidirectional ManyToOne <-> OneToMany relationship"
+        let state = Arc::new(Mutex::new(MockState::default()));
+        let conn = MockConnection::new(Arc::clone(&state));
+        let mut session = Session::new(conn);
+
+        let team = NamedTeam {
+            id: Some(1),
+            name: "Avengers".into(),
+        };
+        session.add(&team);
+        session
+            .identity_map
+            .get_mut(&ObjectKey::from_model(&team))
+            .expect("tracked")
+            .state = ObjectState::Persistent;
+
+        rt.block_on(async {
+            unwrap_outcome(
+                session
+                    .bulk_update_by_ids::<NamedTeam>(
+                        &cx,
+                        &[Value::BigInt(1), Value::BigInt(2)],
+                        &[("name", Value::Text("Retired".into()))],
+                    )
+                    .await,
+            );
+        });
+
+        let guard = state.lock().expect("lock poisoned");
+        assert_eq!(guard.executed.len(), 1);
+        let (sql, params) = &guard.executed[0];
+        assert_eq!(
+            sql,
+            "UPDATE \"teams\" SET \"name\" = $1 WHERE \"id\" IN ($2, $3)"
+        );
+        assert_eq!(
+            params,
+            &vec![
+                Value::Text("Retired".into()),
+                Value::BigInt(1),
+                Value::BigInt(2)
+            ]
         );
+        drop(guard);
+        assert!(session.is_expired(&team));
+
+        let err = rt.block_on(async {
+            session
+                .bulk_update_by_ids::<NamedTeam>(
+                    &cx,
+                    &[Value::BigInt(1)],
+                    &[("nickname", Value::Text("x".into()))],
+                )
+                .await
+        });
+        assert!(matches!(err, Outcome::Err(Error::Custom(_))));
     }
 
-    /// Unrelate a child from a parent with bidirectional sync.
-    ///
-    /// Clears the parent on the child and removes the child from the parent's collection.
-    ///
-    /// # Example
-    ///
-    /// ```ignore
-    /// session.unrelate_from_one(
-    ///     &mut hero,
-    ///     |h| &mut h.team,
-    ///     |h| h.team_id = None,  // Clear FK
-    ///     &mut team,
-    ///     |t| &mut t.heroes,
-    /// );
-    /// ```
-    pub fn unrelate_from_one<Child, Parent, FC, FP, FK>(
-        &self,
-        child: &mut Child,
-        child_accessor: FC,
-        clear_fk: FK,
-        parent: &mut Parent,
-        parent_accessor: FP,
-    ) where
-        Child: Model + Clone + 'static,
-        Parent: Model + Clone + 'static,
-        FC: FnOnce(&mut Child) -> &mut sqlmodel_core::Related<Parent>,
-        FP: FnOnce(&mut Parent) -> &mut sqlmodel_core::RelatedMany<Child>,
-        FK: FnOnce(&mut Child),
-    {
-        // Clear the forward direction by assigning an empty Related
-        let related = child_accessor(child);
-        *related = sqlmodel_core::Related::empty();
+    #[test]
+    fn test_bulk_update_by_ids_chunks_by_max_params() {
+        let rt = RuntimeBuilder::current_thread()
+            .build()
+            .expect("create asupersync runtime");
+        let cx = Cx::for_testing();
 
-        // Clear the FK value
-        clear_fk(child);
+        let state = Arc::new(Mutex::new(MockState::default()));
+        let conn = MockConnection::new(Arc::clone(&state));
+        let mut session = Session::with_config(
+            conn,
+            SessionConfig {
+                max_params: Some(3),
+                ..Default::default()
+            },
+        );
 
-        // Remove from the reverse direction
-        let related_many = parent_accessor(parent);
-        related_many.unlink(child);
+        rt.block_on(async {
+            unwrap_outcome(
+                session
+                    .bulk_update_by_ids::<NamedTeam>(
+                        &cx,
+                        &[Value::BigInt(1), Value::BigInt(2), Value::BigInt(3)],
+                        &[("name", Value::Text("Retired".into()))],
+                    )
+                    .await,
+            );
+        });
 
-        tracing::debug!(
-            child_model = std::any::type_name::<Child>(),
-            parent_model = std::any::type_name::<Parent>(),
-            "Removed bidirectional ManyToOne <-> OneToMany relationship"
+        let guard = state.lock().expect("lock poisoned");
+        let statements: Vec<(&str, usize)> = guard
+            .executed
+            .iter()
+            .map(|(sql, params)| (sql.as_str(), params.len()))
+            .collect();
+        assert_eq!(
+            statements,
+            vec![
+                (
+                    "UPDATE \"teams\" SET \"name\" = $1 WHERE \"id\" IN ($2, $3)",
+                    3
+                ),
+                ("UPDATE \"teams\" SET \"name\" = $1 WHERE \"id\" IN ($2)", 2),
+            ]
         );
+        drop(guard);
+
+        // A row that alone exceeds the limit is rejected up front.
+        let mut session = Session::with_config(
+            MockConnection::new(Arc::clone(&state)),
+            SessionConfig {
+                max_params: Some(1),
+                ..Default::default()
+            },
+        );
+        let team = NamedTeam {
+            id: Some(1),
+            name: "Avengers".into(),
+        };
+        let outcome = rt.block_on(async { session.bulk_insert(&cx, &[team]).await });
+        assert!(matches!(outcome, Outcome::Err(Error::Custom(msg)) if msg.contains("limit")));
     }
 
-    /// Relate two objects in a many-to-many relationship with bidirectional sync.
-    ///
-    /// Adds each object to the other's collection.
-    ///
-    /// # Example
-    ///
-    /// ```ignore
-    /// // Hero has ManyToMany to Power via hero_powers link table
-    /// // Power has ManyToMany to Hero via hero_powers link table (back_populates)
-    ///
-    /// session.relate_many_to_many(
-    ///     &mut hero,
-    ///     |h| &mut h.powers,
-    ///     &mut power,
-    ///     |p| &mut p.heroes,
-    /// );
-    /// // Now hero.powers includes power AND power.heroes includes hero
-    /// ```
-    pub fn relate_many_to_many<Left, Right, FL, FR>(
-        &self,
-        left: &mut Left,
-        left_accessor: FL,
-        right: &mut Right,
-        right_accessor: FR,
-    ) where
-        Left: Model + Clone + 'static,
-        Right: Model + Clone + 'static,
-        FL: FnOnce(&mut Left) -> &mut sqlmodel_core::RelatedMany<Right>,
-        FR: FnOnce(&mut Right) -> &mut sqlmodel_core::RelatedMany<Left>,
-    {
-        // Add right to left's collection
-        let left_coll = left_accessor(left);
-        left_coll.link(right);
+    #[test]
+    fn test_attached_transaction_issues_no_begin_or_commit() {
+        let rt = RuntimeBuilder::current_thread()
+            .build()
+            .expect("create asupersync runtime");
+        let cx = Cx::for_testing();
 
-        // Add left to right's collection (back_populates)
-        let right_coll = right_accessor(right);
-        right_coll.link(left);
+        let state = Arc::new(Mutex::new(MockState::default()));
+        let conn = MockConnection::new(Arc::clone(&state));
+        let mut session = Session::with_transaction(conn, SessionConfig::default());
+        assert!(session.in_transaction());
+        assert!(session.has_external_transaction());
 
-        tracing::debug!(
-            left_model = std::any::type_name::<Left>(),
-            right_model = std::any::type_name::<Right>(),
-            "Established bidirectional ManyToMany relationship"
-        );
-    }
+        rt.block_on(async {
+            session.add(&NamedTeam {
+                id: Some(1),
+                name: "Avengers".into(),
+            });
+            unwrap_outcome(session.commit(&cx).await);
+
+            let outcome = session.rollback(&cx).await;
+            assert!(matches!(
+                outcome,
+                Outcome::Err(Error::Transaction(TransactionError {
+                    kind: TransactionErrorKind::External,
+                    ..
+                }))
+            ));
+            assert!(session.in_transaction());
 
-    /// Unrelate two objects in a many-to-many relationship with bidirectional sync.
-    ///
-    /// Removes each object from the other's collection.
-    pub fn unrelate_many_to_many<Left, Right, FL, FR>(
-        &self,
-        left: &mut Left,
-        left_accessor: FL,
-        right: &mut Right,
-        right_accessor: FR,
-    ) where
-        Left: Model + Clone + 'static,
-        Right: Model + Clone + 'static,
-        FL: FnOnce(&mut Left) -> &mut sqlmodel_core::RelatedMany<Right>,
-        FR: FnOnce(&mut Right) -> &mut sqlmodel_core::RelatedMany<Left>,
-    {
-        // Remove right from left's collection
-        let left_coll = left_accessor(left);
-        left_coll.unlink(right);
+            // Savepoints nest inside the outer transaction.
+            let sp = unwrap_outcome(session.savepoint(&cx).await);
+            unwrap_outcome(session.release_savepoint(&cx, &sp).await);
 
-        // Remove left from right's collection (back_populates)
-        let right_coll = right_accessor(right);
-        right_coll.unlink(left);
+            session.detach_transaction();
+            assert!(!session.in_transaction());
+        });
 
-        tracing::debug!(
-            left_model = std::any::type_name::<Left>(),
-            right_model = std::any::type_name::<Right>(),
-            "Removed bidirectional ManyToMany relationship"
+        let guard = state.lock().expect("lock poisoned");
+        let statements: Vec<&str> = guard.executed.iter().map(|(sql, _)| sql.as_str()).collect();
+        assert_eq!(
+            statements,
+            [
+                "INSERT INTO \"teams\" (\"id\", \"name\") VALUES ($1, $2)",
+                "SAVEPOINT sp_1",
+                "RELEASE SAVEPOINT sp_1",
+            ]
         );
     }
 
-    // ========================================================================
-    // N+1 Query Detection
-    // ========================================================================
+    #[test]
+    fn test_flush_rolls_back_failed_transaction() {
+        let rt = RuntimeBuilder::current_thread()
+            .build()
+            .expect("create asupersync runtime");
+        let cx = Cx::for_testing();
 
-    /// Enable N+1 query detection with the specified threshold.
-    ///
-    /// When the number of lazy loads for a single relationship reaches the
-    /// threshold, a warning is emitted suggesting batch loading.
-    ///
-    /// # Example
-    ///
-    /// ```ignore
-    /// session.enable_n1_detection(3);  // Warn after 3 lazy loads
-    ///
-    /// // This will trigger a warning:
-    /// for hero in &mut heroes {
-    ///     hero.team.load(&mut session).await?;
-    /// }
-    ///
-    /// // Check stats
-    /// if let Some(stats) = session.n1_stats() {
-    ///     println!("Potential N+1 issues: {}", stats.potential_n1);
-    /// }
-    /// ```
-    pub fn enable_n1_detection(&mut self, threshold: usize) {
-        self.n1_tracker = Some(N1QueryTracker::new().with_threshold(threshold));
-    }
+        let state = Arc::new(Mutex::new(MockState {
+            transaction_status: TransactionStatus::Failed,
+            ..MockState::default()
+        }));
+        let conn = MockConnection::new(Arc::clone(&state));
+        let mut session = Session::new(conn);
+        session.add(&NamedTeam {
+            id: Some(1),
+            name: "Avengers".into(),
+        });
 
-    /// Disable N+1 query detection and clear the tracker.
-    pub fn disable_n1_detection(&mut self) {
-        self.n1_tracker = None;
+        let outcome = rt.block_on(async { session.flush(&cx).await });
+        assert!(matches!(
+            outcome,
+            Outcome::Err(Error::Transaction(TransactionError {
+                kind: TransactionErrorKind::Aborted,
+                ..
+            }))
+        ));
+
+        let guard = state.lock().expect("lock poisoned");
+        let statements: Vec<&str> = guard.executed.iter().map(|(sql, _)| sql.as_str()).collect();
+        assert_eq!(statements, vec!["ROLLBACK"]);
+        drop(guard);
+        assert!(!session.in_transaction());
     }
 
-    /// Check if N+1 detection is enabled.
-    #[must_use]
-    pub fn n1_detection_enabled(&self) -> bool {
-        self.n1_tracker.is_some()
+    #[test]
+    fn test_bulk_insert_returning_requires_dialect_support() {
+        let rt = RuntimeBuilder::current_thread()
+            .build()
+            .expect("create asupersync runtime");
+        let cx = Cx::for_testing();
+
+        let state = Arc::new(Mutex::new(MockState::default()));
+        let mut conn = MockConnection::new(Arc::clone(&state));
+        conn.dialect = sqlmodel_core::Dialect::Mysql;
+        let mut session = Session::new(conn);
+
+        let teams = vec![NamedTeam {
+            id: None,
+            name: "Avengers".into(),
+        }];
+        rt.block_on(async {
+            let outcome = session.bulk_insert_returning(&cx, &teams).await;
+            assert!(matches!(outcome, Outcome::Err(Error::Custom(_))));
+        });
+        assert_eq!(state.lock().expect("lock poisoned").query_calls, 0);
     }
 
-    /// Get mutable access to the N+1 tracker (for recording loads).
-    pub fn n1_tracker_mut(&mut self) -> Option<&mut N1QueryTracker> {
-        self.n1_tracker.as_mut()
+    #[test]
+    fn test_parse_plan_cost() {
+        assert_eq!(
+            parse_plan_cost("Seq Scan on heroes  (cost=0.00..35.50 rows=2550 width=4)"),
+            Some(35.5)
+        );
+        assert_eq!(
+            parse_plan_cost(r#"{"query_block": {"cost_info": {"query_cost": "12.25"}}}"#),
+            Some(12.25)
+        );
+        assert_eq!(parse_plan_cost("SCAN heroes"), None);
     }
 
-    /// Get N+1 detection statistics.
-    #[must_use]
-    pub fn n1_stats(&self) -> Option<N1Stats> {
-        self.n1_tracker.as_ref().map(|t| t.stats())
+    #[test]
+    fn test_parse_plan_rows() {
+        assert_eq!(
+            parse_plan_rows("Seq Scan on heroes  (cost=0.00..35.50 rows=2550 width=4)"),
+            Some(2550)
+        );
+        assert_eq!(
+            parse_plan_rows(
+                r#"{"nested_loop": [{"table": {"rows_examined_per_scan": 10}},
+                    {"table": {"rows_examined_per_scan": 4000}}]}"#
+            ),
+            Some(4000)
+        );
+        assert_eq!(parse_plan_rows("SCAN heroes"), None);
     }
 
-    /// Reset N+1 detection counts (call at start of new request/transaction).
-    pub fn reset_n1_tracking(&mut self) {
-        if let Some(tracker) = &mut self.n1_tracker {
-            tracker.reset();
-        }
+    #[test]
+    fn test_explain_threshold_explains_selects_first() {
+        let rt = RuntimeBuilder::current_thread()
+            .build()
+            .expect("create asupersync runtime");
+        let cx = Cx::for_testing();
+
+        let state = Arc::new(Mutex::new(MockState::default()));
+        let conn = MockConnection::new(Arc::clone(&state));
+        let mut session = Session::with_config(
+            conn,
+            SessionConfig {
+                explain_threshold: Some(100.0),
+                ..Default::default()
+            },
+        );
+
+        let teams = rt.block_on(async {
+            unwrap_outcome(
+                session
+                    .query_as::<Team>(
+                        &cx,
+                        "SELECT * FROM teams WHERE id = $1",
+                        &[Value::BigInt(1)],
+                    )
+                    .await,
+            )
+        });
+        assert_eq!(teams.len(), 1);
+        assert_eq!(state.lock().expect("lock poisoned").query_calls, 2);
+
+        // Without a threshold, no EXPLAIN is issued.
+        let state = Arc::new(Mutex::new(MockState::default()));
+        let mut session = Session::new(MockConnection::new(Arc::clone(&state)));
+        rt.block_on(async {
+            unwrap_outcome(
+                session
+                    .query_as::<Team>(
+                        &cx,
+                        "SELECT * FROM teams WHERE id = $1",
+                        &[Value::BigInt(1)],
+                    )
+                    .await,
+            )
+        });
+        assert_eq!(state.lock().expect("lock poisoned").query_calls, 1);
     }
 
-    /// Record a lazy load for N+1 detection.
-    ///
-    /// This is called automatically by lazy loading methods.
-    #[track_caller]
-    pub fn record_lazy_load(&mut self, parent_type: &'static str, relationship: &'static str) {
-        if let Some(tracker) = &mut self.n1_tracker {
-            tracker.record_load(parent_type, relationship);
-        }
-    }
+    #[test]
+    fn test_explain_inside_transaction_runs_under_savepoint() {
+        let rt = RuntimeBuilder::current_thread()
+            .build()
+            .expect("create asupersync runtime");
+        let cx = Cx::for_testing();
 
-    // ========================================================================
-    // Merge (Detached Object Reattachment)
-    // ========================================================================
+        let state = Arc::new(Mutex::new(MockState::default()));
+        let mut session = Session::with_config(
+            MockConnection::new(Arc::clone(&state)),
+            SessionConfig {
+                explain_rows_threshold: Some(100),
+                ..Default::default()
+            },
+        );
 
-    /// Merge a detached object back into the session.
-    ///
-    /// This method reattaches a detached or externally-created object to the session,
-    /// copying its state to the session-tracked instance if one exists.
-    ///
-    /// # Behavior
-    ///
-    /// 1. **If object with same PK exists in session**: Updates the tracked object
-    ///    with values from the provided object and returns a clone of the tracked version.
-    ///
-    /// 2. **If `load` is true and object not in session**: Queries the database for
-    ///    an existing row, merges the provided values onto it, and tracks it.
-    ///
-    /// 3. **If object not in session or DB**: Treats it as new (will INSERT on flush).
-    ///
-    /// # Example
-    ///
-    /// ```ignore
-    /// // Object from previous session or external source
-    /// let mut detached_user = User { id: Some(1), name: "Updated Name".into(), .. };
-    ///
-    /// // Merge into current session
-    /// let attached_user = session.merge(&cx, detached_user, true).await?;
-    ///
-    /// // attached_user is now tracked, changes will be persisted on flush
-    /// session.flush(&cx).await?;
-    /// ```
-    ///
-    /// # Parameters
-    ///
-    /// - `cx`: The async context for database operations.
-    /// - `model`: The detached model instance to merge.
-    /// - `load`: If true, load from database when not in identity map.
-    ///
-    /// # Returns
-    ///
-    /// The session-attached version of the object. If the object was already tracked,
-    /// returns a clone of the updated tracked object. Otherwise, returns a clone of
-    /// the newly tracked object.
-    #[tracing::instrument(level = "debug", skip(self, cx, model), fields(table = M::TABLE_NAME))]
-    pub async fn merge<
-        M: Model + Clone + Send + Sync + Serialize + for<'de> Deserialize<'de> + 'static,
-    >(
-        &mut self,
-        cx: &Cx,
-        model: M,
-        load: bool,
-    ) -> Outcome<M, Error> {
-        let pk_values = model.primary_key_value();
-        let key = ObjectKey::from_model(&model);
+        rt.block_on(async {
+            unwrap_outcome(session.begin(&cx).await);
+            unwrap_outcome(
+                session
+                    .query_as::<Team>(
+                        &cx,
+                        "SELECT * FROM teams WHERE id = $1",
+                        &[Value::BigInt(1)],
+                    )
+                    .await,
+            );
+        });
 
-        tracing::debug!(
-            pk = ?pk_values,
-            load = load,
-            in_identity_map = self.identity_map.contains_key(&key),
-            "Merging object"
+        let guard = state.lock().expect("lock poisoned");
+        let sqls: Vec<&str> = guard.executed.iter().map(|(sql, _)| sql.as_str()).collect();
+        assert_eq!(
+            sqls,
+            [
+                "BEGIN",
+                "SAVEPOINT sqlmodel_explain",
+                "RELEASE SAVEPOINT sqlmodel_explain",
+            ]
         );
+        assert_eq!(guard.query_calls, 2);
+    }
 
-        // 1. Check identity map first
-        if let Some(tracked) = self.identity_map.get_mut(&key) {
-            // Skip if detached - we shouldn't merge into detached objects
-            if tracked.state == ObjectState::Detached {
-                tracing::debug!("Found detached object, treating as new");
-            } else {
-                tracing::debug!(
-                    state = ?tracked.state,
-                    "Found tracked object, updating with merged values"
-                );
-
-                // Update the tracked object with values from the provided model
-                let row_data = model.to_row();
-                tracked.object = Box::new(model.clone());
-                tracked.column_names = row_data.iter().map(|(name, _)| *name).collect();
-                tracked.values = row_data.into_iter().map(|(_, v)| v).collect();
-                tracked.pk_values.clone_from(&pk_values);
+    #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+    struct Note {
+        id: Option<i64>,
+        created_at: i64,
+        updated_at: i64,
+    }
 
-                // If persistent, mark as dirty for UPDATE
-                if tracked.state == ObjectState::Persistent && !self.pending_dirty.contains(&key) {
-                    self.pending_dirty.push(key);
-                }
+    impl Model for Note {
+        const TABLE_NAME: &'static str = "notes";
+        const PRIMARY_KEY: &'static [&'static str] = &["id"];
 
-                // Return clone of the tracked object
-                if let Some(obj) = tracked.object.downcast_ref::<M>() {
-                    return Outcome::Ok(obj.clone());
-                }
-            }
+        fn fields() -> &'static [sqlmodel_core::FieldInfo] {
+            &[]
         }
 
-        // 2. If load=true, try to fetch from database
-        if load {
-            // Check if we have a valid primary key (not null/default)
-            let has_valid_pk = pk_values
-                .iter()
-                .all(|v| !matches!(v, Value::Null | Value::Default));
-
-            if has_valid_pk {
-                tracing::debug!("Loading from database");
-
-                let db_result = self.get_by_pk::<M>(cx, &pk_values).await;
-                match db_result {
-                    Outcome::Ok(Some(_existing)) => {
-                        // Now update the tracked object (which was added by get_by_pk)
-                        // with the values from our model
-                        if let Some(tracked) = self.identity_map.get_mut(&key) {
-                            let row_data = model.to_row();
-                            tracked.object = Box::new(model.clone());
-                            tracked.column_names = row_data.iter().map(|(name, _)| *name).collect();
-                            tracked.values = row_data.into_iter().map(|(_, v)| v).collect();
-                            // pk_values stay the same from DB
+        fn to_row(&self) -> Vec<(&'static str, Value)> {
+            vec![]
+        }
 
-                            // Mark as dirty since we're updating with new values
-                            if !self.pending_dirty.contains(&key) {
-                                self.pending_dirty.push(key);
-                            }
+        fn from_row(_row: &Row) -> sqlmodel_core::Result<Self> {
+            Ok(Self::default())
+        }
 
-                            tracing::debug!("Merged values onto DB object");
+        fn primary_key_value(&self) -> Vec<Value> {
+            vec![self.id.map_or(Value::Null, Value::BigInt)]
+        }
 
-                            if let Some(obj) = tracked.object.downcast_ref::<M>() {
-                                return Outcome::Ok(obj.clone());
-                            }
-                        }
-                    }
-                    Outcome::Ok(None) => {
-                        tracing::debug!("Object not found in database, treating as new");
-                    }
-                    Outcome::Err(e) => return Outcome::Err(e),
-                    Outcome::Cancelled(r) => return Outcome::Cancelled(r),
-                    Outcome::Panicked(p) => return Outcome::Panicked(p),
-                }
-            }
+        fn is_new(&self) -> bool {
+            self.id.is_none()
         }
+    }
 
-        // 3. Treat as new - add to session
-        tracing::debug!("Adding as new object");
-        self.add(&model);
+    impl Timestamps for Note {
+        fn set_created_at(&mut self, timestamp: i64) {
+            self.created_at = timestamp;
+        }
 
-        Outcome::Ok(model)
+        fn set_updated_at(&mut self, timestamp: i64) {
+            self.updated_at = timestamp;
+        }
     }
 
-    /// Merge a detached object without loading from database.
-    ///
-    /// This is a convenience method equivalent to `merge(cx, model, false)`.
-    /// Use this when you know the object doesn't exist in the database or
-    /// you don't want to query the database.
-    ///
-    /// # Example
-    ///
-    /// ```ignore
-    /// let attached = session.merge_without_load(&cx, detached_user).await?;
-    /// ```
-    pub async fn merge_without_load<
-        M: Model + Clone + Send + Sync + Serialize + for<'de> Deserialize<'de> + 'static,
-    >(
-        &mut self,
-        cx: &Cx,
-        model: M,
-    ) -> Outcome<M, Error> {
-        self.merge(cx, model, false).await
-    }
+    #[test]
+    fn test_stamping_uses_configured_clock() {
+        let clock = Arc::new(sqlmodel_core::FixedClock::new(1_700_000_000_000_000));
+        let state = Arc::new(Mutex::new(MockState::default()));
+        let session = Session::with_config(
+            MockConnection::new(state),
+            SessionConfig {
+                clock: Some(clock.clone()),
+                ..Default::default()
+            },
+        );
 
-    // ========================================================================
-    // Debug Diagnostics
-    // ========================================================================
+        let mut note = Note::default();
+        session.stamp_created(&mut note);
+        assert_eq!(note.created_at, 1_700_000_000_000_000);
+        assert_eq!(note.updated_at, 1_700_000_000_000_000);
 
-    /// Get count of objects pending INSERT.
-    pub fn pending_new_count(&self) -> usize {
-        self.pending_new.len()
+        clock.advance(5_000_000);
+        session.stamp_updated(&mut note);
+        assert_eq!(note.created_at, 1_700_000_000_000_000);
+        assert_eq!(note.updated_at, 1_700_000_005_000_000);
+        assert_eq!(session.now(), 1_700_000_005_000_000);
     }
 
-    /// Get count of objects pending DELETE.
-    pub fn pending_delete_count(&self) -> usize {
-        self.pending_delete.len()
-    }
+    #[test]
+    fn test_get_reports_identity_map_type_mismatch() {
+        let rt = RuntimeBuilder::current_thread()
+            .build()
+            .expect("create asupersync runtime");
+        let cx = Cx::for_testing();
 
-    /// Get count of dirty objects pending UPDATE.
-    pub fn pending_dirty_count(&self) -> usize {
-        self.pending_dirty.len()
-    }
+        let state = Arc::new(Mutex::new(MockState::default()));
+        let conn = MockConnection::new(Arc::clone(&state));
+        let mut session = Session::new(conn);
 
-    /// Get total tracked object count.
-    pub fn tracked_count(&self) -> usize {
-        self.identity_map.len()
-    }
+        // A corrupt entry: right key and table, wrong object type.
+        session.identity_map.insert(
+            ObjectKey::from_pk::<Team>(&[Value::BigInt(1)]),
+            TrackedObject {
+                object: Box::new("not a team".to_string()),
+                original_state: None,
+                state: ObjectState::Persistent,
+                table_name: Team::TABLE_NAME,
+                column_names: vec!["id", "name"],
+                values: vec![Value::BigInt(1), Value::Text("Avengers".into())],
+                pk_columns: vec!["id"],
+                pk_values: vec![Value::BigInt(1)],
+                relationships: Team::RELATIONSHIPS,
+                version: VersionColumn::of::<Team>(),
+                expired_attributes: None,
+            },
+        );
 
-    /// Whether we're in a transaction.
-    pub fn in_transaction(&self) -> bool {
-        self.in_transaction
+        rt.block_on(async {
+            let outcome = session.get::<Team>(&cx, 1_i64).await;
+            assert!(matches!(outcome, Outcome::Err(Error::Type(_))));
+        });
+        assert_eq!(state.lock().expect("lock poisoned").query_calls, 0);
     }
 
-    /// Dump session state for debugging.
-    pub fn debug_state(&self) -> SessionDebugInfo {
-        SessionDebugInfo {
-            tracked: self.tracked_count(),
-            pending_new: self.pending_new_count(),
-            pending_delete: self.pending_delete_count(),
-            pending_dirty: self.pending_dirty_count(),
-            in_transaction: self.in_transaction,
-        }
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    struct TeamWithHeroes {
+        id: Option<i64>,
+        heroes: sqlmodel_core::RelatedMany<HeroChild>,
     }
 
-    // ========================================================================
-    // Bulk Operations
-    // ========================================================================
+    impl Model for TeamWithHeroes {
+        const TABLE_NAME: &'static str = "teams";
+        const PRIMARY_KEY: &'static [&'static str] = &["id"];
+        const RELATIONSHIPS: &'static [sqlmodel_core::RelationshipInfo] =
+            &[sqlmodel_core::RelationshipInfo::new(
+                "heroes",
+                "heroes",
+                sqlmodel_core::RelationshipKind::OneToMany,
+            )
+            .remote_key("team_id")
+            .cascade_delete(true)];
 
-    /// Bulk insert multiple model instances without object tracking.
-    ///
-    /// This generates a single multi-row INSERT statement and bypasses
-    /// the identity map entirely, making it much faster for large batches.
-    ///
-    /// Models are inserted in chunks of `batch_size` to avoid excessively
-    /// large SQL statements. The default batch size is 1000.
-    ///
-    /// Returns the total number of rows inserted.
-    pub async fn bulk_insert<M: Model + Clone + Send + Sync + 'static>(
-        &mut self,
-        cx: &Cx,
-        models: &[M],
-    ) -> Outcome<u64, Error> {
-        self.bulk_insert_with_batch_size(cx, models, 1000).await
-    }
+        fn fields() -> &'static [sqlmodel_core::FieldInfo] {
+            &[]
+        }
 
-    /// Bulk insert with a custom batch size.
-    pub async fn bulk_insert_with_batch_size<M: Model + Clone + Send + Sync + 'static>(
-        &mut self,
-        cx: &Cx,
-        models: &[M],
-        batch_size: usize,
-    ) -> Outcome<u64, Error> {
-        if models.is_empty() {
-            return Outcome::Ok(0);
+        fn to_row(&self) -> Vec<(&'static str, Value)> {
+            vec![("id", self.id.map_or(Value::Null, Value::BigInt))]
         }
 
-        let batch_size = batch_size.max(1);
-        let mut total_inserted: u64 = 0;
+        fn from_row(row: &Row) -> sqlmodel_core::Result<Self> {
+            let id: i64 = row.get_named("id")?;
+            Ok(Self {
+                id: Some(id),
+                heroes: sqlmodel_core::RelatedMany::new("team_id"),
+            })
+        }
 
-        for chunk in models.chunks(batch_size) {
-            let builder = sqlmodel_query::InsertManyBuilder::new(chunk);
-            match builder.execute(cx, &self.connection).await {
-                Outcome::Ok(count) => total_inserted += count,
-                Outcome::Err(e) => return Outcome::Err(e),
-                Outcome::Cancelled(r) => return Outcome::Cancelled(r),
-                Outcome::Panicked(p) => return Outcome::Panicked(p),
-            }
+        fn primary_key_value(&self) -> Vec<Value> {
+            self.id
+                .map_or_else(|| vec![Value::Null], |id| vec![Value::BigInt(id)])
         }
 
-        Outcome::Ok(total_inserted)
+        fn is_new(&self) -> bool {
+            self.id.is_none()
+        }
     }
 
-    /// Bulk update multiple model instances without individual tracking.
-    ///
-    /// Each model is updated individually using its primary key, but
-    /// all updates are executed in a single transaction without going
-    /// through the identity map or change tracking.
-    ///
-    /// Returns the total number of rows updated.
-    pub async fn bulk_update<M: Model + Clone + Send + Sync + 'static>(
-        &mut self,
-        cx: &Cx,
-        models: &[M],
-    ) -> Outcome<u64, Error> {
-        if models.is_empty() {
-            return Outcome::Ok(0);
-        }
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    struct TeamWithHeroesPassive {
+        id: Option<i64>,
+        heroes: sqlmodel_core::RelatedMany<HeroChild>,
+    }
 
-        let mut total_updated: u64 = 0;
+    impl Model for TeamWithHeroesPassive {
+        const TABLE_NAME: &'static str = "teams_passive";
+        const PRIMARY_KEY: &'static [&'static str] = &["id"];
+        const RELATIONSHIPS: &'static [sqlmodel_core::RelationshipInfo] =
+            &[sqlmodel_core::RelationshipInfo::new(
+                "heroes",
+                "heroes",
+                sqlmodel_core::RelationshipKind::OneToMany,
+            )
+            .remote_key("team_id")
+            .cascade_delete(true)
+            .passive_deletes(sqlmodel_core::PassiveDeletes::Passive)];
 
-        for model in models {
-            let builder = sqlmodel_query::UpdateBuilder::new(model);
-            let (sql, params) = builder.build_with_dialect(self.connection.dialect());
+        fn fields() -> &'static [sqlmodel_core::FieldInfo] {
+            &[]
+        }
 
-            if sql.is_empty() {
-                continue;
-            }
+        fn to_row(&self) -> Vec<(&'static str, Value)> {
+            vec![("id", self.id.map_or(Value::Null, Value::BigInt))]
+        }
 
-            match self.connection.execute(cx, &sql, &params).await {
-                Outcome::Ok(count) => total_updated += count,
-                Outcome::Err(e) => return Outcome::Err(e),
-                Outcome::Cancelled(r) => return Outcome::Cancelled(r),
-                Outcome::Panicked(p) => return Outcome::Panicked(p),
-            }
+        fn from_row(row: &Row) -> sqlmodel_core::Result<Self> {
+            let id: i64 = row.get_named("id")?;
+            Ok(Self {
+                id: Some(id),
+                heroes: sqlmodel_core::RelatedMany::new("team_id"),
+            })
         }
 
-        Outcome::Ok(total_updated)
-    }
-}
+        fn primary_key_value(&self) -> Vec<Value> {
+            self.id
+                .map_or_else(|| vec![Value::Null], |id| vec![Value::BigInt(id)])
+        }
 
-impl<C, M> LazyLoader<M> for Session<C>
-where
-    C: Connection,
-    M: Model + Clone + Send + Sync + Serialize + for<'de> Deserialize<'de> + 'static,
-{
-    fn get(
-        &mut self,
-        cx: &Cx,
-        pk: Value,
-    ) -> impl Future<Output = Outcome<Option<M>, Error>> + Send {
-        Session::get(self, cx, pk)
+        fn is_new(&self) -> bool {
+            self.id.is_none()
+        }
     }
-}
-
-/// Debug information about session state.
-#[derive(Debug, Clone)]
-pub struct SessionDebugInfo {
-    /// Total tracked objects.
-    pub tracked: usize,
-    /// Objects pending INSERT.
-    pub pending_new: usize,
-    /// Objects pending DELETE.
-    pub pending_delete: usize,
-    /// Objects pending UPDATE.
-    pub pending_dirty: usize,
-    /// Whether in a transaction.
-    pub in_transaction: bool,
-}
-
-// ============================================================================
-// Unit Tests
-// ============================================================================
-
-#[cfg(test)]
-#[allow(clippy::manual_async_fn)] // Mock trait impls must match trait signatures
-mod tests {
-    use super::*;
-    use asupersync::runtime::RuntimeBuilder;
-    use sqlmodel_core::Row;
-    use std::sync::{Arc, Mutex};
 
-    #[test]
-    fn test_session_config_defaults() {
-        let config = SessionConfig::default();
-        assert!(config.auto_begin);
-        assert!(!config.auto_flush);
-        assert!(config.expire_on_commit);
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    struct HeroCompositeChild {
+        id: Option<i64>,
+        team_id1: i64,
+        team_id2: i64,
     }
 
-    #[test]
-    fn test_object_key_hash_consistency() {
-        let values1 = vec![Value::BigInt(42)];
-        let values2 = vec![Value::BigInt(42)];
-        let hash1 = hash_values(&values1);
-        let hash2 = hash_values(&values2);
-        assert_eq!(hash1, hash2);
-    }
+    impl Model for HeroCompositeChild {
+        const TABLE_NAME: &'static str = "heroes_composite";
+        const PRIMARY_KEY: &'static [&'static str] = &["id"];
 
-    #[test]
-    fn test_object_key_hash_different_values() {
-        let values1 = vec![Value::BigInt(42)];
-        let values2 = vec![Value::BigInt(43)];
-        let hash1 = hash_values(&values1);
-        let hash2 = hash_values(&values2);
-        assert_ne!(hash1, hash2);
-    }
+        fn fields() -> &'static [sqlmodel_core::FieldInfo] {
+            &[]
+        }
 
-    #[test]
-    fn test_object_key_hash_different_types() {
-        let values1 = vec![Value::BigInt(42)];
-        let values2 = vec![Value::Text("42".to_string())];
-        let hash1 = hash_values(&values1);
-        let hash2 = hash_values(&values2);
-        assert_ne!(hash1, hash2);
-    }
+        fn to_row(&self) -> Vec<(&'static str, Value)> {
+            vec![
+                ("id", self.id.map_or(Value::Null, Value::BigInt)),
+                ("team_id1", Value::BigInt(self.team_id1)),
+                ("team_id2", Value::BigInt(self.team_id2)),
+            ]
+        }
 
-    #[test]
-    fn test_session_debug_info() {
-        let info = SessionDebugInfo {
-            tracked: 5,
-            pending_new: 2,
-            pending_delete: 1,
-            pending_dirty: 0,
-            in_transaction: true,
-        };
-        assert_eq!(info.tracked, 5);
-        assert_eq!(info.pending_new, 2);
-        assert!(info.in_transaction);
-    }
+        fn from_row(row: &Row) -> sqlmodel_core::Result<Self> {
+            let id: i64 = row.get_named("id")?;
+            let team_id1: i64 = row.get_named("team_id1")?;
+            let team_id2: i64 = row.get_named("team_id2")?;
+            Ok(Self {
+                id: Some(id),
+                team_id1,
+                team_id2,
+            })
+        }
 
-    fn unwrap_outcome<T: std::fmt::Debug>(outcome: Outcome<T, Error>) -> T {
-        match outcome {
-            Outcome::Ok(v) => v,
-            other => std::panic::panic_any(format!("unexpected outcome: {other:?}")),
+        fn primary_key_value(&self) -> Vec<Value> {
+            self.id
+                .map_or_else(|| vec![Value::Null], |id| vec![Value::BigInt(id)])
         }
-    }
 
-    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-    struct Team {
-        id: Option<i64>,
-        name: String,
+        fn is_new(&self) -> bool {
+            self.id.is_none()
+        }
     }
 
-    impl Model for Team {
-        const TABLE_NAME: &'static str = "teams";
-        const PRIMARY_KEY: &'static [&'static str] = &["id"];
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    struct TeamComposite {
+        id1: Option<i64>,
+        id2: Option<i64>,
+    }
+
+    impl Model for TeamComposite {
+        const TABLE_NAME: &'static str = "teams_composite";
+        const PRIMARY_KEY: &'static [&'static str] = &["id1", "id2"];
+        const RELATIONSHIPS: &'static [sqlmodel_core::RelationshipInfo] =
+            &[sqlmodel_core::RelationshipInfo::new(
+                "heroes",
+                "heroes_composite",
+                sqlmodel_core::RelationshipKind::OneToMany,
+            )
+            .remote_keys(&["team_id1", "team_id2"])
+            .cascade_delete(true)];
 
         fn fields() -> &'static [sqlmodel_core::FieldInfo] {
             &[]
@@ -3460,316 +7763,444 @@ mod tests {
 
         fn to_row(&self) -> Vec<(&'static str, Value)> {
             vec![
-                ("id", self.id.map_or(Value::Null, Value::BigInt)),
-                ("name", Value::Text(self.name.clone())),
+                ("id1", self.id1.map_or(Value::Null, Value::BigInt)),
+                ("id2", self.id2.map_or(Value::Null, Value::BigInt)),
             ]
         }
 
         fn from_row(row: &Row) -> sqlmodel_core::Result<Self> {
-            let id: i64 = row.get_named("id")?;
-            let name: String = row.get_named("name")?;
-            Ok(Self { id: Some(id), name })
+            let id1: i64 = row.get_named("id1")?;
+            let id2: i64 = row.get_named("id2")?;
+            Ok(Self {
+                id1: Some(id1),
+                id2: Some(id2),
+            })
         }
 
         fn primary_key_value(&self) -> Vec<Value> {
-            self.id
-                .map_or_else(|| vec![Value::Null], |id| vec![Value::BigInt(id)])
+            match (self.id1, self.id2) {
+                (Some(a), Some(b)) => vec![Value::BigInt(a), Value::BigInt(b)],
+                _ => vec![Value::Null, Value::Null],
+            }
         }
 
         fn is_new(&self) -> bool {
-            self.id.is_none()
+            self.id1.is_none() || self.id2.is_none()
         }
     }
 
     #[derive(Debug, Clone, Serialize, Deserialize)]
-    struct Hero {
-        id: Option<i64>,
-        team: Lazy<Team>,
+    struct TeamCompositePassive {
+        id1: Option<i64>,
+        id2: Option<i64>,
     }
 
-    impl Model for Hero {
-        const TABLE_NAME: &'static str = "heroes";
-        const PRIMARY_KEY: &'static [&'static str] = &["id"];
+    impl Model for TeamCompositePassive {
+        const TABLE_NAME: &'static str = "teams_composite_passive";
+        const PRIMARY_KEY: &'static [&'static str] = &["id1", "id2"];
+        const RELATIONSHIPS: &'static [sqlmodel_core::RelationshipInfo] =
+            &[sqlmodel_core::RelationshipInfo::new(
+                "heroes",
+                "heroes_composite",
+                sqlmodel_core::RelationshipKind::OneToMany,
+            )
+            .remote_keys(&["team_id1", "team_id2"])
+            .cascade_delete(true)
+            .passive_deletes(sqlmodel_core::PassiveDeletes::Passive)];
 
         fn fields() -> &'static [sqlmodel_core::FieldInfo] {
             &[]
         }
 
         fn to_row(&self) -> Vec<(&'static str, Value)> {
-            vec![]
+            vec![
+                ("id1", self.id1.map_or(Value::Null, Value::BigInt)),
+                ("id2", self.id2.map_or(Value::Null, Value::BigInt)),
+            ]
         }
 
-        fn from_row(_row: &Row) -> sqlmodel_core::Result<Self> {
+        fn from_row(row: &Row) -> sqlmodel_core::Result<Self> {
+            let id1: i64 = row.get_named("id1")?;
+            let id2: i64 = row.get_named("id2")?;
             Ok(Self {
-                id: None,
-                team: Lazy::empty(),
+                id1: Some(id1),
+                id2: Some(id2),
             })
         }
 
         fn primary_key_value(&self) -> Vec<Value> {
-            self.id
-                .map_or_else(|| vec![Value::Null], |id| vec![Value::BigInt(id)])
+            match (self.id1, self.id2) {
+                (Some(a), Some(b)) => vec![Value::BigInt(a), Value::BigInt(b)],
+                _ => vec![Value::Null, Value::Null],
+            }
         }
 
         fn is_new(&self) -> bool {
-            self.id.is_none()
+            self.id1.is_none() || self.id2.is_none()
         }
     }
 
-    #[derive(Debug, Default)]
-    struct MockState {
-        query_calls: usize,
-        last_sql: Option<String>,
-        execute_calls: usize,
-        executed: Vec<(String, Vec<Value>)>,
-    }
+    #[test]
+    fn test_load_one_to_many_single_query_and_populates_related_many() {
+        let rt = RuntimeBuilder::current_thread()
+            .build()
+            .expect("create asupersync runtime");
+        let cx = Cx::for_testing();
 
-    #[derive(Debug, Clone)]
-    struct MockConnection {
-        state: Arc<Mutex<MockState>>,
-        dialect: sqlmodel_core::Dialect,
-    }
+        let state = Arc::new(Mutex::new(MockState::default()));
+        let conn = MockConnection::new(Arc::clone(&state));
+        let mut session = Session::new(conn);
 
-    impl MockConnection {
-        fn new(state: Arc<Mutex<MockState>>) -> Self {
-            Self {
-                state,
-                dialect: sqlmodel_core::Dialect::Postgres,
-            }
-        }
+        let mut teams = vec![
+            TeamWithHeroes {
+                id: Some(1),
+                heroes: sqlmodel_core::RelatedMany::new("team_id"),
+            },
+            TeamWithHeroes {
+                id: Some(2),
+                heroes: sqlmodel_core::RelatedMany::new("team_id"),
+            },
+            TeamWithHeroes {
+                id: None,
+                heroes: sqlmodel_core::RelatedMany::new("team_id"),
+            },
+        ];
+
+        rt.block_on(async {
+            let loaded = unwrap_outcome(
+                session
+                    .load_one_to_many::<TeamWithHeroes, HeroChild, _, _>(
+                        &cx,
+                        &mut teams,
+                        |t| &mut t.heroes,
+                        |t| t.id.map_or(Value::Null, Value::BigInt),
+                    )
+                    .await,
+            );
+            assert_eq!(loaded, 3);
+
+            assert!(teams[0].heroes.is_loaded());
+            assert_eq!(teams[0].heroes.len(), 2);
+            assert_eq!(teams[0].heroes.parent_pk(), Some(&Value::BigInt(1)));
+
+            assert!(teams[1].heroes.is_loaded());
+            assert_eq!(teams[1].heroes.len(), 1);
+            assert_eq!(teams[1].heroes.parent_pk(), Some(&Value::BigInt(2)));
+
+            // Unsaved parent gets an empty, loaded collection without querying.
+            assert!(teams[2].heroes.is_loaded());
+            assert_eq!(teams[2].heroes.len(), 0);
+            assert_eq!(teams[2].heroes.parent_pk(), Some(&Value::Null));
+        });
+
+        assert_eq!(state.lock().expect("lock poisoned").query_calls, 1);
+        let sql = state
+            .lock()
+            .expect("lock poisoned")
+            .last_sql
+            .clone()
+            .expect("sql captured");
+        assert!(sql.contains("FROM"), "expected SQL to contain FROM");
+        assert!(
+            sql.contains("heroes"),
+            "expected SQL to target heroes table"
+        );
+        assert!(
+            sql.contains("$1"),
+            "expected Postgres-style placeholders ($1, $2, ...)"
+        );
+        assert!(
+            sql.contains("$2"),
+            "expected Postgres-style placeholders ($1, $2, ...)"
+        );
     }
 
-    impl sqlmodel_core::Connection for MockConnection {
-        type Tx<'conn>
-            = MockTransaction
-        where
-            Self: 'conn;
+    #[test]
+    fn test_flush_cascade_delete_one_to_many_deletes_children_first() {
+        let rt = RuntimeBuilder::current_thread()
+            .build()
+            .expect("create asupersync runtime");
+        let cx = Cx::for_testing();
 
-        fn dialect(&self) -> sqlmodel_core::Dialect {
-            self.dialect
-        }
+        let state = Arc::new(Mutex::new(MockState::default()));
+        let conn = MockConnection::new(Arc::clone(&state));
+        let mut session = Session::with_config(
+            conn,
+            SessionConfig {
+                auto_begin: false,
+                auto_flush: false,
+                expire_on_commit: true,
+                ..Default::default()
+            },
+        );
 
-        fn query(
-            &self,
-            _cx: &Cx,
-            sql: &str,
-            params: &[Value],
-        ) -> impl Future<Output = Outcome<Vec<Row>, Error>> + Send {
-            let params = params.to_vec();
-            let state = Arc::clone(&self.state);
-            let sql = sql.to_string();
-            async move {
-                {
-                    let mut guard = state.lock().expect("lock poisoned");
-                    guard.query_calls += 1;
-                    guard.last_sql = Some(sql.clone());
-                }
+        rt.block_on(async {
+            // Load a parent so it's tracked as Persistent (MockConnection returns a row for id=1).
+            let team = unwrap_outcome(session.get::<TeamWithHeroes>(&cx, 1_i64).await).unwrap();
 
-                let mut rows = Vec::new();
-                let is_teams = sql.contains("teams");
-                let is_heroes = sql.contains("heroes");
+            // Load children into identity map via one-to-many batch loader.
+            let mut teams = vec![team.clone()];
+            let loaded = unwrap_outcome(
+                session
+                    .load_one_to_many::<TeamWithHeroes, HeroChild, _, _>(
+                        &cx,
+                        &mut teams,
+                        |t| &mut t.heroes,
+                        |t| t.id.map_or(Value::Null, Value::BigInt),
+                    )
+                    .await,
+            );
+            assert_eq!(loaded, 2);
+
+            // Mark parent for deletion and flush.
+            session.delete(&team);
+            unwrap_outcome(session.flush(&cx).await);
+
+            // Parent + children should be gone from the identity map after flush.
+            assert_eq!(session.tracked_count(), 0);
+        });
+
+        let guard = state.lock().expect("lock poisoned");
+        assert!(
+            guard.execute_calls >= 2,
+            "expected at least cascade + parent delete"
+        );
+        let (sql0, _params0) = &guard.executed[0];
+        let (sql1, _params1) = &guard.executed[1];
+        assert!(
+            sql0.contains("DELETE") && sql0.contains("heroes"),
+            "expected first delete to target child table"
+        );
+        assert!(
+            sql1.contains("DELETE") && sql1.contains("teams"),
+            "expected second delete to target parent table"
+        );
+    }
 
-                for v in params {
-                    if is_teams {
-                        match v {
-                            Value::BigInt(1) => rows.push(Row::new(
-                                vec!["id".into(), "name".into()],
-                                vec![Value::BigInt(1), Value::Text("Avengers".into())],
-                            )),
-                            Value::BigInt(2) => rows.push(Row::new(
-                                vec!["id".into(), "name".into()],
-                                vec![Value::BigInt(2), Value::Text("X-Men".into())],
-                            )),
-                            _ => {}
-                        }
-                    } else if is_heroes {
-                        // One-to-many child rows keyed by team_id (the query parameter).
-                        match v {
-                            Value::BigInt(1) => {
-                                rows.push(Row::new(
-                                    vec!["id".into(), "team_id".into(), "__parent_pk".into()],
-                                    vec![Value::BigInt(101), Value::BigInt(1), Value::BigInt(1)],
-                                ));
-                                rows.push(Row::new(
-                                    vec!["id".into(), "team_id".into(), "__parent_pk".into()],
-                                    vec![Value::BigInt(102), Value::BigInt(1), Value::BigInt(1)],
-                                ));
-                            }
-                            Value::BigInt(2) => rows.push(Row::new(
-                                vec!["id".into(), "team_id".into(), "__parent_pk".into()],
-                                vec![Value::BigInt(201), Value::BigInt(2), Value::BigInt(2)],
-                            )),
-                            _ => {}
-                        }
-                    }
-                }
+    #[test]
+    fn test_preview_flush_matches_executed_statements() {
+        let rt = RuntimeBuilder::current_thread()
+            .build()
+            .expect("create asupersync runtime");
+        let cx = Cx::for_testing();
 
-                Outcome::Ok(rows)
-            }
-        }
+        let state = Arc::new(Mutex::new(MockState::default()));
+        let conn = MockConnection::new(Arc::clone(&state));
+        let mut session = Session::with_config(
+            conn,
+            SessionConfig {
+                auto_begin: false,
+                ..Default::default()
+            },
+        );
 
-        fn query_one(
-            &self,
-            _cx: &Cx,
-            _sql: &str,
-            _params: &[Value],
-        ) -> impl Future<Output = Outcome<Option<Row>, Error>> + Send {
-            async { Outcome::Ok(None) }
-        }
+        rt.block_on(async {
+            let team = unwrap_outcome(session.get::<TeamWithHeroes>(&cx, 1_i64).await).unwrap();
+            session.delete(&team);
 
-        fn execute(
-            &self,
-            _cx: &Cx,
-            sql: &str,
-            params: &[Value],
-        ) -> impl Future<Output = Outcome<u64, Error>> + Send {
-            let state = Arc::clone(&self.state);
-            let sql = sql.to_string();
-            let params = params.to_vec();
-            async move {
-                let mut guard = state.lock().expect("lock poisoned");
-                guard.execute_calls += 1;
-                guard.executed.push((sql, params));
-                Outcome::Ok(0)
-            }
-        }
+            let mut renamed = unwrap_outcome(session.get::<Team>(&cx, 2_i64).await).unwrap();
+            renamed.name = "Excalibur".to_string();
+            session.mark_dirty(&renamed);
 
-        fn insert(
-            &self,
-            _cx: &Cx,
-            _sql: &str,
-            _params: &[Value],
-        ) -> impl Future<Output = Outcome<i64, Error>> + Send {
-            async { Outcome::Ok(0) }
-        }
+            session.add(&Team {
+                id: Some(3),
+                name: "Defenders".to_string(),
+            });
 
-        fn batch(
-            &self,
-            _cx: &Cx,
-            _statements: &[(String, Vec<Value>)],
-        ) -> impl Future<Output = Outcome<Vec<u64>, Error>> + Send {
-            async { Outcome::Ok(vec![]) }
-        }
+            let preview = session.preview_flush();
+            let kinds: Vec<PreviewKind> = preview.iter().map(|p| p.kind).collect();
+            assert_eq!(
+                kinds,
+                [
+                    PreviewKind::Delete,
+                    PreviewKind::Delete,
+                    PreviewKind::Insert,
+                    PreviewKind::Update,
+                ]
+            );
+            assert_eq!(preview[0].table, "heroes");
+            assert_eq!(
+                preview[3].sql,
+                "UPDATE \"teams\" SET \"name\" = $1 WHERE \"id\" = $2"
+            );
+            assert_eq!(
+                preview[3].params,
+                [Value::Text("Excalibur".into()), Value::BigInt(2)]
+            );
+            // Previewing executes nothing.
+            assert_eq!(state.lock().expect("lock poisoned").execute_calls, 0);
 
-        fn begin(&self, _cx: &Cx) -> impl Future<Output = Outcome<Self::Tx<'_>, Error>> + Send {
-            async { Outcome::Ok(MockTransaction) }
-        }
+            unwrap_outcome(session.flush(&cx).await);
+            let guard = state.lock().expect("lock poisoned");
+            let executed: Vec<(String, Vec<Value>)> =
+                preview.into_iter().map(|p| (p.sql, p.params)).collect();
+            assert_eq!(guard.executed, executed);
+        });
+    }
 
-        fn begin_with(
-            &self,
-            _cx: &Cx,
-            _isolation: sqlmodel_core::connection::IsolationLevel,
-        ) -> impl Future<Output = Outcome<Self::Tx<'_>, Error>> + Send {
-            async { Outcome::Ok(MockTransaction) }
-        }
+    #[test]
+    fn test_flush_detects_stale_update_and_delete() {
+        let rt = RuntimeBuilder::current_thread()
+            .build()
+            .expect("create asupersync runtime");
+        let cx = Cx::for_testing();
 
-        fn prepare(
-            &self,
-            _cx: &Cx,
-            _sql: &str,
-        ) -> impl Future<Output = Outcome<sqlmodel_core::connection::PreparedStatement, Error>> + Send
-        {
-            async {
-                Outcome::Ok(sqlmodel_core::connection::PreparedStatement::new(
-                    0,
-                    String::new(),
-                    0,
-                ))
-            }
-        }
+        let state = Arc::new(Mutex::new(MockState::default()));
+        let conn = MockConnection::new(Arc::clone(&state));
+        let mut session = Session::with_config(
+            conn,
+            SessionConfig {
+                auto_begin: false,
+                detect_stale: true,
+                ..Default::default()
+            },
+        );
 
-        fn query_prepared(
-            &self,
-            _cx: &Cx,
-            _stmt: &sqlmodel_core::connection::PreparedStatement,
-            _params: &[Value],
-        ) -> impl Future<Output = Outcome<Vec<Row>, Error>> + Send {
-            async { Outcome::Ok(vec![]) }
-        }
+        rt.block_on(async {
+            let mut team = unwrap_outcome(session.get::<Team>(&cx, 2_i64).await).unwrap();
+            team.name = "Excalibur".to_string();
+            session.mark_dirty(&team);
 
-        fn execute_prepared(
-            &self,
-            _cx: &Cx,
-            _stmt: &sqlmodel_core::connection::PreparedStatement,
-            _params: &[Value],
-        ) -> impl Future<Output = Outcome<u64, Error>> + Send {
-            async { Outcome::Ok(0) }
-        }
+            let Outcome::Err(Error::StaleObject(err)) = session.flush(&cx).await else {
+                panic!("expected a stale object error");
+            };
+            assert_eq!(err.table, "teams");
+            assert_eq!(err.statement, "UPDATE");
+            assert_eq!(err.pk, [Value::BigInt(2)]);
 
-        fn ping(&self, _cx: &Cx) -> impl Future<Output = Outcome<(), Error>> + Send {
-            async { Outcome::Ok(()) }
-        }
+            // The update stays pending; once the row is found it goes through.
+            state.lock().expect("lock poisoned").rows_affected = 1;
+            unwrap_outcome(session.flush(&cx).await);
 
-        fn close(self, _cx: &Cx) -> impl Future<Output = sqlmodel_core::Result<()>> + Send {
-            async { Ok(()) }
-        }
-    }
+            state.lock().expect("lock poisoned").rows_affected = 0;
+            session.delete(&team);
+            let Outcome::Err(Error::StaleObject(err)) = session.flush(&cx).await else {
+                panic!("expected a stale object error");
+            };
+            assert_eq!(err.statement, "DELETE");
+            assert_eq!(err.pk, [Value::BigInt(2)]);
 
-    struct MockTransaction;
+            // Without detection a zero count is only logged.
+            session.config.detect_stale = false;
+            unwrap_outcome(session.flush(&cx).await);
+            assert_eq!(session.pending_delete_count(), 0);
+        });
+    }
 
-    impl sqlmodel_core::connection::TransactionOps for MockTransaction {
-        fn query(
-            &self,
-            _cx: &Cx,
-            _sql: &str,
-            _params: &[Value],
-        ) -> impl Future<Output = Outcome<Vec<Row>, Error>> + Send {
-            async { Outcome::Ok(vec![]) }
-        }
+    #[test]
+    fn test_version_column_rejects_concurrent_update() {
+        let rt = RuntimeBuilder::current_thread()
+            .build()
+            .expect("create asupersync runtime");
+        let cx = Cx::for_testing();
 
-        fn query_one(
-            &self,
-            _cx: &Cx,
-            _sql: &str,
-            _params: &[Value],
-        ) -> impl Future<Output = Outcome<Option<Row>, Error>> + Send {
-            async { Outcome::Ok(None) }
-        }
+        // Two sessions over the same database, both holding the row at version 1.
+        let state = Arc::new(Mutex::new(MockState::default()));
+        let config = SessionConfig {
+            auto_begin: false,
+            ..Default::default()
+        };
+        let mut alice =
+            Session::with_config(MockConnection::new(Arc::clone(&state)), config.clone());
+        let mut bob = Session::with_config(MockConnection::new(Arc::clone(&state)), config);
+        let roster = Roster {
+            id: 7,
+            name: "Reserves".to_string(),
+            version: 1,
+        };
 
-        fn execute(
-            &self,
-            _cx: &Cx,
-            _sql: &str,
-            _params: &[Value],
-        ) -> impl Future<Output = Outcome<u64, Error>> + Send {
-            async { Outcome::Ok(0) }
-        }
+        rt.block_on(async {
+            alice.add(&roster);
+            bob.add(&roster);
+            unwrap_outcome(alice.flush(&cx).await);
+            unwrap_outcome(bob.flush(&cx).await);
+            state.lock().expect("lock poisoned").executed.clear();
+
+            // Alice's UPDATE matches version 1 and bumps it.
+            state.lock().expect("lock poisoned").rows_affected = 1;
+            let mut renamed = roster.clone();
+            renamed.name = "Starters".to_string();
+            alice.mark_dirty(&renamed);
+            unwrap_outcome(alice.flush(&cx).await);
+            assert_eq!(
+                state.lock().expect("lock poisoned").executed,
+                [(
+                    "UPDATE \"rosters\" SET \"name\" = $1, \"version\" = \"version\" + 1 \
+                     WHERE \"id\" = $2 AND \"version\" = $3"
+                        .to_string(),
+                    vec![
+                        Value::Text("Starters".into()),
+                        Value::BigInt(7),
+                        Value::BigInt(1),
+                    ],
+                )]
+            );
+            let reloaded = unwrap_outcome(alice.get::<Roster>(&cx, 7_i64).await).unwrap();
+            assert_eq!(reloaded.version, 2);
+
+            // Bob still expects version 1, which no longer matches any row.
+            state.lock().expect("lock poisoned").rows_affected = 0;
+            let mut bobs = roster.clone();
+            bobs.name = "Bench".to_string();
+            bob.mark_dirty(&bobs);
+            let Outcome::Err(Error::StaleObject(err)) = bob.flush(&cx).await else {
+                panic!("expected a stale object error");
+            };
+            assert_eq!(err.table, "rosters");
+            assert_eq!(err.statement, "UPDATE");
+            assert_eq!(err.pk, [Value::BigInt(7)]);
+        });
+    }
 
-        fn savepoint(
-            &self,
-            _cx: &Cx,
-            _name: &str,
-        ) -> impl Future<Output = Outcome<(), Error>> + Send {
-            async { Outcome::Ok(()) }
-        }
+    #[test]
+    fn test_version_column_repeated_updates_from_same_object() {
+        let rt = RuntimeBuilder::current_thread()
+            .build()
+            .expect("create asupersync runtime");
+        let cx = Cx::for_testing();
 
-        fn rollback_to(
-            &self,
-            _cx: &Cx,
-            _name: &str,
-        ) -> impl Future<Output = Outcome<(), Error>> + Send {
-            async { Outcome::Ok(()) }
-        }
+        let state = Arc::new(Mutex::new(MockState::default()));
+        let mut session = Session::with_config(
+            MockConnection::new(Arc::clone(&state)),
+            SessionConfig {
+                auto_begin: false,
+                ..Default::default()
+            },
+        );
+        let mut roster = Roster {
+            id: 7,
+            name: "Reserves".to_string(),
+            version: 1,
+        };
 
-        fn release(
-            &self,
-            _cx: &Cx,
-            _name: &str,
-        ) -> impl Future<Output = Outcome<(), Error>> + Send {
-            async { Outcome::Ok(()) }
-        }
+        rt.block_on(async {
+            session.add(&roster);
+            unwrap_outcome(session.flush(&cx).await);
+            state.lock().expect("lock poisoned").executed.clear();
+            state.lock().expect("lock poisoned").rows_affected = 1;
 
-        fn commit(self, _cx: &Cx) -> impl Future<Output = Outcome<(), Error>> + Send {
-            async { Outcome::Ok(()) }
-        }
+            // The caller's object keeps version 1 across both edits.
+            roster.name = "Starters".to_string();
+            session.mark_dirty(&roster);
+            unwrap_outcome(session.flush(&cx).await);
+            roster.name = "Bench".to_string();
+            session.mark_dirty(&roster);
+            unwrap_outcome(session.flush(&cx).await);
 
-        fn rollback(self, _cx: &Cx) -> impl Future<Output = Outcome<(), Error>> + Send {
-            async { Outcome::Ok(()) }
-        }
+            let executed = std::mem::take(&mut state.lock().expect("lock poisoned").executed);
+            let versions: Vec<_> = executed
+                .iter()
+                .map(|(_, params)| params.last().cloned())
+                .collect();
+            assert_eq!(versions, [Some(Value::BigInt(1)), Some(Value::BigInt(2))]);
+            let reloaded = unwrap_outcome(session.get::<Roster>(&cx, 7_i64).await).unwrap();
+            assert_eq!(reloaded.name, "Bench");
+            assert_eq!(reloaded.version, 3);
+        });
     }
 
     #[test]
-    fn test_load_many_single_query_and_populates_lazy() {
+    fn test_commit_without_expire_marks_objects_clean() {
         let rt = RuntimeBuilder::current_thread()
             .build()
             .expect("create asupersync runtime");
@@ -3777,343 +8208,422 @@ mod tests {
 
         let state = Arc::new(Mutex::new(MockState::default()));
         let conn = MockConnection::new(Arc::clone(&state));
-        let mut session = Session::new(conn);
-
-        let heroes = vec![
-            Hero {
-                id: Some(1),
-                team: Lazy::from_fk(1_i64),
-            },
-            Hero {
-                id: Some(2),
-                team: Lazy::from_fk(2_i64),
-            },
-            Hero {
-                id: Some(3),
-                team: Lazy::from_fk(1_i64),
-            },
-            Hero {
-                id: Some(4),
-                team: Lazy::empty(),
-            },
-            Hero {
-                id: Some(5),
-                team: Lazy::from_fk(999_i64),
+        let mut session = Session::with_config(
+            conn,
+            SessionConfig {
+                expire_on_commit: false,
+                ..Default::default()
             },
-        ];
+        );
+        let updates = |state: &Arc<Mutex<MockState>>| -> Vec<(String, Vec<Value>)> {
+            let mut guard = state.lock().expect("lock poisoned");
+            let executed = std::mem::take(&mut guard.executed);
+            executed
+                .into_iter()
+                .filter(|(sql, _)| sql.starts_with("UPDATE"))
+                .collect()
+        };
 
         rt.block_on(async {
-            let loaded = unwrap_outcome(
-                session
-                    .load_many::<Hero, Team, _>(&cx, &heroes, |h| &h.team)
-                    .await,
-            );
-            assert_eq!(loaded, 3);
-
-            // Populated / cached
-            assert!(heroes[0].team.is_loaded());
-            assert_eq!(heroes[0].team.get().unwrap().name, "Avengers");
-            assert_eq!(heroes[1].team.get().unwrap().name, "X-Men");
-            assert_eq!(heroes[2].team.get().unwrap().name, "Avengers");
-
-            // Empty FK gets cached as loaded-none
-            assert!(heroes[3].team.is_loaded());
-            assert!(heroes[3].team.get().is_none());
-
-            // Missing object gets cached as loaded-none
-            assert!(heroes[4].team.is_loaded());
-            assert!(heroes[4].team.get().is_none());
-
-            // Identity map populated: get() should not hit the connection again
-            let team1 = unwrap_outcome(session.get::<Team>(&cx, 1_i64).await);
+            state.lock().expect("lock poisoned").rows_affected = 1;
+            let mut first = unwrap_outcome(session.get::<Team>(&cx, 1_i64).await).unwrap();
+            let mut second = unwrap_outcome(session.get::<Team>(&cx, 2_i64).await).unwrap();
+
+            first.name = "Renamed".to_string();
+            session.mark_dirty(&first);
+            unwrap_outcome(session.commit(&cx).await);
+            assert_eq!(updates(&state).len(), 1);
+            assert_eq!(session.object_state(&first), Some(ObjectState::Persistent));
+            assert!(!session.is_modified(&first));
+
+            // Only the second object's change goes out with the next commit.
+            second.name = "Also renamed".to_string();
+            session.mark_dirty(&second);
+            unwrap_outcome(session.commit(&cx).await);
+            let executed = updates(&state);
+            assert_eq!(executed.len(), 1);
             assert_eq!(
-                team1,
-                Some(Team {
-                    id: Some(1),
-                    name: "Avengers".to_string()
-                })
+                executed[0].1,
+                vec![Value::Text("Also renamed".into()), Value::BigInt(2)]
             );
-        });
 
-        assert_eq!(state.lock().expect("lock poisoned").query_calls, 1);
+            // Nothing left to write.
+            unwrap_outcome(session.commit(&cx).await);
+            assert!(updates(&state).is_empty());
+        });
     }
 
-    #[derive(Debug, Clone, Serialize, Deserialize)]
-    struct HeroChild {
-        id: Option<i64>,
-        team_id: i64,
+    #[derive(Debug, Default)]
+    struct RecordingProgress {
+        phases: Mutex<Vec<String>>,
     }
 
-    impl Model for HeroChild {
-        const TABLE_NAME: &'static str = "heroes";
-        const PRIMARY_KEY: &'static [&'static str] = &["id"];
-
-        fn fields() -> &'static [sqlmodel_core::FieldInfo] {
-            &[]
-        }
-
-        fn to_row(&self) -> Vec<(&'static str, Value)> {
-            vec![
-                ("id", self.id.map_or(Value::Null, Value::BigInt)),
-                ("team_id", Value::BigInt(self.team_id)),
-            ]
-        }
-
-        fn from_row(row: &Row) -> sqlmodel_core::Result<Self> {
-            let id: i64 = row.get_named("id")?;
-            let team_id: i64 = row.get_named("team_id")?;
-            Ok(Self {
-                id: Some(id),
-                team_id,
-            })
-        }
-
-        fn primary_key_value(&self) -> Vec<Value> {
-            self.id
-                .map_or_else(|| vec![Value::Null], |id| vec![Value::BigInt(id)])
-        }
-
-        fn is_new(&self) -> bool {
-            self.id.is_none()
+    impl ProgressReporter for RecordingProgress {
+        fn on_phase(&self, phase: &str) {
+            self.phases
+                .lock()
+                .expect("lock poisoned")
+                .push(phase.to_string());
         }
     }
 
-    #[derive(Debug, Clone, Serialize, Deserialize)]
-    struct TeamWithHeroes {
-        id: Option<i64>,
-        heroes: sqlmodel_core::RelatedMany<HeroChild>,
-    }
-
-    impl Model for TeamWithHeroes {
-        const TABLE_NAME: &'static str = "teams";
-        const PRIMARY_KEY: &'static [&'static str] = &["id"];
-        const RELATIONSHIPS: &'static [sqlmodel_core::RelationshipInfo] =
-            &[sqlmodel_core::RelationshipInfo::new(
-                "heroes",
-                "heroes",
-                sqlmodel_core::RelationshipKind::OneToMany,
-            )
-            .remote_key("team_id")
-            .cascade_delete(true)];
-
-        fn fields() -> &'static [sqlmodel_core::FieldInfo] {
-            &[]
-        }
-
-        fn to_row(&self) -> Vec<(&'static str, Value)> {
-            vec![("id", self.id.map_or(Value::Null, Value::BigInt))]
-        }
-
-        fn from_row(row: &Row) -> sqlmodel_core::Result<Self> {
-            let id: i64 = row.get_named("id")?;
-            Ok(Self {
-                id: Some(id),
-                heroes: sqlmodel_core::RelatedMany::new("team_id"),
-            })
-        }
+    #[test]
+    fn test_progress_reports_flush_and_commit_phases() {
+        let rt = RuntimeBuilder::current_thread()
+            .build()
+            .expect("create asupersync runtime");
+        let cx = Cx::for_testing();
 
-        fn primary_key_value(&self) -> Vec<Value> {
-            self.id
-                .map_or_else(|| vec![Value::Null], |id| vec![Value::BigInt(id)])
-        }
+        let state = Arc::new(Mutex::new(MockState::default()));
+        let conn = MockConnection::new(Arc::clone(&state));
+        let progress = Arc::new(RecordingProgress::default());
+        let mut session = Session::with_config(
+            conn,
+            SessionConfig {
+                progress: Some(Arc::clone(&progress) as Arc<dyn ProgressReporter>),
+                ..Default::default()
+            },
+        );
 
-        fn is_new(&self) -> bool {
-            self.id.is_none()
-        }
-    }
+        rt.block_on(async {
+            session.add(&Team {
+                id: Some(100),
+                name: "New Team".to_string(),
+            });
+            unwrap_outcome(session.commit(&cx).await);
+        });
 
-    #[derive(Debug, Clone, Serialize, Deserialize)]
-    struct TeamWithHeroesPassive {
-        id: Option<i64>,
-        heroes: sqlmodel_core::RelatedMany<HeroChild>,
+        assert_eq!(
+            *progress.phases.lock().expect("lock poisoned"),
+            ["Flushing", "Committing"]
+        );
     }
 
-    impl Model for TeamWithHeroesPassive {
-        const TABLE_NAME: &'static str = "teams_passive";
-        const PRIMARY_KEY: &'static [&'static str] = &["id"];
-        const RELATIONSHIPS: &'static [sqlmodel_core::RelationshipInfo] =
-            &[sqlmodel_core::RelationshipInfo::new(
-                "heroes",
-                "heroes",
-                sqlmodel_core::RelationshipKind::OneToMany,
-            )
-            .remote_key("team_id")
-            .cascade_delete(true)
-            .passive_deletes(sqlmodel_core::PassiveDeletes::Passive)];
-
-        fn fields() -> &'static [sqlmodel_core::FieldInfo] {
-            &[]
-        }
-
-        fn to_row(&self) -> Vec<(&'static str, Value)> {
-            vec![("id", self.id.map_or(Value::Null, Value::BigInt))]
-        }
+    #[test]
+    fn test_rollback_to_savepoint_restores_identity_map() {
+        let rt = RuntimeBuilder::current_thread()
+            .build()
+            .expect("create asupersync runtime");
+        let cx = Cx::for_testing();
 
-        fn from_row(row: &Row) -> sqlmodel_core::Result<Self> {
-            let id: i64 = row.get_named("id")?;
-            Ok(Self {
-                id: Some(id),
-                heroes: sqlmodel_core::RelatedMany::new("team_id"),
-            })
-        }
+        let state = Arc::new(Mutex::new(MockState::default()));
+        let conn = MockConnection::new(Arc::clone(&state));
+        let mut session = Session::with_config(
+            conn,
+            SessionConfig {
+                auto_begin: false,
+                ..Default::default()
+            },
+        );
+        let keep = Team {
+            id: Some(10),
+            name: "Keep".to_string(),
+        };
+        let newcomer = Team {
+            id: Some(11),
+            name: "Newcomer".to_string(),
+        };
 
-        fn primary_key_value(&self) -> Vec<Value> {
-            self.id
-                .map_or_else(|| vec![Value::Null], |id| vec![Value::BigInt(id)])
-        }
+        rt.block_on(async {
+            unwrap_outcome(session.begin(&cx).await);
+            let mut team = unwrap_outcome(session.get::<Team>(&cx, 2_i64).await).unwrap();
+            session.add(&keep);
+            unwrap_outcome(session.flush(&cx).await);
 
-        fn is_new(&self) -> bool {
-            self.id.is_none()
-        }
-    }
+            // Update, delete and insert inside a savepoint, then undo it all.
+            let sp = unwrap_outcome(session.savepoint(&cx).await);
+            state.lock().expect("lock poisoned").rows_affected = 1;
+            team.name = "Renamed".to_string();
+            session.mark_dirty(&team);
+            session.delete(&keep);
+            session.add(&newcomer);
+            unwrap_outcome(session.flush(&cx).await);
+            assert!(!session.contains(&keep));
+            assert!(!session.is_modified(&team));
 
-    #[derive(Debug, Clone, Serialize, Deserialize)]
-    struct HeroCompositeChild {
-        id: Option<i64>,
-        team_id1: i64,
-        team_id2: i64,
+            unwrap_outcome(session.rollback_to_savepoint(&cx, &sp).await);
+            assert_eq!(session.savepoints(), [sp.as_str()]);
+            assert!(session.contains(&keep));
+            assert!(!session.is_modified(&keep));
+            assert!(!session.contains(&newcomer));
+            // The UPDATE is gone from the database but not from memory.
+            assert!(session.is_modified(&team));
+            assert_eq!(session.pending_dirty_count(), 1);
+            assert_eq!(session.pending_new_count(), 0);
+            assert_eq!(session.pending_delete_count(), 0);
+
+            // A released inner savepoint is undone by rolling back the outer one.
+            let outer = unwrap_outcome(session.savepoint(&cx).await);
+            let inner = unwrap_outcome(session.savepoint(&cx).await);
+            session.delete(&keep);
+            unwrap_outcome(session.flush(&cx).await);
+            unwrap_outcome(session.release_savepoint(&cx, &inner).await);
+            assert!(!session.contains(&keep));
+            unwrap_outcome(session.rollback_to_savepoint(&cx, &outer).await);
+            assert!(session.contains(&keep));
+            assert!(!session.is_modified(&keep));
+
+            // A full rollback drops what was inserted in the transaction.
+            unwrap_outcome(session.rollback(&cx).await);
+            assert!(!session.contains(&keep));
+            assert!(session.contains(&team));
+        });
     }
 
-    impl Model for HeroCompositeChild {
-        const TABLE_NAME: &'static str = "heroes_composite";
-        const PRIMARY_KEY: &'static [&'static str] = &["id"];
-
-        fn fields() -> &'static [sqlmodel_core::FieldInfo] {
-            &[]
-        }
+    #[test]
+    fn test_nested_savepoints_rollback_middle_keeps_stack_consistent() {
+        let rt = RuntimeBuilder::current_thread()
+            .build()
+            .expect("create asupersync runtime");
+        let cx = Cx::for_testing();
 
-        fn to_row(&self) -> Vec<(&'static str, Value)> {
-            vec![
-                ("id", self.id.map_or(Value::Null, Value::BigInt)),
-                ("team_id1", Value::BigInt(self.team_id1)),
-                ("team_id2", Value::BigInt(self.team_id2)),
-            ]
-        }
+        let state = Arc::new(Mutex::new(MockState::default()));
+        let conn = MockConnection::new(Arc::clone(&state));
+        let mut session = Session::new(conn);
 
-        fn from_row(row: &Row) -> sqlmodel_core::Result<Self> {
-            let id: i64 = row.get_named("id")?;
-            let team_id1: i64 = row.get_named("team_id1")?;
-            let team_id2: i64 = row.get_named("team_id2")?;
-            Ok(Self {
-                id: Some(id),
-                team_id1,
-                team_id2,
-            })
-        }
+        rt.block_on(async {
+            let outer = unwrap_outcome(session.savepoint(&cx).await);
+            let middle = unwrap_outcome(session.savepoint(&cx).await);
+            let inner = unwrap_outcome(session.savepoint(&cx).await);
+            assert_eq!(
+                (outer.as_str(), middle.as_str(), inner.as_str()),
+                ("sp_1", "sp_2", "sp_3")
+            );
+            assert_eq!(session.savepoints(), ["sp_1", "sp_2", "sp_3"]);
+
+            // Rolling back the middle savepoint pops the inner one but
+            // keeps the middle one active.
+            unwrap_outcome(session.rollback_to_savepoint(&cx, &middle).await);
+            assert_eq!(session.savepoints(), ["sp_1", "sp_2"]);
+
+            // The popped names are gone; they can't be released again.
+            assert!(matches!(
+                session.release_savepoint(&cx, &inner).await,
+                Outcome::Err(Error::Transaction(TransactionError {
+                    kind: TransactionErrorKind::SavepointNotFound,
+                    ..
+                }))
+            ));
 
-        fn primary_key_value(&self) -> Vec<Value> {
-            self.id
-                .map_or_else(|| vec![Value::Null], |id| vec![Value::BigInt(id)])
-        }
+            // New savepoints never reuse a name.
+            let next = unwrap_outcome(session.savepoint(&cx).await);
+            assert_eq!(next, "sp_4");
+            assert_eq!(session.savepoints(), ["sp_1", "sp_2", "sp_4"]);
 
-        fn is_new(&self) -> bool {
-            self.id.is_none()
-        }
-    }
+            unwrap_outcome(session.release_savepoint(&cx, &outer).await);
+            assert!(session.savepoints().is_empty());
+        });
 
-    #[derive(Debug, Clone, Serialize, Deserialize)]
-    struct TeamComposite {
-        id1: Option<i64>,
-        id2: Option<i64>,
+        let guard = state.lock().expect("lock poisoned");
+        let sqls: Vec<&str> = guard.executed.iter().map(|(sql, _)| sql.as_str()).collect();
+        assert_eq!(
+            sqls,
+            [
+                "BEGIN",
+                "SAVEPOINT sp_1",
+                "SAVEPOINT sp_2",
+                "SAVEPOINT sp_3",
+                "ROLLBACK TO SAVEPOINT sp_2",
+                "SAVEPOINT sp_4",
+                "RELEASE SAVEPOINT sp_1",
+            ]
+        );
     }
 
-    impl Model for TeamComposite {
-        const TABLE_NAME: &'static str = "teams_composite";
-        const PRIMARY_KEY: &'static [&'static str] = &["id1", "id2"];
-        const RELATIONSHIPS: &'static [sqlmodel_core::RelationshipInfo] =
-            &[sqlmodel_core::RelationshipInfo::new(
-                "heroes",
-                "heroes_composite",
-                sqlmodel_core::RelationshipKind::OneToMany,
-            )
-            .remote_keys(&["team_id1", "team_id2"])
-            .cascade_delete(true)];
+    #[test]
+    fn test_nested_releases_on_ok_and_rolls_back_on_err() {
+        let rt = RuntimeBuilder::current_thread()
+            .build()
+            .expect("create asupersync runtime");
+        let cx = Cx::for_testing();
 
-        fn fields() -> &'static [sqlmodel_core::FieldInfo] {
-            &[]
-        }
+        let state = Arc::new(Mutex::new(MockState::default()));
+        let conn = MockConnection::new(Arc::clone(&state));
+        let mut session = Session::new(conn);
 
-        fn to_row(&self) -> Vec<(&'static str, Value)> {
-            vec![
-                ("id1", self.id1.map_or(Value::Null, Value::BigInt)),
-                ("id2", self.id2.map_or(Value::Null, Value::BigInt)),
+        rt.block_on(async {
+            let value = unwrap_outcome(
+                session
+                    .nested(&cx, async |outer| {
+                        assert_eq!(outer.savepoints(), ["sp_1"]);
+                        let inner = outer
+                            .nested(&cx, async |inner| {
+                                assert_eq!(inner.savepoints(), ["sp_1", "sp_2"]);
+                                Outcome::<(), Error>::Err(Error::Custom("boom".to_string()))
+                            })
+                            .await;
+                        assert!(matches!(inner, Outcome::Err(Error::Custom(_))));
+                        assert_eq!(outer.savepoints(), ["sp_1"]);
+                        Outcome::Ok(42)
+                    })
+                    .await,
+            );
+            assert_eq!(value, 42);
+            assert!(session.savepoints().is_empty());
+        });
+
+        let guard = state.lock().expect("lock poisoned");
+        let sqls: Vec<&str> = guard.executed.iter().map(|(sql, _)| sql.as_str()).collect();
+        assert_eq!(
+            sqls,
+            [
+                "BEGIN",
+                "SAVEPOINT sp_1",
+                "SAVEPOINT sp_2",
+                "ROLLBACK TO SAVEPOINT sp_2",
+                "RELEASE SAVEPOINT sp_2",
+                "RELEASE SAVEPOINT sp_1",
             ]
-        }
+        );
+    }
 
-        fn from_row(row: &Row) -> sqlmodel_core::Result<Self> {
-            let id1: i64 = row.get_named("id1")?;
-            let id2: i64 = row.get_named("id2")?;
-            Ok(Self {
-                id1: Some(id1),
-                id2: Some(id2),
-            })
-        }
+    #[test]
+    fn test_nested_rolls_back_before_panic_resumes() {
+        let rt = RuntimeBuilder::current_thread()
+            .build()
+            .expect("create asupersync runtime");
+        let cx = Cx::for_testing();
 
-        fn primary_key_value(&self) -> Vec<Value> {
-            match (self.id1, self.id2) {
-                (Some(a), Some(b)) => vec![Value::BigInt(a), Value::BigInt(b)],
-                _ => vec![Value::Null, Value::Null],
-            }
-        }
+        let state = Arc::new(Mutex::new(MockState::default()));
+        let conn = MockConnection::new(Arc::clone(&state));
+        let mut session = Session::new(conn);
 
-        fn is_new(&self) -> bool {
-            self.id1.is_none() || self.id2.is_none()
-        }
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            rt.block_on(async {
+                let _ = session
+                    .nested(&cx, async |_| -> Outcome<(), Error> {
+                        panic!("closure failed")
+                    })
+                    .await;
+            });
+        }));
+        assert!(result.is_err());
+        assert!(session.savepoints().is_empty());
+
+        let guard = state.lock().expect("lock poisoned");
+        let sqls: Vec<&str> = guard.executed.iter().map(|(sql, _)| sql.as_str()).collect();
+        assert_eq!(
+            sqls,
+            [
+                "BEGIN",
+                "SAVEPOINT sp_1",
+                "ROLLBACK TO SAVEPOINT sp_1",
+                "RELEASE SAVEPOINT sp_1",
+            ]
+        );
     }
 
-    #[derive(Debug, Clone, Serialize, Deserialize)]
-    struct TeamCompositePassive {
-        id1: Option<i64>,
-        id2: Option<i64>,
+    #[test]
+    fn test_defer_constraints_begins_transaction() {
+        let rt = RuntimeBuilder::current_thread()
+            .build()
+            .expect("create asupersync runtime");
+        let cx = Cx::for_testing();
+
+        let state = Arc::new(Mutex::new(MockState::default()));
+        let conn = MockConnection::new(Arc::clone(&state));
+        let mut session = Session::new(conn);
+
+        rt.block_on(async {
+            unwrap_outcome(session.defer_constraints(&cx).await);
+        });
+        assert!(session.in_transaction());
+
+        let guard = state.lock().expect("lock poisoned");
+        let sqls: Vec<&str> = guard.executed.iter().map(|(sql, _)| sql.as_str()).collect();
+        assert_eq!(sqls.len(), 2);
+        assert!(sqls[0].starts_with("BEGIN"));
+        assert_eq!(sqls[1], "SET CONSTRAINTS ALL DEFERRED");
     }
 
-    impl Model for TeamCompositePassive {
-        const TABLE_NAME: &'static str = "teams_composite_passive";
-        const PRIMARY_KEY: &'static [&'static str] = &["id1", "id2"];
-        const RELATIONSHIPS: &'static [sqlmodel_core::RelationshipInfo] =
-            &[sqlmodel_core::RelationshipInfo::new(
-                "heroes",
-                "heroes_composite",
-                sqlmodel_core::RelationshipKind::OneToMany,
-            )
-            .remote_keys(&["team_id1", "team_id2"])
-            .cascade_delete(true)
-            .passive_deletes(sqlmodel_core::PassiveDeletes::Passive)];
+    #[test]
+    fn test_begin_uses_configured_transaction_mode() {
+        let rt = RuntimeBuilder::current_thread()
+            .build()
+            .expect("create asupersync runtime");
+        let cx = Cx::for_testing();
 
-        fn fields() -> &'static [sqlmodel_core::FieldInfo] {
-            &[]
-        }
+        let state = Arc::new(Mutex::new(MockState::default()));
+        let conn = MockConnection::new(Arc::clone(&state));
+        let mut session = Session::with_config(
+            conn,
+            SessionConfig {
+                transaction_mode: TransactionMode::new(sqlmodel_core::IsolationLevel::Serializable)
+                    .deferrable(true),
+                read_only: true,
+                ..Default::default()
+            },
+        );
 
-        fn to_row(&self) -> Vec<(&'static str, Value)> {
-            vec![
-                ("id1", self.id1.map_or(Value::Null, Value::BigInt)),
-                ("id2", self.id2.map_or(Value::Null, Value::BigInt)),
+        rt.block_on(async {
+            // Auto-begin on flush picks up the configured mode.
+            unwrap_outcome(session.flush(&cx).await);
+            unwrap_outcome(session.commit(&cx).await);
+
+            let mode = TransactionMode::new(sqlmodel_core::IsolationLevel::RepeatableRead);
+            unwrap_outcome(session.begin_with_mode(&cx, mode).await);
+            // Already in a transaction: no second BEGIN.
+            unwrap_outcome(session.begin(&cx).await);
+        });
+
+        let guard = state.lock().expect("lock poisoned");
+        let begins: Vec<&str> = guard
+            .executed
+            .iter()
+            .map(|(sql, _)| sql.as_str())
+            .filter(|sql| sql.starts_with("BEGIN"))
+            .collect();
+        assert_eq!(
+            begins,
+            [
+                "BEGIN ISOLATION LEVEL SERIALIZABLE READ ONLY DEFERRABLE",
+                "BEGIN ISOLATION LEVEL REPEATABLE READ",
             ]
-        }
+        );
+    }
 
-        fn from_row(row: &Row) -> sqlmodel_core::Result<Self> {
-            let id1: i64 = row.get_named("id1")?;
-            let id2: i64 = row.get_named("id2")?;
-            Ok(Self {
-                id1: Some(id1),
-                id2: Some(id2),
-            })
-        }
+    #[test]
+    fn test_begin_renders_transaction_mode_per_dialect() {
+        let rt = RuntimeBuilder::current_thread()
+            .build()
+            .expect("create asupersync runtime");
+        let cx = Cx::for_testing();
 
-        fn primary_key_value(&self) -> Vec<Value> {
-            match (self.id1, self.id2) {
-                (Some(a), Some(b)) => vec![Value::BigInt(a), Value::BigInt(b)],
-                _ => vec![Value::Null, Value::Null],
-            }
-        }
+        let state = Arc::new(Mutex::new(MockState::default()));
+        let mut conn = MockConnection::new(Arc::clone(&state));
+        conn.dialect = sqlmodel_core::Dialect::Mysql;
+        let config = SessionConfig {
+            transaction_mode: TransactionMode::new(sqlmodel_core::IsolationLevel::RepeatableRead),
+            read_only: true,
+            ..Default::default()
+        };
+        let mut mysql = Session::with_config(conn.clone(), config.clone());
+        conn.dialect = sqlmodel_core::Dialect::Sqlite;
+        let mut sqlite = Session::with_config(conn, config);
 
-        fn is_new(&self) -> bool {
-            self.id1.is_none() || self.id2.is_none()
-        }
+        rt.block_on(async {
+            unwrap_outcome(mysql.begin(&cx).await);
+            assert!(mysql.in_transaction());
+
+            // SQLite has no READ ONLY transactions; the modifier is dropped.
+            unwrap_outcome(sqlite.begin(&cx).await);
+            assert!(sqlite.in_transaction());
+        });
+
+        let guard = state.lock().expect("lock poisoned");
+        let sqls: Vec<&str> = guard.executed.iter().map(|(sql, _)| sql.as_str()).collect();
+        assert_eq!(
+            sqls,
+            [
+                "SET TRANSACTION ISOLATION LEVEL REPEATABLE READ",
+                "START TRANSACTION READ ONLY",
+                "BEGIN IMMEDIATE",
+            ]
+        );
     }
 
     #[test]
-    fn test_load_one_to_many_single_query_and_populates_related_many() {
+    fn test_savepoints_cleared_on_commit() {
         let rt = RuntimeBuilder::current_thread()
             .build()
             .expect("create asupersync runtime");
@@ -4123,72 +8633,65 @@ mod tests {
         let conn = MockConnection::new(Arc::clone(&state));
         let mut session = Session::new(conn);
 
-        let mut teams = vec![
-            TeamWithHeroes {
-                id: Some(1),
-                heroes: sqlmodel_core::RelatedMany::new("team_id"),
-            },
-            TeamWithHeroes {
-                id: Some(2),
-                heroes: sqlmodel_core::RelatedMany::new("team_id"),
-            },
-            TeamWithHeroes {
-                id: None,
-                heroes: sqlmodel_core::RelatedMany::new("team_id"),
-            },
-        ];
-
         rt.block_on(async {
-            let loaded = unwrap_outcome(
-                session
-                    .load_one_to_many::<TeamWithHeroes, HeroChild, _, _>(
-                        &cx,
-                        &mut teams,
-                        |t| &mut t.heroes,
-                        |t| t.id.map_or(Value::Null, Value::BigInt),
-                    )
-                    .await,
-            );
-            assert_eq!(loaded, 3);
+            unwrap_outcome(session.savepoint(&cx).await);
+            unwrap_outcome(session.savepoint(&cx).await);
+            unwrap_outcome(session.commit(&cx).await);
+            assert!(session.savepoints().is_empty());
 
-            assert!(teams[0].heroes.is_loaded());
-            assert_eq!(teams[0].heroes.len(), 2);
-            assert_eq!(teams[0].heroes.parent_pk(), Some(&Value::BigInt(1)));
+            // The counter keeps going across transactions.
+            assert_eq!(unwrap_outcome(session.savepoint(&cx).await), "sp_3");
+        });
+    }
 
-            assert!(teams[1].heroes.is_loaded());
-            assert_eq!(teams[1].heroes.len(), 1);
-            assert_eq!(teams[1].heroes.parent_pk(), Some(&Value::BigInt(2)));
+    #[test]
+    fn test_flush_collects_statement_timings_when_enabled() {
+        let rt = RuntimeBuilder::current_thread()
+            .build()
+            .expect("create asupersync runtime");
+        let cx = Cx::for_testing();
 
-            // Unsaved parent gets an empty, loaded collection without querying.
-            assert!(teams[2].heroes.is_loaded());
-            assert_eq!(teams[2].heroes.len(), 0);
-            assert_eq!(teams[2].heroes.parent_pk(), Some(&Value::Null));
+        let state = Arc::new(Mutex::new(MockState::default()));
+        let conn = MockConnection::new(Arc::clone(&state));
+        let mut session = Session::with_config(
+            conn,
+            SessionConfig {
+                auto_begin: false,
+                collect_timings: true,
+                ..Default::default()
+            },
+        );
+
+        rt.block_on(async {
+            session.add(&Team {
+                id: Some(1),
+                name: "Avengers".to_string(),
+            });
+            session.add(&Team {
+                id: Some(2),
+                name: "X-Men".to_string(),
+            });
+            unwrap_outcome(session.flush(&cx).await);
         });
 
-        assert_eq!(state.lock().expect("lock poisoned").query_calls, 1);
-        let sql = state
-            .lock()
-            .expect("lock poisoned")
-            .last_sql
-            .clone()
-            .expect("sql captured");
-        assert!(sql.contains("FROM"), "expected SQL to contain FROM");
-        assert!(
-            sql.contains("heroes"),
-            "expected SQL to target heroes table"
-        );
-        assert!(
-            sql.contains("$1"),
-            "expected Postgres-style placeholders ($1, $2, ...)"
-        );
-        assert!(
-            sql.contains("$2"),
-            "expected Postgres-style placeholders ($1, $2, ...)"
-        );
+        let timings = session.last_flush_timings();
+        assert_eq!(timings.len(), 2);
+        let guard = state.lock().expect("lock poisoned");
+        for ((sql, _), (executed_sql, _)) in timings.iter().zip(&guard.executed) {
+            assert_eq!(sql, executed_sql);
+            assert!(sql.starts_with("INSERT INTO"));
+        }
+        drop(guard);
+
+        // A subsequent flush with nothing pending resets the timings.
+        rt.block_on(async {
+            unwrap_outcome(session.flush(&cx).await);
+        });
+        assert!(session.last_flush_timings().is_empty());
     }
 
     #[test]
-    fn test_flush_cascade_delete_one_to_many_deletes_children_first() {
+    fn test_flush_skips_statement_timings_by_default() {
         let rt = RuntimeBuilder::current_thread()
             .build()
             .expect("create asupersync runtime");
@@ -4200,52 +8703,20 @@ mod tests {
             conn,
             SessionConfig {
                 auto_begin: false,
-                auto_flush: false,
-                expire_on_commit: true,
+                ..Default::default()
             },
         );
 
         rt.block_on(async {
-            // Load a parent so it's tracked as Persistent (MockConnection returns a row for id=1).
-            let team = unwrap_outcome(session.get::<TeamWithHeroes>(&cx, 1_i64).await).unwrap();
-
-            // Load children into identity map via one-to-many batch loader.
-            let mut teams = vec![team.clone()];
-            let loaded = unwrap_outcome(
-                session
-                    .load_one_to_many::<TeamWithHeroes, HeroChild, _, _>(
-                        &cx,
-                        &mut teams,
-                        |t| &mut t.heroes,
-                        |t| t.id.map_or(Value::Null, Value::BigInt),
-                    )
-                    .await,
-            );
-            assert_eq!(loaded, 2);
-
-            // Mark parent for deletion and flush.
-            session.delete(&team);
+            session.add(&Team {
+                id: Some(1),
+                name: "Avengers".to_string(),
+            });
             unwrap_outcome(session.flush(&cx).await);
-
-            // Parent + children should be gone from the identity map after flush.
-            assert_eq!(session.tracked_count(), 0);
         });
 
-        let guard = state.lock().expect("lock poisoned");
-        assert!(
-            guard.execute_calls >= 2,
-            "expected at least cascade + parent delete"
-        );
-        let (sql0, _params0) = &guard.executed[0];
-        let (sql1, _params1) = &guard.executed[1];
-        assert!(
-            sql0.contains("DELETE") && sql0.contains("heroes"),
-            "expected first delete to target child table"
-        );
-        assert!(
-            sql1.contains("DELETE") && sql1.contains("teams"),
-            "expected second delete to target parent table"
-        );
+        assert_eq!(state.lock().expect("lock poisoned").execute_calls, 1);
+        assert!(session.last_flush_timings().is_empty());
     }
 
     #[test]
@@ -4263,6 +8734,7 @@ mod tests {
                 auto_begin: false,
                 auto_flush: false,
                 expire_on_commit: true,
+                ..Default::default()
             },
         );
 
@@ -4318,6 +8790,7 @@ mod tests {
                 auto_begin: false,
                 auto_flush: false,
                 expire_on_commit: true,
+                ..Default::default()
             },
         );
 
@@ -4340,6 +8813,7 @@ mod tests {
                 pk_columns: vec!["id1", "id2"],
                 pk_values: vec![Value::BigInt(1), Value::BigInt(2)],
                 relationships: TeamComposite::RELATIONSHIPS,
+                version: VersionColumn::of::<TeamComposite>(),
                 expired_attributes: None,
             },
         );
@@ -4370,6 +8844,7 @@ mod tests {
                     pk_columns: vec!["id"],
                     pk_values: vec![Value::BigInt(child_id)],
                     relationships: HeroCompositeChild::RELATIONSHIPS,
+                    version: VersionColumn::of::<HeroCompositeChild>(),
                     expired_attributes: None,
                 },
             );
@@ -4415,6 +8890,7 @@ mod tests {
                 auto_begin: false,
                 auto_flush: false,
                 expire_on_commit: true,
+                ..Default::default()
             },
         );
 
@@ -4436,6 +8912,7 @@ mod tests {
                 pk_columns: vec!["id1", "id2"],
                 pk_values: vec![Value::BigInt(1), Value::BigInt(2)],
                 relationships: TeamCompositePassive::RELATIONSHIPS,
+                version: VersionColumn::of::<TeamCompositePassive>(),
                 expired_attributes: None,
             },
         );
@@ -4457,6 +8934,7 @@ mod tests {
                 pk_columns: vec!["id"],
                 pk_values: vec![Value::BigInt(10)],
                 relationships: HeroCompositeChild::RELATIONSHIPS,
+                version: VersionColumn::of::<HeroCompositeChild>(),
                 expired_attributes: None,
             },
         );
@@ -4586,6 +9064,7 @@ mod tests {
                 auto_begin: false,
                 auto_flush: false,
                 expire_on_commit: true,
+                ..Default::default()
             },
         );
 
@@ -4614,6 +9093,7 @@ mod tests {
                 pk_columns: vec!["id1", "id2"],
                 pk_values: vec![Value::BigInt(1), Value::BigInt(2)],
                 relationships: MmParentComposite::RELATIONSHIPS,
+                version: VersionColumn::of::<MmParentComposite>(),
                 expired_attributes: None,
             },
         );
@@ -4660,6 +9140,7 @@ mod tests {
                 auto_begin: false,
                 auto_flush: false,
                 expire_on_commit: true,
+                ..Default::default()
             },
         );
 
@@ -4856,6 +9337,84 @@ mod tests {
         assert_eq!(info.tracked, 2);
     }
 
+    #[test]
+    fn test_add_all_owned_keeps_order_and_merges_duplicates() {
+        let state = Arc::new(Mutex::new(MockState::default()));
+        let conn = MockConnection::new(Arc::clone(&state));
+        let mut session = Session::new(conn);
+
+        let team = |id, name: &str| Team {
+            id: Some(id),
+            name: name.to_string(),
+        };
+        session.add_all_owned(vec![
+            team(402, "Second"),
+            team(401, "First"),
+            team(402, "Second, renamed"),
+        ]);
+
+        let info = session.debug_state();
+        assert_eq!(info.pending_new, 2);
+        assert_eq!(info.tracked, 2);
+        assert_eq!(
+            session.pending_new,
+            vec![
+                ObjectKey::from_model(&team(402, "")),
+                ObjectKey::from_model(&team(401, "")),
+            ]
+        );
+        let renamed = session
+            .identity_map
+            .get(&ObjectKey::from_model(&team(402, "")))
+            .and_then(|tracked| tracked.object.downcast_ref::<Team>())
+            .map(|t| t.name.clone());
+        assert_eq!(renamed.as_deref(), Some("Second, renamed"));
+    }
+
+    #[test]
+    fn test_get_many_loads_missing_keys_in_one_query() {
+        let rt = RuntimeBuilder::current_thread()
+            .build()
+            .expect("create asupersync runtime");
+        let cx = Cx::for_testing();
+
+        let state = Arc::new(Mutex::new(MockState::default()));
+        let conn = MockConnection::new(Arc::clone(&state));
+        let mut session = Session::new(conn);
+
+        rt.block_on(async {
+            let teams = unwrap_outcome(
+                session
+                    .get_many::<Team>(
+                        &cx,
+                        &[
+                            vec![Value::BigInt(2)],
+                            vec![Value::BigInt(99)],
+                            vec![Value::BigInt(1)],
+                        ],
+                    )
+                    .await,
+            );
+            let names: Vec<&str> = teams.iter().map(|t| t.name.as_str()).collect();
+            assert_eq!(names, vec!["X-Men", "Avengers"]);
+
+            // Both are now tracked, so a second call needs no query.
+            let again = unwrap_outcome(
+                session
+                    .get_many::<Team>(&cx, &[vec![Value::BigInt(1)], vec![Value::BigInt(2)]])
+                    .await,
+            );
+            assert_eq!(again.len(), 2);
+        });
+
+        let guard = state.lock().expect("lock poisoned");
+        assert_eq!(guard.query_calls, 1);
+        assert_eq!(
+            guard.last_sql.as_deref(),
+            Some("SELECT * FROM \"teams\" WHERE \"id\" IN ($1, $2, $3)")
+        );
+    }
+
     // ==================== Merge Tests ====================
 
     #[test]
@@ -4972,33 +9531,275 @@ mod tests {
 
         let state = Arc::new(Mutex::new(MockState::default()));
         let conn = MockConnection::new(Arc::clone(&state));
-        let mut session = Session::new(conn);
+        let mut session = Session::new(conn);
+
+        rt.block_on(async {
+            // Merge an object that doesn't exist in DB (mock returns None for id=999)
+            let detached = Team {
+                id: Some(999),
+                name: "Not In DB".to_string(),
+            };
+
+            let merged = unwrap_outcome(session.merge(&cx, detached, true).await);
+
+            // Should keep the values we provided
+            assert_eq!(merged.id, Some(999));
+            assert_eq!(merged.name, "Not In DB");
+
+            // Should be tracked as new
+            let info = session.debug_state();
+            assert_eq!(info.pending_new, 1);
+            assert_eq!(info.tracked, 1);
+        });
+
+        // Should have queried DB once
+        assert_eq!(state.lock().expect("lock poisoned").query_calls, 1);
+    }
+
+    #[test]
+    fn test_merge_without_load_convenience() {
+        let rt = RuntimeBuilder::current_thread()
+            .build()
+            .expect("create asupersync runtime");
+        let cx = Cx::for_testing();
+
+        let state = Arc::new(Mutex::new(MockState::default()));
+        let conn = MockConnection::new(Arc::clone(&state));
+        let mut session = Session::new(conn);
+
+        rt.block_on(async {
+            let team = Team {
+                id: Some(42),
+                name: "Test".to_string(),
+            };
+
+            // Use the convenience method
+            let merged = unwrap_outcome(session.merge_without_load(&cx, team).await);
+
+            assert_eq!(merged.id, Some(42));
+            assert_eq!(merged.name, "Test");
+
+            let info = session.debug_state();
+            assert_eq!(info.pending_new, 1);
+        });
+
+        // Should not have queried DB
+        assert_eq!(state.lock().expect("lock poisoned").query_calls, 0);
+    }
+
+    #[test]
+    fn test_merge_null_pk_treated_as_new() {
+        let rt = RuntimeBuilder::current_thread()
+            .build()
+            .expect("create asupersync runtime");
+        let cx = Cx::for_testing();
+
+        let state = Arc::new(Mutex::new(MockState::default()));
+        let conn = MockConnection::new(Arc::clone(&state));
+        let mut session = Session::new(conn);
+
+        rt.block_on(async {
+            // Merge object with null PK (new record)
+            let new_team = Team {
+                id: None,
+                name: "Brand New".to_string(),
+            };
+
+            let merged = unwrap_outcome(session.merge(&cx, new_team, true).await);
+
+            // Should keep the null id
+            assert_eq!(merged.id, None);
+            assert_eq!(merged.name, "Brand New");
+
+            // Should be tracked as new (no DB query for null PK)
+            let info = session.debug_state();
+            assert_eq!(info.pending_new, 1);
+        });
+
+        // Should not have queried DB for null PK
+        assert_eq!(state.lock().expect("lock poisoned").query_calls, 0);
+    }
+
+    // ==================== Batch Progress Tests ====================
+
+    #[test]
+    fn test_bulk_insert_reports_progress_per_chunk() {
+        let rt = RuntimeBuilder::current_thread()
+            .build()
+            .expect("create asupersync runtime");
+        let cx = Cx::for_testing();
+
+        let state = Arc::new(Mutex::new(MockState::default()));
+        let conn = MockConnection::new(Arc::clone(&state));
+        let mut session = Session::new(conn);
+
+        let reports = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&reports);
+        session.on_batch_progress(move |p| sink.lock().expect("lock poisoned").push(p));
+
+        let teams: Vec<Team> = (1..=5)
+            .map(|i| Team {
+                id: Some(i),
+                name: format!("Team {i}"),
+            })
+            .collect();
+
+        rt.block_on(async {
+            unwrap_outcome(session.bulk_insert_with_batch_size(&cx, &teams, 2).await);
+        });
+
+        let reports = reports.lock().expect("lock poisoned");
+        let summary: Vec<(&str, u64, u64, u64)> = reports
+            .iter()
+            .map(|p| (p.table, p.rows_in_batch, p.completed, p.total))
+            .collect();
+        assert_eq!(
+            summary,
+            [("teams", 2, 2, 5), ("teams", 2, 4, 5), ("teams", 1, 5, 5)]
+        );
+    }
+
+    #[test]
+    fn test_flush_reports_progress_per_statement() {
+        let rt = RuntimeBuilder::current_thread()
+            .build()
+            .expect("create asupersync runtime");
+        let cx = Cx::for_testing();
+
+        let state = Arc::new(Mutex::new(MockState::default()));
+        let conn = MockConnection::new(Arc::clone(&state));
+        let mut session = Session::new(conn);
+
+        let reports = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&reports);
+        session.on_batch_progress(move |p| sink.lock().expect("lock poisoned").push(p));
+
+        rt.block_on(async {
+            session.add(&Team {
+                id: Some(1),
+                name: "Avengers".to_string(),
+            });
+            session.add(&Team {
+                id: Some(2),
+                name: "X-Men".to_string(),
+            });
+            unwrap_outcome(session.flush(&cx).await);
+
+            // Clearing the callback stops reporting.
+            session.clear_batch_progress();
+            session.add(&Team {
+                id: Some(3),
+                name: "Defenders".to_string(),
+            });
+            unwrap_outcome(session.flush(&cx).await);
+        });
+
+        let reports = reports.lock().expect("lock poisoned");
+        let summary: Vec<(&str, u64, u64)> = reports
+            .iter()
+            .map(|p| (p.table, p.completed, p.total))
+            .collect();
+        assert_eq!(summary, [("teams", 1, 2), ("teams", 2, 2)]);
+    }
+
+    #[test]
+    fn test_flush_reports_cascade_delete_progress() {
+        let rt = RuntimeBuilder::current_thread()
+            .build()
+            .expect("create asupersync runtime");
+        let cx = Cx::for_testing();
+
+        let state = Arc::new(Mutex::new(MockState::default()));
+        state.lock().expect("lock poisoned").rows_affected = 2;
+        let conn = MockConnection::new(Arc::clone(&state));
+        let mut session = Session::with_config(
+            conn,
+            SessionConfig {
+                auto_begin: false,
+                ..Default::default()
+            },
+        );
+
+        let reports = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&reports);
+        session.on_batch_progress(move |p| sink.lock().expect("lock poisoned").push(p));
+
+        rt.block_on(async {
+            let team = unwrap_outcome(session.get::<TeamWithHeroes>(&cx, 1_i64).await).unwrap();
+            session.delete(&team);
+            unwrap_outcome(session.flush(&cx).await);
+        });
+
+        // The cascade DELETE of the children is a step of its own.
+        let reports = reports.lock().expect("lock poisoned");
+        let summary: Vec<(&str, u64, u64, u64)> = reports
+            .iter()
+            .map(|p| (p.table, p.rows_in_batch, p.completed, p.total))
+            .collect();
+        // Every step reports the rows the database says it affected.
+        assert_eq!(summary, [("heroes", 2, 1, 2), ("teams", 2, 2, 2)]);
+    }
+
+    #[test]
+    fn test_flush_skips_planned_statements_of_cascaded_children() {
+        let rt = RuntimeBuilder::current_thread()
+            .build()
+            .expect("create asupersync runtime");
+        let cx = Cx::for_testing();
+
+        let state = Arc::new(Mutex::new(MockState::default()));
+        let conn = MockConnection::new(Arc::clone(&state));
+        let mut session = Session::with_config(
+            conn,
+            SessionConfig {
+                auto_begin: false,
+                ..Default::default()
+            },
+        );
 
         rt.block_on(async {
-            // Merge an object that doesn't exist in DB (mock returns None for id=999)
-            let detached = Team {
-                id: Some(999),
-                name: "Not In DB".to_string(),
-            };
-
-            let merged = unwrap_outcome(session.merge(&cx, detached, true).await);
+            let team = unwrap_outcome(session.get::<TeamWithHeroes>(&cx, 1_i64).await).unwrap();
+            session.add(&HeroChild {
+                id: Some(5),
+                team_id: 1,
+            });
+            session.delete(&team);
 
-            // Should keep the values we provided
-            assert_eq!(merged.id, Some(999));
-            assert_eq!(merged.name, "Not In DB");
+            // The plan still holds the child's INSERT...
+            let kinds: Vec<PreviewKind> = session.preview_flush().iter().map(|s| s.kind).collect();
+            assert_eq!(
+                kinds,
+                [
+                    PreviewKind::Delete,
+                    PreviewKind::Delete,
+                    PreviewKind::Insert
+                ]
+            );
 
-            // Should be tracked as new
-            let info = session.debug_state();
-            assert_eq!(info.pending_new, 1);
-            assert_eq!(info.tracked, 1);
+            // ...but the cascade drops the child before it runs.
+            unwrap_outcome(session.flush(&cx).await);
+            assert_eq!(session.pending_new_count(), 0);
         });
 
-        // Should have queried DB once
-        assert_eq!(state.lock().expect("lock poisoned").query_calls, 1);
+        let guard = state.lock().expect("lock poisoned");
+        let tables: Vec<&str> = guard
+            .executed
+            .iter()
+            .map(|(sql, _)| sql.split('"').nth(1).unwrap_or_default())
+            .collect();
+        assert_eq!(tables, ["heroes", "teams"]);
+        assert!(
+            guard
+                .executed
+                .iter()
+                .all(|(sql, _)| sql.starts_with("DELETE"))
+        );
     }
 
+    // ==================== query_as Tests ====================
+
     #[test]
-    fn test_merge_without_load_convenience() {
+    fn test_query_as_tracks_results_as_persistent() {
         let rt = RuntimeBuilder::current_thread()
             .build()
             .expect("create asupersync runtime");
@@ -5009,27 +9810,29 @@ mod tests {
         let mut session = Session::new(conn);
 
         rt.block_on(async {
-            let team = Team {
-                id: Some(42),
-                name: "Test".to_string(),
-            };
-
-            // Use the convenience method
-            let merged = unwrap_outcome(session.merge_without_load(&cx, team).await);
-
-            assert_eq!(merged.id, Some(42));
-            assert_eq!(merged.name, "Test");
+            let teams = unwrap_outcome(
+                session
+                    .query_as::<Team>(
+                        &cx,
+                        "SELECT * FROM teams WHERE id IN ($1, $2)",
+                        &[Value::BigInt(1), Value::BigInt(2)],
+                    )
+                    .await,
+            );
+            assert_eq!(teams.len(), 2);
+            assert_eq!(session.tracked_count(), 2);
+            assert!(teams.iter().all(|t| !session.is_modified(t)));
 
-            let info = session.debug_state();
-            assert_eq!(info.pending_new, 1);
+            // A PK lookup is now served from the identity map.
+            let team = unwrap_outcome(session.get::<Team>(&cx, 2_i64).await).unwrap();
+            assert_eq!(team.name, "X-Men");
         });
 
-        // Should not have queried DB
-        assert_eq!(state.lock().expect("lock poisoned").query_calls, 0);
+        assert_eq!(state.lock().expect("lock poisoned").query_calls, 1);
     }
 
     #[test]
-    fn test_merge_null_pk_treated_as_new() {
+    fn test_query_as_prefers_tracked_instance() {
         let rt = RuntimeBuilder::current_thread()
             .build()
             .expect("create asupersync runtime");
@@ -5040,25 +9843,20 @@ mod tests {
         let mut session = Session::new(conn);
 
         rt.block_on(async {
-            // Merge object with null PK (new record)
-            let new_team = Team {
-                id: None,
-                name: "Brand New".to_string(),
-            };
-
-            let merged = unwrap_outcome(session.merge(&cx, new_team, true).await);
-
-            // Should keep the null id
-            assert_eq!(merged.id, None);
-            assert_eq!(merged.name, "Brand New");
+            let mut team = unwrap_outcome(session.get::<Team>(&cx, 1_i64).await).unwrap();
+            team.name = "Renamed".to_string();
+            session.mark_dirty(&team);
 
-            // Should be tracked as new (no DB query for null PK)
-            let info = session.debug_state();
-            assert_eq!(info.pending_new, 1);
+            let teams = unwrap_outcome(
+                session
+                    .query_as::<Team>(&cx, "SELECT * FROM teams", &[Value::BigInt(1)])
+                    .await,
+            );
+            assert_eq!(teams.len(), 1);
+            assert_eq!(teams[0].name, "Renamed");
+            assert!(session.is_modified(&teams[0]));
+            assert_eq!(session.tracked_count(), 1);
         });
-
-        // Should not have queried DB for null PK
-        assert_eq!(state.lock().expect("lock poisoned").query_calls, 0);
     }
 
     // ==================== is_modified Tests ====================
@@ -5260,6 +10058,33 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_is_modified_compares_unmarked_copy() {
+        let rt = RuntimeBuilder::current_thread()
+            .build()
+            .expect("create asupersync runtime");
+        let cx = Cx::for_testing();
+
+        let state = Arc::new(Mutex::new(MockState::default()));
+        let conn = MockConnection::new(Arc::clone(&state));
+        let mut session = Session::new(conn);
+
+        rt.block_on(async {
+            let team = unwrap_outcome(session.get::<Team>(&cx, 1_i64).await).unwrap();
+
+            // Edited but never passed to mark_dirty().
+            let mut edited = team.clone();
+            edited.name = "Renamed".to_string();
+            assert!(session.is_modified(&edited));
+            assert_eq!(session.modified_attributes(&edited), ["name"]);
+
+            // Reverting the edit makes it clean again.
+            edited.name = team.name.clone();
+            assert!(!session.is_modified(&edited));
+            assert!(session.modified_attributes(&edited).is_empty());
+        });
+    }
+
     #[test]
     fn test_modified_attributes_untracked_returns_empty() {
         let state = Arc::new(Mutex::new(MockState::default()));
@@ -5484,4 +10309,303 @@ mod tests {
             assert!(expired.is_none());
         });
     }
+
+    // ==================== Unset Primary Key Tests ====================
+
+    /// Model with an auto-increment primary key, for unset-PK tests.
+    #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+    struct Ticket {
+        id: i64,
+        title: String,
+    }
+
+    impl Model for Ticket {
+        const TABLE_NAME: &'static str = "tickets";
+        const PRIMARY_KEY: &'static [&'static str] = &["id"];
+
+        fn fields() -> &'static [sqlmodel_core::FieldInfo] {
+            const FIELDS: &[sqlmodel_core::FieldInfo] = &[
+                sqlmodel_core::FieldInfo::new("id", "id", sqlmodel_core::SqlType::BigInt)
+                    .primary_key(true)
+                    .auto_increment(true),
+                sqlmodel_core::FieldInfo::new("title", "title", sqlmodel_core::SqlType::Text),
+            ];
+            FIELDS
+        }
+
+        fn to_row(&self) -> Vec<(&'static str, Value)> {
+            vec![
+                ("id", Value::BigInt(self.id)),
+                ("title", Value::Text(self.title.clone())),
+            ]
+        }
+
+        fn from_row(row: &Row) -> sqlmodel_core::Result<Self> {
+            Ok(Self {
+                id: row.get_named("id")?,
+                title: row.get_named("title")?,
+            })
+        }
+
+        fn primary_key_value(&self) -> Vec<Value> {
+            vec![Value::BigInt(self.id)]
+        }
+
+        fn is_new(&self) -> bool {
+            self.id == 0
+        }
+    }
+
+    fn ticket(title: &str) -> Ticket {
+        Ticket {
+            id: 0,
+            title: title.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_add_unset_pk_uses_temporary_keys_and_backfills() {
+        let rt = RuntimeBuilder::current_thread()
+            .build()
+            .expect("create asupersync runtime");
+        let cx = Cx::for_testing();
+
+        let state = Arc::new(Mutex::new(MockState::default()));
+        let conn = MockConnection::new(Arc::clone(&state));
+        let mut session = Session::with_config(
+            conn,
+            SessionConfig {
+                auto_begin: false,
+                unset_pk_policy: UnsetPkPolicy::TemporaryKey,
+                ..Default::default()
+            },
+        );
+
+        session.add(&ticket("first"));
+        session.add(&ticket("second"));
+        assert_eq!(session.pending_new_count(), 2);
+
+        rt.block_on(async {
+            unwrap_outcome(session.flush(&cx).await);
+        });
+
+        {
+            let guard = state.lock().expect("lock poisoned");
+            assert_eq!(guard.executed.len(), 2);
+            assert_eq!(
+                guard.executed[0].0,
+                "INSERT INTO \"tickets\" (\"title\") VALUES ($1) RETURNING \"id\""
+            );
+            assert_eq!(guard.executed[1].1, vec![Value::Text("second".into())]);
+        }
+
+        // Both objects were re-keyed under their generated ids.
+        assert!(session.pending_pks.is_empty());
+        for (id, title) in [(1, "first"), (2, "second")] {
+            let key = ObjectKey::from_pk::<Ticket>(&[Value::BigInt(id)]);
+            let tracked = session.identity_map.get(&key).expect("tracked by real id");
+            assert_eq!(tracked.state, ObjectState::Persistent);
+            assert_eq!(tracked.pk_values, vec![Value::BigInt(id)]);
+            let obj = tracked.object.downcast_ref::<Ticket>().unwrap();
+            assert_eq!(
+                obj,
+                &Ticket {
+                    id,
+                    title: title.to_string()
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn test_add_default_objects_with_unset_pk_are_all_flushed() {
+        let rt = RuntimeBuilder::current_thread()
+            .build()
+            .expect("create asupersync runtime");
+        let cx = Cx::for_testing();
+
+        let state = Arc::new(Mutex::new(MockState::default()));
+        let conn = MockConnection::new(Arc::clone(&state));
+        // Default config: no unset-PK policy opted into.
+        let mut session = Session::new(conn);
+
+        session.add(&Ticket::default());
+        session.add(&Ticket::default());
+        assert_eq!(session.pending_new_count(), 2);
+        assert_eq!(session.tracked_count(), 2);
+
+        rt.block_on(async {
+            unwrap_outcome(session.flush(&cx).await);
+        });
+
+        let inserts = state
+            .lock()
+            .expect("lock poisoned")
+            .executed
+            .iter()
+            .filter(|(sql, _)| sql.starts_with("INSERT INTO \"tickets\""))
+            .count();
+        assert_eq!(inserts, 2);
+        assert_eq!(session.pending_new_count(), 0);
+        assert_eq!(session.tracked_count(), 2);
+    }
+
+    #[test]
+    fn test_add_unset_pk_placeholder_policy_collides() {
+        let state = Arc::new(Mutex::new(MockState::default()));
+        let conn = MockConnection::new(Arc::clone(&state));
+        let mut session = Session::with_config(
+            conn,
+            SessionConfig {
+                unset_pk_policy: UnsetPkPolicy::Placeholder,
+                ..Default::default()
+            },
+        );
+
+        session.add(&ticket("first"));
+        session.add(&ticket("second"));
+
+        assert_eq!(session.pending_new_count(), 1);
+        assert!(session.pending_pks.is_empty());
+        assert!(session.contains(&ticket("anything")));
+    }
+
+    /// Ticket whose unset key is a typed NULL, as Postgres-facing models bind it.
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct DraftTicket {
+        id: Option<i64>,
+    }
+
+    impl Model for DraftTicket {
+        const TABLE_NAME: &'static str = "tickets";
+        const PRIMARY_KEY: &'static [&'static str] = &["id"];
+
+        fn fields() -> &'static [sqlmodel_core::FieldInfo] {
+            const FIELDS: &[sqlmodel_core::FieldInfo] =
+                &[
+                    sqlmodel_core::FieldInfo::new("id", "id", sqlmodel_core::SqlType::BigInt)
+                        .primary_key(true)
+                        .auto_increment(true),
+                ];
+            FIELDS
+        }
+
+        fn to_row(&self) -> Vec<(&'static str, Value)> {
+            vec![("id", self.primary_key_value().remove(0))]
+        }
+
+        fn from_row(row: &Row) -> sqlmodel_core::Result<Self> {
+            Ok(Self {
+                id: row.get_named("id")?,
+            })
+        }
+
+        fn primary_key_value(&self) -> Vec<Value> {
+            vec![self.id.map_or(
+                Value::TypedNull(sqlmodel_core::SqlType::BigInt),
+                Value::BigInt,
+            )]
+        }
+
+        fn is_new(&self) -> bool {
+            self.id.is_none()
+        }
+    }
+
+    #[test]
+    fn test_unset_pk_detects_typed_null() {
+        assert_eq!(
+            unset_auto_increment_pk(&DraftTicket { id: None }),
+            Some("id")
+        );
+        assert_eq!(unset_auto_increment_pk(&DraftTicket { id: Some(4) }), None);
+    }
+
+    /// Model whose only column is its auto-increment primary key.
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Sequence {
+        id: i64,
+    }
+
+    impl Model for Sequence {
+        const TABLE_NAME: &'static str = "sequences";
+        const PRIMARY_KEY: &'static [&'static str] = &["id"];
+
+        fn fields() -> &'static [sqlmodel_core::FieldInfo] {
+            const FIELDS: &[sqlmodel_core::FieldInfo] =
+                &[
+                    sqlmodel_core::FieldInfo::new("id", "id", sqlmodel_core::SqlType::BigInt)
+                        .primary_key(true)
+                        .auto_increment(true),
+                ];
+            FIELDS
+        }
+
+        fn to_row(&self) -> Vec<(&'static str, Value)> {
+            vec![("id", Value::BigInt(self.id))]
+        }
+
+        fn from_row(row: &Row) -> sqlmodel_core::Result<Self> {
+            Ok(Self {
+                id: row.get_named("id")?,
+            })
+        }
+
+        fn primary_key_value(&self) -> Vec<Value> {
+            vec![Value::BigInt(self.id)]
+        }
+
+        fn is_new(&self) -> bool {
+            self.id == 0
+        }
+    }
+
+    #[test]
+    fn test_flush_insert_of_only_generated_pk_uses_defaults() {
+        let rt = RuntimeBuilder::current_thread()
+            .build()
+            .expect("create asupersync runtime");
+        let cx = Cx::for_testing();
+
+        for (dialect, expected) in [
+            (
+                sqlmodel_core::Dialect::Postgres,
+                "INSERT INTO \"sequences\" DEFAULT VALUES RETURNING \"id\"",
+            ),
+            (
+                sqlmodel_core::Dialect::Sqlite,
+                "INSERT INTO \"sequences\" DEFAULT VALUES",
+            ),
+            (
+                sqlmodel_core::Dialect::Mysql,
+                "INSERT INTO `sequences` () VALUES ()",
+            ),
+        ] {
+            let state = Arc::new(Mutex::new(MockState::default()));
+            let mut conn = MockConnection::new(Arc::clone(&state));
+            conn.dialect = dialect;
+            let mut session = Session::with_config(
+                conn,
+                SessionConfig {
+                    auto_begin: false,
+                    unset_pk_policy: UnsetPkPolicy::TemporaryKey,
+                    ..Default::default()
+                },
+            );
+
+            session.add(&Sequence { id: 0 });
+            rt.block_on(async {
+                unwrap_outcome(session.flush(&cx).await);
+            });
+
+            let guard = state.lock().expect("lock poisoned");
+            assert_eq!(guard.executed, [(expected.to_string(), Vec::new())]);
+            let key = ObjectKey::from_pk::<Sequence>(&[Value::BigInt(1)]);
+            let tracked = session.identity_map.get(&key).expect("tracked by real id");
+            assert_eq!(
+                tracked.object.downcast_ref::<Sequence>(),
+                Some(&Sequence { id: 1 })
+            );
+        }
+    }
 }
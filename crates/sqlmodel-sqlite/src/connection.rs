@@ -32,7 +32,8 @@
 use crate::ffi;
 use crate::types;
 use sqlmodel_core::{
-    Connection, Cx, Error, IsolationLevel, Outcome, PreparedStatement, Row, TransactionOps, Value,
+    Capabilities, Connection, Cx, Error, IsolationLevel, Outcome, PreparedStatement, Row,
+    TransactionOps, UpsertSyntax, Value,
     error::{ConnectionError, ConnectionErrorKind, QueryError, QueryErrorKind},
     row::ColumnInfo,
 };
@@ -54,6 +55,66 @@ pub struct SqliteConfig {
     pub flags: OpenFlags,
     /// Busy timeout in milliseconds.
     pub busy_timeout_ms: u32,
+    /// PRAGMAs applied, in order, right after the database is opened.
+    ///
+    /// Defaults to `foreign_keys = ON` so foreign key constraints are enforced.
+    pub pragmas: Vec<(String, String)>,
+}
+
+/// Values for `PRAGMA journal_mode`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JournalMode {
+    /// Delete the rollback journal at the end of each transaction (SQLite default).
+    Delete,
+    /// Truncate the rollback journal instead of deleting it.
+    Truncate,
+    /// Keep the rollback journal and zero its header.
+    Persist,
+    /// Keep the rollback journal in memory.
+    Memory,
+    /// Write-ahead logging; readers don't block writers.
+    Wal,
+    /// No rollback journal.
+    Off,
+}
+
+impl JournalMode {
+    /// The PRAGMA value for this mode.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            JournalMode::Delete => "DELETE",
+            JournalMode::Truncate => "TRUNCATE",
+            JournalMode::Persist => "PERSIST",
+            JournalMode::Memory => "MEMORY",
+            JournalMode::Wal => "WAL",
+            JournalMode::Off => "OFF",
+        }
+    }
+}
+
+/// Values for `PRAGMA synchronous`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Synchronous {
+    /// Hand data to the OS without syncing.
+    Off,
+    /// Sync at critical moments; safe with WAL.
+    Normal,
+    /// Sync before each transaction commits (SQLite default).
+    Full,
+    /// Like `Full`, and also sync the directory after unlinking a journal.
+    Extra,
+}
+
+impl Synchronous {
+    /// The PRAGMA value for this level.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Synchronous::Off => "OFF",
+            Synchronous::Normal => "NORMAL",
+            Synchronous::Full => "FULL",
+            Synchronous::Extra => "EXTRA",
+        }
+    }
 }
 
 /// Flags controlling how the database is opened.
@@ -146,10 +207,15 @@ impl Default for SqliteConfig {
             path: ":memory:".to_string(),
             flags: OpenFlags::create_read_write(),
             busy_timeout_ms: 5000,
+            pragmas: default_pragmas(),
         }
     }
 }
 
+fn default_pragmas() -> Vec<(String, String)> {
+    vec![("foreign_keys".to_string(), "ON".to_string())]
+}
+
 impl SqliteConfig {
     /// Create a new config for a file-based database.
     pub fn file(path: impl Into<String>) -> Self {
@@ -157,6 +223,7 @@ impl SqliteConfig {
             path: path.into(),
             flags: OpenFlags::create_read_write(),
             busy_timeout_ms: 5000,
+            pragmas: default_pragmas(),
         }
     }
 
@@ -176,6 +243,40 @@ impl SqliteConfig {
         self.busy_timeout_ms = ms;
         self
     }
+
+    /// Set a PRAGMA to apply after opening.
+    ///
+    /// Replaces an earlier setting of the same PRAGMA, otherwise appends it.
+    /// Names and values must be plain words (letters, digits, `_`, `-`, `.`);
+    /// opening fails otherwise.
+    pub fn pragma(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        let name = name.into();
+        let value = value.into();
+        match self
+            .pragmas
+            .iter_mut()
+            .find(|(existing, _)| existing.eq_ignore_ascii_case(&name))
+        {
+            Some(entry) => entry.1 = value,
+            None => self.pragmas.push((name, value)),
+        }
+        self
+    }
+
+    /// Set `PRAGMA journal_mode`.
+    pub fn journal_mode(self, mode: JournalMode) -> Self {
+        self.pragma("journal_mode", mode.as_str())
+    }
+
+    /// Set `PRAGMA synchronous`.
+    pub fn synchronous(self, level: Synchronous) -> Self {
+        self.pragma("synchronous", level.as_str())
+    }
+
+    /// Enable or disable foreign key enforcement (`PRAGMA foreign_keys`).
+    pub fn foreign_keys(self, enabled: bool) -> Self {
+        self.pragma("foreign_keys", if enabled { "ON" } else { "OFF" })
+    }
 }
 
 /// Inner state of the SQLite connection, protected by a mutex for thread safety.
@@ -249,7 +350,7 @@ impl SqliteConnection {
             }
         }
 
-        Ok(Self {
+        let conn = Self {
             inner: Mutex::new(SqliteInner {
                 db,
                 in_transaction: false,
@@ -257,7 +358,37 @@ impl SqliteConnection {
             path: config.path.clone(),
             #[cfg(feature = "console")]
             console: None,
-        })
+        };
+
+        for (name, value) in &config.pragmas {
+            conn.apply_pragma(name, value)?;
+        }
+
+        Ok(conn)
+    }
+
+    /// Run `PRAGMA name = value`, rejecting anything that isn't a plain word.
+    fn apply_pragma(&self, name: &str, value: &str) -> Result<(), Error> {
+        let is_word = |s: &str| {
+            !s.is_empty()
+                && s.chars()
+                    .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.'))
+        };
+        if !is_word(name) || !is_word(value) {
+            return Err(Error::Connection(ConnectionError {
+                kind: ConnectionErrorKind::Connect,
+                message: format!("Invalid PRAGMA setting: {name} = {value}"),
+                source: None,
+            }));
+        }
+        self.execute_raw(&format!("PRAGMA {name} = {value}"))
+            .map_err(|e| {
+                Error::Connection(ConnectionError {
+                    kind: ConnectionErrorKind::Connect,
+                    message: format!("Failed to apply PRAGMA {name} = {value}: {e}"),
+                    source: Some(Box::new(e)),
+                })
+            })
     }
 
     /// Open an in-memory database.
@@ -286,6 +417,11 @@ impl SqliteConnection {
                 message: "SQL contains null byte".to_string(),
                 detail: None,
                 hint: None,
+                schema: None,
+                table: None,
+                column: None,
+                constraint: None,
+                where_: None,
                 position: None,
                 source: None,
             })
@@ -315,6 +451,11 @@ impl SqliteConnection {
                 message: msg,
                 detail: None,
                 hint: None,
+                schema: None,
+                table: None,
+                column: None,
+                constraint: None,
+                where_: None,
                 position: None,
                 source: None,
             }));
@@ -561,6 +702,11 @@ impl SqliteConnection {
                 message: "Already in a transaction".to_string(),
                 detail: None,
                 hint: None,
+                schema: None,
+                table: None,
+                column: None,
+                constraint: None,
+                where_: None,
                 position: None,
                 source: None,
             }));
@@ -594,6 +740,11 @@ impl SqliteConnection {
                 message: "Not in a transaction".to_string(),
                 detail: None,
                 hint: None,
+                schema: None,
+                table: None,
+                column: None,
+                constraint: None,
+                where_: None,
                 position: None,
                 source: None,
             }));
@@ -619,6 +770,11 @@ impl SqliteConnection {
                 message: "Not in a transaction".to_string(),
                 detail: None,
                 hint: None,
+                schema: None,
+                table: None,
+                column: None,
+                constraint: None,
+                where_: None,
                 position: None,
                 source: None,
             }));
@@ -682,6 +838,10 @@ impl Connection for SqliteConnection {
         sqlmodel_core::Dialect::Sqlite
     }
 
+    fn capabilities(&self) -> Capabilities {
+        sqlite_capabilities(ffi::version_number())
+    }
+
     fn query(
         &self,
         _cx: &Cx,
@@ -897,6 +1057,25 @@ impl TransactionOps for SqliteTransaction<'_> {
 
 // Helper functions
 
+/// Backend capabilities of the linked SQLite library, given
+/// `sqlite3_libversion_number()`.
+///
+/// `RETURNING` arrived in 3.35, `ON CONFLICT ... DO UPDATE` in 3.24, and the
+/// default bound-parameter limit rose from 999 to 32766 in 3.32.
+fn sqlite_capabilities(version_number: i32) -> Capabilities {
+    let mut caps = Capabilities::for_dialect(sqlmodel_core::Dialect::Sqlite);
+    if version_number < 3_035_000 {
+        caps = caps.without_returning();
+    }
+    if version_number < 3_032_000 {
+        caps.max_bind_params = 999;
+    }
+    if version_number < 3_024_000 {
+        caps.upsert_syntax = UpsertSyntax::None;
+    }
+    caps
+}
+
 fn prepare_stmt(db: *mut ffi::sqlite3, sql: &str) -> Result<*mut ffi::sqlite3_stmt, Error> {
     let c_sql = CString::new(sql).map_err(|_| {
         Error::Query(QueryError {
@@ -906,6 +1085,11 @@ fn prepare_stmt(db: *mut ffi::sqlite3, sql: &str) -> Result<*mut ffi::sqlite3_st
             message: "SQL contains null byte".to_string(),
             detail: None,
             hint: None,
+            schema: None,
+            table: None,
+            column: None,
+            constraint: None,
+            where_: None,
             position: None,
             source: None,
         })
@@ -946,6 +1130,11 @@ fn prepare_error(db: *mut ffi::sqlite3, sql: &str) -> Error {
         message: msg,
         detail: None,
         hint: None,
+        schema: None,
+        table: None,
+        column: None,
+        constraint: None,
+        where_: None,
         position: None,
         source: None,
     })
@@ -965,6 +1154,11 @@ fn bind_error(db: *mut ffi::sqlite3, sql: &str, param_index: usize) -> Error {
         message: format!("Failed to bind parameter {}: {}", param_index, msg),
         detail: None,
         hint: None,
+        schema: None,
+        table: None,
+        column: None,
+        constraint: None,
+        where_: None,
         position: None,
         source: None,
     })
@@ -985,6 +1179,11 @@ fn step_error(db: *mut ffi::sqlite3, sql: &str) -> Error {
         message: msg,
         detail: None,
         hint: None,
+        schema: None,
+        table: None,
+        column: None,
+        constraint: None,
+        where_: None,
         position: None,
         source: None,
     })
@@ -1006,7 +1205,7 @@ fn error_code_to_kind(code: c_int) -> QueryErrorKind {
 #[allow(dead_code)]
 fn format_value(value: &Value) -> String {
     match value {
-        Value::Null => "NULL".to_string(),
+        Value::Null | Value::TypedNull(_) => "NULL".to_string(),
         Value::Bool(b) => if *b { "true" } else { "false" }.to_string(),
         Value::TinyInt(n) => n.to_string(),
         Value::SmallInt(n) => n.to_string(),
@@ -1355,6 +1554,25 @@ impl SqliteConnection {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_sqlite_capabilities_by_version() {
+        let current = sqlite_capabilities(3_045_001);
+        assert!(current.returning_insert && current.returning_update);
+        assert!(current.returning_delete && current.multi_row_returning);
+        assert_eq!(current.upsert_syntax, UpsertSyntax::OnConflict);
+        assert_eq!(current.max_bind_params, 32_766);
+
+        let old = sqlite_capabilities(3_031_001);
+        assert!(!old.returning_insert && !old.multi_row_returning);
+        assert_eq!(old.upsert_syntax, UpsertSyntax::OnConflict);
+        assert_eq!(old.max_bind_params, 999);
+
+        assert_eq!(
+            sqlite_capabilities(3_022_000).upsert_syntax,
+            UpsertSyntax::None
+        );
+    }
+
     #[test]
     fn test_open_memory() {
         let conn = SqliteConnection::open_memory().unwrap();
@@ -1372,6 +1590,57 @@ mod tests {
         assert_eq!(conn.last_insert_rowid(), 1);
     }
 
+    #[test]
+    fn test_foreign_keys_enforced_by_default() {
+        let conn = SqliteConnection::open_memory().unwrap();
+        conn.execute_raw("CREATE TABLE teams (id INTEGER PRIMARY KEY)")
+            .unwrap();
+        conn.execute_raw(
+            "CREATE TABLE heroes (id INTEGER PRIMARY KEY, team_id INTEGER REFERENCES teams(id))",
+        )
+        .unwrap();
+
+        let err = conn
+            .execute_sync("INSERT INTO heroes (team_id) VALUES (?)", &[Value::Int(99)])
+            .unwrap_err();
+        assert!(err.to_string().contains("FOREIGN KEY"), "{err}");
+
+        let conn = SqliteConnection::open(&SqliteConfig::memory().foreign_keys(false)).unwrap();
+        conn.execute_raw("CREATE TABLE teams (id INTEGER PRIMARY KEY)")
+            .unwrap();
+        conn.execute_raw(
+            "CREATE TABLE heroes (id INTEGER PRIMARY KEY, team_id INTEGER REFERENCES teams(id))",
+        )
+        .unwrap();
+        conn.execute_sync("INSERT INTO heroes (team_id) VALUES (?)", &[Value::Int(99)])
+            .unwrap();
+    }
+
+    #[test]
+    fn test_pragma_config() {
+        let config = SqliteConfig::memory()
+            .journal_mode(JournalMode::Wal)
+            .synchronous(Synchronous::Normal)
+            .pragma("cache_size", "-2000")
+            .pragma("SYNCHRONOUS", "FULL");
+        assert_eq!(
+            config.pragmas,
+            vec![
+                ("foreign_keys".to_string(), "ON".to_string()),
+                ("journal_mode".to_string(), "WAL".to_string()),
+                ("synchronous".to_string(), "FULL".to_string()),
+                ("cache_size".to_string(), "-2000".to_string()),
+            ]
+        );
+
+        let conn = SqliteConnection::open(&config).unwrap();
+        let rows = conn.query_sync("PRAGMA synchronous", &[]).unwrap();
+        assert_eq!(rows[0].get_as::<i64>(0).unwrap(), 2);
+
+        let bad = SqliteConfig::memory().pragma("foreign_keys", "ON; DROP TABLE x");
+        assert!(SqliteConnection::open(&bad).is_err());
+    }
+
     #[test]
     fn test_query_sync() {
         let conn = SqliteConnection::open_memory().unwrap();
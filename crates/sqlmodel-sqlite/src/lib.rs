@@ -75,7 +75,9 @@ pub mod connection;
 pub mod ffi;
 pub mod types;
 
-pub use connection::{OpenFlags, SqliteConfig, SqliteConnection, SqliteTransaction};
+pub use connection::{
+    JournalMode, OpenFlags, SqliteConfig, SqliteConnection, SqliteTransaction, Synchronous,
+};
 
 // Console integration (feature-gated)
 #[cfg(feature = "console")]
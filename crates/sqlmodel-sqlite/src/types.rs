@@ -28,7 +28,7 @@ pub unsafe fn bind_value(stmt: *mut ffi::sqlite3_stmt, index: c_int, value: &Val
     // SAFETY: All FFI calls require unsafe in Rust 2024
     unsafe {
         match value {
-            Value::Null => ffi::sqlite3_bind_null(stmt, index),
+            Value::Null | Value::TypedNull(_) => ffi::sqlite3_bind_null(stmt, index),
 
             Value::Bool(b) => ffi::sqlite3_bind_int(stmt, index, if *b { 1 } else { 0 }),
 
@@ -321,7 +321,7 @@ fn micros_to_timestamp(micros: i64) -> String {
 /// Convert a Value to a serde_json::Value for array serialization.
 fn value_to_json(value: &Value) -> serde_json::Value {
     match value {
-        Value::Null => serde_json::Value::Null,
+        Value::Null | Value::TypedNull(_) => serde_json::Value::Null,
         Value::Bool(b) => serde_json::Value::Bool(*b),
         Value::TinyInt(v) => serde_json::Value::Number((*v).into()),
         Value::SmallInt(v) => serde_json::Value::Number((*v).into()),
@@ -95,13 +95,16 @@
 //! - **`console` feature**: Enable rich terminal output via `sqlmodel-console`
 
 // Re-export all public types from sub-crates
-pub use sqlmodel_core::connection::{ConnectionConfig, SslMode, Transaction};
+pub use sqlmodel_core::connection::{ConnectionConfig, Savepoint, SslMode, Transaction};
 pub use sqlmodel_core::{
     // asupersync re-exports
     Budget,
     // Core types
+    BytesDisplay,
+    Capabilities,
     Connection,
     Cx,
+    DisplayHint,
     DumpMode,
     DumpOptions,
     DumpResult,
@@ -116,29 +119,36 @@ pub use sqlmodel_core::{
     Model,
     ModelDump,
     Outcome,
+    ProgressReporter,
     RegionId,
     Result,
+    RetryPolicy,
     Row,
     SqlEnum,
     SqlModelDump,
     SqlModelValidate,
     SqlType,
+    StaleObjectError,
+    TableConstraint,
     TaskId,
     TrackedModel,
     TypeInfo,
+    UpsertSyntax,
     ValidateInput,
     ValidateOptions,
     ValidateResult,
     Value,
+    retry,
+    validate_checks,
 };
 
 pub use sqlmodel_macros::{Model, SqlEnum, Validate};
 
 pub use sqlmodel_query::{
-    BinaryOp, Expr, Join, JoinType, Limit, Offset, OrderBy, PolymorphicJoined, PolymorphicJoined2,
-    PolymorphicJoined3, PolymorphicJoinedSelect, PolymorphicJoinedSelect2,
-    PolymorphicJoinedSelect3, QueryBuilder, Select, UnaryOp, Where, delete, insert, raw_execute,
-    raw_query, select, update,
+    Aggregate, BinaryOp, Expr, FieldExt, Join, JoinType, Limit, Offset, OrderBy, PolymorphicJoined,
+    PolymorphicJoined2, PolymorphicJoined3, PolymorphicJoinedSelect, PolymorphicJoinedSelect2,
+    PolymorphicJoinedSelect3, QueryBuilder, Select, TextFieldExt, UnaryOp, Where, delete, insert,
+    raw_execute, raw_query, select, update,
 };
 
 pub use sqlmodel_schema::{
@@ -151,7 +161,8 @@ pub use sqlmodel_pool::{
 };
 
 pub use sqlmodel_session::{
-    GetOptions, ObjectKey, ObjectState, Session, SessionConfig, SessionDebugInfo,
+    GetOptions, ObjectKey, ObjectState, Page, Session, SessionConfig, SessionDebugInfo,
+    UnsetPkPolicy,
 };
 
 /// Wrap a model struct literal and track which fields were explicitly provided.
@@ -622,6 +633,97 @@ mod shard_key_tests {
     }
 }
 
+// ============================================================================
+// Partial Row Tests
+// ============================================================================
+//
+// These tests verify the from_partial_row() the Model derive macro generates
+// for rows that hold only some of a model's columns.
+
+#[cfg(test)]
+mod partial_row_tests {
+    use super::*;
+    use serde::{Deserialize, Serialize};
+    use sqlmodel_core::row::FromValue;
+
+    // A column type with no Default impl.
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Label(String);
+
+    impl From<Label> for Value {
+        fn from(label: Label) -> Self {
+            Value::Text(label.0)
+        }
+    }
+
+    impl FromValue for Label {
+        fn from_value(value: &Value) -> Result<Self> {
+            String::from_value(value).map(Label)
+        }
+    }
+
+    #[derive(Model, Debug, Clone, Serialize, Deserialize)]
+    #[sqlmodel(table)]
+    struct Ticket {
+        #[sqlmodel(primary_key)]
+        id: i64,
+        #[sqlmodel(default = "'open'")]
+        status: String,
+        #[sqlmodel(default = "3")]
+        priority: i32,
+        note: Option<String>,
+        views: i64,
+        label: Label,
+    }
+
+    fn row(columns: &[(&str, Value)]) -> Row {
+        let (names, values) = columns
+            .iter()
+            .map(|(name, value)| ((*name).to_string(), value.clone()))
+            .unzip();
+        Row::new(names, values)
+    }
+
+    #[test]
+    fn test_from_partial_row_fills_declared_defaults() {
+        let (ticket, loaded) = Ticket::from_partial_row(&row(&[
+            ("id", Value::BigInt(9)),
+            ("label", Value::Text("bug".into())),
+        ]))
+        .unwrap();
+        assert_eq!(ticket.id, 9);
+        assert_eq!(ticket.status, "open");
+        assert_eq!(ticket.priority, 3);
+        assert_eq!(ticket.note, None);
+        assert_eq!(ticket.views, 0);
+        assert_eq!(ticket.label, Label("bug".into()));
+        assert_eq!(loaded, ["id", "label"]);
+    }
+
+    #[test]
+    fn test_from_partial_row_prefers_row_values() {
+        let (ticket, _) = Ticket::from_partial_row(&row(&[
+            ("id", Value::BigInt(9)),
+            ("status", Value::Text("closed".into())),
+            ("label", Value::Text("bug".into())),
+        ]))
+        .unwrap();
+        assert_eq!(ticket.status, "closed");
+    }
+
+    #[test]
+    fn test_from_partial_row_requires_undefaultable_columns() {
+        // No default and no Default impl: there is nothing to fill `label` with.
+        let err = Ticket::from_partial_row(&row(&[("id", Value::BigInt(9))])).unwrap_err();
+        assert!(matches!(err, Error::Type(ref e) if e.column.as_deref() == Some("label")));
+
+        // The primary key is never filled in.
+        let err =
+            Ticket::from_partial_row(&row(&[("label", Value::Text("bug".into()))])).unwrap_err();
+        assert!(matches!(err, Error::Type(ref e) if e.column.as_deref() == Some("id")));
+    }
+}
+
 /// Prelude module for convenient imports.
 ///
 /// ```ignore
@@ -642,6 +744,7 @@ pub mod prelude {
         Error,
         // Query building
         Expr,
+        FieldExt,
         FieldsSet,
         GetOptions,
         Hybrid,
@@ -674,6 +777,7 @@ pub mod prelude {
         SqlModelDump,
         SqlModelValidate,
         TaskId,
+        TextFieldExt,
         TrackedModel,
         ValidateInput,
         ValidateOptions,
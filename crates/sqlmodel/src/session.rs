@@ -11,5 +11,6 @@
 //! `sqlmodel::ConnectionSession`.
 
 pub use sqlmodel_session::{
-    GetOptions, ObjectKey, ObjectState, Session, SessionConfig, SessionDebugInfo,
+    GetOptions, ObjectKey, ObjectState, Page, Session, SessionConfig, SessionDebugInfo,
+    UnsetPkPolicy,
 };
@@ -125,3 +125,143 @@ fn sqlite_select_one_enforces_exactly_one_row() {
         }
     });
 }
+
+#[test]
+fn sqlite_scalar_returns_first_column_of_first_row() {
+    let rt = RuntimeBuilder::current_thread()
+        .build()
+        .expect("create asupersync runtime");
+    let cx = Cx::for_testing();
+
+    rt.block_on(async {
+        let conn = SqliteConnection::open_memory().expect("open sqlite memory db");
+
+        let stmts = SchemaBuilder::new().create_table::<User>().build();
+        for stmt in stmts {
+            unwrap_outcome(conn.execute(&cx, &stmt, &[]).await).expect("execute ddl");
+        }
+
+        let empty_max = unwrap_outcome(conn.scalar(&cx, "SELECT max(id) FROM users", &[]).await)
+            .expect("scalar max");
+        assert_eq!(empty_max, Some(Value::Null));
+        let typed_max = unwrap_outcome(
+            conn.scalar_as::<Option<i64>>(&cx, "SELECT max(id) FROM users", &[])
+                .await,
+        )
+        .expect("scalar_as max");
+        assert_eq!(typed_max, Some(None));
+
+        unwrap_outcome(
+            conn.execute(
+                &cx,
+                "INSERT INTO users (id, name) VALUES (?1, ?2)",
+                &[Value::BigInt(7), Value::Text("Alice".to_string())],
+            )
+            .await,
+        )
+        .expect("insert alice");
+
+        let count = unwrap_outcome(
+            conn.scalar_as::<i64>(&cx, "SELECT COUNT(*) FROM users", &[])
+                .await,
+        )
+        .expect("scalar_as count");
+        assert_eq!(count, Some(1));
+
+        let name = unwrap_outcome(
+            conn.scalar_as::<String>(
+                &cx,
+                "SELECT name FROM users WHERE id = ?1",
+                &[Value::BigInt(7)],
+            )
+            .await,
+        )
+        .expect("scalar_as name");
+        assert_eq!(name.as_deref(), Some("Alice"));
+
+        let missing = unwrap_outcome(
+            conn.scalar(
+                &cx,
+                "SELECT name FROM users WHERE id = ?1",
+                &[Value::BigInt(8)],
+            )
+            .await,
+        )
+        .expect("scalar missing");
+        assert_eq!(missing, None);
+
+        let mismatch = conn
+            .scalar_as::<i64>(&cx, "SELECT name FROM users", &[])
+            .await;
+        assert!(matches!(mismatch, Outcome::Err(Error::Type(_))));
+    });
+}
+
+#[test]
+fn sqlite_query_as_decodes_models_without_a_session() {
+    let rt = RuntimeBuilder::current_thread()
+        .build()
+        .expect("create asupersync runtime");
+    let cx = Cx::for_testing();
+
+    rt.block_on(async {
+        let conn = SqliteConnection::open_memory().expect("open sqlite memory db");
+
+        let stmts = SchemaBuilder::new().create_table::<User>().build();
+        for stmt in stmts {
+            unwrap_outcome(conn.execute(&cx, &stmt, &[]).await).expect("execute ddl");
+        }
+        for (id, name) in [(1_i64, "Alice"), (2, "Bob")] {
+            unwrap_outcome(
+                conn.execute(
+                    &cx,
+                    "INSERT INTO users (id, name) VALUES (?1, ?2)",
+                    &[Value::BigInt(id), Value::Text(name.to_string())],
+                )
+                .await,
+            )
+            .expect("insert user");
+        }
+
+        let users = unwrap_outcome(
+            conn.query_as::<User>(&cx, "SELECT id, name FROM users ORDER BY id", &[])
+                .await,
+        )
+        .expect("query_as users");
+        assert_eq!(users.len(), 2);
+        assert_eq!(users[1].name, "Bob");
+
+        let bob = unwrap_outcome(
+            conn.query_one_as::<User>(
+                &cx,
+                "SELECT id, name FROM users WHERE id = ?1",
+                &[Value::BigInt(2)],
+            )
+            .await,
+        )
+        .expect("query_one_as bob");
+        assert_eq!(
+            bob,
+            Some(User {
+                id: 2,
+                name: "Bob".to_string()
+            })
+        );
+
+        let missing = unwrap_outcome(
+            conn.query_one_as::<User>(
+                &cx,
+                "SELECT id, name FROM users WHERE id = ?1",
+                &[Value::BigInt(9)],
+            )
+            .await,
+        )
+        .expect("query_one_as missing");
+        assert_eq!(missing, None);
+
+        let undecodable = conn
+            .query_as::<User>(&cx, "SELECT id FROM users", &[])
+            .await;
+        assert!(matches!(undecodable, Outcome::Err(_)));
+    });
+}